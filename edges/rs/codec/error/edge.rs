@@ -0,0 +1,3 @@
+/// A stream which cannot be compressed or decompressed : its name, then the reason
+#[derive(Clone, Debug)]
+pub struct CodecError(pub String, pub String);
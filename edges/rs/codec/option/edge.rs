@@ -0,0 +1,12 @@
+/// What `codec_gzip` and `codec_zstd` do with the bytes. `level` is the compression level, the default one if None.
+#[derive(Clone, Debug)]
+pub struct CodecOption {
+    pub mode: CodecMode,
+    pub level: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CodecMode {
+    Compress,
+    Decompress,
+}
@@ -1,15 +1,34 @@
 use std::any::Any;
-use rustfbp::scheduler::{Scheduler};
+use rustfbp::scheduler::{AgentCache, Scheduler};
 use std::collections::HashMap;
 #[derive(Debug)]
 pub enum CoreAction {
         Add(CoreActionAdd),
+        // re-read the graph and apply the difference to the running subnet
+        Reload(CoreActionAdd),
+        // read the graph, check its agents, ports and edges without running it, then exit fvm
+        Check(CoreActionAdd),
+        // read a graph on the graph port and add it to the subnet
+        Apply(String),
+        // start the agents without input port of the subnet
+        Start(String),
+        // print the nodes and edges of the subnet
+        List(String),
         Remove(String),
         Connect(CoreActionConnect),
         // TODO need send?
         // Send(CoreActionSend),
+        // connect the exported output port `port` of the network to a sender out of it, see Scheduler::export_output
         ConnectSender(CoreActionConnectSender),
+        // answer the sender of an exported input port of the network, see Scheduler::get_exported_sender
+        GetSender(String, std::sync::mpsc::Sender<rustfbp::result::Result<Box<Any + Send>>>),
+        // the options and limits of the nodes, from the manifest of fvm
+        Configure(CoreConfig),
+        // load the agents in the cache shared with the other networks of fvm, see rustfbp::manager
+        Cache(AgentCache),
         Halt,
+        // wait until the subnets are idle, then halt even if their agents did not end
+        HaltIdle,
 }
 
 #[derive(Debug)]
@@ -35,3 +54,19 @@ pub struct CoreActionConnectSender {
     pub elem: String,
     pub output: Box<Any + Send>,
 }
+
+#[derive(Debug, Clone, Default)]
+pub struct CoreConfig {
+    pub nodes: HashMap<String, CoreNodeConfig>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CoreNodeConfig {
+    // sent to the port option of the node, like an imsg
+    pub option: Option<String>,
+    pub limits: Option<rustfbp::limits::Limits>,
+    // run in its own process, see rustfbp::isolate
+    pub isolated: bool,
+    // run on its own thread, see rustfbp::affinity
+    pub placement: Option<rustfbp::affinity::Placement>,
+}
@@ -0,0 +1,159 @@
+/// The changes from a graph to another one, see `between`. The nodes are matched by name, the imsgs by the port they
+/// are sent to. A pool, an `AFTER=` or a port of the network which changed is removed and added.
+#[derive(Debug, Clone)]
+pub struct CoreGraphDiff {
+    pub nodes_added: Vec<CoreGraphNode>,
+    pub nodes_removed: Vec<CoreGraphNode>,
+    // Same name, another sort
+    pub nodes_changed: Vec<CoreGraphNodeChange>,
+    pub edges_added: Vec<CoreGraphEdge>,
+    pub edges_removed: Vec<CoreGraphEdge>,
+    pub imsgs_added: Vec<CoreGraphIMsg>,
+    pub imsgs_removed: Vec<CoreGraphIMsg>,
+    // Same port, another message
+    pub imsgs_changed: Vec<CoreGraphIMsgChange>,
+    pub pools_added: Vec<CoreGraphPool>,
+    pub pools_removed: Vec<CoreGraphPool>,
+    pub afters_added: Vec<CoreGraphAfter>,
+    pub afters_removed: Vec<CoreGraphAfter>,
+    pub ext_in_added: Vec<CoreGraphExtIn>,
+    pub ext_in_removed: Vec<CoreGraphExtIn>,
+    pub ext_out_added: Vec<CoreGraphExtOut>,
+    pub ext_out_removed: Vec<CoreGraphExtOut>,
+}
+
+impl CoreGraphDiff {
+    pub fn new() -> Self {
+        CoreGraphDiff {
+            nodes_added: Vec::new(),
+            nodes_removed: Vec::new(),
+            nodes_changed: Vec::new(),
+            edges_added: Vec::new(),
+            edges_removed: Vec::new(),
+            imsgs_added: Vec::new(),
+            imsgs_removed: Vec::new(),
+            imsgs_changed: Vec::new(),
+            pools_added: Vec::new(),
+            pools_removed: Vec::new(),
+            afters_added: Vec::new(),
+            afters_removed: Vec::new(),
+            ext_in_added: Vec::new(),
+            ext_in_removed: Vec::new(),
+            ext_out_added: Vec::new(),
+            ext_out_removed: Vec::new(),
+        }
+    }
+
+    /// The changes from `old` to `new`
+    ///
+    /// An include is a node whose sort is its path. An edge moved to another port is removed and added.
+    pub fn between(old: &CoreGraph, new: &CoreGraph) -> Self {
+        let mut diff = CoreGraphDiff::new();
+
+        let old_nodes = diff_nodes(old);
+        let new_nodes = diff_nodes(new);
+        for node in &new_nodes {
+            match old_nodes.iter().find(|n| n.name == node.name) {
+                Some(n) if n.sort != node.sort => {
+                    diff.nodes_changed.push(CoreGraphNodeChange {
+                        name: node.name.clone(),
+                        old_sort: n.sort.clone(),
+                        new_sort: node.sort.clone(),
+                    });
+                },
+                Some(_) => {},
+                None => { diff.nodes_added.push(node.clone()); },
+            }
+        }
+        diff.nodes_removed = old_nodes.into_iter().filter(|n| !new_nodes.iter().any(|node| node.name == n.name)).collect();
+
+        diff.edges_added = added(&old.edges, &new.edges);
+        diff.edges_removed = added(&new.edges, &old.edges);
+
+        let same_port = |a: &CoreGraphIMsg, b: &CoreGraphIMsg| a.comp == b.comp && a.port == b.port && a.elem == b.elem;
+        for imsg in &new.imsgs {
+            if old.imsgs.contains(imsg) {
+                continue;
+            }
+            let olds: Vec<&CoreGraphIMsg> = old.imsgs.iter().filter(|i| same_port(i, imsg)).collect();
+            let news = new.imsgs.iter().filter(|i| same_port(i, imsg)).count();
+            // A port with a single imsg in both graphs is an option which changed
+            if olds.len() == 1 && news == 1 {
+                diff.imsgs_changed.push(CoreGraphIMsgChange {
+                    comp: imsg.comp.clone(),
+                    port: imsg.port.clone(),
+                    elem: imsg.elem.clone(),
+                    old_msg: olds[0].msg.clone(),
+                    new_msg: imsg.msg.clone(),
+                });
+            } else {
+                diff.imsgs_added.push(imsg.clone());
+            }
+        }
+        diff.imsgs_removed = old.imsgs.iter()
+            .filter(|i| !new.imsgs.contains(i))
+            .filter(|i| !diff.imsgs_changed.iter().any(|c| c.comp == i.comp && c.port == i.port && c.elem == i.elem))
+            .cloned()
+            .collect();
+
+        diff.pools_added = added(&old.pools, &new.pools);
+        diff.pools_removed = added(&new.pools, &old.pools);
+        diff.afters_added = added(&old.afters, &new.afters);
+        diff.afters_removed = added(&new.afters, &old.afters);
+        diff.ext_in_added = added(&old.ext_in, &new.ext_in);
+        diff.ext_in_removed = added(&new.ext_in, &old.ext_in);
+        diff.ext_out_added = added(&old.ext_out, &new.ext_out);
+        diff.ext_out_removed = added(&new.ext_out, &old.ext_out);
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes_added.is_empty() && self.nodes_removed.is_empty() && self.nodes_changed.is_empty()
+            && self.edges_added.is_empty() && self.edges_removed.is_empty()
+            && self.imsgs_added.is_empty() && self.imsgs_removed.is_empty() && self.imsgs_changed.is_empty()
+            && self.pools_added.is_empty() && self.pools_removed.is_empty()
+            && self.afters_added.is_empty() && self.afters_removed.is_empty()
+            && self.ext_in_added.is_empty() && self.ext_in_removed.is_empty()
+            && self.ext_out_added.is_empty() && self.ext_out_removed.is_empty()
+    }
+}
+
+// The items of `new` which are not in `old`
+fn added<T: Clone + PartialEq>(old: &[T], new: &[T]) -> Vec<T> {
+    new.iter().filter(|item| !old.contains(item)).cloned().collect()
+}
+
+// The nodes of a graph, once each, in the order of their first use. A node is used without its sort after it is
+// declared, or before.
+fn diff_nodes(graph: &CoreGraph) -> Vec<CoreGraphNode> {
+    let mut nodes: Vec<CoreGraphNode> = Vec::new();
+    let includes = graph.includes.iter().map(|i| CoreGraphNode { name: i.name.clone(), sort: i.path.clone() });
+    for node in graph.nodes.iter().cloned().chain(includes) {
+        match nodes.iter_mut().find(|n| n.name == node.name) {
+            Some(n) => {
+                if n.sort.is_empty() {
+                    n.sort = node.sort;
+                }
+            },
+            None => { nodes.push(node); },
+        }
+    }
+    nodes
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoreGraphNodeChange {
+    pub name: String,
+    pub old_sort: String,
+    pub new_sort: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoreGraphIMsgChange {
+    pub comp: String,
+    pub port: String,
+    pub elem: Option<String>,
+    pub old_msg: String,
+    pub new_msg: String,
+}
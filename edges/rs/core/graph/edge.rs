@@ -10,6 +10,16 @@ pub struct CoreGraph {
     pub ext_in: Vec<CoreGraphExtIn>,
     // out() p[s] => p
     pub ext_out: Vec<CoreGraphExtOut>,
+    // node(sort) * size
+    pub pools: Vec<CoreGraphPool>,
+    // include "path" as name
+    pub includes: Vec<CoreGraphInclude>,
+    // AFTER=node:after
+    pub afters: Vec<CoreGraphAfter>,
+    // The graph, or one of its subgraphs, has errors : it cannot run
+    pub errors: bool,
+    // The canonical paths of the files of the graph and of its subgraphs, set by the vm
+    pub files: Vec<String>,
 }
 
 impl CoreGraph {
@@ -21,17 +31,22 @@ impl CoreGraph {
             imsgs: Vec::new(),
             ext_in: Vec::new(),
             ext_out: Vec::new(),
+            pools: Vec::new(),
+            includes: Vec::new(),
+            afters: Vec::new(),
+            errors: false,
+            files: Vec::new(),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CoreGraphNode {
     pub name: String,
     pub sort: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CoreGraphEdge {
     pub out_comp: String,
     pub out_port: String,
@@ -41,7 +56,7 @@ pub struct CoreGraphEdge {
     pub in_comp: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CoreGraphIMsg {
     pub msg: String,
     pub port: String,
@@ -49,7 +64,7 @@ pub struct CoreGraphIMsg {
     pub comp: String
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CoreGraphExtIn {
     pub port: String,
     pub in_port: String,
@@ -57,10 +72,31 @@ pub struct CoreGraphExtIn {
     pub in_comp: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CoreGraphExtOut {
     pub port: String,
     pub out_port: String,
     pub out_elem: Option<String>,
     pub out_comp: String,
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoreGraphPool {
+    pub name: String,
+    pub size: usize,
+    pub ordered: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoreGraphInclude {
+    pub name: String,
+    // Relative to the graph which includes it
+    pub path: String,
+}
+
+// `node` doesn't run until `after` is ready
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoreGraphAfter {
+    pub node: String,
+    pub after: String,
+}
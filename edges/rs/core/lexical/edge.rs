@@ -3,14 +3,36 @@ pub enum CoreLexical {
     Start(String),
     End(String),
     NotFound(String),
-    Token(CoreLexicalToken),
+    Token(CoreLexicalToken, CoreLexicalPos),
+}
+
+// Where a token starts in the graph, from line 1 and column 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoreLexicalPos {
+    pub line: usize,
+    pub column: usize,
 }
 
 #[derive(Debug, Clone)]
 pub enum CoreLexicalToken {
     Bind, External, Comment,
+    // An empty name for an anonymous node : (sort)
     Comp(String, Option<String>),
     Port(String, Option<String>),
     IMsg(String),
+    // * size [ordered]
+    Pool(usize, bool),
+    // INPORT=comp.port[elem]:NAME
+    InPort(String, String, Option<String>, String),
+    // OUTPORT=comp.port[elem]:NAME
+    OutPort(String, String, Option<String>, String),
+    // ELEMENTS=comp.port:a,b,c or ELEMENTS=comp.port:0..4
+    Elements(String, String, String),
+    // include "path" as name
+    Include(String, String),
+    // AFTER=node:a,b,c
+    After(String, String),
+    // @if feature(name), @else and @end
+    If(String), Else, EndIf,
     Break,
 }
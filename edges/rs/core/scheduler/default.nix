@@ -2,5 +2,5 @@
 
 edge.rs {
   src = ./.;
-  edges =  with edges.rs; [ ];
+  edges =  with edges.rs; [ CoreGraph CoreAction ];
 }
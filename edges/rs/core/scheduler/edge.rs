@@ -1,6 +1,8 @@
 pub struct CoreScheduler {
     pub sched: Scheduler,
     pub subnets: HashMap<String, CoreSchedulerSubnet>,
+    // the options and limits of the nodes, by name
+    pub config: CoreConfig,
 }
 
 impl CoreScheduler {
@@ -8,12 +10,15 @@ impl CoreScheduler {
         CoreScheduler {
             sched: Scheduler::new(),
             subnets: HashMap::new(),
+            config: CoreConfig::default(),
         }
     }
 }
 
 pub struct CoreSchedulerSubnet {
     pub nodes: Vec<String>,
+    // the graph currently running, used to compute the delta on reload
+    pub graph: CoreGraph,
     pub ext_in: HashMap<String, (String, String)>,
     pub ext_out: HashMap<String, (String, String)>,
 }
@@ -22,6 +27,7 @@ impl CoreSchedulerSubnet {
     pub fn new() -> CoreSchedulerSubnet {
         CoreSchedulerSubnet {
             nodes: vec![],
+            graph: CoreGraph::new(),
             ext_in: HashMap::new(),
             ext_out: HashMap::new(),
         }
@@ -0,0 +1,8 @@
+/// The fields of a Cap'n Proto schema, by name and type, see `rustfbp::schema`.
+/// Sent to `core_schema_registry` to register a schema, and received from it as the answer to a query : the fields
+/// of an unknown schema are empty.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CoreSchema {
+    pub name: String,
+    pub fields: Vec<(String, String)>,
+}
@@ -0,0 +1,16 @@
+/// The digest of a stream of `crypto_hash` or `crypto_hmac`, with the name of the stream
+#[derive(Clone, Debug)]
+pub struct CryptoDigest {
+    pub name: String,
+    pub algorithm: CryptoAlgorithm,
+    pub digest: Vec<u8>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CryptoAlgorithm {
+    Sha256,
+    // BLAKE2b, 512 bits
+    Blake2b,
+    // BLAKE2s, 256 bits
+    Blake2s,
+}
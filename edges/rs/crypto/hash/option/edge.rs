@@ -0,0 +1,6 @@
+/// The hash of `crypto_hash`. With `verify`, the digests are checked against the expected ones instead of being sent.
+#[derive(Clone, Debug)]
+pub struct CryptoHashOption {
+    pub algorithm: CryptoAlgorithm,
+    pub verify: bool,
+}
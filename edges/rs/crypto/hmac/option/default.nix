@@ -0,0 +1,6 @@
+{ edge, edges }:
+
+edge.rs {
+  src = ./.;
+  edges =  with edges.rs; [ CryptoDigest ];
+}
@@ -0,0 +1,8 @@
+/// The hash and the secret key of `crypto_hmac`. With `verify`, the digests are checked against the expected ones
+/// instead of being sent.
+#[derive(Clone, Debug)]
+pub struct CryptoHmacOption {
+    pub algorithm: CryptoAlgorithm,
+    pub key: Vec<u8>,
+    pub verify: bool,
+}
@@ -0,0 +1,6 @@
+/// True if the stream `name` has the expected digest
+#[derive(Clone, Debug)]
+pub struct CryptoVerify {
+    pub name: String,
+    pub valid: bool,
+}
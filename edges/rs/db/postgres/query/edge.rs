@@ -0,0 +1,8 @@
+/// A SQL query and its parameters `$1`, `$2`, ...
+///
+/// The parameters are text, they are cast in the query when needed : `where id = $1::text::int`.
+#[derive(Clone, Debug)]
+pub struct DbPostgresQuery {
+    pub sql: String,
+    pub params: Vec<String>,
+}
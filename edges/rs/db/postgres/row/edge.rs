@@ -0,0 +1,8 @@
+/// The rows of a query, between a `Start` with the query and an `End` with the number of rows
+#[derive(Clone, Debug)]
+pub enum DbPostgresRow {
+    Start(String),
+    // The columns, by name, with their value as text, None for NULL
+    Row(Vec<(String, Option<String>)>),
+    End(u64),
+}
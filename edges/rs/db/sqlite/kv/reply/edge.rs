@@ -0,0 +1,11 @@
+/// The reply of `db_sqlite_kv` to each request
+#[derive(Clone, Debug)]
+pub enum DbSqliteKvReply {
+    // The value of a key, None if the key is not in the store
+    Value(String, Option<String>),
+    // The key was set or deleted
+    Done(String),
+    // The keys of a `List`, sorted
+    Keys(Vec<String>),
+    Error(String),
+}
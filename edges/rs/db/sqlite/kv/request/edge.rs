@@ -0,0 +1,8 @@
+/// An operation on the store of `db_sqlite_kv`, `List` takes the keys starting with a prefix
+#[derive(Clone, Debug)]
+pub enum DbSqliteKvRequest {
+    Get(String),
+    Set(String, String),
+    Delete(String),
+    List(String),
+}
@@ -7,15 +7,57 @@ in
 # It is the responsibility of that node-x's author to discuss with the author of the schema in question to stabilize the schema.
 {
   # raw
+  CodecError = callPackage ./codec/error {};
+  CodecOption = callPackage ./codec/option {};
   CoreAction = callPackage ./core/action {};
   CoreLexical = callPackage ./core/lexical {};
   CoreGraph = callPackage ./core/graph {};
+  CoreGraphDiff = callPackage ./core/graph/diff {};
   CoreScheduler = callPackage ./core/scheduler {};
+  CoreSchema = callPackage ./core/schema {};
   CoreSemanticError = callPackage ./core/semantic/error {};
+  CryptoDigest = callPackage ./crypto/digest {};
+  CryptoHashOption = callPackage ./crypto/hash/option {};
+  CryptoHmacOption = callPackage ./crypto/hmac/option {};
+  CryptoVerify = callPackage ./crypto/verify {};
+  DbPostgresError = callPackage ./db/postgres/error {};
+  DbPostgresOption = callPackage ./db/postgres/option {};
+  DbPostgresQuery = callPackage ./db/postgres/query {};
+  DbPostgresRow = callPackage ./db/postgres/row {};
+  DbSqliteKvReply = callPackage ./db/sqlite/kv/reply {};
+  DbSqliteKvRequest = callPackage ./db/sqlite/kv/request {};
+  FlowCircuitBreakerFeedback = callPackage ./flow/circuit/breaker/feedback {};
+  FlowCircuitBreakerOption = callPackage ./flow/circuit/breaker/option {};
+  FlowDedupOption = callPackage ./flow/dedup/option {};
+  FlowDelayOption = callPackage ./flow/delay/option {};
+  FlowJoinOption = callPackage ./flow/join/option {};
+  FlowJoined = callPackage ./flow/join/joined {};
+  FlowLoadbalanceOption = callPackage ./flow/loadbalance/option {};
+  FlowRetryOption = callPackage ./flow/retry/option {};
+  FlowRetryReply = callPackage ./flow/retry/reply {};
+  FlowRouteOption = callPackage ./flow/route/option {};
+  FlowSwitchOption = callPackage ./flow/switch/option {};
+  FmtCsvError = callPackage ./fmt/csv/error {};
+  FmtCsvOption = callPackage ./fmt/csv/option {};
+  FmtCsvRecord = callPackage ./fmt/csv/record {};
+  FsDirListOption = callPackage ./fs/dir/list/option {};
   FsFileDesc = callPackage ./fs/file/desc {};
   FsFileError = callPackage ./fs/file/error {};
+  FsFileOpenOption = callPackage ./fs/file/open/option {};
+  FsFileWriteOption = callPackage ./fs/file/write/option {};
   FsPath = callPackage ./fs/path {};
   FsPathOption = callPackage ./fs/path/option {};
+  FsWatchEvent = callPackage ./fs/watch/event {};
+  JsonOption = callPackage ./json/option {};
+  MaybeJson = callPackage ./maybe/json {};
+  MsgMqttMessage = callPackage ./msg/mqtt/message {};
+  MsgMqttOption = callPackage ./msg/mqtt/option {};
+  NetZmqError = callPackage ./net/zmq/error {};
+  NetZmqSocket = callPackage ./net/zmq/socket {};
+  ProcExecOption = callPackage ./proc/exec/option {};
+  StreamWindow = callPackage ./stream/window {};
+  StreamWindowAggregate = callPackage ./stream/window/aggregate {};
+  StreamWindowOption = callPackage ./stream/window/option {};
   TestConst = callPackage ./test/const {};
   TestEnum = callPackage ./test/enum {};
   TestNil = callPackage ./test/nil {};
@@ -23,6 +65,18 @@ in
   TestPerson = callPackage ./test/person {};
   TestPoint = callPackage ./test/point {};
   TestRectangle = callPackage ./test/rectangle {};
+  TextRegexCaptures = callPackage ./text/regex/captures {};
+  TextRegexOption = callPackage ./text/regex/option {};
+  TextTemplateOption = callPackage ./text/template/option {};
+  TimeTickerControl = callPackage ./time/ticker/control {};
+  TimeTickerOption = callPackage ./time/ticker/option {};
+  TimeTimestamp = callPackage ./time/timestamp {};
+  WebClientError = callPackage ./web/client/error {};
+  WebRequest = callPackage ./web/request {};
+  WebResponse = callPackage ./web/response {};
+  WebServerOption = callPackage ./web/server/option {};
+  WebWsFrame = callPackage ./web/ws/frame {};
+  WebWsOption = callPackage ./web/ws/option {};
 
   # draft
 
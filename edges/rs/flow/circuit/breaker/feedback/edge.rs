@@ -0,0 +1,6 @@
+/// The outcome of a message sent downstream of a circuit breaker
+#[derive(Clone, Debug, PartialEq)]
+pub enum FlowCircuitBreakerFeedback {
+    Success,
+    Failure,
+}
@@ -0,0 +1,9 @@
+/// When the circuit opens : over `failure_rate` (0 to 1) failures in the last `window` outcomes, once there are at
+/// least `min_calls` of them, and how long it stays open before a trial message, in milliseconds
+#[derive(Clone, Debug)]
+pub struct FlowCircuitBreakerOption {
+    pub window: usize,
+    pub min_calls: usize,
+    pub failure_rate: f64,
+    pub cooldown: u64,
+}
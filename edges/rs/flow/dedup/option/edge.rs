@@ -0,0 +1,10 @@
+/// How many keys are remembered (the least recently seen are forgotten first), and for how long in milliseconds (0
+/// for ever), with the field which is the key of a message, read with `schema` (empty for the schema carried by the
+/// messages), or an empty field for the hash of its content
+#[derive(Clone, Debug)]
+pub struct FlowDedupOption {
+    pub capacity: usize,
+    pub ttl: u64,
+    pub schema: String,
+    pub field: String,
+}
@@ -0,0 +1,3 @@
+/// The delay in milliseconds
+#[derive(Clone, Debug)]
+pub struct FlowDelayOption(pub u64);
@@ -0,0 +1,6 @@
+/// The messages of a key, as (element, `CapnpMsg::to_envelope`), sorted by element
+#[derive(Clone, Debug)]
+pub struct FlowJoined {
+    pub key: String,
+    pub messages: Vec<(String, Vec<u8>)>,
+}
@@ -0,0 +1,16 @@
+/// How the messages of the elements are paired, when an incomplete group expires (in milliseconds, 0 for never),
+/// and how many incomplete groups are kept (0 for no bound, 1024 without an option)
+#[derive(Clone, Debug)]
+pub struct FlowJoinOption {
+    pub key: FlowJoinKey,
+    pub timeout: u64,
+    pub max_pending: usize,
+}
+
+/// The key of a message : its rank on its element, its action, or one of its headers
+#[derive(Clone, Debug)]
+pub enum FlowJoinKey {
+    Order,
+    Action,
+    Header(String),
+}
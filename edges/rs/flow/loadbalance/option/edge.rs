@@ -0,0 +1,8 @@
+/// How the messages are distributed : in turn, to the element with the fewest waiting messages,
+/// or always to the same element for the same action
+#[derive(Clone, Debug)]
+pub enum FlowLoadbalanceOption {
+    RoundRobin,
+    LeastLoaded,
+    HashAction,
+}
@@ -0,0 +1,9 @@
+/// How many times a message is sent, the first delay before it is sent again and the longest one (the delay doubles
+/// at each attempt), and how long a reply is waited for, all in milliseconds
+#[derive(Clone, Debug)]
+pub struct FlowRetryOption {
+    pub attempts: u32,
+    pub backoff: u64,
+    pub max_backoff: u64,
+    pub timeout: u64,
+}
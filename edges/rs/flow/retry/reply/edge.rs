@@ -0,0 +1,7 @@
+/// The outcome of a message sent by `flow_retry`, with the id of its header `retry-id`
+#[derive(Clone, Debug)]
+pub enum FlowRetryReply {
+    Ack(String),
+    /// The id and the error
+    Err(String, String),
+}
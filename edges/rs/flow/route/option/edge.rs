@@ -0,0 +1,9 @@
+/// The schema of the routed messages, and the rules which choose their element of the `output` array output
+///
+/// The rules are separated by `;` or new lines, as `predicate => element` or `default => element`, for example
+/// `size > 1024 && kind == "image" => big; default => small`. An empty schema is the schema carried by the message.
+#[derive(Clone, Debug)]
+pub struct FlowRouteOption {
+    pub schema: String,
+    pub rules: String,
+}
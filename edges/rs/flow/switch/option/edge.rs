@@ -0,0 +1,10 @@
+/// The field which chooses the element of a message, read with `schema` (empty for the schema carried by the
+/// messages), and the routes as (value of the field, element of the `output` array output)
+///
+/// A nested field is `a.b`, and a value which isn't a text is compared as JSON, `3` or `true`.
+#[derive(Clone, Debug)]
+pub struct FlowSwitchOption {
+    pub schema: String,
+    pub field: String,
+    pub routes: Vec<(String, String)>,
+}
@@ -0,0 +1,24 @@
+/// How `fmt_csv_read` and `fmt_csv_write` read and write the CSV text
+///
+/// With `header`, the first row names the columns. `columns` picks and types the columns of each record, by name
+/// with a header and by position without; when empty, all the columns are text.
+#[derive(Clone, Debug)]
+pub struct FmtCsvOption {
+    pub delimiter: u8,
+    pub header: bool,
+    pub columns: Vec<FmtCsvColumn>,
+}
+
+#[derive(Clone, Debug)]
+pub struct FmtCsvColumn {
+    pub name: String,
+    pub kind: FmtCsvKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FmtCsvKind {
+    Text,
+    Int,
+    Float,
+    Bool,
+}
@@ -0,0 +1,18 @@
+/// The records of a CSV text, in a substream named like the file
+#[derive(Clone, Debug)]
+pub enum FmtCsvRecord {
+    Start(String),
+    // The fields of a row, by column name
+    Row(Vec<(String, FmtCsvValue)>),
+    End(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FmtCsvValue {
+    // An empty field
+    Null,
+    Text(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
@@ -0,0 +1,6 @@
+/// List the sub-directories if `recursive`, and only the entries whose name matches `glob`
+#[derive(Clone, Debug)]
+pub struct FsDirListOption {
+    pub recursive: bool,
+    pub glob: Option<String>,
+}
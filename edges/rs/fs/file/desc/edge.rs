@@ -3,4 +3,6 @@ pub enum FsFileDesc {
     Start(String),
     End(String),
     Text(String),
+    // A part of the file, which can end in the middle of a line
+    Chunk(Vec<u8>),
 }
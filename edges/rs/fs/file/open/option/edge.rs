@@ -0,0 +1,3 @@
+/// Read the file in chunks of this size (in bytes), instead of line by line
+#[derive(Clone, Debug)]
+pub struct FsFileOpenOption(pub usize);
@@ -0,0 +1,8 @@
+/// `path` replaces the path of the `FsFileDesc::Start`, `append` keeps the content of the file,
+/// and `fsync` waits for the file to be on the disk at the `FsFileDesc::End`
+#[derive(Clone, Debug)]
+pub struct FsFileWriteOption {
+    pub path: Option<String>,
+    pub append: bool,
+    pub fsync: bool,
+}
@@ -0,0 +1,14 @@
+#[derive(Clone, Debug, PartialEq)]
+pub enum FsWatchKind {
+    Created,
+    Modified,
+    Deleted,
+}
+
+/// A change of `path`, at `timestamp` milliseconds since the UNIX epoch
+#[derive(Clone, Debug)]
+pub struct FsWatchEvent {
+    pub path: String,
+    pub kind: FsWatchKind,
+    pub timestamp: u64,
+}
@@ -0,0 +1,7 @@
+/// The name of the Cap'n Proto schema converted by `encode_json` and `decode_json`, like `PrimText`.
+/// `pretty` indents the JSON text of `encode_json`.
+#[derive(Clone, Debug)]
+pub struct JsonOption {
+    pub schema: String,
+    pub pretty: bool,
+}
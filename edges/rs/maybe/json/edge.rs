@@ -0,0 +1,6 @@
+/// JSON text, or the reason why a message could not be converted from or into JSON
+#[derive(Clone, Debug)]
+pub enum MaybeJson {
+    Json(String),
+    Error(String),
+}
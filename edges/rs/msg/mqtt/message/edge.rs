@@ -0,0 +1,6 @@
+/// A message published on an MQTT topic
+#[derive(Clone, Debug)]
+pub struct MsgMqttMessage {
+    pub topic: String,
+    pub payload: Vec<u8>,
+}
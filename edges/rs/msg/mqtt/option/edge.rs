@@ -0,0 +1,13 @@
+/// Connect to an MQTT broker
+///
+/// `qos` is 0 (at most once) or 1 (at least once). Each subscription is a topic filter, which may contain the
+/// `+` and `#` wildcards, and the element of `outputs` of `msg_mqtt_sub` receiving its messages.
+#[derive(Clone, Debug)]
+pub struct MsgMqttOption {
+    pub host: String,
+    pub port: u16,
+    pub client_id: String,
+    pub tls: bool,
+    pub qos: u8,
+    pub subscriptions: Vec<(String, String)>,
+}
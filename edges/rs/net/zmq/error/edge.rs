@@ -0,0 +1,7 @@
+/// A frame received on `endpoint` which is not a capnp message, with the reason
+#[derive(Clone, Debug)]
+pub struct NetZmqError {
+    pub endpoint: String,
+    pub error: String,
+    pub frame: Vec<u8>,
+}
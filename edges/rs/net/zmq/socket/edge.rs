@@ -0,0 +1,10 @@
+/// Configure a ZeroMQ socket
+///
+/// `bind` chooses between binding on the endpoint or connecting to it. `topic` is the prefix
+/// of a PUB message, and the subscription filter of a SUB socket ("" receives everything).
+#[derive(Clone, Debug)]
+pub struct NetZmqSocket {
+    pub endpoint: String,
+    pub bind: bool,
+    pub topic: String,
+}
@@ -0,0 +1,7 @@
+/// The program run by `proc_exec`, with its arguments. `restart` starts it again when it exits.
+#[derive(Clone, Debug)]
+pub struct ProcExecOption {
+    pub program: String,
+    pub args: Vec<String>,
+    pub restart: bool,
+}
@@ -0,0 +1,7 @@
+/// The number of messages of a window, and the sum of the field of the option (0 without field)
+#[derive(Clone, Debug)]
+pub struct StreamWindowAggregate {
+    pub window: u64,
+    pub count: u64,
+    pub sum: f64,
+}
@@ -0,0 +1,8 @@
+/// The messages of a window, in a substream named by the number of the window
+#[derive(Clone, Debug)]
+pub enum StreamWindow {
+    Start(String),
+    // A message, as `CapnpMsg::to_envelope`
+    Msg(Vec<u8>),
+    End(String),
+}
@@ -0,0 +1,16 @@
+/// The size of the windows, and how far the next one starts in the same unit (0 for the size, tumbling windows), with
+/// the numeric `field` summed in the aggregates, read with `schema` (empty for the schema carried by the messages)
+#[derive(Clone, Debug)]
+pub struct StreamWindowOption {
+    pub size: StreamWindowSize,
+    pub slide: u64,
+    pub schema: String,
+    pub field: String,
+}
+
+/// A number of messages, or a time in milliseconds from the first message of the window
+#[derive(Clone, Debug)]
+pub enum StreamWindowSize {
+    Count(u64),
+    Time(u64),
+}
@@ -0,0 +1,6 @@
+/// The groups of a match, by their name or their number (`0` is the whole match), None for a group which didn't
+/// match
+#[derive(Clone, Debug)]
+pub struct TextRegexCaptures {
+    pub groups: Vec<(String, Option<String>)>,
+}
@@ -0,0 +1,7 @@
+/// The regular expression (see the crate `regex`), and for `text_regex_extract` whether every match of a text is
+/// extracted or only the first one
+#[derive(Clone, Debug)]
+pub struct TextRegexOption {
+    pub pattern: String,
+    pub all: bool,
+}
@@ -0,0 +1,7 @@
+/// The template, where `{field}` is the value of a field of the message and `{{` and `}}` are braces, and the schema
+/// the message is read with (empty for the schema carried by the messages)
+#[derive(Clone, Debug)]
+pub struct TextTemplateOption {
+    pub template: String,
+    pub schema: String,
+}
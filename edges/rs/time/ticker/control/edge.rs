@@ -0,0 +1,5 @@
+#[derive(Clone, Debug)]
+pub enum TimeTickerControl {
+    Pause,
+    Resume,
+}
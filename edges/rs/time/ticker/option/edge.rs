@@ -0,0 +1,6 @@
+/// Tick every `Interval` milliseconds (at least 1), or at each time of a `Cron` expression (with seconds, in UTC)
+#[derive(Clone, Debug)]
+pub enum TimeTickerOption {
+    Interval(u64),
+    Cron(String),
+}
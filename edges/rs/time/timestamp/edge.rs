@@ -0,0 +1,3 @@
+/// Milliseconds since the UNIX epoch
+#[derive(Clone, Debug)]
+pub struct TimeTimestamp(pub u64);
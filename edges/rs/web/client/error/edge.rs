@@ -0,0 +1,6 @@
+/// The `WebRequest` with this `id` could not get a response
+#[derive(Clone, Debug)]
+pub struct WebClientError {
+    pub id: usize,
+    pub error: String,
+}
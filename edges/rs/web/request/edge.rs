@@ -0,0 +1,9 @@
+/// An HTTP request, `id` must be given back in the `WebResponse`
+#[derive(Clone, Debug)]
+pub struct WebRequest {
+    pub id: usize,
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
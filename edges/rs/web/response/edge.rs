@@ -0,0 +1,19 @@
+/// The response to the `WebRequest` with the same `id`
+#[derive(Clone, Debug)]
+pub struct WebResponse {
+    pub id: usize,
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl WebResponse {
+    pub fn new(id: usize, status: u16, body: Vec<u8>) -> WebResponse {
+        WebResponse {
+            id: id,
+            status: status,
+            headers: vec![],
+            body: body,
+        }
+    }
+}
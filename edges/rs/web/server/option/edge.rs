@@ -0,0 +1,10 @@
+/// The address to bind, the routes as (path prefix, element of the `request` array output), how long a request
+/// waits for its response in milliseconds (0 for 30 seconds), before it is answered with 504, and the largest body
+/// of a request in bytes (0 for 1 MiB), over which it is answered with 413
+#[derive(Clone, Debug)]
+pub struct WebServerOption {
+    pub bind: String,
+    pub routes: Vec<(String, String)>,
+    pub timeout: u64,
+    pub max_body: u64,
+}
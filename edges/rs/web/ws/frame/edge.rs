@@ -0,0 +1,6 @@
+/// A WebSocket data frame
+#[derive(Clone, Debug)]
+pub enum WebWsFrame {
+    Text(String),
+    Binary(Vec<u8>),
+}
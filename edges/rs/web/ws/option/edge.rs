@@ -0,0 +1,6 @@
+/// Connect to `address` (a ws:// url), or listen on it (host:port) if `server` is true
+#[derive(Clone, Debug)]
+pub struct WebWsOption {
+    pub address: String,
+    pub server: bool,
+}
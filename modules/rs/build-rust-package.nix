@@ -1,19 +1,21 @@
 { rust, lib, buildPlatform, stdenv }:
 
-let mkRustCrate = { crateName, crateVersion, dependencies, complete, crateFeatures, libName, build, release, libPath, crateType, metadata, crateBin, finalBins, verboseBuild, unifiedCapnpEdges ? null, unifiedRustEdges, fractalType, setupHook }:
+let mkRustCrate = { crateName, crateVersion, dependencies, complete, crateFeatures, libName, build, release, libPath, crateType, metadata, crateBin, finalBins, verboseBuild, unifiedCapnpEdges ? null, unifiedRustEdges, fractalType, setupHook, edition, crateRenames, featuresEnv }:
 
       let depsDir = builtins.foldl' (deps: dep: deps + " " + dep.out) "" dependencies;
           completeDepsDir = builtins.foldl' (deps: dep: deps + " " + dep.out) "" complete;
           deps =
             builtins.foldl' (deps: dep:
-              let extern = lib.strings.replaceStrings ["-"] ["_"] dep.libName; in
+              let libName = lib.strings.replaceStrings ["-"] ["_"] dep.libName;
+                  # the name the dependency is imported with, if renamed in the Cargo.toml of the crate
+                  extern = if crateRenames ? ${dep.name} then crateRenames.${dep.name} else libName; in
               deps + (if dep.crateType == "lib" then
-                 " --extern ${extern}=${dep.out}/lib${extern}-${dep.metadata}.rlib"
+                 " --extern ${extern}=${dep.out}/lib${libName}-${dep.metadata}.rlib"
               else
-                 " --extern ${extern}=${dep.out}/lib${extern}-${dep.metadata}.so")
+                 " --extern ${extern}=${dep.out}/lib${libName}-${dep.metadata}.so")
             ) "" dependencies;
           optLevel = if release then 3 else 0;
-          rustcOpts = (if release then "-C opt-level=3" else "-g");
+          rustcOpts = (if release then "-C opt-level=3" else "-g") + (if edition == "" then "" else " --edition " + edition);
           rustcMeta = "-C metadata=" + metadata + " -C extra-filename=-" + metadata;
       in ''
       norm="$(printf '\033[0m')" #returns to "normal"
@@ -38,11 +40,27 @@ let mkRustCrate = { crateName, crateVersion, dependencies, complete, crateFeatur
       BUILD_OUT_DIR=""
       export CARGO_PKG_NAME=${crateName}
       export CARGO_PKG_VERSION=${crateVersion}
+      export CARGO_PKG_VERSION_MAJOR=$(echo ${crateVersion} | cut -d. -f1)
+      export CARGO_PKG_VERSION_MINOR=$(echo ${crateVersion} | cut -d. -f2)
+      export CARGO_PKG_VERSION_PATCH=$(echo ${crateVersion} | cut -d. -f3 | sed -e "s/[-+].*//")
+      export CARGO_PKG_VERSION_PRE=$(echo ${crateVersion} | sed -n -e "s/^[^-+]*-\([^+]*\).*/\1/p")
+      export CARGO_PKG_AUTHORS=""
+      export CARGO_PKG_DESCRIPTION=""
+      export CARGO_PKG_HOMEPAGE=""
+      export CARGO_PKG_REPOSITORY=""
+      export CARGO_PKG_LICENSE=""
       export CARGO_CFG_TARGET_ARCH=$(echo ${buildPlatform.system} | sed -e "s/\([^-]*\)-\([^-]*\)/\1/")
       export CARGO_CFG_TARGET_OS=$(echo ${buildPlatform.system} | sed -e "s/\([^-]*\)-\([^-]*\)/\2/")
 
       export CARGO_CFG_TARGET_ENV="gnu"
-      export CARGO_MANIFEST_DIR="."
+      export CARGO_CFG_TARGET_FAMILY="unix"
+      export CARGO_CFG_UNIX=""
+      export CARGO_CFG_TARGET_VENDOR="unknown"
+      export CARGO_CFG_TARGET_ENDIAN="little"
+      export CARGO_CFG_TARGET_POINTER_WIDTH="64"
+      export CARGO_MANIFEST_DIR=$(pwd)
+      export RUSTC=rustc
+      ${featuresEnv}
       export DEBUG="${toString (!release)}"
       export OPT_LEVEL="${toString optLevel}"
       export TARGET="${buildPlatform.system}-gnu"
@@ -70,11 +88,15 @@ let mkRustCrate = { crateName, crateVersion, dependencies, complete, crateFeatur
            mkdir -p $OUT_DIR
            target/build/${crateName}/build_script_build > target/build/${crateName}.opt
            set +e
-           EXTRA_BUILD=$(grep "^cargo:rustc-flags=" target/build/${crateName}.opt | sed -e "s/cargo:rustc-flags=\(.*\)/\1/")
-           EXTRA_FEATURES=$(grep "^cargo:rustc-cfg=" target/build/${crateName}.opt | sed -e "s/cargo:rustc-cfg=\(.*\)/--cfg \1/")
-
-           EXTRA_LINK=$(grep "^cargo:rustc-link-lib=" target/build/${crateName}.opt | sed -e "s/cargo:rustc-link-lib=\(.*\)/\1/")
-           EXTRA_LINK_SEARCH=$(grep "^cargo:rustc-link-search=" target/build/${crateName}.opt | sed -e "s/cargo:rustc-link-search=\(.*\)/\1/")
+           # the build scripts print cargo:key=value, or cargo::key=value since cargo 1.77
+           EXTRA_BUILD=$(grep -E "^cargo::?rustc-flags=" target/build/${crateName}.opt | sed -E -e "s/cargo::?rustc-flags=(.*)/\1/")
+           EXTRA_FEATURES=$(grep -E "^cargo::?rustc-cfg=" target/build/${crateName}.opt | sed -E -e "s/cargo::?rustc-cfg=(.*)/--cfg \1/")
+
+           EXTRA_LINK=$(grep -E "^cargo::?rustc-link-lib=" target/build/${crateName}.opt | sed -E -e "s/cargo::?rustc-link-lib=(.*)/\1/")
+           EXTRA_LINK_SEARCH=$(grep -E "^cargo::?rustc-link-search=" target/build/${crateName}.opt | sed -E -e "s/cargo::?rustc-link-search=(.*)/\1/")
+           while read -r env; do
+              if [ -n "$env" ]; then export "$env"; fi
+           done <<< "$(grep -E "^cargo::?rustc-env=" target/build/${crateName}.opt | sed -E -e "s/cargo::?rustc-env=(.*)/\1/")"
            set -e
            if [ -n "$(ls target/build/${crateName}.out)" ]; then
 
@@ -144,12 +166,19 @@ let mkRustCrate = { crateName, crateVersion, dependencies, complete, crateFeatur
         ''
       else if fractalType == "fvm" then
         ''
+          echo "$boldgreen" "Building lib.rs (fractalide)" "$norm"
+          if ${verboseBuild}; then
+            echo "$boldgreen" "Running" "$norm" "rustc lib.rs --crate-type rlib ${rustcOpts} ${crateFeatures} --emit=dep-info,link -L dependency=target/deps ${deps} --cap-lints allow $BUILD_OUT_DIR $EXTRA_BUILD $EXTRA_FEATURES --crate-name fractalide -o libfractalide.rlib";
+          fi
+
+          rustc lib.rs --crate-type rlib ${rustcOpts} ${crateFeatures} --emit=dep-info,link -L dependency=target/deps ${deps} --cap-lints allow $BUILD_OUT_DIR $EXTRA_BUILD $EXTRA_FEATURES --crate-name fractalide -o libfractalide.rlib
+
           echo "$boldgreen" "Building main.rs (${libName})" "$norm"
           if ${verboseBuild}; then
-            echo "$boldgreen" "Running" "$norm" "rustc lib.rs --crate-type bin ${rustcOpts} ${crateFeatures} --emit=dep-info,link -L dependency=target/deps ${deps} --cap-lints allow $BUILD_OUT_DIR $EXTRA_BUILD $EXTRA_FEATURES --crate-name fvm -o fvm";
+            echo "$boldgreen" "Running" "$norm" "rustc main.rs --crate-type bin ${rustcOpts} ${crateFeatures} --emit=dep-info,link -L dependency=target/deps ${deps} --extern fractalide=libfractalide.rlib --cap-lints allow $BUILD_OUT_DIR $EXTRA_BUILD $EXTRA_FEATURES --crate-name fvm -o fvm";
           fi
 
-          rustc main.rs --crate-type bin ${rustcOpts} ${crateFeatures} --emit=dep-info,link -L dependency=target/deps ${deps} --cap-lints allow $BUILD_OUT_DIR $EXTRA_BUILD $EXTRA_FEATURES --crate-name fvm -o fvm
+          rustc main.rs --crate-type bin ${rustcOpts} ${crateFeatures} --emit=dep-info,link -L dependency=target/deps ${deps} --extern fractalide=libfractalide.rlib --cap-lints allow $BUILD_OUT_DIR $EXTRA_BUILD $EXTRA_FEATURES --crate-name fvm -o fvm
         '' else ""
       }
 
@@ -221,8 +250,9 @@ let mkRustCrate = { crateName, crateVersion, dependencies, complete, crateFeatur
         mkdir -p $out/lib
         cp libagent.so $out/lib
       '' else if fractalType == "fvm" then ''
-        mkdir -p $out/bin
+        mkdir -p $out/bin $out/lib
         cp fvm $out/bin
+        cp libfractalide.rlib $out/lib
       '' else ""
     }
     '';
@@ -251,6 +281,11 @@ crate: stdenv.mkDerivation rec {
     fractalType = if (lib.attrByPath ["fractalType"] [] crate) == [] then "" else crate.fractalType;
     configurePhase = if (lib.attrByPath ["configurePhase"] [] crate) == [] then "" else crate.configurePhase;
     setupHook = if (lib.attrByPath ["setupHook"] [] crate) == [] then "" else crate.setupHook;
+    edition = if crate ? edition then crate.edition else "";
+    # CARGO_FEATURE_<NAME> for the build script, one per enabled feature
+    featuresEnv = if crate ? features then
+       builtins.foldl' (env: f: env + "export CARGO_FEATURE_${lib.toUpper (lib.strings.replaceStrings ["-"] ["_"] f)}=1;") "" crate.features
+    else "";
 
 
     metadata = if crateVersion == null then builtins.substring 0 10 (builtins.hashString "sha256" (crateName)) else
@@ -286,6 +321,6 @@ crate: stdenv.mkDerivation rec {
       if lib.attrByPath ["procMacro"] false crate then "proc-macro" else
       if lib.attrByPath ["plugin"] false crate then "dylib" else "lib";
     verboseBuild = if lib.attrByPath [ "verbose" ] false crate then "true" else "false";
-    buildPhase = mkRustCrate { inherit crateName dependencies complete crateFeatures libName build release libPath crateType crateVersion metadata crateBin finalBins verboseBuild unifiedCapnpEdges unifiedRustEdges fractalType setupHook; };
+    buildPhase = mkRustCrate { inherit crateName dependencies complete crateFeatures libName build release libPath crateType crateVersion metadata crateBin finalBins verboseBuild unifiedCapnpEdges unifiedRustEdges fractalType setupHook edition featuresEnv; crateRenames = lib.attrByPath ["crateRenames"] {} crate; };
     installPhase = installCrate fractalType unifiedCapnpEdges;
 }
@@ -1,5 +1,54 @@
 { build-rust-package, fetchzip, release, verbose }:
 let
+    addr2line_0_21_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "addr2line";
+      version = "0.21.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/addr2line/0.21.0/download";
+        sha256 = "17frvnb657il06yr2s36sc0gn1l2ymgghdv9r6qlycz0l6gybyrd";
+        name = "addr2line-0.21.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    adler2_2_0_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "adler2";
+      version = "2.0.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/adler2/2.0.1/download";
+        sha256 = "0963pd3d9y44hzrpw77blyba1rp0amxp2pv5822gnm4qa95l4qcv";
+        name = "adler2-2.0.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    ahash_0_8_12_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ahash";
+      version = "0.8.12";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/ahash/0.8.12/download";
+        sha256 = "13mkcn08n4pzc4q6kspl8vbxnzj8kvqivwb5v7jimgb0gmr5fikp";
+        name = "ahash-0.8.12.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    aho_corasick_1_1_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "aho-corasick";
+      version = "1.1.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/aho-corasick/1.1.5/download";
+        sha256 = "1d2f34gkvzjpysf8dx80a9krq8hanxddhnrnrmmag5c98rz9j5lh";
+        name = "aho-corasick-1.1.5.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
     all_crates_1_1_1_ = { dependencies?[], features?[] }: build-rust-package {
       crateName = "all_crates";
       version = "1.1.1";
@@ -7,224 +56,5678 @@ let
       src = ./.;
       inherit dependencies features release verbose;
     };
-    byteorder_1_1_0_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "byteorder";
+    ambient_authority_0_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ambient-authority";
+      version = "0.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/ambient-authority/0.0.2/download";
+        sha256 = "0x7rb3749xmsfry06m1kqkaw3figivwly78fmm7il65gzngxna9p";
+        name = "ambient-authority-0.0.2.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    anyhow_1_0_104_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "anyhow";
+      version = "1.0.104";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/anyhow/1.0.104/download";
+        sha256 = "1ifj73kfzj1k6gk4qz9gi513xc8vcrl9d0aprll4kskh3nzq9ssd";
+        name = "anyhow-1.0.104.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    arbitrary_1_5_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "arbitrary";
+      version = "1.5.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/arbitrary/1.5.0/download";
+        sha256 = "0lc9wrfa6wdjjgd4lpg1cnm85psr0jq9zxfgggxw8xpwqbk0m6yw";
+        name = "arbitrary-1.5.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    ascii_1_1_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ascii";
       version = "1.1.0";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/byteorder/1.1.0/download";
-        sha256 = "1i2n0161jm00zvzh4bncgv9zrwa6ydbxdn5j4bx0wwn7rvi9zycp";
-        name = "byteorder-1.1.0.tar.gz";
+        url = "https://crates.io/api/v1/crates/ascii/1.1.0/download";
+        sha256 = "15bsx5045lf8bj3awnhvnrfj80cf33ci2xx2lmyh0cljgsqvnhis";
+        name = "ascii-1.1.0.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    async_trait_0_1_92_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "async-trait";
+      version = "0.1.92";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/async-trait/0.1.92/download";
+        sha256 = "0gvjlfh5w5m9wdy5vg7wrxvb9sa8v0dacq2crfcd24r0anvya8pd";
+        name = "async-trait-0.1.92.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    autocfg_1_5_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "autocfg";
+      version = "1.5.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/autocfg/1.5.1/download";
+        sha256 = "1dfqr9dji0vsvg7lwya2768071jr8hbid2jxa0wvwzmx2i86m4bx";
+        name = "autocfg-1.5.1.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    aws_lc_rs_1_18_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "aws-lc-rs";
+      version = "1.18.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/aws-lc-rs/1.18.1/download";
+        sha256 = "0ynf7pyfdpqbjps7f5r1mw0icwss0apcb0m3h9hzj4z48269y2pm";
+        name = "aws-lc-rs-1.18.1.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    aws_lc_sys_0_45_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "aws-lc-sys";
+      version = "0.45.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/aws-lc-sys/0.45.0/download";
+        sha256 = "136v7bg0bv9mpzxd1xrh964l6d8zx6a3z7kb028indyrp4fys2gg";
+        name = "aws-lc-sys-0.45.0.tar.gz";
+      };
+      build = "builder/main.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    base64_0_21_7_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "base64";
+      version = "0.21.7";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/base64/0.21.7/download";
+        sha256 = "08aj9d8wkw1j1var57nkx5sdwbzdiqzwcrvh4srkilmjkc1af14l";
+        name = "base64-0.21.7.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    base64_0_22_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "base64";
+      version = "0.22.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/base64/0.22.1/download";
+        sha256 = "15v76sflvvga0q54bhxnq51rh49vgz3l3difxgqlnwrilbzkgf25";
+        name = "base64-0.22.1.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    base64_0_23_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "base64";
+      version = "0.23.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/base64/0.23.1/download";
+        sha256 = "1az5vihkcb8qrmnxnhj3v4skrxrhdvvnqvj503cs0939bxgrm75g";
+        name = "base64-0.23.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    bincode_1_3_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "bincode";
+      version = "1.3.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/bincode/1.3.3/download";
+        sha256 = "147dnk66pwiag7b4avrka7mlas7hhs13pq9hvj64snksxi00smd4";
+        name = "bincode-1.3.3.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    bitflags_1_3_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "bitflags";
+      version = "1.3.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/bitflags/1.3.2/download";
+        sha256 = "010dns3ppspxl74xv1hlp2lj2p8sq6aj8iljb752pvsrsf6mlrma";
+        name = "bitflags-1.3.2.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    bitflags_2_13_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "bitflags";
+      version = "2.13.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/bitflags/2.13.2/download";
+        sha256 = "03qg7c9sbbwfrn6kixmh4xlk1xfjmqplamyn63l9av0r4als2ra3";
+        name = "bitflags-2.13.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    blake2_0_11_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "blake2";
+      version = "0.11.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/blake2/0.11.0/download";
+        sha256 = "0j7jk8fshrkl6f8lwra0vs7wfld0iqcpz9i3m5q5grr87d01bd30";
+        name = "blake2-0.11.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    block_buffer_0_10_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "block-buffer";
+      version = "0.10.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/block-buffer/0.10.4/download";
+        sha256 = "0gjay1r2iwilzpj3b7dwlb9dj5xs6l8xf521rkhbsl47j98gc2kg";
+        name = "block-buffer-0.10.4.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    block_buffer_0_12_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "block-buffer";
+      version = "0.12.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/block-buffer/0.12.1/download";
+        sha256 = "02w9cjlxnh3vf1sp6xra3lbqz2xh9cr3xdbjsyxi1fg9myyzbskv";
+        name = "block-buffer-0.12.1.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    bumpalo_3_20_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "bumpalo";
+      version = "3.20.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/bumpalo/3.20.3/download";
+        sha256 = "1d9w0fppnz95525lyx7ar5qrfaqq41n7s0dxgpw1m84kvv1j3fi5";
+        name = "bumpalo-3.20.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    byteorder_1_5_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "byteorder";
+      version = "1.5.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/byteorder/1.5.0/download";
+        sha256 = "12bxp8z6ajy6rv3vigy3zn2axdryy8p3rryzgw58qhs45in0hngq";
+        name = "byteorder-1.5.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    bytes_1_12_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "bytes";
+      version = "1.12.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/bytes/1.12.1/download";
+        sha256 = "165b4cp1mmvl399ssshbxbhr6ychqbwwxj4lzxxhl8mbncp4jjh6";
+        name = "bytes-1.12.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cap_fs_ext_3_4_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cap-fs-ext";
+      version = "3.4.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cap-fs-ext/3.4.6/download";
+        sha256 = "15wzjaymnhdb2bvb1j0fcvm8s774gwm1yppaaj8hn4f11n89nvm5";
+        name = "cap-fs-ext-3.4.6.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cap_net_ext_3_4_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cap-net-ext";
+      version = "3.4.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cap-net-ext/3.4.6/download";
+        sha256 = "0frrclbqiwgaqyrlf1r0ya228hwhzavcy65vhwfvxcd0dmp3xqxx";
+        name = "cap-net-ext-3.4.6.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cap_primitives_3_4_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cap-primitives";
+      version = "3.4.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cap-primitives/3.4.6/download";
+        sha256 = "0ccc43i5vab6i8slrgxkfn63qa7chv3ypnns55pw3wzkg64887cv";
+        name = "cap-primitives-3.4.6.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cap_rand_3_4_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cap-rand";
+      version = "3.4.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cap-rand/3.4.6/download";
+        sha256 = "0xf7jwagk5l42b54c7ci83yakdxx9hisfa5xqqhjkp2scj11gy5b";
+        name = "cap-rand-3.4.6.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cap_std_3_4_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cap-std";
+      version = "3.4.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cap-std/3.4.6/download";
+        sha256 = "1c9ms9wzpvl818als8axfdywfny33i5apzqk3haza05s3wq62cr3";
+        name = "cap-std-3.4.6.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cap_time_ext_3_4_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cap-time-ext";
+      version = "3.4.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cap-time-ext/3.4.6/download";
+        sha256 = "1x5ymgqq1q6p8ia1ygwk4fkq4s57m73n5syxgn1dm3r53nllmakl";
+        name = "cap-time-ext-3.4.6.tar.gz";
       };
-      libName = "byteorder";
+      edition = "2021";
       inherit dependencies features release verbose;
     };
-    capnp_0_8_11_ = { dependencies?[], features?[] }: build-rust-package {
+    capnp_0_8_17_ = { dependencies?[], features?[] }: build-rust-package {
       crateName = "capnp";
-      version = "0.8.11";
+      version = "0.8.17";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/capnp/0.8.11/download";
-        sha256 = "128nyg50z1rjn7psa1vq3888q8w5xkzkgdcjlc492pjzhsr40bdj";
-        name = "capnp-0.8.11.tar.gz";
+        url = "https://crates.io/api/v1/crates/capnp/0.8.17/download";
+        sha256 = "0s7drrzv3k9ldazkkrs5b0ckdbfll9j2pq6pm15v1ky9x4hyzgi2";
+        name = "capnp-0.8.17.tar.gz";
       };
-      libPath = "src/lib.rs";
-      libName = "capnp";
       inherit dependencies features release verbose;
     };
-    capnpc_0_8_7_ = { dependencies?[], features?[] }: build-rust-package {
+    capnpc_0_8_9_ = { dependencies?[], features?[] }: build-rust-package {
       crateName = "capnpc";
-      version = "0.8.7";
+      version = "0.8.9";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/capnpc/0.8.7/download";
-        sha256 = "0b1kzq316li86hiwx950b8ri61y3mqhfpzc2ycs984hk472f4wiy";
-        name = "capnpc-0.8.7.tar.gz";
+        url = "https://crates.io/api/v1/crates/capnpc/0.8.9/download";
+        sha256 = "0rmxhvdhl5mhk3ksyyr14w9sn676j7hihvjgszpdskhmw83s8w5a";
+        name = "capnpc-0.8.9.tar.gz";
       };
-      libPath = "src/lib.rs";
-      libName = "capnpc";
       crateBin = [ {  name = "capnpc-rust";  path = "src/main.rs"; } ];
       inherit dependencies features release verbose;
     };
-    kernel32_sys_0_2_2_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "kernel32-sys";
-      version = "0.2.2";
+    cc_1_8_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cc";
+      version = "1.8.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cc/1.8.0/download";
+        sha256 = "0s8mbpr0vwal3in20qyrmijsg2m0kdga9mpkxlrm7m65l1wm7lq1";
+        name = "cc-1.8.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cfg_expr_0_15_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cfg-expr";
+      version = "0.15.8";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cfg-expr/0.15.8/download";
+        sha256 = "0m1ighsyv1ncdrvyydbbkywdb5si84018x4jn326iyvyz0in4c0r";
+        name = "cfg-expr-0.15.8.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cfg_if_1_0_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cfg-if";
+      version = "1.0.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cfg-if/1.0.5/download";
+        sha256 = "07nd2xllw1rrglmgjsp2c2q1y9y9b27hd3wmwac9k0g33mrqw7pd";
+        name = "cfg-if-1.0.5.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    chacha20_0_10_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "chacha20";
+      version = "0.10.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/chacha20/0.10.2/download";
+        sha256 = "0d6754vz9immvj1a3yfixgwrp7hcm2a8qc10nk584c94zdby4n8l";
+        name = "chacha20-0.10.2.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    chrono_0_4_45_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "chrono";
+      version = "0.4.45";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/chrono/0.4.45/download";
+        sha256 = "0wnq9ysnsj1rhsvzhvllksz8shb1wwbp1wnisklmqfp1hs1b6pfd";
+        name = "chrono-0.4.45.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    chunked_transfer_1_5_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "chunked_transfer";
+      version = "1.5.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/chunked_transfer/1.5.0/download";
+        sha256 = "01rdjwb71n0gq58y5k13ashki0xdag7bsknhdjb2p9m1hp1lk60g";
+        name = "chunked_transfer-1.5.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    cmake_0_1_58_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cmake";
+      version = "0.1.58";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cmake/0.1.58/download";
+        sha256 = "1g9473vx7ql2ff3kzikph395iacwyvzac9w9n3wf2mcifrllnsdl";
+        name = "cmake-0.1.58.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cmov_0_5_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cmov";
+      version = "0.5.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cmov/0.5.4/download";
+        sha256 = "01vlmwfqg48cc19y0fn8l0spfxh4zqwvcps3prsrp2086n75flqy";
+        name = "cmov-0.5.4.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    const_oid_0_10_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "const-oid";
+      version = "0.10.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/const-oid/0.10.2/download";
+        sha256 = "1jk4fnx4bhmf173bxk80y2xds9l1w0skjjqq5p4bqxq73dvzsjkk";
+        name = "const-oid-0.10.2.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    core_detect_1_0_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "core_detect";
+      version = "1.0.0";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/kernel32-sys/0.2.2/download";
-        sha256 = "1lrw1hbinyvr6cp28g60z97w32w8vsk6pahk64pmrv2fmby8srfj";
-        name = "kernel32-sys-0.2.2.tar.gz";
+        url = "https://crates.io/api/v1/crates/core_detect/1.0.0/download";
+        sha256 = "0kl77xc47ijlggap5m9q799a5vm94s6lv26s68n508pja8nrjspc";
+        name = "core_detect-1.0.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    cpp_demangle_0_4_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cpp_demangle";
+      version = "0.4.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cpp_demangle/0.4.5/download";
+        sha256 = "1b3bykli44vy38c3zx3nj53va8aqr53f5qb0km6c35cvclq03qb1";
+        name = "cpp_demangle-0.4.5.tar.gz";
       };
-      libName = "kernel32";
       build = "build.rs";
-      buildDependencies = [ winapi_build_0_1_1_ ];      inherit dependencies features release verbose;
+      edition = "2018";
+      inherit dependencies features release verbose;
     };
-    lazy_static_0_2_8_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "lazy_static";
-      version = "0.2.8";
+    cpufeatures_0_2_17_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cpufeatures";
+      version = "0.2.17";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/lazy_static/0.2.8/download";
-        sha256 = "1xbpxx7cd5kl60g87g43q80jxyrsildhxfjc42jb1x4jncknpwbl";
-        name = "lazy_static-0.2.8.tar.gz";
+        url = "https://crates.io/api/v1/crates/cpufeatures/0.2.17/download";
+        sha256 = "0fxqjwg3qy1ncw3diql5hfn61kkpvgl71hr6is495a1lln9kwjnq";
+        name = "cpufeatures-0.2.17.tar.gz";
       };
+      edition = "2018";
       inherit dependencies features release verbose;
     };
-    libc_0_2_30_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "libc";
-      version = "0.2.30";
+    cpufeatures_0_3_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cpufeatures";
+      version = "0.3.1";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/libc/0.2.30/download";
-        sha256 = "1c4gi6r5gbpbw3dmryc98x059awl4003cfz5kd6lqm03gp62wlkw";
-        name = "libc-0.2.30.tar.gz";
+        url = "https://crates.io/api/v1/crates/cpufeatures/0.3.1/download";
+        sha256 = "190y9xp1a651pb5wgmr1wp2icfw2ilabln7f6wkrwbb5a4hngc0h";
+        name = "cpufeatures-0.3.1.tar.gz";
       };
+      edition = "2024";
       inherit dependencies features release verbose;
     };
-    libloading_0_4_1_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "libloading";
-      version = "0.4.1";
+    cranelift_bforest_0_107_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cranelift-bforest";
+      version = "0.107.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cranelift-bforest/0.107.2/download";
+        sha256 = "021ggx1hs3c9rxswcxrzlnd3wwfkr90q1jwvllp9qvfd3wqiaz9i";
+        name = "cranelift-bforest-0.107.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cranelift_codegen_0_107_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cranelift-codegen";
+      version = "0.107.2";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/libloading/0.4.1/download";
-        sha256 = "0q0y0vg78vm2fs0wry6dwp3ka5nvahzss0099zw5fmdjkj7yw6y9";
-        name = "libloading-0.4.1.tar.gz";
+        url = "https://crates.io/api/v1/crates/cranelift-codegen/0.107.2/download";
+        sha256 = "0fi43brflfxz7wvah695dsjil7503mqrz9bbgz8zmma4qrry4lkg";
+        name = "cranelift-codegen-0.107.2.tar.gz";
       };
       build = "build.rs";
+      edition = "2021";
       inherit dependencies features release verbose;
     };
-    memchr_1_0_1_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "memchr";
-      version = "1.0.1";
+    cranelift_codegen_meta_0_107_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cranelift-codegen-meta";
+      version = "0.107.2";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/memchr/1.0.1/download";
-        sha256 = "071m5y0zm9p1k7pzqm20f44ixvmycf71xsrpayqaypxrjwchnkxm";
-        name = "memchr-1.0.1.tar.gz";
+        url = "https://crates.io/api/v1/crates/cranelift-codegen-meta/0.107.2/download";
+        sha256 = "1acywb8vxpj6zqzgpy3mzhjzblg22b663ir717na762jr3m3l452";
+        name = "cranelift-codegen-meta-0.107.2.tar.gz";
       };
-      libName = "memchr";
+      edition = "2021";
       inherit dependencies features release verbose;
     };
-    nom_3_2_0_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "nom";
-      version = "3.2.0";
+    cranelift_codegen_shared_0_107_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cranelift-codegen-shared";
+      version = "0.107.2";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/nom/3.2.0/download";
-        sha256 = "16ccwwqi09ai1yf71gpqhih915m7ixyrwbjf6lmhfdnxp0igg6sw";
-        name = "nom-3.2.0.tar.gz";
+        url = "https://crates.io/api/v1/crates/cranelift-codegen-shared/0.107.2/download";
+        sha256 = "17sb2aswrxgql0f6c59mrvrdhnfcw7gi6p8qvsd5xi440p3rnlvr";
+        name = "cranelift-codegen-shared-0.107.2.tar.gz";
       };
+      edition = "2021";
       inherit dependencies features release verbose;
     };
-    num_cpus_1_6_2_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "num_cpus";
-      version = "1.6.2";
+    cranelift_control_0_107_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cranelift-control";
+      version = "0.107.2";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/num_cpus/1.6.2/download";
-        sha256 = "0wxfzxsk05xbkph5qcvdqyi334zn0pnpahzi7n7iagxbb68145rm";
-        name = "num_cpus-1.6.2.tar.gz";
+        url = "https://crates.io/api/v1/crates/cranelift-control/0.107.2/download";
+        sha256 = "1jdb6lzzrmsmd8zxd83xpzy81hxyr1z7fr7x3sv9bx597f0zl57g";
+        name = "cranelift-control-0.107.2.tar.gz";
       };
+      edition = "2021";
       inherit dependencies features release verbose;
     };
-    rustfbp_0_3_34_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "rustfbp";
-      version = "0.3.34";
+    cranelift_entity_0_107_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cranelift-entity";
+      version = "0.107.2";
       fractalType = "crate";
-      src = ../rustfbp;
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cranelift-entity/0.107.2/download";
+        sha256 = "0sgglk0yffvf4v95mc33qsmp294dbv321wy78f5rarw6ckad5mfy";
+        name = "cranelift-entity-0.107.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cranelift_frontend_0_107_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cranelift-frontend";
+      version = "0.107.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cranelift-frontend/0.107.2/download";
+        sha256 = "0djs6f0g40aahc1r5pfgjamijb8ddj384q5167r4h0hngr9sjr59";
+        name = "cranelift-frontend-0.107.2.tar.gz";
+      };
+      edition = "2021";
       inherit dependencies features release verbose;
     };
-    threadpool_1_7_0_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "threadpool";
-      version = "1.7.0";
+    cranelift_isle_0_107_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cranelift-isle";
+      version = "0.107.2";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/threadpool/1.7.0/download";
-        sha256 = "0pcfzhq9m1jggy7h6x7nhybyjq4xrjmbxqhaffrnc7mcm042w5h7";
-        name = "threadpool-1.7.0.tar.gz";
+        url = "https://crates.io/api/v1/crates/cranelift-isle/0.107.2/download";
+        sha256 = "1ihpk957lk3f4xxjpdppfwg86w5x0dvyajbp58zgwkg3v6pa5b3w";
+        name = "cranelift-isle-0.107.2.tar.gz";
       };
+      build = "build.rs";
+      edition = "2021";
       inherit dependencies features release verbose;
     };
-    winapi_0_2_8_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "winapi";
-      version = "0.2.8";
+    cranelift_native_0_107_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cranelift-native";
+      version = "0.107.2";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/winapi/0.2.8/download";
-        sha256 = "0a45b58ywf12vb7gvj6h3j264nydynmzyqz8d8rqxsj6icqv82as";
-        name = "winapi-0.2.8.tar.gz";
+        url = "https://crates.io/api/v1/crates/cranelift-native/0.107.2/download";
+        sha256 = "05w6mlqwjwzbzkjqwxb19i2ry804660viknls2ma9rba8qjy49cn";
+        name = "cranelift-native-0.107.2.tar.gz";
       };
+      edition = "2021";
       inherit dependencies features release verbose;
     };
-    winapi_build_0_1_1_ = { dependencies?[], features?[] }: build-rust-package {
-      crateName = "winapi-build";
-      version = "0.1.1";
+    cranelift_wasm_0_107_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cranelift-wasm";
+      version = "0.107.2";
       fractalType = "crate";
       src = fetchzip {
-        url = "https://crates.io/api/v1/crates/winapi-build/0.1.1/download";
-        sha256 = "1lxlpi87rkhxcwp2ykf1ldw3p108hwm24nywf3jfrvmff4rjhqga";
-        name = "winapi-build-0.1.1.tar.gz";
+        url = "https://crates.io/api/v1/crates/cranelift-wasm/0.107.2/download";
+        sha256 = "11jx12iwy7jz3gldqkgcj9hsifk2s1i0px38a5vxdwaiiw8wvb7p";
+        name = "cranelift-wasm-0.107.2.tar.gz";
       };
-      libName = "build";
+      edition = "2021";
       inherit dependencies features release verbose;
     };
-
-in
-rec {
-  all_crates_1_1_1 = all_crates_1_1_1_ {
-    dependencies = [ capnp_0_8_11 capnpc_0_8_7 nom_3_2_0 rustfbp_0_3_34 ];
-  };
-  byteorder_1_1_0 = byteorder_1_1_0_ {
-    features = [ "std" ];
-  };
-  capnp_0_8_11 = capnp_0_8_11_ {
-    dependencies = [ byteorder_1_1_0 ];
-  };
-  capnpc_0_8_7 = capnpc_0_8_7_ {
-    dependencies = [ capnp_0_8_11 ];
-  };
-  kernel32_sys_0_2_2 = kernel32_sys_0_2_2_ {
-    dependencies = [ winapi_0_2_8 winapi_build_0_1_1 ];
-  };
-  lazy_static_0_2_8 = lazy_static_0_2_8_ {};
-  libc_0_2_30 = libc_0_2_30_ {
-    features = [ "use_std" ];
-  };
-  libloading_0_4_1 = libloading_0_4_1_ {
-    dependencies = [ kernel32_sys_0_2_2 lazy_static_0_2_8 winapi_0_2_8 ];
-  };
-  memchr_1_0_1 = memchr_1_0_1_ {
-    dependencies = [ libc_0_2_30 ];
-    features = [ "use_std" ];
-  };
-  nom_3_2_0 = nom_3_2_0_ {
-    dependencies = [ memchr_1_0_1 ];
-    features = [ "std" "stream" ];
-  };
-  num_cpus_1_6_2 = num_cpus_1_6_2_ {
-    dependencies = [ libc_0_2_30 ];
-  };
-  rustfbp_0_3_34 = rustfbp_0_3_34_ {
-    dependencies = [ capnp_0_8_11 libloading_0_4_1 threadpool_1_7_0 ];
+    crc32fast_1_5_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "crc32fast";
+      version = "1.5.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/crc32fast/1.5.2/download";
+        sha256 = "0y8rr3n6lnprk5mqa9cnr58l0mkaxm9qbfxksyv0wshr0hjvi14q";
+        name = "crc32fast-1.5.2.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    cron_0_17_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "cron";
+      version = "0.17.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/cron/0.17.0/download";
+        sha256 = "0mpciagzcb973vjhkm0bhdisjwgymzrbxzgnfq55smlakplxds6q";
+        name = "cron-0.17.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    crossbeam_channel_0_5_17_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "crossbeam-channel";
+      version = "0.5.17";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/crossbeam-channel/0.5.17/download";
+        sha256 = "1xhhv1pzy7kxbja2gfsvs2ic9lvl3rwzq2a64h3418wz54hh3i02";
+        name = "crossbeam-channel-0.5.17.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    crossbeam_deque_0_8_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "crossbeam-deque";
+      version = "0.8.8";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/crossbeam-deque/0.8.8/download";
+        sha256 = "0ycvw9r94bnm3wl1529jx3zxzmy8054mnwklal4pr5g6rkv2rvy3";
+        name = "crossbeam-deque-0.8.8.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    crossbeam_epoch_0_9_21_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "crossbeam-epoch";
+      version = "0.9.21";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/crossbeam-epoch/0.9.21/download";
+        sha256 = "1x24gmngrin50fiv2brjpm42pi4y1yfh8nnk3ba3yvg8afb2py55";
+        name = "crossbeam-epoch-0.9.21.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    crossbeam_utils_0_8_23_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "crossbeam-utils";
+      version = "0.8.23";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/crossbeam-utils/0.8.23/download";
+        sha256 = "0vcch1x0i2nbk24r0yblz2gfcpghnd9y2gcjn0hdbjmqsxkcikj7";
+        name = "crossbeam-utils-0.8.23.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    crypto_common_0_1_7_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "crypto-common";
+      version = "0.1.7";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/crypto-common/0.1.7/download";
+        sha256 = "0axncqx3mh33nlym0ni3q1gg7wirlayslr08ddsmhazv6dlf2iyh";
+        name = "crypto-common-0.1.7.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    crypto_common_0_2_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "crypto-common";
+      version = "0.2.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/crypto-common/0.2.2/download";
+        sha256 = "1aswrfy9qmyajsxfkshc02w4c6inbs2dardskz2pv710gc06grfj";
+        name = "crypto-common-0.2.2.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    csv_1_4_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "csv";
+      version = "1.4.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/csv/1.4.0/download";
+        sha256 = "00fqh4q3zwzr9gbpcinslpg2anzciagqfk9h864dpkddrji4r96p";
+        name = "csv-1.4.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    csv_core_0_1_13_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "csv-core";
+      version = "0.1.13";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/csv-core/0.1.13/download";
+        sha256 = "1jy4zs8xcl5rjmxx5fzgfr2nk18cysqqah0bq24kzdn0rzik67y5";
+        name = "csv-core-0.1.13.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    ctutils_0_4_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ctutils";
+      version = "0.4.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/ctutils/0.4.3/download";
+        sha256 = "010yf3fjrkhx1bpv29mbp6hj6yk73d29l2ss4bgn0i3wv9vb502l";
+        name = "ctutils-0.4.3.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    data_encoding_2_11_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "data-encoding";
+      version = "2.11.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/data-encoding/2.11.1/download";
+        sha256 = "0x0m21xfzqpxdabchgid5c36if6bkqza86kyizhdkcp14bfm5j81";
+        name = "data-encoding-2.11.1.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    debugid_0_8_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "debugid";
+      version = "0.8.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/debugid/0.8.0/download";
+        sha256 = "15f0ywmvhgwszmx49ijdzy882vz7qaliiipl3k0pi3nnl2drk51z";
+        name = "debugid-0.8.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    digest_0_10_7_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "digest";
+      version = "0.10.7";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/digest/0.10.7/download";
+        sha256 = "09iw47zzzfd88ipv4cqgcflg5rc2f0bsrsnnjl1a69qcnafb463b";
+        name = "digest-0.10.7.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    digest_0_11_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "digest";
+      version = "0.11.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/digest/0.11.3/download";
+        sha256 = "02gknc9iramdvwk6azpk24mhzm18aaid003gsdvhkwibn6cgfzay";
+        name = "digest-0.11.3.tar.gz";
+      };
+      edition = "2024";
+      crateRenames = { "crypto-common-0.2.2" = "common"; };
+      inherit dependencies features release verbose;
+    };
+    dircpy_0_3_21_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "dircpy";
+      version = "0.3.21";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/dircpy/0.3.21/download";
+        sha256 = "1w0d0qlfvmk0bbhf814b6vzk0djpfgbnrrb7d6apsks70m589pm8";
+        name = "dircpy-0.3.21.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    directories_next_2_0_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "directories-next";
+      version = "2.0.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/directories-next/2.0.0/download";
+        sha256 = "1sfppapww1yn0m3ay4kfhc4k66060hyshr6zq94q67nmiqng3x7g";
+        name = "directories-next-2.0.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    dirs_4_0_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "dirs";
+      version = "4.0.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/dirs/4.0.0/download";
+        sha256 = "0ixnzbc64c4sfs4mzq2jzs4ljy5jvj94a9cv3v8v7hq4vrxgm7ij";
+        name = "dirs-4.0.0.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    dirs_sys_0_3_7_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "dirs-sys";
+      version = "0.3.7";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/dirs-sys/0.3.7/download";
+        sha256 = "1250nd6za60wl7lh7birviwfhp6qgjxqxfdvvsnlzn1q83gznvl9";
+        name = "dirs-sys-0.3.7.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    dirs_sys_next_0_1_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "dirs-sys-next";
+      version = "0.1.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/dirs-sys-next/0.1.2/download";
+        sha256 = "1ilbxcl9h69p2mfj0fbr9ckyx20k8r9v3wcg539wacidb3m1bbaf";
+        name = "dirs-sys-next-0.1.2.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    displaydoc_0_2_7_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "displaydoc";
+      version = "0.2.7";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/displaydoc/0.2.7/download";
+        sha256 = "156iy0gnnki2bnv8g5pm43dkndzyhinnmw1qsl4zxarm57affpwj";
+        name = "displaydoc-0.2.7.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    dunce_1_0_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "dunce";
+      version = "1.0.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/dunce/1.0.5/download";
+        sha256 = "0m97vim7jzz51qzz5avak2067a8245k5v5iailm13rykxmph6ggz";
+        name = "dunce-1.0.5.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    either_1_19_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "either";
+      version = "1.19.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/either/1.19.0/download";
+        sha256 = "0dz7wmwjk4p91bqqm3nc5n622zk274bb9yibaxx63rfmcvw3zajz";
+        name = "either-1.19.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    encoding_rs_0_8_42_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "encoding_rs";
+      version = "0.8.42";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/encoding_rs/0.8.42/download";
+        sha256 = "0jd290zalks15pzri76nv682pyhyqa6ngzniqp1rp8n2a069jdls";
+        name = "encoding_rs-0.8.42.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    equivalent_1_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "equivalent";
+      version = "1.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/equivalent/1.0.2/download";
+        sha256 = "0mfmzd7sqc7k16mjva3zmi1xahvnsc0zhrgmyswyj5rbsr89ydxr";
+        name = "equivalent-1.0.2.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    errno_0_3_14_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "errno";
+      version = "0.3.14";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/errno/0.3.14/download";
+        sha256 = "1qrgj9awlmd8c574vkwv548248rzhnaq1adswcwz44c65k8cf9bi";
+        name = "errno-0.3.14.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    fallible_iterator_0_2_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "fallible-iterator";
+      version = "0.2.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/fallible-iterator/0.2.0/download";
+        sha256 = "1i7y2k0jdsxglv4pkqh2mx0rzx122njacjhvhh8dzishb679l30d";
+        name = "fallible-iterator-0.2.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    fallible_iterator_0_3_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "fallible-iterator";
+      version = "0.3.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/fallible-iterator/0.3.0/download";
+        sha256 = "1wsalpr71rwbxfgp5qcv8laxrssfivlgkqrjnay3p6jp7cshjrpv";
+        name = "fallible-iterator-0.3.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    fallible_streaming_iterator_0_1_9_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "fallible-streaming-iterator";
+      version = "0.1.9";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/fallible-streaming-iterator/0.1.9/download";
+        sha256 = "0yhqqhqf22vy8x22drkn045d65d6n6m2nxchdph8ixvp1fshx8s4";
+        name = "fallible-streaming-iterator-0.1.9.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    find_msvc_tools_0_1_14_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "find-msvc-tools";
+      version = "0.1.14";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/find-msvc-tools/0.1.14/download";
+        sha256 = "084kdqm1j2kisxjbc02dpnyzyygmcgcwbdbmlnpxdfznfcb44lvf";
+        name = "find-msvc-tools-0.1.14.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    fixedbitset_0_5_7_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "fixedbitset";
+      version = "0.5.7";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/fixedbitset/0.5.7/download";
+        sha256 = "16gm9gcwna0ivcd2lsyxybqmiz5v5lkf3j92fa9w77q4v1lz3hbv";
+        name = "fixedbitset-0.5.7.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    flate2_1_1_10_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "flate2";
+      version = "1.1.10";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/flate2/1.1.10/download";
+        sha256 = "1jp69lyx8ygk8rpp216cayv79f0m93jq0whpylmanyly9rsxikxg";
+        name = "flate2-1.1.10.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    flume_0_11_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "flume";
+      version = "0.11.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/flume/0.11.1/download";
+        sha256 = "12zkf8d9wi1g95wskb1qi7b3z3hrvf8wly079bh76k9fqgbsg6pi";
+        name = "flume-0.11.1.tar.gz";
+      };
+      edition = "2018";
+      crateRenames = { "spin-0.9.9" = "spin1"; };
+      inherit dependencies features release verbose;
+    };
+    foldhash_0_2_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "foldhash";
+      version = "0.2.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/foldhash/0.2.0/download";
+        sha256 = "108sybl2yh6l5yjsl5mmvfb5bfxg6zf9awp79cjcjvxa59cxb2b2";
+        name = "foldhash-0.2.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    form_urlencoded_1_2_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "form_urlencoded";
+      version = "1.2.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/form_urlencoded/1.2.2/download";
+        sha256 = "13miqxsvcs7igdy7c13slqipnalf0birz32a15md3k7gfqazivjv";
+        name = "form_urlencoded-1.2.2.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    fs_set_times_0_20_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "fs-set-times";
+      version = "0.20.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/fs-set-times/0.20.3/download";
+        sha256 = "0spb30hq7fk92iqgp2qfh2pircfbs9y0piwg7jrcmimz8b1g5xz8";
+        name = "fs-set-times-0.20.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    fs_extra_1_3_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "fs_extra";
+      version = "1.3.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/fs_extra/1.3.0/download";
+        sha256 = "0m9k1j3a059gxj11shq5g4z3fwj08w22wywaj3zf51wyxl5w5gjd";
+        name = "fs_extra-1.3.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    futures_0_3_34_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "futures";
+      version = "0.3.34";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/futures/0.3.34/download";
+        sha256 = "0mas3kd1jp1f49ic9yinavj5k2p97ixiax82g489h9m8vpwmn7pj";
+        name = "futures-0.3.34.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    futures_channel_0_3_34_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "futures-channel";
+      version = "0.3.34";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/futures-channel/0.3.34/download";
+        sha256 = "1kacywaknj7chll0qan84plhab4fvanwwrfx52nm38951fjnqxkj";
+        name = "futures-channel-0.3.34.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    futures_core_0_3_34_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "futures-core";
+      version = "0.3.34";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/futures-core/0.3.34/download";
+        sha256 = "1w9xnmfxylfdhdlrfiwaglak4cy7inna2n26hz59v2gi08z0y8cf";
+        name = "futures-core-0.3.34.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    futures_io_0_3_34_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "futures-io";
+      version = "0.3.34";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/futures-io/0.3.34/download";
+        sha256 = "0q6x0pgvw44jrv8p127xq4m5m3r19j9mx4z2spv8m6sh8rqh63qy";
+        name = "futures-io-0.3.34.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    futures_sink_0_3_34_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "futures-sink";
+      version = "0.3.34";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/futures-sink/0.3.34/download";
+        sha256 = "1c8ik5x30lnfcf46jsjvk23z2whbr3wgk5sfzcb1lvz8gw01iw59";
+        name = "futures-sink-0.3.34.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    futures_task_0_3_34_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "futures-task";
+      version = "0.3.34";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/futures-task/0.3.34/download";
+        sha256 = "0nnvyx48n0zi0vwqahxf1h995qmjxdjsh699hn3507nzi18r8n38";
+        name = "futures-task-0.3.34.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    futures_util_0_3_34_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "futures-util";
+      version = "0.3.34";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/futures-util/0.3.34/download";
+        sha256 = "1bkp1ih7qspkqixygqxxkgg67rblyv6ylhkm7a4mvylbw06h7ypb";
+        name = "futures-util-0.3.34.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    fxhash_0_2_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "fxhash";
+      version = "0.2.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/fxhash/0.2.1/download";
+        sha256 = "0p4mfqswwxhkkskdpjld581ksg3cipfs85jbw7znljhkz40l9fjj";
+        name = "fxhash-0.2.1.tar.gz";
+      };
+      libPath = "lib.rs";
+      inherit dependencies features release verbose;
+    };
+    fxprof_processed_profile_0_6_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "fxprof-processed-profile";
+      version = "0.6.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/fxprof-processed-profile/0.6.0/download";
+        sha256 = "0vwi4ikm771m4z21iyin88g6vjrwcnp1zmlsj2asfjb8jhnz6n94";
+        name = "fxprof-processed-profile-0.6.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    generic_array_0_14_7_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "generic-array";
+      version = "0.14.7";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/generic-array/0.14.7/download";
+        sha256 = "07xiss5j1f24jx047czsc5p50l49y4wkpkbnby72mha1l71v91m3";
+        name = "generic-array-0.14.7.tar.gz";
+      };
+      build = "build.rs";
+      inherit dependencies features release verbose;
+    };
+    getrandom_0_2_17_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "getrandom";
+      version = "0.2.17";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/getrandom/0.2.17/download";
+        sha256 = "0kyd064lglll47pidn4knkcxn9rq5g6vqdzhfl6yksi9yainhyx7";
+        name = "getrandom-0.2.17.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    getrandom_0_4_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "getrandom";
+      version = "0.4.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/getrandom/0.4.3/download";
+        sha256 = "1l30caimhb4vy3dbvgnqd8bcik9spjknvxmf0vxshl6pch1vpfls";
+        name = "getrandom-0.4.3.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    gimli_0_28_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "gimli";
+      version = "0.28.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/gimli/0.28.1/download";
+        sha256 = "12kvs9zy7j8wfwcrrqp6xfjkpcclh8gfm9hwyyx0qb4q0yz33npl";
+        name = "gimli-0.28.1.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    glob_0_3_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "glob";
+      version = "0.3.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/glob/0.3.4/download";
+        sha256 = "1h837qvicjdy0f2kspvpcjprr3mg0bwqkcqavhf2bqwabjl0af5q";
+        name = "glob-0.3.4.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    hashbrown_0_13_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "hashbrown";
+      version = "0.13.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/hashbrown/0.13.2/download";
+        sha256 = "0bi1awnj6j3cszr3b5b0hbyvijmdamgpnyr2qqp4fp7pr9k5kb6n";
+        name = "hashbrown-0.13.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    hashbrown_0_14_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "hashbrown";
+      version = "0.14.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/hashbrown/0.14.5/download";
+        sha256 = "1c9hfa3b1pvncib623499813p65cgbck52y7cfih2i436dhjb9b8";
+        name = "hashbrown-0.14.5.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    hashbrown_0_17_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "hashbrown";
+      version = "0.17.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/hashbrown/0.17.1/download";
+        sha256 = "1g19a5xrh5yvpp7py0f5p4flf96iy3mjkbkckk71nki3xvdwwj1f";
+        name = "hashbrown-0.17.1.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    hashlink_0_12_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "hashlink";
+      version = "0.12.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/hashlink/0.12.2/download";
+        sha256 = "0x7nya8kh89lslix08syb2bw8l8grrm6ambf4vpk8zsgpbdjshvw";
+        name = "hashlink-0.12.2.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    heck_0_4_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "heck";
+      version = "0.4.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/heck/0.4.1/download";
+        sha256 = "10i3dr6kjsvhdy1y4v1rl9970vf5czyh9cpnmfj6fq3ji1fw0yw2";
+        name = "heck-0.4.1.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    heck_0_5_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "heck";
+      version = "0.5.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/heck/0.5.0/download";
+        sha256 = "1xb9a08i4pmyr491lbsdkyz5slhzc5lqwj1nxiivhsa9gp62flxx";
+        name = "heck-0.5.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    hmac_0_13_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "hmac";
+      version = "0.13.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/hmac/0.13.0/download";
+        sha256 = "0x36w7498l2glx58qf2rms8xwkqrc20xv1ns0wyn04949vjg2hi8";
+        name = "hmac-0.13.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    http_1_5_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "http";
+      version = "1.5.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/http/1.5.0/download";
+        sha256 = "0k19xw0qs23b0qn2d52jhvkdq7qziw66d30aljaa8jph8rhrlm5i";
+        name = "http-1.5.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    httparse_1_10_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "httparse";
+      version = "1.10.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/httparse/1.10.1/download";
+        sha256 = "0bkwh6pw0ha52p4lb8h69xmh9yvk3jy0ncvl2xfzdz4ff65izl9k";
+        name = "httparse-1.10.1.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    httpdate_1_0_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "httpdate";
+      version = "1.0.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/httpdate/1.0.3/download";
+        sha256 = "0qmcfgnx78zys7idci1s52rbq7bsirsz5cx5sj62yv21b1489xhp";
+        name = "httpdate-1.0.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    hybrid_array_0_4_15_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "hybrid-array";
+      version = "0.4.15";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/hybrid-array/0.4.15/download";
+        sha256 = "1y5nqwlpllhw4g05zkg3cxm2s8q8syqajqib1wrcglbg6mbqf8v3";
+        name = "hybrid-array-0.4.15.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    iana_time_zone_0_1_65_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "iana-time-zone";
+      version = "0.1.65";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/iana-time-zone/0.1.65/download";
+        sha256 = "1i3cgkqi2nj0rzfdnpraqirnxhwsnqs6n4rssllhaxw8wcm0p87p";
+        name = "iana-time-zone-0.1.65.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    icu_collections_2_3_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "icu_collections";
+      version = "2.3.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/icu_collections/2.3.0/download";
+        sha256 = "02y9qlz0illz4pq1j1irc5ripc3vj738hj58nc8lis6dvqj828wl";
+        name = "icu_collections-2.3.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    icu_locale_core_2_3_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "icu_locale_core";
+      version = "2.3.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/icu_locale_core/2.3.0/download";
+        sha256 = "0l8gms0hpiyl0k5azryrif51cs3ij4l6awqq1mm6rzjgmi5y0ys9";
+        name = "icu_locale_core-2.3.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    icu_normalizer_2_3_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "icu_normalizer";
+      version = "2.3.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/icu_normalizer/2.3.0/download";
+        sha256 = "0f9ikgxvscffwwqm7rfhx7ci7nzb9lwnpwswfzcacvh4x1wn44bs";
+        name = "icu_normalizer-2.3.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    icu_normalizer_data_2_3_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "icu_normalizer_data";
+      version = "2.3.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/icu_normalizer_data/2.3.0/download";
+        sha256 = "0hvjasj8z4nmb0yhxh1xapk455k0lv3ybfa17jzpikwgw1gs4p5g";
+        name = "icu_normalizer_data-2.3.0.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    icu_properties_2_3_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "icu_properties";
+      version = "2.3.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/icu_properties/2.3.0/download";
+        sha256 = "0jxkazmm0mfyn32cvwiipv3p18x105vl84i3k05c0y9pm4gga3bq";
+        name = "icu_properties-2.3.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    icu_properties_data_2_3_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "icu_properties_data";
+      version = "2.3.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/icu_properties_data/2.3.0/download";
+        sha256 = "1n6wsa3syxn7kzpwy23qxkbrz17s73r8cijp8k1v71mc5wyhp805";
+        name = "icu_properties_data-2.3.0.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    icu_provider_2_3_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "icu_provider";
+      version = "2.3.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/icu_provider/2.3.1/download";
+        sha256 = "0ak80j9wim3219hcwx0ardas75p6psrcx9xqdi1czybwg0qx3s82";
+        name = "icu_provider-2.3.1.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    id_arena_2_3_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "id-arena";
+      version = "2.3.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/id-arena/2.3.0/download";
+        sha256 = "0ar8swza36vghkp1jif8v8y1yrldbm7p7283zlj7ysxh0i2kbxlg";
+        name = "id-arena-2.3.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    idna_1_1_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "idna";
+      version = "1.1.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/idna/1.1.0/download";
+        sha256 = "0gv8382rs6vzyhlsvlxv9aj06awhppvrhvwl0havv2ccd81bc5ck";
+        name = "idna-1.1.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    idna_adapter_1_2_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "idna_adapter";
+      version = "1.2.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/idna_adapter/1.2.2/download";
+        sha256 = "15riihp6rr1nv1wgxx49ym4id0sx97wl5rglg5z1k403s3rx2lpl";
+        name = "idna_adapter-1.2.2.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    indexmap_2_14_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "indexmap";
+      version = "2.14.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/indexmap/2.14.2/download";
+        sha256 = "0k47bb87rvxaaczlbk1ajr9nn9y7296s75n3nr2j6xs5zk0x0zbh";
+        name = "indexmap-2.14.2.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    inotify_0_11_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "inotify";
+      version = "0.11.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/inotify/0.11.5/download";
+        sha256 = "1z5laf36s5xsz0291fxkya3ihsfly7hmkxhanc87shmw9bv124rm";
+        name = "inotify-0.11.5.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    inotify_sys_0_1_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "inotify-sys";
+      version = "0.1.8";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/inotify-sys/0.1.8/download";
+        sha256 = "15svbc9yjh1w9m3ysw5ax1xas53ck7lh7r749m40631i38y4fflq";
+        name = "inotify-sys-0.1.8.tar.gz";
+      };
+      build = "build.rs";
+      inherit dependencies features release verbose;
+    };
+    io_extras_0_18_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "io-extras";
+      version = "0.18.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/io-extras/0.18.4/download";
+        sha256 = "0rpig0y55gby78w70rka0fm8w911pzlq452hlfp9lp8l8xais3dh";
+        name = "io-extras-0.18.4.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    io_lifetimes_2_0_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "io-lifetimes";
+      version = "2.0.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/io-lifetimes/2.0.4/download";
+        sha256 = "06bc6spcc90rcgv3q28qj1x573rsjzw8p62i8kwjys0k6v27ivbm";
+        name = "io-lifetimes-2.0.4.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    ipnet_2_12_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ipnet";
+      version = "2.12.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/ipnet/2.12.2/download";
+        sha256 = "06mj138p9ch88mj89499k7mfdl7ar7gjrnn2hgr1shmy19m0kn7m";
+        name = "ipnet-2.12.2.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    itertools_0_12_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "itertools";
+      version = "0.12.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/itertools/0.12.1/download";
+        sha256 = "1wlraacs97ihmfmvc3vv123miqphpnadvvs27plrd3kiv2qwdk28";
+        name = "itertools-0.12.1.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    itoa_1_0_18_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "itoa";
+      version = "1.0.18";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/itoa/1.0.18/download";
+        sha256 = "11hjdzzqfgkj6z3xz1dh3an20f82zcl3miwpjb5gfjj2hvzglqk4";
+        name = "itoa-1.0.18.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    ittapi_0_4_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ittapi";
+      version = "0.4.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/ittapi/0.4.0/download";
+        sha256 = "1alr5c2yd3nb1nppnhvj38902dl235rz9rrk91vd9ndxbp18kz54";
+        name = "ittapi-0.4.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    ittapi_sys_0_4_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ittapi-sys";
+      version = "0.4.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/ittapi-sys/0.4.0/download";
+        sha256 = "0qyzcidkf6a26yqxj63f19iam7vg8q8dn8dq6w43c9cr8xfn06x7";
+        name = "ittapi-sys-0.4.0.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    jobserver_0_1_35_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "jobserver";
+      version = "0.1.35";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/jobserver/0.1.35/download";
+        sha256 = "136yjspxsq4r0fi352mdk887f17pywgl0x1i4p9hna717071yhyz";
+        name = "jobserver-0.1.35.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    lazy_static_1_5_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "lazy_static";
+      version = "1.5.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/lazy_static/1.5.1/download";
+        sha256 = "1b4vgwndmv6nhcnxgmp0al8ryvypygbz07wvz4w3ki132m5b7yhs";
+        name = "lazy_static-1.5.1.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    leb128_0_2_7_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "leb128";
+      version = "0.2.7";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/leb128/0.2.7/download";
+        sha256 = "076aqd01dkzckmw8bd6h12kjg79k6z0hza6mm99dja44c0k4f0hd";
+        name = "leb128-0.2.7.tar.gz";
+      };
+      crateBin = [ {  name = "leb128-repl";  path = "src/bin/leb128-repl.rs"; } ];
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    leb128fmt_0_1_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "leb128fmt";
+      version = "0.1.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/leb128fmt/0.1.0/download";
+        sha256 = "0cgiakn91pcgxj8n60qsrm9afh6halbw9ighdghchk7rx31nnbr1";
+        name = "leb128fmt-0.1.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    libc_0_2_190_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "libc";
+      version = "0.2.190";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/libc/0.2.190/download";
+        sha256 = "16jhqh2g8n7dvfrr6bxq39rklizapcspcvw9dsk40lg7xml96rqg";
+        name = "libc-0.2.190.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    libloading_0_4_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "libloading";
+      version = "0.4.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/libloading/0.4.3/download";
+        sha256 = "1cgb6xbadm59gc3cq733wrzsp59914hrjam0fan5gn1z100b6319";
+        name = "libloading-0.4.3.tar.gz";
+      };
+      build = "build.rs";
+      inherit dependencies features release verbose;
+    };
+    libsqlite3_sys_0_38_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "libsqlite3-sys";
+      version = "0.38.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/libsqlite3-sys/0.38.2/download";
+        sha256 = "1jagdxjkhqf14s5g487zbj9qmzl9vs38n6icb81p5fxn73abqfpj";
+        name = "libsqlite3-sys-0.38.2.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    linux_raw_sys_0_4_15_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "linux-raw-sys";
+      version = "0.4.15";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/linux-raw-sys/0.4.15/download";
+        sha256 = "0yg2gd594fj3fcgk8vc2lamw06j9fm7gbnl9786kbnzhm9rmk9h0";
+        name = "linux-raw-sys-0.4.15.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    linux_raw_sys_0_12_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "linux-raw-sys";
+      version = "0.12.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/linux-raw-sys/0.12.1/download";
+        sha256 = "0qxw44h7iav6xdxwca8b2wcc724w89xz04h0qmx8kglya573ladk";
+        name = "linux-raw-sys-0.12.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    litemap_0_8_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "litemap";
+      version = "0.8.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/litemap/0.8.3/download";
+        sha256 = "0rg4cil9kyglbwv484r72hhx04rp8kw53shfacxkkrnq1bdhh8s0";
+        name = "litemap-0.8.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    lock_api_0_4_14_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "lock_api";
+      version = "0.4.14";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/lock_api/0.4.14/download";
+        sha256 = "02a7f0xa9srsdh5yf66yd7im6fph5ymri5r3i9nvlh5qbb0n35ca";
+        name = "lock_api-0.4.14.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    log_0_4_34_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "log";
+      version = "0.4.34";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/log/0.4.34/download";
+        sha256 = "02sddxccz4pycxxabwa1lcw81gdqgk4g95lzgkqq8jch1hd0054n";
+        name = "log-0.4.34.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    maybe_owned_0_3_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "maybe-owned";
+      version = "0.3.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/maybe-owned/0.3.4/download";
+        sha256 = "08n5r5f925632sblcjqld4w8s6dckc7w1ff2s5lx9bnrh5az4c5p";
+        name = "maybe-owned-0.3.4.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    md_5_0_11_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "md-5";
+      version = "0.11.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/md-5/0.11.0/download";
+        sha256 = "1r3l3jrakgy60myh8wzm96qrk2in8jb8z5nsrdsz807g385814kk";
+        name = "md-5-0.11.0.tar.gz";
+      };
+      libName = "md5";
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    memchr_1_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "memchr";
+      version = "1.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/memchr/1.0.2/download";
+        sha256 = "0dfb8ifl9nrc9kzgd5z91q6qg87sh285q1ih7xgrsglmqfav9lg7";
+        name = "memchr-1.0.2.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    memchr_2_8_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "memchr";
+      version = "2.8.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/memchr/2.8.3/download";
+        sha256 = "0al4api55fkrd75dm8isb8z159n6jb6af0jib31y46l56jj88nlh";
+        name = "memchr-2.8.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    memfd_0_6_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "memfd";
+      version = "0.6.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/memfd/0.6.6/download";
+        sha256 = "0zg5g530dgry5xcv4qmmi4h718n3vv72cl2dia1ci3hg39wvd121";
+        name = "memfd-0.6.6.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    memoffset_0_9_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "memoffset";
+      version = "0.9.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/memoffset/0.9.1/download";
+        sha256 = "03lc67isx1cvd5zmgk1ss4sbqdhsl1858gna6hrw1ccs3qd975zz";
+        name = "memoffset-0.9.1.tar.gz";
+      };
+      build = "build.rs";
+      inherit dependencies features release verbose;
+    };
+    miniz_oxide_0_9_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "miniz_oxide";
+      version = "0.9.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/miniz_oxide/0.9.1/download";
+        sha256 = "1k07nhdzzn0vhyfagh2dcnzd4id4w9c9j78c8w11miqnbd6qhy3l";
+        name = "miniz_oxide-0.9.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    mio_1_2_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "mio";
+      version = "1.2.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/mio/1.2.4/download";
+        sha256 = "17dk8gknkn4vqqzljc6bx17qqfj085cgsmahj3cpbjksahcxhzf3";
+        name = "mio-1.2.4.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    multiversion_no_op_1_0_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "multiversion_no_op";
+      version = "1.0.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/multiversion_no_op/1.0.0/download";
+        sha256 = "13awk1dibxg55jkkh841bc0zp9w42bkyv02p55lfa321m79zsq8m";
+        name = "multiversion_no_op-1.0.0.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    nom_3_2_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "nom";
+      version = "3.2.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/nom/3.2.1/download";
+        sha256 = "1vcllxrz9hdw6j25kn020ka3psz1vkaqh1hm3yfak2240zrxgi07";
+        name = "nom-3.2.1.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    notify_8_2_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "notify";
+      version = "8.2.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/notify/8.2.0/download";
+        sha256 = "04d5fy28v2fxlvnjv55x7b9m76nym79v23761h0s2xc0b7qh9isl";
+        name = "notify-8.2.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    notify_types_2_1_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "notify-types";
+      version = "2.1.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/notify-types/2.1.0/download";
+        sha256 = "0awak9cdfybym790fkhypr5sjam8jxjagwxkb3sqavs82i31zrw4";
+        name = "notify-types-2.1.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    num_traits_0_2_19_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "num-traits";
+      version = "0.2.19";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/num-traits/0.2.19/download";
+        sha256 = "0j8mr5cwz62brjbab87r1ji25lfz8vvkswaindqqvwm60wl2dgg4";
+        name = "num-traits-0.2.19.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    object_0_33_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "object";
+      version = "0.33.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/object/0.33.0/download";
+        sha256 = "0q5mprkjfy73kqiva5k2jvv3k85ag6p3amzn0yif7zac2b2f1dg0";
+        name = "object-0.33.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    once_cell_1_21_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "once_cell";
+      version = "1.21.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/once_cell/1.21.4/download";
+        sha256 = "0xmnil60500v7p1ds44cz9wkq5n4baxb8b3l52yai5hg7xh6f6ng";
+        name = "once_cell-1.21.4.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    openssl_probe_0_2_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "openssl-probe";
+      version = "0.2.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/openssl-probe/0.2.1/download";
+        sha256 = "1n7zs5hv5kydp1r2kcxm6rv3zf3c3mz6iy40bbbh2brw1zp5cbh8";
+        name = "openssl-probe-0.2.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    parking_lot_0_12_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "parking_lot";
+      version = "0.12.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/parking_lot/0.12.5/download";
+        sha256 = "0lkhcfrmc3v9hbyrw6fki0igb5hc8686v4p3nlay60bz6ad3394j";
+        name = "parking_lot-0.12.5.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    parking_lot_core_0_9_12_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "parking_lot_core";
+      version = "0.9.12";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/parking_lot_core/0.9.12/download";
+        sha256 = "0s5zx8milis742bgccgjhm8izgnmmnj57jvi9p6rjxawqgwwg0hj";
+        name = "parking_lot_core-0.9.12.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    paste_1_0_15_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "paste";
+      version = "1.0.15";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/paste/1.0.15/download";
+        sha256 = "07bmn23mq1ral99azp3lznqvwbva3hka50znriqm9fwl84iprr7l";
+        name = "paste-1.0.15.tar.gz";
+      };
+      procMacro = true;
+      build = "build.rs";
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    percent_encoding_2_3_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "percent-encoding";
+      version = "2.3.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/percent-encoding/2.3.2/download";
+        sha256 = "1jn89csd2b2xhmjamhbf29jq6y6mnhapc5c2a2jpzhbv8dmmywb6";
+        name = "percent-encoding-2.3.2.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    phf_0_11_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "phf";
+      version = "0.11.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/phf/0.11.3/download";
+        sha256 = "0bvkhwlh5ldcamsjpyx56ns4jx6j9yfyhb2552fkxffhq60fi1i7";
+        name = "phf-0.11.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    phf_0_13_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "phf";
+      version = "0.13.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/phf/0.13.1/download";
+        sha256 = "1v51hpn9siibdcp8167di8819kk1xlms1yahf46mh02qrhwwmgyr";
+        name = "phf-0.13.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    phf_generator_0_11_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "phf_generator";
+      version = "0.11.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/phf_generator/0.11.3/download";
+        sha256 = "0kg0j6k4gfsmvscdrcz32w51h2xqwl2c7wly6xp8mbfy7ps2ia7m";
+        name = "phf_generator-0.11.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    phf_macros_0_11_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "phf_macros";
+      version = "0.11.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/phf_macros/0.11.3/download";
+        sha256 = "18dzacwmimnm2m8hdmc1a3lj5kzxg0ylh714nhi7fbbl49m72hyl";
+        name = "phf_macros-0.11.3.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    phf_shared_0_11_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "phf_shared";
+      version = "0.11.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/phf_shared/0.11.3/download";
+        sha256 = "1l8bmpcg3k4223wb4j9gxmnsbab4wrs2qfjpyf70vc4jaw67chr7";
+        name = "phf_shared-0.11.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    phf_shared_0_13_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "phf_shared";
+      version = "0.13.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/phf_shared/0.13.1/download";
+        sha256 = "0wfqmmi2b2cff35vn8dfv9fa58kvc7b3r6b1yskl9725in6dxxlp";
+        name = "phf_shared-0.13.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    pin_project_lite_0_2_17_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "pin-project-lite";
+      version = "0.2.17";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/pin-project-lite/0.2.17/download";
+        sha256 = "0m16ag58jiqafv43cc892v71rzx4qgpcll853y574bb1lan3ww80";
+        name = "pin-project-lite-0.2.17.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    pkg_config_0_3_34_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "pkg-config";
+      version = "0.3.34";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/pkg-config/0.3.34/download";
+        sha256 = "0i8qb5w8hxf3jd8jlpgdnpb2a7f5swfgvh3s6wg338ardh4pqjxs";
+        name = "pkg-config-0.3.34.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    postgres_0_19_14_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "postgres";
+      version = "0.19.14";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/postgres/0.19.14/download";
+        sha256 = "130kdw3058bi5svriy93yis3znfn4nbcrpn8j7v8n9ihxks6kkn1";
+        name = "postgres-0.19.14.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    postgres_protocol_0_6_12_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "postgres-protocol";
+      version = "0.6.12";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/postgres-protocol/0.6.12/download";
+        sha256 = "037c5zmi57xzvd087pph1p0bvz2jfzbaia7968j6fcmyly63drcf";
+        name = "postgres-protocol-0.6.12.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    postgres_types_0_2_14_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "postgres-types";
+      version = "0.2.14";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/postgres-types/0.2.14/download";
+        sha256 = "07bz9v48rvj9y9j2xaj4anj6a7lc3wl505pnxpq0f4ri4ymba9xn";
+        name = "postgres-types-0.2.14.tar.gz";
+      };
+      edition = "2024";
+      crateRenames = { "serde_core-1.0.229" = "serde_1"; "serde_json-1.0.154" = "serde_json_1"; };
+      inherit dependencies features release verbose;
+    };
+    potential_utf_0_1_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "potential_utf";
+      version = "0.1.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/potential_utf/0.1.6/download";
+        sha256 = "16b0gmzx91ikywwj0s56yivf16pwd66q1pg0yyyw9cbk9yvfpncd";
+        name = "potential_utf-0.1.6.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    ppv_lite86_0_2_21_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ppv-lite86";
+      version = "0.2.21";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/ppv-lite86/0.2.21/download";
+        sha256 = "1p51hwil9shihg29p2blxkb638wdq5whvcn1axd390rss203mmxy";
+        name = "ppv-lite86-0.2.21.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    proc_macro2_1_0_107_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "proc-macro2";
+      version = "1.0.107";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/proc-macro2/1.0.107/download";
+        sha256 = "1jqgsj4zxmdf5m5scw50568rfz1898nn0qis7yd9m444x3mmcxln";
+        name = "proc-macro2-1.0.107.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    pyo3_0_29_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "pyo3";
+      version = "0.29.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/pyo3/0.29.3/download";
+        sha256 = "0c623brpp0fjw5py63dcdg8ajg3ivjmxpy8a1gnzplhrkg67hsjy";
+        name = "pyo3-0.29.3.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    pyo3_build_config_0_29_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "pyo3-build-config";
+      version = "0.29.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/pyo3-build-config/0.29.3/download";
+        sha256 = "0mwijdc7xyx9rdarncr3q28hlm7v7pn7hy3bm0i7bs7cmh29lp5y";
+        name = "pyo3-build-config-0.29.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    pyo3_ffi_0_29_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "pyo3-ffi";
+      version = "0.29.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/pyo3-ffi/0.29.3/download";
+        sha256 = "08vzj0nfpfnsqs3wj03g4vzcciwrlixa7j42rn917h2hmsmkkpws";
+        name = "pyo3-ffi-0.29.3.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    pyo3_macros_0_29_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "pyo3-macros";
+      version = "0.29.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/pyo3-macros/0.29.3/download";
+        sha256 = "1h2rc3wcc12ijy04r1c1y5b1pf8nhs19djzbgkwbxqxzxv0b750d";
+        name = "pyo3-macros-0.29.3.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    pyo3_macros_backend_0_29_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "pyo3-macros-backend";
+      version = "0.29.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/pyo3-macros-backend/0.29.3/download";
+        sha256 = "02jgx8jxqkviyc7zy33svxs5spdxbppmpkjwcq0jzw8xn4jmflha";
+        name = "pyo3-macros-backend-0.29.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    quote_1_0_47_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "quote";
+      version = "1.0.47";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/quote/1.0.47/download";
+        sha256 = "0d5khqj9jh49388jw2bfpz72kvjx1mdrzmnnf654sjd48x8j3ap2";
+        name = "quote-1.0.47.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    r2d2_0_8_10_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "r2d2";
+      version = "0.8.10";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/r2d2/0.8.10/download";
+        sha256 = "1iyhj7i3v8s54ckrdyn0czmhp56wa1rzrzckiysni9jm5d2hbasw";
+        name = "r2d2-0.8.10.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    r2d2_postgres_0_18_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "r2d2_postgres";
+      version = "0.18.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/r2d2_postgres/0.18.2/download";
+        sha256 = "0kfrd00x2dk5y76cnkdammg04jj2gwymgwghr56vjgr49c9xcqms";
+        name = "r2d2_postgres-0.18.2.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    rand_0_8_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rand";
+      version = "0.8.8";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rand/0.8.8/download";
+        sha256 = "1dgaypp6hv6xssmq4s6kc1d1678lrx4j4g5f0zck2h8ryfdlixgf";
+        name = "rand-0.8.8.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    rand_0_10_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rand";
+      version = "0.10.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rand/0.10.3/download";
+        sha256 = "1sk0bxgikvv963kg96qysw0axxh9zkiv7w38mg4f3ij34fh045vg";
+        name = "rand-0.10.3.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    rand_chacha_0_3_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rand_chacha";
+      version = "0.3.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rand_chacha/0.3.1/download";
+        sha256 = "0kiicslq5x8s2q39mqrz189jvdn2zfy5akxida495dy1jgnbnylb";
+        name = "rand_chacha-0.3.1.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    rand_core_0_6_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rand_core";
+      version = "0.6.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rand_core/0.6.4/download";
+        sha256 = "11sv3vji0vd3hsidccdas7bhf5jb4jnps8yri55b83xdrl62y33z";
+        name = "rand_core-0.6.4.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    rand_core_0_10_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rand_core";
+      version = "0.10.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rand_core/0.10.1/download";
+        sha256 = "0ma9bhr0p36ghdnhkl84bkcqc0l8q9687ifii660w3pavs8mkq2p";
+        name = "rand_core-0.10.1.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    rayon_1_12_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rayon";
+      version = "1.12.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rayon/1.12.0/download";
+        sha256 = "0bc4qh52fq6cqvb38yyrvy0nv9zfqr1vjfsgfcns36gz8w56wc9r";
+        name = "rayon-1.12.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    rayon_core_1_13_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rayon-core";
+      version = "1.13.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rayon-core/1.13.0/download";
+        sha256 = "021xng9gxwx4r4cabiazhgqd90h6r9xblgszl95b6ymx0yx7l2xm";
+        name = "rayon-core-1.13.0.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    regalloc2_0_9_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "regalloc2";
+      version = "0.9.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/regalloc2/0.9.3/download";
+        sha256 = "108zah5c0b2a6m1mmldm0gph4avxyhz5vfqcn9ssmvzlxnyc2fw8";
+        name = "regalloc2-0.9.3.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    regex_1_13_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "regex";
+      version = "1.13.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/regex/1.13.1/download";
+        sha256 = "19rfdfrrlji7cxp6lf5hcvfiq09cjml458vxfk970vqv4yp34glx";
+        name = "regex-1.13.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    regex_automata_0_4_18_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "regex-automata";
+      version = "0.4.18";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/regex-automata/0.4.18/download";
+        sha256 = "06ckyam26ljlyjfx2swpv8220ignwdb3c8hk2z89j534xrni0i38";
+        name = "regex-automata-0.4.18.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    regex_syntax_0_8_11_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "regex-syntax";
+      version = "0.8.11";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/regex-syntax/0.8.11/download";
+        sha256 = "1i83ibkljssmdqcjnxbd9mrh71g5jf73sywc3y5iakbiknzyd2z4";
+        name = "regex-syntax-0.8.11.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    ring_0_17_14_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ring";
+      version = "0.17.14";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/ring/0.17.14/download";
+        sha256 = "0g04szpc64ky72vw1kxbnh5qk4fz2sis0ali2835p1is7pnnxsvy";
+        name = "ring-0.17.14.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    rumqttc_0_25_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rumqttc";
+      version = "0.25.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rumqttc/0.25.1/download";
+        sha256 = "1qhq1r2v9klfrcmnjaiyzchdsgpgw3smh81dnfhrjfbv9kwsk09f";
+        name = "rumqttc-0.25.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    rusqlite_0_40_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rusqlite";
+      version = "0.40.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rusqlite/0.40.2/download";
+        sha256 = "1ikhmk8zs0w1x97k360vnsa082c7frrzwd4q6lydl9943p97p68j";
+        name = "rusqlite-0.40.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    rustc_demangle_0_1_28_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustc-demangle";
+      version = "0.1.28";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustc-demangle/0.1.28/download";
+        sha256 = "17iv9s2npixamqb5c0kdvp8dql4m6xfbx9kwhy3biwzc2jsfi33q";
+        name = "rustc-demangle-0.1.28.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    rustc_hash_1_1_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustc-hash";
+      version = "1.1.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustc-hash/1.1.0/download";
+        sha256 = "0zc9wvar2hqrmv7wm5p4lpb03633rkgar8wkwqvyvz05kakgnsv9";
+        name = "rustc-hash-1.1.0.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    rustfbp_0_3_34_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustfbp";
+      version = "0.3.34";
+      fractalType = "crate";
+      src = ../rustfbp;
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    rustfbp_macros_0_3_34_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustfbp_macros";
+      version = "0.3.34";
+      fractalType = "crate";
+      src = ../rustfbp_macros;
+      procMacro = true;
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    rustix_0_38_44_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustix";
+      version = "0.38.44";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustix/0.38.44/download";
+        sha256 = "1j7bjcl0wcx11nqi5sj7cr80riimjpp18dhsdybr0vh95gxv8jzx";
+        name = "rustix-0.38.44.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      crateRenames = { "errno-0.3.14" = "libc_errno"; };
+      inherit dependencies features release verbose;
+    };
+    rustix_1_1_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustix";
+      version = "1.1.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustix/1.1.5/download";
+        sha256 = "15k0m50bamgbc135vwb846n4fbvi09a7gjp4x0s8f1hlfj9i65b7";
+        name = "rustix-1.1.5.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      crateRenames = { "errno-0.3.14" = "libc_errno"; };
+      inherit dependencies features release verbose;
+    };
+    rustix_linux_procfs_0_1_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustix-linux-procfs";
+      version = "0.1.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustix-linux-procfs/0.1.1/download";
+        sha256 = "053bp9b36r4grvlddcdv01f91sni2bqf1y3b0cqarlwbvyn94h5b";
+        name = "rustix-linux-procfs-0.1.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    rustls_0_23_45_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustls";
+      version = "0.23.45";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustls/0.23.45/download";
+        sha256 = "1gqq4d5rxh3mcvg8a5nrxk1yz75cnyq429yxvjhvi6l13d83bsvh";
+        name = "rustls-0.23.45.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      crateRenames = { "rustls-pki-types-1.15.1" = "pki_types"; };
+      inherit dependencies features release verbose;
+    };
+    rustls_native_certs_0_8_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustls-native-certs";
+      version = "0.8.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustls-native-certs/0.8.4/download";
+        sha256 = "0r3sddrnmfrf4zigyrp4wwzviip8fv898fvmipy1dzqabhsgdv3q";
+        name = "rustls-native-certs-0.8.4.tar.gz";
+      };
+      edition = "2021";
+      crateRenames = { "rustls-pki-types-1.15.1" = "pki_types"; };
+      inherit dependencies features release verbose;
+    };
+    rustls_pemfile_2_2_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustls-pemfile";
+      version = "2.2.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustls-pemfile/2.2.0/download";
+        sha256 = "1vppcjknn2qwrm87mdkbdh3p9whq3zlv3z3rrbwyrnbzkqrbdm6l";
+        name = "rustls-pemfile-2.2.0.tar.gz";
+      };
+      edition = "2018";
+      crateRenames = { "rustls-pki-types-1.15.1" = "pki_types"; };
+      inherit dependencies features release verbose;
+    };
+    rustls_pki_types_1_15_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustls-pki-types";
+      version = "1.15.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustls-pki-types/1.15.1/download";
+        sha256 = "1gx4zyl0bn24r26hm1by0x5wnrlq1qybx71hirir8n26r5cvblw2";
+        name = "rustls-pki-types-1.15.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    rustls_webpki_0_102_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustls-webpki";
+      version = "0.102.8";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustls-webpki/0.102.8/download";
+        sha256 = "15r8jj10r99m1j4kl7m74p29shijh8wz23isg3qlfkf1pq1d4jr8";
+        name = "rustls-webpki-0.102.8.tar.gz";
+      };
+      libName = "webpki";
+      edition = "2021";
+      crateRenames = { "rustls-pki-types-1.15.1" = "pki_types"; };
+      inherit dependencies features release verbose;
+    };
+    rustls_webpki_0_103_15_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustls-webpki";
+      version = "0.103.15";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustls-webpki/0.103.15/download";
+        sha256 = "0fls47dlzj2q9nbxsap0qirdd8lj7hbfdzw1yadn7s1cdl0i0kyy";
+        name = "rustls-webpki-0.103.15.tar.gz";
+      };
+      libName = "webpki";
+      edition = "2021";
+      crateRenames = { "rustls-pki-types-1.15.1" = "pki_types"; };
+      inherit dependencies features release verbose;
+    };
+    rustversion_1_0_23_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "rustversion";
+      version = "1.0.23";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/rustversion/1.0.23/download";
+        sha256 = "0si1998dhzd8gzzbz2syhll9x4x5dbbw7cm2w6g1fyqy0hi062ps";
+        name = "rustversion-1.0.23.tar.gz";
+      };
+      procMacro = true;
+      build = "build/build.rs";
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    ryu_1_0_23_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ryu";
+      version = "1.0.23";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/ryu/1.0.23/download";
+        sha256 = "1rzdsrj63779dma6ix8gbffxksyswzixz164w8v90s49r91ck81c";
+        name = "ryu-1.0.23.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    same_file_1_0_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "same-file";
+      version = "1.0.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/same-file/1.0.6/download";
+        sha256 = "1cxk0l015nkr3n0hs8wkkc0mpni0yn6a06r0jxqv4r61sgl227mz";
+        name = "same-file-1.0.6.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    scheduled_thread_pool_0_2_7_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "scheduled-thread-pool";
+      version = "0.2.7";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/scheduled-thread-pool/0.2.7/download";
+        sha256 = "1pbknyvkprgldf7m597hyq5jz1g470mqyna4kpzyinr09gklm050";
+        name = "scheduled-thread-pool-0.2.7.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    scopeguard_1_2_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "scopeguard";
+      version = "1.2.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/scopeguard/1.2.0/download";
+        sha256 = "1dsd5k8rda5nm8dgdxvnkc4p58hlpjqv0cmyzkd56b5z9q4vw4yp";
+        name = "scopeguard-1.2.0.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    semver_1_0_28_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "semver";
+      version = "1.0.28";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/semver/1.0.28/download";
+        sha256 = "0ch667xpvlz6vdg16xbzp7630lrf31azx96drx9wlp6qqslphn2y";
+        name = "semver-1.0.28.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    serde_1_0_229_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "serde";
+      version = "1.0.229";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/serde/1.0.229/download";
+        sha256 = "0ww222hr6iw5aayyg5vn1q1kszald3bdc86vw58x5h0s0w7zslag";
+        name = "serde-1.0.229.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    serde_core_1_0_229_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "serde_core";
+      version = "1.0.229";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/serde_core/1.0.229/download";
+        sha256 = "18qhb853r23frviwm9qj4crv44amw95igkxrpk4b5f3a0g066zn6";
+        name = "serde_core-1.0.229.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    serde_derive_1_0_229_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "serde_derive";
+      version = "1.0.229";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/serde_derive/1.0.229/download";
+        sha256 = "113i0x6vm0fxix90byywhqxqrryfx75gk5aw1rhpjddg19vcxhbm";
+        name = "serde_derive-1.0.229.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    serde_json_1_0_154_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "serde_json";
+      version = "1.0.154";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/serde_json/1.0.154/download";
+        sha256 = "12yc83gpvxsppb1nnp887lsblqw0sl9dqgzvpfpdd5i5gmgnqnrc";
+        name = "serde_json-1.0.154.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    serde_spanned_0_6_9_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "serde_spanned";
+      version = "0.6.9";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/serde_spanned/0.6.9/download";
+        sha256 = "1dpkkdx733qhd6k3xh3mc6g74qj9csd6v4xmn8zqhm0b20mci797";
+        name = "serde_spanned-0.6.9.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    serde_spanned_1_1_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "serde_spanned";
+      version = "1.1.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/serde_spanned/1.1.2/download";
+        sha256 = "11ic8v6d29v39fm0ych6n0kbgy1kqm55fq4x69k2b7ril63z4ijx";
+        name = "serde_spanned-1.1.2.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    sha1_0_11_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "sha1";
+      version = "0.11.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/sha1/0.11.0/download";
+        sha256 = "059qq9srnqivn89cz9f33ir4w2jmay91ph4hq27bgp67jijjfgi0";
+        name = "sha1-0.11.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    sha2_0_10_9_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "sha2";
+      version = "0.10.9";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/sha2/0.10.9/download";
+        sha256 = "0djxyxqaylkj6y72gwz3fk5znwfj9ifqc6v6dgwfwl9s50971134";
+        name = "sha2-0.10.9.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    sha2_0_11_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "sha2";
+      version = "0.11.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/sha2/0.11.0/download";
+        sha256 = "0i5x8n811n1hyv7kq9xgg3702d5iyk3z026d0akr3p5a63xqvp8j";
+        name = "sha2-0.11.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    shellexpand_2_1_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "shellexpand";
+      version = "2.1.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/shellexpand/2.1.2/download";
+        sha256 = "1m94lfn4hr1p9whmc36d51nnlr5y87hrkgr7avvsbzcqrzz8dbjg";
+        name = "shellexpand-2.1.2.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    shlex_2_0_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "shlex";
+      version = "2.0.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/shlex/2.0.1/download";
+        sha256 = "0fjard6fx8vjy378ax48v4i6qcclz71zcn2ribzvylfwg03i9rgw";
+        name = "shlex-2.0.1.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    simd_adler32_0_3_10_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "simd-adler32";
+      version = "0.3.10";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/simd-adler32/0.3.10/download";
+        sha256 = "1z0sh1qmyixb0xh4a9n3pz2k5j28kddgh3b2050iz8rayxixxws9";
+        name = "simd-adler32-0.3.10.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    simdutf8_0_1_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "simdutf8";
+      version = "0.1.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/simdutf8/0.1.5/download";
+        sha256 = "0df9lyaq8mgsmcqb2xyqbafx11g17m2hgclik8qdinxkdf99fcnr";
+        name = "simdutf8-0.1.5.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    siphasher_1_0_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "siphasher";
+      version = "1.0.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/siphasher/1.0.4/download";
+        sha256 = "1qwznz1fvjzxms6q6jdk0z4pl31ps0gygbpm0vyl3hwb7rl3h99p";
+        name = "siphasher-1.0.4.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    slab_0_4_12_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "slab";
+      version = "0.4.12";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/slab/0.4.12/download";
+        sha256 = "1lq9jrksd9rah2h9bm3asbl1g63cijjfsy3qqvn1w9nwf02hhrln";
+        name = "slab-0.4.12.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    slice_group_by_0_3_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "slice-group-by";
+      version = "0.3.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/slice-group-by/0.3.1/download";
+        sha256 = "04m68l6dpwh3ni4232mk18zd1y9bar8ygaks74hyd3yplb13d9fw";
+        name = "slice-group-by-0.3.1.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    smallvec_1_16_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "smallvec";
+      version = "1.16.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/smallvec/1.16.3/download";
+        sha256 = "135lig3fw4wrbj0flxdfdk29vc7nysw6cysfddvwiyfrrdkwra4x";
+        name = "smallvec-1.16.3.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    socket2_0_6_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "socket2";
+      version = "0.6.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/socket2/0.6.5/download";
+        sha256 = "02v4qm7wcvcg3274pzsyywqmpx8bskfa7h5d5a760sw26rkg66v6";
+        name = "socket2-0.6.5.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    spin_0_9_9_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "spin";
+      version = "0.9.9";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/spin/0.9.9/download";
+        sha256 = "1gyvzzhzyspan1brwjlpidammjn69cqs5vzn6g2zky8s6nps2acw";
+        name = "spin-0.9.9.tar.gz";
+      };
+      crateRenames = { "lock_api-0.4.14" = "lock_api_crate"; };
+      inherit dependencies features release verbose;
+    };
+    sptr_0_3_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "sptr";
+      version = "0.3.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/sptr/0.3.2/download";
+        sha256 = "0vz96sb8l0f8r2925gnyx7xzq7kf5jmnn3cb12y4f05mxhjkalps";
+        name = "sptr-0.3.2.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    stable_deref_trait_1_2_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "stable_deref_trait";
+      version = "1.2.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/stable_deref_trait/1.2.1/download";
+        sha256 = "1sif71jcfvfiln73hgjf049chvbgx24fdhpwawdswhdskk3bq5in";
+        name = "stable_deref_trait-1.2.1.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    stringprep_0_1_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "stringprep";
+      version = "0.1.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/stringprep/0.1.5/download";
+        sha256 = "0872s03l1y8wx77riig9ng6zb3m0l6miyrdgafgf6mk6a607wcva";
+        name = "stringprep-0.1.5.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    subtle_2_6_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "subtle";
+      version = "2.6.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/subtle/2.6.1/download";
+        sha256 = "0fgafav7anwqi9w9z3zl04ppvwgpcfysd9g0jxj16hwimxyw31sx";
+        name = "subtle-2.6.1.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    syn_2_0_119_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "syn";
+      version = "2.0.119";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/syn/2.0.119/download";
+        sha256 = "16g7wdvinbz116f04q386p5qi6vmf7m7hci0myc3zcss5fw522wf";
+        name = "syn-2.0.119.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    syn_3_0_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "syn";
+      version = "3.0.8";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/syn/3.0.8/download";
+        sha256 = "17rdg9q5cwwqv3873wpmny8l0d2ffbx7zlbvz71krcxqxjn549s5";
+        name = "syn-3.0.8.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    synstructure_0_14_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "synstructure";
+      version = "0.14.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/synstructure/0.14.0/download";
+        sha256 = "0k78mghz7fi5i8g0dr4ic9rxk9k94yg4akkzdri885jigxajplfl";
+        name = "synstructure-0.14.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    system_deps_6_2_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "system-deps";
+      version = "6.2.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/system-deps/6.2.2/download";
+        sha256 = "173bj885b9c20wihwyjxq8mlzwa489gy4mg3s4d7wbn13bn9ygh5";
+        name = "system-deps-6.2.2.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    system_interface_0_27_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "system-interface";
+      version = "0.27.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/system-interface/0.27.3/download";
+        sha256 = "0rvplc3w2nrq6x1h0jclbryyvl3fgvdy3ngpndvbhdr8hacjfzp9";
+        name = "system-interface-0.27.3.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    target_lexicon_0_12_16_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "target-lexicon";
+      version = "0.12.16";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/target-lexicon/0.12.16/download";
+        sha256 = "1h6vczmxdpfqni5fv52fff6g9mpbhpm74v52plndahz64l5sxipy";
+        name = "target-lexicon-0.12.16.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    target_lexicon_0_13_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "target-lexicon";
+      version = "0.13.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/target-lexicon/0.13.5/download";
+        sha256 = "1mqyj5wf7m6qcfkkrqx3c7q5rk37i1c1n6mwb9gwy7a2s2j7ay53";
+        name = "target-lexicon-0.13.5.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    thiserror_1_0_69_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "thiserror";
+      version = "1.0.69";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/thiserror/1.0.69/download";
+        sha256 = "1yin4iwrx5dqnrmhlccgbfm18zncik5w4pk627678m5l3vqxxjbb";
+        name = "thiserror-1.0.69.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    thiserror_2_0_21_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "thiserror";
+      version = "2.0.21";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/thiserror/2.0.21/download";
+        sha256 = "00lrwzfxy023v061fqz2lgcp0y5bdixfgyp38fjnjmmnipib2xfb";
+        name = "thiserror-2.0.21.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    thiserror_impl_1_0_69_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "thiserror-impl";
+      version = "1.0.69";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/thiserror-impl/1.0.69/download";
+        sha256 = "1769g7nl9knqkglvrkpwpaqh5gcsf58b7n4hvlz8914a3m0681k0";
+        name = "thiserror-impl-1.0.69.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    thiserror_impl_2_0_21_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "thiserror-impl";
+      version = "2.0.21";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/thiserror-impl/2.0.21/download";
+        sha256 = "19p7w22pl2vfn5vh8j6nmii0f0zd9i1x54g653px1qazn9cg0a9d";
+        name = "thiserror-impl-2.0.21.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    tiny_http_0_12_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tiny_http";
+      version = "0.12.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tiny_http/0.12.0/download";
+        sha256 = "1ffiis50bpy0hgbzfraqmiayn72whcjl22vn37159waqb0f5lwhy";
+        name = "tiny_http-0.12.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    tinystr_0_8_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tinystr";
+      version = "0.8.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tinystr/0.8.4/download";
+        sha256 = "18ihyxaqzhj12wwka3c7jlfcm7x101k5r8z31a9dz0qx7c5l2ak3";
+        name = "tinystr-0.8.4.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    tinyvec_1_13_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tinyvec";
+      version = "1.13.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tinyvec/1.13.3/download";
+        sha256 = "0jnhxxd0pmpacvka6zmbrxz3l3z7qnkr8qywh4n3ag59kw9mg0lh";
+        name = "tinyvec-1.13.3.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    tokio_1_53_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tokio";
+      version = "1.53.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tokio/1.53.2/download";
+        sha256 = "15f091f7c7yy4xrfs6ihbb3nwh3smsmqs6kc9mrvf11zw9khzik1";
+        name = "tokio-1.53.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    tokio_macros_2_7_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tokio-macros";
+      version = "2.7.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tokio-macros/2.7.2/download";
+        sha256 = "0d7k9grhpd0q082gvzs8msa65yscgmrrb2qfs4lkkxn63bwqkh4x";
+        name = "tokio-macros-2.7.2.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    tokio_postgres_0_7_18_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tokio-postgres";
+      version = "0.7.18";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tokio-postgres/0.7.18/download";
+        sha256 = "10qbl8xm4ywdkc3zadyzphsh86cfwk3ivmjilz4p4yhpb5fvkw8s";
+        name = "tokio-postgres-0.7.18.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    tokio_rustls_0_26_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tokio-rustls";
+      version = "0.26.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tokio-rustls/0.26.6/download";
+        sha256 = "1d93fa13yd8041vciiwigddf6xh3cimj7fbjcgp7zr4fhxw0ly2v";
+        name = "tokio-rustls-0.26.6.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    tokio_stream_0_1_19_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tokio-stream";
+      version = "0.1.19";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tokio-stream/0.1.19/download";
+        sha256 = "1i71cwkxg9p36gxscqm1igs2nhzph7ycd6hgs17qfchaxln7gznj";
+        name = "tokio-stream-0.1.19.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    tokio_util_0_7_20_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tokio-util";
+      version = "0.7.20";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tokio-util/0.7.20/download";
+        sha256 = "03f50rwh4jc9vghcps0ckc4x5vl25pgvgiwgs5y3g5qynbg9z012";
+        name = "tokio-util-0.7.20.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    toml_0_8_23_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "toml";
+      version = "0.8.23";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/toml/0.8.23/download";
+        sha256 = "0ad4m9rgnw8sh5mx84g5rbdmalzl7gxabsyfvg16qfymwr903acp";
+        name = "toml-0.8.23.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    toml_1_1_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "toml";
+      version = "1.1.8+spec-1.1.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/toml/1.1.8+spec-1.1.0/download";
+        sha256 = "0g06jggqxh8vpb5d2yidfp4bq0nbrgr79rhaaaicn3dhcb38kjwh";
+        name = "toml-1.1.8+spec-1.1.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    toml_datetime_0_6_11_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "toml_datetime";
+      version = "0.6.11";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/toml_datetime/0.6.11/download";
+        sha256 = "1v7vblaxhd70i1kc6aa2i9c10abidy0rcqqmsa4k0a8pcksdrbmc";
+        name = "toml_datetime-0.6.11.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    toml_datetime_1_1_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "toml_datetime";
+      version = "1.1.2+spec-1.1.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/toml_datetime/1.1.2+spec-1.1.0/download";
+        sha256 = "1hp3aj0s5v7r10kl967k831h3lg5s4mfh312xasi3iazf0m8nd9q";
+        name = "toml_datetime-1.1.2+spec-1.1.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    toml_edit_0_22_27_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "toml_edit";
+      version = "0.22.27";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/toml_edit/0.22.27/download";
+        sha256 = "180y8060xnc9z8146ijp7qj9kssp2ka180w3dlhss7ndpcyl3bc8";
+        name = "toml_edit-0.22.27.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    toml_parser_1_1_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "toml_parser";
+      version = "1.1.5+spec-1.1.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/toml_parser/1.1.5+spec-1.1.0/download";
+        sha256 = "0ak7rmln7f6ddlk273zw5z84k7vlmj1mhm9cf48306g38nr2rzn3";
+        name = "toml_parser-1.1.5+spec-1.1.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    toml_write_0_1_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "toml_write";
+      version = "0.1.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/toml_write/0.1.2/download";
+        sha256 = "10aw02pxqz0q17i6kn9r90v1vsm4g3xl9cg374jl5r55swz82nbn";
+        name = "toml_write-0.1.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    toml_writer_1_1_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "toml_writer";
+      version = "1.1.3+spec-1.1.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/toml_writer/1.1.3+spec-1.1.0/download";
+        sha256 = "0vr096zn8y3wnc9hrsv28syf4iv9x8b1hx11gh7whmda8fzn5031";
+        name = "toml_writer-1.1.3+spec-1.1.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    tracing_0_1_44_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tracing";
+      version = "0.1.44";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tracing/0.1.44/download";
+        sha256 = "1p8gh29rzf6p1lmxcq57g9jdc8j5p5wrygwcxjsb9892lby6p2iv";
+        name = "tracing-0.1.44.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    tracing_attributes_0_1_31_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tracing-attributes";
+      version = "0.1.31";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tracing-attributes/0.1.31/download";
+        sha256 = "109fv53sqqsfwb7qih69vzhi0ahdmns9ad1l1nsq42xf1k1s0g5d";
+        name = "tracing-attributes-0.1.31.tar.gz";
+      };
+      procMacro = true;
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    tracing_core_0_1_36_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tracing-core";
+      version = "0.1.36";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tracing-core/0.1.36/download";
+        sha256 = "1m77f15k2k9x17hlc9a2agl2cngjnl1lsr07ywwd8ndm2wgz7w2i";
+        name = "tracing-core-0.1.36.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    tungstenite_0_30_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "tungstenite";
+      version = "0.30.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/tungstenite/0.30.0/download";
+        sha256 = "1iya9df5w5pkwxs07w69skz14fk7c44rnwjbmgh2xcx47a5wvzx6";
+        name = "tungstenite-0.30.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    typenum_1_20_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "typenum";
+      version = "1.20.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/typenum/1.20.1/download";
+        sha256 = "0b2qqinpwwq9q9xgqddnl6y1ghk1gwb6dinx6n4vbp51sw5pbwc9";
+        name = "typenum-1.20.1.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    unicode_bidi_0_3_18_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "unicode-bidi";
+      version = "0.3.18";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/unicode-bidi/0.3.18/download";
+        sha256 = "1fwvnqxgic70w99wqrycafl4i0dkgnw1n9grjbrfck82k8a7260w";
+        name = "unicode-bidi-0.3.18.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    unicode_ident_1_0_26_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "unicode-ident";
+      version = "1.0.26";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/unicode-ident/1.0.26/download";
+        sha256 = "1ybby7hrp2v4j01alpvnpn8r2amihgqqdi6p5xcgy5pnn3vxv8aq";
+        name = "unicode-ident-1.0.26.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    unicode_normalization_0_1_25_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "unicode-normalization";
+      version = "0.1.25";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/unicode-normalization/0.1.25/download";
+        sha256 = "1h60gyg0mpm62lli5b1v2y5zvz151apz3r7fw8b5drzjmb67wm5l";
+        name = "unicode-normalization-0.1.25.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    unicode_properties_0_1_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "unicode-properties";
+      version = "0.1.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/unicode-properties/0.1.4/download";
+        sha256 = "0827vhk3zafgym43d0gbmg2rjxmq3dpd733gkjgh65w5a00h0rma";
+        name = "unicode-properties-0.1.4.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    unicode_width_0_2_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "unicode-width";
+      version = "0.2.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/unicode-width/0.2.2/download";
+        sha256 = "1z9dff31pck6200rksxs9vbqqkqs9i0iksrk34i6ywkgj13m7lkv";
+        name = "unicode-width-0.2.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    unicode_xid_0_2_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "unicode-xid";
+      version = "0.2.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/unicode-xid/0.2.6/download";
+        sha256 = "0rvhd03b5p5zr5pm7r853iijz0yy4rwafav2qybfkm1xnv9p0ppp";
+        name = "unicode-xid-0.2.6.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    untrusted_0_9_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "untrusted";
+      version = "0.9.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/untrusted/0.9.0/download";
+        sha256 = "11cq4l1gq1ghy7ks1l2h5fpy4imqhfzk0gkfvm4pnsgdid34q82w";
+        name = "untrusted-0.9.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    ureq_3_4_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ureq";
+      version = "3.4.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/ureq/3.4.2/download";
+        sha256 = "1xr03qvr4lrkczzssbgb10987mpl22kw7z2zrvgr5vxfryy8kx7s";
+        name = "ureq-3.4.2.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    ureq_proto_0_6_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "ureq-proto";
+      version = "0.6.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/ureq-proto/0.6.4/download";
+        sha256 = "0z06nb3739in7disz8c54h51bpyn5cymwh7aifyimpqzl48yfjaq";
+        name = "ureq-proto-0.6.4.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    url_2_5_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "url";
+      version = "2.5.8";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/url/2.5.8/download";
+        sha256 = "0mxf9bv2wgcnw4x6fwc4z95qk8xarswav6scslcq3l3jib7y4si3";
+        name = "url-2.5.8.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    utf8_zero_0_8_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "utf8-zero";
+      version = "0.8.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/utf8-zero/0.8.1/download";
+        sha256 = "0fqkq3y87i8z84wmjhv6f3n2bzjk76424yf1z7r5ynqrnyj4zlns";
+        name = "utf8-zero-0.8.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    utf8_iter_1_0_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "utf8_iter";
+      version = "1.0.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/utf8_iter/1.0.4/download";
+        sha256 = "1s4pmq90x24qy2ifbfgn1f76fsr009yjvs00iwnz9rn7pal2ar2k";
+        name = "utf8_iter-1.0.4.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    uuid_1_28_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "uuid";
+      version = "1.28.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/uuid/1.28.0/download";
+        sha256 = "06gh4nvd9ijgsmdcgb5w6iszp5zb1h9d0kg5p19k2mmaykqvaaqm";
+        name = "uuid-1.28.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    vcpkg_0_2_15_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "vcpkg";
+      version = "0.2.15";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/vcpkg/0.2.15/download";
+        sha256 = "1np6fl61qr7qzx52csyf4hcq4ghdf6rbz8cy3z5bplsjg9lf6vn5";
+        name = "vcpkg-0.2.15.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    version_compare_0_2_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "version-compare";
+      version = "0.2.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/version-compare/0.2.1/download";
+        sha256 = "0pmn4hwf30cs8iq4wwjmzqyrz6f6yi6a0scysal6rdq6v04zq3gn";
+        name = "version-compare-0.2.1.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    version_check_0_9_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "version_check";
+      version = "0.9.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/version_check/0.9.5/download";
+        sha256 = "19q311nfgckxkqi54vlwffkk54rc6csydhyvkhz1bwpcs2x5j1mm";
+        name = "version_check-0.9.5.tar.gz";
+      };
+      inherit dependencies features release verbose;
+    };
+    walkdir_2_5_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "walkdir";
+      version = "2.5.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/walkdir/2.5.0/download";
+        sha256 = "15lpam3bmfv3klfp9ff8li4syh06wc616h8nbqbm7pdl0i539nlq";
+        name = "walkdir-2.5.0.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    wasm_encoder_0_202_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasm-encoder";
+      version = "0.202.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasm-encoder/0.202.0/download";
+        sha256 = "1v1lv35ir0xxxjg8zxrkjpwbpjs51ss61wzrg44m2j0vbzsvaqnh";
+        name = "wasm-encoder-0.202.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasm_encoder_0_261_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasm-encoder";
+      version = "0.261.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasm-encoder/0.261.0/download";
+        sha256 = "1iv5imn31r3ybym1a085i8ghmw2g6j3zs77yb0pbjdrb0ibvq08l";
+        name = "wasm-encoder-0.261.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    wasmparser_0_202_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmparser";
+      version = "0.202.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmparser/0.202.0/download";
+        sha256 = "0xqldbyy54hjhjba19i1xxvnlsjh6rvypbx1xh0qq4hn08xzk7d0";
+        name = "wasmparser-0.202.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmparser_0_261_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmparser";
+      version = "0.261.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmparser/0.261.0/download";
+        sha256 = "0j3wn7nwmccjmxm9c4nz1cgamj8v27l40cmhdv87az5mk9w4q2qx";
+        name = "wasmparser-0.261.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    wasmprinter_0_202_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmprinter";
+      version = "0.202.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmprinter/0.202.0/download";
+        sha256 = "0g34fki6pypfyimy8xmlzf67vwqfdnglsxd23rlrcz4csvav78fr";
+        name = "wasmprinter-0.202.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime/20.0.2/download";
+        sha256 = "1hv4s65kmfyxaj9c1s97dqxh775i00x5cwg8vpyww3w1f6v5cqx2";
+        name = "wasmtime-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_asm_macros_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-asm-macros";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-asm-macros/20.0.2/download";
+        sha256 = "0w5xrmx047kgdn5rkf34jvnlj1ifmjg3n2hpnnby3qniwmgdas1a";
+        name = "wasmtime-asm-macros-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_cache_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-cache";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-cache/20.0.2/download";
+        sha256 = "1dak4qpfghihvz301559ayqv1yhkg7avx1h7h6ns414bvkpab20b";
+        name = "wasmtime-cache-20.0.2.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_component_macro_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-component-macro";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-component-macro/20.0.2/download";
+        sha256 = "0vnzyffm8iwk78zqwappaybwj0blyc41lhz1n0anzd39js296y90";
+        name = "wasmtime-component-macro-20.0.2.tar.gz";
+      };
+      procMacro = true;
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_component_util_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-component-util";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-component-util/20.0.2/download";
+        sha256 = "1hxx7j4pmmim7f80ck6imsh2bjw86l4n1hv09ldiy2x4gxd10yir";
+        name = "wasmtime-component-util-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_cranelift_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-cranelift";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-cranelift/20.0.2/download";
+        sha256 = "17d2m4nlhdx8h2pkdimaq19q14dsnc9y8p9hwfgzz6lnh8l2mlf2";
+        name = "wasmtime-cranelift-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_environ_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-environ";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-environ/20.0.2/download";
+        sha256 = "1gvayqp6kdcj98b22q5kh94v2lfwr2w1kcm1q84v5237478mnxf1";
+        name = "wasmtime-environ-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_fiber_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-fiber";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-fiber/20.0.2/download";
+        sha256 = "09dayr39pl4lmcvsamnyxskw1q12zg1cmb6s57rpdyjdk10ynfi6";
+        name = "wasmtime-fiber-20.0.2.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_jit_debug_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-jit-debug";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-jit-debug/20.0.2/download";
+        sha256 = "1l8g5v81nv0nnvxzw89a24zgw44h1211rq4lcb1i5ffhss9m0d6l";
+        name = "wasmtime-jit-debug-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_jit_icache_coherence_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-jit-icache-coherence";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-jit-icache-coherence/20.0.2/download";
+        sha256 = "1xr761ab5151byjx0x50qwysqxailifg8ksrblgyvxkxhhch8rlk";
+        name = "wasmtime-jit-icache-coherence-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_runtime_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-runtime";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-runtime/20.0.2/download";
+        sha256 = "1pzbcxmrdik325r6zlncn28cqncg8y8vrkjx7f766wcq40szv7k7";
+        name = "wasmtime-runtime-20.0.2.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_slab_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-slab";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-slab/20.0.2/download";
+        sha256 = "0c5l5dk7wr9zysppxdlxdgimmr1v5dw1f64xyz4iwvpypjkbwwz4";
+        name = "wasmtime-slab-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_types_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-types";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-types/20.0.2/download";
+        sha256 = "0v11i4swiikxh4k3vv5n60h2mgm3111j4k21x79bwc9xll0aznp1";
+        name = "wasmtime-types-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_versioned_export_macros_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-versioned-export-macros";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-versioned-export-macros/20.0.2/download";
+        sha256 = "0s92nla5mz7gnhnd6yxcsbxh451xlaybf7728pwf5x9masc4syqn";
+        name = "wasmtime-versioned-export-macros-20.0.2.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_wasi_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-wasi";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-wasi/20.0.2/download";
+        sha256 = "10p9vbw13p0fgyflf09bkmk8mb3fvapfgjp384hclx68hxd39r96";
+        name = "wasmtime-wasi-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_winch_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-winch";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-winch/20.0.2/download";
+        sha256 = "00ylqaxklmdj276h145vffs8y2f2903zvjrvp8kd4b4p9fyxc9cl";
+        name = "wasmtime-winch-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wasmtime_wit_bindgen_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wasmtime-wit-bindgen";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wasmtime-wit-bindgen/20.0.2/download";
+        sha256 = "1kksg49fq9ynpm7s6h696fi0xvdc6b7cvh5ywvakbll812k9dpds";
+        name = "wasmtime-wit-bindgen-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wast_35_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wast";
+      version = "35.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wast/35.0.2/download";
+        sha256 = "18v1xf6s3w7k7m5mf1v3d0l4yfvx2dy3lib8rhvyrrw506l3yjwg";
+        name = "wast-35.0.2.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    wast_261_0_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wast";
+      version = "261.0.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wast/261.0.0/download";
+        sha256 = "1w4rsdvknpzrlkrza9zpffpk1iknrrs6fg66svqf030rp30rbx4d";
+        name = "wast-261.0.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    wat_1_261_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wat";
+      version = "1.261.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wat/1.261.0/download";
+        sha256 = "1im3nl49avx5gf1vh4d4qsw4h6ippla16477fmf06inyqlcym7w8";
+        name = "wat-1.261.0.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    webpki_roots_1_0_9_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "webpki-roots";
+      version = "1.0.9";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/webpki-roots/1.0.9/download";
+        sha256 = "0cw2z71c0ndh51n92fcvwlzwghgzb7gis512cj3qv5q1m653r5pf";
+        name = "webpki-roots-1.0.9.tar.gz";
+      };
+      edition = "2021";
+      crateRenames = { "rustls-pki-types-1.15.1" = "pki_types"; };
+      inherit dependencies features release verbose;
+    };
+    whoami_2_1_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "whoami";
+      version = "2.1.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/whoami/2.1.3/download";
+        sha256 = "071j2bgp2sy14gqi6rshqlg8smsjqiv1s0aps57hybq2dvijmknl";
+        name = "whoami-2.1.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wiggle_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wiggle";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wiggle/20.0.2/download";
+        sha256 = "1nn321pbqv7yvw708n21iqfia7610781q39r5bnkz69xnzv055g1";
+        name = "wiggle-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wiggle_generate_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wiggle-generate";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wiggle-generate/20.0.2/download";
+        sha256 = "1jzg8adpbm075xi4hzxcy9jd0h4vyaaihq3gz1ygr6vpgmam2vaf";
+        name = "wiggle-generate-20.0.2.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wiggle_macro_20_0_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wiggle-macro";
+      version = "20.0.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wiggle-macro/20.0.2/download";
+        sha256 = "0v9qapmbainh3lbj98nqqppyx2swyxibj0391dlp9jhpkxjmws4a";
+        name = "wiggle-macro-20.0.2.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    winch_codegen_0_18_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "winch-codegen";
+      version = "0.18.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/winch-codegen/0.18.2/download";
+        sha256 = "16in5l9x9pzh2r7lxassx0frvzh6y53w4v457ifqf9lc0kam9m8z";
+        name = "winch-codegen-0.18.2.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    winnow_0_7_15_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "winnow";
+      version = "0.7.15";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/winnow/0.7.15/download";
+        sha256 = "0prp40jjgybv6kxnczslc8cy7bmzm1krmk2wwgzrx17bl2nrlfby";
+        name = "winnow-0.7.15.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    winnow_1_0_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "winnow";
+      version = "1.0.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/winnow/1.0.4/download";
+        sha256 = "0wd8aifazzjzd2wcgi003x2w0gdynzwd8zgr2h501a6c6387aplx";
+        name = "winnow-1.0.4.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    wit_parser_0_202_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "wit-parser";
+      version = "0.202.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/wit-parser/0.202.0/download";
+        sha256 = "0v0104dknb4g3wzr5bwkp6ln0r00dqyphl93ly61kch0120l7a6p";
+        name = "wit-parser-0.202.0.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    witx_0_9_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "witx";
+      version = "0.9.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/witx/0.9.1/download";
+        sha256 = "1haxdz7jsggq1rgyhbn58fdjifxkf0rss7kdzh6mhscdhjdadcdx";
+        name = "witx-0.9.1.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    writeable_0_6_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "writeable";
+      version = "0.6.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/writeable/0.6.4/download";
+        sha256 = "1s4my81i7q24a1xy9jx6niax70f92c1ryh6p0ysp9i7c7xy88k62";
+        name = "writeable-0.6.4.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    yoke_0_8_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "yoke";
+      version = "0.8.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/yoke/0.8.3/download";
+        sha256 = "1gjfjyvv4jfz01k4jd5y36g3a906c02972fa19v8f1m2hdasxi2f";
+        name = "yoke-0.8.3.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    yoke_derive_0_8_4_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "yoke-derive";
+      version = "0.8.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/yoke-derive/0.8.4/download";
+        sha256 = "0nh75ag7l8x4pfsrfwfwlkw4rp73cdvj4hvfxsngjgkwm40xmidb";
+        name = "yoke-derive-0.8.4.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    zerocopy_0_8_62_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zerocopy";
+      version = "0.8.62";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zerocopy/0.8.62/download";
+        sha256 = "0fiqrzhr9rp2ly9pzz63ixgm8g7hxfqf9vxg26krybjd28vbdsbr";
+        name = "zerocopy-0.8.62.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    zerofrom_0_1_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zerofrom";
+      version = "0.1.8";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zerofrom/0.1.8/download";
+        sha256 = "1478m9hxj956lld7sx6pmw4bls0sfif4gvyggrd07s2fizafmzji";
+        name = "zerofrom-0.1.8.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    zerofrom_derive_0_1_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zerofrom-derive";
+      version = "0.1.8";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zerofrom-derive/0.1.8/download";
+        sha256 = "19jdj1057hsfw3717zqfa22n80gh0ifvfg1ym06k6hads8m0wviq";
+        name = "zerofrom-derive-0.1.8.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    zeroize_1_9_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zeroize";
+      version = "1.9.1";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zeroize/1.9.1/download";
+        sha256 = "1kqjcjwjfznmk1il13xqv43cj1dfqg95322kwfhyy97b0k3g8bxn";
+        name = "zeroize-1.9.1.tar.gz";
+      };
+      edition = "2024";
+      inherit dependencies features release verbose;
+    };
+    zeromq_src_0_2_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zeromq-src";
+      version = "0.2.6+4.3.4";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zeromq-src/0.2.6+4.3.4/download";
+        sha256 = "01w3jgyacb477b3ah54isdwhrb5gn235bg45algdh2cyvk347r8m";
+        name = "zeromq-src-0.2.6+4.3.4.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    zerotrie_0_2_5_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zerotrie";
+      version = "0.2.5";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zerotrie/0.2.5/download";
+        sha256 = "16qiqd2p02zp2ls1qmnndjjh63rv7p8zswb16xr29mfrixai3bgn";
+        name = "zerotrie-0.2.5.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    zerovec_0_11_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zerovec";
+      version = "0.11.8";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zerovec/0.11.8/download";
+        sha256 = "18ff1yzrq1b6f4i3mlwin8pcrjxbf2bba1z7gxgjin0vmq1vsfbz";
+        name = "zerovec-0.11.8.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    zerovec_derive_0_11_6_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zerovec-derive";
+      version = "0.11.6";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zerovec-derive/0.11.6/download";
+        sha256 = "15g38labc8qpmilxc6qii7aa7ky83idsbwqdmb5a1di8hb3vgbrf";
+        name = "zerovec-derive-0.11.6.tar.gz";
+      };
+      procMacro = true;
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    zlib_rs_0_6_8_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zlib-rs";
+      version = "0.6.8";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zlib-rs/0.6.8/download";
+        sha256 = "0h9zr9kl6cz8bdrl7xxmpj3mfx9wwwjbdrhkijywmrkjf1cr2srp";
+        name = "zlib-rs-0.6.8.tar.gz";
+      };
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    zmij_1_0_23_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zmij";
+      version = "1.0.23";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zmij/1.0.23/download";
+        sha256 = "06njjakqyxn40wxn8w7bzjlvxafvpz6yad8zbdzxr2n6b19ll89j";
+        name = "zmij-1.0.23.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2021";
+      inherit dependencies features release verbose;
+    };
+    zmq_0_10_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zmq";
+      version = "0.10.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zmq/0.10.0/download";
+        sha256 = "12ykq50pq4dikhw9jvxj6dxh049jl6z4am7zn8rv23j64w0nhf6x";
+        name = "zmq-0.10.0.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    zmq_sys_0_12_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zmq-sys";
+      version = "0.12.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zmq-sys/0.12.0/download";
+        sha256 = "0hf6pczvn2l156y3r9r9c98jj7kal5f3grmjpl8bsk3py019wby0";
+        name = "zmq-sys-0.12.0.tar.gz";
+      };
+      build = "build/main.rs";
+      inherit dependencies features release verbose;
+    };
+    zstd_0_13_3_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zstd";
+      version = "0.13.3";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zstd/0.13.3/download";
+        sha256 = "0iycxqjlb1sg0f7srj277s8qfmvz4vq4is6wjcyz1dxx0hbdjirs";
+        name = "zstd-0.13.3.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    zstd_0_14_2_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zstd";
+      version = "0.14.2";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zstd/0.14.2/download";
+        sha256 = "0qf5dg4sg4fsvswd1kkgs77ymvrcvyknfwkq3nfl2jd2in5djnfw";
+        name = "zstd-0.14.2.tar.gz";
+      };
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    zstd_safe_7_3_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zstd-safe";
+      version = "7.3.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zstd-safe/7.3.0/download";
+        sha256 = "1166w61rp5qxiiiyxl4fkxjck48kjw2s664ppjslllibp1d0bm8r";
+        name = "zstd-safe-7.3.0.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    zstd_safe_8_1_0_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zstd-safe";
+      version = "8.1.0";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zstd-safe/8.1.0/download";
+        sha256 = "0q89xhkq7f16ddgprvjrih545p1sxi8iwcfi5h9yqmksqd92b2hc";
+        name = "zstd-safe-8.1.0.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+    zstd_sys_2_1_1_ = { dependencies?[], features?[] }: build-rust-package {
+      crateName = "zstd-sys";
+      version = "2.1.1+zstd.1.5.7";
+      fractalType = "crate";
+      src = fetchzip {
+        url = "https://crates.io/api/v1/crates/zstd-sys/2.1.1+zstd.1.5.7/download";
+        sha256 = "0cvmh27lr644z7d1q2rzlkn4akh9jriz1539n2kn4ham421vham2";
+        name = "zstd-sys-2.1.1+zstd.1.5.7.tar.gz";
+      };
+      build = "build.rs";
+      edition = "2018";
+      inherit dependencies features release verbose;
+    };
+
+in
+rec {
+  addr2line_0_21_0 = addr2line_0_21_0_ {
+    dependencies = [ gimli_0_28_1 ];
+  };
+  adler2_2_0_1 = adler2_2_0_1_ {};
+  ahash_0_8_12 = ahash_0_8_12_ {
+    dependencies = [ cfg_if_1_0_5 once_cell_1_21_4 version_check_0_9_5 zerocopy_0_8_62 ];
+  };
+  aho_corasick_1_1_5 = aho_corasick_1_1_5_ {
+    dependencies = [ memchr_2_8_3 ];
+    features = [ "perf-literal" "std" ];
+  };
+  all_crates_1_1_1 = all_crates_1_1_1_ {
+    dependencies = [ blake2_0_11_0 capnp_0_8_17 capnpc_0_8_9 chrono_0_4_45 cron_0_17_0 crossbeam_channel_0_5_17 csv_1_4_0 flate2_1_1_10 glob_0_3_4 hmac_0_13_0 libc_0_2_190 log_0_4_34 nom_3_2_1 notify_8_2_0 postgres_0_19_14 pyo3_0_29_3 r2d2_0_8_10 r2d2_postgres_0_18_2 regex_1_13_1 rumqttc_0_25_1 rusqlite_0_40_2 rustfbp_0_3_34 rustfbp_macros_0_3_34 semver_1_0_28 serde_1_0_229 serde_derive_1_0_229 serde_json_1_0_154 sha2_0_11_0 tiny_http_0_12_0 tokio_1_53_2 toml_1_1_8 tungstenite_0_30_0 ureq_3_4_2 wasmtime_20_0_2 wasmtime_wasi_20_0_2 zmq_0_10_0 zstd_0_14_2 ];
+  };
+  ambient_authority_0_0_2 = ambient_authority_0_0_2_ {};
+  anyhow_1_0_104 = anyhow_1_0_104_ {
+    features = [ "default" "std" ];
+  };
+  arbitrary_1_5_0 = arbitrary_1_5_0_ {};
+  ascii_1_1_0 = ascii_1_1_0_ {
+    features = [ "alloc" "default" "std" ];
+  };
+  async_trait_0_1_92 = async_trait_0_1_92_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_3_0_8 ];
+  };
+  autocfg_1_5_1 = autocfg_1_5_1_ {};
+  aws_lc_rs_1_18_1 = aws_lc_rs_1_18_1_ {
+    dependencies = [ aws_lc_sys_0_45_0 zeroize_1_9_1 ];
+    features = [ "aws-lc-sys" "prebuilt-nasm" ];
+  };
+  aws_lc_sys_0_45_0 = aws_lc_sys_0_45_0_ {
+    dependencies = [ cc_1_8_0 cmake_0_1_58 dunce_1_0_5 fs_extra_1_3_0 pkg_config_0_3_34 ];
+    features = [ "prebuilt-nasm" ];
+  };
+  base64_0_21_7 = base64_0_21_7_ {
+    features = [ "alloc" "default" "std" ];
+  };
+  base64_0_22_1 = base64_0_22_1_ {
+    features = [ "alloc" "default" "std" ];
+  };
+  base64_0_23_1 = base64_0_23_1_ {
+    features = [ "alloc" "std" ];
+  };
+  bincode_1_3_3 = bincode_1_3_3_ {
+    dependencies = [ serde_1_0_229 ];
+  };
+  bitflags_1_3_2 = bitflags_1_3_2_ {
+    features = [ "default" ];
+  };
+  bitflags_2_13_2 = bitflags_2_13_2_ {
+    features = [ "std" ];
+  };
+  blake2_0_11_0 = blake2_0_11_0_ {
+    dependencies = [ digest_0_11_3 ];
+    features = [ "alloc" "default" ];
+  };
+  block_buffer_0_10_4 = block_buffer_0_10_4_ {
+    dependencies = [ generic_array_0_14_7 ];
+  };
+  block_buffer_0_12_1 = block_buffer_0_12_1_ {
+    dependencies = [ hybrid_array_0_4_15 ];
+  };
+  bumpalo_3_20_3 = bumpalo_3_20_3_ {
+    features = [ "default" ];
+  };
+  byteorder_1_5_0 = byteorder_1_5_0_ {
+    features = [ "default" "std" ];
+  };
+  bytes_1_12_1 = bytes_1_12_1_ {
+    features = [ "default" "std" ];
+  };
+  cap_fs_ext_3_4_6 = cap_fs_ext_3_4_6_ {
+    dependencies = [ cap_primitives_3_4_6 cap_std_3_4_6 io_lifetimes_2_0_4 ];
+    features = [ "cap-std" "default" "std" ];
+  };
+  cap_net_ext_3_4_6 = cap_net_ext_3_4_6_ {
+    dependencies = [ cap_primitives_3_4_6 cap_std_3_4_6 rustix_1_1_5 smallvec_1_16_3 ];
+  };
+  cap_primitives_3_4_6 = cap_primitives_3_4_6_ {
+    dependencies = [ ambient_authority_0_0_2 fs_set_times_0_20_3 io_extras_0_18_4 io_lifetimes_2_0_4 ipnet_2_12_2 maybe_owned_0_3_4 rustix_1_1_5 rustix_linux_procfs_0_1_1 ];
+  };
+  cap_rand_3_4_6 = cap_rand_3_4_6_ {
+    dependencies = [ ambient_authority_0_0_2 rand_0_8_8 ];
+    features = [ "default" "small_rng" ];
+  };
+  cap_std_3_4_6 = cap_std_3_4_6_ {
+    dependencies = [ cap_primitives_3_4_6 io_extras_0_18_4 io_lifetimes_2_0_4 rustix_1_1_5 ];
+    features = [ "default" ];
+  };
+  cap_time_ext_3_4_6 = cap_time_ext_3_4_6_ {
+    dependencies = [ ambient_authority_0_0_2 cap_primitives_3_4_6 iana_time_zone_0_1_65 rustix_1_1_5 ];
+  };
+  capnp_0_8_17 = capnp_0_8_17_ {
+    dependencies = [ byteorder_1_5_0 ];
+  };
+  capnpc_0_8_9 = capnpc_0_8_9_ {
+    dependencies = [ capnp_0_8_17 ];
+  };
+  cc_1_8_0 = cc_1_8_0_ {
+    dependencies = [ find_msvc_tools_0_1_14 jobserver_0_1_35 libc_0_2_190 shlex_2_0_1 ];
+    features = [ "parallel" ];
+  };
+  cfg_expr_0_15_8 = cfg_expr_0_15_8_ {
+    dependencies = [ smallvec_1_16_3 target_lexicon_0_12_16 ];
+    features = [ "default" "target-lexicon" "targets" ];
+  };
+  cfg_if_1_0_5 = cfg_if_1_0_5_ {};
+  chacha20_0_10_2 = chacha20_0_10_2_ {
+    dependencies = [ cfg_if_1_0_5 cpufeatures_0_3_1 rand_core_0_10_1 ];
+    features = [ "rng" ];
+  };
+  chrono_0_4_45 = chrono_0_4_45_ {
+    dependencies = [ iana_time_zone_0_1_65 num_traits_0_2_19 ];
+    features = [ "alloc" "clock" "default" "iana-time-zone" "js-sys" "now" "oldtime" "std" "wasm-bindgen" "wasmbind" "winapi" "windows-link" ];
+  };
+  chunked_transfer_1_5_0 = chunked_transfer_1_5_0_ {};
+  cmake_0_1_58 = cmake_0_1_58_ {
+    dependencies = [ cc_1_8_0 ];
+  };
+  cmov_0_5_4 = cmov_0_5_4_ {};
+  const_oid_0_10_2 = const_oid_0_10_2_ {};
+  core_detect_1_0_0 = core_detect_1_0_0_ {
+    features = [ "default" ];
+  };
+  cpp_demangle_0_4_5 = cpp_demangle_0_4_5_ {
+    dependencies = [ cfg_if_1_0_5 ];
+    features = [ "alloc" "default" "std" ];
+  };
+  cpufeatures_0_2_17 = cpufeatures_0_2_17_ {};
+  cpufeatures_0_3_1 = cpufeatures_0_3_1_ {};
+  cranelift_bforest_0_107_2 = cranelift_bforest_0_107_2_ {
+    dependencies = [ cranelift_entity_0_107_2 ];
+  };
+  cranelift_codegen_0_107_2 = cranelift_codegen_0_107_2_ {
+    dependencies = [ bumpalo_3_20_3 cranelift_bforest_0_107_2 cranelift_codegen_meta_0_107_2 cranelift_codegen_shared_0_107_2 cranelift_control_0_107_2 cranelift_entity_0_107_2 cranelift_isle_0_107_2 gimli_0_28_1 hashbrown_0_14_5 log_0_4_34 regalloc2_0_9_3 smallvec_1_16_3 target_lexicon_0_12_16 ];
+    features = [ "gimli" "host-arch" "std" "trace-log" "unwind" ];
+  };
+  cranelift_codegen_meta_0_107_2 = cranelift_codegen_meta_0_107_2_ {
+    dependencies = [ cranelift_codegen_shared_0_107_2 ];
+  };
+  cranelift_codegen_shared_0_107_2 = cranelift_codegen_shared_0_107_2_ {};
+  cranelift_control_0_107_2 = cranelift_control_0_107_2_ {
+    dependencies = [ arbitrary_1_5_0 ];
+  };
+  cranelift_entity_0_107_2 = cranelift_entity_0_107_2_ {
+    dependencies = [ serde_1_0_229 serde_derive_1_0_229 ];
+    features = [ "enable-serde" "serde" "serde_derive" ];
+  };
+  cranelift_frontend_0_107_2 = cranelift_frontend_0_107_2_ {
+    dependencies = [ cranelift_codegen_0_107_2 log_0_4_34 smallvec_1_16_3 target_lexicon_0_12_16 ];
+    features = [ "default" "std" ];
+  };
+  cranelift_isle_0_107_2 = cranelift_isle_0_107_2_ {
+    features = [ "default" ];
+  };
+  cranelift_native_0_107_2 = cranelift_native_0_107_2_ {
+    dependencies = [ cranelift_codegen_0_107_2 target_lexicon_0_12_16 ];
+    features = [ "default" "std" ];
+  };
+  cranelift_wasm_0_107_2 = cranelift_wasm_0_107_2_ {
+    dependencies = [ cranelift_codegen_0_107_2 cranelift_entity_0_107_2 cranelift_frontend_0_107_2 itertools_0_12_1 log_0_4_34 smallvec_1_16_3 wasmparser_0_202_0 wasmtime_types_20_0_2 ];
+    features = [ "default" "std" ];
+  };
+  crc32fast_1_5_2 = crc32fast_1_5_2_ {
+    dependencies = [ cfg_if_1_0_5 ];
+    features = [ "std" ];
+  };
+  cron_0_17_0 = cron_0_17_0_ {
+    dependencies = [ chrono_0_4_45 once_cell_1_21_4 phf_0_11_3 winnow_0_7_15 ];
+  };
+  crossbeam_channel_0_5_17 = crossbeam_channel_0_5_17_ {
+    dependencies = [ crossbeam_utils_0_8_23 ];
+    features = [ "default" "std" ];
+  };
+  crossbeam_deque_0_8_8 = crossbeam_deque_0_8_8_ {
+    dependencies = [ crossbeam_epoch_0_9_21 crossbeam_utils_0_8_23 ];
+    features = [ "default" "std" ];
+  };
+  crossbeam_epoch_0_9_21 = crossbeam_epoch_0_9_21_ {
+    dependencies = [ crossbeam_utils_0_8_23 ];
+    features = [ "alloc" "std" ];
+  };
+  crossbeam_utils_0_8_23 = crossbeam_utils_0_8_23_ {
+    features = [ "default" "std" ];
+  };
+  crypto_common_0_1_7 = crypto_common_0_1_7_ {
+    dependencies = [ generic_array_0_14_7 typenum_1_20_1 ];
+    features = [ "std" ];
+  };
+  crypto_common_0_2_2 = crypto_common_0_2_2_ {
+    dependencies = [ hybrid_array_0_4_15 ];
+  };
+  csv_1_4_0 = csv_1_4_0_ {
+    dependencies = [ csv_core_0_1_13 itoa_1_0_18 ryu_1_0_23 serde_core_1_0_229 ];
+  };
+  csv_core_0_1_13 = csv_core_0_1_13_ {
+    dependencies = [ memchr_2_8_3 ];
+    features = [ "default" ];
+  };
+  ctutils_0_4_3 = ctutils_0_4_3_ {
+    dependencies = [ cmov_0_5_4 ];
+  };
+  data_encoding_2_11_1 = data_encoding_2_11_1_ {
+    features = [ "alloc" "default" "std" ];
+  };
+  debugid_0_8_0 = debugid_0_8_0_ {
+    dependencies = [ uuid_1_28_0 ];
+  };
+  digest_0_10_7 = digest_0_10_7_ {
+    dependencies = [ block_buffer_0_10_4 crypto_common_0_1_7 ];
+    features = [ "alloc" "block-buffer" "core-api" "default" "std" ];
+  };
+  digest_0_11_3 = digest_0_11_3_ {
+    dependencies = [ block_buffer_0_12_1 const_oid_0_10_2 crypto_common_0_2_2 ctutils_0_4_3 ];
+    features = [ "alloc" "block-api" "default" "mac" "oid" ];
+  };
+  dircpy_0_3_21 = dircpy_0_3_21_ {
+    dependencies = [ log_0_4_34 walkdir_2_5_0 ];
+    features = [ "default" ];
+  };
+  directories_next_2_0_0 = directories_next_2_0_0_ {
+    dependencies = [ cfg_if_1_0_5 dirs_sys_next_0_1_2 ];
+  };
+  dirs_4_0_0 = dirs_4_0_0_ {
+    dependencies = [ dirs_sys_0_3_7 ];
+  };
+  dirs_sys_0_3_7 = dirs_sys_0_3_7_ {
+    dependencies = [ libc_0_2_190 ];
+  };
+  dirs_sys_next_0_1_2 = dirs_sys_next_0_1_2_ {
+    dependencies = [ libc_0_2_190 ];
+  };
+  displaydoc_0_2_7 = displaydoc_0_2_7_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_3_0_8 ];
+  };
+  dunce_1_0_5 = dunce_1_0_5_ {};
+  either_1_19_0 = either_1_19_0_ {
+    features = [ "std" "use_std" ];
+  };
+  encoding_rs_0_8_42 = encoding_rs_0_8_42_ {
+    dependencies = [ cfg_if_1_0_5 core_detect_1_0_0 multiversion_no_op_1_0_0 rustversion_1_0_23 scopeguard_1_2_0 simdutf8_0_1_5 ];
+    features = [ "alloc" "default" ];
+  };
+  equivalent_1_0_2 = equivalent_1_0_2_ {};
+  errno_0_3_14 = errno_0_3_14_ {
+    dependencies = [ libc_0_2_190 ];
+    features = [ "std" ];
+  };
+  fallible_iterator_0_2_0 = fallible_iterator_0_2_0_ {
+    features = [ "default" "std" ];
+  };
+  fallible_iterator_0_3_0 = fallible_iterator_0_3_0_ {
+    features = [ "alloc" "default" "std" ];
+  };
+  fallible_streaming_iterator_0_1_9 = fallible_streaming_iterator_0_1_9_ {};
+  find_msvc_tools_0_1_14 = find_msvc_tools_0_1_14_ {};
+  fixedbitset_0_5_7 = fixedbitset_0_5_7_ {
+    features = [ "default" "std" ];
+  };
+  flate2_1_1_10 = flate2_1_1_10_ {
+    dependencies = [ crc32fast_1_5_2 miniz_oxide_0_9_1 zlib_rs_0_6_8 ];
+    features = [ "any_impl" "default" "miniz_oxide" "runtime_detection" "rust_backend" ];
+  };
+  flume_0_11_1 = flume_0_11_1_ {
+    dependencies = [ futures_core_0_3_34 futures_sink_0_3_34 spin_0_9_9 ];
+    features = [ "async" "futures-core" "futures-sink" ];
+  };
+  foldhash_0_2_0 = foldhash_0_2_0_ {};
+  form_urlencoded_1_2_2 = form_urlencoded_1_2_2_ {
+    dependencies = [ percent_encoding_2_3_2 ];
+    features = [ "alloc" "std" ];
+  };
+  fs_set_times_0_20_3 = fs_set_times_0_20_3_ {
+    dependencies = [ io_lifetimes_2_0_4 rustix_1_1_5 ];
+  };
+  fs_extra_1_3_0 = fs_extra_1_3_0_ {};
+  futures_0_3_34 = futures_0_3_34_ {
+    dependencies = [ futures_channel_0_3_34 futures_core_0_3_34 futures_io_0_3_34 futures_sink_0_3_34 futures_task_0_3_34 futures_util_0_3_34 ];
+  };
+  futures_channel_0_3_34 = futures_channel_0_3_34_ {
+    dependencies = [ futures_core_0_3_34 futures_sink_0_3_34 ];
+    features = [ "alloc" "default" "futures-sink" "sink" "std" ];
+  };
+  futures_core_0_3_34 = futures_core_0_3_34_ {
+    features = [ "alloc" "default" "std" ];
+  };
+  futures_io_0_3_34 = futures_io_0_3_34_ {};
+  futures_sink_0_3_34 = futures_sink_0_3_34_ {
+    features = [ "alloc" "default" "std" ];
+  };
+  futures_task_0_3_34 = futures_task_0_3_34_ {
+    features = [ "alloc" "std" ];
+  };
+  futures_util_0_3_34 = futures_util_0_3_34_ {
+    dependencies = [ futures_core_0_3_34 futures_sink_0_3_34 futures_task_0_3_34 pin_project_lite_0_2_17 slab_0_4_12 ];
+    features = [ "alloc" "futures-sink" "sink" "slab" "std" ];
+  };
+  fxhash_0_2_1 = fxhash_0_2_1_ {
+    dependencies = [ byteorder_1_5_0 ];
+  };
+  fxprof_processed_profile_0_6_0 = fxprof_processed_profile_0_6_0_ {
+    dependencies = [ bitflags_2_13_2 debugid_0_8_0 fxhash_0_2_1 serde_1_0_229 serde_json_1_0_154 ];
+  };
+  generic_array_0_14_7 = generic_array_0_14_7_ {
+    dependencies = [ typenum_1_20_1 version_check_0_9_5 ];
+    features = [ "more_lengths" ];
+  };
+  getrandom_0_2_17 = getrandom_0_2_17_ {
+    dependencies = [ cfg_if_1_0_5 libc_0_2_190 ];
+    features = [ "std" ];
+  };
+  getrandom_0_4_3 = getrandom_0_4_3_ {
+    dependencies = [ cfg_if_1_0_5 libc_0_2_190 rand_core_0_10_1 ];
+    features = [ "std" "sys_rng" ];
+  };
+  gimli_0_28_1 = gimli_0_28_1_ {
+    dependencies = [ fallible_iterator_0_3_0 indexmap_2_14_2 stable_deref_trait_1_2_1 ];
+    features = [ "read" "read-core" "std" "write" ];
+  };
+  glob_0_3_4 = glob_0_3_4_ {};
+  hashbrown_0_13_2 = hashbrown_0_13_2_ {
+    dependencies = [ ahash_0_8_12 ];
+    features = [ "ahash" "default" "inline-more" ];
+  };
+  hashbrown_0_14_5 = hashbrown_0_14_5_ {
+    dependencies = [ ahash_0_8_12 ];
+    features = [ "ahash" "raw" ];
+  };
+  hashbrown_0_17_1 = hashbrown_0_17_1_ {
+    dependencies = [ foldhash_0_2_0 ];
+    features = [ "default-hasher" ];
+  };
+  hashlink_0_12_2 = hashlink_0_12_2_ {
+    dependencies = [ hashbrown_0_17_1 ];
+  };
+  heck_0_4_1 = heck_0_4_1_ {
+    features = [ "default" ];
+  };
+  heck_0_5_0 = heck_0_5_0_ {};
+  hmac_0_13_0 = hmac_0_13_0_ {
+    dependencies = [ digest_0_11_3 ];
+  };
+  http_1_5_0 = http_1_5_0_ {
+    dependencies = [ bytes_1_12_1 itoa_1_0_18 ];
+    features = [ "default" "std" ];
+  };
+  httparse_1_10_1 = httparse_1_10_1_ {
+    features = [ "default" "std" ];
+  };
+  httpdate_1_0_3 = httpdate_1_0_3_ {};
+  hybrid_array_0_4_15 = hybrid_array_0_4_15_ {
+    dependencies = [ typenum_1_20_1 ];
+  };
+  iana_time_zone_0_1_65 = iana_time_zone_0_1_65_ {
+    features = [ "fallback" ];
+  };
+  icu_collections_2_3_0 = icu_collections_2_3_0_ {
+    dependencies = [ displaydoc_0_2_7 potential_utf_0_1_6 utf8_iter_1_0_4 yoke_0_8_3 zerofrom_0_1_8 zerovec_0_11_8 ];
+  };
+  icu_locale_core_2_3_0 = icu_locale_core_2_3_0_ {
+    dependencies = [ displaydoc_0_2_7 litemap_0_8_3 tinystr_0_8_4 writeable_0_6_4 zerovec_0_11_8 ];
+    features = [ "zerovec" ];
+  };
+  icu_normalizer_2_3_0 = icu_normalizer_2_3_0_ {
+    dependencies = [ icu_collections_2_3_0 icu_normalizer_data_2_3_0 icu_properties_2_3_0 icu_provider_2_3_1 smallvec_1_16_3 zerovec_0_11_8 ];
+    features = [ "compiled_data" ];
+  };
+  icu_normalizer_data_2_3_0 = icu_normalizer_data_2_3_0_ {};
+  icu_properties_2_3_0 = icu_properties_2_3_0_ {
+    dependencies = [ displaydoc_0_2_7 icu_collections_2_3_0 icu_locale_core_2_3_0 icu_properties_data_2_3_0 icu_provider_2_3_1 zerotrie_0_2_5 zerovec_0_11_8 ];
+    features = [ "compiled_data" ];
+  };
+  icu_properties_data_2_3_0 = icu_properties_data_2_3_0_ {};
+  icu_provider_2_3_1 = icu_provider_2_3_1_ {
+    dependencies = [ displaydoc_0_2_7 icu_locale_core_2_3_0 writeable_0_6_4 yoke_0_8_3 zerofrom_0_1_8 zerotrie_0_2_5 zerovec_0_11_8 ];
+    features = [ "baked" ];
+  };
+  id_arena_2_3_0 = id_arena_2_3_0_ {
+    features = [ "default" "std" ];
+  };
+  idna_1_1_0 = idna_1_1_0_ {
+    dependencies = [ idna_adapter_1_2_2 smallvec_1_16_3 utf8_iter_1_0_4 ];
+    features = [ "alloc" "compiled_data" "std" ];
+  };
+  idna_adapter_1_2_2 = idna_adapter_1_2_2_ {
+    dependencies = [ icu_normalizer_2_3_0 icu_properties_2_3_0 ];
+    features = [ "compiled_data" ];
+  };
+  indexmap_2_14_2 = indexmap_2_14_2_ {
+    dependencies = [ equivalent_1_0_2 hashbrown_0_17_1 serde_core_1_0_229 ];
+    features = [ "default" "serde" "std" ];
+  };
+  inotify_0_11_5 = inotify_0_11_5_ {
+    dependencies = [ bitflags_2_13_2 inotify_sys_0_1_8 libc_0_2_190 ];
+  };
+  inotify_sys_0_1_8 = inotify_sys_0_1_8_ {
+    dependencies = [ libc_0_2_190 ];
+  };
+  io_extras_0_18_4 = io_extras_0_18_4_ {
+    dependencies = [ io_lifetimes_2_0_4 ];
+    features = [ "default" ];
+  };
+  io_lifetimes_2_0_4 = io_lifetimes_2_0_4_ {
+    features = [ "default" ];
+  };
+  ipnet_2_12_2 = ipnet_2_12_2_ {
+    features = [ "default" "std" ];
+  };
+  itertools_0_12_1 = itertools_0_12_1_ {
+    dependencies = [ either_1_19_0 ];
+    features = [ "default" "use_alloc" "use_std" ];
+  };
+  itoa_1_0_18 = itoa_1_0_18_ {};
+  ittapi_0_4_0 = ittapi_0_4_0_ {
+    dependencies = [ anyhow_1_0_104 ittapi_sys_0_4_0 log_0_4_34 ];
+  };
+  ittapi_sys_0_4_0 = ittapi_sys_0_4_0_ {
+    dependencies = [ cc_1_8_0 ];
+  };
+  jobserver_0_1_35 = jobserver_0_1_35_ {
+    dependencies = [ libc_0_2_190 ];
+  };
+  lazy_static_1_5_1 = lazy_static_1_5_1_ {};
+  leb128_0_2_7 = leb128_0_2_7_ {};
+  leb128fmt_0_1_0 = leb128fmt_0_1_0_ {};
+  libc_0_2_190 = libc_0_2_190_ {
+    features = [ "default" "extra_traits" "std" "use_std" ];
+  };
+  libloading_0_4_3 = libloading_0_4_3_ {
+    dependencies = [ lazy_static_1_5_1 ];
+  };
+  libsqlite3_sys_0_38_2 = libsqlite3_sys_0_38_2_ {
+    dependencies = [ pkg_config_0_3_34 vcpkg_0_2_15 ];
+    features = [ "default" "min_sqlite_version_3_34_1" "pkg-config" "vcpkg" ];
+  };
+  linux_raw_sys_0_4_15 = linux_raw_sys_0_4_15_ {
+    features = [ "elf" "errno" "general" "if_ether" "ioctl" "net" "netlink" "no_std" "prctl" "xdp" ];
+  };
+  linux_raw_sys_0_12_1 = linux_raw_sys_0_12_1_ {
+    features = [ "auxvec" "elf" "errno" "general" "if_ether" "ioctl" "net" "netlink" "no_std" "prctl" "xdp" ];
+  };
+  litemap_0_8_3 = litemap_0_8_3_ {};
+  lock_api_0_4_14 = lock_api_0_4_14_ {
+    dependencies = [ scopeguard_1_2_0 ];
+    features = [ "atomic_usize" "default" ];
+  };
+  log_0_4_34 = log_0_4_34_ {
+    features = [ "kv" ];
+  };
+  maybe_owned_0_3_4 = maybe_owned_0_3_4_ {};
+  md_5_0_11_0 = md_5_0_11_0_ {
+    dependencies = [ cfg_if_1_0_5 digest_0_11_3 ];
+    features = [ "alloc" "default" "oid" ];
+  };
+  memchr_1_0_2 = memchr_1_0_2_ {
+    dependencies = [ libc_0_2_190 ];
+    features = [ "libc" "use_std" ];
+  };
+  memchr_2_8_3 = memchr_2_8_3_ {
+    features = [ "alloc" "default" "std" ];
+  };
+  memfd_0_6_6 = memfd_0_6_6_ {
+    dependencies = [ rustix_1_1_5 ];
+  };
+  memoffset_0_9_1 = memoffset_0_9_1_ {
+    dependencies = [ autocfg_1_5_1 ];
+    features = [ "default" ];
+  };
+  miniz_oxide_0_9_1 = miniz_oxide_0_9_1_ {
+    dependencies = [ adler2_2_0_1 simd_adler32_0_3_10 ];
+    features = [ "default" "simd" "simd-adler32" "with-alloc" ];
+  };
+  mio_1_2_4 = mio_1_2_4_ {
+    dependencies = [ libc_0_2_190 log_0_4_34 ];
+    features = [ "default" "log" "net" "os-ext" "os-poll" ];
+  };
+  multiversion_no_op_1_0_0 = multiversion_no_op_1_0_0_ {};
+  nom_3_2_1 = nom_3_2_1_ {
+    dependencies = [ memchr_1_0_2 ];
+    features = [ "default" "std" "stream" ];
+  };
+  notify_8_2_0 = notify_8_2_0_ {
+    dependencies = [ inotify_0_11_5 libc_0_2_190 log_0_4_34 mio_1_2_4 notify_types_2_1_0 walkdir_2_5_0 ];
+    features = [ "default" "fsevent-sys" "macos_fsevent" ];
+  };
+  notify_types_2_1_0 = notify_types_2_1_0_ {
+    dependencies = [ bitflags_2_13_2 ];
+  };
+  num_traits_0_2_19 = num_traits_0_2_19_ {
+    dependencies = [ autocfg_1_5_1 ];
+  };
+  object_0_33_0 = object_0_33_0_ {
+    dependencies = [ crc32fast_1_5_2 hashbrown_0_14_5 indexmap_2_14_2 memchr_2_8_3 ];
+    features = [ "coff" "elf" "macho" "pe" "read_core" "std" "write" "write_core" "write_std" "xcoff" ];
+  };
+  once_cell_1_21_4 = once_cell_1_21_4_ {
+    features = [ "alloc" "default" "race" "std" ];
+  };
+  openssl_probe_0_2_1 = openssl_probe_0_2_1_ {};
+  parking_lot_0_12_5 = parking_lot_0_12_5_ {
+    dependencies = [ lock_api_0_4_14 parking_lot_core_0_9_12 ];
+    features = [ "default" ];
+  };
+  parking_lot_core_0_9_12 = parking_lot_core_0_9_12_ {
+    dependencies = [ cfg_if_1_0_5 libc_0_2_190 smallvec_1_16_3 ];
+  };
+  paste_1_0_15 = paste_1_0_15_ {};
+  percent_encoding_2_3_2 = percent_encoding_2_3_2_ {
+    features = [ "alloc" "default" "std" ];
+  };
+  phf_0_11_3 = phf_0_11_3_ {
+    dependencies = [ phf_macros_0_11_3 phf_shared_0_11_3 ];
+    features = [ "default" "macros" "phf_macros" "std" ];
+  };
+  phf_0_13_1 = phf_0_13_1_ {
+    dependencies = [ phf_shared_0_13_1 serde_1_0_229 ];
+    features = [ "default" "std" ];
+  };
+  phf_generator_0_11_3 = phf_generator_0_11_3_ {
+    dependencies = [ phf_shared_0_11_3 rand_0_8_8 ];
+  };
+  phf_macros_0_11_3 = phf_macros_0_11_3_ {
+    dependencies = [ phf_generator_0_11_3 phf_shared_0_11_3 proc_macro2_1_0_107 quote_1_0_47 syn_2_0_119 ];
+  };
+  phf_shared_0_11_3 = phf_shared_0_11_3_ {
+    dependencies = [ siphasher_1_0_4 ];
+    features = [ "std" ];
+  };
+  phf_shared_0_13_1 = phf_shared_0_13_1_ {
+    dependencies = [ siphasher_1_0_4 ];
+    features = [ "std" ];
+  };
+  pin_project_lite_0_2_17 = pin_project_lite_0_2_17_ {};
+  pkg_config_0_3_34 = pkg_config_0_3_34_ {};
+  postgres_0_19_14 = postgres_0_19_14_ {
+    dependencies = [ bytes_1_12_1 fallible_iterator_0_2_0 futures_util_0_3_34 log_0_4_34 tokio_1_53_2 tokio_postgres_0_7_18 ];
+    features = [ "with-serde_json-1" ];
+  };
+  postgres_protocol_0_6_12 = postgres_protocol_0_6_12_ {
+    dependencies = [ base64_0_22_1 byteorder_1_5_0 bytes_1_12_1 fallible_iterator_0_2_0 hmac_0_13_0 md_5_0_11_0 memchr_2_8_3 rand_0_10_3 sha2_0_11_0 stringprep_0_1_5 ];
+    features = [ "default" ];
+  };
+  postgres_types_0_2_14 = postgres_types_0_2_14_ {
+    dependencies = [ bytes_1_12_1 fallible_iterator_0_2_0 postgres_protocol_0_6_12 serde_core_1_0_229 serde_json_1_0_154 ];
+    features = [ "serde-1" "serde_json-1" "with-serde_json-1" ];
+  };
+  potential_utf_0_1_6 = potential_utf_0_1_6_ {
+    dependencies = [ zerovec_0_11_8 ];
+    features = [ "zerovec" ];
+  };
+  ppv_lite86_0_2_21 = ppv_lite86_0_2_21_ {
+    dependencies = [ zerocopy_0_8_62 ];
+    features = [ "simd" "std" ];
+  };
+  proc_macro2_1_0_107 = proc_macro2_1_0_107_ {
+    dependencies = [ unicode_ident_1_0_26 ];
+    features = [ "default" "proc-macro" ];
+  };
+  pyo3_0_29_3 = pyo3_0_29_3_ {
+    dependencies = [ libc_0_2_190 once_cell_1_21_4 pyo3_build_config_0_29_3 pyo3_ffi_0_29_3 pyo3_macros_0_29_3 ];
+    features = [ "default" "macros" "pyo3-macros" ];
+  };
+  pyo3_build_config_0_29_3 = pyo3_build_config_0_29_3_ {
+    dependencies = [ target_lexicon_0_13_5 ];
+    features = [ "default" ];
+  };
+  pyo3_ffi_0_29_3 = pyo3_ffi_0_29_3_ {
+    dependencies = [ libc_0_2_190 pyo3_build_config_0_29_3 ];
+    features = [ "default" ];
+  };
+  pyo3_macros_0_29_3 = pyo3_macros_0_29_3_ {
+    dependencies = [ proc_macro2_1_0_107 pyo3_macros_backend_0_29_3 quote_1_0_47 syn_2_0_119 ];
+  };
+  pyo3_macros_backend_0_29_3 = pyo3_macros_backend_0_29_3_ {
+    dependencies = [ heck_0_5_0 proc_macro2_1_0_107 quote_1_0_47 syn_2_0_119 ];
+  };
+  quote_1_0_47 = quote_1_0_47_ {
+    dependencies = [ proc_macro2_1_0_107 ];
+    features = [ "default" "proc-macro" ];
+  };
+  r2d2_0_8_10 = r2d2_0_8_10_ {
+    dependencies = [ log_0_4_34 parking_lot_0_12_5 scheduled_thread_pool_0_2_7 ];
+  };
+  r2d2_postgres_0_18_2 = r2d2_postgres_0_18_2_ {
+    dependencies = [ postgres_0_19_14 r2d2_0_8_10 ];
+  };
+  rand_0_8_8 = rand_0_8_8_ {
+    dependencies = [ libc_0_2_190 rand_chacha_0_3_1 rand_core_0_6_4 ];
+    features = [ "alloc" "default" "getrandom" "libc" "rand_chacha" "small_rng" "std" "std_rng" ];
+  };
+  rand_0_10_3 = rand_0_10_3_ {
+    dependencies = [ chacha20_0_10_2 getrandom_0_4_3 rand_core_0_10_1 ];
+    features = [ "alloc" "default" "std" "std_rng" "sys_rng" "thread_rng" ];
+  };
+  rand_chacha_0_3_1 = rand_chacha_0_3_1_ {
+    dependencies = [ ppv_lite86_0_2_21 rand_core_0_6_4 ];
+    features = [ "std" ];
+  };
+  rand_core_0_6_4 = rand_core_0_6_4_ {
+    dependencies = [ getrandom_0_2_17 ];
+    features = [ "alloc" "getrandom" "std" ];
+  };
+  rand_core_0_10_1 = rand_core_0_10_1_ {};
+  rayon_1_12_0 = rayon_1_12_0_ {
+    dependencies = [ either_1_19_0 rayon_core_1_13_0 ];
+  };
+  rayon_core_1_13_0 = rayon_core_1_13_0_ {
+    dependencies = [ crossbeam_deque_0_8_8 crossbeam_utils_0_8_23 ];
+  };
+  regalloc2_0_9_3 = regalloc2_0_9_3_ {
+    dependencies = [ hashbrown_0_13_2 log_0_4_34 rustc_hash_1_1_0 slice_group_by_0_3_1 smallvec_1_16_3 ];
+    features = [ "checker" "default" "std" "trace-log" ];
+  };
+  regex_1_13_1 = regex_1_13_1_ {
+    dependencies = [ aho_corasick_1_1_5 memchr_2_8_3 regex_automata_0_4_18 regex_syntax_0_8_11 ];
+    features = [ "default" "perf" "perf-backtrack" "perf-cache" "perf-dfa" "perf-inline" "perf-literal" "perf-onepass" "std" "unicode" "unicode-age" "unicode-bool" "unicode-case" "unicode-gencat" "unicode-perl" "unicode-script" "unicode-segment" ];
+  };
+  regex_automata_0_4_18 = regex_automata_0_4_18_ {
+    dependencies = [ aho_corasick_1_1_5 memchr_2_8_3 regex_syntax_0_8_11 ];
+    features = [ "alloc" "dfa-onepass" "hybrid" "meta" "nfa-backtrack" "nfa-pikevm" "nfa-thompson" "perf-inline" "perf-literal" "perf-literal-multisubstring" "perf-literal-substring" "std" "syntax" "unicode" "unicode-age" "unicode-bool" "unicode-case" "unicode-gencat" "unicode-perl" "unicode-script" "unicode-segment" "unicode-word-boundary" ];
+  };
+  regex_syntax_0_8_11 = regex_syntax_0_8_11_ {
+    features = [ "default" "std" "unicode" "unicode-age" "unicode-bool" "unicode-case" "unicode-gencat" "unicode-perl" "unicode-script" "unicode-segment" ];
+  };
+  ring_0_17_14 = ring_0_17_14_ {
+    dependencies = [ cc_1_8_0 cfg_if_1_0_5 getrandom_0_2_17 untrusted_0_9_0 ];
+    features = [ "alloc" "default" "dev_urandom_fallback" ];
+  };
+  rumqttc_0_25_1 = rumqttc_0_25_1_ {
+    dependencies = [ bytes_1_12_1 fixedbitset_0_5_7 flume_0_11_1 futures_util_0_3_34 log_0_4_34 rustls_native_certs_0_8_4 rustls_pemfile_2_2_0 rustls_webpki_0_102_8 thiserror_2_0_21 tokio_1_53_2 tokio_rustls_0_26_6 tokio_stream_0_1_19 tokio_util_0_7_20 ];
+    features = [ "default" "use-rustls" "use-rustls-no-provider" ];
+  };
+  rusqlite_0_40_2 = rusqlite_0_40_2_ {
+    dependencies = [ bitflags_2_13_2 fallible_iterator_0_3_0 fallible_streaming_iterator_0_1_9 hashlink_0_12_2 libsqlite3_sys_0_38_2 smallvec_1_16_3 ];
+    features = [ "cache" "default" "ffi-sqlite-wasm-rs" "hashlink" ];
+  };
+  rustc_demangle_0_1_28 = rustc_demangle_0_1_28_ {};
+  rustc_hash_1_1_0 = rustc_hash_1_1_0_ {};
+  rustfbp_0_3_34 = rustfbp_0_3_34_ {
+    dependencies = [ capnp_0_8_17 crossbeam_channel_0_5_17 libc_0_2_190 libloading_0_4_3 log_0_4_34 rayon_1_12_0 rustfbp_macros_0_3_34 semver_1_0_28 serde_1_0_229 serde_json_1_0_154 tokio_1_53_2 ];
+  };
+  rustfbp_macros_0_3_34 = rustfbp_macros_0_3_34_ {};
+  rustix_0_38_44 = rustix_0_38_44_ {
+    dependencies = [ bitflags_2_13_2 errno_0_3_14 libc_0_2_190 linux_raw_sys_0_4_15 ];
+    features = [ "alloc" "default" "event" "fs" "libc-extra-traits" "mm" "net" "param" "process" "std" "thread" "time" "use-libc-auxv" ];
+  };
+  rustix_1_1_5 = rustix_1_1_5_ {
+    dependencies = [ bitflags_2_13_2 errno_0_3_14 libc_0_2_190 linux_raw_sys_0_12_1 ];
+    features = [ "alloc" "default" "fs" "net" "process" "std" "termios" "time" ];
+  };
+  rustix_linux_procfs_0_1_1 = rustix_linux_procfs_0_1_1_ {
+    dependencies = [ once_cell_1_21_4 rustix_1_1_5 ];
+    features = [ "default" "std" ];
+  };
+  rustls_0_23_45 = rustls_0_23_45_ {
+    dependencies = [ aws_lc_rs_1_18_1 log_0_4_34 once_cell_1_21_4 ring_0_17_14 rustls_pki_types_1_15_1 rustls_webpki_0_103_15 subtle_2_6_1 zeroize_1_9_1 ];
+    features = [ "aws-lc-rs" "aws_lc_rs" "log" "logging" "ring" "std" "tls12" ];
+  };
+  rustls_native_certs_0_8_4 = rustls_native_certs_0_8_4_ {
+    dependencies = [ openssl_probe_0_2_1 rustls_pki_types_1_15_1 ];
+  };
+  rustls_pemfile_2_2_0 = rustls_pemfile_2_2_0_ {
+    dependencies = [ rustls_pki_types_1_15_1 ];
+    features = [ "default" "std" ];
+  };
+  rustls_pki_types_1_15_1 = rustls_pki_types_1_15_1_ {
+    dependencies = [ zeroize_1_9_1 ];
+    features = [ "alloc" "default" "std" ];
+  };
+  rustls_webpki_0_102_8 = rustls_webpki_0_102_8_ {
+    dependencies = [ ring_0_17_14 rustls_pki_types_1_15_1 untrusted_0_9_0 ];
+    features = [ "alloc" "default" "ring" "std" ];
+  };
+  rustls_webpki_0_103_15 = rustls_webpki_0_103_15_ {
+    dependencies = [ aws_lc_rs_1_18_1 ring_0_17_14 rustls_pki_types_1_15_1 untrusted_0_9_0 ];
+    features = [ "alloc" "aws-lc-rs" "ring" "std" ];
+  };
+  rustversion_1_0_23 = rustversion_1_0_23_ {};
+  ryu_1_0_23 = ryu_1_0_23_ {};
+  same_file_1_0_6 = same_file_1_0_6_ {};
+  scheduled_thread_pool_0_2_7 = scheduled_thread_pool_0_2_7_ {
+    dependencies = [ parking_lot_0_12_5 ];
+  };
+  scopeguard_1_2_0 = scopeguard_1_2_0_ {};
+  semver_1_0_28 = semver_1_0_28_ {
+    features = [ "default" "std" ];
+  };
+  serde_1_0_229 = serde_1_0_229_ {
+    dependencies = [ serde_core_1_0_229 serde_derive_1_0_229 ];
+    features = [ "default" "derive" "serde_derive" "std" ];
+  };
+  serde_core_1_0_229 = serde_core_1_0_229_ {
+    features = [ "alloc" "default" "result" "std" ];
+  };
+  serde_derive_1_0_229 = serde_derive_1_0_229_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_3_0_8 ];
+    features = [ "default" ];
+  };
+  serde_json_1_0_154 = serde_json_1_0_154_ {
+    dependencies = [ itoa_1_0_18 memchr_2_8_3 serde_core_1_0_229 zmij_1_0_23 ];
+    features = [ "default" "std" ];
+  };
+  serde_spanned_0_6_9 = serde_spanned_0_6_9_ {
+    dependencies = [ serde_1_0_229 ];
+    features = [ "serde" ];
+  };
+  serde_spanned_1_1_2 = serde_spanned_1_1_2_ {
+    dependencies = [ serde_core_1_0_229 ];
+    features = [ "alloc" "serde" "std" ];
+  };
+  sha1_0_11_0 = sha1_0_11_0_ {
+    dependencies = [ cfg_if_1_0_5 cpufeatures_0_3_1 digest_0_11_3 ];
+    features = [ "alloc" "default" "oid" ];
+  };
+  sha2_0_10_9 = sha2_0_10_9_ {
+    dependencies = [ cfg_if_1_0_5 cpufeatures_0_2_17 digest_0_10_7 ];
+    features = [ "default" "std" ];
+  };
+  sha2_0_11_0 = sha2_0_11_0_ {
+    dependencies = [ cfg_if_1_0_5 cpufeatures_0_3_1 digest_0_11_3 ];
+    features = [ "alloc" "default" "oid" ];
+  };
+  shellexpand_2_1_2 = shellexpand_2_1_2_ {
+    dependencies = [ dirs_4_0_0 ];
+  };
+  shlex_2_0_1 = shlex_2_0_1_ {
+    features = [ "default" "std" ];
+  };
+  simd_adler32_0_3_10 = simd_adler32_0_3_10_ {};
+  simdutf8_0_1_5 = simdutf8_0_1_5_ {
+    features = [ "aarch64_neon" "public_imp" ];
+  };
+  siphasher_1_0_4 = siphasher_1_0_4_ {
+    features = [ "default" "std" ];
+  };
+  slab_0_4_12 = slab_0_4_12_ {
+    features = [ "std" ];
+  };
+  slice_group_by_0_3_1 = slice_group_by_0_3_1_ {};
+  smallvec_1_16_3 = smallvec_1_16_3_ {
+    features = [ "const_generics" "union" ];
+  };
+  socket2_0_6_5 = socket2_0_6_5_ {
+    dependencies = [ libc_0_2_190 ];
+    features = [ "all" ];
+  };
+  spin_0_9_9 = spin_0_9_9_ {
+    dependencies = [ lock_api_0_4_14 ];
+    features = [ "barrier" "default" "lazy" "lock_api" "lock_api_crate" "mutex" "once" "rwlock" "spin_mutex" ];
+  };
+  sptr_0_3_2 = sptr_0_3_2_ {
+    features = [ "default" ];
+  };
+  stable_deref_trait_1_2_1 = stable_deref_trait_1_2_1_ {
+    features = [ "alloc" "std" ];
+  };
+  stringprep_0_1_5 = stringprep_0_1_5_ {
+    dependencies = [ unicode_bidi_0_3_18 unicode_normalization_0_1_25 unicode_properties_0_1_4 ];
+  };
+  subtle_2_6_1 = subtle_2_6_1_ {};
+  syn_2_0_119 = syn_2_0_119_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 unicode_ident_1_0_26 ];
+    features = [ "clone-impls" "default" "derive" "extra-traits" "full" "parsing" "printing" "proc-macro" "visit-mut" ];
+  };
+  syn_3_0_8 = syn_3_0_8_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 unicode_ident_1_0_26 ];
+    features = [ "clone-impls" "default" "derive" "extra-traits" "fold" "full" "parsing" "printing" "proc-macro" "visit" "visit-mut" ];
+  };
+  synstructure_0_14_0 = synstructure_0_14_0_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_3_0_8 ];
+    features = [ "default" "proc-macro" ];
+  };
+  system_deps_6_2_2 = system_deps_6_2_2_ {
+    dependencies = [ cfg_expr_0_15_8 heck_0_5_0 pkg_config_0_3_34 toml_0_8_23 version_compare_0_2_1 ];
+  };
+  system_interface_0_27_3 = system_interface_0_27_3_ {
+    dependencies = [ bitflags_2_13_2 cap_std_3_4_6 io_lifetimes_2_0_4 rustix_0_38_44 ];
+    features = [ "cap-std" "cap_std_impls" "default" ];
+  };
+  target_lexicon_0_12_16 = target_lexicon_0_12_16_ {
+    features = [ "default" "std" ];
+  };
+  target_lexicon_0_13_5 = target_lexicon_0_13_5_ {
+    features = [ "default" ];
+  };
+  thiserror_1_0_69 = thiserror_1_0_69_ {
+    dependencies = [ thiserror_impl_1_0_69 ];
+  };
+  thiserror_2_0_21 = thiserror_2_0_21_ {
+    dependencies = [ thiserror_impl_2_0_21 ];
+    features = [ "default" "std" ];
+  };
+  thiserror_impl_1_0_69 = thiserror_impl_1_0_69_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_2_0_119 ];
+  };
+  thiserror_impl_2_0_21 = thiserror_impl_2_0_21_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_3_0_8 ];
+  };
+  tiny_http_0_12_0 = tiny_http_0_12_0_ {
+    dependencies = [ ascii_1_1_0 chunked_transfer_1_5_0 httpdate_1_0_3 log_0_4_34 ];
+    features = [ "default" ];
+  };
+  tinystr_0_8_4 = tinystr_0_8_4_ {
+    dependencies = [ displaydoc_0_2_7 zerovec_0_11_8 ];
+    features = [ "zerovec" ];
+  };
+  tinyvec_1_13_3 = tinyvec_1_13_3_ {
+    features = [ "alloc" "default" ];
+  };
+  tokio_1_53_2 = tokio_1_53_2_ {
+    dependencies = [ bytes_1_12_1 libc_0_2_190 mio_1_2_4 pin_project_lite_0_2_17 socket2_0_6_5 tokio_macros_2_7_2 ];
+    features = [ "bytes" "default" "io-std" "io-util" "libc" "macros" "mio" "net" "rt" "rt-multi-thread" "socket2" "sync" "time" "tokio-macros" "windows-sys" ];
+  };
+  tokio_macros_2_7_2 = tokio_macros_2_7_2_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_3_0_8 ];
+  };
+  tokio_postgres_0_7_18 = tokio_postgres_0_7_18_ {
+    dependencies = [ async_trait_0_1_92 byteorder_1_5_0 bytes_1_12_1 fallible_iterator_0_2_0 futures_channel_0_3_34 futures_util_0_3_34 log_0_4_34 parking_lot_0_12_5 percent_encoding_2_3_2 phf_0_13_1 pin_project_lite_0_2_17 postgres_protocol_0_6_12 postgres_types_0_2_14 rand_0_10_3 socket2_0_6_5 tokio_1_53_2 tokio_util_0_7_20 whoami_2_1_3 ];
+    features = [ "default" "runtime" "with-serde_json-1" ];
+  };
+  tokio_rustls_0_26_6 = tokio_rustls_0_26_6_ {
+    dependencies = [ rustls_0_23_45 tokio_1_53_2 ];
+    features = [ "aws_lc_rs" "default" "logging" "tls12" ];
+  };
+  tokio_stream_0_1_19 = tokio_stream_0_1_19_ {
+    dependencies = [ futures_core_0_3_34 pin_project_lite_0_2_17 tokio_1_53_2 ];
+    features = [ "default" "time" ];
+  };
+  tokio_util_0_7_20 = tokio_util_0_7_20_ {
+    dependencies = [ bytes_1_12_1 futures_core_0_3_34 futures_sink_0_3_34 libc_0_2_190 pin_project_lite_0_2_17 tokio_1_53_2 ];
+    features = [ "codec" "default" "libc" ];
+  };
+  toml_0_8_23 = toml_0_8_23_ {
+    dependencies = [ serde_1_0_229 serde_spanned_0_6_9 toml_datetime_0_6_11 toml_edit_0_22_27 ];
+    features = [ "default" "display" "parse" ];
+  };
+  toml_1_1_8 = toml_1_1_8_ {
+    dependencies = [ indexmap_2_14_2 serde_core_1_0_229 serde_spanned_1_1_2 toml_datetime_1_1_2 toml_parser_1_1_5 toml_writer_1_1_3 winnow_1_0_4 ];
+    features = [ "default" "display" "parse" "serde" "std" ];
+  };
+  toml_datetime_0_6_11 = toml_datetime_0_6_11_ {
+    dependencies = [ serde_1_0_229 ];
+    features = [ "serde" ];
+  };
+  toml_datetime_1_1_2 = toml_datetime_1_1_2_ {
+    dependencies = [ serde_core_1_0_229 ];
+    features = [ "alloc" "serde" "std" ];
+  };
+  toml_edit_0_22_27 = toml_edit_0_22_27_ {
+    dependencies = [ indexmap_2_14_2 serde_1_0_229 serde_spanned_0_6_9 toml_datetime_0_6_11 toml_write_0_1_2 winnow_0_7_15 ];
+    features = [ "display" "parse" "serde" ];
+  };
+  toml_parser_1_1_5 = toml_parser_1_1_5_ {
+    dependencies = [ winnow_1_0_4 ];
+    features = [ "alloc" "std" ];
+  };
+  toml_write_0_1_2 = toml_write_0_1_2_ {
+    features = [ "alloc" "default" "std" ];
+  };
+  toml_writer_1_1_3 = toml_writer_1_1_3_ {
+    features = [ "alloc" "std" ];
+  };
+  tracing_0_1_44 = tracing_0_1_44_ {
+    dependencies = [ pin_project_lite_0_2_17 tracing_attributes_0_1_31 tracing_core_0_1_36 ];
+    features = [ "attributes" "default" "std" "tracing-attributes" ];
+  };
+  tracing_attributes_0_1_31 = tracing_attributes_0_1_31_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_2_0_119 ];
+  };
+  tracing_core_0_1_36 = tracing_core_0_1_36_ {
+    dependencies = [ once_cell_1_21_4 ];
+    features = [ "once_cell" "std" ];
+  };
+  tungstenite_0_30_0 = tungstenite_0_30_0_ {
+    dependencies = [ bytes_1_12_1 data_encoding_2_11_1 http_1_5_0 httparse_1_10_1 log_0_4_34 rand_0_10_3 sha1_0_11_0 thiserror_2_0_21 ];
+    features = [ "data-encoding" "default" "handshake" "http" "httparse" "sha1" ];
+  };
+  typenum_1_20_1 = typenum_1_20_1_ {
+    features = [ "const-generics" ];
+  };
+  unicode_bidi_0_3_18 = unicode_bidi_0_3_18_ {
+    features = [ "default" "hardcoded-data" "std" ];
+  };
+  unicode_ident_1_0_26 = unicode_ident_1_0_26_ {};
+  unicode_normalization_0_1_25 = unicode_normalization_0_1_25_ {
+    dependencies = [ tinyvec_1_13_3 ];
+    features = [ "default" "std" ];
+  };
+  unicode_properties_0_1_4 = unicode_properties_0_1_4_ {
+    features = [ "default" "emoji" "general-category" ];
+  };
+  unicode_width_0_2_2 = unicode_width_0_2_2_ {
+    features = [ "cjk" "default" ];
+  };
+  unicode_xid_0_2_6 = unicode_xid_0_2_6_ {
+    features = [ "default" ];
+  };
+  untrusted_0_9_0 = untrusted_0_9_0_ {};
+  ureq_3_4_2 = ureq_3_4_2_ {
+    dependencies = [ base64_0_23_1 flate2_1_1_10 log_0_4_34 percent_encoding_2_3_2 rustls_0_23_45 rustls_pki_types_1_15_1 ureq_proto_0_6_4 utf8_zero_0_8_1 webpki_roots_1_0_9 ];
+    features = [ "_ring" "_rustls" "_tls" "default" "gzip" "rustls" "rustls-no-provider" "rustls-webpki-roots" ];
+  };
+  ureq_proto_0_6_4 = ureq_proto_0_6_4_ {
+    dependencies = [ base64_0_23_1 http_1_5_0 httparse_1_10_1 log_0_4_34 ];
+    features = [ "client" ];
+  };
+  url_2_5_8 = url_2_5_8_ {
+    dependencies = [ form_urlencoded_1_2_2 idna_1_1_0 percent_encoding_2_3_2 serde_1_0_229 ];
+    features = [ "default" "std" ];
+  };
+  utf8_zero_0_8_1 = utf8_zero_0_8_1_ {
+    features = [ "default" "std" ];
+  };
+  utf8_iter_1_0_4 = utf8_iter_1_0_4_ {};
+  uuid_1_28_0 = uuid_1_28_0_ {
+    features = [ "default" "std" ];
+  };
+  vcpkg_0_2_15 = vcpkg_0_2_15_ {};
+  version_compare_0_2_1 = version_compare_0_2_1_ {};
+  version_check_0_9_5 = version_check_0_9_5_ {};
+  walkdir_2_5_0 = walkdir_2_5_0_ {
+    dependencies = [ same_file_1_0_6 ];
+  };
+  wasm_encoder_0_202_0 = wasm_encoder_0_202_0_ {
+    dependencies = [ leb128_0_2_7 ];
+  };
+  wasm_encoder_0_261_0 = wasm_encoder_0_261_0_ {
+    dependencies = [ leb128fmt_0_1_0 wasmparser_0_261_0 ];
+    features = [ "component-model" "std" ];
+  };
+  wasmparser_0_202_0 = wasmparser_0_202_0_ {
+    dependencies = [ bitflags_2_13_2 indexmap_2_14_2 semver_1_0_28 ];
+  };
+  wasmparser_0_261_0 = wasmparser_0_261_0_ {
+    dependencies = [ bitflags_2_13_2 indexmap_2_14_2 semver_1_0_28 ];
+    features = [ "component-model" "simd" "std" ];
+  };
+  wasmprinter_0_202_0 = wasmprinter_0_202_0_ {
+    dependencies = [ anyhow_1_0_104 wasmparser_0_202_0 ];
+  };
+  wasmtime_20_0_2 = wasmtime_20_0_2_ {
+    dependencies = [ addr2line_0_21_0 anyhow_1_0_104 async_trait_0_1_92 bincode_1_3_3 bumpalo_3_20_3 cfg_if_1_0_5 encoding_rs_0_8_42 fxprof_processed_profile_0_6_0 gimli_0_28_1 indexmap_2_14_2 ittapi_0_4_0 libc_0_2_190 log_0_4_34 object_0_33_0 once_cell_1_21_4 paste_1_0_15 rayon_1_12_0 rustix_0_38_44 semver_1_0_28 serde_1_0_229 serde_derive_1_0_229 serde_json_1_0_154 target_lexicon_0_12_16 wasm_encoder_0_202_0 wasmparser_0_202_0 wasmtime_cache_20_0_2 wasmtime_component_macro_20_0_2 wasmtime_component_util_20_0_2 wasmtime_cranelift_20_0_2 wasmtime_environ_20_0_2 wasmtime_fiber_20_0_2 wasmtime_jit_debug_20_0_2 wasmtime_jit_icache_coherence_20_0_2 wasmtime_runtime_20_0_2 wasmtime_slab_20_0_2 wasmtime_winch_20_0_2 wat_1_261_0 ];
+    features = [ "addr2line" "async" "cache" "component-model" "coredump" "cranelift" "debug-builtins" "default" "demangle" "gc" "parallel-compilation" "pooling-allocator" "profiling" "runtime" "threads" "wat" ];
+  };
+  wasmtime_asm_macros_20_0_2 = wasmtime_asm_macros_20_0_2_ {
+    dependencies = [ cfg_if_1_0_5 ];
+  };
+  wasmtime_cache_20_0_2 = wasmtime_cache_20_0_2_ {
+    dependencies = [ anyhow_1_0_104 base64_0_21_7 bincode_1_3_3 directories_next_2_0_0 log_0_4_34 rustix_0_38_44 serde_1_0_229 serde_derive_1_0_229 sha2_0_10_9 toml_0_8_23 zstd_0_13_3 ];
+  };
+  wasmtime_component_macro_20_0_2 = wasmtime_component_macro_20_0_2_ {
+    dependencies = [ anyhow_1_0_104 proc_macro2_1_0_107 quote_1_0_47 syn_2_0_119 wasmtime_component_util_20_0_2 wasmtime_wit_bindgen_20_0_2 wit_parser_0_202_0 ];
+    features = [ "async" ];
+  };
+  wasmtime_component_util_20_0_2 = wasmtime_component_util_20_0_2_ {};
+  wasmtime_cranelift_20_0_2 = wasmtime_cranelift_20_0_2_ {
+    dependencies = [ anyhow_1_0_104 cfg_if_1_0_5 cranelift_codegen_0_107_2 cranelift_control_0_107_2 cranelift_entity_0_107_2 cranelift_frontend_0_107_2 cranelift_native_0_107_2 cranelift_wasm_0_107_2 gimli_0_28_1 log_0_4_34 object_0_33_0 target_lexicon_0_12_16 thiserror_1_0_69 wasmparser_0_202_0 wasmtime_environ_20_0_2 wasmtime_versioned_export_macros_20_0_2 ];
+    features = [ "component-model" "gc" "threads" ];
+  };
+  wasmtime_environ_20_0_2 = wasmtime_environ_20_0_2_ {
+    dependencies = [ anyhow_1_0_104 bincode_1_3_3 cpp_demangle_0_4_5 cranelift_entity_0_107_2 gimli_0_28_1 indexmap_2_14_2 log_0_4_34 object_0_33_0 rustc_demangle_0_1_28 serde_1_0_229 serde_derive_1_0_229 target_lexicon_0_12_16 thiserror_1_0_69 wasm_encoder_0_202_0 wasmparser_0_202_0 wasmprinter_0_202_0 wasmtime_component_util_20_0_2 wasmtime_types_20_0_2 ];
+    features = [ "compile" "component-model" "demangle" "gc" "threads" ];
+  };
+  wasmtime_fiber_20_0_2 = wasmtime_fiber_20_0_2_ {
+    dependencies = [ anyhow_1_0_104 cc_1_8_0 cfg_if_1_0_5 rustix_0_38_44 wasmtime_asm_macros_20_0_2 wasmtime_versioned_export_macros_20_0_2 ];
+  };
+  wasmtime_jit_debug_20_0_2 = wasmtime_jit_debug_20_0_2_ {
+    dependencies = [ object_0_33_0 once_cell_1_21_4 rustix_0_38_44 wasmtime_versioned_export_macros_20_0_2 ];
+    features = [ "gdb_jit_int" "object" "once_cell" "perf_jitdump" "rustix" ];
+  };
+  wasmtime_jit_icache_coherence_20_0_2 = wasmtime_jit_icache_coherence_20_0_2_ {
+    dependencies = [ cfg_if_1_0_5 libc_0_2_190 ];
+  };
+  wasmtime_runtime_20_0_2 = wasmtime_runtime_20_0_2_ {
+    dependencies = [ anyhow_1_0_104 cc_1_8_0 cfg_if_1_0_5 encoding_rs_0_8_42 indexmap_2_14_2 libc_0_2_190 log_0_4_34 memfd_0_6_6 memoffset_0_9_1 paste_1_0_15 rustix_0_38_44 sptr_0_3_2 wasm_encoder_0_202_0 wasmtime_asm_macros_20_0_2 wasmtime_environ_20_0_2 wasmtime_fiber_20_0_2 wasmtime_jit_debug_20_0_2 wasmtime_slab_20_0_2 wasmtime_versioned_export_macros_20_0_2 ];
+    features = [ "async" "component-model" "coredump" "debug-builtins" "gc" "pooling-allocator" "threads" ];
+  };
+  wasmtime_slab_20_0_2 = wasmtime_slab_20_0_2_ {};
+  wasmtime_types_20_0_2 = wasmtime_types_20_0_2_ {
+    dependencies = [ cranelift_entity_0_107_2 serde_1_0_229 serde_derive_1_0_229 thiserror_1_0_69 wasmparser_0_202_0 ];
+  };
+  wasmtime_versioned_export_macros_20_0_2 = wasmtime_versioned_export_macros_20_0_2_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_2_0_119 ];
+  };
+  wasmtime_wasi_20_0_2 = wasmtime_wasi_20_0_2_ {
+    dependencies = [ anyhow_1_0_104 async_trait_0_1_92 bitflags_2_13_2 bytes_1_12_1 cap_fs_ext_3_4_6 cap_net_ext_3_4_6 cap_rand_3_4_6 cap_std_3_4_6 cap_time_ext_3_4_6 fs_set_times_0_20_3 futures_0_3_34 io_lifetimes_2_0_4 once_cell_1_21_4 rustix_0_38_44 system_interface_0_27_3 thiserror_1_0_69 tokio_1_53_2 tracing_0_1_44 url_2_5_8 wasmtime_20_0_2 wiggle_20_0_2 ];
+    features = [ "default" "preview1" ];
+  };
+  wasmtime_winch_20_0_2 = wasmtime_winch_20_0_2_ {
+    dependencies = [ anyhow_1_0_104 cranelift_codegen_0_107_2 gimli_0_28_1 object_0_33_0 target_lexicon_0_12_16 wasmparser_0_202_0 wasmtime_cranelift_20_0_2 wasmtime_environ_20_0_2 winch_codegen_0_18_2 ];
+    features = [ "component-model" ];
+  };
+  wasmtime_wit_bindgen_20_0_2 = wasmtime_wit_bindgen_20_0_2_ {
+    dependencies = [ anyhow_1_0_104 heck_0_4_1 indexmap_2_14_2 wit_parser_0_202_0 ];
+  };
+  wast_35_0_2 = wast_35_0_2_ {
+    dependencies = [ leb128_0_2_7 ];
+  };
+  wast_261_0_0 = wast_261_0_0_ {
+    dependencies = [ bumpalo_3_20_3 leb128fmt_0_1_0 memchr_2_8_3 unicode_width_0_2_2 wasm_encoder_0_261_0 ];
+    features = [ "component-model" "wasm-module" ];
+  };
+  wat_1_261_0 = wat_1_261_0_ {
+    dependencies = [ wast_261_0_0 ];
+    features = [ "component-model" "default" ];
+  };
+  webpki_roots_1_0_9 = webpki_roots_1_0_9_ {
+    dependencies = [ rustls_pki_types_1_15_1 ];
+  };
+  whoami_2_1_3 = whoami_2_1_3_ {
+    dependencies = [ libc_0_2_190 ];
+    features = [ "default" "std" "wasi-wasite" "wasm-web" ];
+  };
+  wiggle_20_0_2 = wiggle_20_0_2_ {
+    dependencies = [ anyhow_1_0_104 async_trait_0_1_92 bitflags_2_13_2 thiserror_1_0_69 tracing_0_1_44 wasmtime_20_0_2 wiggle_macro_20_0_2 ];
+    features = [ "wasmtime" ];
+  };
+  wiggle_generate_20_0_2 = wiggle_generate_20_0_2_ {
+    dependencies = [ anyhow_1_0_104 heck_0_4_1 proc_macro2_1_0_107 quote_1_0_47 shellexpand_2_1_2 syn_2_0_119 witx_0_9_1 ];
+  };
+  wiggle_macro_20_0_2 = wiggle_macro_20_0_2_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_2_0_119 wiggle_generate_20_0_2 ];
+  };
+  winch_codegen_0_18_2 = winch_codegen_0_18_2_ {
+    dependencies = [ anyhow_1_0_104 cranelift_codegen_0_107_2 gimli_0_28_1 regalloc2_0_9_3 smallvec_1_16_3 target_lexicon_0_12_16 wasmparser_0_202_0 wasmtime_cranelift_20_0_2 wasmtime_environ_20_0_2 ];
+  };
+  winnow_0_7_15 = winnow_0_7_15_ {
+    dependencies = [ memchr_2_8_3 ];
+    features = [ "alloc" "default" "std" ];
+  };
+  winnow_1_0_4 = winnow_1_0_4_ {};
+  wit_parser_0_202_0 = wit_parser_0_202_0_ {
+    dependencies = [ anyhow_1_0_104 id_arena_2_3_0 indexmap_2_14_2 log_0_4_34 semver_1_0_28 serde_1_0_229 serde_derive_1_0_229 serde_json_1_0_154 unicode_xid_0_2_6 wasmparser_0_202_0 ];
+    features = [ "decoding" "default" "serde" "serde_json" ];
+  };
+  witx_0_9_1 = witx_0_9_1_ {
+    dependencies = [ anyhow_1_0_104 log_0_4_34 thiserror_1_0_69 wast_35_0_2 ];
+  };
+  writeable_0_6_4 = writeable_0_6_4_ {};
+  yoke_0_8_3 = yoke_0_8_3_ {
+    dependencies = [ stable_deref_trait_1_2_1 yoke_derive_0_8_4 zerofrom_0_1_8 ];
+    features = [ "derive" "zerofrom" ];
+  };
+  yoke_derive_0_8_4 = yoke_derive_0_8_4_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_3_0_8 synstructure_0_14_0 ];
+  };
+  zerocopy_0_8_62 = zerocopy_0_8_62_ {
+    features = [ "simd" ];
+  };
+  zerofrom_0_1_8 = zerofrom_0_1_8_ {
+    dependencies = [ zerofrom_derive_0_1_8 ];
+    features = [ "derive" ];
+  };
+  zerofrom_derive_0_1_8 = zerofrom_derive_0_1_8_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_3_0_8 synstructure_0_14_0 ];
+  };
+  zeroize_1_9_1 = zeroize_1_9_1_ {
+    features = [ "alloc" "default" ];
+  };
+  zeromq_src_0_2_6 = zeromq_src_0_2_6_ {
+    dependencies = [ cc_1_8_0 dircpy_0_3_21 ];
+  };
+  zerotrie_0_2_5 = zerotrie_0_2_5_ {
+    dependencies = [ displaydoc_0_2_7 yoke_0_8_3 zerofrom_0_1_8 ];
+    features = [ "yoke" "zerofrom" ];
+  };
+  zerovec_0_11_8 = zerovec_0_11_8_ {
+    dependencies = [ yoke_0_8_3 zerofrom_0_1_8 zerovec_derive_0_11_6 ];
+    features = [ "derive" "yoke" ];
+  };
+  zerovec_derive_0_11_6 = zerovec_derive_0_11_6_ {
+    dependencies = [ proc_macro2_1_0_107 quote_1_0_47 syn_3_0_8 ];
+  };
+  zlib_rs_0_6_8 = zlib_rs_0_6_8_ {
+    features = [ "rust-allocator" "std" ];
+  };
+  zmij_1_0_23 = zmij_1_0_23_ {};
+  zmq_0_10_0 = zmq_0_10_0_ {
+    dependencies = [ bitflags_1_3_2 libc_0_2_190 zmq_sys_0_12_0 ];
+    features = [ "default" "zmq_has" ];
+  };
+  zmq_sys_0_12_0 = zmq_sys_0_12_0_ {
+    dependencies = [ libc_0_2_190 system_deps_6_2_2 zeromq_src_0_2_6 ];
+  };
+  zstd_0_13_3 = zstd_0_13_3_ {
+    dependencies = [ zstd_safe_7_3_0 ];
+  };
+  zstd_0_14_2 = zstd_0_14_2_ {
+    dependencies = [ zstd_safe_8_1_0 ];
+    features = [ "arrays" "default" "legacy" "zdict_builder" ];
+  };
+  zstd_safe_7_3_0 = zstd_safe_7_3_0_ {
+    dependencies = [ zstd_sys_2_1_1 ];
+    features = [ "std" ];
+  };
+  zstd_safe_8_1_0 = zstd_safe_8_1_0_ {
+    dependencies = [ zstd_sys_2_1_1 ];
+    features = [ "arrays" "legacy" "std" "zdict_builder" ];
   };
-  threadpool_1_7_0 = threadpool_1_7_0_ {
-    dependencies = [ num_cpus_1_6_2 ];
+  zstd_sys_2_1_1 = zstd_sys_2_1_1_ {
+    dependencies = [ cc_1_8_0 pkg_config_0_3_34 ];
+    features = [ "legacy" "std" "zdict_builder" ];
   };
-  winapi_0_2_8 = winapi_0_2_8_ {};
-  winapi_build_0_1_1 = winapi_build_0_1_1_ {};
+  addr2line = addr2line_0_21_0;
+  adler2 = adler2_2_0_1;
+  ahash = ahash_0_8_12;
+  aho_corasick = aho_corasick_1_1_5;
   all_crates = all_crates_1_1_1;
-  byteorder = byteorder_1_1_0;
-  capnp = capnp_0_8_11;
-  capnpc = capnpc_0_8_7;
-  kernel32_sys = kernel32_sys_0_2_2;
-  lazy_static = lazy_static_0_2_8;
-  libc = libc_0_2_30;
-  libloading = libloading_0_4_1;
-  memchr = memchr_1_0_1;
-  nom = nom_3_2_0;
-  num_cpus = num_cpus_1_6_2;
+  ambient_authority = ambient_authority_0_0_2;
+  anyhow = anyhow_1_0_104;
+  arbitrary = arbitrary_1_5_0;
+  ascii = ascii_1_1_0;
+  async_trait = async_trait_0_1_92;
+  autocfg = autocfg_1_5_1;
+  aws_lc_rs = aws_lc_rs_1_18_1;
+  aws_lc_sys = aws_lc_sys_0_45_0;
+  base64 = base64_0_23_1;
+  bincode = bincode_1_3_3;
+  bitflags = bitflags_2_13_2;
+  blake2 = blake2_0_11_0;
+  block_buffer = block_buffer_0_12_1;
+  bumpalo = bumpalo_3_20_3;
+  byteorder = byteorder_1_5_0;
+  bytes = bytes_1_12_1;
+  cap_fs_ext = cap_fs_ext_3_4_6;
+  cap_net_ext = cap_net_ext_3_4_6;
+  cap_primitives = cap_primitives_3_4_6;
+  cap_rand = cap_rand_3_4_6;
+  cap_std = cap_std_3_4_6;
+  cap_time_ext = cap_time_ext_3_4_6;
+  capnp = capnp_0_8_17;
+  capnpc = capnpc_0_8_9;
+  cc = cc_1_8_0;
+  cfg_expr = cfg_expr_0_15_8;
+  cfg_if = cfg_if_1_0_5;
+  chacha20 = chacha20_0_10_2;
+  chrono = chrono_0_4_45;
+  chunked_transfer = chunked_transfer_1_5_0;
+  cmake = cmake_0_1_58;
+  cmov = cmov_0_5_4;
+  const_oid = const_oid_0_10_2;
+  core_detect = core_detect_1_0_0;
+  cpp_demangle = cpp_demangle_0_4_5;
+  cpufeatures = cpufeatures_0_3_1;
+  cranelift_bforest = cranelift_bforest_0_107_2;
+  cranelift_codegen = cranelift_codegen_0_107_2;
+  cranelift_codegen_meta = cranelift_codegen_meta_0_107_2;
+  cranelift_codegen_shared = cranelift_codegen_shared_0_107_2;
+  cranelift_control = cranelift_control_0_107_2;
+  cranelift_entity = cranelift_entity_0_107_2;
+  cranelift_frontend = cranelift_frontend_0_107_2;
+  cranelift_isle = cranelift_isle_0_107_2;
+  cranelift_native = cranelift_native_0_107_2;
+  cranelift_wasm = cranelift_wasm_0_107_2;
+  crc32fast = crc32fast_1_5_2;
+  cron = cron_0_17_0;
+  crossbeam_channel = crossbeam_channel_0_5_17;
+  crossbeam_deque = crossbeam_deque_0_8_8;
+  crossbeam_epoch = crossbeam_epoch_0_9_21;
+  crossbeam_utils = crossbeam_utils_0_8_23;
+  crypto_common = crypto_common_0_2_2;
+  csv = csv_1_4_0;
+  csv_core = csv_core_0_1_13;
+  ctutils = ctutils_0_4_3;
+  data_encoding = data_encoding_2_11_1;
+  debugid = debugid_0_8_0;
+  digest = digest_0_11_3;
+  dircpy = dircpy_0_3_21;
+  directories_next = directories_next_2_0_0;
+  dirs = dirs_4_0_0;
+  dirs_sys = dirs_sys_0_3_7;
+  dirs_sys_next = dirs_sys_next_0_1_2;
+  displaydoc = displaydoc_0_2_7;
+  dunce = dunce_1_0_5;
+  either = either_1_19_0;
+  encoding_rs = encoding_rs_0_8_42;
+  equivalent = equivalent_1_0_2;
+  errno = errno_0_3_14;
+  fallible_iterator = fallible_iterator_0_3_0;
+  fallible_streaming_iterator = fallible_streaming_iterator_0_1_9;
+  find_msvc_tools = find_msvc_tools_0_1_14;
+  fixedbitset = fixedbitset_0_5_7;
+  flate2 = flate2_1_1_10;
+  flume = flume_0_11_1;
+  foldhash = foldhash_0_2_0;
+  form_urlencoded = form_urlencoded_1_2_2;
+  fs_set_times = fs_set_times_0_20_3;
+  fs_extra = fs_extra_1_3_0;
+  futures = futures_0_3_34;
+  futures_channel = futures_channel_0_3_34;
+  futures_core = futures_core_0_3_34;
+  futures_io = futures_io_0_3_34;
+  futures_sink = futures_sink_0_3_34;
+  futures_task = futures_task_0_3_34;
+  futures_util = futures_util_0_3_34;
+  fxhash = fxhash_0_2_1;
+  fxprof_processed_profile = fxprof_processed_profile_0_6_0;
+  generic_array = generic_array_0_14_7;
+  getrandom = getrandom_0_4_3;
+  gimli = gimli_0_28_1;
+  glob = glob_0_3_4;
+  hashbrown = hashbrown_0_17_1;
+  hashlink = hashlink_0_12_2;
+  heck = heck_0_5_0;
+  hmac = hmac_0_13_0;
+  http = http_1_5_0;
+  httparse = httparse_1_10_1;
+  httpdate = httpdate_1_0_3;
+  hybrid_array = hybrid_array_0_4_15;
+  iana_time_zone = iana_time_zone_0_1_65;
+  icu_collections = icu_collections_2_3_0;
+  icu_locale_core = icu_locale_core_2_3_0;
+  icu_normalizer = icu_normalizer_2_3_0;
+  icu_normalizer_data = icu_normalizer_data_2_3_0;
+  icu_properties = icu_properties_2_3_0;
+  icu_properties_data = icu_properties_data_2_3_0;
+  icu_provider = icu_provider_2_3_1;
+  id_arena = id_arena_2_3_0;
+  idna = idna_1_1_0;
+  idna_adapter = idna_adapter_1_2_2;
+  indexmap = indexmap_2_14_2;
+  inotify = inotify_0_11_5;
+  inotify_sys = inotify_sys_0_1_8;
+  io_extras = io_extras_0_18_4;
+  io_lifetimes = io_lifetimes_2_0_4;
+  ipnet = ipnet_2_12_2;
+  itertools = itertools_0_12_1;
+  itoa = itoa_1_0_18;
+  ittapi = ittapi_0_4_0;
+  ittapi_sys = ittapi_sys_0_4_0;
+  jobserver = jobserver_0_1_35;
+  lazy_static = lazy_static_1_5_1;
+  leb128 = leb128_0_2_7;
+  leb128fmt = leb128fmt_0_1_0;
+  libc = libc_0_2_190;
+  libloading = libloading_0_4_3;
+  libsqlite3_sys = libsqlite3_sys_0_38_2;
+  linux_raw_sys = linux_raw_sys_0_12_1;
+  litemap = litemap_0_8_3;
+  lock_api = lock_api_0_4_14;
+  log = log_0_4_34;
+  maybe_owned = maybe_owned_0_3_4;
+  md_5 = md_5_0_11_0;
+  memchr = memchr_2_8_3;
+  memfd = memfd_0_6_6;
+  memoffset = memoffset_0_9_1;
+  miniz_oxide = miniz_oxide_0_9_1;
+  mio = mio_1_2_4;
+  multiversion_no_op = multiversion_no_op_1_0_0;
+  nom = nom_3_2_1;
+  notify = notify_8_2_0;
+  notify_types = notify_types_2_1_0;
+  num_traits = num_traits_0_2_19;
+  object = object_0_33_0;
+  once_cell = once_cell_1_21_4;
+  openssl_probe = openssl_probe_0_2_1;
+  parking_lot = parking_lot_0_12_5;
+  parking_lot_core = parking_lot_core_0_9_12;
+  paste = paste_1_0_15;
+  percent_encoding = percent_encoding_2_3_2;
+  phf = phf_0_13_1;
+  phf_generator = phf_generator_0_11_3;
+  phf_macros = phf_macros_0_11_3;
+  phf_shared = phf_shared_0_13_1;
+  pin_project_lite = pin_project_lite_0_2_17;
+  pkg_config = pkg_config_0_3_34;
+  postgres = postgres_0_19_14;
+  postgres_protocol = postgres_protocol_0_6_12;
+  postgres_types = postgres_types_0_2_14;
+  potential_utf = potential_utf_0_1_6;
+  ppv_lite86 = ppv_lite86_0_2_21;
+  proc_macro2 = proc_macro2_1_0_107;
+  pyo3 = pyo3_0_29_3;
+  pyo3_build_config = pyo3_build_config_0_29_3;
+  pyo3_ffi = pyo3_ffi_0_29_3;
+  pyo3_macros = pyo3_macros_0_29_3;
+  pyo3_macros_backend = pyo3_macros_backend_0_29_3;
+  quote = quote_1_0_47;
+  r2d2 = r2d2_0_8_10;
+  r2d2_postgres = r2d2_postgres_0_18_2;
+  rand = rand_0_10_3;
+  rand_chacha = rand_chacha_0_3_1;
+  rand_core = rand_core_0_10_1;
+  rayon = rayon_1_12_0;
+  rayon_core = rayon_core_1_13_0;
+  regalloc2 = regalloc2_0_9_3;
+  regex = regex_1_13_1;
+  regex_automata = regex_automata_0_4_18;
+  regex_syntax = regex_syntax_0_8_11;
+  ring = ring_0_17_14;
+  rumqttc = rumqttc_0_25_1;
+  rusqlite = rusqlite_0_40_2;
+  rustc_demangle = rustc_demangle_0_1_28;
+  rustc_hash = rustc_hash_1_1_0;
   rustfbp = rustfbp_0_3_34;
-  threadpool = threadpool_1_7_0;
-  winapi = winapi_0_2_8;
-  winapi_build = winapi_build_0_1_1;
+  rustfbp_macros = rustfbp_macros_0_3_34;
+  rustix = rustix_1_1_5;
+  rustix_linux_procfs = rustix_linux_procfs_0_1_1;
+  rustls = rustls_0_23_45;
+  rustls_native_certs = rustls_native_certs_0_8_4;
+  rustls_pemfile = rustls_pemfile_2_2_0;
+  rustls_pki_types = rustls_pki_types_1_15_1;
+  rustls_webpki = rustls_webpki_0_103_15;
+  rustversion = rustversion_1_0_23;
+  ryu = ryu_1_0_23;
+  same_file = same_file_1_0_6;
+  scheduled_thread_pool = scheduled_thread_pool_0_2_7;
+  scopeguard = scopeguard_1_2_0;
+  semver = semver_1_0_28;
+  serde = serde_1_0_229;
+  serde_core = serde_core_1_0_229;
+  serde_derive = serde_derive_1_0_229;
+  serde_json = serde_json_1_0_154;
+  serde_spanned = serde_spanned_1_1_2;
+  sha1 = sha1_0_11_0;
+  sha2 = sha2_0_11_0;
+  shellexpand = shellexpand_2_1_2;
+  shlex = shlex_2_0_1;
+  simd_adler32 = simd_adler32_0_3_10;
+  simdutf8 = simdutf8_0_1_5;
+  siphasher = siphasher_1_0_4;
+  slab = slab_0_4_12;
+  slice_group_by = slice_group_by_0_3_1;
+  smallvec = smallvec_1_16_3;
+  socket2 = socket2_0_6_5;
+  spin = spin_0_9_9;
+  sptr = sptr_0_3_2;
+  stable_deref_trait = stable_deref_trait_1_2_1;
+  stringprep = stringprep_0_1_5;
+  subtle = subtle_2_6_1;
+  syn = syn_3_0_8;
+  synstructure = synstructure_0_14_0;
+  system_deps = system_deps_6_2_2;
+  system_interface = system_interface_0_27_3;
+  target_lexicon = target_lexicon_0_13_5;
+  thiserror = thiserror_2_0_21;
+  thiserror_impl = thiserror_impl_2_0_21;
+  tiny_http = tiny_http_0_12_0;
+  tinystr = tinystr_0_8_4;
+  tinyvec = tinyvec_1_13_3;
+  tokio = tokio_1_53_2;
+  tokio_macros = tokio_macros_2_7_2;
+  tokio_postgres = tokio_postgres_0_7_18;
+  tokio_rustls = tokio_rustls_0_26_6;
+  tokio_stream = tokio_stream_0_1_19;
+  tokio_util = tokio_util_0_7_20;
+  toml = toml_1_1_8;
+  toml_datetime = toml_datetime_1_1_2;
+  toml_edit = toml_edit_0_22_27;
+  toml_parser = toml_parser_1_1_5;
+  toml_write = toml_write_0_1_2;
+  toml_writer = toml_writer_1_1_3;
+  tracing = tracing_0_1_44;
+  tracing_attributes = tracing_attributes_0_1_31;
+  tracing_core = tracing_core_0_1_36;
+  tungstenite = tungstenite_0_30_0;
+  typenum = typenum_1_20_1;
+  unicode_bidi = unicode_bidi_0_3_18;
+  unicode_ident = unicode_ident_1_0_26;
+  unicode_normalization = unicode_normalization_0_1_25;
+  unicode_properties = unicode_properties_0_1_4;
+  unicode_width = unicode_width_0_2_2;
+  unicode_xid = unicode_xid_0_2_6;
+  untrusted = untrusted_0_9_0;
+  ureq = ureq_3_4_2;
+  ureq_proto = ureq_proto_0_6_4;
+  url = url_2_5_8;
+  utf8_zero = utf8_zero_0_8_1;
+  utf8_iter = utf8_iter_1_0_4;
+  uuid = uuid_1_28_0;
+  vcpkg = vcpkg_0_2_15;
+  version_compare = version_compare_0_2_1;
+  version_check = version_check_0_9_5;
+  walkdir = walkdir_2_5_0;
+  wasm_encoder = wasm_encoder_0_261_0;
+  wasmparser = wasmparser_0_261_0;
+  wasmprinter = wasmprinter_0_202_0;
+  wasmtime = wasmtime_20_0_2;
+  wasmtime_asm_macros = wasmtime_asm_macros_20_0_2;
+  wasmtime_cache = wasmtime_cache_20_0_2;
+  wasmtime_component_macro = wasmtime_component_macro_20_0_2;
+  wasmtime_component_util = wasmtime_component_util_20_0_2;
+  wasmtime_cranelift = wasmtime_cranelift_20_0_2;
+  wasmtime_environ = wasmtime_environ_20_0_2;
+  wasmtime_fiber = wasmtime_fiber_20_0_2;
+  wasmtime_jit_debug = wasmtime_jit_debug_20_0_2;
+  wasmtime_jit_icache_coherence = wasmtime_jit_icache_coherence_20_0_2;
+  wasmtime_runtime = wasmtime_runtime_20_0_2;
+  wasmtime_slab = wasmtime_slab_20_0_2;
+  wasmtime_types = wasmtime_types_20_0_2;
+  wasmtime_versioned_export_macros = wasmtime_versioned_export_macros_20_0_2;
+  wasmtime_wasi = wasmtime_wasi_20_0_2;
+  wasmtime_winch = wasmtime_winch_20_0_2;
+  wasmtime_wit_bindgen = wasmtime_wit_bindgen_20_0_2;
+  wast = wast_261_0_0;
+  wat = wat_1_261_0;
+  webpki_roots = webpki_roots_1_0_9;
+  whoami = whoami_2_1_3;
+  wiggle = wiggle_20_0_2;
+  wiggle_generate = wiggle_generate_20_0_2;
+  wiggle_macro = wiggle_macro_20_0_2;
+  winch_codegen = winch_codegen_0_18_2;
+  winnow = winnow_1_0_4;
+  wit_parser = wit_parser_0_202_0;
+  witx = witx_0_9_1;
+  writeable = writeable_0_6_4;
+  yoke = yoke_0_8_3;
+  yoke_derive = yoke_derive_0_8_4;
+  zerocopy = zerocopy_0_8_62;
+  zerofrom = zerofrom_0_1_8;
+  zerofrom_derive = zerofrom_derive_0_1_8;
+  zeroize = zeroize_1_9_1;
+  zeromq_src = zeromq_src_0_2_6;
+  zerotrie = zerotrie_0_2_5;
+  zerovec = zerovec_0_11_8;
+  zerovec_derive = zerovec_derive_0_11_6;
+  zlib_rs = zlib_rs_0_6_8;
+  zmij = zmij_1_0_23;
+  zmq = zmq_0_10_0;
+  zmq_sys = zmq_sys_0_12_0;
+  zstd = zstd_0_14_2;
+  zstd_safe = zstd_safe_8_1_0;
+  zstd_sys = zstd_sys_2_1_1;
 }
@@ -0,0 +1,87 @@
+//! Throughput of the channels behind the ports
+//!
+//! `std_mpsc` is the channel used before crossbeam, `crossbeam` the one used now, and `msg_receiver` the full
+//! MsgSender/MsgReceiver path with the scheduler messages.
+//!
+//! Run with `cargo bench` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate criterion;
+extern crate crossbeam_channel;
+extern crate rustfbp;
+
+use criterion::Criterion;
+
+use rustfbp::ports::MsgReceiver;
+
+use std::sync::mpsc::{channel, sync_channel};
+use std::thread;
+
+// The number of messages of one iteration, and the number of threads sending them
+const MSGS: usize = 10_000;
+const PRODUCERS: usize = 4;
+
+fn std_mpsc(c: &mut Criterion) {
+    c.bench_function("std_mpsc", |b| b.iter(|| {
+        let (s, r) = sync_channel(25);
+        let producers: Vec<_> = (0..PRODUCERS).map(|_| {
+            let s = s.clone();
+            thread::spawn(move || {
+                for i in 0..MSGS / PRODUCERS {
+                    s.send((None as Option<String>, i)).unwrap();
+                }
+            })
+        }).collect();
+        for _ in 0..MSGS {
+            r.recv().unwrap();
+        }
+        for p in producers {
+            p.join().unwrap();
+        }
+    }));
+}
+
+fn crossbeam(c: &mut Criterion) {
+    c.bench_function("crossbeam", |b| b.iter(|| {
+        let (s, r) = crossbeam_channel::bounded(25);
+        let producers: Vec<_> = (0..PRODUCERS).map(|_| {
+            let s = s.clone();
+            thread::spawn(move || {
+                for i in 0..MSGS / PRODUCERS {
+                    s.send((None as Option<String>, i)).unwrap();
+                }
+            })
+        }).collect();
+        for _ in 0..MSGS {
+            r.recv().unwrap();
+        }
+        for p in producers {
+            p.join().unwrap();
+        }
+    }));
+}
+
+fn msg_receiver(c: &mut Criterion) {
+    c.bench_function("msg_receiver", |b| b.iter(|| {
+        // Nobody schedules here, the scheduler messages pile up in _sched_r
+        let (sched, _sched_r) = channel();
+        let (r, s) = MsgReceiver::<usize>::new(0, sched, true);
+        let producers: Vec<_> = (0..PRODUCERS).map(|_| {
+            let s = s.clone();
+            thread::spawn(move || {
+                for i in 0..MSGS / PRODUCERS {
+                    s.send(i).unwrap();
+                }
+            })
+        }).collect();
+        for _ in 0..MSGS {
+            r.recv().unwrap();
+        }
+        for p in producers {
+            p.join().unwrap();
+        }
+    }));
+}
+
+criterion_group!(benches, std_mpsc, crossbeam, msg_receiver);
+criterion_main!(benches);
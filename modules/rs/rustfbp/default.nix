@@ -2,6 +2,7 @@
 
 crate {
   name = "rustfbp";
-  mods = with crates; [ capnp libloading threadpool ];
+  mods = with crates; [ capnp crossbeam_channel libc libloading log rayon rustfbp_macros semver serde serde_json tokio ];
   src = ./.;
+  edition = "2018";
 }
@@ -0,0 +1,240 @@
+//! An HTTP admin endpoint for a running scheduler, built with the feature `admin`
+//!
+//! The endpoint answers in JSON :
+//!
+//! * `GET /nodes` : the nodes, with their sort,
+//! * `GET /nodes/<name>` : the status of a node (see `Scheduler::node_status`),
+//! * `GET /edges` : the edges, an array port element written `port[element]`,
+//! * `GET /metrics` : the counters of every input port (see `PortWatch`),
+//! * `POST /pause` and `POST /resume` : pause or resume all the nodes,
+//! * `POST /nodes/<name>/pause` and `POST /nodes/<name>/resume` : pause or resume a node,
+//! * `DELETE /nodes/<name>` : remove a node.
+//!
+//! The scheduler is shared with the endpoint behind a mutex, which each request locks, so the owner must not keep it
+//! locked while it waits (in `wait_idle` for example). There is no authentication : listen on the loopback, or behind a
+//! proxy which checks the operators.
+//!
+//! ```rust,ignore
+//! let sched = Arc::new(Mutex::new(sched));
+//! let addr = rustfbp::admin::serve("127.0.0.1:8080", sched.clone())?;
+//! // curl -X POST http://127.0.0.1:8080/nodes/add/pause
+//! ```
+
+extern crate serde_json;
+
+use crate::result;
+use crate::result::Result;
+use crate::scheduler::{NodeState, PortStatus, Scheduler};
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::{Duration, UNIX_EPOCH};
+
+use self::serde_json::{json, Value};
+
+// The largest request head read, the bodies are ignored
+const MAX_HEAD: usize = 64 * 1024;
+
+// How long a client may take to send its request head
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Serve the admin endpoint of `sched` on `addr`, in a thread
+///
+/// Returns the address listened on, whose port is chosen by the system if `addr` has the port 0. Each connection is
+/// answered in its own thread, so a slow client doesn't hold the others.
+pub fn serve(addr: &str, sched: Arc<Mutex<Scheduler>>) -> Result<SocketAddr> {
+    let listener = TcpListener::bind(addr)?;
+    let local = listener.local_addr()?;
+    thread::Builder::new()
+        .name("admin".into())
+        .spawn(move || {
+            for stream in listener.incoming() {
+                if let Ok(stream) = stream {
+                    let sched = sched.clone();
+                    let spawned = thread::Builder::new()
+                        .name("admin request".into())
+                        .spawn(move || {
+                            if let Err(e) = answer(stream, &sched) {
+                                log::warn!("admin : {}", e);
+                            }
+                        });
+                    if let Err(e) = spawned {
+                        log::warn!("admin : cannot answer a request : {}", e);
+                    }
+                }
+            }
+        })
+        .map_err(|e| result::Error::Misc(format!("cannot start the admin endpoint : {}", e)))?;
+    Ok(local)
+}
+
+fn answer(mut stream: TcpStream, sched: &Mutex<Scheduler>) -> Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let (status, body) = match read_head(&mut stream)? {
+        Some((method, path)) => {
+            let mut sched = sched.lock().map_err(|_| result::Error::Misc("admin : poisoned scheduler".into()))?;
+            route(&mut sched, &method, &path)
+        },
+        None => (400, json!({ "error": "bad request" })),
+    };
+    let body = body.to_string();
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Internal Server Error",
+    };
+    write!(stream, "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+           status, reason, body.len(), body)?;
+    Ok(())
+}
+
+// The method and the path of the request, None if it is not HTTP
+fn read_head(stream: &mut TcpStream) -> Result<Option<(String, String)>> {
+    let mut head = vec![];
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf)?;
+        if n == 0 || head.len() + n > MAX_HEAD {
+            return Ok(None);
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+    let head = String::from_utf8_lossy(&head);
+    let mut line = head.lines().next().unwrap_or("").split_whitespace();
+    match (line.next(), line.next()) {
+        (Some(method), Some(path)) => {
+            // The query is not used
+            let path = path.split('?').next().unwrap_or(path);
+            Ok(Some((method.to_string(), path.to_string())))
+        },
+        _ => Ok(None),
+    }
+}
+
+fn route(sched: &mut Scheduler, method: &str, path: &str) -> (u16, Value) {
+    let parts: Vec<String> = path.trim_matches('/').split('/').map(percent_decode).collect();
+    let parts: Vec<&str> = parts.iter().map(|part| part.as_str()).collect();
+    match (method, &parts[..]) {
+        ("GET", ["nodes"]) => (200, nodes(sched)),
+        ("GET", ["edges"]) => (200, edges(sched)),
+        ("GET", ["metrics"]) => (200, metrics(sched)),
+        ("GET", ["nodes", name]) => match status(sched, name) {
+            Ok(status) => (200, status),
+            Err(e) => done(Err(e)),
+        },
+        ("POST", ["pause"]) => {
+            sched.pause_all();
+            (200, json!({}))
+        },
+        ("POST", ["resume"]) => {
+            sched.resume_all();
+            (200, json!({}))
+        },
+        ("POST", ["nodes", name, "pause"]) => done(sched.pause(*name)),
+        ("POST", ["nodes", name, "resume"]) => done(sched.resume(*name)),
+        ("DELETE", ["nodes", name]) => done(sched.remove_agent(*name).map(|_| ())),
+        (_, ["nodes"]) | (_, ["edges"]) | (_, ["metrics"]) | (_, ["pause"]) | (_, ["resume"]) |
+        (_, ["nodes", _]) | (_, ["nodes", _, "pause"]) | (_, ["nodes", _, "resume"]) => {
+            (405, json!({ "error": format!("{} is not allowed on {}", method, path) }))
+        },
+        _ => (404, json!({ "error": format!("no route {}", path) })),
+    }
+}
+
+fn done(res: Result<()>) -> (u16, Value) {
+    match res {
+        Ok(()) => (200, json!({})),
+        Err(e @ result::Error::NodeNotFound(_)) => (404, json!({ "error": e.to_string() })),
+        Err(e) => (400, json!({ "error": e.to_string() })),
+    }
+}
+
+fn nodes(sched: &Scheduler) -> Value {
+    Value::Array(sched.list_nodes().into_iter().map(|name| {
+        json!({ "name": name, "sort": sched.agents[&name].sort })
+    }).collect())
+}
+
+fn status(sched: &Scheduler, name: &str) -> Result<Value> {
+    let status = sched.node_status(name)?;
+    let state = match status.state {
+        NodeState::Idle => "idle",
+        NodeState::Running => "running",
+        NodeState::Blocked => "blocked",
+        NodeState::Paused => "paused",
+        NodeState::Waiting => "waiting",
+        NodeState::Ended => "ended",
+    };
+    let last_run = status.last_run
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|t| t.as_millis() as u64);
+    let ports = |ports: &[PortStatus]| -> Value {
+        Value::Array(ports.iter().map(|p| {
+            let edges: Vec<Value> = p.edges.iter().map(|&(ref agent, ref port)| json!({ "agent": agent, "port": port })).collect();
+            json!({ "port": p.port, "queued": p.queued, "edges": edges })
+        }).collect())
+    };
+    Ok(json!({
+        "name": status.name,
+        "sort": status.sort,
+        "state": state,
+        "last_run_ms": last_run,
+        "inputs": ports(&status.inputs),
+        "outputs": ports(&status.outputs),
+    }))
+}
+
+fn edges(sched: &Scheduler) -> Value {
+    let network = sched.network();
+    let network = network.lock().expect("admin edges : poisoned network");
+    Value::Array(network.links.iter().map(|l| {
+        json!({ "out_agent": l.out_agent, "out_port": l.out_port, "in_agent": l.in_agent, "in_port": l.in_port })
+    }).collect())
+}
+
+fn metrics(sched: &Scheduler) -> Value {
+    let network = sched.network();
+    let network = network.lock().expect("admin metrics : poisoned network");
+    let mut ports: Vec<_> = network.ports.iter().collect();
+    ports.sort_by(|a, b| a.0.cmp(b.0));
+    Value::Array(ports.into_iter().map(|(&(ref agent, ref port), watch)| {
+        let arena = watch.arena_stats();
+        json!({
+            "agent": agent,
+            "port": port,
+            "queued": watch.queued.load(Ordering::SeqCst),
+            "sent": watch.sent.load(Ordering::SeqCst),
+            "received": watch.received.load(Ordering::SeqCst),
+            "dropped": watch.dropped.load(Ordering::SeqCst),
+            "blocked_senders": watch.blocked_senders.load(Ordering::SeqCst),
+            "idle_ms": watch.idle_for().as_millis() as u64,
+            "arena_allocated": arena.map(|arena| arena.allocated),
+            "arena_reused": arena.map(|arena| arena.reused),
+        })
+    }).collect())
+}
+
+// `%2F` to `/` in a segment of the path, for the node names which are not plain words
+fn percent_decode(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok().and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            if let Some(b) = hex {
+                out.push(b);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
@@ -0,0 +1,67 @@
+//! The thread of an agent
+//!
+//! An agent given a `Placement` with `Scheduler::set_placement` runs on its own thread instead of the workers. The
+//! thread is named after the node, so `top -H` or `gdb` tell which agent it is, and it can be pinned to some cores
+//! for the cache locality of a pipeline. The pinning is only supported on Linux, elsewhere it is logged and ignored.
+//!
+//! A `blocking` agent gets such a thread too, without placement, at its first run.
+//!
+//! An async agent stays on the async runtime of the scheduler, without its own thread.
+
+use crate::result;
+use crate::result::Result;
+
+use std::sync::mpsc::{channel, Sender};
+use std::thread;
+
+/// Where the thread of an agent runs
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Placement {
+    /// The cores of the thread, any core if empty
+    pub cpus: Vec<usize>,
+}
+
+/// Start the thread of the node `name`, which runs the jobs sent until the sender is dropped
+pub(crate) fn spawn(name: &str, placement: Placement) -> Result<Sender<Box<FnOnce() + Send>>> {
+    let (s, r) = channel::<Box<FnOnce() + Send>>();
+    let node = name.to_string();
+    thread::Builder::new()
+        .name(name.into())
+        .spawn(move || {
+            if !placement.cpus.is_empty() {
+                if let Err(e) = pin(&placement.cpus) {
+                    log::warn!(target: node.as_str(), "{}", e);
+                }
+            }
+            for job in r {
+                job();
+            }
+        })
+        .map_err(|e| result::Error::Misc(format!("cannot start the thread of {} : {}", name, e)))?;
+    Ok(s)
+}
+
+/// Pin the current thread to the cores `cpus`
+#[cfg(target_os = "linux")]
+pub fn pin(cpus: &[usize]) -> Result<()> {
+    use std::io;
+    use std::mem;
+
+    let mut set: libc::cpu_set_t = unsafe { mem::zeroed() };
+    for &cpu in cpus {
+        if cpu >= libc::CPU_SETSIZE as usize {
+            return Err(result::Error::Misc(format!("cannot pin the thread to the cpu {}, over {}", cpu, libc::CPU_SETSIZE)));
+        }
+        unsafe { libc::CPU_SET(cpu, &mut set) };
+    }
+    if unsafe { libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set) } != 0 {
+        return Err(result::Error::Misc(format!("cannot pin the thread to the cpus {:?} : {}", cpus, io::Error::last_os_error())));
+    }
+    Ok(())
+}
+
+/// Pin the current thread to the cores `cpus`
+#[cfg(not(target_os = "linux"))]
+pub fn pin(cpus: &[usize]) -> Result<()> {
+    Err(result::Error::Misc(format!("cannot pin the thread to the cpus {:?} : only supported on Linux", cpus)))
+}
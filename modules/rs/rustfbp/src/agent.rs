@@ -6,12 +6,31 @@
 
 
 extern crate capnp;
+extern crate serde;
+extern crate serde_json;
 
 // TODO : Add method to remove agents
-use ports::{MsgSender, MsgReceiver};
-use scheduler::Signal;
-use result::Result;
-use std::any::Any;
+use crate::limits::Throttle;
+use crate::ports::{Cancel, Interrupt, PortWatch};
+use crate::remote::{RemoteReader, RemoteWriter};
+use crate::scheduler::{CompMsg, PoolInput, Signal};
+use crate::result;
+use crate::result::Result;
+use self::serde::Serialize;
+use self::serde::de::DeserializeOwned;
+use std::any::{type_name, Any, TypeId};
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::mpsc::Sender;
+
+/// The execution of an async agent, driven by the runtime of the scheduler
+pub type AgentFuture<'a> = Pin<Box<Future<Output = Result<Signal>> + Send + 'a>>;
 
 /// Provide the generic functions of agents
 ///
@@ -19,16 +38,163 @@ use std::any::Any;
 pub trait Agent {
     /// Return true if there is at least one input port
     fn is_input_ports(&self) -> bool;
+    /// Return true if the agent must be started even if it has input ports
+    fn autostart(&self) -> bool;
+    /// Return true if the agent must run on its own thread, instead of a worker of the scheduler
+    fn is_blocking(&self) -> bool;
+    /// Return true if the agent is an `async_agent!`, which runs with `run_async`
+    fn is_async(&self) -> bool;
+    /// The flush timeout of the batch port or of the accumulator, in ms
+    fn batch_flush(&self) -> u64;
     /// Connect output port
     fn connect(&mut self, port: &str, sender: Box<Any + Send>) -> Result<()>;
     /// Connect array output port
     fn connect_array(&mut self, port: &str, element: String, sender: Box<Any + Send>) -> Result<()>;
+    /// Disconnect output port
+    fn disconnect(&mut self, port: &str) -> Result<()>;
+    /// Disconnect an element of an array output port
+    fn disconnect_array(&mut self, port: &str, element: &str) -> Result<()>;
+    /// Copy a sample of the messages of an output port to a sink, `None` stops the copies
+    fn tap(&mut self, port: &str, tap: Option<(Box<Any + Send>, f64)>) -> Result<()>;
+    /// Keep the last messages of an output port in a trace, `None` stops the trace
+    fn trace(&mut self, port: &str, trace: Option<crate::trace::SharedTrace>) -> Result<()>;
     /// Add input element
     fn add_inarr_element(&mut self, port: &str, element: String, recv: Box<Any + Send>) -> Result<()>;
     /// Run the method of the agent, his personal logic
     fn run(&mut self) -> Result<Signal>;
+    /// Get the execution of an async agent
+    fn run_async<'a>(&'a mut self) -> Option<AgentFuture<'a>>;
+    /// Save the state of the agent, empty if the agent has no `state_serde`
+    fn snapshot(&self) -> Result<Vec<u8>>;
+    /// Set back the state saved by `snapshot`
+    fn restore(&mut self, bytes: &[u8]) -> Result<()>;
+    /// Give the agent its name in the network and the logger of the scheduler, for its log entries
+    fn set_log(&mut self, log: crate::log::Context);
+    /// Interrupts the blocking receives of the agent when a new option comes, if its option is live
+    fn interrupt(&self) -> Option<Interrupt> {
+        None
+    }
+    /// Cancels the receives and the sends of the agent, when it is over its run time with `LimitAction::Cancel`
+    fn cancel(&self) -> Option<Cancel> {
+        None
+    }
+}
+
+/// Serialize the state of a `state_serde` agent
+#[doc(hidden)]
+pub fn snapshot_state<T: Serialize>(state: &T) -> Result<Vec<u8>> {
+    Ok(serde_json::to_vec(state)?)
+}
+
+/// Deserialize the state of a `state_serde` agent
+#[doc(hidden)]
+pub fn restore_state<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    Ok(serde_json::from_slice(bytes)?)
+}
+
+/// Downcast the sender or the receiver of the port `port`, which is of another edge after a user mistake
+#[doc(hidden)]
+pub fn downcast<T: Any>(port: &str, boxed: Box<Any + Send>) -> Result<Box<T>> {
+    boxed.downcast::<T>().map_err(|_| mismatch::<T>(port))
+}
+
+/// Borrow the sender of the port `port`, see `downcast`
+#[doc(hidden)]
+pub fn downcast_ref<'a, T: Any>(port: &str, boxed: &'a Box<Any + Send>) -> Result<&'a T> {
+    boxed.downcast_ref::<T>().ok_or_else(|| mismatch::<T>(port))
+}
+
+fn mismatch<T>(port: &str) -> result::Error {
+    result::Error::TypeMismatch {
+        port: port.into(),
+        expected: type_name::<T>().into(),
+        found: "another edge".into(),
+    }
+}
+
+/// The version of the functions exported by the dylib of an agent, bumped each time one of their signatures changes
+pub const ABI_VERSION: u32 = 5;
+
+/// The ABI an agent was built against, exported by its dylib as `rustfbp_abi`
+///
+/// The exported functions are `extern "C"` only for their calling convention : they pass Rust types (`Box<Any>`,
+/// `&str`, `Sender<CompMsg>`, ...), so this ABI is not FFI-safe. Only agents built with the same compiler and the
+/// same rustfbp as the scheduler are supported, as the nix build does.
+///
+/// The fingerprint hashes the `TypeId`s and the sizes of these types with `DefaultHasher`, none of which is stable
+/// across compilers, so the scheduler compares it with its own before calling any other function of the dylib. It
+/// is a best effort check against a mixed build, not a guarantee that two builds are compatible.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AgentAbi {
+    pub version: u32,
+    pub fingerprint: u64,
+}
+
+impl AgentAbi {
+    /// The ABI of this build of rustfbp
+    pub fn current() -> Self {
+        let mut hasher = DefaultHasher::new();
+        TypeId::of::<AgentExports>().hash(&mut hasher);
+        TypeId::of::<CompMsg>().hash(&mut hasher);
+        TypeId::of::<result::Error>().hash(&mut hasher);
+        TypeId::of::<Box<Any + Send>>().hash(&mut hasher);
+        mem::size_of::<CompMsg>().hash(&mut hasher);
+        mem::size_of::<Sender<CompMsg>>().hash(&mut hasher);
+        mem::size_of::<Result<String>>().hash(&mut hasher);
+        AgentAbi {
+            version: ABI_VERSION,
+            fingerprint: hasher.finish(),
+        }
+    }
+}
+
+impl fmt::Display for AgentAbi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{:016x}", self.version, self.fingerprint)
+    }
 }
 
+/// The names of the ports of an agent, as declared in `agent!`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Ports {
+    pub inputs: Vec<String>,
+    pub input_arrays: Vec<String>,
+    pub outputs: Vec<String>,
+    pub output_arrays: Vec<String>,
+    /// `option`, and the named options
+    pub options: Vec<String>,
+}
+
+/// The functions exported by the dylib of an agent, for one edge of a `generic` agent
+///
+/// The scheduler gets them with the function `generic_agent` of the dylib, instead of one symbol per function, or
+/// from the mocks of `rustfbp::testing`. The table is versioned by `ABI_VERSION`, and is not FFI-safe : see
+/// `AgentAbi`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct AgentExports {
+    pub create_agent: extern "C" fn(usize, Sender<CompMsg>) -> Result<(Box<Agent + Send>, HashMap<String, Box<Any + Send>>)>,
+    pub clone_input: extern "C" fn(&str, &Box<Any + Send>) -> Result<Box<Any + Send>>,
+    pub clone_input_array: extern "C" fn(&str, &Box<Any + Send>) -> Result<Box<Any + Send>>,
+    pub watch_input: extern "C" fn(&str, &Box<Any + Send>) -> Result<Arc<PortWatch>>,
+    pub watch_input_array: extern "C" fn(&str, &Box<Any + Send>) -> Result<Arc<PortWatch>>,
+    pub throttle_input: extern "C" fn(&str, &Box<Any + Send>, Arc<Throttle>) -> Result<Box<Any + Send>>,
+    pub close_input: extern "C" fn(&str, &Box<Any + Send>) -> Result<()>,
+    pub reopen_input: extern "C" fn(&str, &Box<Any + Send>) -> Result<()>,
+    pub create_input_array: extern "C" fn(&str, usize, Sender<CompMsg>, bool) -> Result<(Box<Any + Send>, Box<Any + Send>)>,
+    pub remote_output: extern "C" fn(&str, usize, Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)>,
+    pub remote_pipe: extern "C" fn(&str, usize, Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)>,
+    pub remote_input: extern "C" fn(&str, &Box<Any + Send>) -> Result<RemoteWriter>,
+    pub pool_input: extern "C" fn(&str, Vec<Box<Any + Send>>, PoolInput, Sender<CompMsg>) -> Result<Box<Any + Send>>,
+    pub pool_output: extern "C" fn(&str, &Box<Any + Send>, usize, Sender<CompMsg>) -> Result<Vec<Box<Any + Send>>>,
+    pub get_schema_input: extern "C" fn(&str) -> Result<String>,
+    pub get_schema_input_array: extern "C" fn(&str) -> Result<String>,
+    pub get_schema_output: extern "C" fn(&str) -> Result<String>,
+    pub get_schema_output_array: extern "C" fn(&str) -> Result<String>,
+    pub agent_version: extern "C" fn() -> &'static str,
+    pub get_ports: extern "C" fn() -> Ports,
+}
 
 /// The agent macro.
 ///
@@ -40,18 +206,16 @@ pub trait Agent {
 /// agent! {
 ///    inputs(input: any),
 ///    outputs(output: any),
-///    option(prim_text),
+///    option(PrimText),
 ///    fn run(&mut self) -> Result<Signal> {
 ///        // Receive an IP
 ///        let msg = try!(self.input.input.recv());
 ///
-///        // Received an IP from the option port (a prim_text)
-///        let opt = self.recv_opt();
+///        // Received an IP from the option port, the typed PrimText (see `capnp_edge`)
+///        let opt = self.recv_option();
 ///
-///        // Get the capn'p reader
-///        let reader: prim_text::Reader = try!(opt.read_schema());
 ///        // Print the option
-///        println!("{}", try!(reader.get_text()));
+///        println!("{}", opt.text);
 ///
 ///        // Send the received IP outside, but don't care about the success (drop on fail)
 ///        let _ = self.output.output.send(msg);
@@ -60,21 +224,120 @@ pub trait Agent {
 ///    }
 /// }
 /// ```
+///
+/// An agent with input ports only runs when it receives a message. `autostart(true)` also runs it once when the
+/// scheduler starts, for agents that listen to the outside world (a socket, a timer, ...).
+///
+/// The agents share a few worker threads. `blocking(true)` runs the agent on its own thread, for agents that wait
+/// a long time in `run` (a blocking read, a sleep, an endless loop, a `send` on a throttled edge, ...).
+///
+/// `run` returns a `Signal` : `End` when the agent is done, `Continue` to keep the scheduler running for it, `Yield`
+/// to run again after the other ready agents (a streaming agent working by slices instead of holding a thread),
+/// and `WaitFor("input".into())` to only run again when a message waits on the port `input`.
+///
+/// ```rust,ignore
+/// let chunk = self.compress_next(4096)?;
+/// self.output.output.send(chunk)?;
+/// Ok(if self.done() { WaitFor("input".into()) } else { Yield })
+/// ```
+///
+/// `batch(input: 64)` only runs the agent when 64 messages wait on the input port `input`, or 100 ms after the
+/// first of them (`batch(input: 64, 20)` for 20 ms). `run` takes them with `self.input.input.recv_batch()`.
+///
+/// `input(input: DbInsert [ack])` puts the port `input` in ack mode. `run` takes the messages with
+/// `self.input.input.recv_ack()`, and must call `ack()` on each of them : a message not acknowledged within
+/// `ACK_TIMEOUT` is sent again in the port. `reject()` sends it to the dead letters of the scheduler instead.
+///
+/// `state_serde(Counter => Counter::default())` declares a state like `state`, which must implement the serde
+/// `Serialize` and `Deserialize` traits. The scheduler can then save it with `checkpoint`, and set it back with
+/// `restore`.
+///
+/// `accumulator(u64, init = 0, flush = 1000)` makes a streaming fold : `self.acc_update(|n| *n += 1)?` updates the
+/// accumulator, which starts at `init` (or the message of the graph on the port `accumulator`), and `acc_flush()?`
+/// takes it and starts again from `init`. The agent flushes on the end of a substream, or when `acc_flush_due()`
+/// says that `flush` ms passed since the first update : the agent is then run even without message, so it must
+/// take its messages with `try_recv`.
+///
+/// ```rust,ignore
+/// if self.acc_flush_due() {
+///     if let Some(n) = self.acc_flush()? { self.output.count.send(n)?; }
+/// }
+/// match self.input.input.try_recv() {
+///     Ok(FsFileDesc::End(_)) => if let Some(n) = self.acc_flush()? { self.output.count.send(n)?; },
+///     Ok(_) => self.acc_update(|n| *n += 1)?,
+///     Err(_) => {},
+/// }
+/// ```
+///
+/// `options(config: AppConfig, credentials: SecretText)` declares several named option ports, each keeping its last
+/// option like `option` : `self.options.config.recv()?` (see `OptionPort`). They can be live too, with
+/// `options(config: AppConfig [live])`.
+///
+/// `option(AppConfig [live])` makes the option live : a new option interrupts a blocking `recv` of the agent, which
+/// returns `Error::Interrupted` if no message waits. A long-lived agent takes the new option with
+/// `self.on_option_changed()`, and reconfigures itself. An execution ended by `Error::Interrupted` is not a failure.
+///
+/// The agent logs with `log_info!(self, ...)`, `log_warn!` and `log_error!`, see `rustfbp::log`.
+///
+/// `generic(T: PrimText, PrimU64)`, before the ports, builds the agent once for each of the edges, with `T` the edge.
+/// The graph picks one with the sort `flow_delay<PrimText>`. The agent is built in a module per edge, so the
+/// functions of `lib.rs` used by `run` must be declared out of `agent!`.
+///
+/// ```rust,ignore
+/// agent! {
+///    generic(T: PrimText, PrimU64),
+///    input(input: T),
+///    output(output: T),
+///    fn run(&mut self) -> Result<Signal> {
+///        let msg = self.input.input.recv()?;
+///        self.output.output.send(msg)?;
+///        Ok(End)
+///    }
+/// }
+/// ```
+///
+/// A port used in `run` but not declared, like `self.input.inptu`, is a compile error naming the port and listing
+/// the declared ones (see `rustfbp_macros::check_ports`).
+///
+/// An agent can also be declared by a structure whose fields are its ports, with `#[agent_struct]` (see
+/// `rustfbp_macros::agent_struct`), which expands to `agent!`.
+///
+/// `run_async` is only set by `async_agent!`.
 #[macro_export]
 macro_rules! agent {
     (
-        $( input($( $input_name:ident: $input_contract:ident ),*), )*
-        $( inarr($( $input_a_name:ident: $input_a_contract:ident ),*), )*
-        $( output($( $output_name:ident: $output_contract:ident ),*), )*
-        $( outarr($( $output_a_name:ident: $output_a_contract:ident ),*), )*
+        @exports[$( $export:meta ),*]
+        $( @$edges:ident )*
+        $( input($( $input_name:ident: $input_contract:ty $( [$input_ack:ident] )* ),*), )*
+        $( inarr($( $input_a_name:ident: $input_a_contract:ty ),*), )*
+        $( output($( $output_name:ident: $output_contract:ty ),*), )*
+        $( outarr($( $output_a_name:ident: $output_a_contract:ty ),*), )*
         $( state( $state_type:ty => $state_value:expr ), )*
-        $( option($option:ident), )*
-        $( accumulator($accumulator:ident ), )*
+        $( state_serde( $serde_type:ty => $serde_value:expr ), )*
+        $( option($option:ty $( [$option_live:ident] )* ), )*
+        $( options($( $options_name:ident: $options_contract:ty $( [$options_live:ident] )* ),*), )*
+        $( accumulator($accumulator:ty $(, init = $acc_init:expr $(, flush = $acc_flush:expr)* )* ), )*
+        $( autostart($autostart:expr), )*
+        $( blocking($blocking:expr), )*
+        $( batch($batch_name:ident: $batch_size:expr $(, $batch_flush:expr)* ), )*
+        $( run_async($run_async:ident), )*
         fn run(&mut $arg:ident) -> Result<Signal> $fun:block
     )
         =>
     {
-        use rustfbp::agent::Agent;
+        rustfbp::check_ports! {
+            input($($( $input_name )*)*)
+            inarr($($( $input_a_name )*)*)
+            output($($( $output_name )*)*)
+            outarr($($( $output_a_name )*)*)
+            options($($( $options_name )*)*)
+            option($( ($option) )*)
+            accumulator($( ($accumulator) )*)
+            batch($( $batch_name )*)
+            run($arg) $fun
+        }
+
+        use rustfbp::agent::{Agent, AgentFuture};
 
         use rustfbp::result;
         use rustfbp::result::Result;
@@ -84,7 +347,15 @@ macro_rules! agent {
         use std::sync::mpsc::{Sender};
         use std::sync::mpsc::channel;
 
-        use rustfbp::ports::{MsgSender, MsgReceiver, OutputSend};
+        #[allow(unused_imports)]
+        use rustfbp::ports::{MsgSender, MsgReceiver, OutputPort, OutputSend, SerdeEdge, SharedMsg, CapnpMsg, Priority, select,
+                             TrySendError, SendTimeoutError};
+        #[allow(unused_imports)]
+        use rustfbp::remote::{Remote, RemoteCodec, NoRemoteCodec, RemoteReader, RemoteWriter};
+        #[allow(unused_imports)]
+        use rustfbp::ports::{Tapper, TapEdge, NoTapEdge, Sizer, SizeEdge, NoSizeEdge};
+        #[allow(unused_imports)]
+        use rustfbp::trace::{SharedTrace, Tracer, TraceEdge, NoTraceEdge};
 
         #[allow(unused_imports)]
         use std::collections::HashMap;
@@ -97,15 +368,7 @@ macro_rules! agent {
 
         use rustfbp::scheduler::Signal::*;
 
-        mod edge_capnp {
-                include!("edge_capnp.rs");
-        }
-        use edge_capnp::*;
-
-        mod edges {
-                include!("edges.rs");
-        }
-        use edges::*;
+        $( agent!(@$edges); )*
 
         impl ThisAgent {
             $(
@@ -122,6 +385,9 @@ macro_rules! agent {
             }
 
             pub fn try_recv_option(&mut self) -> Option<$option> {
+                if let Some(ref interrupt) = self.interrupt {
+                    interrupt.clear();
+                }
                 loop {
                     match self.input.option.try_recv() {
                         Err(_) => { break; },
@@ -130,10 +396,91 @@ macro_rules! agent {
                 }
                 self.option_msg.as_ref().map(|msg|{ msg.clone() })
             }
+
+            /// The new option, if one came since the last `recv_option`, `try_recv_option` or `on_option_changed`
+            pub fn on_option_changed(&mut self) -> Option<$option> {
+                if let Some(ref interrupt) = self.interrupt {
+                    interrupt.clear();
+                }
+                let mut changed = false;
+                while let Ok(msg) = self.input.option.try_recv() {
+                    self.option_msg = Some(msg);
+                    changed = true;
+                }
+                if changed { self.option_msg.clone() } else { None }
+            }
             )*
 
+            /// Signal that the agent is ready : the agents started after it with `Scheduler::start_after` can run
+            #[allow(dead_code)]
+            pub fn ready(&self) -> Result<()> {
+                self.sched.send(CompMsg::Ready(self.id))?;
+                Ok(())
+            }
         }
 
+        $(
+        impl ThisAgent {
+            // The `init` of the accumulator, if any
+            #[allow(unreachable_code)]
+            fn acc_init() -> Option<$accumulator> {
+                $(
+                    return Some($acc_init);
+                )*
+                None
+            }
+
+            // The current accumulator : the last one sent, else the one of the graph or the `init`
+            fn acc_take(&mut self) -> Result<$accumulator> {
+                match self.input.accumulator.try_recv() {
+                    Ok(acc) => Ok(acc),
+                    Err(_) => match Self::acc_init() {
+                        Some(init) => Ok(init),
+                        None => self.input.accumulator.recv(),
+                    },
+                }
+            }
+
+            /// Update the accumulator with `f`, and send it back on the accumulator port for the next update
+            pub fn acc_update<F: FnOnce(&mut $accumulator)>(&mut self, f: F) -> Result<()> {
+                let mut acc = self.acc_take()?;
+                f(&mut acc);
+                if self.acc_since.is_none() {
+                    self.acc_since = Some(std::time::Instant::now());
+                    $($(
+                        let _ = $acc_flush;
+                        self.sched.send(CompMsg::ArmFlush(self.id))?;
+                    )*)*
+                }
+                self.output.accumulator.send(acc)
+            }
+
+            /// Take the accumulator and start again from the `init`, None if it was not updated since the last flush
+            ///
+            /// Without `init`, the next update waits for a new accumulator on the port.
+            pub fn acc_flush(&mut self) -> Result<Option<$accumulator>> {
+                if self.acc_since.take().is_none() {
+                    return Ok(None);
+                }
+                let acc = self.acc_take()?;
+                if let Some(init) = Self::acc_init() {
+                    self.output.accumulator.send(init)?;
+                }
+                Ok(Some(acc))
+            }
+
+            /// True when the `flush` timeout is over since the first update after the last flush
+            pub fn acc_flush_due(&self) -> bool {
+                $($(
+                    if let Some(since) = self.acc_since {
+                        return since.elapsed() >= std::time::Duration::from_millis($acc_flush);
+                    }
+                )*)*
+                false
+            }
+        }
+        )*
+
         impl Agent for ThisAgent {
 
             fn is_input_ports(&self) -> bool {
@@ -146,18 +493,54 @@ macro_rules! agent {
                 false
             }
 
+            fn autostart(&self) -> bool {
+                $(
+                    return $autostart;
+                )*
+                #[allow(unreachable_code)]
+                false
+            }
+
+            fn is_blocking(&self) -> bool {
+                $(
+                    return $blocking;
+                )*
+                #[allow(unreachable_code)]
+                false
+            }
+
+            fn is_async(&self) -> bool {
+                $(
+                    if true || stringify!($run_async) == "" { return true; }
+                )*
+                false
+            }
+
+            fn batch_flush(&self) -> u64 {
+                $($(
+                    return $batch_flush;
+                )*)*
+                $($($(
+                    return $acc_flush;
+                )*)*)*
+                #[allow(unreachable_code)]
+                100
+            }
+
             fn connect(&mut self, port: &str, sender: Box<Any + Send>) -> Result<()> {
                 match port {
                     $($(
                         stringify!($output_name) => {
-                            let s = sender.downcast::<MsgSender<$output_contract>>().expect("cannot downast");
-                            self.output.$output_name = Some(*s);
+                            let mut s = rustfbp::agent::downcast::<MsgSender<$output_contract>>(port, sender)?;
+                            s.set_cancel(self.cancel.clone());
+                            *self.output.$output_name = Some(*s);
                         }
                     )*)*
                         _ => {
                             return Err(result::Error::PortDontExist(port.into()));
                         }
                 }
+                #[allow(unreachable_code)]
                 Ok(())
             }
 
@@ -165,7 +548,8 @@ macro_rules! agent {
                 match port {
                     $($(
                         stringify!($output_a_name) => {
-                            let s = sender.downcast::<MsgSender<$output_a_contract>>().expect("cannot downcast");
+                            let mut s = rustfbp::agent::downcast::<MsgSender<$output_a_contract>>(port, sender)?;
+                            s.set_cancel(self.cancel.clone());
                             self.outarr.$output_a_name.insert(element, *s);
                         }
                     )*)*
@@ -173,6 +557,75 @@ macro_rules! agent {
                             return Err(result::Error::PortDontExist(port.into()));
                         }
                 }
+                #[allow(unreachable_code)]
+                Ok(())
+            }
+
+            fn disconnect(&mut self, port: &str) -> Result<()> {
+                match port {
+                    $($(
+                        stringify!($output_name) => {
+                            *self.output.$output_name = None;
+                        }
+                    )*)*
+                        _ => {
+                            return Err(result::Error::PortDontExist(port.into()));
+                        }
+                }
+                #[allow(unreachable_code)]
+                Ok(())
+            }
+
+            fn tap(&mut self, port: &str, tap: Option<(Box<Any + Send>, f64)>) -> Result<()> {
+                match port {
+                    $($(
+                        stringify!($output_name) => {
+                            let tap = match tap {
+                                Some((sink, rate)) => Some((&&Tapper::<$output_contract>::new()).tap(sink, rate)?),
+                                None => None,
+                            };
+                            self.output.$output_name.set_tap(tap);
+                        }
+                    )*)*
+                        _ => {
+                            return Err(result::Error::PortDontExist(port.into()));
+                        }
+                }
+                #[allow(unreachable_code)]
+                Ok(())
+            }
+
+            fn trace(&mut self, port: &str, trace: Option<SharedTrace>) -> Result<()> {
+                match port {
+                    $($(
+                        stringify!($output_name) => {
+                            let trace = match trace {
+                                Some(trace) => Some((trace, (&&Tracer::<$output_contract>::new()).encoder()?)),
+                                None => None,
+                            };
+                            self.output.$output_name.set_trace(trace);
+                        }
+                    )*)*
+                        _ => {
+                            return Err(result::Error::PortDontExist(port.into()));
+                        }
+                }
+                #[allow(unreachable_code)]
+                Ok(())
+            }
+
+            fn disconnect_array(&mut self, port: &str, element: &str) -> Result<()> {
+                match port {
+                    $($(
+                        stringify!($output_a_name) => {
+                            self.outarr.$output_a_name.remove(element);
+                        }
+                    )*)*
+                        _ => {
+                            return Err(result::Error::PortDontExist(port.into()));
+                        }
+                }
+                #[allow(unreachable_code)]
                 Ok(())
             }
 
@@ -180,7 +633,11 @@ macro_rules! agent {
                 match port {
                     $($(
                         stringify!($input_a_name) => {
-                            let r = recv.downcast::<MsgReceiver<$input_a_contract>>().expect("cannot downcast");
+                            let mut r = rustfbp::agent::downcast::<MsgReceiver<$input_a_contract>>(port, recv)?;
+                            if let Some(ref interrupt) = self.interrupt {
+                                r.set_interrupt(interrupt.clone());
+                            }
+                            r.set_cancel(self.cancel.clone());
                             self.inarr.$input_a_name.insert(element, *r);
                             Ok(())
                         }
@@ -191,10 +648,56 @@ macro_rules! agent {
                 }
             }
 
-            fn run(&mut $arg) -> Result<Signal> $fun
+            fn run(&mut $arg) -> Result<Signal> {
+                $arg.log.next_run();
+                $fun
+            }
+
+            fn run_async<'a>(&'a mut self) -> Option<AgentFuture<'a>> {
+                $(
+                    self.log.next_run();
+                    return Some(self.$run_async());
+                )*
+                #[allow(unreachable_code)]
+                None
+            }
+
+            fn snapshot(&self) -> Result<Vec<u8>> {
+                $(
+                    return rustfbp::agent::snapshot_state::<$serde_type>(&self.state);
+                )*
+                #[allow(unreachable_code)]
+                Ok(vec![])
+            }
+
+            fn set_log(&mut self, log: rustfbp::log::Context) {
+                self.log = log;
+            }
+
+            fn interrupt(&self) -> Option<rustfbp::ports::Interrupt> {
+                self.interrupt.clone()
+            }
+
+            fn cancel(&self) -> Option<rustfbp::ports::Cancel> {
+                Some(self.cancel.clone())
+            }
+
+            #[allow(unused_variables)]
+            fn restore(&mut self, bytes: &[u8]) -> Result<()> {
+                $(
+                    self.state = rustfbp::agent::restore_state::<$serde_type>(bytes)?;
+                )*
+                Ok(())
+            }
 
         }
 
+        pub struct Options {
+            $($(
+                pub $options_name: rustfbp::ports::OptionPort<$options_contract>,
+            )*)*
+        }
+
         pub struct Input {
             $($(
                 $input_name: MsgReceiver<$input_contract>,
@@ -221,7 +724,7 @@ macro_rules! agent {
 
         pub struct Output {
             $($(
-                $output_name: Option<MsgSender<$output_contract>>,
+                $output_name: OutputPort<$output_contract>,
             )*)*
             $(
                 accumulator: Option<MsgSender<$accumulator>>,
@@ -236,6 +739,7 @@ macro_rules! agent {
         pub struct ThisAgent {
             id: usize,
             pub input: Input,
+            pub options: Options,
             pub output: Output,
             pub inarr: Inarr,
             pub outarr: Outarr,
@@ -243,19 +747,43 @@ macro_rules! agent {
                 pub option_msg: Option<$option>,
             )*
             sched: Sender<CompMsg>,
+            pub log: rustfbp::log::Context,
+            interrupt: Option<rustfbp::ports::Interrupt>,
+            cancel: rustfbp::ports::Cancel,
+            // The first update of the accumulator since the last flush
+            acc_since: Option<std::time::Instant>,
             $(
             pub state: $state_type ,
             )*
+            $(
+            pub state: $serde_type ,
+            )*
         }
 
         #[allow(dead_code)]
         pub fn new(id: usize, sched: Sender<CompMsg>) -> Result<(Box<Agent + Send>, HashMap<String, Box<Any + Send>>)> {
 
             let mut senders: HashMap<String, Box<Any + Send>> = HashMap::new();
+            #[allow(unused_mut)]
+            let mut interrupt: Option<rustfbp::ports::Interrupt> = None;
             $(
                 let option = MsgReceiver::<$option>::new(id, sched.clone(), false);
+                $(
+                    let _ = stringify!($option_live);
+                    let option = (option.0, option.1.live_option());
+                    interrupt = Some(rustfbp::ports::Interrupt::new());
+                )*
                 senders.insert("option".to_string(), Box::new(option.1));
             )*
+            $($(
+                let $options_name = MsgReceiver::<$options_contract>::new(id, sched.clone(), false);
+                $(
+                    let _ = stringify!($options_live);
+                    let $options_name = ($options_name.0, $options_name.1.live_option());
+                    interrupt = Some(rustfbp::ports::Interrupt::new());
+                )*
+                senders.insert(stringify!($options_name).to_string(), Box::new($options_name.1));
+            )*)*
 
             $(
                 let accumulator = MsgReceiver::<$accumulator>::new(id, sched.clone(), false);
@@ -263,11 +791,21 @@ macro_rules! agent {
             )*
 
             $($(
-                let $input_name = MsgReceiver::<$input_contract>::new(id, sched.clone(), true);
+                let $input_name = MsgReceiver::<$input_contract>::sized(id, sched.clone(), true, (&&Sizer::<$input_contract>::new()).size());
+                $(
+                    let _ = stringify!($input_ack);
+                    let $input_name = ($input_name.0.with_ack(stringify!($input_name), rustfbp::ports::ACK_TIMEOUT), $input_name.1);
+                )*
                 senders.insert(stringify!($input_name).to_string(), Box::new($input_name.1));
             )*)*
 
-            let input = Input {
+            $(
+                let $batch_name = $batch_name.0.batched($batch_size);
+                senders.insert(stringify!($batch_name).to_string(), Box::new($batch_name.1));
+            )*
+
+            #[allow(unused_mut)]
+            let mut input = Input {
                 $($(
                     $input_name: $input_name.0,
                 )*)*
@@ -278,9 +816,27 @@ macro_rules! agent {
                     accumulator: accumulator.0 as MsgReceiver::<$accumulator>,
                 )*
             };
+            let cancel = rustfbp::ports::Cancel::new();
+            $($(
+                if let Some(ref interrupt) = interrupt {
+                    input.$input_name.set_interrupt(interrupt.clone());
+                }
+                input.$input_name.set_cancel(cancel.clone());
+            )*)*
+            #[allow(unused_mut)]
+            let mut options = Options {
+                $($(
+                    $options_name: rustfbp::ports::OptionPort::new($options_name.0),
+                )*)*
+            };
+            $($(
+                if let Some(ref interrupt) = interrupt {
+                    options.$options_name.set_interrupt(interrupt.clone());
+                }
+            )*)*
             let output = Output {
                 $($(
-                    $output_name: None,
+                    $output_name: OutputPort::new(id, stringify!($output_name), sched.clone()),
                 )*)*
                 $(
                     accumulator: Some(accumulator.1) as Option<MsgSender::<$accumulator>>,
@@ -300,6 +856,7 @@ macro_rules! agent {
             let agent= ThisAgent {
                 id: id,
                 input: input,
+                options: options,
                 output: output,
                 inarr: inarr,
                 outarr: outarr,
@@ -307,37 +864,50 @@ macro_rules! agent {
                     option_msg: None as Option<$option>,
                 )*
                 sched: sched,
+                log: rustfbp::log::Context::new(format!("agent-{}", id)),
+                interrupt: interrupt,
+                cancel: cancel,
+                acc_since: None,
                 $(
                     state: $state_value,
                 )*
+                $(
+                    state: $serde_value,
+                )*
             };
 
             Ok((Box::new(agent) as Box<Agent + Send>, senders))
         }
 
-        #[no_mangle]
+        $( #[$export] )*
         pub extern fn create_agent(id: usize, sched: Sender<CompMsg>) -> Result<(Box<Agent + Send>, HashMap<String, Box<Any + Send>>)> {
             new(id, sched)
         }
 
-        #[no_mangle]
+        $( #[$export] )*
         pub extern fn clone_input(port: &str, sender: &Box<Any + Send>) -> Result<Box<Any + Send>> {
             match port {
                 $($(
                     stringify!($input_name) => {
-                        let s = sender.downcast_ref::<MsgSender<$input_contract>>().unwrap();
+                        let s = rustfbp::agent::downcast_ref::<MsgSender<$input_contract>>(port, sender)?;
                         Ok(Box::new(s.clone()))
                     },
                 )*)*
                     $(
                         "option" => {
-                            let s = sender.downcast_ref::<MsgSender<$option>>().unwrap();
+                            let s = rustfbp::agent::downcast_ref::<MsgSender<$option>>(port, sender)?;
                             Ok(Box::new(s.clone()))
                         }
                     )*
+                    $($(
+                        stringify!($options_name) => {
+                            let s = rustfbp::agent::downcast_ref::<MsgSender<$options_contract>>(port, sender)?;
+                            Ok(Box::new(s.clone()))
+                        },
+                    )*)*
                     $(
                         "accumulator" => {
-                            let s = sender.downcast_ref::<MsgSender<$accumulator>>().unwrap();
+                            let s = rustfbp::agent::downcast_ref::<MsgSender<$accumulator>>(port, sender)?;
                             Ok(Box::new(s.clone()))
                         }
                     )*
@@ -345,12 +915,12 @@ macro_rules! agent {
             }
         }
 
-        #[no_mangle]
+        $( #[$export] )*
         pub extern fn clone_input_array(port: &str, sender: &Box<Any + Send>) -> Result<Box<Any + Send>> {
             match port {
                 $($(
                     stringify!($input_a_name) => {
-                        let s = sender.downcast_ref::<MsgSender<$input_a_contract>>().unwrap();
+                        let s = rustfbp::agent::downcast_ref::<MsgSender<$input_a_contract>>(port, sender)?;
                         Ok(Box::new(s.clone()))
                     },
                 )*)*
@@ -358,12 +928,85 @@ macro_rules! agent {
             }
         }
 
-        #[no_mangle]
+        $( #[$export] )*
+        pub extern fn watch_input(port: &str, sender: &Box<Any + Send>) -> Result<std::sync::Arc<rustfbp::ports::PortWatch>> {
+            match port {
+                $($(
+                    stringify!($input_name) => {
+                        Ok(rustfbp::agent::downcast_ref::<MsgSender<$input_contract>>(port, sender)?.watch())
+                    },
+                )*)*
+                    _ => { Err(result::Error::PortDontExist(port.into())) }
+            }
+        }
+
+        $( #[$export] )*
+        pub extern fn watch_input_array(port: &str, sender: &Box<Any + Send>) -> Result<std::sync::Arc<rustfbp::ports::PortWatch>> {
+            match port {
+                $($(
+                    stringify!($input_a_name) => {
+                        Ok(rustfbp::agent::downcast_ref::<MsgSender<$input_a_contract>>(port, sender)?.watch())
+                    },
+                )*)*
+                    _ => { Err(result::Error::PortDontExist(port.into())) }
+            }
+        }
+
+        $( #[$export] )*
+        pub extern fn throttle_input(port: &str, sender: &Box<Any + Send>, throttle: std::sync::Arc<rustfbp::limits::Throttle>) -> Result<Box<Any + Send>> {
+            match port {
+                $($(
+                    stringify!($input_name) => {
+                        Ok(Box::new(rustfbp::agent::downcast_ref::<MsgSender<$input_contract>>(port, sender)?.throttled(throttle)))
+                    },
+                )*)*
+                    _ => { Err(result::Error::PortDontExist(port.into())) }
+            }
+        }
+
+        // The end of stream of a simple or an array input port, see `MsgSender::close`
+        $( #[$export] )*
+        pub extern fn close_input(port: &str, sender: &Box<Any + Send>) -> Result<()> {
+            match port {
+                $($(
+                    stringify!($input_name) => {
+                        rustfbp::agent::downcast_ref::<MsgSender<$input_contract>>(port, sender)?.close()
+                    },
+                )*)*
+                $($(
+                    stringify!($input_a_name) => {
+                        rustfbp::agent::downcast_ref::<MsgSender<$input_a_contract>>(port, sender)?.close()
+                    },
+                )*)*
+                    _ => { Err(result::Error::PortDontExist(port.into())) }
+            }
+        }
+
+        // Open a closed simple or array input port again, see `MsgSender::reopen`
+        $( #[$export] )*
+        pub extern fn reopen_input(port: &str, sender: &Box<Any + Send>) -> Result<()> {
+            match port {
+                $($(
+                    stringify!($input_name) => {
+                        rustfbp::agent::downcast_ref::<MsgSender<$input_contract>>(port, sender)?.reopen()
+                    },
+                )*)*
+                $($(
+                    stringify!($input_a_name) => {
+                        rustfbp::agent::downcast_ref::<MsgSender<$input_a_contract>>(port, sender)?.reopen()
+                    },
+                )*)*
+                    _ => { Err(result::Error::PortDontExist(port.into())) }
+            }
+        }
+
+        $( #[$export] )*
         pub extern fn create_input_array(port: &str, id: usize, sched: Sender<CompMsg>, must_sched: bool ) -> Result<(Box<Any + Send>, Box<Any + Send>)> {
             match port {
                 $($(
                     stringify!($input_a_name) => {
-                        let (r, s): (MsgReceiver::<$input_a_contract>, MsgSender::<$input_a_contract>) = MsgReceiver::new(id, sched, must_sched);
+                        let (r, s): (MsgReceiver::<$input_a_contract>, MsgSender::<$input_a_contract>) =
+                            MsgReceiver::sized(id, sched, must_sched, (&&Sizer::<$input_a_contract>::new()).size());
                         Ok((Box::new(r), Box::new(s)))
                     },
                 )*)*
@@ -371,7 +1014,134 @@ macro_rules! agent {
             }
         }
 
-        #[no_mangle]
+        $( #[$export] )*
+        pub extern fn remote_output(port: &str, id: usize, sched: Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)> {
+            match port {
+                $($(
+                    stringify!($output_name) => {
+                        let (r, s) = MsgReceiver::<$output_contract>::new(id, sched, false);
+                        let reader = (&&Remote::<$output_contract>::new()).reader(r)?;
+                        Ok((Box::new(s), reader))
+                    },
+                )*)*
+                    _ => { Err(result::Error::PortDontExist(port.into())) }
+            }
+        }
+
+        // A new channel of the edge of an input port, read by a remote edge, for an isolated node
+        $( #[$export] )*
+        pub extern fn remote_pipe(port: &str, id: usize, sched: Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)> {
+            match port {
+                $($(
+                    stringify!($input_name) => {
+                        let (r, s) = MsgReceiver::<$input_contract>::new(id, sched, false);
+                        let reader = (&&Remote::<$input_contract>::new()).reader(r)?;
+                        Ok((Box::new(s), reader))
+                    },
+                )*)*
+                    $(
+                        "option" => {
+                            let (r, s) = MsgReceiver::<$option>::new(id, sched, false);
+                            let reader = (&&Remote::<$option>::new()).reader(r)?;
+                            Ok((Box::new(s), reader))
+                        }
+                    )*
+                    $($(
+                        stringify!($options_name) => {
+                            let (r, s) = MsgReceiver::<$options_contract>::new(id, sched, false);
+                            let reader = (&&Remote::<$options_contract>::new()).reader(r)?;
+                            Ok((Box::new(s), reader))
+                        },
+                    )*)*
+                    _ => { Err(result::Error::PortDontExist(port.into())) }
+            }
+        }
+
+        $( #[$export] )*
+        pub extern fn remote_input(port: &str, sender: &Box<Any + Send>) -> Result<RemoteWriter> {
+            match port {
+                $($(
+                    stringify!($input_name) => {
+                        let s = rustfbp::agent::downcast_ref::<MsgSender<$input_contract>>(port, sender)?;
+                        (&&Remote::<$input_contract>::new()).writer(s.clone())
+                    },
+                )*)*
+                    $(
+                        "option" => {
+                            let s = rustfbp::agent::downcast_ref::<MsgSender<$option>>(port, sender)?;
+                            (&&Remote::<$option>::new()).writer(s.clone())
+                        }
+                    )*
+                    $($(
+                        stringify!($options_name) => {
+                            let s = rustfbp::agent::downcast_ref::<MsgSender<$options_contract>>(port, sender)?;
+                            (&&Remote::<$options_contract>::new()).writer(s.clone())
+                        },
+                    )*)*
+                    _ => { Err(result::Error::PortDontExist(port.into())) }
+            }
+        }
+
+        $( #[$export] )*
+        pub extern fn pool_input(port: &str, senders: Vec<Box<Any + Send>>, mode: rustfbp::scheduler::PoolInput, sched: Sender<CompMsg>) -> Result<Box<Any + Send>> {
+            match port {
+                $($(
+                    stringify!($input_name) => {
+                        let senders = senders.iter()
+                            .map(|s| rustfbp::agent::downcast_ref::<MsgSender<$input_contract>>(port, s).map(|s| s.clone()))
+                            .collect::<Result<_>>()?;
+                        let (r, s) = MsgReceiver::<$input_contract>::new(0, sched, false);
+                        rustfbp::scheduler::balance(r, senders, mode);
+                        Ok(Box::new(s) as Box<Any + Send>)
+                    },
+                )*)*
+                    $(
+                        "option" => {
+                            let senders = senders.iter()
+                                .map(|s| rustfbp::agent::downcast_ref::<MsgSender<$option>>(port, s).map(|s| s.clone()))
+                                .collect::<Result<_>>()?;
+                            let (r, s) = MsgReceiver::<$option>::new(0, sched, false);
+                            rustfbp::scheduler::broadcast(r, senders);
+                            Ok(Box::new(s) as Box<Any + Send>)
+                        }
+                    )*
+                    // Like `option`, all the instances get the named options
+                    $($(
+                        stringify!($options_name) => {
+                            let senders = senders.iter()
+                                .map(|s| rustfbp::agent::downcast_ref::<MsgSender<$options_contract>>(port, s).map(|s| s.clone()))
+                                .collect::<Result<_>>()?;
+                            let (r, s) = MsgReceiver::<$options_contract>::new(0, sched, false);
+                            rustfbp::scheduler::broadcast(r, senders);
+                            Ok(Box::new(s) as Box<Any + Send>)
+                        },
+                    )*)*
+                    _ => { Err(result::Error::PortDontExist(port.into())) }
+            }
+        }
+
+        $( #[$export] )*
+        pub extern fn pool_output(port: &str, target: &Box<Any + Send>, size: usize, sched: Sender<CompMsg>) -> Result<Vec<Box<Any + Send>>> {
+            match port {
+                $($(
+                    stringify!($output_name) => {
+                        let target = rustfbp::agent::downcast_ref::<MsgSender<$output_contract>>(port, target)?.clone();
+                        let mut recvs = vec![];
+                        let mut senders = vec![];
+                        for _ in 0..size {
+                            let (r, s) = MsgReceiver::<$output_contract>::new(0, sched.clone(), false);
+                            recvs.push(r);
+                            senders.push(Box::new(s) as Box<Any + Send>);
+                        }
+                        rustfbp::scheduler::merge_ordered(recvs, target);
+                        Ok(senders)
+                    },
+                )*)*
+                    _ => { Err(result::Error::PortDontExist(port.into())) }
+            }
+        }
+
+        $( #[$export] )*
         pub extern fn get_schema_input(port: &str) -> Result<String> {
             match port {
                 $($(
@@ -380,6 +1150,9 @@ macro_rules! agent {
                 $(
                     "option" => Ok(stringify!($option).into()),
                 )*
+                $($(
+                    stringify!($options_name) => Ok(stringify!($options_contract).into()),
+                )*)*
                 $(
                     "accumulator" => Ok(stringify!($accumulator).into()),
                 )*
@@ -387,7 +1160,7 @@ macro_rules! agent {
             }
         }
 
-        #[no_mangle]
+        $( #[$export] )*
         pub extern fn get_schema_input_array(port: &str) -> Result<String> {
             match port {
                 $($(
@@ -397,7 +1170,7 @@ macro_rules! agent {
             }
         }
 
-        #[no_mangle]
+        $( #[$export] )*
         pub extern fn get_schema_output(port: &str) -> Result<String> {
             match port {
                 $($(
@@ -407,7 +1180,7 @@ macro_rules! agent {
             }
         }
 
-        #[no_mangle]
+        $( #[$export] )*
         pub extern fn get_schema_output_array(port: &str) -> Result<String> {
             match port {
                 $($(
@@ -416,6 +1189,199 @@ macro_rules! agent {
                 _ => { Err(result::Error::PortDontExist(port.into())) }
             }
         }
+
+        /// The version of the crate of the agent, checked by the scheduler for the sorts of a `rustfbp::registry::Registry`
+        $( #[$export] )*
+        pub extern fn agent_version() -> &'static str {
+            env!("CARGO_PKG_VERSION")
+        }
+
+        $( #[$export] )*
+        pub extern fn get_ports() -> rustfbp::agent::Ports {
+            rustfbp::agent::Ports {
+                inputs: vec![$($( stringify!($input_name).to_string(), )*)*],
+                input_arrays: vec![$($( stringify!($input_a_name).to_string(), )*)*],
+                outputs: vec![$($( stringify!($output_name).to_string(), )*)*],
+                output_arrays: vec![$($( stringify!($output_a_name).to_string(), )*)*],
+                options: vec![
+                    $( { let _ = stringify!($option); "option".to_string() }, )*
+                    $($( stringify!($options_name).to_string(), )*)*
+                ],
+            }
+        }
+    };
+    (@exports[$( $export:meta ),*] $( $body:tt )*) => {
+        compile_error!("agent! : expected the clauses in the order input, inarr, output, outarr, state, state_serde, \
+                        option, options, accumulator, autostart, blocking, batch, then fn run(&mut self) -> Result<Signal>");
+    };
+    (
+        generic($param:ident: $( $edge:ident ),*),
+        $( $body:tt )*
+    )
+        =>
+    {
+        agent!(@edges);
+        agent!(@abi);
+
+        #[allow(non_snake_case)]
+        mod generic_agents {
+            agent!(@generic $param [$( $edge )*] { $( $body )* });
+        }
+
+        /// The functions of the agent for the edge `edge`, None if the agent is not built for it
+        #[no_mangle]
+        pub extern fn generic_agent(edge: &str) -> Option<rustfbp::agent::AgentExports> {
+            match edge {
+                $(
+                    stringify!($edge) => Some(generic_agents::$edge::exports()),
+                )*
+                _ => None,
+            }
+        }
+    };
+    (@generic $param:ident [$edge:ident $( $rest:ident )*] { $( $body:tt )* }) => {
+        // The agent for one edge, its functions are only reached with `generic_agent`
+        pub mod $edge {
+            #[allow(unused_imports)]
+            use super::super::*;
+
+            #[allow(dead_code)]
+            type $param = $edge;
+
+            agent!(@exports[] $( $body )*);
+            agent!(@exports_fn);
+        }
+
+        agent!(@generic $param [$( $rest )*] { $( $body )* });
+    };
+    (@generic $param:ident [] { $( $body:tt )* }) => {};
+    // The functions of an agent declared with `@exports[]`, in its module
+    (@exports_fn) => {
+        pub fn exports() -> rustfbp::agent::AgentExports {
+            rustfbp::agent::AgentExports {
+                create_agent: create_agent,
+                clone_input: clone_input,
+                clone_input_array: clone_input_array,
+                watch_input: watch_input,
+                watch_input_array: watch_input_array,
+                throttle_input: throttle_input,
+                close_input: close_input,
+                reopen_input: reopen_input,
+                create_input_array: create_input_array,
+                remote_output: remote_output,
+                remote_pipe: remote_pipe,
+                remote_input: remote_input,
+                pool_input: pool_input,
+                pool_output: pool_output,
+                get_schema_input: get_schema_input,
+                get_schema_input_array: get_schema_input_array,
+                get_schema_output: get_schema_output,
+                get_schema_output_array: get_schema_output_array,
+                agent_version: agent_version,
+                get_ports: get_ports,
+            }
+        }
+    };
+    // The ABI of the dylib, checked by the scheduler before it calls any other function
+    (@abi) => {
+        #[no_mangle]
+        pub extern "C" fn rustfbp_abi() -> rustfbp::agent::AgentAbi {
+            rustfbp::agent::AgentAbi::current()
+        }
+    };
+    // The edges of the crate, declared once so all its agents share their types with the agents of other crates
+    (@edges) => {
+        mod edge_capnp {
+                include!("edge_capnp.rs");
+        }
+        use edge_capnp::*;
+
+        mod edges {
+                include!("edges.rs");
+        }
+        use edges::*;
+    };
+    ( $( $body:tt )* ) => {
+        agent!(@abi);
+        agent!(@exports[no_mangle] @edges $( $body )*);
+    };
+}
+
+/// The async agent macro.
+///
+/// It takes the same ports as `agent`, but `run` is an async fn. The agent runs on the async runtime of the
+/// scheduler, and waits for its messages with `recv_async().await` instead of holding a thread. An agent which
+/// waits on the network (HTTP, WebSocket, database, ...) should be an async agent.
+///
+/// The crate of an async agent must be built with `edition = "2018";` in its `default.nix`.
+///
+/// Example :
+///
+/// ```rust,ignore
+/// async_agent! {
+///    input(input: WebRequest),
+///    output(output: WebResponse),
+///    async fn run(&mut self) -> Result<Signal> {
+///        let request = self.input.input.recv_async().await?;
+///        let body = fetch(&request.url).await?;
+///        self.output.output.send(WebResponse::new(request.id, 200, body))?;
+///        Ok(End)
+///    }
+/// }
+/// ```
+#[macro_export]
+macro_rules! async_agent {
+    (
+        $( input($( $input_name:ident: $input_contract:ty $( [$input_ack:ident] )* ),*), )*
+        $( inarr($( $input_a_name:ident: $input_a_contract:ty ),*), )*
+        $( output($( $output_name:ident: $output_contract:ty ),*), )*
+        $( outarr($( $output_a_name:ident: $output_a_contract:ty ),*), )*
+        $( state( $state_type:ty => $state_value:expr ), )*
+        $( state_serde( $serde_type:ty => $serde_value:expr ), )*
+        $( option($option:ty $( [$option_live:ident] )* ), )*
+        $( options($( $options_name:ident: $options_contract:ty $( [$options_live:ident] )* ),*), )*
+        $( accumulator($accumulator:ty $(, init = $acc_init:expr $(, flush = $acc_flush:expr)* )* ), )*
+        $( autostart($autostart:expr), )*
+        $( batch($batch_name:ident: $batch_size:expr $(, $batch_flush:expr)* ), )*
+        async fn run(&mut $arg:ident) -> Result<Signal> $fun:block
+    )
+        =>
+    {
+        agent! {
+            $( input($( $input_name: $input_contract $( [$input_ack] )* ),*), )*
+            $( inarr($( $input_a_name: $input_a_contract ),*), )*
+            $( output($( $output_name: $output_contract ),*), )*
+            $( outarr($( $output_a_name: $output_a_contract ),*), )*
+            $( state( $state_type => $state_value ), )*
+            $( state_serde( $serde_type => $serde_value ), )*
+            $( option($option $( [$option_live] )* ), )*
+            $( options($( $options_name: $options_contract $( [$options_live] )* ),*), )*
+            $( accumulator($accumulator $(, init = $acc_init $(, flush = $acc_flush)* )* ), )*
+            $( autostart($autostart), )*
+            $( batch($batch_name: $batch_size $(, $batch_flush)* ), )*
+            run_async(run_future),
+            fn run(&mut self) -> Result<Signal> {
+                Err(result::Error::Misc("an async agent runs on the async runtime of the scheduler".into()))
+            }
+        }
+
+        rustfbp::check_ports! {
+            input($($( $input_name )*)*)
+            inarr($($( $input_a_name )*)*)
+            output($($( $output_name )*)*)
+            outarr($($( $output_a_name )*)*)
+            options($($( $options_name )*)*)
+            option($( ($option) )*)
+            accumulator($( ($accumulator) )*)
+            batch()
+            run($arg) $fun
+        }
+
+        impl ThisAgent {
+            fn run_future<'a>(&'a mut $arg) -> AgentFuture<'a> {
+                Box::pin(async move $fun)
+            }
+        }
     }
 }
 
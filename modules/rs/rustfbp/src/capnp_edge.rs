@@ -0,0 +1,190 @@
+//! Typed Rust values for the Cap'n Proto edges
+//!
+//! For each flat Cap'n Proto schema (only primitive, `Text` and `Data` fields), the build generates a call to
+//! the macro `capnp_edge`, which declares a plain Rust structure and its conversions from and into the Cap'n Proto message
+//! and JSON (see `json`).
+//!
+//! These structures can be used directly as the edge of a port, the Cap'n Proto message is only built when the value
+//! must leave the process. The messages built by `into_capnp_msg` carry the name and the version of their schema, and
+//! `from_capnp_msg` refuses the messages of another schema or another version (see `schema`).
+//!
+//! Example :
+//!
+//! ```rust,ignore
+//! let msg = PrimText::new("hello".into()).into_msg();
+//! let text = PrimText::from_msg(&msg.into_reader())?;
+//! assert_eq!(text.text, "hello");
+//! ```
+
+extern crate capnp;
+
+use self::capnp::message;
+use self::capnp::serialize;
+
+use crate::ports::{CapnpMsg, MsgBuilder};
+use crate::result::Result;
+use crate::schema;
+use crate::schema::SchemaTag;
+
+/// A Rust value that can be converted from and into a Cap'n Proto message
+pub trait CapnpEdge: Sized {
+    /// Build the Cap'n Proto message of the value
+    fn into_msg(self) -> MsgBuilder;
+    /// Read the value from a Cap'n Proto message
+    fn from_msg<S: message::ReaderSegments>(msg: &message::Reader<S>) -> Result<Self>;
+
+    /// Serialize the value in the Cap'n Proto wire format
+    fn into_bytes(self) -> Result<Vec<u8>> {
+        let mut bytes = vec![];
+        serialize::write_message(&mut bytes, &self.into_msg())?;
+        Ok(bytes)
+    }
+
+    /// Deserialize the value from the Cap'n Proto wire format
+    fn from_bytes(mut bytes: &[u8]) -> Result<Self> {
+        let msg = serialize::read_message(&mut bytes, message::ReaderOptions::new())?;
+        Self::from_msg(&msg)
+    }
+
+    /// The name and the version of the schema, None if it's unknown
+    fn schema() -> Option<SchemaTag> {
+        None
+    }
+
+    /// Build the message of the value, with its schema
+    fn into_capnp_msg(self) -> CapnpMsg {
+        let msg = CapnpMsg::new(self.into_msg());
+        match Self::schema() {
+            Some(tag) => msg.with_schema(tag),
+            None => msg,
+        }
+    }
+
+    /// Read the value from a message, which must be of the same schema and the same version, if it has one
+    ///
+    /// The messages of an older version can be upgraded first, see `SchemaRegistry::read`.
+    fn from_capnp_msg(msg: &CapnpMsg) -> Result<Self> {
+        schema::check(Self::schema().as_ref(), msg.schema())?;
+        Self::from_bytes(&msg.to_bytes()?)
+    }
+}
+
+/// Declare the typed Rust structure of a Cap'n Proto schema.
+///
+/// The calls are generated by the build, one per schema, from the schema of the edge.
+///
+/// Example :
+///
+/// ```rust,ignore
+/// capnp_edge!(PrimText, prim_text {
+///     text: Text = (get_text, set_text)
+/// });
+/// ```
+#[macro_export]
+macro_rules! capnp_edge {
+    ($name:ident, $schema:ident { $( $field:ident : $kind:ident = ($get:ident, $set:ident) ),* }) => {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name {
+            $( pub $field: capnp_edge_type!($kind), )*
+        }
+
+        impl $name {
+            #[allow(dead_code)]
+            pub fn new($( $field: capnp_edge_type!($kind) ),*) -> Self {
+                $name {
+                    $( $field: $field, )*
+                }
+            }
+        }
+
+        impl $crate::capnp_edge::CapnpEdge for $name {
+            fn schema() -> Option<$crate::schema::SchemaTag> {
+                let fields = [$( $crate::schema::Field::new(stringify!($field), stringify!($kind)) ),*];
+                Some($crate::schema::SchemaTag::new(stringify!($name), $crate::schema::fingerprint(&fields)))
+            }
+
+            fn into_msg(self) -> $crate::ports::MsgBuilder {
+                let mut msg = $crate::ports::CapnpMsg::builder();
+                {
+                    #[allow(unused_mut, unused_variables)]
+                    let mut builder = msg.init_root::<$schema::Builder>();
+                    $( capnp_edge_set!($kind, builder, $set, self.$field); )*
+                }
+                msg
+            }
+
+            fn from_msg<S: ::capnp::message::ReaderSegments>(msg: &::capnp::message::Reader<S>) -> $crate::result::Result<Self> {
+                #[allow(unused_variables)]
+                let reader: $schema::Reader = msg.get_root()?;
+                Ok($name {
+                    $( $field: capnp_edge_get!($kind, reader, $get), )*
+                })
+            }
+        }
+
+        impl $crate::json::JsonEdge for $name {
+            fn to_json(&self) -> $crate::json::Value {
+                #[allow(unused_mut)]
+                let mut object = $crate::json::Map::new();
+                $( object.insert(stringify!($field).to_string(), $crate::json::JsonEdge::to_json(&self.$field)); )*
+                $crate::json::Value::Object(object)
+            }
+
+            fn from_json(value: &$crate::json::Value) -> $crate::result::Result<Self> {
+                #[allow(unused_variables)]
+                let object = value.as_object()
+                    .ok_or_else(|| $crate::result::Error::Misc(format!("json : expected an object for {}, got {}", stringify!($name), value)))?;
+                Ok($name {
+                    $( $field: match object.get(stringify!($field)) {
+                        Some(member) => $crate::json::JsonEdge::from_json(member)?,
+                        None => Default::default(),
+                    }, )*
+                })
+            }
+        }
+
+        impl $crate::remote::RemoteEdge for $name {
+            fn to_bytes(self) -> $crate::result::Result<Vec<u8>> {
+                $crate::capnp_edge::CapnpEdge::into_capnp_msg(self).to_envelope()
+            }
+
+            fn from_bytes(bytes: &[u8]) -> $crate::result::Result<Self> {
+                <$name as $crate::capnp_edge::CapnpEdge>::from_capnp_msg(&$crate::ports::CapnpMsg::from_envelope(bytes)?)
+            }
+        }
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! capnp_edge_type {
+    (Text) => { String };
+    (Data) => { Vec<u8> };
+    (Bool) => { bool };
+    (Int8) => { i8 };
+    (Int16) => { i16 };
+    (Int32) => { i32 };
+    (Int64) => { i64 };
+    (UInt8) => { u8 };
+    (UInt16) => { u16 };
+    (UInt32) => { u32 };
+    (UInt64) => { u64 };
+    (Float32) => { f32 };
+    (Float64) => { f64 };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! capnp_edge_set {
+    (Text, $builder:ident, $set:ident, $value:expr) => { $builder.$set(&$value) };
+    (Data, $builder:ident, $set:ident, $value:expr) => { $builder.$set(&$value) };
+    ($kind:ident, $builder:ident, $set:ident, $value:expr) => { $builder.$set($value) };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! capnp_edge_get {
+    (Text, $reader:ident, $get:ident) => { $reader.$get()?.to_string() };
+    (Data, $reader:ident, $get:ident) => { $reader.$get()?.to_vec() };
+    ($kind:ident, $reader:ident, $get:ident) => { $reader.$get() };
+}
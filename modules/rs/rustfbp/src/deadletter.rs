@@ -0,0 +1,99 @@
+//! The messages that could not be delivered
+//!
+//! Instead of being dropped, a message sent on an output port that is not connected, sent to an agent that is gone,
+//! rejected by its agent on an ack port, or by the remote scheduler of a remote edge, is kept by the scheduler with where it comes from. They can be taken with
+//! `Scheduler::take_dead_letters`, and a description of each one can be sent to an agent with
+//! `Scheduler::connect_dead_letters`, for alerting.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::Sender;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The number of dead letters kept, the oldest are dropped first
+pub const MAX_DEAD_LETTERS: usize = 1000;
+
+/// Why a message was not delivered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// The output port is not connected
+    NotConnected,
+    /// The agent of the input port is gone
+    Disconnected,
+    /// The agent rejected the message of an ack port
+    Rejected,
+    /// The remote scheduler could not read the message, which is kept serialized in a `Vec<u8>`
+    RemoteRejected,
+}
+
+/// A message that could not be delivered
+pub struct DeadLetter {
+    /// The agent which sent the message, or which rejected it
+    pub agent: String,
+    /// Its port
+    pub port: String,
+    pub reason: Reason,
+    pub time: SystemTime,
+    /// The message itself, to downcast to the type of the port
+    pub msg: Box<Any + Send>,
+}
+
+impl fmt::Debug for DeadLetter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DeadLetter")
+            .field("agent", &self.agent)
+            .field("port", &self.port)
+            .field("reason", &self.reason)
+            .field("time", &self.time)
+            .finish()
+    }
+}
+
+impl fmt::Display for DeadLetter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let time = self.time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        let reason = match self.reason {
+            Reason::NotConnected => "output port not connected",
+            Reason::Disconnected => "input agent gone",
+            Reason::Rejected => "rejected",
+            Reason::RemoteRejected => "rejected by the remote scheduler",
+        };
+        write!(f, "{} {}() {} : {}", time, self.agent, self.port, reason)
+    }
+}
+
+/// The dead letters of a scheduler
+#[derive(Default)]
+pub struct DeadLetters {
+    letters: VecDeque<DeadLetter>,
+    /// Receives the description of each new dead letter
+    sink: Option<Sender<String>>,
+}
+
+impl DeadLetters {
+    /// Keep a dead letter, and describe it to the sink
+    pub fn push(&mut self, letter: DeadLetter) {
+        if let Some(ref sink) = self.sink {
+            if sink.send(letter.to_string()).is_err() {
+                self.sink = None;
+            }
+        }
+        if self.letters.len() >= MAX_DEAD_LETTERS {
+            self.letters.pop_front();
+        }
+        self.letters.push_back(letter);
+    }
+
+    /// Take all the dead letters, the oldest first
+    pub fn take(&mut self) -> Vec<DeadLetter> {
+        self.letters.drain(..).collect()
+    }
+
+    pub fn set_sink(&mut self, sink: Sender<String>) {
+        self.sink = Some(sink);
+    }
+}
+
+pub type SharedDeadLetters = Arc<Mutex<DeadLetters>>;
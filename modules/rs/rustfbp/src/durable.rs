@@ -0,0 +1,198 @@
+//! Edges that survive a restart of the process
+//!
+//! A durable edge is created by `Scheduler::connect_durable`. Each message of the output port is appended to a
+//! write-ahead log before it is delivered in the input port, and acknowledged once the consumer took it out of the
+//! port. When the edge is created again after a restart, the messages that were not acknowledged are sent first.
+//!
+//! The log of an edge lives in the storage directory of the scheduler, in two files named after the edge :
+//!
+//! * `<edge>.log` : the messages, each one written as its length as a big endian u32 followed by its bytes,
+//! * `<edge>.ack` : the number of messages of the log already acknowledged, as a decimal number.
+//!
+//! The log is emptied each time all its messages are acknowledged. A message cut by a crash in the middle of its
+//! write is dropped, it was never delivered.
+//!
+//! The consumed messages are counted on the input port, so a high priority message of another sender can
+//! acknowledge a message of the durable edge too early.
+
+extern crate crossbeam_channel;
+extern crate log;
+
+use self::crossbeam_channel::bounded;
+
+use crate::ports::PortWatch;
+use crate::remote::{RemoteReader, RemoteWriter};
+use crate::result::{IoContext, Result};
+
+use std::collections::VecDeque;
+use std::fs;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+/// The write-ahead log of a durable edge
+pub struct Log {
+    ack: PathBuf,
+    file: File,
+    /// The messages in the log
+    written: u64,
+    /// The messages in the log already acknowledged
+    acked: u64,
+}
+
+impl Log {
+    /// Open the log of the edge `name` in `dir`, and return it with its messages not yet acknowledged
+    pub fn open(dir: &Path, name: &str) -> Result<(Log, Vec<Vec<u8>>)> {
+        fs::create_dir_all(dir).path(dir)?;
+        let log = dir.join(format!("{}.log", name));
+        let ack = dir.join(format!("{}.ack", name));
+
+        let mut msgs = vec![];
+        let mut pos = 0;
+        if let Ok(mut file) = File::open(&log) {
+            let mut bytes = vec![];
+            file.read_to_end(&mut bytes).path(&log)?;
+            while pos + 4 <= bytes.len() {
+                let len = ((bytes[pos] as usize) << 24) | ((bytes[pos + 1] as usize) << 16)
+                    | ((bytes[pos + 2] as usize) << 8) | bytes[pos + 3] as usize;
+                if pos + 4 + len > bytes.len() {
+                    break;
+                }
+                msgs.push(bytes[pos + 4..pos + 4 + len].to_vec());
+                pos += 4 + len;
+            }
+        }
+        let acked = match fs::read_to_string(&ack) {
+            Ok(acked) => acked.trim().parse::<u64>().unwrap_or(0),
+            Err(_) => 0,
+        };
+        // The acknowledged messages stay in the log until it is emptied
+        let written = msgs.len() as u64;
+        let acked = acked.min(written);
+        let pending: Vec<Vec<u8>> = msgs.into_iter().skip(acked as usize).collect();
+
+        let file = OpenOptions::new().create(true).append(true).open(&log).path(&log)?;
+        file.set_len(pos as u64).path(&log)?;
+        let log = Log {
+            file: file,
+            ack: ack,
+            written: written,
+            acked: acked,
+        };
+        Ok((log, pending))
+    }
+
+    /// Append a message, on disk when the call returns
+    pub fn append(&mut self, msg: &[u8]) -> Result<()> {
+        let len = msg.len() as u32;
+        self.file.write_all(&[(len >> 24) as u8, (len >> 16) as u8, (len >> 8) as u8, len as u8])?;
+        self.file.write_all(msg)?;
+        self.file.sync_data()?;
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Acknowledge the `n` oldest messages not yet acknowledged
+    pub fn ack(&mut self, n: u64) -> Result<()> {
+        self.acked += n;
+        if self.acked == self.written {
+            // Emptied before the ack is reset, a crash in between only skips messages that are not there
+            self.file.set_len(0)?;
+            self.written = 0;
+            self.acked = 0;
+        }
+        self.write_ack()
+    }
+
+    fn write_ack(&self) -> Result<()> {
+        let tmp = self.ack.with_extension("ack.tmp");
+        {
+            let mut file = File::create(&tmp).path(&tmp)?;
+            file.write_all(self.acked.to_string().as_bytes()).path(&tmp)?;
+            file.sync_data().path(&tmp)?;
+        }
+        fs::rename(&tmp, &self.ack).path(&self.ack)?;
+        Ok(())
+    }
+}
+
+/// Deliver the messages of `reader` with `writer`, through the log `name` in `dir`
+///
+/// `watch` is the state of the input port behind `writer`, where the consumed messages are counted.
+pub fn connect(dir: &Path, name: String, mut reader: RemoteReader, writer: RemoteWriter, watch: Arc<PortWatch>) -> Result<()> {
+    let (log, pending) = Log::open(dir, &name)?;
+    let log = Arc::new(Mutex::new(log));
+    // The position in the input port of each delivered message, not yet acknowledged
+    let delivered: Arc<Mutex<VecDeque<u64>>> = Arc::new(Mutex::new(VecDeque::new()));
+    // Set when the output port is disconnected
+    let done = Arc::new(AtomicBool::new(false));
+    // Wakes the acks when a message is taken out of the input port, delivered, or when the edge is done
+    let (wake_s, wake_r) = bounded(1);
+    watch.on_received(wake_s.clone());
+
+    let deliver = {
+        let delivered = delivered.clone();
+        let watch = watch.clone();
+        let wake = wake_s.clone();
+        move |bytes: &[u8]| -> Result<()> {
+            writer(bytes)?;
+            // At least the position of the message, later if other senders were faster
+            let position = watch.sent.load(Ordering::SeqCst);
+            delivered.lock().expect("durable edge : poisoned delivered").push_back(position);
+            // Taken out of the port before its position was known
+            let _ = wake.try_send(());
+            Ok(())
+        }
+    };
+
+    {
+        let log = log.clone();
+        let name = name.clone();
+        let done = done.clone();
+        thread::spawn(move || {
+            let res = (|| -> Result<()> {
+                for msg in pending {
+                    deliver(&msg)?;
+                }
+                loop {
+                    let bytes = reader()?;
+                    log.lock().expect("durable edge : poisoned log").append(&bytes)?;
+                    deliver(&bytes)?;
+                }
+            })();
+            if let Err(e) = res {
+                log::warn!("durable edge {} : {}", name, e);
+            }
+            done.store(true, Ordering::SeqCst);
+            let _ = wake_s.try_send(());
+        });
+    }
+
+    thread::spawn(move || {
+        while wake_r.recv().is_ok() {
+            let finished = done.load(Ordering::SeqCst);
+            let received = watch.received.load(Ordering::SeqCst);
+            let mut n = 0;
+            {
+                let mut delivered = delivered.lock().expect("durable edge : poisoned delivered");
+                while delivered.front().map(|p| *p <= received).unwrap_or(false) {
+                    delivered.pop_front();
+                    n += 1;
+                }
+            }
+            if n > 0 {
+                if let Err(e) = log.lock().expect("durable edge : poisoned log").ack(n) {
+                    log::error!("durable edge {} : {}", name, e);
+                    return;
+                }
+            }
+            if finished && delivered.lock().expect("durable edge : poisoned delivered").is_empty() {
+                return;
+            }
+        }
+    });
+    Ok(())
+}
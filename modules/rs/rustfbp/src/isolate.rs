@@ -0,0 +1,182 @@
+//! Nodes running in their own process, so a crash of a native agent cannot kill the scheduler
+//!
+//! `Scheduler::add_isolated_node` starts the current executable again, with the environment variable
+//! `RUSTFBP_ISOLATED` set to `name\tsort\taddr`. The executable must call `child` first thing in its `main` : the
+//! child process then runs the agent in its own scheduler, and receives its messages on `addr` (see `remote`).
+//!
+//! The parent scheduler sends the commands of the node on the standard input of the child, one by line :
+//! `connect\tport\taddr\tnode\tport` to connect an output port to a remote input port, `disconnect\tport`, `start`
+//! and `start_agent`. The child exits when its standard input is closed, so it never outlives its parent. A child
+//! which exits on its own is started again, and all the commands are sent to it again.
+
+use crate::result;
+use crate::result::Result;
+use crate::scheduler::Scheduler;
+
+use std::env;
+use std::io;
+use std::io::{BufRead, Write};
+use std::net::TcpListener;
+use std::process;
+use std::process::{ChildStdin, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+extern crate log;
+
+/// The environment variable which makes a process the child of an isolated node
+pub const ISOLATED: &str = "RUSTFBP_ISOLATED";
+
+/// Run the isolated node if this process is the child of one, and exit. Return at once otherwise
+///
+/// # Example
+/// ```rust,ignore
+/// fn main() {
+///     rustfbp::isolate::child();
+///     ...
+/// }
+/// ```
+pub fn child() {
+    let spec = match env::var(ISOLATED) {
+        Ok(spec) => spec,
+        Err(_) => { return; },
+    };
+    let code = match serve(&spec) {
+        Ok(()) => 0,
+        Err(e) => {
+            log::error!("isolated node {} : {}", spec, e);
+            1
+        }
+    };
+    process::exit(code);
+}
+
+// Run the agent, and apply the commands of the parent until it is gone
+fn serve(spec: &str) -> Result<()> {
+    let spec: Vec<&str> = spec.split('\t').collect();
+    if spec.len() != 3 {
+        return Err(result::Error::Misc(format!("bad {}, expected name, sort and address", ISOLATED)));
+    }
+    let (name, sort, addr) = (spec[0], spec[1], spec[2]);
+    let mut sched = Scheduler::new();
+    sched.add_node(name, sort)?;
+    sched.listen_remote(addr)?;
+    let stdin = io::stdin();
+    for line in stdin.lock().lines() {
+        let line = line?;
+        let cmd: Vec<&str> = line.split('\t').collect();
+        match cmd.as_slice() {
+            ["connect", port, addr, node, in_port] => sched.connect_remote(name, *port, *addr, *node, *in_port)?,
+            ["disconnect", port] => sched.disconnect(name, *port)?,
+            ["start"] => sched.start_if_needed(name)?,
+            ["start_agent"] => sched.start_agent(name)?,
+            _ => { return Err(result::Error::Misc(format!("unknown command {}", line))); },
+        }
+    }
+    Ok(())
+}
+
+/// A free address on the loopback, for the messages of a process
+pub fn free_addr() -> Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    Ok(listener.local_addr()?.to_string())
+}
+
+/// The child process of an isolated node, started again each time it exits
+///
+/// Dropping it stops the child.
+pub struct Isolated {
+    /// Where the child receives the messages of the node
+    pub addr: String,
+    /// Set if the agent must be started, it has no input port or it is an autostart agent
+    pub start: bool,
+    // The commands sent to the child since it was added, sent again when it is restarted
+    commands: Arc<Mutex<Vec<String>>>,
+    stdin: Arc<Mutex<Option<ChildStdin>>>,
+    stopped: Arc<AtomicBool>,
+    restarts: Arc<AtomicUsize>,
+}
+
+impl Isolated {
+    /// Start the child of the node `name`, and supervise it
+    pub fn spawn(name: String, sort: String, start: bool) -> Result<Self> {
+        let isolated = Isolated {
+            addr: free_addr()?,
+            start: start,
+            commands: Arc::new(Mutex::new(vec![])),
+            stdin: Arc::new(Mutex::new(None)),
+            stopped: Arc::new(AtomicBool::new(false)),
+            restarts: Arc::new(AtomicUsize::new(0)),
+        };
+        let spec = format!("{}\t{}\t{}", name, sort, isolated.addr);
+        let exe = env::current_exe()?;
+        let (commands, stdin) = (isolated.commands.clone(), isolated.stdin.clone());
+        let (stopped, restarts) = (isolated.stopped.clone(), isolated.restarts.clone());
+        thread::spawn(move || {
+            let mut backoff = 100;
+            while !stopped.load(Ordering::SeqCst) {
+                let started = Instant::now();
+                let child = Command::new(&exe).env(ISOLATED, &spec).stdin(Stdio::piped()).spawn();
+                match child {
+                    Ok(mut child) => {
+                        {
+                            let commands = commands.lock().expect("Isolated spawn : poisoned commands");
+                            let mut child_stdin = child.stdin.take();
+                            if let Some(ref mut child_stdin) = child_stdin {
+                                for cmd in commands.iter() {
+                                    let _ = writeln!(child_stdin, "{}", cmd);
+                                }
+                            }
+                            *stdin.lock().expect("Isolated spawn : poisoned stdin") = child_stdin;
+                        }
+                        // Stopped while it was starting
+                        if stopped.load(Ordering::SeqCst) {
+                            stdin.lock().expect("Isolated spawn : poisoned stdin").take();
+                        }
+                        let status = child.wait();
+                        if stopped.load(Ordering::SeqCst) { break; }
+                        restarts.fetch_add(1, Ordering::SeqCst);
+                        match status {
+                            Ok(status) => log::error!("the isolated node {} exited ({}), it is restarted", name, status),
+                            Err(e) => log::error!("the isolated node {} is lost ({}), it is restarted", name, e),
+                        }
+                    },
+                    Err(e) => { log::error!("cannot start the isolated node {} : {}", name, e); },
+                }
+                // A child which keeps crashing is restarted less and less often
+                if started.elapsed() > Duration::from_secs(10) {
+                    backoff = 100;
+                }
+                thread::sleep(Duration::from_millis(backoff));
+                backoff = (backoff * 2).min(5000);
+            }
+        });
+        Ok(isolated)
+    }
+
+    /// Send a command to the child, and keep it for its next restarts
+    pub fn command(&self, cmd: &[&str]) {
+        let cmd = cmd.join("\t");
+        let mut commands = self.commands.lock().expect("Isolated command : poisoned commands");
+        // A child being restarted gets it with the others
+        if let Some(ref mut stdin) = *self.stdin.lock().expect("Isolated command : poisoned stdin") {
+            let _ = writeln!(stdin, "{}", cmd);
+        }
+        commands.push(cmd);
+    }
+
+    /// The number of times the child was restarted
+    pub fn restarts(&self) -> usize {
+        self.restarts.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for Isolated {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // The child exits at the end of its standard input
+        self.stdin.lock().expect("Isolated drop : poisoned stdin").take();
+    }
+}
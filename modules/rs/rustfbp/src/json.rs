@@ -0,0 +1,206 @@
+//! JSON conversions of the Cap'n Proto edges
+//!
+//! Each structure declared by `capnp_edge` can be converted from and into a JSON object, with one member per field.
+//! `Data` fields are arrays of bytes, and a missing member takes the default value of its field, like in Cap'n Proto.
+//!
+//! The build generates, for each agent with Cap'n Proto edges, a function `json_registry` which returns the
+//! `JsonRegistry` of all its flat schemas. The registry converts a `CapnpMsg` from and into JSON by the name of its
+//! schema, it's what the agents `encode_json` and `decode_json` use.
+//!
+//! Example :
+//!
+//! ```rust,ignore
+//! let registry = json_registry();
+//! let json = registry.encode("PrimText", &msg)?;
+//! assert_eq!(json.to_string(), r#"{"text":"hello"}"#);
+//! let msg = registry.decode("PrimText", &json)?;
+//! ```
+
+extern crate serde_json;
+
+use std::collections::HashMap;
+
+pub use self::serde_json::{Map, Value};
+
+use crate::capnp_edge::CapnpEdge;
+use crate::ports::CapnpMsg;
+use crate::result;
+use crate::result::Result;
+
+/// A Rust value that can be converted from and into JSON
+pub trait JsonEdge: Sized {
+    fn to_json(&self) -> Value;
+    fn from_json(value: &Value) -> Result<Self>;
+}
+
+fn mismatch(expected: &str, value: &Value) -> result::Error {
+    result::Error::Misc(format!("json : expected {}, got {}", expected, value))
+}
+
+impl JsonEdge for String {
+    fn to_json(&self) -> Value {
+        Value::String(self.clone())
+    }
+
+    fn from_json(value: &Value) -> Result<Self> {
+        value.as_str().map(|s| s.to_string()).ok_or_else(|| mismatch("a string", value))
+    }
+}
+
+impl JsonEdge for Vec<u8> {
+    fn to_json(&self) -> Value {
+        Value::Array(self.iter().map(|b| Value::from(*b)).collect())
+    }
+
+    fn from_json(value: &Value) -> Result<Self> {
+        let array = value.as_array().ok_or_else(|| mismatch("an array of bytes", value))?;
+        array.iter().map(|b| u8::from_json(b)).collect()
+    }
+}
+
+impl JsonEdge for bool {
+    fn to_json(&self) -> Value {
+        Value::Bool(*self)
+    }
+
+    fn from_json(value: &Value) -> Result<Self> {
+        value.as_bool().ok_or_else(|| mismatch("a boolean", value))
+    }
+}
+
+macro_rules! json_int {
+    ($t:ty, $as:ident, $wide:ty) => {
+        impl JsonEdge for $t {
+            fn to_json(&self) -> Value {
+                Value::from(*self)
+            }
+
+            fn from_json(value: &Value) -> Result<Self> {
+                let wide: $wide = value.$as().ok_or_else(|| mismatch(stringify!($t), value))?;
+                if wide < <$t>::min_value() as $wide || wide > <$t>::max_value() as $wide {
+                    return Err(mismatch(stringify!($t), value));
+                }
+                Ok(wide as $t)
+            }
+        }
+    }
+}
+
+json_int!(i8, as_i64, i64);
+json_int!(i16, as_i64, i64);
+json_int!(i32, as_i64, i64);
+json_int!(i64, as_i64, i64);
+json_int!(u8, as_u64, u64);
+json_int!(u16, as_u64, u64);
+json_int!(u32, as_u64, u64);
+json_int!(u64, as_u64, u64);
+
+// JSON has no NaN nor infinity, they are null
+impl JsonEdge for f32 {
+    fn to_json(&self) -> Value {
+        Value::from(*self as f64)
+    }
+
+    fn from_json(value: &Value) -> Result<Self> {
+        f64::from_json(value).map(|f| f as f32)
+    }
+}
+
+impl JsonEdge for f64 {
+    fn to_json(&self) -> Value {
+        Value::from(*self)
+    }
+
+    fn from_json(value: &Value) -> Result<Self> {
+        match *value {
+            Value::Null => Ok(::std::f64::NAN),
+            _ => value.as_f64().ok_or_else(|| mismatch("a number", value)),
+        }
+    }
+}
+
+/// The conversions of one schema
+struct JsonSchema {
+    encode: fn(&CapnpMsg) -> Result<Value>,
+    decode: fn(&Value) -> Result<CapnpMsg>,
+}
+
+fn encode<T: CapnpEdge + JsonEdge>(msg: &CapnpMsg) -> Result<Value> {
+    Ok(T::from_capnp_msg(msg)?.to_json())
+}
+
+fn decode<T: CapnpEdge + JsonEdge>(value: &Value) -> Result<CapnpMsg> {
+    Ok(T::from_json(value)?.into_capnp_msg())
+}
+
+/// The JSON conversions of Cap'n Proto messages, by the name of their schema
+#[derive(Default)]
+pub struct JsonRegistry {
+    schemas: HashMap<String, JsonSchema>,
+}
+
+impl JsonRegistry {
+    pub fn new() -> Self {
+        JsonRegistry::default()
+    }
+
+    /// Add the schema `name`, read and built with `T`
+    pub fn register<T: CapnpEdge + JsonEdge>(&mut self, name: &str) {
+        self.schemas.insert(name.to_string(), JsonSchema {
+            encode: encode::<T>,
+            decode: decode::<T>,
+        });
+    }
+
+    /// The names of the registered schemas, sorted
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.schemas.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.schemas.contains_key(name)
+    }
+
+    /// Convert the message `msg` of the schema `name` into JSON
+    pub fn encode(&self, name: &str, msg: &CapnpMsg) -> Result<Value> {
+        (self.schema(name)?.encode)(msg)
+    }
+
+    /// Convert `msg` into JSON, with the schema `name`, or with the schema of its tag if `name` is empty
+    ///
+    /// It's the `schema` of the option of the agents which read the fields of any message.
+    pub fn encode_msg(&self, name: &str, msg: &CapnpMsg) -> Result<Value> {
+        match msg.schema() {
+            Some(tag) if name.is_empty() => self.encode(&tag.name, msg),
+            _ if name.is_empty() => Err(result::Error::Misc("json : the message has no schema, and none is given".into())),
+            _ => self.encode(name, msg),
+        }
+    }
+
+    /// Build a message of the schema `name` from JSON
+    pub fn decode(&self, name: &str, value: &Value) -> Result<CapnpMsg> {
+        (self.schema(name)?.decode)(value)
+    }
+
+    fn schema(&self, name: &str) -> Result<&JsonSchema> {
+        self.schemas.get(name).ok_or_else(|| result::Error::Misc(format!("json : unknown schema {}", name)))
+    }
+}
+
+/// Declare the function `json_registry`, with the JSON conversions of the structures declared by `capnp_edge`.
+///
+/// The call is generated by the build, after the calls of `capnp_edge`.
+#[macro_export]
+macro_rules! capnp_edge_json {
+    ($( $name:ident ),*) => {
+        #[allow(dead_code)]
+        pub fn json_registry() -> $crate::json::JsonRegistry {
+            #[allow(unused_mut)]
+            let mut registry = $crate::json::JsonRegistry::new();
+            $( registry.register::<$name>(stringify!($name)); )*
+            registry
+        }
+    }
+}
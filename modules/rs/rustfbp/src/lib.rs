@@ -1,13 +1,35 @@
-#![feature(alloc_system)]
-
-extern crate alloc_system;
-
 extern crate libloading;
 extern crate capnp;
+extern crate rustfbp_macros;
+
+#[doc(hidden)]
+pub use rustfbp_macros::check_ports;
+pub use rustfbp_macros::agent_struct;
 
+#[cfg(feature = "admin")]
+pub mod admin;
+pub mod affinity;
 pub mod agent;
 
 pub mod scheduler;
 
 pub mod ports;
+pub mod capnp_edge;
+pub mod deadletter;
+pub mod durable;
+pub mod isolate;
+pub mod json;
+pub mod limits;
+#[macro_use]
+pub mod log;
+pub mod manager;
+pub mod registry;
+pub mod remote;
 pub mod result;
+pub mod schema;
+pub mod secret;
+pub mod testing;
+pub mod trace;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod watchdog;
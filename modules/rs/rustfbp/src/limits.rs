@@ -0,0 +1,240 @@
+//! Resource limits of an agent
+//!
+//! An agent added with `Scheduler::add_node_with_limits` can be limited in the size of the messages waiting in its
+//! input ports, and in the duration of each of its executions. The size of a message is given by its edge, with what it
+//! owns on the heap (see `ports::MsgSize`, implemented by `CapnpMsg` and `SerdeEdge`), else it is the size of its type.
+//!
+//! Over a limit, the `LimitAction` of the agent is applied, and a `Violation` is sent to the supervisor of the
+//! scheduler, if any (see `Scheduler::supervise`).
+//!
+//! An edge connected with `Scheduler::connect_throttled` is limited in the rate of its messages : the sender waits
+//! for a token of a `Throttle` before each of them. `send` sleeps the thread of the sending agent meanwhile, so the
+//! agent must be `blocking(true)`, else it holds one of the workers shared by the agents. `try_send` never waits,
+//! it fails with `Full` while there is no token : a non blocking agent keeps the message and returns `Signal::Yield`.
+//!
+//! An input port connected with `Scheduler::connect_with_overflow` holds a bounded number of messages : a new message
+//! sent in the full port drops the oldest one instead of waiting, see `Overflow`.
+
+extern crate crossbeam_channel;
+
+use self::crossbeam_channel::{bounded, Receiver, Sender};
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// What happens when an agent is over one of its limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitAction {
+    /// The senders wait until the agent is under its limits again
+    Backpressure,
+    /// The oldest waiting messages are dropped, to make room for the new ones
+    DropOldest,
+    /// At the end of its current execution, the agent is set back to its state when it was added (see `state_serde`),
+    /// and its waiting messages are dropped
+    Restart,
+    /// Over its run time, the current execution of the agent is cancelled : its next `recv` or `send` returns
+    /// `Error::Cancelled` (see `ports::Cancel`). Over its queue limit, the senders wait as with `Backpressure`
+    Cancel,
+}
+
+/// The limits of an agent
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// The maximum size of the messages waiting in all the input ports
+    pub max_queued_bytes: Option<usize>,
+    /// The maximum duration of one execution
+    pub max_run_time: Option<Duration>,
+    pub action: LimitAction,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_queued_bytes: None,
+            max_run_time: None,
+            action: LimitAction::Backpressure,
+        }
+    }
+}
+
+/// A limit exceeded by an agent
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    /// The messages waiting in the input ports of the agent take `usize` bytes
+    QueuedBytes(String, usize),
+    /// The current execution of the agent takes longer than its limit
+    RunTime(String, Duration),
+}
+
+/// The limits of an agent and its usage, shared by its input ports and the scheduler
+#[derive(Debug)]
+pub struct LimitState {
+    pub limits: Limits,
+    /// The size of the messages waiting in the input ports
+    pub queued_bytes: AtomicUsize,
+    /// The number of messages waiting in the input ports
+    pub queued_msgs: AtomicUsize,
+    /// Set while the current execution is over the time limit
+    pub over_time: AtomicBool,
+    /// The messages to drop in the input ports, with `DropOldest`
+    pub to_drop: AtomicUsize,
+    /// The messages dropped since the agent was added
+    pub dropped: AtomicUsize,
+    // Wakes a sender waiting for room, which passes it on when it stops waiting
+    room: Sender<()>,
+    room_recv: Receiver<()>,
+}
+
+impl LimitState {
+    pub fn new(limits: Limits) -> Self {
+        let (s, r) = bounded(1);
+        LimitState {
+            limits: limits,
+            queued_bytes: AtomicUsize::new(0),
+            queued_msgs: AtomicUsize::new(0),
+            over_time: AtomicBool::new(false),
+            to_drop: AtomicUsize::new(0),
+            dropped: AtomicUsize::new(0),
+            room: s,
+            room_recv: r,
+        }
+    }
+
+    /// Take `msgs` messages of `bytes` out of the queue
+    pub fn unqueue(&self, bytes: usize, msgs: usize) {
+        self.queued_bytes.fetch_sub(bytes, Ordering::SeqCst);
+        self.queued_msgs.fetch_sub(msgs, Ordering::SeqCst);
+        self.made_room();
+    }
+
+    /// Wake a sender waiting for room, if any
+    pub fn made_room(&self) {
+        let _ = self.room.try_send(());
+    }
+
+    /// Ready after `made_room`, for the senders waiting for room
+    pub fn room(&self) -> &Receiver<()> {
+        &self.room_recv
+    }
+
+    /// True if `bytes` more would be over the queue limit
+    pub fn over_bytes(&self, bytes: usize) -> bool {
+        match self.limits.max_queued_bytes {
+            Some(max) => self.queued_bytes.load(Ordering::SeqCst) + bytes > max,
+            None => false,
+        }
+    }
+
+    /// True if the agent is over one of its limits
+    pub fn over(&self) -> bool {
+        self.over_bytes(0) || self.over_time.load(Ordering::SeqCst)
+    }
+
+    /// Drop all the waiting messages
+    pub fn purge(&self) {
+        self.to_drop.store(self.queued_msgs.load(Ordering::SeqCst), Ordering::SeqCst);
+    }
+
+    /// Take one of the messages to drop, if any
+    pub fn take_drop(&self) -> bool {
+        let mut to_drop = self.to_drop.load(Ordering::SeqCst);
+        while to_drop > 0 {
+            match self.to_drop.compare_exchange(to_drop, to_drop - 1, Ordering::SeqCst, Ordering::SeqCst) {
+                Ok(_) => {
+                    self.dropped.fetch_add(1, Ordering::SeqCst);
+                    return true;
+                }
+                Err(current) => { to_drop = current; }
+            }
+        }
+        false
+    }
+}
+
+/// What a sender does when the input port is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// The sender waits for room, the default
+    Block,
+    /// At most `usize` messages wait in the port, a new one drops the oldest
+    DropOldest(usize),
+    /// Only the latest message waits in the port, like `DropOldest(1)`
+    LatestOnly,
+}
+
+impl Overflow {
+    /// The messages kept in the port, 0 if they are never dropped
+    pub fn keep(&self) -> usize {
+        match *self {
+            Overflow::Block => 0,
+            Overflow::DropOldest(n) => n,
+            Overflow::LatestOnly => 1,
+        }
+    }
+}
+
+impl Default for Overflow {
+    fn default() -> Self {
+        Overflow::Block
+    }
+}
+
+/// The rate limit of an edge
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rate {
+    /// The messages sent per second, on average
+    pub per_sec: f64,
+    /// The messages that can be sent at once, after a pause of the sender
+    pub burst: usize,
+}
+
+/// A token bucket, shared by the senders of a throttled edge
+#[derive(Debug)]
+pub struct Throttle {
+    pub rate: Rate,
+    // The tokens left, and when they were counted
+    bucket: Mutex<(f64, Instant)>,
+}
+
+impl Throttle {
+    /// A full bucket of `rate.burst` tokens
+    pub fn new(rate: Rate) -> Self {
+        Throttle {
+            rate: rate,
+            bucket: Mutex::new((rate.burst as f64, Instant::now())),
+        }
+    }
+
+    /// Take a token, waiting for the next one if the bucket is empty
+    pub fn take(&self) {
+        self.take_before(None);
+    }
+
+    /// Take a token if there is one before `deadline`, false otherwise
+    ///
+    /// It sleeps the thread until the next token, see the module documentation.
+    pub fn take_before(&self, deadline: Option<Instant>) -> bool {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().expect("Throttle take : poisoned bucket");
+                let now = Instant::now();
+                let elapsed = now.duration_since(bucket.1);
+                let tokens = (bucket.0 + elapsed.as_secs_f64() * self.rate.per_sec).min(self.rate.burst as f64);
+                if tokens >= 1.0 {
+                    *bucket = (tokens - 1.0, now);
+                    return true;
+                }
+                *bucket = (tokens, now);
+                Duration::from_secs_f64((1.0 - tokens) / self.rate.per_sec)
+            };
+            if let Some(deadline) = deadline {
+                if Instant::now() + wait > deadline {
+                    return false;
+                }
+            }
+            thread::sleep(wait);
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! Logging of the agents and of the scheduler, on top of the `log` crate
+//!
+//! The agents log with `log_info!`, `log_warn!` and `log_error!`, which tag each entry with the name of the agent,
+//! the current execution of the agent (the trace id) and optionally a port :
+//!
+//! ```rust,ignore
+//! log_info!(self, "connected to {}", address);
+//! log_warn!(self, port = "input", "bad message : {}", e);
+//! ```
+//!
+//! The entries go through the `log` crate, so any logger can receive them, with the name of the agent as target and
+//! the `port` and `trace` tags as key-values. The agents are loaded from their own libraries, with their own copy
+//! of the `log` crate, so the scheduler gives them its logger with their name (see `Agent::set_log`).
+//!
+//! The logger of rustfbp, installed by the scheduler unless another one is, writes the entries to its sinks :
+//! `Stderr` by default, or the ones given to `Scheduler::log_to`.
+
+extern crate log;
+extern crate serde_json;
+
+use crate::ports::MsgSender;
+use crate::result::{IoContext, Result};
+
+pub use self::log::{Level, LevelFilter};
+use self::log::{Log, Metadata, Record};
+use self::log::kv::Key;
+use self::serde_json::{Map, Value};
+
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::mpsc::{channel, Sender};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A log entry, with its tags
+pub struct Entry {
+    pub level: Level,
+    /// The agent, or the module for the entries which do not come from an agent
+    pub target: String,
+    pub port: Option<String>,
+    pub trace: Option<String>,
+    pub message: String,
+    pub time: SystemTime,
+}
+
+impl Entry {
+    /// The entry as a JSON object
+    pub fn to_json(&self) -> String {
+        let mut obj = Map::new();
+        let time = self.time.duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0);
+        obj.insert("time".into(), Value::from(time));
+        obj.insert("level".into(), Value::from(self.level.to_string()));
+        obj.insert("target".into(), Value::from(self.target.clone()));
+        if let Some(ref port) = self.port {
+            obj.insert("port".into(), Value::from(port.clone()));
+        }
+        if let Some(ref trace) = self.trace {
+            obj.insert("trace".into(), Value::from(trace.clone()));
+        }
+        obj.insert("message".into(), Value::from(self.message.clone()));
+        Value::Object(obj).to_string()
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:5} {}()", self.level, self.target)?;
+        if let Some(ref port) = self.port {
+            write!(f, " {}", port)?;
+        }
+        if let Some(ref trace) = self.trace {
+            write!(f, " [{}]", trace)?;
+        }
+        write!(f, " : {}", self.message)
+    }
+}
+
+/// Where the log entries are written
+pub trait Sink: Send {
+    fn write(&mut self, entry: &Entry) -> Result<()>;
+}
+
+/// Write the entries on stderr, one line each
+pub struct Stderr;
+
+impl Sink for Stderr {
+    fn write(&mut self, entry: &Entry) -> Result<()> {
+        writeln!(std::io::stderr(), "{}", entry)?;
+        Ok(())
+    }
+}
+
+/// Append the entries to a file, one JSON object by line
+pub struct JsonLines {
+    file: File,
+}
+
+impl JsonLines {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(JsonLines {
+            file: OpenOptions::new().create(true).append(true).open(&path).path(&path)?,
+        })
+    }
+}
+
+impl Sink for JsonLines {
+    fn write(&mut self, entry: &Entry) -> Result<()> {
+        writeln!(self.file, "{}", entry.to_json())?;
+        Ok(())
+    }
+}
+
+/// Send the entries as JSON objects to an input port, see `Scheduler::log_to_edge`
+pub struct Edge {
+    forward: Sender<String>,
+}
+
+impl Edge {
+    pub fn new(sender: MsgSender<String>) -> Self {
+        // Sent from another thread, so an agent logging while the port is full does not block
+        let (s, r) = channel::<String>();
+        thread::spawn(move || {
+            for entry in r {
+                if sender.send(entry).is_err() {
+                    return;
+                }
+            }
+        });
+        Edge { forward: s }
+    }
+}
+
+impl Sink for Edge {
+    fn write(&mut self, entry: &Entry) -> Result<()> {
+        self.forward.send(entry.to_json()).map_err(|_| crate::result::Error::MpscSend)
+    }
+}
+
+/// The logging state of an agent, the `log` field of the agents
+#[derive(Clone)]
+pub struct Context {
+    agent: String,
+    run: u64,
+    logger: &'static Log,
+}
+
+impl Context {
+    /// A context with the logger installed in this copy of the `log` crate
+    pub fn new(agent: String) -> Self {
+        Context {
+            agent: agent,
+            run: 0,
+            logger: self::log::logger(),
+        }
+    }
+
+    /// Start a new execution, with a new trace id
+    pub fn next_run(&mut self) {
+        self.run += 1;
+    }
+
+    /// The id of the current execution of the agent
+    pub fn trace(&self) -> String {
+        format!("{}-{}", self.agent, self.run)
+    }
+
+    /// Log an entry, the agents use the `log_*!` macros instead
+    pub fn log(&self, level: Level, port: Option<&str>, args: fmt::Arguments) {
+        let trace = self.trace();
+        let with_port = [("trace", trace.as_str()), ("port", port.unwrap_or(""))];
+        let tags = if port.is_some() { &with_port[..] } else { &with_port[..1] };
+        self.logger.log(&Record::builder()
+            .level(level)
+            .target(&self.agent)
+            .args(args)
+            .key_values(&tags)
+            .build());
+    }
+
+    pub fn info(&self, args: fmt::Arguments) {
+        self.log(Level::Info, None, args);
+    }
+
+    pub fn warn(&self, args: fmt::Arguments) {
+        self.log(Level::Warn, None, args);
+    }
+
+    pub fn error(&self, args: fmt::Arguments) {
+        self.log(Level::Error, None, args);
+    }
+}
+
+struct Logger {
+    sinks: Mutex<Vec<Box<Sink>>>,
+}
+
+static LOGGER: Logger = Logger { sinks: Mutex::new(Vec::new()) };
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self::log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let kv = record.key_values();
+        let entry = Entry {
+            level: record.level(),
+            target: record.target().into(),
+            port: kv.get(Key::from("port")).map(|v| v.to_string()),
+            trace: kv.get(Key::from("trace")).map(|v| v.to_string()),
+            message: record.args().to_string(),
+            time: SystemTime::now(),
+        };
+        let mut sinks = match self.sinks.lock() {
+            Ok(sinks) => sinks,
+            Err(_) => { return; }
+        };
+        if sinks.is_empty() {
+            let _ = Stderr.write(&entry);
+        }
+        for sink in sinks.iter_mut() {
+            let _ = sink.write(&entry);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Install the logger of rustfbp, unless another logger is already installed
+pub fn init() {
+    if self::log::set_logger(&LOGGER).is_ok() {
+        self::log::set_max_level(LevelFilter::Info);
+    }
+}
+
+/// Add a sink to the logger of rustfbp. The first sink replaces `Stderr`.
+pub fn add_sink(sink: Box<Sink>) {
+    LOGGER.sinks.lock().expect("log add_sink : poisoned sinks").push(sink);
+}
+
+/// Only log the entries of `level` and above
+pub fn set_level(level: LevelFilter) {
+    self::log::set_max_level(level);
+}
+
+/// Log an info entry of the agent
+///
+/// # Example
+/// ```rust,ignore
+/// log_info!(self, "connected to {}", address);
+/// log_info!(self, port = "input", "{} messages", n);
+/// ```
+#[macro_export]
+macro_rules! log_info {
+    ($agent:expr, port = $port:expr, $($arg:tt)+) => {
+        $agent.log.log(rustfbp::log::Level::Info, Some($port), format_args!($($arg)+))
+    };
+    ($agent:expr, $($arg:tt)+) => {
+        $agent.log.log(rustfbp::log::Level::Info, None, format_args!($($arg)+))
+    };
+}
+
+/// Log a warning entry of the agent, see `log_info!`
+#[macro_export]
+macro_rules! log_warn {
+    ($agent:expr, port = $port:expr, $($arg:tt)+) => {
+        $agent.log.log(rustfbp::log::Level::Warn, Some($port), format_args!($($arg)+))
+    };
+    ($agent:expr, $($arg:tt)+) => {
+        $agent.log.log(rustfbp::log::Level::Warn, None, format_args!($($arg)+))
+    };
+}
+
+/// Log an error entry of the agent, see `log_info!`
+#[macro_export]
+macro_rules! log_error {
+    ($agent:expr, port = $port:expr, $($arg:tt)+) => {
+        $agent.log.log(rustfbp::log::Level::Error, Some($port), format_args!($($arg)+))
+    };
+    ($agent:expr, $($arg:tt)+) => {
+        $agent.log.log(rustfbp::log::Level::Error, None, format_args!($($arg)+))
+    };
+}
@@ -0,0 +1,89 @@
+//! Several independent networks in one process
+//!
+//! Each network has its own `Scheduler`, so its nodes have their own names and it starts and stops without the
+//! others. All the networks share one `AgentCache` : a dylib used by several networks is loaded once.
+//!
+//! Example :
+//!
+//! ```rust,ignore
+//! let mut manager = NetworkManager::new();
+//! {
+//!     let orders = manager.add("orders")?;
+//!     orders.add_node("parse", "/home/xxx/agents/parse.so")?;
+//! }
+//! manager.add("billing")?.add_node("parse", "/home/xxx/agents/parse.so")?;
+//! manager.stop("orders")?;
+//! manager.join_all();
+//! ```
+
+use crate::result;
+use crate::result::Result;
+use crate::scheduler::{AgentCache, Scheduler};
+
+use std::collections::HashMap;
+
+/// The networks of the process, by name
+pub struct NetworkManager {
+    cache: AgentCache,
+    networks: HashMap<String, Scheduler>,
+}
+
+impl NetworkManager {
+    pub fn new() -> Self {
+        NetworkManager {
+            cache: AgentCache::new(),
+            networks: HashMap::new(),
+        }
+    }
+
+    /// The agents loaded by all the networks
+    pub fn cache(&self) -> &AgentCache {
+        &self.cache
+    }
+
+    /// Create the network `name`, with a new scheduler which shares the loaded agents of the other networks
+    pub fn add(&mut self, name: &str) -> Result<&mut Scheduler> {
+        if self.networks.contains_key(name) {
+            return Err(result::Error::Misc(format!("the network {} already exists", name)));
+        }
+        let mut sched = Scheduler::new();
+        sched.cache = self.cache.clone();
+        Ok(self.networks.entry(name.to_string()).or_insert(sched))
+    }
+
+    pub fn get(&self, name: &str) -> Result<&Scheduler> {
+        self.networks.get(name).ok_or_else(|| result::Error::NetworkNotFound(name.into()))
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Result<&mut Scheduler> {
+        self.networks.get_mut(name).ok_or_else(|| result::Error::NetworkNotFound(name.into()))
+    }
+
+    /// The names of the networks, sorted
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.networks.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Stop the network without waiting for the end of its agents, see `Scheduler::stop`
+    pub fn stop(&mut self, name: &str) -> Result<()> {
+        let sched = self.networks.remove(name).ok_or_else(|| result::Error::NetworkNotFound(name.into()))?;
+        sched.stop();
+        Ok(())
+    }
+
+    /// Wait for the end of the network, see `Scheduler::join`
+    pub fn join(&mut self, name: &str) -> Result<()> {
+        let sched = self.networks.remove(name).ok_or_else(|| result::Error::NetworkNotFound(name.into()))?;
+        sched.join();
+        Ok(())
+    }
+
+    /// Wait for the end of all the networks
+    pub fn join_all(self) {
+        for (_, sched) in self.networks {
+            sched.join();
+        }
+    }
+}
@@ -1,48 +1,634 @@
-use std::sync::mpsc::{Sender, Receiver, SyncSender};
-use std::sync::mpsc::sync_channel;
-use result;
-use result::Result;
+extern crate capnp;
+extern crate crossbeam_channel;
+extern crate log;
+extern crate serde;
+extern crate serde_json;
 
-use scheduler::CompMsg;
+use self::capnp::{any_pointer, message, serialize, Word};
+use self::crossbeam_channel::{bounded, unbounded, Receiver, Select, SendError, Sender as ChannelSender, TryRecvError,
+                                TrySendError as ChannelTrySendError};
+use self::serde::Serialize;
+use self::serde::de::DeserializeOwned;
+
+use std::cmp;
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::Sender;
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use crate::result;
+use crate::result::Result;
+
+use crate::scheduler::CompMsg;
+use crate::deadletter::Reason;
+use crate::limits::{LimitAction, LimitState, Overflow, Throttle};
+use crate::schema::SchemaTag;
+use crate::trace::{SharedTrace, TraceEncoder};
+
+/// The priority of a message
+///
+/// A `High` message overtakes the `Normal` messages waiting in the port, and its agent overtakes the other ready
+/// agents. It is meant for the control messages (reconfigure, stop, ...), not for the bulk data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    Normal,
+    High,
+}
 
 pub struct MsgSender<T> {
-    pub sender: SyncSender<(Option<String>, T)>,
+    pub sender: ChannelSender<(Option<String>, T)>,
+    urgent: ChannelSender<(Option<String>, T)>,
     pub dest: usize,
     pub sched: Sender<CompMsg>,
     must_sched: bool,
+    batch: usize,
+    watch: Arc<PortWatch>,
+    waker: Arc<Mutex<Option<Waker>>>,
+    // Set on the live option port, see `live_option`
+    option_changed: bool,
+    // The rate limit of the edge, see `throttled`
+    throttle: Option<Arc<Throttle>>,
+    // The cancellation token of the sending agent, see `set_cancel`
+    cancel: Option<Cancel>,
+    // The messages of the port, to drop the oldest one when it overflows, until the receiver is dropped
+    evict: Arc<Mutex<Option<Receiver<(Option<String>, T)>>>>,
+    // The size of a message in the limits of the agent, see `MsgSize`
+    size: fn(&T) -> usize,
+}
+
+/// Why `try_send` did not send a message, which is given back
+#[derive(Debug, PartialEq)]
+pub enum TrySendError<T> {
+    /// The port is full, the receiving agent is over its limits, or the throttled edge has no token
+    Full(T),
+    /// The receiving agent is gone, or the output port is not connected
+    Disconnected(T),
+}
+
+/// Why `send_timeout` did not send a message, which is given back
+#[derive(Debug, PartialEq)]
+pub enum SendTimeoutError<T> {
+    /// The port was still full at the end of the timeout
+    Timeout(T),
+    /// The receiving agent is gone, or the output port is not connected
+    Disconnected(T),
+    /// The sending agent was cancelled while it was waiting, see `Cancel`
+    Cancelled(T),
+}
+
+impl<T> From<TrySendError<T>> for result::Error {
+    fn from(err: TrySendError<T>) -> result::Error {
+        match err {
+            TrySendError::Full(_) => result::Error::PortFull,
+            TrySendError::Disconnected(_) => result::Error::MpscSend,
+        }
+    }
+}
+
+impl<T> From<SendTimeoutError<T>> for result::Error {
+    fn from(err: SendTimeoutError<T>) -> result::Error {
+        match err {
+            SendTimeoutError::Timeout(_) => result::Error::SendTimeout,
+            SendTimeoutError::Disconnected(_) => result::Error::MpscSend,
+            SendTimeoutError::Cancelled(_) => result::Error::Cancelled,
+        }
+    }
+}
+
+// How long a send waits for room in a full port, or a receive for a message
+#[derive(Clone, Copy)]
+enum Wait {
+    Block,
+    Never,
+    Until(Instant),
+}
+
+impl Wait {
+    fn deadline(&self) -> Option<Instant> {
+        match *self {
+            Wait::Block => None,
+            Wait::Never => Some(Instant::now()),
+            Wait::Until(deadline) => Some(deadline),
+        }
+    }
+
+    fn expired(&self) -> bool {
+        self.deadline().map(|deadline| Instant::now() >= deadline).unwrap_or(false)
+    }
+}
+
+// A message which was not sent, given back
+enum Undelivered<T> {
+    Full(T),
+    Disconnected(T),
+    Cancelled(T),
 }
 
 impl<T> MsgSender<T> {
-    pub fn send(&self, mut msg: T) -> Result<()> {
-        self.sender.send((None, msg))?;
-        if self.must_sched {
-            self.sched.send(CompMsg::Inc(self.dest))?;
+    /// Send a message, waiting for room if the port is full
+    pub fn send(&self, msg: T) -> Result<()> {
+        self.push((None, msg), Priority::Normal)
+    }
+
+    pub fn send_with_action(&self, msg: T, action: String) -> Result<()> {
+        self.push((Some(action), msg), Priority::Normal)
+    }
+
+    pub fn send_with_priority(&self, msg: T, priority: Priority) -> Result<()> {
+        self.push((None, msg), priority)
+    }
+
+    /// Send a message only if the port has room for it now
+    pub fn try_send(&self, msg: T) -> std::result::Result<(), TrySendError<T>> {
+        match self.deliver((None, msg), Priority::Normal, Wait::Never) {
+            // The message is in the port, only the scheduler is gone
+            Ok(_) => Ok(()),
+            Err(Undelivered::Full(msg)) | Err(Undelivered::Cancelled(msg)) => Err(TrySendError::Full(msg)),
+            Err(Undelivered::Disconnected(msg)) => Err(TrySendError::Disconnected(msg)),
+        }
+    }
+
+    /// Send a message, waiting at most `timeout` for room if the port is full
+    pub fn send_timeout(&self, msg: T, timeout: Duration) -> std::result::Result<(), SendTimeoutError<T>> {
+        match self.deliver((None, msg), Priority::Normal, Wait::Until(Instant::now() + timeout)) {
+            Ok(_) => Ok(()),
+            Err(Undelivered::Full(msg)) => Err(SendTimeoutError::Timeout(msg)),
+            Err(Undelivered::Disconnected(msg)) => Err(SendTimeoutError::Disconnected(msg)),
+            Err(Undelivered::Cancelled(msg)) => Err(SendTimeoutError::Cancelled(msg)),
+        }
+    }
+
+    fn push(&self, msg: (Option<String>, T), priority: Priority) -> Result<()> {
+        match self.deliver(msg, priority, Wait::Block) {
+            Ok(res) => res,
+            Err(Undelivered::Cancelled(_)) => Err(result::Error::Cancelled),
+            Err(_) => Err(result::Error::MpscSend),
+        }
+    }
+
+    // Send the message, or give it back if it cannot be sent
+    fn deliver(&self, msg: (Option<String>, T), priority: Priority, wait: Wait) -> std::result::Result<Result<()>, Undelivered<T>> {
+        if self.cancelled() {
+            return Err(Undelivered::Cancelled(msg.1));
+        }
+        if self.watch.closed.load(Ordering::SeqCst) {
+            return Err(Undelivered::Disconnected(msg.1));
+        }
+        if let Some(ref throttle) = self.throttle {
+            if !throttle.take_before(wait.deadline()) {
+                return Err(Undelivered::Full(msg.1));
+            }
+        }
+        // The oldest messages make room first, so the new one is not held by the limits
+        let keep = self.watch.keep.load(Ordering::SeqCst);
+        if keep > 0 {
+            self.evict(keep);
+        }
+        let limit = self.watch.limit();
+        // Only measured for an agent with limits, a message may be costly to measure
+        let bytes = limit.as_ref().map(|_| (self.size)(&msg.1)).unwrap_or(0);
+        if let Some(ref limit) = limit {
+            match self.apply_limit(limit, bytes, wait) {
+                Ok(true) => {},
+                Ok(false) => { return Err(Undelivered::Full(msg.1)); },
+                Err(result::Error::Cancelled) => { return Err(Undelivered::Cancelled(msg.1)); },
+                Err(e) => { return Ok(Err(e)); },
+            }
+        }
+        let sender = match priority {
+            Priority::High => &self.urgent,
+            Priority::Normal => &self.sender,
+        };
+        // Counted before the send, so the receiver never takes it away first
+        let queued = self.watch.queued.fetch_add(1, Ordering::SeqCst) + 1;
+        self.watch.sent.fetch_add(1, Ordering::SeqCst);
+        let res = match sender.try_send(msg) {
+            Ok(()) => Ok(()),
+            Err(ChannelTrySendError::Full(msg)) => {
+                // The port is full, wait for room
+                self.watch.blocked_senders.fetch_add(1, Ordering::SeqCst);
+                let res = self.wait_room(sender, msg, wait);
+                self.watch.blocked_senders.fetch_sub(1, Ordering::SeqCst);
+                res
+            },
+            Err(ChannelTrySendError::Disconnected(msg)) => Err(Undelivered::Disconnected(msg)),
+        };
+        if let Err(undelivered) = res {
+            self.watch.queued.fetch_sub(1, Ordering::SeqCst);
+            self.watch.sent.fetch_sub(1, Ordering::SeqCst);
+            if let Some(ref limit) = limit {
+                limit.unqueue(bytes, 1);
+            }
+            return Err(match undelivered {
+                // A cancelled agent stops at its send
+                Undelivered::Full((_, msg)) if self.cancelled() => Undelivered::Cancelled(msg),
+                Undelivered::Full((_, msg)) => Undelivered::Full(msg),
+                Undelivered::Disconnected((_, msg)) => Undelivered::Disconnected(msg),
+                Undelivered::Cancelled((_, msg)) => Undelivered::Cancelled(msg),
+            });
+        }
+        if self.must_sched && priority == Priority::High {
+            // Before the Inc, so the run it triggers is already urgent
+            if let Err(e) = self.sched.send(CompMsg::Urgent(self.dest)) {
+                return Ok(Err(e.into()));
+            }
+        }
+        Ok(self.sent(queued))
+    }
+
+    // Wait for room in a full port, until the end of `wait` or until the sending agent is cancelled
+    fn wait_room(&self, sender: &ChannelSender<(Option<String>, T)>, mut msg: (Option<String>, T), wait: Wait)
+                 -> std::result::Result<(), Undelivered<(Option<String>, T)>> {
+        let deadline = match wait {
+            Wait::Never => { return Err(Undelivered::Full(msg)); },
+            Wait::Block => None,
+            Wait::Until(deadline) => Some(deadline),
+        };
+        if self.cancel.is_none() && deadline.is_none() {
+            return sender.send(msg).map_err(|SendError(msg)| Undelivered::Disconnected(msg));
+        }
+        loop {
+            if self.cancelled() {
+                return Err(Undelivered::Cancelled(msg));
+            }
+            let mut sel = Select::new();
+            sel.send(sender);
+            if let Some(ref cancel) = self.cancel {
+                sel.recv(&cancel.recv);
+            }
+            match deadline {
+                Some(deadline) => {
+                    if sel.ready_deadline(deadline).is_err() {
+                        return Err(Undelivered::Full(msg));
+                    }
+                },
+                None => { sel.ready(); },
+            }
+            msg = match sender.try_send(msg) {
+                Ok(()) => { return Ok(()); },
+                Err(ChannelTrySendError::Full(msg)) => msg,
+                Err(ChannelTrySendError::Disconnected(msg)) => { return Err(Undelivered::Disconnected(msg)); },
+            };
+        }
+    }
+
+    fn cancelled(&self) -> bool {
+        self.cancel.as_ref().map(|c| c.is_cancelled()).unwrap_or(false)
+    }
+
+    // Drop the oldest messages of the port until there is room for a new one under `keep`, see `Overflow`
+    fn evict(&self, keep: usize) {
+        let evict = self.evict.lock().expect("MsgSender evict : poisoned receiver");
+        let recv = match *evict {
+            Some(ref recv) => recv,
+            None => { return; },
+        };
+        while self.watch.queued.load(Ordering::SeqCst) >= keep {
+            // Taken by the receiver meanwhile, or only high priority messages are waiting
+            let msg = match recv.try_recv() {
+                Ok(msg) => msg,
+                Err(_) => { break; },
+            };
+            self.watch.queued.fetch_sub(1, Ordering::SeqCst);
+            self.watch.dropped.fetch_add(1, Ordering::SeqCst);
+            if let Some(limit) = self.watch.limit() {
+                limit.unqueue((self.size)(&msg.1), 1);
+            }
+            if self.must_sched && self.batch == 1 {
+                let _ = self.sched.send(CompMsg::Dec(self.dest));
+            }
+        }
+    }
+
+    // Apply the limits of the agent to a new message of `bytes`, false if there is no room for it before the end of `wait`
+    fn apply_limit(&self, limit: &LimitState, bytes: usize, wait: Wait) -> Result<bool> {
+        if !limit.over_bytes(0) && limit.over_bytes(bytes) {
+            self.sched.send(CompMsg::QueueLimit(self.dest, limit.queued_bytes.load(Ordering::SeqCst) + bytes))?;
         }
+        match limit.limits.action {
+            LimitAction::Backpressure | LimitAction::Cancel => {
+                // A message bigger than the limit still goes in an empty port
+                let full = || limit.over() && limit.queued_msgs.load(Ordering::SeqCst) > 0;
+                if full() {
+                    self.watch.blocked_senders.fetch_add(1, Ordering::SeqCst);
+                    let res = self.wait_limit(limit, full, wait);
+                    self.watch.blocked_senders.fetch_sub(1, Ordering::SeqCst);
+                    // The wake up may be for another sender
+                    limit.made_room();
+                    if let Some(res) = res {
+                        return res;
+                    }
+                }
+            },
+            LimitAction::DropOldest => {
+                if limit.over_bytes(bytes) || limit.over_time.load(Ordering::SeqCst) {
+                    limit.to_drop.fetch_add(1, Ordering::SeqCst);
+                }
+            },
+            LimitAction::Restart => {},
+        }
+        limit.queued_bytes.fetch_add(bytes, Ordering::SeqCst);
+        limit.queued_msgs.fetch_add(1, Ordering::SeqCst);
+        Ok(true)
+    }
+
+    // Wait until the limits of the agent leave room, None once there is room
+    fn wait_limit<F: Fn() -> bool>(&self, limit: &LimitState, full: F, wait: Wait) -> Option<Result<bool>> {
+        while full() {
+            if self.cancelled() {
+                return Some(Err(result::Error::Cancelled));
+            }
+            let mut sel = Select::new();
+            sel.recv(limit.room());
+            if let Some(ref cancel) = self.cancel {
+                sel.recv(&cancel.recv);
+            }
+            match wait.deadline() {
+                Some(deadline) => {
+                    if sel.ready_deadline(deadline).is_err() {
+                        return Some(Ok(false));
+                    }
+                },
+                None => { sel.ready(); },
+            }
+            let _ = limit.room().try_recv();
+        }
+        None
+    }
+
+    /// Close the port : its receiver gets `Err(Closed)` once it has taken the messages already sent, and the next
+    /// sends fail
+    ///
+    /// The port is closed for all its senders, until `reopen`.
+    pub fn close(&self) -> Result<()> {
+        if self.watch.closed.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+        // The end of stream is counted like a message, so the agent runs to see it
+        let queued = self.watch.queued.fetch_add(1, Ordering::SeqCst) + 1;
+        let _ = self.watch.close_send.try_send(());
+        self.sent(queued)
+    }
+
+    /// Open a closed port again, for a new connection : the next sends are delivered, and an end of stream the
+    /// receiver didn't take yet is dropped, so the receiver goes on with the messages of the new connection
+    pub fn reopen(&self) -> Result<()> {
+        if !self.watch.closed.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+        // Take the end of stream in place of the receiver, like `eof`
+        if !self.watch.eof_taken.swap(true, Ordering::SeqCst) {
+            self.watch.queued.fetch_sub(1, Ordering::SeqCst);
+            if self.must_sched && self.batch == 1 {
+                self.sched.send(CompMsg::Dec(self.dest))?;
+            }
+        }
+        let _ = self.watch.close_recv.try_recv();
+        self.watch.closed.store(false, Ordering::SeqCst);
+        self.watch.eof_taken.store(false, Ordering::SeqCst);
         Ok(())
     }
 
-    pub fn send_with_action(&self, mut msg: T, action: String) -> Result<()> {
-        self.sender.send((Some(action), msg))?;
+    /// Make a sender of the same port, which waits for a token of `throttle` before each message
+    ///
+    /// The clones of the sender share the throttle, see `Scheduler::connect_throttled`.
+    pub fn throttled(&self, throttle: Arc<Throttle>) -> Self {
+        let mut sender = self.clone();
+        sender.throttle = Some(throttle);
+        sender
+    }
+
+    /// Make the sender of a live option port : each new option tells the scheduler to interrupt the blocking
+    /// receives of the agent
+    pub fn live_option(mut self) -> Self {
+        self.option_changed = true;
+        self
+    }
+
+    /// Stop the sends of the agent which owns the sender when it is cancelled, see `Cancel`
+    pub fn set_cancel(&mut self, cancel: Cancel) {
+        self.cancel = Some(cancel);
+    }
+
+    // Tell the receiver about a new message, `queued` messages are now waiting
+    fn sent(&self, queued: usize) -> Result<()> {
+        self.wake();
+        if self.option_changed {
+            self.sched.send(CompMsg::OptionChanged(self.dest))?;
+        }
         if self.must_sched {
-            self.sched.send(CompMsg::Inc(self.dest))?;
+            // A batch port runs the agent for each full batch, or after the flush timeout. A held message runs it
+            // once released, see `PortWatch::hold`
+            if self.batch == 1 && self.watch.hold_one() {
+            } else if queued % self.batch == 0 {
+                self.sched.send(CompMsg::Inc(self.dest))?;
+            } else if queued == 1 {
+                self.sched.send(CompMsg::ArmFlush(self.dest))?;
+            }
         }
         Ok(())
     }
+
+    /// The number of messages waiting in the input port
+    pub fn queue_len(&self) -> usize {
+        self.watch.queued.load(Ordering::SeqCst)
+    }
+
+    /// The state of the input port, for the watchdog
+    pub fn watch(&self) -> Arc<PortWatch> {
+        self.watch.clone()
+    }
+
+    // Wake an async agent waiting on the receiver
+    fn wake(&self) {
+        let waker = self.waker.lock().expect("MsgSender wake : poisoned waker").take();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
 }
 
 impl<T> Clone for MsgSender<T> {
     fn clone(&self) -> Self {
         MsgSender::<T> {
             sender: self.sender.clone(),
+            urgent: self.urgent.clone(),
             dest: self.dest,
             sched: self.sched.clone(),
             must_sched: self.must_sched,
+            batch: self.batch,
+            watch: self.watch.clone(),
+            waker: self.waker.clone(),
+            option_changed: self.option_changed,
+            throttle: self.throttle.clone(),
+            cancel: self.cancel.clone(),
+            evict: self.evict.clone(),
+            size: self.size,
         }
     }
 }
 
+impl MsgSender<CapnpMsg> {
+    /// A builder for a message to send on the port, in the arena of the port if it has one
+    pub fn new_builder(&self) -> MsgBuilder {
+        message::Builder::new(MsgAllocator::new(self.watch.arena()))
+    }
+}
+
 pub trait OutputSend<T> {
     fn send(&self, msg:T) -> Result<()>;
+    fn send_with_priority(&self, msg: T, priority: Priority) -> Result<()>;
+    /// Send only if the port has room now, see `MsgSender::try_send`
+    fn try_send(&self, msg: T) -> std::result::Result<(), TrySendError<T>>;
+    /// Wait at most `timeout` for room, see `MsgSender::send_timeout`
+    fn send_timeout(&self, msg: T, timeout: Duration) -> std::result::Result<(), SendTimeoutError<T>>;
+    /// Tell the receiver that no more messages will come, see `MsgSender::close`
+    fn close(&self) -> Result<()>;
+}
+
+/// An output port of an agent
+///
+/// It is used as the `Option<MsgSender<T>>` of its connection. A message sent while the port is not connected, or
+/// while the agent at the other end is gone, goes to the dead letters of the scheduler.
+pub struct OutputPort<T> {
+    sender: Option<MsgSender<T>>,
+    agent: usize,
+    port: &'static str,
+    sched: Sender<CompMsg>,
+    tap: Option<Tap<T>>,
+    trace: Option<(SharedTrace, TraceEncoder<T>)>,
+}
+
+impl<T: Send + 'static> OutputPort<T> {
+    pub fn new(agent: usize, port: &'static str, sched: Sender<CompMsg>) -> Self {
+        OutputPort {
+            sender: None,
+            agent: agent,
+            port: port,
+            sched: sched,
+            tap: None,
+            trace: None,
+        }
+    }
+
+    /// Copy a sample of the messages of the port to the sink of `tap`, or stop with `None`
+    pub fn set_tap(&mut self, tap: Option<Tap<T>>) {
+        self.tap = tap;
+    }
+
+    /// Keep the last messages of the port in `trace`, serialized by `encode`, or stop with `None`
+    pub fn set_trace(&mut self, trace: Option<(SharedTrace, TraceEncoder<T>)>) {
+        self.trace = trace;
+    }
+
+    // Give a message to the tap and to the trace of the port
+    fn copy(&self, msg: &T) {
+        if let Some(ref tap) = self.tap {
+            tap.sample(msg);
+        }
+        if let Some((ref trace, encode)) = self.trace {
+            match encode(msg) {
+                Ok(bytes) => trace.push(bytes),
+                Err(e) => log::warn!(target: self.port, "cannot trace a message : {}", e),
+            }
+        }
+    }
+
+    fn dead_letter(&self, reason: Reason, msg: T) {
+        let _ = self.sched.send(CompMsg::DeadLetter(self.agent, self.port.into(), reason, Box::new(msg)));
+    }
+}
+
+impl OutputPort<CapnpMsg> {
+    /// A builder for a message to send on the port, see `MsgSender::new_builder`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut builder = self.output.output.new_builder();
+    /// builder.init_root::<prim_text::Builder>().set_text("hello");
+    /// self.output.output.send(CapnpMsg::new(builder))?;
+    /// ```
+    pub fn new_builder(&self) -> MsgBuilder {
+        match self.sender {
+            Some(ref sender) => sender.new_builder(),
+            None => CapnpMsg::builder(),
+        }
+    }
+}
+
+impl<T> Deref for OutputPort<T> {
+    type Target = Option<MsgSender<T>>;
+
+    fn deref(&self) -> &Option<MsgSender<T>> {
+        &self.sender
+    }
+}
+
+impl<T> DerefMut for OutputPort<T> {
+    fn deref_mut(&mut self) -> &mut Option<MsgSender<T>> {
+        &mut self.sender
+    }
+}
+
+impl<T: Send + 'static> OutputSend<T> for OutputPort<T> {
+    fn send(&self, msg: T) -> Result<()> {
+        self.send_with_priority(msg, Priority::Normal)
+    }
+
+    fn send_with_priority(&self, msg: T, priority: Priority) -> Result<()> {
+        self.copy(&msg);
+        match self.sender {
+            Some(ref sender) => match sender.deliver((None, msg), priority, Wait::Block) {
+                Ok(res) => res,
+                Err(Undelivered::Cancelled(_)) => Err(result::Error::Cancelled),
+                Err(Undelivered::Full(msg)) | Err(Undelivered::Disconnected(msg)) => {
+                    self.dead_letter(Reason::Disconnected, msg);
+                    Err(result::Error::Disconnected { port: self.port.into() })
+                }
+            },
+            None => {
+                self.dead_letter(Reason::NotConnected, msg);
+                Err(result::Error::OutputNotConnected)
+            }
+        }
+    }
+
+    // The messages given back are not dead letters, the agent still has them
+    fn try_send(&self, msg: T) -> std::result::Result<(), TrySendError<T>> {
+        match self.sender {
+            Some(ref sender) => {
+                self.copy(&msg);
+                sender.try_send(msg)
+            },
+            None => Err(TrySendError::Disconnected(msg)),
+        }
+    }
+
+    fn send_timeout(&self, msg: T, timeout: Duration) -> std::result::Result<(), SendTimeoutError<T>> {
+        match self.sender {
+            Some(ref sender) => {
+                self.copy(&msg);
+                sender.send_timeout(msg, timeout)
+            },
+            None => Err(SendTimeoutError::Disconnected(msg)),
+        }
+    }
+
+    fn close(&self) -> Result<()> {
+        match self.sender {
+            Some(ref sender) => sender.close(),
+            None => Err(result::Error::OutputNotConnected),
+        }
+    }
 }
 
 impl<T> OutputSend<T> for Option<MsgSender<T>> {
@@ -54,69 +640,1505 @@ impl<T> OutputSend<T> for Option<MsgSender<T>> {
             Err(result::Error::OutputNotConnected)
         }
     }
+
+    fn send_with_priority(&self, msg: T, priority: Priority) -> Result<()> {
+        if let &Some(ref sender) = self {
+            sender.send_with_priority(msg, priority)
+        } else {
+            Err(result::Error::OutputNotConnected)
+        }
+    }
+
+    fn try_send(&self, msg: T) -> std::result::Result<(), TrySendError<T>> {
+        match *self {
+            Some(ref sender) => sender.try_send(msg),
+            None => Err(TrySendError::Disconnected(msg)),
+        }
+    }
+
+    fn send_timeout(&self, msg: T, timeout: Duration) -> std::result::Result<(), SendTimeoutError<T>> {
+        match *self {
+            Some(ref sender) => sender.send_timeout(msg, timeout),
+            None => Err(SendTimeoutError::Disconnected(msg)),
+        }
+    }
+
+    fn close(&self) -> Result<()> {
+        match *self {
+            Some(ref sender) => sender.close(),
+            None => Err(result::Error::OutputNotConnected),
+        }
+    }
+}
+
+/// A copy of a sample of the messages of an output port, sent to a debug sink (see `Scheduler::tap`)
+pub struct Tap<T> {
+    sink: MsgSender<T>,
+    copy: fn(&T) -> T,
+    rate: f64,
+    seen: AtomicU64,
+}
+
+impl<T: Clone> Tap<T> {
+    /// Copy `rate` of the messages (between 0 and 1) to `sink`
+    pub fn new(sink: MsgSender<T>, rate: f64) -> Self {
+        Tap {
+            sink: sink,
+            copy: T::clone,
+            rate: rate,
+            seen: AtomicU64::new(0),
+        }
+    }
+}
+
+impl<T> Tap<T> {
+    fn sample(&self, msg: &T) {
+        let n = self.seen.fetch_add(1, Ordering::SeqCst) as f64;
+        // The messages which pass a multiple of 1 / rate, exactly `rate` of them
+        if ((n + 1.0) * self.rate).floor() > (n * self.rate).floor() {
+            // The sink never slows down the port, a copy without room is lost
+            let _ = self.sink.try_send((self.copy)(msg));
+        }
+    }
+}
+
+/// Used by the agent macro to tap an output port, which needs a `Clone` edge
+///
+/// `(&&Tapper::<T>::new()).tap(sink, rate)` resolves to `TapEdge` if `T` is `Clone`, to `NoTapEdge` otherwise.
+#[doc(hidden)]
+pub struct Tapper<T>(PhantomData<T>);
+
+impl<T> Tapper<T> {
+    pub fn new() -> Self {
+        Tapper(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait TapEdge<T> {
+    fn tap(&self, sink: Box<Any + Send>, rate: f64) -> Result<Tap<T>>;
+}
+
+impl<'a, T: Clone + Send + 'static> TapEdge<T> for &'a Tapper<T> {
+    fn tap(&self, sink: Box<Any + Send>, rate: f64) -> Result<Tap<T>> {
+        let sink = sink.downcast::<MsgSender<T>>()
+            .map_err(|_| result::Error::Misc("the sink of the tap is not a sender of the edge of the port".into()))?;
+        Ok(Tap::new(*sink, rate))
+    }
+}
+
+#[doc(hidden)]
+pub trait NoTapEdge<T> {
+    fn tap(&self, sink: Box<Any + Send>, rate: f64) -> Result<Tap<T>>;
+}
+
+impl<T> NoTapEdge<T> for Tapper<T> {
+    fn tap(&self, _sink: Box<Any + Send>, _rate: f64) -> Result<Tap<T>> {
+        Err(result::Error::Misc("the edge of the port is not Clone, it cannot be tapped".into()))
+    }
+}
+
+/// The size of a message in the limits of an agent (see `limits`), with what it owns on the heap
+///
+/// An edge which doesn't implement it counts for the size of its type.
+pub trait MsgSize {
+    fn msg_size(&self) -> usize;
+}
+
+impl MsgSize for String {
+    fn msg_size(&self) -> usize {
+        mem::size_of::<String>() + self.len()
+    }
+}
+
+impl MsgSize for Vec<u8> {
+    fn msg_size(&self) -> usize {
+        mem::size_of::<Vec<u8>>() + self.len()
+    }
+}
+
+// The size of a message of an edge without `MsgSize`
+fn type_size<T>(_msg: &T) -> usize {
+    mem::size_of::<T>()
+}
+
+/// Used by the agent macro to measure the messages of an input port
+///
+/// `(&&Sizer::<T>::new()).size()` resolves to `SizeEdge` if `T` is `MsgSize`, to `NoSizeEdge` otherwise.
+#[doc(hidden)]
+pub struct Sizer<T>(PhantomData<T>);
+
+impl<T> Sizer<T> {
+    pub fn new() -> Self {
+        Sizer(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait SizeEdge<T> {
+    fn size(&self) -> fn(&T) -> usize;
+}
+
+impl<'a, T: MsgSize> SizeEdge<T> for &'a Sizer<T> {
+    fn size(&self) -> fn(&T) -> usize {
+        T::msg_size
+    }
+}
+
+#[doc(hidden)]
+pub trait NoSizeEdge<T> {
+    fn size(&self) -> fn(&T) -> usize;
 }
 
+impl<T> NoSizeEdge<T> for Sizer<T> {
+    fn size(&self) -> fn(&T) -> usize {
+        type_size::<T>
+    }
+}
 
 pub struct MsgReceiver<T> {
     id: usize,
     recv: Receiver<(Option<String>, T)>,
+    urgent: Receiver<(Option<String>, T)>,
     sender: MsgSender<T>,
     sched: Sender<CompMsg>,
     must_sched: bool,
+    batch: usize,
+    watch: Arc<PortWatch>,
+    waker: Arc<Mutex<Option<Waker>>>,
+    acks: Option<Arc<Acks<T>>>,
+    interrupt: Option<Interrupt>,
+    cancel: Option<Cancel>,
 }
 
 impl<T> MsgReceiver<T> {
     pub fn new(id: usize, sched: Sender<CompMsg>, must_sched: bool) -> (MsgReceiver<T>, MsgSender<T>) {
-        let (s, r) = sync_channel(25);
+        Self::sized(id, sched, must_sched, type_size::<T>)
+    }
+
+    /// Create a port whose messages take `size` bytes in the limits of the agent, see `MsgSize`
+    pub fn sized(id: usize, sched: Sender<CompMsg>, must_sched: bool, size: fn(&T) -> usize) -> (MsgReceiver<T>, MsgSender<T>) {
+        Self::with_batch(id, sched, must_sched, 1, size)
+    }
+
+    /// Create a port of the same agent, which only runs it when `batch` messages are waiting, or after the flush timeout
+    ///
+    /// The messages must be received with `recv_batch`.
+    pub fn batched(&self, batch: usize) -> (MsgReceiver<T>, MsgSender<T>) {
+        Self::with_batch(self.id, self.sched.clone(), true, batch, self.sender.size)
+    }
+
+    /// Create a port without bound, which never blocks its senders
+    ///
+    /// It's for `testing`, which takes the messages of an agent only after its run, or when the network is idle.
+    pub fn unbounded(id: usize, sched: Sender<CompMsg>) -> (MsgReceiver<T>, MsgSender<T>) {
+        Self::with_channels(id, sched, false, 1, type_size::<T>, unbounded(), unbounded())
+    }
+
+    fn with_batch(id: usize, sched: Sender<CompMsg>, must_sched: bool, batch: usize, size: fn(&T) -> usize)
+                  -> (MsgReceiver<T>, MsgSender<T>) {
+        // Room for two batches, so the senders keep going while a batch is handled
+        Self::with_channels(id, sched, must_sched, batch, size, bounded(cmp::max(25, 2 * batch)), bounded(25))
+    }
+
+    fn with_channels(id: usize, sched: Sender<CompMsg>, must_sched: bool, batch: usize, size: fn(&T) -> usize,
+                     (s, r): (ChannelSender<(Option<String>, T)>, Receiver<(Option<String>, T)>),
+                     (urgent_s, urgent_r): (ChannelSender<(Option<String>, T)>, Receiver<(Option<String>, T)>))
+                     -> (MsgReceiver<T>, MsgSender<T>) {
+        let watch = Arc::new(PortWatch::new());
+        let waker = Arc::new(Mutex::new(None));
+        let evict = Arc::new(Mutex::new(Some(r.clone())));
         let s = MsgSender::<T> {
             sender: s,
+            urgent: urgent_s,
             dest: id,
             must_sched: must_sched,
+            batch: batch,
             sched: sched.clone(),
+            watch: watch.clone(),
+            waker: waker.clone(),
+            option_changed: false,
+            throttle: None,
+            cancel: None,
+            evict: evict,
+            size: size,
         };
         let r = MsgReceiver::<T> {
             recv: r,
+            urgent: urgent_r,
             sender: s.clone(),
             id: id,
             sched: sched,
             must_sched: must_sched,
+            batch: batch,
+            watch: watch,
+            waker: waker,
+            acks: None,
+            interrupt: None,
+            cancel: None,
         };
         (r, s)
     }
 
+    /// Let a new live option interrupt the blocking receives of the port, see `Interrupt`
+    pub fn set_interrupt(&mut self, interrupt: Interrupt) {
+        self.interrupt = Some(interrupt);
+    }
+
+    /// Stop the receives of the agent when it is cancelled, see `Cancel`
+    pub fn set_cancel(&mut self, cancel: Cancel) {
+        self.cancel = Some(cancel);
+    }
+
     pub fn recv(&self) -> Result<T> {
-        let msg = self.recv.recv()?;
-        if self.must_sched {
-            self.sched.send(CompMsg::Dec(self.id))?;
-        }
+        let msg = self.take(Wait::Block)?;
+        self.received(&msg.1)?;
         Ok(msg.1)
     }
 
     pub fn recv_with_action(&self) -> Result<(Option<String>, T)> {
-        let msg = self.recv.recv()?;
-        if self.must_sched {
-            self.sched.send(CompMsg::Dec(self.id))?;
-        }
+        let msg = self.take(Wait::Block)?;
+        self.received(&msg.1)?;
         Ok(msg)
     }
 
     pub fn try_recv(&self) -> Result<T> {
-        let msg = self.recv.try_recv()?;
-        if self.must_sched {
-            self.sched.send(CompMsg::Dec(self.id))?;
-        }
+        let msg = self.take(Wait::Never)?;
+        self.received(&msg.1)?;
         Ok(msg.1)
     }
 
     pub fn try_recv_with_action(&self) -> Result<(Option<String>, T)> {
-        let msg = self.recv.try_recv()?;
+        let msg = self.take(Wait::Never)?;
+        self.received(&msg.1)?;
+        Ok(msg)
+    }
+
+    /// Wait at most `timeout` for a message, else fail with `Error::RecvTimeout`
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T> {
+        self.recv_timeout_with_action(timeout).map(|msg| msg.1)
+    }
+
+    pub fn recv_timeout_with_action(&self, timeout: Duration) -> Result<(Option<String>, T)> {
+        let msg = self.take(Wait::Until(Instant::now() + timeout))?;
+        self.received(&msg.1)?;
+        Ok(msg)
+    }
+
+    // Take the next message, the high priority ones first
+    fn take(&self, wait: Wait) -> Result<(Option<String>, T)> {
+        loop {
+            if let Some(ref cancel) = self.cancel {
+                if cancel.is_cancelled() {
+                    return Err(result::Error::Cancelled);
+                }
+            }
+            // A breakpoint hides the messages it holds, until `step` wakes the receiver
+            if self.watch.held.load(Ordering::SeqCst) {
+                let _ = self.watch.step_recv.try_recv();
+            }
+            let holding = self.watch.holding();
+            let msg = if holding {
+                None
+            } else {
+                match self.urgent.try_recv() {
+                    Ok(msg) => Some(msg),
+                    Err(_) => match self.recv.try_recv() {
+                        Err(TryRecvError::Empty) if self.watch.closed.load(Ordering::SeqCst) => { return self.eof(); },
+                        Err(TryRecvError::Empty) => None,
+                        res => Some(res?),
+                    },
+                }
+            };
+            if msg.is_none() && wait.expired() {
+                return match wait {
+                    Wait::Until(_) => Err(result::Error::RecvTimeout),
+                    _ => Err(result::Error::ChannelTryRecv(TryRecvError::Empty)),
+                };
+            }
+            if let Some(msg) = msg {
+                if self.watch.held.load(Ordering::SeqCst) {
+                    let _ = self.watch.released.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1));
+                }
+                // Over the limits of the agent, the oldest messages are dropped
+                let dropped = self.watch.limit().map(|limit| limit.take_drop()).unwrap_or(false);
+                if !dropped {
+                    return Ok(msg);
+                }
+                self.received(&msg.1)?;
+                continue;
+            }
+            // Only interrupted when no message waits
+            if let Some(ref interrupt) = self.interrupt {
+                if interrupt.recv.try_recv().is_ok() {
+                    return Err(result::Error::Interrupted);
+                }
+            }
+            let mut sel = Select::new();
+            if holding {
+                sel.recv(&self.watch.step_recv);
+            } else {
+                sel.recv(&self.urgent);
+                sel.recv(&self.recv);
+            }
+            if let Some(ref interrupt) = self.interrupt {
+                sel.recv(&interrupt.recv);
+            }
+            if let Some(ref cancel) = self.cancel {
+                sel.recv(&cancel.recv);
+            }
+            sel.recv(&self.watch.close_recv);
+            self.watch.receiving.store(true, Ordering::SeqCst);
+            // At the deadline, the next loop fails if no message came
+            match wait.deadline() {
+                Some(deadline) => { let _ = sel.ready_deadline(deadline); },
+                None => { sel.ready(); },
+            }
+            self.watch.receiving.store(false, Ordering::SeqCst);
+        }
+    }
+
+    /// Receive the waiting messages of a batch port, at most one batch, without blocking
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// for token in self.input.input.recv_batch()? {
+    ///     ...
+    /// }
+    /// ```
+    pub fn recv_batch(&self) -> Result<Vec<T>> {
+        let mut msgs = Vec::with_capacity(self.batch);
+        while msgs.len() < self.batch {
+            match self.take(Wait::Never) {
+                Ok(msg) => { msgs.push(msg.1); },
+                Err(_) => { break; },
+            }
+        }
+        let left = self.watch.queued.fetch_sub(msgs.len(), Ordering::SeqCst) - msgs.len();
+        self.watch.received.fetch_add(msgs.len() as u64, Ordering::SeqCst);
+        self.watch.notify_received();
+        self.unlimit(&msgs);
+        if !msgs.is_empty() {
+            self.watch.touch();
+        }
         if self.must_sched {
+            if msgs.len() == self.batch {
+                self.sched.send(CompMsg::Dec(self.id))?;
+            }
+            // The rest of an incomplete batch waits for the next flush
+            if left % self.batch != 0 {
+                self.sched.send(CompMsg::ArmFlush(self.id))?;
+            }
+        }
+        Ok(msgs)
+    }
+
+    // The end of stream of a closed port, taken out of the port once like a message
+    fn eof(&self) -> Result<(Option<String>, T)> {
+        if !self.watch.eof_taken.swap(true, Ordering::SeqCst) {
+            self.watch.queued.fetch_sub(1, Ordering::SeqCst);
+            if self.must_sched && self.batch == 1 {
+                self.sched.send(CompMsg::Dec(self.id))?;
+            }
+        }
+        Err(result::Error::Closed)
+    }
+
+    // Account for a message taken out of the port
+    fn received(&self, msg: &T) -> Result<()> {
+        self.watch.queued.fetch_sub(1, Ordering::SeqCst);
+        self.watch.received.fetch_add(1, Ordering::SeqCst);
+        self.watch.notify_received();
+        self.unlimit(::std::slice::from_ref(msg));
+        self.watch.touch();
+        if self.must_sched && self.batch == 1 {
             self.sched.send(CompMsg::Dec(self.id))?;
         }
-        Ok(msg)
+        Ok(())
+    }
+
+    // Account for the messages taken out of the port in the limits of the agent
+    fn unlimit(&self, msgs: &[T]) {
+        if let Some(limit) = self.watch.limit() {
+            let bytes = msgs.iter().map(|msg| (self.sender.size)(msg)).sum();
+            limit.unqueue(bytes, msgs.len());
+        }
+    }
+
+    /// Receive a message without blocking the thread, in an `async_agent!`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let msg = self.input.input.recv_async().await?;
+    /// ```
+    pub fn recv_async(&mut self) -> Recv<T> {
+        Recv { receiver: self }
     }
 
     pub fn get_sender(&self) -> MsgSender<T> {
         self.sender.clone()
     }
 }
+
+/// A named option port of an agent, declared with `options(config: AppConfig, ...)`
+///
+/// It keeps the last option received : `recv` gives it again at each execution, until a new one comes.
+///
+/// # Example
+/// ```rust,ignore
+/// let config = self.options.config.recv()?;
+/// if let Some(credentials) = self.options.credentials.changed() {
+///     ...
+/// }
+/// ```
+pub struct OptionPort<T> {
+    recv: MsgReceiver<T>,
+    last: Option<T>,
+    interrupt: Option<Interrupt>,
+}
+
+impl<T: Clone> OptionPort<T> {
+    pub fn new(recv: MsgReceiver<T>) -> Self {
+        OptionPort {
+            recv: recv,
+            last: None,
+            interrupt: None,
+        }
+    }
+
+    /// Clear the interruption of the agent when a new option is taken, see `Interrupt`
+    pub fn set_interrupt(&mut self, interrupt: Interrupt) {
+        self.interrupt = Some(interrupt);
+    }
+
+    /// The last option, waiting for the first one
+    pub fn recv(&mut self) -> Result<T> {
+        if let Some(last) = self.try_recv() {
+            return Ok(last);
+        }
+        let msg = self.recv.recv()?;
+        self.last = Some(msg.clone());
+        Ok(msg)
+    }
+
+    /// The last option, None before the first one
+    pub fn try_recv(&mut self) -> Option<T> {
+        self.changed();
+        self.last.clone()
+    }
+
+    /// The new option, if one came since the last call to `recv`, `try_recv` or `changed`
+    pub fn changed(&mut self) -> Option<T> {
+        if let Some(ref interrupt) = self.interrupt {
+            interrupt.clear();
+        }
+        let mut changed = false;
+        while let Ok(msg) = self.recv.try_recv() {
+            self.last = Some(msg);
+            changed = true;
+        }
+        if changed { self.last.clone() } else { None }
+    }
+}
+
+/// Interrupts the blocking receives of an agent with a live option (`option(T [live])`), when a new option comes
+///
+/// A blocking `recv` with no message waiting then returns `Error::Interrupted`, once for each new option. The agent
+/// takes the new option with `on_option_changed`, which also clears the interruption.
+#[derive(Clone)]
+pub struct Interrupt {
+    sender: ChannelSender<()>,
+    recv: Receiver<()>,
+}
+
+impl Interrupt {
+    pub fn new() -> Self {
+        // Several new options before a receive make one interruption
+        let (s, r) = bounded(1);
+        Interrupt {
+            sender: s,
+            recv: r,
+        }
+    }
+
+    pub fn fire(&self) {
+        let _ = self.sender.try_send(());
+    }
+
+    pub fn clear(&self) {
+        while self.recv.try_recv().is_ok() {}
+    }
+}
+
+/// Cancels the current execution of an agent, when it is over its time limit with `LimitAction::Cancel`
+///
+/// While cancelled, the `recv` and the `send` of the agent return `Error::Cancelled`, the blocked ones at once, so a
+/// stuck agent ends at its next port operation. The scheduler resets the token before each execution.
+#[derive(Clone)]
+pub struct Cancel {
+    cancelled: Arc<AtomicBool>,
+    // Wakes the blocked receives and sends, never emptied while cancelled
+    sender: ChannelSender<()>,
+    recv: Receiver<()>,
+}
+
+impl Cancel {
+    pub fn new() -> Self {
+        let (s, r) = bounded(1);
+        Cancel {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            sender: s,
+            recv: r,
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        let _ = self.sender.try_send(());
+    }
+
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::SeqCst);
+        while self.recv.try_recv().is_ok() {}
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// The state of an input port, shared by its receiver and its senders
+///
+/// The watchdog of the scheduler reads it to find the blocked and the starving agents, the durable edges to know
+/// which of their messages were consumed.
+#[derive(Debug)]
+pub struct PortWatch {
+    /// The messages waiting in the port
+    pub queued: AtomicUsize,
+    /// True while the agent waits for a message in `recv`
+    pub receiving: AtomicBool,
+    /// The senders waiting for room in the port
+    pub blocked_senders: AtomicUsize,
+    /// The last time a message was taken out of the port, in ms since the epoch
+    pub last_recv: AtomicU64,
+    /// The messages sent in the port since its creation
+    pub sent: AtomicU64,
+    /// The messages taken out of the port since its creation
+    pub received: AtomicU64,
+    /// True once the port is closed, see `MsgSender::close`
+    pub closed: AtomicBool,
+    /// The messages dropped because the port overflowed, see `Overflow`
+    pub dropped: AtomicU64,
+    // The messages kept by the port when it overflows, 0 to block the senders
+    keep: AtomicUsize,
+    /// The limits of the agent, see `Scheduler::add_node_with_limits`
+    limit: Mutex<Option<Arc<LimitState>>>,
+    // Wakes the receiver waiting in `recv` when the port is closed
+    close_send: ChannelSender<()>,
+    close_recv: Receiver<()>,
+    // Set once the receiver got the end of stream
+    eof_taken: AtomicBool,
+    // The breakpoint of the port : set, the messages it holds, and the ones released by `step` not taken yet
+    held: AtomicBool,
+    staged: AtomicUsize,
+    released: AtomicUsize,
+    // Wakes the receiver waiting in `recv` when messages are released
+    step_send: ChannelSender<()>,
+    step_recv: Receiver<()>,
+    // Woken when messages are taken out of the port, see `on_received`
+    on_received: Mutex<Option<ChannelSender<()>>>,
+    notify: AtomicBool,
+    // The segments of the messages built for the port, see `Scheduler::connect_pooled`
+    arena: Mutex<Option<Arena>>,
+}
+
+impl PortWatch {
+    pub fn new() -> Self {
+        let (close_send, close_recv) = bounded(1);
+        let (step_send, step_recv) = bounded(1);
+        let watch = PortWatch {
+            queued: AtomicUsize::new(0),
+            receiving: AtomicBool::new(false),
+            blocked_senders: AtomicUsize::new(0),
+            last_recv: AtomicU64::new(0),
+            sent: AtomicU64::new(0),
+            received: AtomicU64::new(0),
+            closed: AtomicBool::new(false),
+            dropped: AtomicU64::new(0),
+            keep: AtomicUsize::new(0),
+            limit: Mutex::new(None),
+            close_send: close_send,
+            close_recv: close_recv,
+            eof_taken: AtomicBool::new(false),
+            held: AtomicBool::new(false),
+            staged: AtomicUsize::new(0),
+            released: AtomicUsize::new(0),
+            step_send: step_send,
+            step_recv: step_recv,
+            on_received: Mutex::new(None),
+            notify: AtomicBool::new(false),
+            arena: Mutex::new(None),
+        };
+        watch.touch();
+        watch
+    }
+
+    fn touch(&self) {
+        self.last_recv.store(now_ms(), Ordering::SeqCst);
+    }
+
+    /// Wake `wake` each time messages are taken out of the port, instead of a previous one
+    ///
+    /// The wakes of a full `wake` are merged, the count is `received`.
+    pub fn on_received(&self, wake: ChannelSender<()>) {
+        *self.on_received.lock().expect("port on_received : poisoned waker") = Some(wake);
+        self.notify.store(true, Ordering::SeqCst);
+    }
+
+    fn notify_received(&self) {
+        if self.notify.load(Ordering::SeqCst) {
+            if let Some(ref wake) = *self.on_received.lock().expect("port notify_received : poisoned waker") {
+                let _ = wake.try_send(());
+            }
+        }
+    }
+
+    /// Count the messages of the port in the limits of its agent
+    pub fn set_limit(&self, limit: Arc<LimitState>) {
+        *self.limit.lock().expect("port set_limit : poisoned limit") = Some(limit);
+    }
+
+    pub fn limit(&self) -> Option<Arc<LimitState>> {
+        self.limit.lock().ok().and_then(|limit| limit.clone())
+    }
+
+    /// Build the messages sent to the port in the segments of `arena`, None to build them on the heap
+    pub fn set_arena(&self, arena: Option<Arena>) {
+        *self.arena.lock().expect("port set_arena : poisoned arena") = arena;
+    }
+
+    pub fn arena(&self) -> Option<Arena> {
+        self.arena.lock().ok().and_then(|arena| arena.clone())
+    }
+
+    /// The segments allocated and reused by the arena of the port, if it has one
+    pub fn arena_stats(&self) -> Option<ArenaStats> {
+        self.arena().map(|arena| arena.stats())
+    }
+
+    /// Drop the oldest messages instead of blocking the senders when the port is full
+    ///
+    /// The port holds at most as many messages as its channel, so a larger `Overflow::DropOldest` still blocks the
+    /// senders at that size.
+    pub fn set_overflow(&self, overflow: Overflow) {
+        self.keep.store(overflow.keep(), Ordering::SeqCst);
+    }
+
+    /// Hold the new messages of the port, and the ones waiting, until `step` releases them
+    ///
+    /// Returns the number of messages which were waiting : the scheduler counted them for the agent, it must not any
+    /// more (see `Scheduler::set_breakpoint`).
+    pub fn hold(&self) -> usize {
+        if self.held.swap(true, Ordering::SeqCst) {
+            return 0;
+        }
+        self.released.store(0, Ordering::SeqCst);
+        let waiting = self.queued.load(Ordering::SeqCst);
+        self.staged.fetch_add(waiting, Ordering::SeqCst);
+        waiting
+    }
+
+    /// Release at most `n` of the held messages, and return how many were released
+    pub fn step(&self, n: usize) -> usize {
+        let mut released = 0;
+        let _ = self.staged.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |staged| {
+            released = cmp::min(staged, n);
+            Some(staged - released)
+        });
+        self.released.fetch_add(released, Ordering::SeqCst);
+        let _ = self.step_send.try_send(());
+        released
+    }
+
+    /// Remove the breakpoint, and return the number of messages it still held
+    pub fn unhold(&self) -> usize {
+        self.held.store(false, Ordering::SeqCst);
+        let staged = self.staged.swap(0, Ordering::SeqCst);
+        let _ = self.step_send.try_send(());
+        staged
+    }
+
+    /// The messages held by the breakpoint of the port
+    pub fn held(&self) -> usize {
+        self.staged.load(Ordering::SeqCst)
+    }
+
+    // Count a new message as held, if the port has a breakpoint
+    fn hold_one(&self) -> bool {
+        if self.held.load(Ordering::SeqCst) {
+            self.staged.fetch_add(1, Ordering::SeqCst);
+            true
+        } else {
+            false
+        }
+    }
+
+    // True if the receiver cannot take a message, because of a breakpoint. A closed port releases them all
+    fn holding(&self) -> bool {
+        self.held.load(Ordering::SeqCst) && self.released.load(Ordering::SeqCst) == 0
+            && !self.closed.load(Ordering::SeqCst)
+    }
+
+    /// The time since a message was last taken out of the port
+    pub fn idle_for(&self) -> Duration {
+        Duration::from_millis(now_ms().saturating_sub(self.last_recv.load(Ordering::SeqCst)))
+    }
+}
+
+fn now_ms() -> u64 {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or(Duration::from_secs(0));
+    now.as_secs() * 1000 + now.subsec_millis() as u64
+}
+
+/// A receiver which can wait with others in `select`
+pub trait Selectable {
+    /// Add the receiver to the selection, and return the indexes of its operations
+    fn register<'a>(&'a self, sel: &mut Select<'a>) -> Vec<usize>;
+}
+
+impl<T> Drop for MsgReceiver<T> {
+    fn drop(&mut self) {
+        // The senders must see the port disconnected
+        if let Ok(mut evict) = self.sender.evict.lock() {
+            evict.take();
+        }
+    }
+}
+
+impl<T> Selectable for MsgReceiver<T> {
+    fn register<'a>(&'a self, sel: &mut Select<'a>) -> Vec<usize> {
+        vec![sel.recv(&self.urgent), sel.recv(&self.recv), sel.recv(&self.watch.close_recv)]
+    }
+}
+
+/// Wait until one of the receivers has a message, and return its index in `receivers`
+///
+/// The message stays in the receiver. It can rarely be a false alarm, so take it with `try_recv`.
+///
+/// # Example
+/// ```rust,ignore
+/// match select(&[&self.input.data, &self.input.control]) {
+///     0 => if let Ok(msg) = self.input.data.try_recv() { ... },
+///     _ => if let Ok(msg) = self.input.control.try_recv() { ... },
+/// }
+/// ```
+pub fn select(receivers: &[&Selectable]) -> usize {
+    let mut sel = Select::new();
+    let indexes: Vec<Vec<usize>> = receivers.iter().map(|r| r.register(&mut sel)).collect();
+    let ready = sel.ready();
+    indexes.iter().position(|ops| ops.contains(&ready)).expect("select : unknown operation")
+}
+
+/// The future returned by `MsgReceiver::recv_async`
+pub struct Recv<'a, T: 'a> {
+    receiver: &'a mut MsgReceiver<T>,
+}
+
+impl<'a, T> Future for Recv<'a, T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<T>> {
+        match self.receiver.try_recv() {
+            Err(result::Error::ChannelTryRecv(TryRecvError::Empty)) => {},
+            res => { return Poll::Ready(res); }
+        }
+        *self.receiver.waker.lock().expect("Recv poll : poisoned waker") = Some(cx.waker().clone());
+        // A message sent before the waker was set would not wake us
+        match self.receiver.try_recv() {
+            Err(result::Error::ChannelTryRecv(TryRecvError::Empty)) => Poll::Pending,
+            res => Poll::Ready(res),
+        }
+    }
+}
+
+/// How long a message of an ack port waits for its `ack`, before it is sent again
+pub const ACK_TIMEOUT: Duration = Duration::from_secs(10);
+
+// The messages of an ack port taken by the agent, not yet acknowledged
+struct Acks<T> {
+    pending: Mutex<HashMap<u64, (Instant, T)>>,
+    next: AtomicU64,
+    timeout: Duration,
+    sender: MsgSender<T>,
+    port: String,
+}
+
+impl<T> Acks<T> {
+    fn take(&self, id: u64) -> Option<T> {
+        self.pending.lock().expect("Acks : poisoned pending").remove(&id).map(|(_, msg)| msg)
+    }
+}
+
+impl<T: Clone + Send + 'static> MsgReceiver<T> {
+    /// Put the port in ack mode : a message taken with `recv_ack` is sent again in the port, unless it is
+    /// acknowledged before `timeout`
+    ///
+    /// This gives at-least-once delivery : the agent can see a message twice if it acknowledges it too late.
+    pub fn with_ack(mut self, port: &str, timeout: Duration) -> Self {
+        let acks = Arc::new(Acks {
+            pending: Mutex::new(HashMap::new()),
+            next: AtomicU64::new(0),
+            timeout: timeout,
+            sender: self.sender.clone(),
+            port: port.into(),
+        });
+        let weak: Weak<Acks<T>> = Arc::downgrade(&acks);
+        thread::spawn(move || {
+            let period = cmp::max(timeout / 4, Duration::from_millis(10));
+            loop {
+                thread::sleep(period);
+                // Stop with the port
+                let acks = match weak.upgrade() {
+                    Some(acks) => acks,
+                    None => { return; }
+                };
+                let now = Instant::now();
+                let expired: Vec<T> = {
+                    let mut pending = acks.pending.lock().expect("Acks : poisoned pending");
+                    let ids: Vec<u64> = pending.iter().filter(|&(_, &(deadline, _))| deadline <= now).map(|(id, _)| *id).collect();
+                    ids.into_iter().filter_map(|id| pending.remove(&id)).map(|(_, msg)| msg).collect()
+                };
+                for msg in expired {
+                    let _ = acks.sender.send(msg);
+                }
+            }
+        });
+        self.acks = Some(acks);
+        self
+    }
+
+    /// Receive a message of an ack port, to acknowledge with `ack`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let msg = self.input.input.recv_ack()?;
+    /// write_in_db(&msg)?;
+    /// msg.ack();
+    /// ```
+    pub fn recv_ack(&self) -> Result<Acked<T>> {
+        let msg = self.recv()?;
+        Ok(self.track(msg))
+    }
+
+    /// Receive a message of an ack port without blocking, to acknowledge with `ack`
+    pub fn try_recv_ack(&self) -> Result<Acked<T>> {
+        let msg = self.try_recv()?;
+        Ok(self.track(msg))
+    }
+
+    fn track(&self, msg: T) -> Acked<T> {
+        let id = match self.acks {
+            Some(ref acks) => {
+                let id = acks.next.fetch_add(1, Ordering::SeqCst);
+                acks.pending.lock().expect("Acks : poisoned pending").insert(id, (Instant::now() + acks.timeout, msg.clone()));
+                id
+            }
+            None => 0,
+        };
+        Acked {
+            msg: msg,
+            id: id,
+            acks: self.acks.clone(),
+        }
+    }
+}
+
+/// A message received on an ack port
+///
+/// It is sent again after the timeout of the port, unless `ack` or `reject` is called. Outside of ack mode, `ack`,
+/// `nack` and `reject` do nothing.
+pub struct Acked<T> {
+    msg: T,
+    id: u64,
+    acks: Option<Arc<Acks<T>>>,
+}
+
+impl<T> Acked<T> {
+    /// The message is handled, it will not be sent again
+    pub fn ack(&self) {
+        if let Some(ref acks) = self.acks {
+            acks.take(self.id);
+        }
+    }
+
+    /// The message cannot be handled now, send it again without waiting for the timeout
+    pub fn nack(&self) -> Result<()> {
+        if let Some(ref acks) = self.acks {
+            if let Some(msg) = acks.take(self.id) {
+                acks.sender.send(msg)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The message can never be handled, it goes to the dead letters of the scheduler instead of being sent again
+    pub fn reject(&self) where T: Send + 'static {
+        if let Some(ref acks) = self.acks {
+            if let Some(msg) = acks.take(self.id) {
+                let _ = acks.sender.sched.send(CompMsg::DeadLetter(acks.sender.dest, acks.port.clone(), Reason::Rejected, Box::new(msg)));
+            }
+        }
+    }
+
+    /// Get the message, which is still sent again if it is not acknowledged
+    pub fn into_inner(self) -> T {
+        self.msg
+    }
+}
+
+impl<T> Deref for Acked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.msg
+    }
+}
+
+impl<T> DerefMut for Acked<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.msg
+    }
+}
+
+/// An edge carrying an ordinary Rust structure, without any schema file
+///
+/// Rust only networks can use it instead of a Cap'n Proto edge, which stay needed for the cross-languages nodes.
+/// The schema of the port is the Rust type : two ports can be connected if they carry the same `SerdeEdge<T>`.
+///
+/// # Example
+/// ```rust,ignore
+/// agent! {
+///     input(input: SerdeEdge<Person>),
+///     output(output: SerdeEdge<Person>),
+///     fn run(&mut self) -> Result<Signal> {
+///         let mut person = self.input.input.recv()?;
+///         person.age += 1;
+///         self.output.output.send(person)?;
+///         Ok(End)
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SerdeEdge<T>(pub T);
+
+impl<T> SerdeEdge<T> {
+    pub fn new(value: T) -> Self {
+        SerdeEdge(value)
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> SerdeEdge<T> {
+    /// Serialize the value, when it must leave the process
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(&self.0)?)
+    }
+
+    /// Deserialize a value serialized with `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(SerdeEdge(serde_json::from_slice(bytes)?))
+    }
+}
+
+impl<T: Serialize> MsgSize for SerdeEdge<T> {
+    // The length of its serialization, without keeping it
+    fn msg_size(&self) -> usize {
+        struct Count(usize);
+        impl ::std::io::Write for Count {
+            fn write(&mut self, buf: &[u8]) -> ::std::io::Result<usize> {
+                self.0 += buf.len();
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> ::std::io::Result<()> { Ok(()) }
+        }
+        let mut count = Count(0);
+        let _ = serde_json::to_writer(&mut count, &self.0);
+        mem::size_of::<T>() + count.0
+    }
+}
+
+impl<T> From<T> for SerdeEdge<T> {
+    fn from(value: T) -> Self {
+        SerdeEdge(value)
+    }
+}
+
+impl<T> Deref for SerdeEdge<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for SerdeEdge<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// A message shared between the agents of the same process
+///
+/// Cloning a `SharedMsg` (for example to send it on several outputs) only clones the pointer.
+/// The message is copied the first time a receiver wants to mutate it, if it is still shared.
+pub struct SharedMsg<T>(Arc<T>);
+
+impl<T> SharedMsg<T> {
+    pub fn new(msg: T) -> Self {
+        SharedMsg(Arc::new(msg))
+    }
+
+    /// True if other receivers hold the same message
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.0) > 1
+    }
+}
+
+impl<T: Clone> SharedMsg<T> {
+    /// Get a mutable reference, copying the message if it is shared
+    pub fn get_mut(&mut self) -> &mut T {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Retrieve the message, copying it if it is shared
+    pub fn into_inner(self) -> T {
+        Arc::try_unwrap(self.0).unwrap_or_else(|msg| (*msg).clone())
+    }
+}
+
+impl<T> Clone for SharedMsg<T> {
+    fn clone(&self) -> Self {
+        SharedMsg(self.0.clone())
+    }
+}
+
+impl<T> Deref for SharedMsg<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for SharedMsg<T> {
+    fn from(msg: T) -> Self {
+        SharedMsg::new(msg)
+    }
+}
+
+/// A Cap'n Proto message passed without serialization between the agents of the same process
+///
+/// Like `SharedMsg`, a clone only clones the pointer and the message is copied when a shared message is mutated.
+///
+/// A message built from a typed edge (see `CapnpEdge::into_capnp_msg`) carries the name and the version of its
+/// schema, so a consumer built with another version of the schema fails to read it instead of misreading it (see
+/// `schema`).
+///
+/// A message also carries headers, a small map of names to bytes outside of its schema (trace ids, content types, user
+/// keys, ...). They follow the message through the clones, `forward` and the remote edges.
+///
+/// A message built with the builder of a pooled connection (see `MsgSender::new_builder`) takes its segments from the
+/// arena of the connection and gives them back when its last clone is dropped.
+pub struct CapnpMsg {
+    msg: Arc<Mutex<MsgBuilder>>,
+    schema: Option<SchemaTag>,
+    // Few, so a vector is faster than a map
+    headers: Vec<(String, Vec<u8>)>,
+}
+
+// The start of a message serialized with its schema, never the start of a Cap'n Proto message : it would be a
+// message of more than a billion segments
+const ENVELOPE: &[u8] = b"FBPS";
+// The start of the headers of a message, before its envelope
+const HEADERS: &[u8] = b"FBPH";
+
+/// The builder of a `CapnpMsg`
+pub type MsgBuilder = message::Builder<MsgAllocator>;
+
+impl CapnpMsg {
+    pub fn new(msg: MsgBuilder) -> Self {
+        CapnpMsg {
+            msg: Arc::new(Mutex::new(msg)),
+            schema: None,
+            headers: vec![],
+        }
+    }
+
+    /// A new message with the headers of this one, for an agent which forwards what it receives
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let msg = self.input.input.recv()?;
+    /// self.output.output.send(msg.forward(builder))?;
+    /// ```
+    pub fn forward(&self, msg: MsgBuilder) -> Self {
+        CapnpMsg {
+            msg: Arc::new(Mutex::new(msg)),
+            schema: None,
+            headers: self.headers.clone(),
+        }
+    }
+
+    /// A builder for a new message, on the heap
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let mut builder = CapnpMsg::builder();
+    /// builder.init_root::<prim_text::Builder>().set_text("hello");
+    /// self.output.output.send(CapnpMsg::new(builder))?;
+    /// ```
+    pub fn builder() -> MsgBuilder {
+        message::Builder::new(MsgAllocator::new(None))
+    }
+
+    /// The value of a header
+    pub fn header(&self, name: &str) -> Option<&[u8]> {
+        self.headers.iter().find(|h| h.0 == name).map(|h| &h.1[..])
+    }
+
+    /// Set a header, replacing its value if it is already set
+    pub fn set_header<V: Into<Vec<u8>>>(&mut self, name: &str, value: V) {
+        let value = value.into();
+        match self.headers.iter_mut().find(|h| h.0 == name) {
+            Some(header) => { header.1 = value; },
+            None => { self.headers.push((name.to_string(), value)); },
+        }
+    }
+
+    /// Remove a header, and return its value
+    pub fn remove_header(&mut self, name: &str) -> Option<Vec<u8>> {
+        let i = self.headers.iter().position(|h| h.0 == name)?;
+        Some(self.headers.remove(i).1)
+    }
+
+    /// All the headers, in the order they were set
+    pub fn headers(&self) -> &[(String, Vec<u8>)] {
+        &self.headers
+    }
+
+    /// Set the schema of the message
+    pub fn with_schema(mut self, schema: SchemaTag) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// The schema of the message, if it was built from a typed edge
+    pub fn schema(&self) -> Option<&SchemaTag> {
+        self.schema.as_ref()
+    }
+
+    /// True if other receivers hold the same message
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.msg) > 1
+    }
+
+    /// Read the message without copying it
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let text = msg.read(|msg| {
+    ///     let reader: prim_text::Reader = msg.get_root_as_reader()?;
+    ///     Ok(reader.get_text()?.to_string())
+    /// })?;
+    /// ```
+    pub fn read<F, R>(&self, f: F) -> Result<R> where
+        F: FnOnce(&MsgBuilder) -> Result<R>
+    {
+        let msg = self.msg.lock().expect("CapnpMsg read : poisoned message");
+        f(&*msg)
+    }
+
+    /// Get the builder of the message, copying the message on the heap if it is shared
+    pub fn get_builder(&mut self) -> Result<MutexGuard<MsgBuilder>> {
+        if self.is_shared() {
+            let mut copy = CapnpMsg::builder();
+            {
+                let msg = self.msg.lock().expect("CapnpMsg get_builder : poisoned message");
+                copy.set_root(msg.get_root_as_reader::<any_pointer::Reader>()?)?;
+            }
+            self.msg = Arc::new(Mutex::new(copy));
+        }
+        Ok(self.msg.lock().expect("CapnpMsg get_builder : poisoned message"))
+    }
+
+    /// Serialize the message, only needed when it leaves the process
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let msg = self.msg.lock().expect("CapnpMsg to_bytes : poisoned message");
+        let mut bytes = vec![];
+        serialize::write_message(&mut bytes, &*msg)?;
+        Ok(bytes)
+    }
+
+    /// Read a message serialized with `to_bytes`
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self> {
+        let reader = serialize::read_message(&mut bytes, message::ReaderOptions::new())?;
+        let mut msg = CapnpMsg::builder();
+        msg.set_root(reader.get_root::<any_pointer::Reader>()?)?;
+        Ok(CapnpMsg::new(msg))
+    }
+
+    /// Serialize the message with its schema and its headers, like `to_bytes` if it has none
+    pub fn to_envelope(&self) -> Result<Vec<u8>> {
+        let mut bytes = vec![];
+        if !self.headers.is_empty() {
+            bytes.extend_from_slice(HEADERS);
+            bytes.extend_from_slice(&(self.headers.len() as u32).to_le_bytes());
+            for &(ref name, ref value) in &self.headers {
+                bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+                bytes.extend_from_slice(name.as_bytes());
+                bytes.extend_from_slice(&(value.len() as u32).to_le_bytes());
+                bytes.extend_from_slice(value);
+            }
+        }
+        if let Some(ref schema) = self.schema {
+            bytes.extend_from_slice(ENVELOPE);
+            bytes.extend_from_slice(&(schema.name.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(schema.name.as_bytes());
+            bytes.extend_from_slice(&schema.version.to_le_bytes());
+        }
+        bytes.extend(self.to_bytes()?);
+        Ok(bytes)
+    }
+
+    /// Read a message serialized with `to_envelope`, or with `to_bytes`
+    pub fn from_envelope(bytes: &[u8]) -> Result<Self> {
+        let mut headers = vec![];
+        let mut rest = bytes;
+        if rest.starts_with(HEADERS) {
+            let bad = || result::Error::Misc("CapnpMsg from_envelope : truncated headers".into());
+            rest = &rest[HEADERS.len()..];
+            let count = take_u32(&mut rest).ok_or_else(bad)?;
+            for _ in 0..count {
+                let name = take_block(&mut rest).ok_or_else(bad)?;
+                let value = take_block(&mut rest).ok_or_else(bad)?;
+                headers.push((String::from_utf8(name)?, value));
+            }
+            // `to_envelope` writes one block of headers
+            if rest.starts_with(HEADERS) {
+                return Err(result::Error::Misc("CapnpMsg from_envelope : a second block of headers".into()));
+            }
+        }
+        let mut msg = CapnpMsg::from_schema_envelope(rest)?;
+        msg.headers = headers;
+        Ok(msg)
+    }
+
+    // Read the schema envelope and the message, after the headers
+    fn from_schema_envelope(bytes: &[u8]) -> Result<Self> {
+        if !bytes.starts_with(ENVELOPE) {
+            return CapnpMsg::from_bytes(bytes);
+        }
+        let bad = || result::Error::Misc("CapnpMsg from_envelope : truncated envelope".into());
+        let rest = &bytes[ENVELOPE.len()..];
+        let len = rest.get(..4).ok_or_else(bad)?;
+        let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize;
+        let name = rest.get(4..4 + len).ok_or_else(bad)?;
+        let version = rest.get(4 + len..12 + len).ok_or_else(bad)?;
+        let mut v = [0; 8];
+        v.copy_from_slice(version);
+        let schema = SchemaTag {
+            name: String::from_utf8(name.to_vec())?,
+            version: u64::from_le_bytes(v),
+        };
+        Ok(CapnpMsg::from_bytes(&rest[12 + len..])?.with_schema(schema))
+    }
+}
+
+// A u32 at the start of `bytes`, in little endian
+fn take_u32(bytes: &mut &[u8]) -> Option<u32> {
+    let n = bytes.get(..4)?;
+    let n = u32::from_le_bytes([n[0], n[1], n[2], n[3]]);
+    *bytes = &bytes[4..];
+    Some(n)
+}
+
+// A block of bytes prefixed by its length at the start of `bytes`
+fn take_block(bytes: &mut &[u8]) -> Option<Vec<u8>> {
+    let len = take_u32(bytes)? as usize;
+    let block = bytes.get(..len)?.to_vec();
+    *bytes = &bytes[len..];
+    Some(block)
+}
+
+impl Clone for CapnpMsg {
+    fn clone(&self) -> Self {
+        CapnpMsg {
+            msg: self.msg.clone(),
+            schema: self.schema.clone(),
+            headers: self.headers.clone(),
+        }
+    }
+}
+
+impl MsgSize for CapnpMsg {
+    // The segments of the message, of 8 bytes words, and its headers
+    fn msg_size(&self) -> usize {
+        let segments: usize = {
+            let msg = self.msg.lock().expect("CapnpMsg msg_size : poisoned message");
+            msg.get_segments_for_output().iter().map(|segment| segment.len() * 8).sum()
+        };
+        let headers: usize = self.headers.iter().map(|&(ref name, ref value)| name.len() + value.len()).sum();
+        mem::size_of::<CapnpMsg>() + segments + headers
+    }
+}
+
+impl From<MsgBuilder> for CapnpMsg {
+    fn from(msg: MsgBuilder) -> Self {
+        CapnpMsg::new(msg)
+    }
+}
+
+// The sizes of the segments recycled by an arena, in words of 8 bytes : from 64 B to 1 MiB. A new message asks for a
+// first segment of 1024 words.
+const ARENA_CLASSES: [usize; 8] = [8, 32, 128, 1024, 4096, 16384, 65536, 131072];
+
+/// A pool of message segments, recycled by size class
+///
+/// The messages built with the builder of a pooled connection (see `Scheduler::connect_pooled`) take their segments
+/// from the arena of the connection and give them back once they are dropped, so a steady flow of messages stops
+/// allocating. A segment larger than the largest class (1 MiB) is not recycled. An arena can be shared by several
+/// connections, a clone only clones the pointer.
+#[derive(Clone)]
+pub struct Arena {
+    inner: Arc<ArenaInner>,
+}
+
+struct ArenaInner {
+    classes: Vec<Mutex<Vec<Vec<Word>>>>,
+    keep: usize,
+    allocated: AtomicUsize,
+    reused: AtomicUsize,
+}
+
+/// The segments handed out by an arena
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ArenaStats {
+    /// The segments allocated because no free segment was large enough
+    pub allocated: usize,
+    /// The segments taken from the free ones, each one an allocation saved
+    pub reused: usize,
+}
+
+impl Arena {
+    /// An arena keeping at most `keep` free segments by size class
+    pub fn new(keep: usize) -> Self {
+        Arena {
+            inner: Arc::new(ArenaInner {
+                classes: ARENA_CLASSES.iter().map(|_| Mutex::new(vec![])).collect(),
+                keep: keep,
+                allocated: AtomicUsize::new(0),
+                reused: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    /// Take a segment of at least `words` words, all zeroes
+    pub fn take(&self, words: usize) -> Vec<Word> {
+        match ARENA_CLASSES.iter().position(|&size| size >= words) {
+            Some(class) => {
+                let free = self.inner.classes[class].lock().expect("Arena take : poisoned class").pop();
+                if let Some(segment) = free {
+                    self.inner.reused.fetch_add(1, Ordering::Relaxed);
+                    return segment;
+                }
+                self.inner.allocated.fetch_add(1, Ordering::Relaxed);
+                Word::allocate_zeroed_vec(ARENA_CLASSES[class])
+            }
+            None => {
+                self.inner.allocated.fetch_add(1, Ordering::Relaxed);
+                Word::allocate_zeroed_vec(words)
+            }
+        }
+    }
+
+    /// Give back a segment taken with `take`
+    pub fn give(&self, mut segment: Vec<Word>) {
+        if let Some(class) = ARENA_CLASSES.iter().position(|&size| size == segment.len()) {
+            let mut free = self.inner.classes[class].lock().expect("Arena give : poisoned class");
+            if free.len() < self.inner.keep {
+                for word in segment.iter_mut() {
+                    word.raw_content = 0;
+                }
+                free.push(segment);
+            }
+        }
+    }
+
+    /// The segments handed out since the arena was created
+    pub fn stats(&self) -> ArenaStats {
+        ArenaStats {
+            allocated: self.inner.allocated.load(Ordering::Relaxed),
+            reused: self.inner.reused.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl fmt::Debug for Arena {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Arena({:?})", self.stats())
+    }
+}
+
+/// The allocator of a `MsgBuilder` : the segments come from an arena if it has one, else from the heap
+///
+/// Like the default allocator of Cap'n Proto, each new segment is as large as all the previous ones.
+pub struct MsgAllocator {
+    arena: Option<Arena>,
+    segments: Vec<Vec<Word>>,
+    next_size: u32,
+}
+
+impl MsgAllocator {
+    pub fn new(arena: Option<Arena>) -> Self {
+        MsgAllocator {
+            arena: arena,
+            segments: vec![],
+            next_size: message::SUGGESTED_FIRST_SEGMENT_WORDS,
+        }
+    }
+}
+
+unsafe impl message::Allocator for MsgAllocator {
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut Word, u32) {
+        let size = cmp::max(minimum_size, self.next_size) as usize;
+        let mut segment = match self.arena {
+            Some(ref arena) => arena.take(size),
+            None => Word::allocate_zeroed_vec(size),
+        };
+        // Moving the vector doesn't move its words
+        let ptr = segment.as_mut_ptr();
+        let len = segment.len() as u32;
+        self.segments.push(segment);
+        self.next_size = self.next_size.saturating_add(len);
+        (ptr, len)
+    }
+}
+
+impl Drop for MsgAllocator {
+    fn drop(&mut self) {
+        if let Some(ref arena) = self.arena {
+            for segment in self.segments.drain(..) {
+                arena.give(segment);
+            }
+        }
+    }
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Arena::new(64)
+    }
+}
@@ -0,0 +1,110 @@
+//! A registry of agents, by their namespaced name and their version
+//!
+//! The sort `vendor/name@^1.2` is the path of the newest version of `vendor/name` registered which matches `^1.2`
+//! (see `semver::VersionReq`), and `vendor/name@^1.2<PrimText>` is the same for a generic agent. The scheduler
+//! resolves these sorts with its registry (see `Scheduler::add_node`), then checks the version exported by the dylib
+//! itself, `agent_version` in `agent!`, so a stale dylib cannot run with the schemas of another version.
+//!
+//! ```rust,ignore
+//! sched.registry.register("acme/parse", "1.2.3", "/opt/agents/parse/lib/libagent.so")?;
+//! sched.registry.scan("/opt/registry")?;
+//! sched.add_node("parse", "acme/parse@^1.2")?;
+//! ```
+
+use crate::result::{Error, IoContext, Result};
+
+use semver::{Version, VersionReq};
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// The agents known by their name, `vendor/name`, and their version
+#[derive(Debug, Default)]
+pub struct Registry {
+    agents: HashMap<String, Vec<(Version, String)>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    /// Register the dylib of a version of an agent, replacing the one already registered for this version
+    pub fn register<P: Into<String>>(&mut self, name: &str, version: &str, path: P) -> Result<()> {
+        check_name(name)?;
+        let version = Version::parse(version)
+            .map_err(|e| Error::Misc(format!("bad version {} of {} : {}", version, name, e)))?;
+        let versions = self.agents.entry(name.into()).or_insert_with(Vec::new);
+        versions.retain(|&(ref v, _)| *v != version);
+        versions.push((version, path.into()));
+        Ok(())
+    }
+
+    /// Register all the agents of a directory, laid out as `dir/vendor/name/version/lib/libagent.so`
+    pub fn scan<P: AsRef<Path>>(&mut self, dir: P) -> Result<()> {
+        let dir = dir.as_ref();
+        for vendor in fs::read_dir(dir).path(dir)? {
+            let vendor = vendor?.path();
+            if !vendor.is_dir() { continue; }
+            for agent in fs::read_dir(&vendor).path(&vendor)? {
+                let agent = agent?.path();
+                if !agent.is_dir() { continue; }
+                for version in fs::read_dir(&agent).path(&agent)? {
+                    let version = version?.path();
+                    let lib = version.join("lib").join("libagent.so");
+                    if !lib.is_file() { continue; }
+                    let name = format!("{}/{}", file_name(&vendor), file_name(&agent));
+                    self.register(&name, &file_name(&version), lib.to_string_lossy().into_owned())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The path of the newest version matching a sort `vendor/name@requirement`, and the requirement
+    pub fn resolve(&self, sort: &str) -> Result<(String, VersionReq)> {
+        let (sort, edge) = match sort.rfind('<') {
+            Some(i) if sort.ends_with('>') => (&sort[..i], &sort[i..]),
+            _ => (sort, ""),
+        };
+        let at = sort.find('@').ok_or_else(|| Error::Misc(format!("bad sort {}, expected vendor/name@version", sort)))?;
+        let (name, req) = (&sort[..at], &sort[at + 1..]);
+        let req = VersionReq::parse(req)
+            .map_err(|e| Error::Misc(format!("bad version requirement {} of {} : {}", req, name, e)))?;
+        let versions = self.agents.get(name).ok_or_else(|| Error::AgentNotFound(name.into()))?;
+        versions.iter()
+            .filter(|&&(ref v, _)| req.matches(v))
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .map(|&(_, ref path)| (format!("{}{}", path, edge), req.clone()))
+            .ok_or_else(|| Error::BadVersion(name.into(), versions.iter().map(|v| v.0.to_string()).collect::<Vec<_>>().join(", "), req.to_string()))
+    }
+}
+
+/// A sort to resolve with a `Registry`, `vendor/name@requirement`, and not a path
+pub fn is_registry_sort(sort: &str) -> bool {
+    !sort.starts_with('/') && sort.contains('@')
+}
+
+/// Check an embedded version, the `agent_version` of a dylib, against the requirement of its sort
+pub fn check_version(sort: &str, version: &str, req: &VersionReq) -> Result<()> {
+    match Version::parse(version) {
+        Ok(ref v) if req.matches(v) => Ok(()),
+        Ok(_) => Err(Error::BadVersion(sort.into(), version.into(), req.to_string())),
+        Err(_) => Err(Error::BadVersion(sort.into(), "no version".into(), req.to_string())),
+    }
+}
+
+fn check_name(name: &str) -> Result<()> {
+    let mut parts = name.split('/');
+    let valid = |p: Option<&str>| p.map_or(false, |p| !p.is_empty() && !p.contains(|c| c == '@' || c == '<'));
+    if valid(parts.next()) && valid(parts.next()) && parts.next().is_none() {
+        Ok(())
+    } else {
+        Err(Error::Misc(format!("bad agent name {}, expected vendor/name", name)))
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default()
+}
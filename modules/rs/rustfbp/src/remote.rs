@@ -0,0 +1,290 @@
+//! Edges between two schedulers over TCP
+//!
+//! The sending side is created by `Scheduler::connect_remote`, the receiving side by `Scheduler::listen_remote`.
+//!
+//! A connection starts with a header frame `node\0port\0edge`, which the listener accepts (`1`) or rejects (`0`).
+//! Each message is then sent in a frame : its length as a big endian u32, followed by the bytes of the message.
+//! A frame is at most `MAX_FRAME` bytes : the listener closes a connection which announces a longer one.
+//! The listener acknowledges a message once it is delivered in the input port (`1`), so a full input port blocks the
+//! remote sender. If the connection is lost, the sender reconnects and sends the unacknowledged message again, waiting
+//! longer after each failure. A message the listener cannot read is rejected (`2`) : the sender doesn't send it again
+//! but gives it to `rejected`, usually the dead letters of its scheduler.
+
+use crate::ports::{MsgSender, MsgReceiver, SerdeEdge, CapnpMsg};
+use crate::result;
+use crate::result::Result;
+use crate::scheduler::compatible_schema;
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+extern crate log;
+extern crate serde;
+use self::serde::Serialize;
+use self::serde::de::DeserializeOwned;
+
+/// Receive the next message of a local output port, serialized
+pub type RemoteReader = Box<FnMut() -> Result<Vec<u8>> + Send>;
+/// Deserialize a message and send it in a local input port, `Error::BadRemoteMsg` if it cannot be read
+pub type RemoteWriter = Box<Fn(&[u8]) -> Result<()> + Send>;
+/// Take a message rejected by the listener, serialized
+pub type RemoteRejected = Box<Fn(Vec<u8>) + Send>;
+/// The input ports reachable by the listener, by (agent, port), with their edge
+pub type RemoteInputs = Arc<Mutex<HashMap<(String, String), (String, Arc<Mutex<RemoteWriter>>)>>>;
+
+/// An edge that can be sent to another scheduler
+pub trait RemoteEdge: Sized + Send + 'static {
+    fn to_bytes(self) -> Result<Vec<u8>>;
+    fn from_bytes(bytes: &[u8]) -> Result<Self>;
+}
+
+impl RemoteEdge for Vec<u8> {
+    fn to_bytes(self) -> Result<Vec<u8>> { Ok(self) }
+    fn from_bytes(bytes: &[u8]) -> Result<Self> { Ok(bytes.to_vec()) }
+}
+
+impl RemoteEdge for String {
+    fn to_bytes(self) -> Result<Vec<u8>> { Ok(self.into_bytes()) }
+    fn from_bytes(bytes: &[u8]) -> Result<Self> { Ok(String::from_utf8(bytes.to_vec())?) }
+}
+
+impl RemoteEdge for bool {
+    fn to_bytes(self) -> Result<Vec<u8>> { Ok(vec![self as u8]) }
+    fn from_bytes(bytes: &[u8]) -> Result<Self> { Ok(bytes.get(0).map(|b| *b != 0).unwrap_or(false)) }
+}
+
+impl<T: Serialize + DeserializeOwned + Send + 'static> RemoteEdge for SerdeEdge<T> {
+    fn to_bytes(self) -> Result<Vec<u8>> { SerdeEdge::to_bytes(&self) }
+    fn from_bytes(bytes: &[u8]) -> Result<Self> { SerdeEdge::from_bytes(bytes) }
+}
+
+impl RemoteEdge for CapnpMsg {
+    fn to_bytes(self) -> Result<Vec<u8>> { self.to_envelope() }
+    fn from_bytes(bytes: &[u8]) -> Result<Self> { CapnpMsg::from_envelope(bytes) }
+}
+
+/// Used by the agent macro to find the `RemoteEdge` implementation of a port.
+///
+/// `(&&Remote::<T>::new()).reader(r)` resolves to `RemoteCodec` if `T` is a `RemoteEdge`, to `NoRemoteCodec` otherwise.
+#[doc(hidden)]
+pub struct Remote<T>(PhantomData<T>);
+
+impl<T> Remote<T> {
+    pub fn new() -> Self {
+        Remote(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait RemoteCodec<T> {
+    fn reader(&self, recv: MsgReceiver<T>) -> Result<RemoteReader>;
+    fn writer(&self, sender: MsgSender<T>) -> Result<RemoteWriter>;
+}
+
+impl<'a, T: RemoteEdge> RemoteCodec<T> for &'a Remote<T> {
+    fn reader(&self, recv: MsgReceiver<T>) -> Result<RemoteReader> {
+        Ok(Box::new(move || { recv.recv()?.to_bytes() }))
+    }
+
+    fn writer(&self, sender: MsgSender<T>) -> Result<RemoteWriter> {
+        Ok(Box::new(move |bytes| {
+            let msg = T::from_bytes(bytes).map_err(|e| result::Error::BadRemoteMsg(e.to_string()))?;
+            sender.send(msg)
+        }))
+    }
+}
+
+#[doc(hidden)]
+pub trait NoRemoteCodec<T> {
+    fn reader(&self, recv: MsgReceiver<T>) -> Result<RemoteReader>;
+    fn writer(&self, sender: MsgSender<T>) -> Result<RemoteWriter>;
+}
+
+impl<T> NoRemoteCodec<T> for Remote<T> {
+    fn reader(&self, _recv: MsgReceiver<T>) -> Result<RemoteReader> {
+        Err(result::Error::NotRemote)
+    }
+
+    fn writer(&self, _sender: MsgSender<T>) -> Result<RemoteWriter> {
+        Err(result::Error::NotRemote)
+    }
+}
+
+/// The longest frame of a remote edge, in bytes
+pub const MAX_FRAME: usize = 64 << 20;
+
+// The answers of the listener, to the header and to each message
+const ACK: u8 = 1;
+const NACK: u8 = 2;
+
+fn write_frame(stream: &mut TcpStream, bytes: &[u8]) -> Result<()> {
+    let len = bytes.len() as u32;
+    stream.write_all(&[(len >> 24) as u8, (len >> 16) as u8, (len >> 8) as u8, len as u8])?;
+    stream.write_all(bytes)?;
+    Ok(())
+}
+
+fn read_frame(stream: &mut TcpStream) -> Result<Vec<u8>> {
+    let mut len = [0u8; 4];
+    stream.read_exact(&mut len)?;
+    let len = ((len[0] as usize) << 24) | ((len[1] as usize) << 16) | ((len[2] as usize) << 8) | len[3] as usize;
+    // The length comes from the peer, it is checked before anything is allocated
+    if len > MAX_FRAME {
+        return Err(result::Error::Misc(format!("remote edge : a frame of {} bytes, over the {} bytes limit", len, MAX_FRAME)));
+    }
+    let mut bytes = vec![0u8; len];
+    stream.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_ack(stream: &mut TcpStream) -> Result<u8> {
+    let mut ack = [0u8; 1];
+    stream.read_exact(&mut ack)?;
+    Ok(ack[0])
+}
+
+/// Send the messages of `reader` to the input port `node.port` of the remote scheduler at `addr`
+///
+/// The messages the listener cannot read are given to `rejected`.
+pub fn connect(addr: String, node: String, port: String, schema: String, mut reader: RemoteReader, rejected: RemoteRejected) {
+    let header = format!("{}\0{}\0{}", node, port, schema).into_bytes();
+    thread::spawn(move || {
+        let mut stream: Option<TcpStream> = None;
+        // Reset by an answer of the listener only : one which accepts the connections and drops them is not hammered
+        let mut backoff = 100;
+        loop {
+            let bytes = match reader() {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    log::error!("remote edge to {} {}() : {}", port, node, e);
+                    return;
+                }
+            };
+            if bytes.len() > MAX_FRAME {
+                log::error!("remote edge to {} {}() : a message of {} bytes, over the {} bytes limit, is dropped",
+                            port, node, bytes.len(), MAX_FRAME);
+                continue;
+            }
+            // Retry until the listener answers
+            loop {
+                if stream.is_none() {
+                    match open(&addr, &header) {
+                        Ok(Some(s)) => { stream = Some(s); },
+                        Ok(None) => {
+                            log::error!("remote edge to {} {}() : rejected by {}", port, node, addr);
+                            return;
+                        },
+                        Err(_) => {
+                            thread::sleep(Duration::from_millis(backoff));
+                            backoff = (backoff * 2).min(5000);
+                            continue;
+                        },
+                    }
+                }
+                let answer = {
+                    let s = stream.as_mut().expect("remote edge : no stream");
+                    write_frame(s, &bytes).and_then(|_| read_ack(s))
+                };
+                match answer {
+                    Ok(ACK) => {
+                        backoff = 100;
+                        break;
+                    },
+                    Ok(_) => {
+                        log::warn!("remote edge to {} {}() : a message is rejected by {}", port, node, addr);
+                        backoff = 100;
+                        rejected(bytes);
+                        break;
+                    },
+                    Err(_) => {
+                        stream = None;
+                        thread::sleep(Duration::from_millis(backoff));
+                        backoff = (backoff * 2).min(5000);
+                    },
+                }
+            }
+        }
+    });
+}
+
+fn open(addr: &str, header: &[u8]) -> Result<Option<TcpStream>> {
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_nodelay(true)?;
+    write_frame(&mut stream, header)?;
+    if read_ack(&mut stream)? == ACK {
+        Ok(Some(stream))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Accept the remote edges on `addr`, and deliver their messages in `inputs`
+///
+/// Returns the address listened on, whose port is chosen by the system if `addr` has the port 0.
+pub fn listen(addr: &str, inputs: RemoteInputs) -> Result<SocketAddr> {
+    let listener = TcpListener::bind(addr)?;
+    let local = listener.local_addr()?;
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            if let Ok(stream) = stream {
+                let inputs = inputs.clone();
+                thread::spawn(move || {
+                    if let Err(e) = serve(stream, inputs) {
+                        log::warn!("remote edge : {}", e);
+                    }
+                });
+            }
+        }
+    });
+    Ok(local)
+}
+
+fn serve(mut stream: TcpStream, inputs: RemoteInputs) -> Result<()> {
+    let header = String::from_utf8(read_frame(&mut stream)?)?;
+    let header: Vec<&str> = header.split('\0').collect();
+    if header.len() != 3 {
+        stream.write_all(&[0])?;
+        return Err(result::Error::BadMessageInfo);
+    }
+    let (node, port, schema) = (header[0].to_string(), header[1].to_string(), header[2]);
+
+    let writer = {
+        let inputs = inputs.lock().expect("remote edge : poisoned inputs");
+        match inputs.get(&(node.clone(), port.clone())) {
+            Some(&(ref in_schema, ref writer)) => {
+                if !compatible_schema(schema, in_schema) {
+                    stream.write_all(&[0])?;
+                    return Err(result::Error::BadSchema("remote".into(), "".into(), schema.into(), node, port, in_schema.clone()));
+                }
+                writer.clone()
+            }
+            None => {
+                stream.write_all(&[0])?;
+                return Err(result::Error::PortNotFound(node, port));
+            }
+        }
+    };
+    stream.write_all(&[ACK])?;
+
+    loop {
+        let bytes = read_frame(&mut stream)?;
+        let delivered = {
+            let writer = writer.lock().expect("remote edge : poisoned writer");
+            (*writer)(&bytes)
+        };
+        match delivered {
+            Ok(()) => { stream.write_all(&[ACK])?; },
+            // Sent again, it would be rejected again : the sender drops it
+            Err(result::Error::BadRemoteMsg(e)) => {
+                log::warn!("remote edge to {} {}() : {}", port, node, e);
+                stream.write_all(&[NACK])?;
+            },
+            Err(e) => { return Err(e); },
+        }
+    }
+}
@@ -1,29 +1,61 @@
 extern crate capnp;
+extern crate crossbeam_channel;
+extern crate serde_json;
+#[cfg(feature = "wasm")]
+extern crate wasmtime;
 
 use std::fmt;
 
 use std::error;
 use std::result;
 use std::io;
+use std::path::Path;
 use std::string;
 use std::sync::mpsc;
 
-use scheduler::CompMsg;
+use crate::scheduler::CompMsg;
 
 pub type Result<T> = result::Result<T, Error>;
 
 #[derive(Debug)]
 pub enum Error {
     BadSchema(String, String, String, String, String, String),
+    BadVersion(String, String, String),
+    BadAbi(String, String, String),
+    /// A message of another schema, or of another version of the schema : expected, found
+    SchemaMismatch(String, String),
+    /// A dylib which lacks one of the functions of an agent
+    InvalidComponent { path: String, missing_symbol: String },
+    /// A sender or a receiver of another edge than the one of the port
+    TypeMismatch { port: String, expected: String, found: String },
     Capnp(capnp::Error),
     CapnpNIS(capnp::NotInSchema),
+    SerdeJson(serde_json::Error),
+    /// An io error without context, see `File`
     IO(io::Error),
+    /// An io error on a file, see `IoContext`
+    File { path: String, source: io::Error },
+    /// The dylib of an agent cannot be loaded
+    Dlopen { path: String, source: io::Error },
     FromUtf8(string::FromUtf8Error),
     Mpsc(mpsc::RecvError),
     MpscTryRecv(mpsc::TryRecvError),
+    ChannelRecv(crossbeam_channel::RecvError),
+    ChannelTryRecv(crossbeam_channel::TryRecvError),
     Misc(String),
     MpscSend,
+    /// An agent which is not in the cache of the scheduler, by its sort
     AgentNotFound(String),
+    /// A node which is not in the network, by its name
+    NodeNotFound(String),
+    /// A network which is not in the `NetworkManager`, by its name
+    NetworkNotFound(String),
+    /// A port which is not exported by the network, by its name
+    ExportNotFound(String),
+    /// The agent behind the output port is gone, or the port is closed
+    Disconnected { port: String },
+    /// An edge of a graph cannot be connected
+    Edge { edge: String, source: Box<Error> },
     OutputPortNotConnected(String, String),
     OutputNotConnected,
     ArrayOutputPortNotConnected(String, String, String),
@@ -32,29 +64,66 @@ pub enum Error {
     ElementNotFound(String, String, String),
     CannotRemove(String),
     BadMessageInfo,
+    NotRemote,
+    /// A message of a remote edge which cannot be read, the listener rejects it
+    BadRemoteMsg(String),
+    NoStorage,
+    Wasm(String),
+    Interrupted,
+    Cancelled,
+    PortFull,
+    /// The port is closed and empty, see `MsgSender::close`
+    Closed,
+    SendTimeout,
+    RecvTimeout,
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Error::BadSchema(ref oc, ref op, ref os, ref ic, ref ip, ref is) => write!(f, "Cap'n Proto Schema mismatch between {}() {} -> {} {}(), found {} -> {}", oc, op, ip, ic, os, is),
+            Error::BadVersion(ref s, ref v, ref r) => write!(f, "Registry error : found {} in version {}, {} is required", s, v, r),
+            Error::BadAbi(ref p, ref found, ref abi) => write!(f, "Loader error : {} is built for the ABI {}, the scheduler is built for {}", p, found, abi),
+            Error::SchemaMismatch(ref expected, ref found) => write!(f, "Schema error : expected a message {}, found {}", expected, found),
+            Error::InvalidComponent { ref path, ref missing_symbol } => write!(f, "Loader error : {} is not a valid agent, the symbol {} is missing", path, missing_symbol),
+            Error::TypeMismatch { ref port, ref expected, ref found } => write!(f, "Ports error : the port {} expects {}, found {}", port, expected, found),
             Error::Capnp(ref err) => write!(f, "Cap'n Proto error: {}", err),
             Error::CapnpNIS(ref err) => write!(f, "Cap'n Proto error: {}", err),
+            Error::SerdeJson(ref err) => write!(f, "Serde error: {}", err),
             Error::IO(ref err) => write!(f, "IO error : {}", err),
+            Error::File { ref path, ref source } => write!(f, "IO error : {} : {}", path, source),
+            Error::Dlopen { ref path, ref source } => write!(f, "Loader error : cannot load {} : {}", path, source),
             Error::FromUtf8(ref err) => write!(f, "From Utf8 error : {}", err),
             Error::Mpsc(ref err) => write!(f, "Mpsc error : {}", err),
             Error::MpscTryRecv(ref err) => write!(f, "Mpsc error : {}", err),
+            Error::ChannelRecv(ref err) => write!(f, "Channel error : {}", err),
+            Error::ChannelTryRecv(ref err) => write!(f, "Channel error : {}", err),
             Error::Misc(ref err) => write!(f, "Misc error : {}", err),
             Error::MpscSend => write!(f, "Mpsc error : cannot send"),
             Error::OutputPortNotConnected(ref c, ref p) => write!(f, "OutputSender : Port {} of agent {} is not connected", p, c),
             Error::OutputNotConnected => write!(f, "OutputSender : Port not connected"),
             Error::ArrayOutputPortNotConnected(ref c, ref p, ref s) => write!(f, "OutputSender : Element {} Port {} of agent {} is not connected", s, p, c),
             Error::AgentNotFound(ref c) => write!(f, "Scheduler error : agent {} is not found", c),
+            Error::NodeNotFound(ref n) => write!(f, "Scheduler error : node {} is not in the network", n),
+            Error::NetworkNotFound(ref n) => write!(f, "Manager error : network {} is not found", n),
+            Error::ExportNotFound(ref n) => write!(f, "Scheduler error : the network exports no port {}", n),
+            Error::Disconnected { ref port } => write!(f, "OutputSender : the agent behind the port {} is gone, or the port is closed", port),
+            Error::Edge { ref edge, ref source } => write!(f, "Scheduler error : cannot connect {} : {}", edge, source),
             Error::PortNotFound(ref c, ref p) => write!(f, "agent error : Port {} of agent {} is not found", p, c),
             Error::PortDontExist(ref p) => write!(f, "agent error : Port {} doesn't exist", p),
             Error::ElementNotFound(ref c, ref p, ref s) => write!(f, "agent error : Element {} on port {} of agent {} is not found", s, p, c),
             Error::CannotRemove(ref c) => write!(f, "Scheduler error : Cannot remove agent {}", c),
             Error::BadMessageInfo => write!(f, "Ports error : Bad message information"),
+            Error::NotRemote => write!(f, "Ports error : the edge cannot be sent to another scheduler"),
+            Error::BadRemoteMsg(ref err) => write!(f, "Remote edge error : cannot read the message : {}", err),
+            Error::NoStorage => write!(f, "Scheduler error : no storage directory for the durable edges"),
+            Error::Wasm(ref err) => write!(f, "Wasm error : {}", err),
+            Error::Interrupted => write!(f, "Ports error : the receive was interrupted by a new option"),
+            Error::Cancelled => write!(f, "Ports error : the agent was cancelled over its run time"),
+            Error::PortFull => write!(f, "Ports error : the port is full"),
+            Error::Closed => write!(f, "Ports error : the port is closed, no more messages will come"),
+            Error::SendTimeout => write!(f, "Ports error : the port is still full at the end of the timeout"),
+            Error::RecvTimeout => write!(f, "Ports error : no message came before the end of the timeout"),
         }
     }
 }
@@ -63,39 +132,103 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::BadSchema(..) => "Bad schema",
+            Error::BadVersion(..) => "Bad version",
+            Error::BadAbi(..) => "Bad ABI",
+            Error::SchemaMismatch(..) => "Schema mismatch",
+            Error::InvalidComponent { .. } => "Invalid component",
+            Error::TypeMismatch { .. } => "Type mismatch",
             Error::Capnp(ref err) => err.description(),
             Error::CapnpNIS(ref err) => err.description(),
+            Error::SerdeJson(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::File { .. } => "IO error on a file",
+            Error::Dlopen { .. } => "Cannot load the agent",
             Error::FromUtf8(ref err) => err.description(),
             Error::Mpsc(ref err) => err.description(),
             Error::MpscTryRecv(ref err) => err.description(),
+            Error::ChannelRecv(ref err) => err.description(),
+            Error::ChannelTryRecv(ref err) => err.description(),
             Error::Misc(ref err) => &err,
             Error::MpscSend => "Mpsc : cannot send",
             Error::OutputPortNotConnected(..) => "Output port not connected",
             Error::OutputNotConnected => "Output port not connected",
             Error::ArrayOutputPortNotConnected(..) => "Array Output port not connect",
             Error::AgentNotFound(..) => "Agent not found",
+            Error::NodeNotFound(..) => "Node not found",
+            Error::NetworkNotFound(..) => "Network not found",
+            Error::ExportNotFound(..) => "Exported port not found",
+            Error::Disconnected { .. } => "Output port disconnected",
+            Error::Edge { .. } => "Cannot connect the edge",
             Error::PortNotFound(..) => "Port not found",
             Error::PortDontExist(..) => "Port not found",
             Error::ElementNotFound(..) => "Element not found",
             Error::CannotRemove(..) => "Cannot remove agent",
             Error::BadMessageInfo => "Ports error : cannot receive the message, wrong bit information",
+            Error::NotRemote => "Edge not remote",
+            Error::BadRemoteMsg(..) => "Remote message unreadable",
+            Error::NoStorage => "No storage directory",
+            Error::Wasm(ref err) => &err,
+            Error::Interrupted => "Receive interrupted",
+            Error::Cancelled => "Agent cancelled",
+            Error::PortFull => "Port full",
+            Error::Closed => "Port closed",
+            Error::SendTimeout => "Send timeout",
+            Error::RecvTimeout => "Receive timeout",
         }
     }
 
-    fn cause(&self) -> Option<&error::Error> {
+    fn source(&self) -> Option<&(error::Error + 'static)> {
         match *self {
             Error::Capnp(ref err) => Some(err),
             Error::CapnpNIS(ref err) => Some(err),
+            Error::SerdeJson(ref err) => Some(err),
             Error::IO(ref err) => Some(err),
+            Error::File { ref source, .. } => Some(source),
+            Error::Dlopen { ref source, .. } => Some(source),
+            Error::Edge { ref source, .. } => Some(&**source),
             Error::FromUtf8(ref err) => Some(err),
             Error::Mpsc(ref err) => Some(err),
             Error::MpscTryRecv(ref err) => Some(err),
+            Error::ChannelRecv(ref err) => Some(err),
+            Error::ChannelTryRecv(ref err) => Some(err),
             _ => None
         }
     }
 }
 
+impl Error {
+    /// The error and all its sources, `: ` separated, for the users
+    pub fn chain(&self) -> String {
+        let mut chain = self.to_string();
+        let mut source = error::Error::source(self);
+        while let Some(err) = source {
+            let text = err.to_string();
+            // Most variants already display their source
+            if !chain.ends_with(&text) {
+                chain.push_str(" : ");
+                chain.push_str(&text);
+            }
+            source = err.source();
+        }
+        chain
+    }
+}
+
+/// Add the path of the file to an io error
+///
+/// ```rust,ignore
+/// let file = File::open(&path).path(&path)?;
+/// ```
+pub trait IoContext<T> {
+    fn path<P: AsRef<Path>>(self, path: P) -> Result<T>;
+}
+
+impl<T> IoContext<T> for io::Result<T> {
+    fn path<P: AsRef<Path>>(self, path: P) -> Result<T> {
+        self.map_err(|e| Error::File { path: path.as_ref().display().to_string(), source: e })
+    }
+}
+
 impl From<capnp::Error> for Error {
     fn from(err: capnp::Error) -> Error {
         Error::Capnp(err)
@@ -108,12 +241,25 @@ impl From<capnp::NotInSchema> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::SerdeJson(err)
+    }
+}
+
 impl From<String> for Error {
     fn from(err: String) -> Error {
         Error::Misc(err)
     }
 }
 
+#[cfg(feature = "wasm")]
+impl From<wasmtime::Error> for Error {
+    fn from(err: wasmtime::Error) -> Error {
+        Error::Wasm(err.to_string())
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
         Error::IO(err)
@@ -144,8 +290,20 @@ impl From<mpsc::SendError<CompMsg>> for Error {
     }
 }
 
-impl<T> From<mpsc::SendError<(Option<String>, T)>> for Error {
-    fn from(_: mpsc::SendError<(Option<String>, T)>) -> Error {
+impl From<crossbeam_channel::RecvError> for Error {
+    fn from(err: crossbeam_channel::RecvError) -> Error {
+        Error::ChannelRecv(err)
+    }
+}
+
+impl From<crossbeam_channel::TryRecvError> for Error {
+    fn from(err: crossbeam_channel::TryRecvError) -> Error {
+        Error::ChannelTryRecv(err)
+    }
+}
+
+impl<T> From<crossbeam_channel::SendError<(Option<String>, T)>> for Error {
+    fn from(_: crossbeam_channel::SendError<(Option<String>, T)>) -> Error {
         Error::MpscSend
     }
 }
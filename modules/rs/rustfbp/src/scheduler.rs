@@ -9,32 +9,65 @@
 
 
 extern crate libloading;
-extern crate threadpool;
-
-use self::threadpool::ThreadPool;
-
-use result;
-use result::Result;
-
-use ports::{MsgSender, MsgReceiver};
-use agent::Agent;
+extern crate rayon;
+extern crate tokio;
+extern crate serde_json;
+extern crate log;
+
+use self::rayon::{ThreadPool, ThreadPoolBuilder};
+use self::tokio::runtime::{Builder, Runtime};
+
+use crate::result;
+use crate::result::{IoContext, Result};
+
+use crate::ports::{Arena, Cancel, Interrupt, MsgSender, MsgReceiver, Priority};
+use crate::affinity;
+use crate::affinity::Placement;
+use crate::agent::{Agent, AgentAbi, AgentExports, Ports};
+use crate::remote;
+use crate::remote::{RemoteReader, RemoteRejected, RemoteWriter, RemoteInputs};
+use crate::durable;
+use crate::isolate::Isolated;
+use crate::deadletter::{DeadLetter, Reason, SharedDeadLetters};
+use crate::trace::{SharedTrace, SharedTraces, Trace, TraceFailure, TracedMsg};
+use crate::limits::{LimitAction, LimitState, Limits, Overflow, Rate, Throttle, Violation};
+use crate::log::{Context, LevelFilter, Sink};
+use crate::ports::PortWatch;
+use crate::registry;
+use crate::registry::Registry;
+use crate::testing;
+#[cfg(feature = "wasm")]
+use crate::wasm;
+use crate::watchdog;
+use crate::watchdog::{Diagnostic, Link, SharedNetwork};
 
 use std::borrow::Cow;
 use std::any::Any;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::Ordering;
 use std::sync::mpsc::{Sender, Receiver};
 use std::sync::mpsc::channel;
 
 use std::thread;
 use std::thread::JoinHandle;
 
+use std::cmp;
+use std::fmt;
+use std::fs;
+use std::future::Future;
 use std::mem;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context as TaskContext, Poll};
+use std::time::{Duration, SystemTime};
 
 
 /// A boxed comp is a agent that can be send between thread
 pub type BoxedComp = Box<Agent + Send>;
-// TODO : manage "can_run": allow a user to pause a agent
 
 /// All the messages that can be send between the "exterior scheduler" and the "interior scheduler".
 pub enum CompMsg {
@@ -54,6 +87,10 @@ pub enum CompMsg {
     Disconnect(usize, String),
     /// Disconnect an array output port
     DisconnectArray(usize, String, String),
+    /// Copy a sample of the messages of an output port to a sink, or stop
+    Tap(usize, String, Option<(Box<Any + Send>, f64)>),
+    /// Keep the last messages of an output port in a trace, or stop
+    Trace(usize, String, Option<SharedTrace>),
     /// Add an element in an array input port
     AddInputArrayElement(usize, String, String, Box<Any + Send>),
     /// Remove an element in an array input port
@@ -68,13 +105,60 @@ pub enum CompMsg {
     Inc(usize),
     /// The agent read an Msg
     Dec(usize),
+    /// A batch port of the agent has messages waiting, start its flush timeout
+    ArmFlush(usize),
+    /// The flush timeout of the agent is over, run it with an incomplete batch
+    Flush(usize),
+    /// The agent received a high priority Msg
+    Urgent(usize),
     /// Remove a agent
     Remove(usize, Sender<SyncMsg>),
+    /// Stop running an agent, its messages wait in its ports
+    Pause(usize),
+    /// Run an agent again
+    Resume(usize),
+    /// Pause all the agents
+    PauseAll,
+    /// Resume all the agents
+    ResumeAll,
+    /// Hold the first agent until the second one is ready
+    After(usize, usize),
+    /// The agent is ready, the agents started after it can run
+    Ready(usize),
+    /// Save the state of all the agents, by name
+    Checkpoint(Sender<Result<HashMap<String, Vec<u8>>>>),
+    /// Set back the state of an agent
+    Restore(usize, Vec<u8>),
+    /// A message of the port of the agent could not be delivered
+    DeadLetter(usize, String, Reason, Box<Any + Send>),
+    /// Set the limits of an agent
+    SetLimits(usize, Arc<LimitState>),
+    /// The messages waiting in the input ports of the agent take `usize` bytes, over its limit
+    QueueLimit(usize, usize),
+    /// The time limit of the execution of the agent is over, if it is still the `u64` execution
+    RunTimeout(usize, u64),
+    /// Send the violations of the limits of the agents
+    Supervise(Sender<Violation>),
+    /// The agent received a new live option, interrupt its blocking receives
+    OptionChanged(usize),
+    /// Reply once the network is idle
+    WaitIdle(Sender<()>),
+    /// Run the agent on its own thread
+    SetPlacement(usize, Placement),
+    /// Send what the agent is doing, and the start of its last execution
+    Status(usize, Sender<(NodeState, Option<SystemTime>)>),
 }
 
+/// What the scheduler does with an agent at the end of its execution
 pub enum Signal {
+    /// The agent is done : it runs again when a message comes, but the scheduler can halt without it
     End,
+    /// Run the agent again when a message comes on any of its input ports, the scheduler waits for it
     Continue,
+    /// Run the agent again, after the other ready agents, even without message
+    Yield,
+    /// Run the agent again when a message waits on the input port, the messages of its other ports wait
+    WaitFor(String),
 }
 
 /// This structure keep all the information for the "exterior scheduler".
@@ -90,24 +174,162 @@ pub struct Comp {
     pub sort: String,
     /// True if a agent had no input port
     pub start: bool,
+    /// The instances, if the agent is a pool
+    pub pool: Option<Pool>,
+}
+
+/// What an agent is doing, see `Scheduler::node_status`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NodeState {
+    /// Waiting for a message, or not started
+    Idle,
+    /// Executing
+    Running,
+    /// Executing, but waiting for a message in a receive
+    Blocked,
+    /// Paused with `Scheduler::pause`, its messages wait
+    Paused,
+    /// Held with `Scheduler::start_after` until other agents are ready, its messages wait
+    Waiting,
+    /// Its last execution returned `Signal::End`, it runs again only if a message comes
+    Ended,
+}
+
+/// The status of an agent, see `Scheduler::node_status`
+#[derive(Clone, Debug)]
+pub struct NodeStatus {
+    pub name: String,
+    pub sort: String,
+    pub state: NodeState,
+    /// The start of its last execution, None if it never ran
+    pub last_run: Option<SystemTime>,
+    /// The connected input ports, sorted
+    pub inputs: Vec<PortStatus>,
+    /// The connected output ports, sorted
+    pub outputs: Vec<PortStatus>,
+}
+
+/// A port of an agent, with the other end of its edges as (agent, port)
+#[derive(Clone, Debug)]
+pub struct PortStatus {
+    pub port: String,
+    /// The messages waiting in the port, always 0 for an output port
+    pub queued: usize,
+    pub edges: Vec<(String, String)>,
+}
+
+/// The instances of an agent added with `add_pool`
+pub struct Pool {
+    pub instances: Vec<String>,
+    pub ordered: bool,
+}
+
+/// How the messages of an input port of a pool are given to its instances
+pub enum PoolInput {
+    /// To the instance with the fewest waiting messages
+    LeastLoaded,
+    /// To each instance in turn
+    RoundRobin,
+    /// To all the instances
+    Broadcast,
+}
+
+/// Give the messages of `recv` to one of the instances of a pool, until `recv` is dropped
+///
+/// Used by the `pool_input` of the agent macro, `Broadcast` is handled by `broadcast`.
+#[doc(hidden)]
+pub fn balance<T: Send + 'static>(recv: MsgReceiver<T>, senders: Vec<MsgSender<T>>, mode: PoolInput) {
+    thread::spawn(move || {
+        let mut next = 0;
+        while let Ok((action, msg)) = recv.recv_with_action() {
+            let i = match mode {
+                PoolInput::LeastLoaded => {
+                    (0..senders.len()).min_by_key(|i| senders[*i].queue_len()).unwrap_or(0)
+                },
+                _ => {
+                    let i = next;
+                    next = (next + 1) % senders.len();
+                    i
+                },
+            };
+            let _ = match action {
+                Some(action) => senders[i].send_with_action(msg, action),
+                None => senders[i].send(msg),
+            };
+        }
+    });
+}
+
+/// Give a copy of the messages of `recv` to all the instances of a pool, until `recv` is dropped
+#[doc(hidden)]
+pub fn broadcast<T: Clone + Send + 'static>(recv: MsgReceiver<T>, senders: Vec<MsgSender<T>>) {
+    thread::spawn(move || {
+        while let Ok(msg) = recv.recv() {
+            for sender in &senders {
+                let _ = sender.send(msg.clone());
+            }
+        }
+    });
+}
+
+/// Forward the messages of the instances of an ordered pool, taking one message of each instance in turn
+#[doc(hidden)]
+pub fn merge_ordered<T: Send + 'static>(recvs: Vec<MsgReceiver<T>>, target: MsgSender<T>) {
+    thread::spawn(move || {
+        for i in (0..recvs.len()).cycle() {
+            match recvs[i].recv_with_action() {
+                Ok((Some(action), msg)) => { let _ = target.send_with_action(msg, action); },
+                Ok((None, msg)) => { let _ = target.send(msg); },
+                Err(_) => { break; },
+            }
+        }
+    });
+}
+
+/// A port of an agent, exported by the network under a stable name, see `Scheduler::export_input`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportedPort {
+    pub comp: String,
+    pub port: String,
+    /// The element, for an array port
+    pub element: Option<String>,
 }
 
 /// the exterior scheduler. The end user use the methods of this structure.
 pub struct Scheduler {
     /// Keep the dylib of the loaded agents
     pub cache: AgentCache,
+    /// Resolve the sorts `vendor/name@version`
+    pub registry: Registry,
     /// Keep the agent
     pub agents: HashMap<String, Comp>,
     /// A sender to send message to the scheduler
     pub sender: Sender<CompMsg>,
     /// Received the error from the "interior scheduler"
     pub error_receiver: Receiver<result::Error>,
+    /// The input ports that can receive messages from a remote scheduler
+    remote_inputs: RemoteInputs,
+    /// The nodes running in their own process, see `add_isolated_node`
+    isolated: HashMap<String, Isolated>,
+    /// Where the isolated nodes send their messages, once one is added
+    isolated_addr: Option<String>,
+    /// The ports and the edges, for the watchdog
+    network: SharedNetwork,
+    /// The directory of the logs of the durable edges
+    storage: Option<PathBuf>,
+    /// The messages that could not be delivered
+    dead_letters: SharedDeadLetters,
+    /// The traced output ports, see `trace`
+    traces: SharedTraces,
+    /// The ports of the network, by their name : `INPORT=open.input:IN` in a graph
+    inports: HashMap<String, ExportedPort>,
+    outports: HashMap<String, ExportedPort>,
     id: usize,
     th: JoinHandle<()>,
 }
 
 impl Scheduler {
-    /// Create a new scheduler
+    /// Create a new scheduler, with one worker thread by CPU
     ///
     /// # Example
     ///
@@ -115,9 +337,28 @@ impl Scheduler {
     /// let sched = Scheduler::new();
     /// ```
     pub fn new() -> Self {
+        Scheduler::with_workers(0)
+    }
+
+    /// Create a new scheduler, which runs the agents on `workers` threads (0 for one by CPU)
+    ///
+    /// The ready agents wait in one queue, the high priority ones first, and each free worker takes the next one. A
+    /// `blocking` agent runs on its own thread instead, started at its first run, so it cannot hold a worker. The
+    /// async agents run on an async runtime, owned by the scheduler.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let sched = Scheduler::with_workers(4);
+    /// ```
+    pub fn with_workers(workers: usize) -> Self {
         let (s, r) = channel();
         let (error_s, error_r) = channel();
-        let mut sched_s = SchedState::new(s.clone());
+        crate::log::init();
+        let dead_letters: SharedDeadLetters = Arc::new(Mutex::new(Default::default()));
+        let network: SharedNetwork = Arc::new(Mutex::new(Default::default()));
+        let traces: SharedTraces = Arc::new(Mutex::new(HashMap::new()));
+        let mut sched_s = SchedState::new(s.clone(), workers, dead_letters.clone(), network.clone(), traces.clone());
         let th = thread::spawn(move || {
             loop {
                 let msg = r.recv().expect("no message received");
@@ -151,21 +392,74 @@ impl Scheduler {
                     CompMsg::DisconnectArray(name, port, element) => {
                         sched_s.edit_agent(name, EditCmp::DisconnectArray(port, element))
                     },
+                    CompMsg::Tap(name, port, tap) => {
+                        sched_s.edit_agent(name, EditCmp::Tap(port, tap))
+                    },
+                    CompMsg::Trace(name, port, trace) => {
+                        sched_s.edit_agent(name, EditCmp::Trace(port, trace))
+                    },
                     CompMsg::Inc(dest) => { sched_s.inc(dest) },
                     CompMsg::Dec(dest) => { sched_s.dec(dest) },
+                    CompMsg::ArmFlush(dest) => { sched_s.arm_flush(dest) },
+                    CompMsg::Flush(dest) => { sched_s.flush(dest) },
+                    CompMsg::Urgent(dest) => { sched_s.urgent(dest) },
                     CompMsg::Remove(name, sync_sender) => {
                         sched_s.remove(name, sync_sender)
                     }
+                    CompMsg::Pause(id) => { sched_s.pause(id) },
+                    CompMsg::Resume(id) => { sched_s.resume(id) },
+                    CompMsg::PauseAll => { sched_s.pause_all() },
+                    CompMsg::ResumeAll => { sched_s.resume_all() },
+                    CompMsg::After(id, after) => { sched_s.after(id, after) },
+                    CompMsg::Ready(id) => { sched_s.ready(id) },
+                    CompMsg::Checkpoint(reply) => { sched_s.checkpoint(reply) },
+                    CompMsg::Restore(id, bytes) => {
+                        sched_s.edit_agent(id, EditCmp::Restore(bytes))
+                    },
+                    CompMsg::DeadLetter(id, port, reason, msg) => {
+                        sched_s.dead_letter(id, port, reason, msg)
+                    },
+                    CompMsg::SetLimits(id, limit) => { sched_s.set_limits(id, limit) },
+                    CompMsg::SetPlacement(id, placement) => { sched_s.set_placement(id, placement) },
+                    CompMsg::QueueLimit(id, bytes) => { sched_s.queue_limit(id, bytes) },
+                    CompMsg::RunTimeout(id, run) => { sched_s.run_timeout(id, run) },
+                    CompMsg::Supervise(supervisor) => {
+                        sched_s.supervisor = Some(supervisor);
+                        Ok(())
+                    },
+                    CompMsg::OptionChanged(id) => { sched_s.option_changed(id) },
+                    CompMsg::WaitIdle(reply) => {
+                        sched_s.idle_waiters.push(reply);
+                        Ok(())
+                    },
+                    CompMsg::Status(id, reply) => {
+                        if let Some(status) = sched_s.status(id) {
+                            // The caller may be gone
+                            let _ = reply.send(status);
+                        }
+                        Ok(())
+                    },
                 };
                 res.map_err(|e| { error_s.send(e).expect("cannot send the error"); }).ok();
+                sched_s.check_idle();
             }
         });
 
         Scheduler {
             cache: AgentCache::new(),
+            registry: Registry::new(),
             agents: HashMap::new(),
             sender: s,
             error_receiver: error_r,
+            remote_inputs: Arc::new(Mutex::new(HashMap::new())),
+            isolated: HashMap::new(),
+            isolated_addr: None,
+            network: network,
+            storage: None,
+            dead_letters: dead_letters,
+            traces: traces,
+            inports: HashMap::new(),
+            outports: HashMap::new(),
             th: th,
             id: 0,
         }
@@ -173,7 +467,12 @@ impl Scheduler {
 
     /// Add a agent to the scheduler
     ///
-    /// The sort is a complete path to the dylib
+    /// The sort is a complete path to the dylib, followed by the edge for a generic agent : `delay.so<PrimText>`.
+    /// It can also be an agent of the `registry`, `acme/delay@^1.2`, which fails if the version exported by its
+    /// dylib does not match.
+    ///
+    /// A dylib which cannot be loaded, or which lacks a function of the agents (`Error::InvalidComponent`), is an
+    /// error, and the scheduler is left as it was.
     ///
     /// # Example
     ///
@@ -185,10 +484,20 @@ impl Scheduler {
         B: Into<Cow<'a, str>>
     {
         let name = name.into().into_owned();
-        let sort = sort.into().into_owned();
-        let (comp, senders) = self.cache.create_comp(&sort, self.id, self.sender.clone()).expect("cannot create comp");
-        let start = !comp.is_input_ports();
+        let sort = self.resolve_sort(sort.into().into_owned())?;
+        let (comp, senders) = self.cache.create_comp(&sort, self.id, self.sender.clone())?;
+        let start = !comp.is_input_ports() || comp.autostart();
         self.sender.send(CompMsg::NewAgent(self.id, name.clone(), comp)).expect("Cannot send to sched state");
+        {
+            let mut remote_inputs = self.remote_inputs.lock().expect("add_node : poisoned remote inputs");
+            for (port, sender) in &senders {
+                if port == "accumulator" { continue; }
+                if let (Ok(schema), Ok(writer)) = (self.cache.get_schema_input(&sort, port), self.cache.remote_input(&sort, port, sender)) {
+                    remote_inputs.insert((name.clone(), port.clone()), (schema, Arc::new(Mutex::new(writer))));
+                }
+            }
+        }
+        self.watch_inputs(&name, &sort, &senders);
         //let s_acc = try!(senders.get("accumulator").ok_or(result::Error::PortNotFound(name.clone(), "accumulator".into()))).clone();
         self.agents.insert(name.clone(),
                                Comp {
@@ -197,12 +506,284 @@ impl Scheduler {
                                    inputs_array: HashMap::new(),
                                    sort: sort,
                                    start: start,
+                                   pool: None,
                                });
         // self.sender.send(CompMsg::ConnectOutputPort(self.id, "accumulator".into(), s_acc)).expect("Cannot send to sched state");
         self.id += 1;
         Ok(())
     }
 
+    // The path of a sort of the registry, once the version exported by its dylib is checked, or the sort itself
+    fn resolve_sort(&mut self, sort: String) -> Result<String> {
+        if !registry::is_registry_sort(&sort) {
+            return Ok(sort);
+        }
+        let (path, req) = self.registry.resolve(&sort)?;
+        self.cache.load(&path)?;
+        registry::check_version(&sort, &self.cache.version(&path)?, &req)?;
+        Ok(path)
+    }
+
+    /// Add a agent to the scheduler, with limits on its resources
+    ///
+    /// The messages waiting in the input ports (but the option and the accumulator) and each execution of the agent
+    /// are limited, and the `action` of the limits is applied when the agent is over them. The violations are sent
+    /// to the supervisor, see `supervise`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// try!(sched.add_node_with_limits("parse", "/home/xxx/agents/parse.so", Limits {
+    ///     max_queued_bytes: Some(1 << 20),
+    ///     max_run_time: Some(Duration::from_secs(5)),
+    ///     action: LimitAction::Restart,
+    /// }));
+    /// ```
+    pub fn add_node_with_limits<'a, A, B>(&mut self, name: A, sort: B, limits: Limits) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>
+    {
+        let name = name.into().into_owned();
+        self.add_node(name.clone(), sort)?;
+        let limit = Arc::new(LimitState::new(limits));
+        let comp = &self.agents[&name];
+        for (port, sender) in &comp.inputs {
+            if port == "option" || port == "accumulator" { continue; }
+            // The named options have no watch, they are not limited either
+            if let Ok(watch) = self.cache.watch_input(&comp.sort, port, sender) {
+                watch.set_limit(limit.clone());
+            }
+        }
+        self.sender.send(CompMsg::SetLimits(comp.id, limit)).expect("add_node_with_limits : unable to send to sched state");
+        Ok(())
+    }
+
+    /// Add a agent to the scheduler, whose executions are cancelled after `max_run_time`
+    ///
+    /// Over its run time, the agent is reported to the supervisor, and its next `recv` or `send` returns
+    /// `Error::Cancelled`, even if it is blocked, so a stuck agent cannot hang the network or its shutdown. It's
+    /// `add_node_with_limits` with `LimitAction::Cancel`.
+    pub fn add_node_with_timeout<'a, A, B>(&mut self, name: A, sort: B, max_run_time: Duration) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>
+    {
+        self.add_node_with_limits(name, sort, Limits {
+            max_run_time: Some(max_run_time),
+            action: LimitAction::Cancel,
+            ..Limits::default()
+        })
+    }
+
+    /// Add a agent to the scheduler, which runs on its own thread, see `set_placement`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// try!(sched.add_node_with_placement("parse", "/home/xxx/agents/parse.so", Placement { cpus: vec![2, 3] }));
+    /// ```
+    pub fn add_node_with_placement<'a, A, B>(&mut self, name: A, sort: B, placement: Placement) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>
+    {
+        let name = name.into().into_owned();
+        self.add_node(name.clone(), sort)?;
+        self.set_placement(name, placement)
+    }
+
+    /// Run an agent on its own thread, named after the node and pinned to the cores of `placement`
+    ///
+    /// The agent doesn't take a worker anymore, and its thread shows in `top -H` or `gdb`. Each instance of a pool
+    /// gets its own thread, with the same cores. See `affinity`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// try!(sched.add_node_with_limits("parse", "/home/xxx/agents/parse.so", limits));
+    /// try!(sched.set_placement("parse", Placement { cpus: vec![2] }));
+    /// ```
+    pub fn set_placement<'a, A: Into<Cow<'a, str>>>(&self, name: A, placement: Placement) -> Result<()> {
+        let name = name.into();
+        self.check_local(&name)?;
+        let comp = self.agents.get(&name as &str).ok_or(result::Error::NodeNotFound(name.to_string()))?;
+        for id in self.instance_ids(comp) {
+            self.sender.send(CompMsg::SetPlacement(id, placement.clone())).expect("set_placement : unable to send to sched state");
+        }
+        Ok(())
+    }
+
+    /// Add a agent which runs in its own process, so a crash of the agent cannot kill the scheduler
+    ///
+    /// The process runs the current executable, which must call `isolate::child` first thing in its `main`. The
+    /// messages go through remote edges on the loopback, so only the ports with a `RemoteEdge` can be connected, and
+    /// an output port of the node only to a simple input port. The process is started again each time it exits, and
+    /// its connections restored; the messages it was handling are lost.
+    ///
+    /// The agent cannot be paused, limited, tapped or checkpointed. It is removed with `remove_isolated_node`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// try!(sched.add_isolated_node("decode", "/home/xxx/agents/decode.so"));
+    /// try!(sched.connect("read", "output", "decode", "input"));
+    /// ```
+    pub fn add_isolated_node<'a, A, B>(&mut self, name: A, sort: B) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>
+    {
+        let name = name.into().into_owned();
+        let sort = self.resolve_sort(sort.into().into_owned())?;
+        if self.isolated_addr.is_none() {
+            let addr = remote::listen("127.0.0.1:0", self.remote_inputs.clone())?;
+            self.isolated_addr = Some(addr.to_string());
+        }
+        // Created only to know its ports, the agent never runs in this process
+        let (comp, ports) = self.cache.create_comp(&sort, self.id, self.sender.clone())?;
+        let start = !comp.is_input_ports() || comp.autostart();
+        let isolated = Isolated::spawn(name.clone(), sort.clone(), start)?;
+        // Each input port is a channel in this process, forwarded to the child
+        let mut senders = HashMap::new();
+        {
+            let mut remote_inputs = self.remote_inputs.lock().expect("add_isolated_node : poisoned remote inputs");
+            for port in ports.keys() {
+                if port == "accumulator" { continue; }
+                let (sender, reader) = match self.cache.remote_pipe(&sort, port, self.id, self.sender.clone()) {
+                    Ok(pipe) => pipe,
+                    Err(_) => { continue; },
+                };
+                let schema = self.cache.get_schema_input(&sort, port)?;
+                let rejected = self.remote_rejected(name.clone(), port.clone());
+                remote::connect(isolated.addr.clone(), name.clone(), port.clone(), schema.clone(), reader, rejected);
+                // The other isolated nodes send to it through this process
+                let writer = self.cache.remote_input(&sort, port, &sender)?;
+                remote_inputs.insert((name.clone(), port.clone()), (schema, Arc::new(Mutex::new(writer))));
+                senders.insert(port.clone(), sender);
+            }
+        }
+        self.watch_inputs(&name, &sort, &senders);
+        self.agents.insert(name.clone(),
+                               Comp {
+                                   id: self.id,
+                                   inputs: senders,
+                                   inputs_array: HashMap::new(),
+                                   sort: sort,
+                                   start: false,
+                                   pool: None,
+                               });
+        self.isolated.insert(name, isolated);
+        self.id += 1;
+        Ok(())
+    }
+
+    /// True if the agent `name` was added with `add_isolated_node`
+    pub fn is_isolated(&self, name: &str) -> bool {
+        self.isolated.contains_key(name)
+    }
+
+    /// Remove an agent added with `add_isolated_node`, and stop its process
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.remove_isolated_node("decode"));
+    /// ```
+    pub fn remove_isolated_node<'a, A: Into<Cow<'a, str>>>(&mut self, name: A) -> Result<Comp> {
+        let name = name.into().into_owned();
+        self.isolated.remove(&name).ok_or(result::Error::NodeNotFound(name.clone()))?;
+        self.remote_inputs.lock().expect("remove_isolated_node : poisoned remote inputs")
+            .retain(|&(ref agent, _), _| agent != &name);
+        self.network.lock().expect("remove_isolated_node : poisoned network").remove_agent(&name);
+        self.agents.remove(&name).ok_or(result::Error::NodeNotFound(name))
+    }
+
+    /// Receive the violations of the limits of the agents
+    ///
+    /// The violations are logged as warnings too. Only the last call to `supervise` receives them.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// for violation in sched.supervise() {
+    ///     println!("{:?}", violation);
+    /// }
+    /// ```
+    pub fn supervise(&self) -> Receiver<Violation> {
+        let (s, r) = channel();
+        self.sender.send(CompMsg::Supervise(s)).expect("supervise : unable to send to sched state");
+        r
+    }
+
+    /// Add `size` instances of an agent, used as one agent named `name`
+    ///
+    /// A message sent to an input port of the pool goes to the instance with the fewest waiting messages, and the
+    /// option goes to all the instances. The outputs of the instances are merged.
+    ///
+    /// If `ordered`, the messages go to each instance in turn, and the simple outputs are merged in the same order.
+    /// Each instance must then send exactly one message for each message it receives.
+    ///
+    /// The array input ports of the instances cannot be reached through the pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// try!(sched.add_pool("worker", "/home/xxx/agents/heavy.so", 8, false));
+    /// try!(sched.connect("input", "output", "worker", "input"));
+    /// ```
+    pub fn add_pool<'a, A, B>(&mut self, name: A, sort: B, size: usize, ordered: bool) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>
+    {
+        let name = name.into().into_owned();
+        let sort = self.resolve_sort(sort.into().into_owned())?;
+        if size == 0 {
+            return Err(result::Error::Misc(format!("the pool {} needs at least one instance", name)));
+        }
+
+        let mut instances = vec![];
+        for i in 0..size {
+            let instance = format!("{}#{}", name, i);
+            self.add_node(instance.clone(), sort.clone())?;
+            instances.push(instance);
+        }
+
+        // One sender for each input port, which dispatches to the instances
+        let mut inputs = HashMap::new();
+        let ports: Vec<String> = self.agents[&instances[0]].inputs.keys().cloned().collect();
+        for port in ports {
+            if port == "accumulator" { continue; }
+            let mode = if port == "option" {
+                PoolInput::Broadcast
+            } else if ordered {
+                PoolInput::RoundRobin
+            } else {
+                PoolInput::LeastLoaded
+            };
+            let mut senders = vec![];
+            for instance in &instances {
+                senders.push(self.get_sender(instance as &str, &port as &str)?);
+            }
+            let sender = self.cache.pool_input(&sort, &port, senders, mode, self.sender.clone())?;
+            inputs.insert(port, sender);
+        }
+
+        self.watch_inputs(&name, &sort, &inputs);
+        let start = self.agents[&instances[0]].start;
+        // The pool has its own id, no agent of the scheduler state has it : the operations by id go to each
+        // instance, see `instance_ids`, or refuse a pool, see `agent_id`
+        let id = self.id;
+        self.id += 1;
+        self.agents.insert(name,
+                           Comp {
+                               id: id,
+                               inputs: inputs,
+                               inputs_array: HashMap::new(),
+                               sort: sort,
+                               start: start,
+                               pool: Some(Pool {
+                                   instances: instances,
+                                   ordered: ordered,
+                               }),
+                           });
+        Ok(())
+    }
+
     /// Start the scheduler
     ///
     /// Start all the agent that have no input ports
@@ -213,14 +794,20 @@ impl Scheduler {
     /// sched.start();
     /// ```
     pub fn start(&self) {
+        // The instances of the pools are also in the agents
         for comp in self.agents.values() {
-            if comp.start {
+            if comp.start && comp.pool.is_none() {
                 self.sender.send(CompMsg::Start(comp.id)).expect("start: unable to send to sched state");
             }
         }
+        for isolated in self.isolated.values() {
+            if isolated.start {
+                isolated.command(&["start"]);
+            }
+        }
     }
 
-    /// Start the agent `name` if it has no input port
+    /// Start the agent `name` if it has no input port, or if it is an autostart agent
     ///
     /// # Example
     ///
@@ -229,10 +816,18 @@ impl Scheduler {
     /// ```
     pub fn start_if_needed<'a, A: Into<Cow<'a, str>>>(&self, name: A) -> Result<()> {
         let name = name.into().into_owned();
-        self.agents.get(&name).ok_or(result::Error::AgentNotFound(name.clone()))
+        if let Some(isolated) = self.isolated.get(&name) {
+            if isolated.start {
+                isolated.command(&["start"]);
+            }
+            return Ok(());
+        }
+        self.agents.get(&name).ok_or(result::Error::NodeNotFound(name.clone()))
             .and_then(|comp| {
                 if comp.start {
-                    self.sender.send(CompMsg::Start(comp.id)).expect("start_if_needed");
+                    for id in self.instance_ids(comp) {
+                        self.sender.send(CompMsg::Start(id)).expect("start_if_needed");
+                    }
                 }
                 Ok(())
             })
@@ -248,171 +843,961 @@ impl Scheduler {
         A: Into<Cow<'a, str>>
     {
         let name = name.into();
-        let comp = self.agents.get(&name as &str).ok_or(result::Error::AgentNotFound(name.into_owned()))?;
-        self.sender.send(CompMsg::Start(comp.id)).expect("start: unable to send to sched state");
+        if let Some(isolated) = self.isolated.get(&name as &str) {
+            isolated.command(&["start_agent"]);
+            return Ok(());
+        }
+        let comp = self.agents.get(&name as &str).ok_or(result::Error::NodeNotFound(name.into_owned()))?;
+        for id in self.instance_ids(comp) {
+            self.sender.send(CompMsg::Start(id)).expect("start: unable to send to sched state");
+        }
         Ok(())
     }
 
-    /// Remove a agent form the scheduler and retrieve all the information
+    /// Pause an agent, or all the instances of a pool
+    ///
+    /// A paused agent still receives messages in its ports, but is not run until `resume`. A running agent
+    /// finishes its current execution.
     ///
     /// # Example
     /// ```rust,ignore
-    /// let (boxed_comp, comp) = try!(sched.remove_agent("add"));
-    /// assert!(boxed_comp.is_input_ports());
+    /// try!(sched.pause("add"));
     /// ```
-    pub fn remove_agent<'a, A: Into<Cow<'a, str>>>(&mut self, name: A) -> Result<(BoxedComp, Comp)>{
-        /*
-        let name = name.into().into_owned();
-        let (s, r) = channel();
-        {
-            let comp = self.agents.get(&name).ok_or(result::Error::AgentNotFound(name.clone()))?;
-            self.sender.send(CompMsg::Remove(comp.id, s)).expect("Scheduler remove_agent: cannot send to the state");
-        }
-        let response = try!(r.recv());
-        match response {
-            SyncMsg::Remove(boxed_comp) => {
-                Ok((boxed_comp, try!(self.agents.remove(&name).ok_or(result::Error::AgentNotFound(name.into())))))
-            },
-            SyncMsg::CannotRemove => {
-                Err(result::Error::CannotRemove(name))
-            },
+    pub fn pause<'a, A: Into<Cow<'a, str>>>(&self, name: A) -> Result<()> {
+        let name = name.into();
+        let comp = self.agents.get(&name as &str).ok_or(result::Error::NodeNotFound(name.into_owned()))?;
+        for id in self.instance_ids(comp) {
+            self.sender.send(CompMsg::Pause(id)).expect("pause: unable to send to sched state");
         }
-         */
-        unimplemented!()
+        Ok(())
     }
 
-    /// Connect a simple output port to a simple input port
+    /// Resume a paused agent, which runs if messages wait in its ports
     ///
     /// # Example
     /// ```rust,ignore
-    /// try!(sched.connect("add", "output", "display", "input"));
+    /// try!(sched.resume("add"));
     /// ```
-    pub fn connect<'a, A, B, C, D>(&self, comp_out: A, port_out: B, comp_in: C, port_in: D) -> Result<()> where
-        A: Into<Cow<'a, str>>,
-        B: Into<Cow<'a, str>>,
-        C: Into<Cow<'a, str>>,
-        D: Into<Cow<'a, str>>
-    {
-        let comp_out = comp_out.into().into_owned();
-        let port_out = port_out.into().into_owned();
-        let comp_in = &*(comp_in.into());
-        let port_in = &*(port_in.into());
-        // Check schema
-        let sort_in = self.agents.get(comp_in).ok_or(result::Error::AgentNotFound(comp_in.into()))?;
-        let sort_out = self.agents.get(&comp_out).ok_or(result::Error::AgentNotFound(comp_out.clone()))?;
-        let in_schema = self.cache.get_schema_input(&sort_in.sort, port_in)?;
-        let out_schema = self.cache.get_schema_output(&sort_out.sort, &port_out)?;
-        if in_schema != "any" && out_schema != "any" && in_schema != out_schema {
-            return Err(result::Error::BadSchema(comp_out.clone(), port_out.clone(), out_schema, comp_in.into(), port_in.into(), in_schema));
+    pub fn resume<'a, A: Into<Cow<'a, str>>>(&self, name: A) -> Result<()> {
+        let name = name.into();
+        let comp = self.agents.get(&name as &str).ok_or(result::Error::NodeNotFound(name.into_owned()))?;
+        for id in self.instance_ids(comp) {
+            self.sender.send(CompMsg::Resume(id)).expect("resume: unable to send to sched state");
         }
-
-        let sender = try!(self.get_sender(comp_in, port_in));
-        let comp = self.agents.get(&comp_out).ok_or(result::Error::AgentNotFound(comp_out.clone()))?;
-        self.sender.send(CompMsg::ConnectOutputPort(comp.id, port_out, sender)).ok().expect("Scheduler connect: unable to send to sched state");
         Ok(())
     }
 
-    /// Connect a array output port to a simple input port
+    /// Hold an agent until another one is ready
+    ///
+    /// `name` receives messages in its ports, but is not run until `after` calls `ready()` in its `run`, e.g. once
+    /// its connection is open. Each instance of a pool waits for all the instances of the other pool. An agent
+    /// already ready holds nothing, so call it before sending the first messages. `resume` doesn't release a held
+    /// agent. An agent removed before it is ready releases `name`, with a warning, but one which never calls
+    /// `ready()` holds it for good.
     ///
     /// # Example
     /// ```rust,ignore
-    /// try!(sched.connect_array("add", "outputs", "1", "display", "input"));
+    /// try!(sched.start_after("api", "db"));
     /// ```
-    pub fn connect_array<'a, A, B, C, D, E>(&self, comp_out: A, port_out: B, element_out: C, comp_in: D, port_in: E) -> Result<()> where
+    pub fn start_after<'a, A, B>(&self, name: A, after: B) -> Result<()> where
         A: Into<Cow<'a, str>>,
-        B: Into<Cow<'a, str>>,
-        C: Into<Cow<'a, str>>,
-        D: Into<Cow<'a, str>>,
-        E: Into<Cow<'a, str>>
+        B: Into<Cow<'a, str>>
     {
-        let comp_out = comp_out.into().into_owned();
-        let port_out = port_out.into().into_owned();
-        let element_out = element_out.into().into_owned();
-        let comp_in = &*(comp_in.into());
-        let port_in = &*(port_in.into());
-        // Check schema
-        let sort_in = self.agents.get(comp_in).ok_or(result::Error::AgentNotFound(comp_in.into()))?;
-        let sort_out = self.agents.get(&comp_out).ok_or(result::Error::AgentNotFound(comp_out.clone()))?;
-        let in_schema = self.cache.get_schema_input(&sort_in.sort, port_in)?;
-        let out_schema = self.cache.get_schema_output_array(&sort_out.sort, &port_out)?;
-        if in_schema != "any" && out_schema != "any" && in_schema != out_schema {
-            return Err(result::Error::BadSchema(comp_out.clone(), port_out.clone(), out_schema, comp_in.into(), port_in.into(), in_schema));
+        let name = name.into();
+        let after = after.into();
+        let comp = self.agents.get(&name as &str).ok_or(result::Error::NodeNotFound(name.into_owned()))?;
+        let after_comp = self.agents.get(&after as &str).ok_or(result::Error::NodeNotFound(after.into_owned()))?;
+        for id in self.instance_ids(comp) {
+            for after_id in self.instance_ids(after_comp) {
+                self.sender.send(CompMsg::After(id, after_id)).expect("start_after: unable to send to sched state");
+            }
         }
-
-        let sender = try!(self.get_sender(comp_in, port_in));
-        let comp = self.agents.get(&comp_out).ok_or(result::Error::AgentNotFound(comp_out.clone()))?;
-        self.sender.send(CompMsg::ConnectOutputArrayPort(comp.id, port_out, element_out, sender)).ok().expect("Scheduler connect: unable to send to scheduler state");
         Ok(())
     }
 
-    /// Connect a simple output port to an array input port
+    /// Set a breakpoint on an input port of an agent, or of all the instances of a pool
+    ///
+    /// The messages sent to the port, and the ones waiting in it, are held : the agent doesn't run for them until
+    /// `step` releases them. The other ports are not held. A batch port can't have a breakpoint.
     ///
     /// # Example
     /// ```rust,ignore
-    /// try!(sched.connect_to_array("add", "output", "display", "inputs", "1"));
+    /// try!(sched.set_breakpoint("add", "a"));
+    /// try!(sched.step("add", "a", 1));
+    /// try!(sched.clear_breakpoint("add", "a"));
     /// ```
-    pub fn connect_to_array<'a, A, B, C, D, E>(&self, comp_out: A, port_out: B, comp_in: C, port_in: D, element_in: E) -> Result<()> where
-        A: Into<Cow<'a, str>>,
-        B: Into<Cow<'a, str>>,
-        C: Into<Cow<'a, str>>,
-        D: Into<Cow<'a, str>>,
-        E: Into<Cow<'a, str>>
-    {
-        let comp_out = comp_out.into().into_owned();
-        let port_out = port_out.into().into_owned();
-        let comp_in = &*(comp_in.into());
-        let port_in = &*(port_in.into());
-        let element_in = &*(element_in.into());
-        // Check schema
-        let sort_in = self.agents.get(comp_in).ok_or(result::Error::AgentNotFound(comp_in.into()))?;
-        let sort_out = self.agents.get(&comp_out).ok_or(result::Error::AgentNotFound(comp_out.clone()))?;
-        let in_schema = self.cache.get_schema_input_array(&sort_in.sort, port_in)?;
-        let out_schema = self.cache.get_schema_output(&sort_out.sort, &port_out)?;
-        if in_schema != "any" && out_schema != "any" && in_schema != out_schema {
-            return Err(result::Error::BadSchema(comp_out.clone(), port_out.clone(), out_schema, comp_in.into(), port_in.into(), in_schema));
+    pub fn set_breakpoint<'a, A: Into<Cow<'a, str>>, B: Into<Cow<'a, str>>>(&self, name: A, port: B) -> Result<()> {
+        for (id, watch) in self.breakpoint_ports(name.into(), port.into())? {
+            for _ in 0..watch.hold() {
+                self.sender.send(CompMsg::Dec(id)).expect("set_breakpoint: unable to send to sched state");
+            }
         }
-
-        let sender = try!(self.get_array_sender(comp_in, port_in, element_in));
-        let comp = self.agents.get(&comp_out).ok_or(result::Error::AgentNotFound(comp_out.clone()))?;
-        self.sender.send(CompMsg::ConnectOutputPort(comp.id, port_out, sender)).ok().expect("Scheduler connect: unable to send to scheduler state");
         Ok(())
     }
 
-    /// Connect an array output port to an array input port
-    ///
-    /// # Example
+    /// Release at most `n` messages held by the breakpoint of a port, and return how many were released
+    pub fn step<'a, A: Into<Cow<'a, str>>, B: Into<Cow<'a, str>>>(&self, name: A, port: B, n: usize) -> Result<usize> {
+        let mut released = 0;
+        for (id, watch) in self.breakpoint_ports(name.into(), port.into())? {
+            if released == n {
+                break;
+            }
+            let step = watch.step(n - released);
+            for _ in 0..step {
+                self.sender.send(CompMsg::Inc(id)).expect("step: unable to send to sched state");
+            }
+            released += step;
+        }
+        Ok(released)
+    }
+
+    /// Remove the breakpoint of a port, the messages it held are delivered
+    pub fn clear_breakpoint<'a, A: Into<Cow<'a, str>>, B: Into<Cow<'a, str>>>(&self, name: A, port: B) -> Result<()> {
+        for (id, watch) in self.breakpoint_ports(name.into(), port.into())? {
+            for _ in 0..watch.unhold() {
+                self.sender.send(CompMsg::Inc(id)).expect("clear_breakpoint: unable to send to sched state");
+            }
+        }
+        Ok(())
+    }
+
+    // The port of each instance of an agent, with the id of the instance
+    fn breakpoint_ports(&self, name: Cow<str>, port: Cow<str>) -> Result<Vec<(usize, Arc<PortWatch>)>> {
+        let comp = self.agents.get(&name as &str).ok_or_else(|| result::Error::NodeNotFound(name.to_string()))?;
+        let names = match comp.pool {
+            Some(ref pool) => pool.instances.clone(),
+            None => vec![name.to_string()],
+        };
+        let network = self.network.lock().expect("breakpoint : poisoned network");
+        let mut ports = vec![];
+        for instance in names {
+            if let (Some(comp), Some(watch)) = (self.agents.get(&instance), network.ports.get(&(instance.clone(), port.to_string()))) {
+                ports.push((comp.id, watch.clone()));
+            }
+        }
+        if ports.is_empty() {
+            return Err(result::Error::PortNotFound(name.into_owned(), port.into_owned()));
+        }
+        Ok(ports)
+    }
+
+    /// The names of the agents, sorted
+    pub fn list_nodes(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.agents.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// What an agent is doing, when it last ran, and its connected ports with the messages waiting in them
+    ///
+    /// The state of a pool is the busiest of its instances : running, blocked, idle, ended, waiting then paused.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let status = try!(sched.node_status("add"));
+    /// if status.state == NodeState::Blocked { ... }
+    /// ```
+    pub fn node_status<'a, A: Into<Cow<'a, str>>>(&self, name: A) -> Result<NodeStatus> {
+        let name = name.into();
+        let comp = self.agents.get(&name as &str).ok_or_else(|| result::Error::NodeNotFound(name.to_string()))?;
+        let busiest = [NodeState::Running, NodeState::Blocked, NodeState::Idle, NodeState::Ended, NodeState::Waiting,
+                       NodeState::Paused];
+        let mut state: Option<NodeState> = None;
+        let mut last_run = None;
+        for id in self.instance_ids(comp) {
+            let (s, r) = channel();
+            self.sender.send(CompMsg::Status(id, s)).expect("node_status : unable to send to sched state");
+            if let Ok((instance, run)) = r.recv() {
+                let rank = |state: NodeState| busiest.iter().position(|s| *s == state);
+                if state.map_or(true, |state| rank(instance) < rank(state)) {
+                    state = Some(instance);
+                }
+                last_run = cmp::max(last_run, run);
+            }
+        }
+        let state = state.ok_or_else(|| result::Error::NodeNotFound(name.to_string()))?;
+
+        let network = self.network.lock().expect("node_status : poisoned network");
+        let mut inputs: BTreeMap<String, PortStatus> = BTreeMap::new();
+        let mut outputs: BTreeMap<String, PortStatus> = BTreeMap::new();
+        let port = |port: &str| PortStatus { port: port.into(), queued: 0, edges: vec![] };
+        for (&(ref agent, ref p), watch) in &network.ports {
+            if *agent == name {
+                inputs.entry(p.clone()).or_insert_with(|| port(p)).queued = watch.queued.load(Ordering::SeqCst);
+            }
+        }
+        for link in &network.links {
+            if link.in_agent == name {
+                inputs.entry(link.in_port.clone()).or_insert_with(|| port(&link.in_port))
+                    .edges.push((link.out_agent.clone(), link.out_port.clone()));
+            }
+            if link.out_agent == name {
+                outputs.entry(link.out_port.clone()).or_insert_with(|| port(&link.out_port))
+                    .edges.push((link.in_agent.clone(), link.in_port.clone()));
+            }
+        }
+        Ok(NodeStatus {
+            name: name.to_string(),
+            sort: comp.sort.clone(),
+            state: state,
+            last_run: last_run,
+            inputs: inputs.into_iter().map(|(_, port)| port).collect(),
+            outputs: outputs.into_iter().map(|(_, port)| port).collect(),
+        })
+    }
+
+    /// Pause all the agents of the network
+    pub fn pause_all(&self) {
+        self.sender.send(CompMsg::PauseAll).expect("pause_all: unable to send to sched state");
+    }
+
+    /// Resume all the agents of the network
+    pub fn resume_all(&self) {
+        self.sender.send(CompMsg::ResumeAll).expect("resume_all: unable to send to sched state");
+    }
+
+    /// Start the network, and wait until it is idle
+    ///
+    /// The agents are started like with `start`, so it must be called once : `wait_idle` waits again after new
+    /// messages are sent.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.run_until_idle());
+    /// // All the messages sent so far are processed
+    /// ```
+    pub fn run_until_idle(&self) -> Result<()> {
+        self.start();
+        self.wait_idle()
+    }
+
+    /// Wait until the network is idle : no agent is executing, and no message waits for an agent that can run
+    ///
+    /// The scheduler counts the messages of each agent, and every message sent by an agent is counted before the end
+    /// of its execution, so a network with feedback loops is idle only once the messages stop going round. The
+    /// agents don't need to return `End`, but an agent blocked in a receive is still executing. A paused agent, or
+    /// one waiting for an empty port (`Signal::WaitFor`), cannot run and doesn't prevent the network from being
+    /// idle. The messages sent from outside the scheduler after the call (a remote scheduler, another thread) are
+    /// not waited for.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.wait_idle());
+    /// ```
+    pub fn wait_idle(&self) -> Result<()> {
+        let (s, r) = channel();
+        self.sender.send(CompMsg::WaitIdle(s)).expect("wait_idle: unable to send to sched state");
+        Ok(r.recv()?)
+    }
+
+    /// Stop the scheduler without waiting for the end of the agents, usually once it is idle
+    ///
+    /// Unlike `join`, the agents which did not return `End` are dropped. An agent still executing is dropped at the
+    /// end of its execution : all the input ports are closed first, so an agent waiting in `recv` gets the end of
+    /// stream instead of blocking the stop.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.run_until_idle());
+    /// sched.stop();
+    /// ```
+    pub fn stop(self) {
+        for comp in self.agents.values() {
+            for (port, sender) in &comp.inputs {
+                if port == "option" || port == "accumulator" { continue; }
+                let _ = self.cache.close_input(&comp.sort, port, sender);
+            }
+            for (port, elements) in &comp.inputs_array {
+                for sender in elements.values() {
+                    let _ = self.cache.close_input(&comp.sort, port, sender);
+                }
+            }
+        }
+        self.sender.send(CompMsg::Halt).ok().expect("Scheduler stop : Cannot send Halt");
+        self.th.join().ok().expect("Scheduler stop : Cannot join the thread");
+    }
+
+    /// Save the state of all the agents in the file `path`
+    ///
+    /// Only the agents with a `state_serde` are saved. The running agents are saved at the end of their current
+    /// execution, so the call blocks until then : an agent that never ends its execution blocks the checkpoint.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.checkpoint("/var/lib/network.checkpoint"));
+    /// ```
+    pub fn checkpoint<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let (s, r) = channel();
+        self.sender.send(CompMsg::Checkpoint(s)).expect("checkpoint: unable to send to sched state");
+        let snapshots = r.recv()??;
+        fs::write(&path, serde_json::to_vec(&snapshots)?).path(&path)?;
+        Ok(())
+    }
+
+    /// Set back the state of the agents saved by `checkpoint` in the file `path`
+    ///
+    /// The agents must already be in the network. The saved agents that are not in the network anymore are
+    /// ignored, so a checkpoint survives the edit of the graph. A running agent is restored at the end of its
+    /// current execution.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.restore("/var/lib/network.checkpoint"));
+    /// ```
+    pub fn restore<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let snapshots: HashMap<String, Vec<u8>> = serde_json::from_slice(&fs::read(&path).path(&path)?)?;
+        for (name, bytes) in snapshots {
+            // The instances of a pool have their own snapshots
+            if let Some(comp) = self.agents.get(&name).filter(|comp| comp.pool.is_none()) {
+                self.sender.send(CompMsg::Restore(comp.id, bytes)).expect("restore: unable to send to sched state");
+            }
+        }
+        Ok(())
+    }
+
+    /// Add a sink to the logger of the scheduler. The first sink replaces the default `Stderr`.
+    ///
+    /// The logger is shared by all the schedulers of the process, and is not used if another logger was installed
+    /// before the first scheduler.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// sched.log_to(Box::new(try!(JsonLines::open("/var/log/network.json"))));
+    /// ```
+    pub fn log_to(&self, sink: Box<Sink>) {
+        crate::log::add_sink(sink);
+    }
+
+    /// Send the log entries, as JSON objects, to the input port of an agent
+    ///
+    /// The edge of the port must be a `String`. The entries of the agent itself are sent too, so it should not log
+    /// each entry it receives.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.log_to_edge("logs", "input"));
+    /// ```
+    pub fn log_to_edge<'a, A, B>(&self, comp: A, port: B) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+    {
+        let comp = &*(comp.into());
+        let port = &*(port.into());
+        let sender = self.get_typed_sender::<String, _, _>(comp, port)?;
+        self.log_to(Box::new(crate::log::Edge::new(sender)));
+        Ok(())
+    }
+
+    /// Only log the entries of `level` and above, `Info` by default
+    pub fn log_level(&self, level: LevelFilter) {
+        crate::log::set_level(level);
+    }
+
+    /// Take the messages that could not be delivered, the oldest first
+    ///
+    /// Only the last `MAX_DEAD_LETTERS` are kept.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// for letter in sched.take_dead_letters() {
+    ///     println!("{}", letter);
+    /// }
+    /// ```
+    pub fn take_dead_letters(&self) -> Vec<DeadLetter> {
+        self.dead_letters.lock().expect("take_dead_letters : poisoned dead letters").take()
+    }
+
+    /// Send the description of each new dead letter to the input port of an agent, for alerting
+    ///
+    /// The edge of the port must be a `String`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.connect_dead_letters("alert", "input"));
+    /// ```
+    pub fn connect_dead_letters<'a, A, B>(&self, comp: A, port: B) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+    {
+        let comp = &*(comp.into());
+        let port = &*(port.into());
+        let sender = self.get_typed_sender::<String, _, _>(comp, port)?;
+        // Sent from another thread, a full port must not block the scheduler
+        let (s, r) = channel::<String>();
+        thread::spawn(move || {
+            for description in r {
+                if sender.send(description).is_err() {
+                    return;
+                }
+            }
+        });
+        self.dead_letters.lock().expect("connect_dead_letters : poisoned dead letters").set_sink(s);
+        Ok(())
+    }
+
+    /// Remove a agent form the scheduler and retrieve all the information
+    ///
+    /// If the agent is running, the call blocks until the end of its current execution. The input ports fed only by
+    /// the agent are closed : their agents receive the end of stream once their messages are read. A new connection
+    /// to such a port, or a new sender from `get_sender`, opens it again.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let (boxed_comp, comp) = try!(sched.remove_agent("add"));
+    /// assert!(boxed_comp.is_input_ports());
+    /// ```
+    pub fn remove_agent<'a, A: Into<Cow<'a, str>>>(&mut self, name: A) -> Result<(BoxedComp, Comp)>{
+        let name = name.into().into_owned();
+        self.check_local(&name)?;
+        // A pool is removed with all its instances, the first one is returned
+        let instances = self.agents.get(&name).and_then(|c| c.pool.as_ref()).map(|p| p.instances.clone());
+        if let Some(instances) = instances {
+            let mut first = None;
+            for instance in instances {
+                let (boxed_comp, _) = self.remove_agent(instance)?;
+                if first.is_none() { first = Some(boxed_comp); }
+            }
+            self.close_downstream(&name);
+            let comp = self.agents.remove(&name).ok_or(result::Error::NodeNotFound(name.clone()))?;
+            return first.map(|boxed_comp| (boxed_comp, comp)).ok_or(result::Error::NodeNotFound(name));
+        }
+        let (s, r) = channel();
+        {
+            let comp = self.agents.get(&name).ok_or(result::Error::NodeNotFound(name.clone()))?;
+            self.sender.send(CompMsg::Remove(comp.id, s)).expect("Scheduler remove_agent: cannot send to the state");
+        }
+        let response = r.recv()?;
+        match response {
+            SyncMsg::Remove(boxed_comp) => {
+                self.remote_inputs.lock().expect("remove_agent : poisoned remote inputs")
+                    .retain(|&(ref agent, _), _| agent != &name);
+                self.close_downstream(&name);
+                Ok((boxed_comp, self.agents.remove(&name).ok_or(result::Error::NodeNotFound(name))?))
+            },
+            SyncMsg::CannotRemove => {
+                Err(result::Error::CannotRemove(name))
+            },
+        }
+    }
+
+    /// Connect a simple output port to a simple input port
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.connect("add", "output", "display", "input"));
+    /// ```
+    pub fn connect<'a, A, B, C, D>(&self, comp_out: A, port_out: B, comp_in: C, port_in: D) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>
+    {
+        let comp_out = comp_out.into().into_owned();
+        let port_out = port_out.into().into_owned();
+        let comp_in = &*(comp_in.into());
+        let port_in = &*(port_in.into());
+        // Check schema
+        let sort_in = self.agents.get(comp_in).ok_or(result::Error::NodeNotFound(comp_in.into()))?;
+        let sort_out = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        let in_schema = self.cache.get_schema_input(&sort_in.sort, port_in)?;
+        let out_schema = self.cache.get_schema_output(&sort_out.sort, &port_out)?;
+        if !compatible_schema(&out_schema, &in_schema) {
+            return Err(result::Error::BadSchema(comp_out.clone(), port_out.clone(), out_schema, comp_in.into(), port_in.into(), in_schema));
+        }
+
+        let comp = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        self.link(&comp_out, port_out.clone(), comp_in, port_in.into());
+        // The child sends to this process, which delivers in the input port
+        if let (Some(isolated), Some(addr)) = (self.isolated.get(&comp_out), self.isolated_addr.as_ref()) {
+            isolated.command(&["connect", &port_out, addr, comp_in, port_in]);
+            return Ok(());
+        }
+        self.connect_output(comp, port_out, None, || self.get_sender(comp_in, port_in))
+    }
+
+    /// Connect a simple output port to a simple input port, with at most `rate.per_sec` messages per second
+    ///
+    /// The sender waits before a message over the rate, but `rate.burst` messages can be sent at once after a
+    /// pause. The instances of a pool share the rate of the edge.
+    ///
+    /// The sender waits in `send` by sleeping its thread : the agent of `comp_out` must be `blocking(true)`, or
+    /// use `try_send` and return `Signal::Yield` while it fails, see `limits`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.connect_throttled("orders", "output", "db", "input", Rate { per_sec: 50.0, burst: 10 }));
+    /// ```
+    pub fn connect_throttled<'a, A, B, C, D>(&self, comp_out: A, port_out: B, comp_in: C, port_in: D, rate: Rate) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>
+    {
+        let comp_out = comp_out.into().into_owned();
+        let port_out = port_out.into().into_owned();
+        let comp_in = &*(comp_in.into());
+        let port_in = &*(port_in.into());
+        if !(rate.per_sec > 0.0) || rate.burst == 0 {
+            return Err(result::Error::Misc(format!("connect_throttled : bad rate {:?}", rate)));
+        }
+        // Check schema
+        let sort_in = self.agents.get(comp_in).ok_or(result::Error::NodeNotFound(comp_in.into()))?;
+        let sort_out = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        let in_schema = self.cache.get_schema_input(&sort_in.sort, port_in)?;
+        let out_schema = self.cache.get_schema_output(&sort_out.sort, &port_out)?;
+        if !compatible_schema(&out_schema, &in_schema) {
+            return Err(result::Error::BadSchema(comp_out.clone(), port_out.clone(), out_schema, comp_in.into(), port_in.into(), in_schema));
+        }
+
+        let throttle = Arc::new(Throttle::new(rate));
+        self.link(&comp_out, port_out.clone(), comp_in, port_in.into());
+        self.connect_output(sort_out, port_out, None, || {
+            let sender = self.get_sender(comp_in, port_in)?;
+            self.cache.throttle_input(&sort_in.sort, port_in, &sender, throttle.clone())
+        })
+    }
+
+    /// Connect a simple output port to a simple input port, which drops its oldest messages when it is full
+    ///
+    /// For the flows where only fresh data matter (telemetry, positions, ...) : a slow consumer sees the latest
+    /// messages instead of an ever-growing backlog, and the sender never waits. The overflow is the one of the input
+    /// port, shared by all its edges, and `Overflow::Block` sets it back. The dropped messages are counted in the
+    /// `dropped` of the port watch.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.connect_with_overflow("gps", "output", "map", "input", Overflow::LatestOnly));
+    /// ```
+    pub fn connect_with_overflow<'a, A, B, C, D>(&self, comp_out: A, port_out: B, comp_in: C, port_in: D, overflow: Overflow) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>
+    {
+        let comp_in = comp_in.into();
+        let port_in = port_in.into();
+        let sort_in = self.agents.get(&*comp_in).ok_or(result::Error::NodeNotFound(comp_in.to_string()))?;
+        let sender = self.get_sender(&*comp_in, &*port_in)?;
+        let watch = self.cache.watch_input(&sort_in.sort, &port_in, &sender)?;
+        self.connect(comp_out, port_out, comp_in.into_owned(), port_in.into_owned())?;
+        watch.set_overflow(overflow);
+        Ok(())
+    }
+
+    /// Connect a simple output port to a simple input port, whose messages are built in the segments of `arena`
+    ///
+    /// The agent of `comp_out` builds its messages with `new_builder` on its output port : their segments come from
+    /// the arena, and go back to it once the last receiver drops the message, so a steady flow stops allocating. Only
+    /// the `CapnpMsg` ports have builders. The arena is the one of the input port, shared by all its edges, and the
+    /// port watch counts the segments it allocated and reused (see `PortWatch::arena_stats`).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let arena = Arena::default();
+    /// try!(sched.connect_pooled("camera", "output", "encode", "input", arena.clone()));
+    /// ```
+    pub fn connect_pooled<'a, A, B, C, D>(&self, comp_out: A, port_out: B, comp_in: C, port_in: D, arena: Arena) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>
+    {
+        let comp_in = comp_in.into();
+        let port_in = port_in.into();
+        let sort_in = self.agents.get(&*comp_in).ok_or(result::Error::NodeNotFound(comp_in.to_string()))?;
+        let sender = self.get_sender(&*comp_in, &*port_in)?;
+        let watch = self.cache.watch_input(&sort_in.sort, &port_in, &sender)?;
+        self.connect(comp_out, port_out, comp_in.into_owned(), port_in.into_owned())?;
+        watch.set_arena(Some(arena));
+        Ok(())
+    }
+
+    /// Connect a simple output port to a simple input port of another edge, through an adapter
+    ///
+    /// The adapter is an agent with an input port `input`, of the edge of the output port, and an output port `output`,
+    /// of the edge of the input port : it converts each message, like a `prim_text` to a `fs_path`. The scheduler adds
+    /// it as the node `comp_out.port_out>comp_in.port_in`, which is removed with `remove_agent` like the other nodes.
+    /// Connecting the same ports again uses the same adapter.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.connect_adapted("ask", "output", "/home/xxx/agents/text_to_path.so", "open", "input"));
+    /// ```
+    pub fn connect_adapted<'a, A, B, C, D, E>(&mut self, comp_out: A, port_out: B, adapter: C, comp_in: D, port_in: E) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+        E: Into<Cow<'a, str>>
+    {
+        let comp_out = comp_out.into().into_owned();
+        let port_out = port_out.into().into_owned();
+        let comp_in = comp_in.into().into_owned();
+        let port_in = port_in.into().into_owned();
+        let name = format!("{}.{}>{}.{}", comp_out, port_out, comp_in, port_in);
+        let added = !self.agents.contains_key(&name);
+        if added {
+            self.add_node(name.clone(), adapter)?;
+        }
+        // The adapter is connected to the input port first, so the output port is left alone on a bad edge
+        let res = self.connect(&name as &str, "output", &comp_in as &str, &port_in as &str)
+            .and_then(|_| self.connect(&comp_out as &str, &port_out as &str, &name as &str, "input"));
+        if res.is_err() && added {
+            self.remove_agent(name)?;
+        }
+        res
+    }
+
+    /// Connect a array output port to a simple input port
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.connect_array("add", "outputs", "1", "display", "input"));
+    /// ```
+    pub fn connect_array<'a, A, B, C, D, E>(&self, comp_out: A, port_out: B, element_out: C, comp_in: D, port_in: E) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+        E: Into<Cow<'a, str>>
+    {
+        let comp_out = comp_out.into().into_owned();
+        let port_out = port_out.into().into_owned();
+        let element_out = element_out.into().into_owned();
+        let comp_in = &*(comp_in.into());
+        let port_in = &*(port_in.into());
+        // Check schema
+        let sort_in = self.agents.get(comp_in).ok_or(result::Error::NodeNotFound(comp_in.into()))?;
+        let sort_out = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        let in_schema = self.cache.get_schema_input(&sort_in.sort, port_in)?;
+        let out_schema = self.cache.get_schema_output_array(&sort_out.sort, &port_out)?;
+        if !compatible_schema(&out_schema, &in_schema) {
+            return Err(result::Error::BadSchema(comp_out.clone(), port_out.clone(), out_schema, comp_in.into(), port_in.into(), in_schema));
+        }
+
+        let comp = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        self.link(&comp_out, format!("{}[{}]", port_out, element_out), comp_in, port_in.into());
+        self.connect_output(comp, port_out, Some(element_out), || self.get_sender(comp_in, port_in))
+    }
+
+    /// Connect a simple output port to an array input port
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.connect_to_array("add", "output", "display", "inputs", "1"));
+    /// ```
+    pub fn connect_to_array<'a, A, B, C, D, E>(&self, comp_out: A, port_out: B, comp_in: C, port_in: D, element_in: E) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+        E: Into<Cow<'a, str>>
+    {
+        let comp_out = comp_out.into().into_owned();
+        let port_out = port_out.into().into_owned();
+        let comp_in = &*(comp_in.into());
+        let port_in = &*(port_in.into());
+        let element_in = &*(element_in.into());
+        // Check schema
+        let sort_in = self.agents.get(comp_in).ok_or(result::Error::NodeNotFound(comp_in.into()))?;
+        let sort_out = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        let in_schema = self.cache.get_schema_input_array(&sort_in.sort, port_in)?;
+        let out_schema = self.cache.get_schema_output(&sort_out.sort, &port_out)?;
+        if !compatible_schema(&out_schema, &in_schema) {
+            return Err(result::Error::BadSchema(comp_out.clone(), port_out.clone(), out_schema, comp_in.into(), port_in.into(), in_schema));
+        }
+
+        let comp = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        self.link(&comp_out, port_out.clone(), comp_in, format!("{}[{}]", port_in, element_in));
+        self.connect_output(comp, port_out, None, || self.get_array_sender(comp_in, port_in, element_in))
+    }
+
+    /// Connect an array output port to an array input port
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.connect_array_to_array("add", "outputs", "1", "display", "inputs", "1"));
+    /// ```
+    pub fn connect_array_to_array<'a, A, B, C, D, E, F>(&self, comp_out: A, port_out: B, element_out: C, comp_in: D, port_in: E, element_in: F) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+        E: Into<Cow<'a, str>>,
+        F: Into<Cow<'a, str>>
+    {
+        let comp_out = comp_out.into().into_owned();
+        let port_out = port_out.into().into_owned();
+        let element_out = element_out.into().into_owned();
+        let comp_in = &*(comp_in.into());
+        let port_in = &*(port_in.into());
+        let element_in = &*(element_in.into());
+        // Check schema
+        let sort_in = self.agents.get(comp_in).ok_or(result::Error::NodeNotFound(comp_in.into()))?;
+        let sort_out = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        let in_schema = self.cache.get_schema_input_array(&sort_in.sort, port_in)?;
+        let out_schema = self.cache.get_schema_output_array(&sort_out.sort, &port_out)?;
+        if !compatible_schema(&out_schema, &in_schema) {
+            return Err(result::Error::BadSchema(comp_out.clone(), port_out.clone(), out_schema, comp_in.into(), port_in.into(), in_schema));
+        }
+
+        let comp = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        self.link(&comp_out, format!("{}[{}]", port_out, element_out), comp_in, format!("{}[{}]", port_in, element_in));
+        self.connect_output(comp, port_out, Some(element_out), || self.get_array_sender(comp_in, port_in, element_in))
+    }
+
+    /// Connect a simple output port to an input port of a remote scheduler
+    ///
+    /// The remote scheduler must listen on `addr` with `listen_remote`. The edge of the port must implement `RemoteEdge`.
+    /// A message the remote scheduler cannot read is a dead letter, `Reason::RemoteRejected`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.connect_remote("add", "output", "10.0.0.2:9000", "display", "input"));
+    /// ```
+    pub fn connect_remote<'a, A, B, C, D, E>(&self, comp_out: A, port_out: B, addr: C, comp_in: D, port_in: E) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+        E: Into<Cow<'a, str>>
+    {
+        let comp_out = comp_out.into().into_owned();
+        let port_out = port_out.into().into_owned();
+        self.check_local(&comp_out)?;
+        let comp = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        let id = self.agent_id(&comp_out, comp, "connect_remote")?;
+        let schema = self.cache.get_schema_output(&comp.sort, &port_out)?;
+        let (sender, reader) = self.cache.remote_output(&comp.sort, &port_out, id, self.sender.clone())?;
+        let rejected = self.remote_rejected(comp_out.clone(), port_out.clone());
+        remote::connect(addr.into().into_owned(), comp_in.into().into_owned(), port_in.into().into_owned(), schema, reader, rejected);
+        self.sender.send(CompMsg::ConnectOutputPort(id, port_out, sender)).ok().expect("Scheduler connect_remote: unable to send to scheduler state");
+        Ok(())
+    }
+
+    /// Connect a simple output port to a sender which is not an input port of the network
+    ///
+    /// The sender must be a `MsgSender` of the edge of the port, the agent panics on the first message otherwise.
+    /// A pool can't be connected this way.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let (recv, sender) = MsgReceiver::<PrimText>::unbounded(0, sched.sender.clone());
+    /// try!(sched.connect_sender("add", "output", Box::new(sender)));
+    /// ```
+    pub fn connect_sender<'a, A, B>(&self, comp_out: A, port_out: B, sender: Box<Any + Send>) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+    {
+        let comp_out = comp_out.into().into_owned();
+        let port_out = port_out.into().into_owned();
+        self.check_local(&comp_out)?;
+        let comp = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        if comp.pool.is_some() {
+            return Err(result::Error::Misc(format!("connect_sender : {} is a pool", comp_out)));
+        }
+        // Check that the port exists
+        self.cache.get_schema_output(&comp.sort, &port_out)?;
+        self.sender.send(CompMsg::ConnectOutputPort(comp.id, port_out, sender)).ok().expect("Scheduler connect_sender: unable to send to scheduler state");
+        Ok(())
+    }
+
+    /// Copy a sample of the messages of a simple output port to a sink, for debugging
+    ///
+    /// The sink must be a `MsgSender` of the edge of the port, which must be `Clone`. `sample_rate` is the part of the
+    /// messages copied, between 0 and 1. The copies never slow down the edge : a copy is lost when the sink is full.
+    /// A pool can't be tapped. An error of the agent is sent to the `error_receiver`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let (recv, sink) = MsgReceiver::<PrimText>::new(0, sched.sender.clone(), false);
+    /// try!(sched.tap("add", "output", Box::new(sink), 0.01));
+    /// ```
+    pub fn tap<'a, A, B>(&self, comp: A, port: B, sink: Box<Any + Send>, sample_rate: f64) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+    {
+        if !(sample_rate > 0.0 && sample_rate <= 1.0) {
+            return Err(result::Error::Misc(format!("tap : the sample rate {} is not between 0 and 1", sample_rate)));
+        }
+        self.send_tap(comp.into().into_owned(), port.into().into_owned(), Some((sink, sample_rate)))
+    }
+
+    /// Stop copying the messages of an output port, see `tap`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.untap("add", "output"));
+    /// ```
+    pub fn untap<'a, A, B>(&self, comp: A, port: B) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+    {
+        self.send_tap(comp.into().into_owned(), port.into().into_owned(), None)
+    }
+
+    // Give the tap of an output port to its agent
+    fn send_tap(&self, comp: String, port: String, tap: Option<(Box<Any + Send>, f64)>) -> Result<()> {
+        self.check_local(&comp)?;
+        let c = self.agents.get(&comp).ok_or(result::Error::NodeNotFound(comp.clone()))?;
+        if c.pool.is_some() {
+            return Err(result::Error::Misc(format!("tap : {} is a pool", comp)));
+        }
+        // Check that the port exists
+        self.cache.get_schema_output(&c.sort, &port)?;
+        self.sender.send(CompMsg::Tap(c.id, port, tap)).ok().expect("Scheduler tap: unable to send to scheduler state");
+        Ok(())
+    }
+
+    /// Keep the last `len` messages sent on a simple output port, for post-mortem debugging
+    ///
+    /// The messages are copied serialized, so the edge of the port must be `Clone` and implement `RemoteEdge`. They
+    /// are read with `dump_trace`. When the agent at the other end of the edge fails, the messages are kept with the
+    /// error, and read with `trace_failure`. A pool can't be traced. An error of the agent is sent to the
+    /// `error_receiver`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.trace("add", "output", 100));
+    /// // The display agent fails
+    /// if let Some(failure) = try!(sched.trace_failure("add", "output")) {
+    ///     println!("{} : {} after {} messages", failure.agent, failure.error, failure.msgs.len());
+    /// }
+    /// ```
+    pub fn trace<'a, A, B>(&self, comp: A, port: B, len: usize) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+    {
+        if len == 0 {
+            return Err(result::Error::Misc("trace : the trace must keep at least one message".into()));
+        }
+        let comp = comp.into().into_owned();
+        let port = port.into().into_owned();
+        let trace = Arc::new(Trace::new(len));
+        self.send_trace(comp.clone(), port.clone(), Some(trace.clone()))?;
+        self.traces.lock().expect("trace : poisoned traces").insert((comp, port), trace);
+        Ok(())
+    }
+
+    /// Stop keeping the messages of an output port, see `trace`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.untrace("add", "output"));
+    /// ```
+    pub fn untrace<'a, A, B>(&self, comp: A, port: B) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+    {
+        let comp = comp.into().into_owned();
+        let port = port.into().into_owned();
+        self.send_trace(comp.clone(), port.clone(), None)?;
+        self.traces.lock().expect("untrace : poisoned traces").remove(&(comp, port));
+        Ok(())
+    }
+
+    /// The last messages of a traced output port, the oldest first
+    pub fn dump_trace<'a, A, B>(&self, comp: A, port: B) -> Result<Vec<TracedMsg>> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+    {
+        Ok(self.get_trace(comp.into(), port.into())?.dump())
+    }
+
+    /// The last messages of a traced output port when the agent at its other end last failed, if it failed
+    pub fn trace_failure<'a, A, B>(&self, comp: A, port: B) -> Result<Option<TraceFailure>> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+    {
+        Ok(self.get_trace(comp.into(), port.into())?.failure())
+    }
+
+    fn get_trace(&self, comp: Cow<str>, port: Cow<str>) -> Result<SharedTrace> {
+        self.traces.lock().expect("get_trace : poisoned traces")
+            .get(&(comp.to_string(), port.to_string())).cloned()
+            .ok_or_else(|| result::Error::Misc(format!("trace : {}() {} is not traced", comp, port)))
+    }
+
+    // Give the trace of an output port to its agent
+    fn send_trace(&self, comp: String, port: String, trace: Option<SharedTrace>) -> Result<()> {
+        self.check_local(&comp)?;
+        let c = self.agents.get(&comp).ok_or(result::Error::NodeNotFound(comp.clone()))?;
+        if c.pool.is_some() {
+            return Err(result::Error::Misc(format!("trace : {} is a pool", comp)));
+        }
+        // Check that the port exists
+        self.cache.get_schema_output(&c.sort, &port)?;
+        self.sender.send(CompMsg::Trace(c.id, port, trace)).ok().expect("Scheduler trace: unable to send to scheduler state");
+        Ok(())
+    }
+
+    /// Set the directory where the durable edges keep their messages
+    ///
+    /// # Example
     /// ```rust,ignore
-    /// try!(sched.connect_array_to_array("add", "outputs", "1", "display", "inputs", "1"));
+    /// sched.set_storage("/var/lib/network");
     /// ```
-    pub fn connect_array_to_array<'a, A, B, C, D, E, F>(&self, comp_out: A, port_out: B, element_out: C, comp_in: D, port_in: E, element_in: F) -> Result<()> where
+    pub fn set_storage<P: Into<PathBuf>>(&mut self, dir: P) {
+        self.storage = Some(dir.into());
+    }
+
+    /// Connect a simple output port to a simple input port, with an edge that survives a restart
+    ///
+    /// The messages are written in a log in the storage directory (see `set_storage`) until the input agent takes
+    /// them out of its port. When the same edge is connected again after a restart, the messages left in its log are
+    /// sent first. The edge of the port must implement `RemoteEdge`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// sched.set_storage("/var/lib/network");
+    /// try!(sched.connect_durable("add", "output", "display", "input"));
+    /// ```
+    pub fn connect_durable<'a, A, B, C, D>(&self, comp_out: A, port_out: B, comp_in: C, port_in: D) -> Result<()> where
         A: Into<Cow<'a, str>>,
         B: Into<Cow<'a, str>>,
         C: Into<Cow<'a, str>>,
-        D: Into<Cow<'a, str>>,
-        E: Into<Cow<'a, str>>,
-        F: Into<Cow<'a, str>>
+        D: Into<Cow<'a, str>>
     {
         let comp_out = comp_out.into().into_owned();
         let port_out = port_out.into().into_owned();
-        let element_out = element_out.into().into_owned();
         let comp_in = &*(comp_in.into());
         let port_in = &*(port_in.into());
-        let element_in = &*(element_in.into());
+        let storage = self.storage.as_ref().ok_or(result::Error::NoStorage)?;
         // Check schema
-        let sort_in = self.agents.get(comp_in).ok_or(result::Error::AgentNotFound(comp_in.into()))?;
-        let sort_out = self.agents.get(&comp_out).ok_or(result::Error::AgentNotFound(comp_out.clone()))?;
-        let in_schema = self.cache.get_schema_input_array(&sort_in.sort, port_in)?;
-        let out_schema = self.cache.get_schema_output_array(&sort_out.sort, &port_out)?;
-        if in_schema != "any" && out_schema != "any" && in_schema != out_schema {
+        let sort_in = self.agents.get(comp_in).ok_or(result::Error::NodeNotFound(comp_in.into()))?;
+        self.check_local(&comp_out)?;
+        let sort_out = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        let out_id = self.agent_id(&comp_out, sort_out, "connect_durable")?;
+        let in_schema = self.cache.get_schema_input(&sort_in.sort, port_in)?;
+        let out_schema = self.cache.get_schema_output(&sort_out.sort, &port_out)?;
+        if !compatible_schema(&out_schema, &in_schema) {
             return Err(result::Error::BadSchema(comp_out.clone(), port_out.clone(), out_schema, comp_in.into(), port_in.into(), in_schema));
         }
 
-        let sender = try!(self.get_array_sender(comp_in, port_in, element_in));
-        let comp = self.agents.get(&comp_out).ok_or(result::Error::AgentNotFound(comp_out.clone()))?;
-        self.sender.send(CompMsg::ConnectOutputArrayPort(comp.id, port_out, element_out, sender)).ok().expect("Scheduler connect: unable to send to scheduler state");
+        let in_sender = self.get_sender(comp_in, port_in)?;
+        let writer = self.cache.remote_input(&sort_in.sort, port_in, &in_sender)?;
+        let watch = self.cache.watch_input(&sort_in.sort, port_in, &in_sender)?;
+        let (sender, reader) = self.cache.remote_output(&sort_out.sort, &port_out, out_id, self.sender.clone())?;
+        let name = format!("{}.{}-{}.{}", comp_out, port_out, comp_in, port_in);
+        durable::connect(storage, name, reader, writer, watch)?;
+
+        self.link(&comp_out, port_out.clone(), comp_in, port_in.into());
+        self.sender.send(CompMsg::ConnectOutputPort(out_id, port_out, sender)).ok().expect("Scheduler connect_durable: unable to send to scheduler state");
         Ok(())
     }
 
+    /// Accept the remote edges of other schedulers on `addr`
+    ///
+    /// All the input ports with a `RemoteEdge` can be reached.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.listen_remote("0.0.0.0:9000"));
+    /// ```
+    pub fn listen_remote<'a, A: Into<Cow<'a, str>>>(&self, addr: A) -> Result<()> {
+        remote::listen(&addr.into(), self.remote_inputs.clone()).map(|_| ())
+    }
+
     /// disconnect an output port
     ///
     /// # Example
@@ -425,8 +1810,15 @@ impl Scheduler {
     {
         let comp_out = comp_out.into().into_owned();
         let port_out = port_out.into().into_owned();
-        let comp = self.agents.get(&comp_out).ok_or(result::Error::AgentNotFound(comp_out.clone()))?;
-        self.sender.send(CompMsg::Disconnect(comp.id, port_out)).ok().expect("Scheduler disconnect: unable to send to scheduler state");
+        let comp = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        self.network.lock().expect("disconnect : poisoned network").disconnect(&comp_out, &port_out);
+        if let Some(isolated) = self.isolated.get(&comp_out) {
+            isolated.command(&["disconnect", &port_out]);
+            return Ok(());
+        }
+        for id in self.instance_ids(comp) {
+            self.sender.send(CompMsg::Disconnect(id, port_out.clone())).ok().expect("Scheduler disconnect: unable to send to scheduler state");
+        }
         Ok(())
     }
 
@@ -444,8 +1836,12 @@ impl Scheduler {
         let comp_out = comp_out.into().into_owned();
         let port_out = port_out.into().into_owned();
         let element = element.into().into_owned();
-        let comp = self.agents.get(&comp_out).ok_or(result::Error::AgentNotFound(comp_out.clone()))?;
-        self.sender.send(CompMsg::DisconnectArray(comp.id, port_out, element)).ok().expect("Scheduler disconnect_array: unable to send to scheduler state");
+        self.check_local(&comp_out)?;
+        let comp = self.agents.get(&comp_out).ok_or(result::Error::NodeNotFound(comp_out.clone()))?;
+        self.network.lock().expect("disconnect_array : poisoned network").disconnect(&comp_out, &format!("{}[{}]", port_out, element));
+        for id in self.instance_ids(comp) {
+            self.sender.send(CompMsg::DisconnectArray(id, port_out.clone(), element.clone())).ok().expect("Scheduler disconnect_array: unable to send to scheduler state");
+        }
         Ok(())
     }
 
@@ -462,16 +1858,22 @@ impl Scheduler {
     {
         // TODO: the cache must create the channel of the right type
         let comp_name = comp_name.into().into_owned();
+        self.check_local(&comp_name)?;
         let port = port.into().into_owned();
         let element = element.into().into_owned();
 
         let (r, s, comp_id) = {
-            let comp = self.agents.get(&comp_name).ok_or(result::Error::AgentNotFound(comp_name.clone()))?;
-            let (r, s) = self.cache.create_input_array(&comp.sort, &port, comp.id, self.sender.clone(), true)?;
-            (r, s, comp.id)
+            let comp = self.agents.get(&comp_name).ok_or(result::Error::NodeNotFound(comp_name.clone()))?;
+            let id = self.agent_id(&comp_name, comp, "add_input_array_element")?;
+            let (r, s) = self.cache.create_input_array(&comp.sort, &port, id, self.sender.clone(), true)?;
+            if let Ok(watch) = self.cache.watch_input_array(&comp.sort, &port, &s) {
+                self.network.lock().expect("add_input_array_element : poisoned network")
+                    .ports.insert((comp_name.clone(), format!("{}[{}]", port, element)), watch);
+            }
+            (r, s, id)
         };
 
-        try!(self.agents.get_mut(&comp_name).ok_or(result::Error::AgentNotFound(comp_name.clone()))
+        self.agents.get_mut(&comp_name).ok_or(result::Error::NodeNotFound(comp_name.clone()))
             .and_then(|mut comp| {
                 if !comp.inputs_array.contains_key(&port) {
                     comp.inputs_array.insert(port.clone(), HashMap::new());
@@ -481,7 +1883,7 @@ impl Scheduler {
                         port.insert(element.clone(), s);
                         Ok(())
                     })
-            }));
+            })?;
         self.sender.send(CompMsg::AddInputArrayElement(comp_id, port, element, r)).ok().expect("Scheduler add_input_array_element : Unable to send to scheduler state");
         Ok(())
     }
@@ -538,8 +1940,11 @@ impl Scheduler {
         let comp = comp.into();
         let port = port.into().into_owned();
         let element = element.into().into_owned();
-        let comp = self.agents.get(&comp as &str).ok_or(result::Error::AgentNotFound(comp.into_owned()))?;
-        self.sender.send(CompMsg::AddOutputArrayElement(comp.id, port, element)).ok().expect("Scheduler add_output_array_element : Unable to send to scheduler state");
+        self.check_local(&comp)?;
+        let comp = self.agents.get(&comp as &str).ok_or(result::Error::NodeNotFound(comp.into_owned()))?;
+        for id in self.instance_ids(comp) {
+            self.sender.send(CompMsg::AddOutputArrayElement(id, port.clone(), element.clone())).ok().expect("Scheduler add_output_array_element : Unable to send to scheduler state");
+        }
         Ok(())
     }
 
@@ -557,8 +1962,10 @@ impl Scheduler {
     {
         let comp = comp.into();
         let port = port.into().into_owned();
-        let comp = self.agents.get(&comp as &str).ok_or(result::Error::AgentNotFound(comp.into_owned()))?;
-        self.sender.send(CompMsg::SetReceiver(comp.id, port, receiver)).expect("scheduler cannot send");
+        self.check_local(&comp)?;
+        let c = self.agents.get(&comp as &str).ok_or(result::Error::NodeNotFound(comp.to_string()))?;
+        let id = self.agent_id(&comp, c, "set_receiver")?;
+        self.sender.send(CompMsg::SetReceiver(id, port, receiver)).expect("scheduler cannot send");
         Ok(())
     }
 
@@ -578,8 +1985,10 @@ impl Scheduler {
         let comp = comp.into();
         let port = port.into().into_owned();
         let element = element.into().into_owned();
-        let comp = self.agents.get(&comp as &str).ok_or(result::Error::AgentNotFound(comp.into_owned()))?;
-        self.sender.send(CompMsg::AddInputArrayElement(comp.id, port, element, receiver)).expect("scheduler cannot send");
+        self.check_local(&comp)?;
+        let c = self.agents.get(&comp as &str).ok_or(result::Error::NodeNotFound(comp.to_string()))?;
+        let id = self.agent_id(&comp, c, "set_array_receiver")?;
+        self.sender.send(CompMsg::AddInputArrayElement(id, port, element, receiver)).expect("scheduler cannot send");
         Ok(())
     }
 
@@ -595,9 +2004,11 @@ impl Scheduler {
     {
         let comp = comp.into();
         let port = port.into();
-        let c = self.agents.get(&comp as &str).ok_or(result::Error::AgentNotFound(comp.to_string()))?;
+        let c = self.agents.get(&comp as &str).ok_or(result::Error::NodeNotFound(comp.to_string()))?;
         let t = &c.sort;
         let s = c.inputs.get(&port as &str).ok_or(result::Error::PortNotFound(comp.to_string(), port.to_string()))?;
+        // A port closed by the removal of its last upstream agent is opened again for the new sender
+        self.cache.reopen_input(t, &port, s)?;
         self.cache.clone_input(t, &port, s)
     }
 
@@ -615,13 +2026,207 @@ impl Scheduler {
         let comp = comp.into();
         let port = port.into();
         let element = element.into();
-        let c = self.agents.get(&comp as &str).ok_or(result::Error::AgentNotFound(comp.to_string()))?;
+        let c = self.agents.get(&comp as &str).ok_or(result::Error::NodeNotFound(comp.to_string()))?;
         let t = &c.sort;
         let p = c.inputs_array.get(&port as &str).ok_or(result::Error::PortNotFound(comp.to_string(), port.to_string()))?;
         let s = p.get(&element as &str).ok_or(result::Error::ElementNotFound(comp.to_string(), port.to_string(), element.to_string()))?;
+        self.cache.reopen_input(t, &port, s)?;
         self.cache.clone_input_array(t, &port, s)
     }
 
+    /// Get the sender of an input port, for the edge `T`
+    ///
+    /// Fails with `Error::TypeMismatch` if the port takes another edge.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let sender = try!(sched.get_typed_sender::<String, _, _>("display", "input"));
+    /// try!(sender.send("hello".into()));
+    /// ```
+    pub fn get_typed_sender<'a, T: 'static, A, B>(&self, comp: A, port: B) -> Result<MsgSender<T>> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+    {
+        let comp = comp.into();
+        let port = port.into();
+        let sender = self.get_sender(&comp as &str, &port as &str)?;
+        self.typed(sender, &comp, &port, || self.get_schema_input(&comp as &str, &port as &str))
+    }
+
+    /// Get the sender of an element of an array input port, for the edge `T`, see `get_typed_sender`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let sender = try!(sched.get_typed_array_sender::<String, _, _, _>("add", "input", "1"));
+    /// ```
+    pub fn get_typed_array_sender<'a, T: 'static, A, B, C>(&self, comp: A, port: B, element: C) -> Result<MsgSender<T>> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+    {
+        let comp = comp.into();
+        let port = port.into();
+        let sender = self.get_array_sender(comp.to_string(), port.to_string(), element)?;
+        self.typed(sender, &comp, &port, || self.get_schema_input_array(&comp as &str, &port as &str))
+    }
+
+    /// Export an input port of the network under the name `name`, which replaces the previous port of that name
+    ///
+    /// The applications send their messages to `name`, whatever the node behind it. The node and the port are only
+    /// looked up when the port is used, so a node replaced under the same name keeps its exported ports.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.export_input("IN", "open", "input", None));
+    /// let sender = try!(sched.get_typed_exported_sender::<FsPath>("IN"));
+    /// ```
+    pub fn export_input<'a, A, B, C>(&mut self, name: A, comp: B, port: C, element: Option<&str>) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+    {
+        let export = ExportedPort {
+            comp: comp.into().into_owned(),
+            port: port.into().into_owned(),
+            element: element.map(|e| e.into()),
+        };
+        // Check that the port exists
+        match export.element {
+            Some(_) => self.get_schema_input_array(&export.comp as &str, &export.port as &str)?,
+            None => self.get_schema_input(&export.comp as &str, &export.port as &str)?,
+        };
+        self.inports.insert(name.into().into_owned(), export);
+        Ok(())
+    }
+
+    /// Export an output port of the network under the name `name`, see `export_input`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(sched.export_output("OUT", "graph_check", "output", None));
+    /// let recv = try!(sched.get_exported_receiver::<CoreGraph>("OUT"));
+    /// ```
+    pub fn export_output<'a, A, B, C>(&mut self, name: A, comp: B, port: C, element: Option<&str>) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, str>>,
+    {
+        let export = ExportedPort {
+            comp: comp.into().into_owned(),
+            port: port.into().into_owned(),
+            element: element.map(|e| e.into()),
+        };
+        match export.element {
+            Some(_) => self.get_schema_output_array(&export.comp as &str, &export.port as &str)?,
+            None => self.get_schema_output(&export.comp as &str, &export.port as &str)?,
+        };
+        self.outports.insert(name.into().into_owned(), export);
+        Ok(())
+    }
+
+    /// Remove the exported input port `name`, the port of its node stays as it is
+    pub fn unexport_input(&mut self, name: &str) -> Result<ExportedPort> {
+        self.inports.remove(name).ok_or(result::Error::ExportNotFound(name.into()))
+    }
+
+    /// Remove the exported output port `name`, the port of its node stays connected
+    pub fn unexport_output(&mut self, name: &str) -> Result<ExportedPort> {
+        self.outports.remove(name).ok_or(result::Error::ExportNotFound(name.into()))
+    }
+
+    /// The exported input ports, by their name
+    pub fn exported_inputs(&self) -> &HashMap<String, ExportedPort> {
+        &self.inports
+    }
+
+    /// The exported output ports, by their name
+    pub fn exported_outputs(&self) -> &HashMap<String, ExportedPort> {
+        &self.outports
+    }
+
+    /// Get the sender of the exported input port `name`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let sender = try!(sched.get_exported_sender("IN"));
+    /// ```
+    pub fn get_exported_sender(&self, name: &str) -> Result<Box<Any + Send>> {
+        let export = self.inports.get(name).ok_or(result::Error::ExportNotFound(name.into()))?;
+        match export.element {
+            Some(ref element) => self.get_array_sender(&export.comp as &str, &export.port as &str, element as &str),
+            None => self.get_sender(&export.comp as &str, &export.port as &str),
+        }
+    }
+
+    /// Get the sender of the exported input port `name`, for the edge `T`, see `get_typed_sender`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(try!(sched.get_typed_exported_sender::<FsPath>("IN")).send(FsPath("app.fbp".into())));
+    /// ```
+    pub fn get_typed_exported_sender<T: 'static>(&self, name: &str) -> Result<MsgSender<T>> {
+        let export = self.inports.get(name).ok_or(result::Error::ExportNotFound(name.into()))?;
+        match export.element {
+            Some(ref element) => self.get_typed_array_sender::<T, _, _, _>(&export.comp as &str, &export.port as &str, element as &str),
+            None => self.get_typed_sender::<T, _, _>(&export.comp as &str, &export.port as &str),
+        }
+    }
+
+    /// Connect the exported output port `name` to a sender which is not an input port of the network
+    ///
+    /// As with `connect_sender`, the sender must be a `MsgSender` of the edge of the port, and the node cannot be a
+    /// pool.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let (recv, sender) = MsgReceiver::<CoreGraph>::unbounded(0, sched.sender.clone());
+    /// try!(sched.connect_exported_output("OUT", Box::new(sender)));
+    /// ```
+    pub fn connect_exported_output(&self, name: &str, sender: Box<Any + Send>) -> Result<()> {
+        let export = self.outports.get(name).ok_or(result::Error::ExportNotFound(name.into()))?;
+        let element = match export.element {
+            Some(ref element) => element.clone(),
+            None => { return self.connect_sender(&export.comp as &str, &export.port as &str, sender); }
+        };
+        self.check_local(&export.comp)?;
+        let comp = self.agents.get(&export.comp).ok_or(result::Error::NodeNotFound(export.comp.clone()))?;
+        if comp.pool.is_some() {
+            return Err(result::Error::Misc(format!("connect_exported_output : {} is a pool", export.comp)));
+        }
+        self.cache.get_schema_output_array(&comp.sort, &export.port)?;
+        self.sender.send(CompMsg::ConnectOutputArrayPort(comp.id, export.port.clone(), element, sender))
+            .ok().expect("Scheduler connect_exported_output: unable to send to scheduler state");
+        Ok(())
+    }
+
+    /// Receive the messages of the exported output port `name`, for the edge `T`, see `connect_exported_output`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let recv = try!(sched.get_exported_receiver::<CoreGraph>("OUT"));
+    /// let graph = try!(recv.recv());
+    /// ```
+    pub fn get_exported_receiver<T: Send + 'static>(&self, name: &str) -> Result<MsgReceiver<T>> {
+        // The receiver belongs to no agent : its messages never start one
+        let (recv, sender) = MsgReceiver::<T>::new(::std::usize::MAX, self.sender.clone(), false);
+        self.connect_exported_output(name, Box::new(sender))?;
+        Ok(recv)
+    }
+
+    // Downcast the sender of an input port, the error tells the edge of the port
+    fn typed<T: 'static, F>(&self, sender: Box<Any + Send>, comp: &str, port: &str, schema: F) -> Result<MsgSender<T>> where
+        F: Fn() -> Result<String>
+    {
+        match sender.downcast::<MsgSender<T>>() {
+            Ok(sender) => Ok(*sender),
+            Err(_) => Err(result::Error::TypeMismatch {
+                port: format!("{}() {}", comp, port),
+                expected: std::any::type_name::<T>().into(),
+                found: schema().unwrap_or_else(|_| "another edge".into()),
+            }),
+        }
+    }
+
     /// Get the edge of an input port
     ///
     /// # Example
@@ -634,7 +2239,7 @@ impl Scheduler {
     {
         let comp = comp.into();
         let port = port.into();
-        self.agents.get(&comp as &str).ok_or(result::Error::AgentNotFound(comp.to_string()))
+        self.agents.get(&comp as &str).ok_or(result::Error::NodeNotFound(comp.to_string()))
             .and_then(|c| {
                 self.cache.get_schema_input(&c.sort, &port as &str)
             })
@@ -652,7 +2257,7 @@ impl Scheduler {
     {
         let comp = comp.into();
         let port = port.into();
-        self.agents.get(&comp as &str).ok_or(result::Error::AgentNotFound(comp.to_string()))
+        self.agents.get(&comp as &str).ok_or(result::Error::NodeNotFound(comp.to_string()))
             .and_then(|c| {
                 self.cache.get_schema_input_array(&c.sort, &port as &str)
             })
@@ -670,7 +2275,7 @@ impl Scheduler {
     {
         let comp = comp.into();
         let port = port.into();
-        self.agents.get(&comp as &str).ok_or(result::Error::AgentNotFound(comp.to_string()))
+        self.agents.get(&comp as &str).ok_or(result::Error::NodeNotFound(comp.to_string()))
             .and_then(|c| {
                 self.cache.get_schema_output(&c.sort, &port as &str)
             })
@@ -688,12 +2293,165 @@ impl Scheduler {
     {
         let comp = comp.into();
         let port = port.into();
-        self.agents.get(&comp as &str).ok_or(result::Error::AgentNotFound(comp.to_string()))
+        self.agents.get(&comp as &str).ok_or(result::Error::NodeNotFound(comp.to_string()))
             .and_then(|c| {
                 self.cache.get_schema_output_array(&c.sort, &port as &str)
             })
     }
 
+    /// Watch the network every `period`, and report the deadlocks and the ports not read for longer than `starvation`
+    ///
+    /// The watchdog stops when the receiver is dropped.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let diagnostics = sched.watchdog(Duration::from_secs(1), Duration::from_secs(30));
+    /// for diagnostic in diagnostics {
+    ///     println!("{:?}", diagnostic);
+    /// }
+    /// ```
+    pub fn watchdog(&self, period: Duration, starvation: Duration) -> Receiver<Diagnostic> {
+        watchdog::watch(self.network.clone(), period, starvation)
+    }
+
+    /// The input ports and the edges of the network, as the watchdog sees them
+    pub fn network(&self) -> SharedNetwork {
+        self.network.clone()
+    }
+
+    // Give the input ports of an agent to the watchdog
+    fn watch_inputs(&self, name: &str, sort: &str, senders: &HashMap<String, Box<Any + Send>>) {
+        let mut network = self.network.lock().expect("watch_inputs : poisoned network");
+        for (port, sender) in senders {
+            // The option and the accumulator are not read like the other ports
+            if port == "option" || port == "accumulator" { continue; }
+            if let Ok(watch) = self.cache.watch_input(sort, port, sender) {
+                network.ports.insert((name.into(), port.clone()), watch);
+            }
+        }
+    }
+
+    // The dead letters of the messages of `agent` `port` rejected by a remote scheduler
+    fn remote_rejected(&self, agent: String, port: String) -> RemoteRejected {
+        let dead_letters = self.dead_letters.clone();
+        Box::new(move |bytes| {
+            dead_letters.lock().expect("remote_rejected : poisoned dead letters").push(DeadLetter {
+                agent: agent.clone(),
+                port: port.clone(),
+                reason: Reason::RemoteRejected,
+                time: SystemTime::now(),
+                msg: Box::new(bytes),
+            });
+        })
+    }
+
+    // Record an edge for the watchdog
+    fn link(&self, out_agent: &str, out_port: String, in_agent: &str, in_port: String) {
+        self.network.lock().expect("link : poisoned network").links.push(Link {
+            out_agent: out_agent.into(),
+            out_port: out_port,
+            in_agent: in_agent.into(),
+            in_port: in_port,
+        });
+    }
+
+    // Forget a removed agent in the network, and close the input ports that only it fed : the downstream agents get
+    // the end of stream and drain, instead of waiting for messages that will never come
+    fn close_downstream(&self, name: &str) {
+        self.traces.lock().expect("close_downstream : poisoned traces").retain(|&(ref agent, _), _| agent != name);
+        let inputs: Vec<(String, String)> = {
+            let mut network = self.network.lock().expect("close_downstream : poisoned network");
+            let downstream: Vec<Link> = network.links.iter()
+                .filter(|l| l.out_agent == name && l.in_agent != name)
+                .cloned()
+                .collect();
+            network.remove_agent(name);
+            downstream.into_iter()
+                .filter(|l| !network.links.iter().any(|other| other.in_agent == l.in_agent && other.in_port == l.in_port))
+                .map(|l| (l.in_agent, l.in_port))
+                .collect()
+        };
+        for (agent, port) in inputs {
+            if let Err(e) = self.close_input(&agent, &port) {
+                log::warn!("cannot close {} of {} : {}", port, agent, e);
+            }
+        }
+    }
+
+    // Close an input port, `port` or `port[element]`
+    fn close_input(&self, agent: &str, port: &str) -> Result<()> {
+        // The agent of an isolated node closes its own ports when its process ends
+        if self.isolated.contains_key(agent) {
+            return Ok(());
+        }
+        let comp = self.agents.get(agent).ok_or(result::Error::NodeNotFound(agent.into()))?;
+        let not_found = || result::Error::PortNotFound(agent.into(), port.into());
+        match port.find('[') {
+            Some(i) if port.ends_with(']') => {
+                let (port, element) = (&port[..i], &port[i + 1..port.len() - 1]);
+                let sender = comp.inputs_array.get(port).and_then(|p| p.get(element)).ok_or_else(not_found)?;
+                self.cache.close_input(&comp.sort, port, sender)
+            }
+            _ => {
+                let sender = comp.inputs.get(port).ok_or_else(not_found)?;
+                self.cache.close_input(&comp.sort, port, sender)
+            }
+        }
+    }
+
+    // Fails for an isolated node, whose agent is not in this process
+    fn check_local(&self, name: &str) -> Result<()> {
+        if self.isolated.contains_key(name) {
+            return Err(result::Error::Misc(format!("{} is an isolated node, see add_isolated_node", name)));
+        }
+        Ok(())
+    }
+
+    // The id of an agent, for an operation `op` which a pool can't do
+    fn agent_id(&self, name: &str, comp: &Comp, op: &str) -> Result<usize> {
+        if comp.pool.is_some() {
+            return Err(result::Error::Misc(format!("{} : {} is a pool", op, name)));
+        }
+        Ok(comp.id)
+    }
+
+    // The agent, or the instances of the pool
+    fn instance_ids(&self, comp: &Comp) -> Vec<usize> {
+        match comp.pool {
+            Some(ref pool) => pool.instances.iter().filter_map(|i| self.agents.get(i).map(|c| c.id)).collect(),
+            None => vec![comp.id],
+        }
+    }
+
+    // Connect the output port of an agent, or of all the instances of a pool
+    fn connect_output<F>(&self, comp: &Comp, port: String, element: Option<String>, get_sender: F) -> Result<()> where
+        F: Fn() -> Result<Box<Any + Send>>
+    {
+        if let Some(name) = self.isolated.keys().find(|name| self.agents.get(*name).map(|c| c.id) == Some(comp.id)) {
+            self.check_local(name)?;
+        }
+        let ids = self.instance_ids(comp);
+        let ordered = comp.pool.as_ref().map(|p| p.ordered).unwrap_or(false);
+        let senders = if ordered && element.is_none() {
+            let target = get_sender()?;
+            self.cache.pool_output(&comp.sort, &port, &target, ids.len(), self.sender.clone())?
+        } else {
+            let mut senders = vec![];
+            for _ in &ids {
+                senders.push(get_sender()?);
+            }
+            senders
+        };
+        for (id, sender) in ids.into_iter().zip(senders) {
+            let msg = match element {
+                Some(ref element) => CompMsg::ConnectOutputArrayPort(id, port.clone(), element.clone(), sender),
+                None => CompMsg::ConnectOutputPort(id, port.clone(), sender),
+            };
+            self.sender.send(msg).ok().expect("Scheduler connect: unable to send to scheduler state");
+        }
+        Ok(())
+    }
+
     /// Wait for the end of the scheduler
     ///
     /// # Example
@@ -707,6 +2465,18 @@ impl Scheduler {
     }
 }
 
+/// True if an output port with the edge `out_schema` can be connected to an input port with the edge `in_schema`
+///
+/// The edge of a `SerdeEdge` port is its Rust type, as written in the agent macro.
+pub fn compatible_schema(out_schema: &str, in_schema: &str) -> bool {
+    if in_schema == "any" || out_schema == "any" {
+        return true;
+    }
+    let out_schema: String = out_schema.chars().filter(|c| !c.is_whitespace()).collect();
+    let in_schema: String = in_schema.chars().filter(|c| !c.is_whitespace()).collect();
+    out_schema == in_schema
+}
+
 enum EditCmp {
     AddInputArrayElement(String, String, Box<Any + Send>),
     RemoveInputArrayElement(String, String),
@@ -716,6 +2486,9 @@ enum EditCmp {
     SetReceiver(String, Box<Any + Send>),
     Disconnect(String),
     DisconnectArray(String, String),
+    Tap(String, Option<(Box<Any + Send>, f64)>),
+    Trace(String, Option<SharedTrace>),
+    Restore(Vec<u8>),
 }
 
 /// To be removed, replace by async msg
@@ -733,6 +2506,88 @@ struct CompState {
     can_run: bool,
     edit_msgs: Vec<EditCmp>,
     ips: isize,
+    /// Set when the agent must be removed at the end of its current execution
+    remove: Option<Sender<SyncMsg>>,
+    /// The flush timeout of the batch port, in ms
+    batch_flush: u64,
+    flush_armed: bool,
+    /// Set when a high priority message waits, the next run overtakes the other agents
+    urgent: bool,
+    /// A paused agent keeps its messages, but does not run
+    paused: bool,
+    /// Set when the agent should have run while it was paused or waiting
+    pending: bool,
+    /// The agents which are not ready yet, see `Scheduler::start_after`
+    after: Vec<usize>,
+    /// Set once the agent called `ready`
+    is_ready: bool,
+    /// The limits of the agent, if added with `add_node_with_limits`
+    limit: Option<Arc<LimitState>>,
+    /// The state of the agent when its limits were set, for `LimitAction::Restart`
+    initial: Option<Vec<u8>>,
+    /// The executions of the agent since its limits were set
+    runs: u64,
+    /// Set when the agent must be restarted at the end of its current execution
+    restart: bool,
+    /// Interrupts the receives of an agent with a live option
+    interrupt: Option<Interrupt>,
+    /// Cancels the current execution of the agent, for `LimitAction::Cancel`
+    cancel: Option<Cancel>,
+    /// The input port the agent waits for, set by `Signal::WaitFor`
+    wait_for: Option<Arc<PortWatch>>,
+    /// The thread of the agent, if set with `Scheduler::set_placement` or if the agent is blocking
+    thread: Option<Sender<Job>>,
+    /// The start of the last execution of the agent
+    last_run: Option<SystemTime>,
+}
+
+type Job = Box<FnOnce() + Send>;
+
+/// The agents ready to run on the workers, the high priority ones first
+#[derive(Default)]
+struct ReadyQueue {
+    high: VecDeque<Job>,
+    normal: VecDeque<Job>,
+}
+
+impl ReadyQueue {
+    fn push(&mut self, priority: Priority, job: Job) {
+        match priority {
+            Priority::High => self.high.push_back(job),
+            Priority::Normal => self.normal.push_back(job),
+        }
+    }
+
+    fn pop(&mut self) -> Option<Job> {
+        self.high.pop_front().or_else(|| self.normal.pop_front())
+    }
+}
+
+/// A checkpoint waiting for the end of the running agents
+struct Checkpoint {
+    reply: Sender<Result<HashMap<String, Vec<u8>>>>,
+    snapshots: HashMap<String, Vec<u8>>,
+    waiting: HashSet<usize>,
+    error: Option<result::Error>,
+}
+
+impl Checkpoint {
+    fn add(&mut self, name: &str, comp: &BoxedComp) {
+        match comp.snapshot() {
+            Ok(ref bytes) if bytes.is_empty() => {},
+            Ok(bytes) => { self.snapshots.insert(name.into(), bytes); },
+            Err(e) => { self.error = Some(e); },
+        }
+    }
+
+    fn send(self) {
+        let res = match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.snapshots),
+        };
+        // The caller may be gone
+        let _ = self.reply.send(res);
+    }
 }
 
 /// The state of the internal scheduler
@@ -742,16 +2597,86 @@ struct SchedState {
     running: usize,
     can_halt: bool,
     pool: ThreadPool,
+    ready: Arc<Mutex<ReadyQueue>>,
+    runtime: Runtime,
+    checkpoint: Option<Checkpoint>,
+    dead_letters: SharedDeadLetters,
+    /// The traced output ports, a failure of the agent at their other end is kept in them
+    traces: SharedTraces,
+    supervisor: Option<Sender<Violation>>,
+    /// The input ports of the agents, for `Signal::WaitFor`
+    network: SharedNetwork,
+    /// Waiting for the network to be idle, see `Scheduler::wait_idle`
+    idle_waiters: Vec<Sender<()>>,
+}
+
+// The error of a run which panicked, with the message of the panic
+fn panicked(payload: Box<Any + Send>) -> result::Error {
+    let msg = payload.downcast_ref::<&str>().map(|msg| msg.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "no message".into());
+    result::Error::Misc(format!("the agent panicked : {}", msg))
+}
+
+// Catch the panics of a future, like `panic::catch_unwind` for a function
+struct CatchUnwind<F>(F);
+
+impl<F: Future + Unpin> Future for CatchUnwind<F> {
+    type Output = thread::Result<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext) -> Poll<Self::Output> {
+        let future = &mut self.0;
+        match panic::catch_unwind(AssertUnwindSafe(|| Pin::new(future).poll(cx))) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(res)) => Poll::Ready(Ok(res)),
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    }
 }
 
 impl SchedState {
-    fn new(s: Sender<CompMsg>) -> Self {
+    fn new(s: Sender<CompMsg>, workers: usize, dead_letters: SharedDeadLetters, network: SharedNetwork,
+           traces: SharedTraces) -> Self {
         SchedState {
             sched_sender: s,
             agents: HashMap::new(),
             running: 0,
             can_halt: false,
-            pool: ThreadPool::new(8),
+            pool: ThreadPoolBuilder::new()
+                .num_threads(workers)
+                .thread_name(|i| format!("rustfbp-worker-{}", i))
+                // The panics of the agents are caught in their job, see `run`
+                .panic_handler(|_| log::error!("a worker panicked"))
+                .build()
+                .expect("SchedState new : cannot create the worker threads"),
+            ready: Arc::new(Mutex::new(ReadyQueue::default())),
+            runtime: Builder::new_multi_thread()
+                .enable_all()
+                .thread_name("rustfbp-async")
+                .build()
+                .expect("SchedState new : cannot create the async runtime"),
+            checkpoint: None,
+            dead_letters: dead_letters,
+            traces: traces,
+            supervisor: None,
+            network: network,
+            idle_waiters: vec![],
+        }
+    }
+
+    // Reply to the waiters once no agent executes, and no agent which can run has messages or a batch to flush
+    fn check_idle(&mut self) {
+        if self.idle_waiters.is_empty() {
+            return;
+        }
+        let busy = self.agents.values().any(|comp| {
+            comp.comp.is_none() || comp.flush_armed || (comp.ips > 0 && !comp.paused && comp.after.is_empty() && Self::awaited(comp))
+        });
+        if !busy {
+            for waiter in self.idle_waiters.drain(..) {
+                // The caller may be gone
+                let _ = waiter.send(());
+            }
         }
     }
 
@@ -760,7 +2685,7 @@ impl SchedState {
         let mut start = false;
         if let Some(ref mut comp) = self.agents.get_mut(&id) {
             comp.ips += 1;
-            start = comp.ips > 0 && comp.comp.is_some();
+            start = comp.ips > 0 && comp.comp.is_some() && Self::awaited(comp);
         }
         if start { self.run(id); }
         Ok(())
@@ -769,12 +2694,57 @@ impl SchedState {
     fn dec(&mut self, id: usize) -> Result<()> {
         // silent error for exterior ports
         if let Some(ref mut comp) = self.agents.get_mut(&id) {
-            comp.ips -= 1;
+            comp.ips -= 1;
+        }
+        Ok(())
+    }
+
+    fn arm_flush(&mut self, id: usize) -> Result<()> {
+        if let Some(ref mut comp) = self.agents.get_mut(&id) {
+            if !comp.flush_armed {
+                comp.flush_armed = true;
+                let delay = Duration::from_millis(comp.batch_flush);
+                let sched_s = self.sched_sender.clone();
+                self.runtime.spawn(async move {
+                    tokio::time::sleep(delay).await;
+                    let _ = sched_s.send(CompMsg::Flush(id));
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn urgent(&mut self, id: usize) -> Result<()> {
+        if let Some(ref mut comp) = self.agents.get_mut(&id) {
+            comp.urgent = true;
+        }
+        Ok(())
+    }
+
+    fn option_changed(&mut self, id: usize) -> Result<()> {
+        if let Some(ref comp) = self.agents.get(&id) {
+            if let Some(ref interrupt) = comp.interrupt {
+                interrupt.fire();
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, id: usize) -> Result<()> {
+        let mut start = false;
+        if let Some(ref mut comp) = self.agents.get_mut(&id) {
+            comp.flush_armed = false;
+            start = comp.comp.is_some();
         }
+        if start { self.run(id); }
         Ok(())
     }
 
-    fn new_agent(&mut self, id: usize, name: String, comp: BoxedComp) -> Result<()> {
+    fn new_agent(&mut self, id: usize, name: String, mut comp: BoxedComp) -> Result<()> {
+        comp.set_log(Context::new(name.clone()));
+        let batch_flush = comp.batch_flush();
+        let interrupt = comp.interrupt();
+        let cancel = comp.cancel();
         self.agents.insert(id, CompState {
             comp: Some(comp),
             name: name,
@@ -782,23 +2752,52 @@ impl SchedState {
             can_run: false,
             edit_msgs: vec![],
             ips: 0,
+            remove: None,
+            batch_flush: batch_flush,
+            flush_armed: false,
+            urgent: false,
+            paused: false,
+            pending: false,
+            after: vec![],
+            is_ready: false,
+            limit: None,
+            initial: None,
+            runs: 0,
+            restart: false,
+            interrupt: interrupt,
+            cancel: cancel,
+            wait_for: None,
+            thread: None,
+            last_run: None,
         });
         Ok(())
     }
 
     fn remove(&mut self, id: usize, sync_sender: Sender<SyncMsg>) -> Result<()>{
-        let must_remove = {
-            let mut o_comp = self.agents.get_mut(&id).expect("SchedState remove : agent doesn't exist");
-            let b_comp = mem::replace(&mut o_comp.comp, None);
-            if let Some(boxed_comp) = b_comp {
-                sync_sender.send(SyncMsg::Remove(boxed_comp)).expect("SchedState remove : cannot send to the channel");
-                true
-            } else {
-                sync_sender.send(SyncMsg::CannotRemove).expect("SchedState remove : cannot send to the channel");
-                false
+        let boxed_comp = {
+            let mut o_comp = match self.agents.get_mut(&id) {
+                Some(comp) => comp,
+                None => {
+                    sync_sender.send(SyncMsg::CannotRemove).expect("SchedState remove : cannot send to the channel");
+                    return Ok(());
+                }
+            };
+            match mem::replace(&mut o_comp.comp, None) {
+                Some(boxed_comp) => {
+                    if o_comp.is_run {
+                        self.running -= 1;
+                    }
+                    boxed_comp
+                }
+                None => {
+                    // The agent is running, it will be removed in run_end
+                    o_comp.remove = Some(sync_sender);
+                    return Ok(());
+                }
             }
         };
-        if must_remove { self.agents.remove(&id); }
+        self.removed(id);
+        sync_sender.send(SyncMsg::Remove(boxed_comp)).expect("SchedState remove : cannot send to the channel");
         Ok(())
     }
 
@@ -814,6 +2813,252 @@ impl SchedState {
         Ok(())
     }
 
+    // What the agent is doing, None if it doesn't exist
+    fn status(&self, id: usize) -> Option<(NodeState, Option<SystemTime>)> {
+        let comp = self.agents.get(&id)?;
+        let state = if comp.paused {
+            NodeState::Paused
+        } else if comp.comp.is_none() {
+            let network = self.network.lock().expect("SchedState status : poisoned network");
+            let receiving = network.ports.iter()
+                .any(|(&(ref agent, _), watch)| *agent == comp.name && watch.receiving.load(Ordering::SeqCst));
+            if receiving { NodeState::Blocked } else { NodeState::Running }
+        } else if !comp.after.is_empty() {
+            NodeState::Waiting
+        } else if comp.is_run || !comp.can_run || comp.last_run.is_none() {
+            NodeState::Idle
+        } else {
+            NodeState::Ended
+        };
+        Some((state, comp.last_run))
+    }
+
+    fn pause(&mut self, id: usize) -> Result<()> {
+        if let Some(ref mut comp) = self.agents.get_mut(&id) {
+            comp.paused = true;
+        }
+        Ok(())
+    }
+
+    fn resume(&mut self, id: usize) -> Result<()> {
+        let mut start = false;
+        if let Some(ref mut comp) = self.agents.get_mut(&id) {
+            comp.paused = false;
+            start = (comp.pending || comp.ips > 0) && comp.comp.is_some() && Self::awaited(comp);
+            comp.pending = false;
+        }
+        if start { self.run(id); }
+        Ok(())
+    }
+
+    fn pause_all(&mut self) -> Result<()> {
+        for comp in self.agents.values_mut() {
+            comp.paused = true;
+        }
+        Ok(())
+    }
+
+    fn resume_all(&mut self) -> Result<()> {
+        let ids: Vec<usize> = self.agents.keys().cloned().collect();
+        for id in ids {
+            self.resume(id)?;
+        }
+        Ok(())
+    }
+
+    fn after(&mut self, id: usize, after: usize) -> Result<()> {
+        // An agent already ready, or gone, holds nothing
+        if self.agents.get(&after).map_or(true, |comp| comp.is_ready) {
+            return Ok(());
+        }
+        if let Some(ref mut comp) = self.agents.get_mut(&id) {
+            if !comp.after.contains(&after) {
+                comp.after.push(after);
+            }
+        }
+        Ok(())
+    }
+
+    fn ready(&mut self, id: usize) -> Result<()> {
+        match self.agents.get_mut(&id) {
+            Some(comp) => { comp.is_ready = true; },
+            None => { return Ok(()); },
+        }
+        self.release(id);
+        Ok(())
+    }
+
+    // Run the agents held by `id`, which is ready or removed
+    fn release(&mut self, id: usize) {
+        let waiting: Vec<usize> = self.agents.iter()
+            .filter(|&(_, comp)| comp.after.contains(&id))
+            .map(|(id, _)| *id)
+            .collect();
+        for waiting_id in waiting {
+            let mut start = false;
+            if let Some(ref mut comp) = self.agents.get_mut(&waiting_id) {
+                comp.after.retain(|after| *after != id);
+                if comp.after.is_empty() && !comp.paused {
+                    start = (comp.pending || comp.ips > 0) && comp.comp.is_some() && Self::awaited(comp);
+                    comp.pending = false;
+                }
+            }
+            if start { self.run(waiting_id); }
+        }
+    }
+
+    // An agent removed before it is ready releases the agents it holds, they would wait for good
+    fn removed(&mut self, id: usize) {
+        if let Some(comp) = self.agents.remove(&id) {
+            let held = self.agents.values().filter(|c| c.after.contains(&id)).count();
+            if !comp.is_ready && held > 0 {
+                log::warn!(target: comp.name.as_str(), "removed before it was ready, the {} agents started after it run now", held);
+            }
+            self.release(id);
+        }
+    }
+
+    fn checkpoint(&mut self, reply: Sender<Result<HashMap<String, Vec<u8>>>>) -> Result<()> {
+        let mut checkpoint = Checkpoint {
+            reply: reply,
+            snapshots: HashMap::new(),
+            waiting: HashSet::new(),
+            error: None,
+        };
+        for (id, comp) in &self.agents {
+            match comp.comp {
+                Some(ref c) => checkpoint.add(&comp.name, c),
+                // Running, saved in run_end
+                None => { checkpoint.waiting.insert(*id); },
+            }
+        }
+        if checkpoint.waiting.is_empty() {
+            checkpoint.send();
+        } else {
+            self.checkpoint = Some(checkpoint);
+        }
+        Ok(())
+    }
+
+    // Keep the last messages of the traced edges to a failed agent with its error
+    fn trace_failure(network: &SharedNetwork, traces: &SharedTraces, name: &str, error: &result::Error) {
+        let traces = traces.lock().expect("SchedState trace_failure : poisoned traces");
+        if traces.is_empty() {
+            return;
+        }
+        let network = network.lock().expect("SchedState trace_failure : poisoned network");
+        for link in network.links.iter().filter(|l| l.in_agent == name) {
+            if let Some(trace) = traces.get(&(link.out_agent.clone(), link.out_port.clone())) {
+                trace.fail(name, error.to_string());
+                log::error!(target: name, "the last messages of {}() {} are kept, see Scheduler::trace_failure",
+                            link.out_agent, link.out_port);
+            }
+        }
+    }
+
+    fn dead_letter(&mut self, id: usize, port: String, reason: Reason, msg: Box<Any + Send>) -> Result<()> {
+        let agent = self.agents.get(&id).map(|comp| comp.name.clone()).unwrap_or_else(|| "?".into());
+        self.dead_letters.lock().expect("SchedState dead_letter : poisoned dead letters").push(DeadLetter {
+            agent: agent,
+            port: port,
+            reason: reason,
+            time: SystemTime::now(),
+            msg: msg,
+        });
+        Ok(())
+    }
+
+    fn set_limits(&mut self, id: usize, limit: Arc<LimitState>) -> Result<()> {
+        if let Some(ref mut comp) = self.agents.get_mut(&id) {
+            if limit.limits.action == LimitAction::Restart {
+                // Just added, the agent is not running yet
+                if let Some(ref c) = comp.comp {
+                    comp.initial = Some(c.snapshot()?);
+                }
+            }
+            comp.limit = Some(limit);
+        }
+        Ok(())
+    }
+
+    fn set_placement(&mut self, id: usize, placement: Placement) -> Result<()> {
+        if let Some(ref mut comp) = self.agents.get_mut(&id) {
+            // The previous thread ends with its sender
+            comp.thread = Some(affinity::spawn(&comp.name, placement)?);
+        }
+        Ok(())
+    }
+
+    fn queue_limit(&mut self, id: usize, bytes: usize) -> Result<()> {
+        if let Some(comp) = self.agents.get_mut(&id) {
+            Self::report(&mut self.supervisor, Violation::QueuedBytes(comp.name.clone(), bytes));
+            let restart = comp.limit.as_ref().map(|l| l.limits.action == LimitAction::Restart).unwrap_or(false);
+            if restart {
+                // A running agent is restarted at the end of its execution
+                match comp.comp.take() {
+                    Some(mut box_comp) => {
+                        let res = Self::restart(comp, &mut box_comp);
+                        comp.comp = Some(box_comp);
+                        res?;
+                    }
+                    None => { comp.restart = true; }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn run_timeout(&mut self, id: usize, run: u64) -> Result<()> {
+        if let Some(ref mut comp) = self.agents.get_mut(&id) {
+            // Over only if the agent is still in the same execution
+            if comp.runs != run || comp.comp.is_some() {
+                return Ok(());
+            }
+            if let Some(limit) = comp.limit.clone() {
+                limit.over_time.store(true, Ordering::SeqCst);
+                let max = limit.limits.max_run_time.unwrap_or_default();
+                Self::report(&mut self.supervisor, Violation::RunTime(comp.name.clone(), max));
+                match limit.limits.action {
+                    LimitAction::Restart => { comp.restart = true; },
+                    LimitAction::Cancel => {
+                        if let Some(ref cancel) = comp.cancel {
+                            cancel.cancel();
+                        }
+                    },
+                    _ => {},
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Log a violation and send it to the supervisor
+    fn report(supervisor: &mut Option<Sender<Violation>>, violation: Violation) {
+        log::warn!("limit exceeded : {:?}", violation);
+        if let Some(ref s) = *supervisor {
+            if s.send(violation).is_err() {
+                *supervisor = None;
+            }
+        }
+    }
+
+    // Set back the agent to its initial state, and drop its waiting messages
+    fn restart(comp: &mut CompState, box_comp: &mut BoxedComp) -> Result<()> {
+        comp.restart = false;
+        if let Some(ref limit) = comp.limit {
+            limit.purge();
+        }
+        match comp.initial {
+            Some(ref bytes) if !bytes.is_empty() => box_comp.restore(bytes),
+            _ => Ok(()),
+        }
+    }
+
+    // True if the port the agent waits for has a message, or if it doesn't wait for a port
+    fn awaited(comp: &CompState) -> bool {
+        comp.wait_for.as_ref().map_or(true, |watch| watch.queued.load(Ordering::SeqCst) > 0)
+    }
+
     fn halt(&mut self) -> Result<()> {
         self.can_halt = true;
         if self.running <= 0 {
@@ -823,23 +3068,87 @@ impl SchedState {
     }
 
     fn run_end(&mut self, id: usize, mut box_comp: BoxedComp, res: Result<Signal>) -> Result<()>{
-        let must_restart = {
+        // A receive interrupted by a new option, or on a closed port, ends the execution, it's not a failure
+        let res = match res {
+            Err(result::Error::Interrupted) | Err(result::Error::Closed) => Ok(Signal::End),
+            res => res,
+        };
+        let (must_restart, removed) = {
             let mut comp = self.agents.get_mut(&id).expect("SchedState RunEnd : agent doesn't exist");
+            // Neither is a cancellation over the run time, already reported to the supervisor
+            let res = match res {
+                Err(result::Error::Cancelled) => {
+                    log::warn!(target: comp.name.as_str(), "cancelled over its run time");
+                    Ok(Signal::End)
+                },
+                res => res,
+            };
             for msg in comp.edit_msgs.drain(..) {
-                try!(Self::edit_one_comp(&mut box_comp, msg));
+                Self::edit_one_comp(&mut box_comp, msg)?;
+            }
+            if let Err(ref e) = res {
+                log::error!(target: comp.name.as_str(), "fails : {}", e);
+                Self::trace_failure(&self.network, &self.traces, &comp.name, e);
             }
-            let must_restart = comp.ips > 0;
-            comp.comp = Some(box_comp);
-            if let Ok(Signal::End) = res {
+            if let Some(ref limit) = comp.limit {
+                limit.over_time.store(false, Ordering::SeqCst);
+                limit.made_room();
+            }
+            if comp.restart {
+                Self::restart(comp, &mut box_comp)?;
+            }
+            let checkpoint_done = match self.checkpoint {
+                Some(ref mut checkpoint) => {
+                    if checkpoint.waiting.remove(&id) {
+                        checkpoint.add(&comp.name, &box_comp);
+                        checkpoint.waiting.is_empty()
+                    } else {
+                        false
+                    }
+                }
+                None => false,
+            };
+            if checkpoint_done {
+                if let Some(checkpoint) = self.checkpoint.take() {
+                    checkpoint.send();
+                }
+            }
+            if let Some(sync_sender) = comp.remove.take() {
                 if comp.is_run {
                     self.running -= 1;
-                    comp.is_run = false;
                 }
-            } else if let Err(e) = res {
-                println!("{} fails : {}", comp.name, e);
+                sync_sender.send(SyncMsg::Remove(box_comp)).expect("SchedState RunEnd : cannot send to the channel");
+                (false, true)
+            } else {
+                comp.wait_for = match res {
+                    Ok(Signal::WaitFor(ref port)) => {
+                        let watch = self.network.lock().expect("SchedState RunEnd : poisoned network")
+                            .ports.get(&(comp.name.clone(), port.clone())).cloned();
+                        if watch.is_none() {
+                            log::error!(target: comp.name.as_str(), "waits for the port {}, which doesn't exist", port);
+                        }
+                        watch
+                    },
+                    _ => None,
+                };
+                let must_restart = match res {
+                    Ok(Signal::Yield) => true,
+                    _ => comp.ips > 0 && Self::awaited(comp),
+                };
+                comp.comp = Some(box_comp);
+                // A failed run, a panic included, ends the agent until its next message, so the scheduler can halt
+                if let Ok(Signal::End) | Err(_) = res {
+                    if comp.is_run {
+                        self.running -= 1;
+                        comp.is_run = false;
+                    }
+                }
+                (must_restart, false)
             }
-            must_restart
         };
+        if removed {
+            self.removed(id);
+        }
         if must_restart {
             self.run(id);
         } else {
@@ -852,16 +3161,71 @@ impl SchedState {
     #[allow(unused_must_use)]
     fn run(&mut self, id: usize) {
         let mut o_comp = self.agents.get_mut(&id).expect("SchedSate run : agent doesn't exist");
+        if o_comp.paused || !o_comp.after.is_empty() {
+            o_comp.pending = true;
+            return;
+        }
         if let Some(mut b_comp) = mem::replace(&mut o_comp.comp, None) {
+            if let Some(ref cancel) = o_comp.cancel {
+                cancel.reset();
+            }
+            let blocking = b_comp.is_blocking();
+            let priority = if mem::replace(&mut o_comp.urgent, false) { Priority::High } else { Priority::Normal };
             if !o_comp.is_run {
                 self.running += 1;
                 o_comp.is_run = true;
             }
+            o_comp.last_run = Some(SystemTime::now());
             let sched_s = self.sched_sender.clone();
-            self.pool.execute(move || {
-                let res = b_comp.run();
+            let max_run_time = o_comp.limit.as_ref().and_then(|limit| limit.limits.max_run_time);
+            if let Some(max) = max_run_time {
+                o_comp.runs += 1;
+                let run = o_comp.runs;
+                let sched_s = sched_s.clone();
+                self.runtime.spawn(async move {
+                    tokio::time::sleep(max).await;
+                    let _ = sched_s.send(CompMsg::RunTimeout(id, run));
+                });
+            }
+            if b_comp.is_async() {
+                self.runtime.spawn(async move {
+                    let res = match b_comp.run_async() {
+                        Some(run) => CatchUnwind(run).await.unwrap_or_else(|payload| Err(panicked(payload))),
+                        None => Err(result::Error::Misc("the agent is not async".into())),
+                    };
+                    sched_s.send(CompMsg::RunEnd(id, b_comp, res)).expect("SchedState run : unable to send RunEnd");
+                });
+                return;
+            }
+            // A panic ends the run with an error, so the agent is not lost and the scheduler can still halt
+            let job = move || {
+                let res = panic::catch_unwind(AssertUnwindSafe(|| b_comp.run()))
+                    .unwrap_or_else(|payload| Err(panicked(payload)));
                 sched_s.send(CompMsg::RunEnd(id, b_comp, res)).expect("SchedState run : unable to send RunEnd");
-            });
+            };
+            if blocking && o_comp.thread.is_none() {
+                // Kept for the next runs, it ends with the agent
+                match affinity::spawn(&o_comp.name, Placement::default()) {
+                    Ok(thread) => { o_comp.thread = Some(thread); },
+                    Err(e) => { log::error!(target: o_comp.name.as_str(), "{}, it runs on the workers", e); },
+                }
+            }
+            if let Some(ref thread) = o_comp.thread {
+                if thread.send(Box::new(job)).is_err() {
+                    // The thread died with a panic of the agent
+                    log::error!(target: o_comp.name.as_str(), "the thread of the agent is gone");
+                }
+            } else {
+                // Each task of the pool runs the most urgent ready agent, not necessarily this one
+                self.ready.lock().expect("SchedState run : poisoned ready queue").push(priority, Box::new(job));
+                let ready = self.ready.clone();
+                self.pool.spawn(move || {
+                    let job = ready.lock().expect("SchedState run : poisoned ready queue").pop();
+                    if let Some(job) = job {
+                        job();
+                    }
+                });
+            }
         };
     }
 
@@ -869,7 +3233,7 @@ impl SchedState {
         let mut comp = self.agents.get_mut(&id).expect("SchedState edit_agent : agent doesn't exist");
         if let Some(ref mut c) = comp.comp {
             let mut c = c;
-            try!(Self::edit_one_comp(&mut c, msg));
+            Self::edit_one_comp(&mut c, msg)?;
         } else {
             comp.edit_msgs.push(msg);
         }
@@ -901,36 +3265,126 @@ impl SchedState {
                 unimplemented!();
                 //c.set_receiver(port, hir);
             }
-            EditCmp::Disconnect(_port) => {
-                unimplemented!();
-                //try!(c.disconnect(port));
+            EditCmp::Disconnect(port) => {
+                c.disconnect(&port)?;
             },
-            EditCmp::DisconnectArray(_port, _element) => {
-                unimplemented!();
-                //try!(c.disconnect_array(port, element));
+            EditCmp::DisconnectArray(port, element) => {
+                c.disconnect_array(&port, &element)?;
+            },
+            EditCmp::Tap(port, tap) => {
+                c.tap(&port, tap)?;
+            },
+            EditCmp::Trace(port, trace) => {
+                c.trace(&port, trace)?;
+            },
+            EditCmp::Restore(bytes) => {
+                c.restore(&bytes)?;
             },
         }
         Ok(())
     }
 }
 
+/// The prefix of the sort of a mock agent
+const MOCK_PREFIX: &str = "mock:";
+
+// Split the sort of a generic agent, `path<Edge>`, in the path of its dylib and its edge
+fn generic_sort(sort: &str) -> Result<(&str, &str)> {
+    match sort.rfind('<') {
+        Some(i) if i + 2 < sort.len() && sort.ends_with('>') => Ok((&sort[..i], &sort[i + 1..sort.len() - 1])),
+        _ => Err(result::Error::Misc(format!("bad sort {}, expected path<Edge>", sort))),
+    }
+}
+
+// Refuse a dylib built with another compiler or another rustfbp, before calling any of its functions : only
+// same toolchain builds are supported
+fn check_abi(lib: &libloading::Library, path: &str) -> Result<()> {
+    let abi = AgentAbi::current();
+    let found = unsafe {
+        lib.get::<extern "C" fn() -> AgentAbi>(b"rustfbp_abi\0")
+            .map(|rustfbp_abi| rustfbp_abi())
+            .ok()
+    };
+    match found {
+        Some(found) if found == abi => Ok(()),
+        // Built before the ABI was exported
+        None => Err(result::Error::BadAbi(path.into(), "none".into(), abi.to_string())),
+        Some(found) => Err(result::Error::BadAbi(path.into(), found.to_string(), abi.to_string())),
+    }
+}
+
+// A function exported by the dylib of an agent, a missing one makes the dylib an invalid agent
+unsafe fn symbol<T: Copy>(lib: &libloading::Library, path: &str, name: &str) -> Result<T> {
+    let mut symbol = name.as_bytes().to_vec();
+    symbol.push(0);
+    lib.get::<T>(&symbol)
+        .map(|symbol| *symbol)
+        .map_err(|_| result::Error::InvalidComponent { path: path.into(), missing_symbol: name.into() })
+}
+
 /// Contains all the information of a dylib agents
 #[allow(dead_code)]
 pub struct AgentLoader {
-    lib: libloading::Library,
-    create: extern "C" fn(usize, Sender<CompMsg>) -> Result<(Box<Agent + Send>, HashMap<String, Box<Any + Send>>)>,
+    /// None for a wasm agent or a mock
+    lib: Option<libloading::Library>,
+    create: Box<Fn(usize, Sender<CompMsg>) -> Result<(Box<Agent + Send>, HashMap<String, Box<Any + Send>>)>>,
     clone_input: extern "C" fn(&str, &Box<Any + Send>) -> Result<Box<Any + Send>>,
     clone_input_array: extern "C" fn(&str, &Box<Any + Send>) -> Result<Box<Any + Send>>,
     create_input_array: extern "C" fn(&str, usize, Sender<CompMsg>, bool) -> Result<(Box<Any + Send>, Box<Any + Send>)>,
-    get_schema_input: extern "C" fn(&str) -> Result<String>,
-    get_schema_input_array: extern "C" fn(&str) -> Result<String>,
-    get_schema_output: extern "C" fn(&str) -> Result<String>,
-    get_schema_output_array: extern "C" fn(&str) -> Result<String>,
+    get_schema_input: Box<Fn(&str) -> Result<String>>,
+    get_schema_input_array: Box<Fn(&str) -> Result<String>>,
+    get_schema_output: Box<Fn(&str) -> Result<String>>,
+    get_schema_output_array: Box<Fn(&str) -> Result<String>>,
+    remote_output: extern "C" fn(&str, usize, Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)>,
+    remote_pipe: extern "C" fn(&str, usize, Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)>,
+    remote_input: extern "C" fn(&str, &Box<Any + Send>) -> Result<RemoteWriter>,
+    pool_input: extern "C" fn(&str, Vec<Box<Any + Send>>, PoolInput, Sender<CompMsg>) -> Result<Box<Any + Send>>,
+    pool_output: extern "C" fn(&str, &Box<Any + Send>, usize, Sender<CompMsg>) -> Result<Vec<Box<Any + Send>>>,
+    watch_input: extern "C" fn(&str, &Box<Any + Send>) -> Result<Arc<PortWatch>>,
+    watch_input_array: extern "C" fn(&str, &Box<Any + Send>) -> Result<Arc<PortWatch>>,
+    throttle_input: extern "C" fn(&str, &Box<Any + Send>, Arc<Throttle>) -> Result<Box<Any + Send>>,
+    close_input: extern "C" fn(&str, &Box<Any + Send>) -> Result<()>,
+    reopen_input: extern "C" fn(&str, &Box<Any + Send>) -> Result<()>,
+    /// The version of the crate of the agent, empty if unknown
+    version: String,
+    ports: Ports,
 }
 
-/// Keep all the dylib agents and load them
+impl AgentLoader {
+    // An agent whose functions are already known, a generic agent or a mock
+    fn from_exports(lib: Option<libloading::Library>, exports: AgentExports) -> Self {
+        AgentLoader {
+            lib: lib,
+            create: Box::new(move |id, sched| (exports.create_agent)(id, sched)),
+            clone_input: exports.clone_input,
+            clone_input_array: exports.clone_input_array,
+            create_input_array: exports.create_input_array,
+            get_schema_input: Box::new(move |port| (exports.get_schema_input)(port)),
+            get_schema_input_array: Box::new(move |port| (exports.get_schema_input_array)(port)),
+            get_schema_output: Box::new(move |port| (exports.get_schema_output)(port)),
+            get_schema_output_array: Box::new(move |port| (exports.get_schema_output_array)(port)),
+            remote_output: exports.remote_output,
+            remote_pipe: exports.remote_pipe,
+            remote_input: exports.remote_input,
+            pool_input: exports.pool_input,
+            pool_output: exports.pool_output,
+            watch_input: exports.watch_input,
+            watch_input_array: exports.watch_input_array,
+            throttle_input: exports.throttle_input,
+            close_input: exports.close_input,
+            reopen_input: exports.reopen_input,
+            version: (exports.agent_version)().into(),
+            ports: (exports.get_ports)(),
+        }
+    }
+}
+
+/// Keep all the dylib and wasm agents and load them
+///
+/// The clones of a cache share its agents : the networks of a `NetworkManager` load each dylib once.
+#[derive(Clone)]
 pub struct AgentCache {
-    cache: HashMap<String, AgentLoader>,
+    cache: Arc<Mutex<HashMap<String, Arc<AgentLoader>>>>,
 }
 
 impl AgentCache {
@@ -942,90 +3396,317 @@ impl AgentCache {
     /// ```
     pub fn new() -> Self {
         AgentCache {
-            cache: HashMap::new(),
+            cache: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
-    /// Load a new agent from the system file
+    // A loaded agent
+    fn loader(&self, comp: &str) -> Result<Arc<AgentLoader>> {
+        self.cache.lock().expect("AgentCache : poisoned cache").get(comp).cloned()
+            .ok_or(result::Error::AgentNotFound(comp.into()))
+    }
+
+    /// The sorts of the loaded agents, sorted
+    pub fn sorts(&self) -> Vec<String> {
+        let mut sorts: Vec<String> = self.cache.lock().expect("AgentCache sorts : poisoned cache").keys().cloned().collect();
+        sorts.sort();
+        sorts
+    }
+
+    /// Load a new agent from the system file, a dylib or a wasm module (see `rustfbp::wasm`)
+    ///
+    /// The path of a generic agent ends with its edge, `/home/xxx/agents/delay.so<PrimText>` (see `generic` in `agent!`).
+    /// The sort `mock:ledger` is the mock `ledger` of the test (see `testing::Mock`).
     ///
     /// # Example
     /// ```rust,ignore
-    /// try!(cc.create_comp("/home/xxx/agents/add.so", "add", sched_sender));
+    /// try!(cc.load("/home/xxx/agents/add.so"));
     /// ```
-    pub fn create_comp(&mut self, path: &str, id: usize, sender: Sender<CompMsg>) -> Result<(Box<Agent + Send>, HashMap<String, Box<Any + Send>>)> {
-        if !self.cache.contains_key(path) {
-            let lib_comp = libloading::Library::new(path).expect("cannot load");
+    pub fn load(&mut self, path: &str) -> Result<()> {
+        let mut cache = self.cache.lock().expect("AgentCache load : poisoned cache");
+        #[cfg(not(feature = "wasm"))]
+        {
+            if path.ends_with(".wasm") {
+                return Err(result::Error::Wasm(format!("{} : rustfbp is built without the wasm feature", path)));
+            }
+        }
+        #[cfg(feature = "wasm")]
+        if !cache.contains_key(path) && path.ends_with(".wasm") {
+            let sort = Arc::new(wasm::WasmSort::load(path)?);
+            let ports = sort.ports();
+            let (s1, s2, s3, s4, s5) = (sort.clone(), sort.clone(), sort.clone(), sort.clone(), sort);
+            cache.insert(path.into(),
+                              Arc::new(AgentLoader {
+                                  lib: None,
+                                  create: Box::new(move |id, sched| wasm::WasmSort::create(&s1, id, sched)),
+                                  clone_input: wasm::clone_input,
+                                  clone_input_array: wasm::clone_input,
+                                  create_input_array: wasm::create_input_array,
+                                  get_schema_input: Box::new(move |port| s2.get_schema_input(port)),
+                                  get_schema_input_array: Box::new(move |port| s3.get_schema_input_array(port)),
+                                  get_schema_output: Box::new(move |port| s4.get_schema_output(port)),
+                                  get_schema_output_array: Box::new(move |port| s5.get_schema_output_array(port)),
+                                  remote_output: wasm::remote_output,
+                                  remote_pipe: wasm::remote_pipe,
+                                  remote_input: wasm::remote_input,
+                                  pool_input: wasm::pool_input,
+                                  pool_output: wasm::pool_output,
+                                  watch_input: wasm::watch_input,
+                                  watch_input_array: wasm::watch_input,
+                                  throttle_input: wasm::throttle_input,
+                                  close_input: wasm::close_input,
+                                  reopen_input: wasm::reopen_input,
+                                  version: String::new(),
+                                  ports: ports,
+                              }));
+        }
+        if !cache.contains_key(path) && path.ends_with('>') {
+            let (lib_path, edge) = generic_sort(path)?;
+            let lib_comp = libloading::Library::new(lib_path)
+                .map_err(|e| result::Error::Dlopen { path: lib_path.into(), source: e })?;
+            check_abi(&lib_comp, lib_path)?;
+
+            let generic: extern fn(&str) -> Option<AgentExports> = unsafe {
+                symbol(&lib_comp, lib_path, "generic_agent")?
+            };
+            let exports = generic(edge)
+                .ok_or_else(|| result::Error::Misc(format!("the generic agent {} is not built for the edge {}", lib_path, edge)))?;
+            cache.insert(path.into(), Arc::new(AgentLoader::from_exports(Some(lib_comp), exports)));
+        }
+        if !cache.contains_key(path) && path.starts_with(MOCK_PREFIX) {
+            let exports = testing::mock_exports(&path[MOCK_PREFIX.len()..])?;
+            cache.insert(path.into(), Arc::new(AgentLoader::from_exports(None, exports)));
+        }
+        if !cache.contains_key(path) {
+            let lib_comp = libloading::Library::new(path)
+                .map_err(|e| result::Error::Dlopen { path: path.into(), source: e })?;
+            check_abi(&lib_comp, path)?;
 
             let new_comp: extern fn(usize, Sender<CompMsg>) -> Result<(Box<Agent + Send>, HashMap<String, Box<Any + Send>>)> = unsafe {
-                *(lib_comp.get(b"create_agent\0").expect("cannot find create method"))
+                symbol(&lib_comp, path, "create_agent")?
             };
 
             let clone_in: extern fn(&str, &Box<Any + Send>) -> Result<Box<Any + Send>> = unsafe {
-                *(lib_comp.get(b"clone_input\0").expect("cannot find clone_input method"))
+                symbol(&lib_comp, path, "clone_input")?
             };
 
             let clone_in_a: extern fn(&str, &Box<Any + Send>) -> Result<Box<Any + Send>> = unsafe {
-                *(lib_comp.get(b"clone_input_array\0").expect("cannot find clone_input_array method"))
+                symbol(&lib_comp, path, "clone_input_array")?
             };
 
             let create_in_a: extern fn(&str, usize, Sender<CompMsg>, bool) -> Result<(Box<Any + Send>, Box<Any + Send>)> = unsafe {
-                *(lib_comp.get(b"create_input_array\0").expect("cannot ifnd create_input_array method"))
+                symbol(&lib_comp, path, "create_input_array")?
             };
 
             let get_in : extern fn(&str) -> Result<String> = unsafe {
-                *(lib_comp.get(b"get_schema_input\0").expect("cannot find get input method"))
+                symbol(&lib_comp, path, "get_schema_input")?
             };
 
             let get_in_a : extern fn(&str) -> Result<String> = unsafe {
-                *(lib_comp.get(b"get_schema_input_array\0").expect("cannot find get input method"))
+                symbol(&lib_comp, path, "get_schema_input_array")?
             };
 
             let get_out : extern fn(&str) -> Result<String> = unsafe {
-                *(lib_comp.get(b"get_schema_output\0").expect("cannot find get output method"))
+                symbol(&lib_comp, path, "get_schema_output")?
             };
 
             let get_out_a : extern fn(&str) -> Result<String> = unsafe {
-                *(lib_comp.get(b"get_schema_output_array\0").expect("cannot find get output method"))
+                symbol(&lib_comp, path, "get_schema_output_array")?
+            };
+
+            let remote_out : extern fn(&str, usize, Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)> = unsafe {
+                symbol(&lib_comp, path, "remote_output")?
+            };
+
+            let remote_p : extern fn(&str, usize, Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)> = unsafe {
+                symbol(&lib_comp, path, "remote_pipe")?
+            };
+
+            let remote_in : extern fn(&str, &Box<Any + Send>) -> Result<RemoteWriter> = unsafe {
+                symbol(&lib_comp, path, "remote_input")?
+            };
+
+            let pool_in : extern fn(&str, Vec<Box<Any + Send>>, PoolInput, Sender<CompMsg>) -> Result<Box<Any + Send>> = unsafe {
+                symbol(&lib_comp, path, "pool_input")?
+            };
+
+            let pool_out : extern fn(&str, &Box<Any + Send>, usize, Sender<CompMsg>) -> Result<Vec<Box<Any + Send>>> = unsafe {
+                symbol(&lib_comp, path, "pool_output")?
+            };
+
+            let watch_in : extern fn(&str, &Box<Any + Send>) -> Result<Arc<PortWatch>> = unsafe {
+                symbol(&lib_comp, path, "watch_input")?
+            };
+
+            let watch_in_a : extern fn(&str, &Box<Any + Send>) -> Result<Arc<PortWatch>> = unsafe {
+                symbol(&lib_comp, path, "watch_input_array")?
+            };
+
+            let throttle_in : extern fn(&str, &Box<Any + Send>, Arc<Throttle>) -> Result<Box<Any + Send>> = unsafe {
+                symbol(&lib_comp, path, "throttle_input")?
+            };
+
+            let close_in : extern fn(&str, &Box<Any + Send>) -> Result<()> = unsafe {
+                symbol(&lib_comp, path, "close_input")?
+            };
+
+            let reopen_in : extern fn(&str, &Box<Any + Send>) -> Result<()> = unsafe {
+                symbol(&lib_comp, path, "reopen_input")?
+            };
+
+            let get_ports : extern fn() -> Ports = unsafe {
+                symbol(&lib_comp, path, "get_ports")?
             };
 
-            self.cache.insert(path.into(),
-                              AgentLoader {
-                                  lib: lib_comp,
-                                  create: new_comp,
+            // Missing in the dylibs built before the registry
+            let version = unsafe {
+                lib_comp.get::<extern fn() -> &'static str>(b"agent_version\0")
+                    .map(|version| version().to_string())
+                    .unwrap_or_default()
+            };
+
+            cache.insert(path.into(),
+                              Arc::new(AgentLoader {
+                                  lib: Some(lib_comp),
+                                  create: Box::new(move |id, sched| new_comp(id, sched)),
                                   clone_input: clone_in,
                                   clone_input_array: clone_in_a,
                                   create_input_array: create_in_a,
-                                  get_schema_input: get_in,
-                                  get_schema_input_array: get_in_a,
-                                  get_schema_output: get_out,
-                                  get_schema_output_array: get_out_a,
-                              });
-        }
-        if let Some(loader) = self.cache.get(path){
-            (loader.create)(id, sender)
-        } else {
-            unreachable!()
+                                  get_schema_input: Box::new(move |port| get_in(port)),
+                                  get_schema_input_array: Box::new(move |port| get_in_a(port)),
+                                  get_schema_output: Box::new(move |port| get_out(port)),
+                                  get_schema_output_array: Box::new(move |port| get_out_a(port)),
+                                  remote_output: remote_out,
+                                  remote_pipe: remote_p,
+                                  remote_input: remote_in,
+                                  pool_input: pool_in,
+                                  pool_output: pool_out,
+                                  watch_input: watch_in,
+                                  watch_input_array: watch_in_a,
+                                  throttle_input: throttle_in,
+                                  close_input: close_in,
+                                  reopen_input: reopen_in,
+                                  version: version,
+                                  ports: get_ports(),
+                              }));
         }
+        Ok(())
+    }
+
+    /// Load an agent if needed (see `load`), and create an instance
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// try!(cc.create_comp("/home/xxx/agents/add.so", 0, sched_sender));
+    /// ```
+    pub fn create_comp(&mut self, path: &str, id: usize, sender: Sender<CompMsg>) -> Result<(Box<Agent + Send>, HashMap<String, Box<Any + Send>>)> {
+        self.load(path)?;
+        let loader = self.loader(path)?;
+        (loader.create)(id, sender)
+    }
+
+    /// The version exported by a loaded agent, empty if it has none
+    pub fn version(&self, comp: &str) -> Result<String> {
+        self.loader(comp)
+            .map(|comp| comp.version.clone())
+    }
+
+    /// The names of the ports of a loaded agent
+    pub fn get_ports(&self, comp: &str) -> Result<Ports> {
+        self.loader(comp)
+            .map(|comp| comp.ports.clone())
     }
 
     pub fn clone_input(&self, comp: &str, port: &str, sender: &Box<Any + Send>) -> Result<Box<Any + Send>> {
-        self.cache.get(comp).ok_or(result::Error::AgentNotFound(comp.into()))
-            .map(|comp| {
-                (comp.clone_input)(port, sender).expect("cannot clone input")
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.clone_input)(port, sender)
             })
     }
 
     pub fn clone_input_array(&self, comp: &str, port: &str, sender: &Box<Any + Send>) -> Result<Box<Any + Send>> {
-        self.cache.get(comp).ok_or(result::Error::AgentNotFound(comp.into()))
-            .map(|comp| {
-                (comp.clone_input_array)(port, sender).expect("cannot clone input")
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.clone_input_array)(port, sender)
+            })
+    }
+
+    pub fn throttle_input(&self, comp: &str, port: &str, sender: &Box<Any + Send>, throttle: Arc<Throttle>) -> Result<Box<Any + Send>> {
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.throttle_input)(port, sender, throttle)
             })
     }
 
     pub fn create_input_array(&self, comp: &str, port: &str, id: usize, sched: Sender<CompMsg>, mc: bool) -> Result<(Box<Any + Send>, Box<Any + Send>)> {
-        self.cache.get(comp).ok_or(result::Error::AgentNotFound(comp.into()))
-            .map(|comp| {
-                (comp.create_input_array)(port, id, sched, mc).expect("cannot create input array")
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.create_input_array)(port, id, sched, mc)
+            })
+    }
+
+    pub fn remote_output(&self, comp: &str, port: &str, id: usize, sched: Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)> {
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.remote_output)(port, id, sched)
+            })
+    }
+
+    pub fn remote_pipe(&self, comp: &str, port: &str, id: usize, sched: Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)> {
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.remote_pipe)(port, id, sched)
+            })
+    }
+
+    pub fn remote_input(&self, comp: &str, port: &str, sender: &Box<Any + Send>) -> Result<RemoteWriter> {
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.remote_input)(port, sender)
+            })
+    }
+
+    pub fn pool_input(&self, comp: &str, port: &str, senders: Vec<Box<Any + Send>>, mode: PoolInput, sched: Sender<CompMsg>) -> Result<Box<Any + Send>> {
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.pool_input)(port, senders, mode, sched)
+            })
+    }
+
+    pub fn pool_output(&self, comp: &str, port: &str, target: &Box<Any + Send>, size: usize, sched: Sender<CompMsg>) -> Result<Vec<Box<Any + Send>>> {
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.pool_output)(port, target, size, sched)
+            })
+    }
+
+    pub fn watch_input(&self, comp: &str, port: &str, sender: &Box<Any + Send>) -> Result<Arc<PortWatch>> {
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.watch_input)(port, sender)
+            })
+    }
+
+    pub fn watch_input_array(&self, comp: &str, port: &str, sender: &Box<Any + Send>) -> Result<Arc<PortWatch>> {
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.watch_input_array)(port, sender)
+            })
+    }
+
+    /// Close a simple or an array input port, its agent gets the end of stream
+    pub fn close_input(&self, comp: &str, port: &str, sender: &Box<Any + Send>) -> Result<()> {
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.close_input)(port, sender)
+            })
+    }
+
+    /// Open a closed simple or array input port again, for a new connection
+    pub fn reopen_input(&self, comp: &str, port: &str, sender: &Box<Any + Send>) -> Result<()> {
+        self.loader(comp)
+            .and_then(|comp| {
+                (comp.reopen_input)(port, sender)
             })
     }
 
@@ -1036,9 +3717,9 @@ impl AgentCache {
     /// cc.get_schema_input("add", "input");
     /// ```
     pub fn get_schema_input(&self, comp: &str, port: &str) -> Result<String> {
-        self.cache.get(comp).ok_or(result::Error::AgentNotFound(comp.into()))
-            .map(|comp| {
-                (comp.get_schema_input)(port).expect("cannot get")
+        self.loader(comp)
+            .and_then(|loader| {
+                (loader.get_schema_input)(port).map_err(|e| port_context(comp, port, e))
             })
     }
 
@@ -1049,9 +3730,9 @@ impl AgentCache {
     /// cc.get_schema_input_array("add", "inputs");
     /// ```
     pub fn get_schema_input_array(&self, comp: &str, port: &str) -> Result<String> {
-        self.cache.get(comp).ok_or(result::Error::AgentNotFound(comp.into()))
-            .map(|comp| {
-                (comp.get_schema_input_array)(port).expect("cannot get")
+        self.loader(comp)
+            .and_then(|loader| {
+                (loader.get_schema_input_array)(port).map_err(|e| port_context(comp, port, e))
             })
     }
 
@@ -1062,9 +3743,9 @@ impl AgentCache {
     /// cc.get_schema_output("add", "output");
     /// ```
     pub fn get_schema_output(&self, comp: &str, port: &str) -> Result<String> {
-        self.cache.get(comp).ok_or(result::Error::AgentNotFound(comp.into()))
-            .map(|comp| {
-                (comp.get_schema_output)(port).expect("cannot get")
+        self.loader(comp)
+            .and_then(|loader| {
+                (loader.get_schema_output)(port).map_err(|e| port_context(comp, port, e))
             })
     }
 
@@ -1075,11 +3756,25 @@ impl AgentCache {
     /// cc.get_schema_output_array("add", "outputs");
     /// ```
     pub fn get_schema_output_array(&self, comp: &str, port: &str) -> Result<String> {
-        self.cache.get(comp).ok_or(result::Error::AgentNotFound(comp.into()))
-            .map(|comp| {
-                (comp.get_schema_output_array)(port).expect("cannot get")
+        self.loader(comp)
+            .and_then(|loader| {
+                (loader.get_schema_output_array)(port).map_err(|e| port_context(comp, port, e))
             })
     }
 }
 
+// The agents only know the name of the port, give it the sort of the agent
+fn port_context(comp: &str, port: &str, e: result::Error) -> result::Error {
+    match e {
+        result::Error::PortDontExist(_) => result::Error::PortNotFound(comp.into(), port.into()),
+        e => e,
+    }
+}
+
+impl fmt::Debug for AgentCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AgentCache").field("sorts", &self.sorts()).finish()
+    }
+}
+
 unsafe impl Send for AgentCache {}
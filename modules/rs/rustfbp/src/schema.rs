@@ -0,0 +1,202 @@
+//! The fields of the Cap'n Proto edges, known at runtime
+//!
+//! The build reads the schemas of the Cap'n Proto edges of an agent, and generates a function `schema_registry` which
+//! returns the `SchemaRegistry` of all of them, flat or not. A generic agent, like a logger or a field selector, asks
+//! it the fields of the edge it receives instead of being built for one edge. The agent `core_schema_registry` answers
+//! the same questions for the other agents of a graph.
+//!
+//! Example :
+//!
+//! ```rust,ignore
+//! let registry = schema_registry();
+//! let fields = registry.fields("PrimText")?;
+//! assert_eq!(fields, &[Field::new("text", "Text")]);
+//! ```
+//!
+//! The version of a schema is the fingerprint of its fields, so it changes each time a field is added, removed,
+//! renamed or retyped. A message built from a typed edge carries its `SchemaTag`, and reading it as another version
+//! fails with `Error::SchemaMismatch`. An upgrade function registered for the old version converts the message
+//! before it is read :
+//!
+//! ```rust,ignore
+//! let mut registry = schema_registry();
+//! registry.register_upgrade("AppTodo", OLD_TODO, AppTodo::schema().unwrap().version, todo_from_v1);
+//! let todo: AppTodo = registry.read(&msg)?;
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::capnp_edge::CapnpEdge;
+use crate::ports::CapnpMsg;
+use crate::result;
+use crate::result::Result;
+
+/// A field of a schema, with its Cap'n Proto type : `Text`, `UInt32`, `List(Text)`, the name of a struct...
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub kind: String,
+}
+
+impl Field {
+    pub fn new<N: Into<String>, K: Into<String>>(name: N, kind: K) -> Self {
+        Field {
+            name: name.into(),
+            kind: kind.into(),
+        }
+    }
+}
+
+/// The fingerprint of the fields of a schema, its version
+pub fn fingerprint(fields: &[Field]) -> u64 {
+    // FNV-1a, the same in every build
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for field in fields {
+        for b in field.name.bytes().chain(b":".iter().cloned()).chain(field.kind.bytes()).chain(b";".iter().cloned()) {
+            hash ^= b as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+/// The name and the version of the schema of a message
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaTag {
+    pub name: String,
+    pub version: u64,
+}
+
+impl SchemaTag {
+    pub fn new<N: Into<String>>(name: N, version: u64) -> Self {
+        SchemaTag {
+            name: name.into(),
+            version: version,
+        }
+    }
+}
+
+impl fmt::Display for SchemaTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}@{:016x}", self.name, self.version)
+    }
+}
+
+/// Check the schema of a message against the expected one. A message without schema, or read without schema, is
+/// accepted
+pub fn check(expected: Option<&SchemaTag>, found: Option<&SchemaTag>) -> Result<()> {
+    match (expected, found) {
+        (Some(expected), Some(found)) if expected != found => {
+            Err(result::Error::SchemaMismatch(expected.to_string(), found.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Convert a message of an old version of a schema into a message of a newer version
+pub type Upgrade = fn(CapnpMsg) -> Result<CapnpMsg>;
+
+/// The fields of schemas, by the name of their schema, and the upgrades between their versions
+#[derive(Clone, Debug, Default)]
+pub struct SchemaRegistry {
+    schemas: HashMap<String, Vec<Field>>,
+    // By schema and version : the version after the upgrade, and the upgrade
+    upgrades: HashMap<(String, u64), (u64, Upgrade)>,
+}
+
+impl SchemaRegistry {
+    pub fn new() -> Self {
+        SchemaRegistry::default()
+    }
+
+    /// Add the schema `name`, or replace its fields
+    pub fn register(&mut self, name: &str, fields: Vec<Field>) {
+        self.schemas.insert(name.to_string(), fields);
+    }
+
+    /// Add all the schemas and the upgrades of another registry
+    pub fn extend(&mut self, other: SchemaRegistry) {
+        self.schemas.extend(other.schemas);
+        self.upgrades.extend(other.upgrades);
+    }
+
+    /// Convert the messages of the version `from` of the schema `name` into messages of the version `to`
+    pub fn register_upgrade(&mut self, name: &str, from: u64, to: u64, upgrade: Upgrade) {
+        self.upgrades.insert((name.to_string(), from), (to, upgrade));
+    }
+
+    /// The names of the registered schemas, sorted
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.schemas.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.schemas.contains_key(name)
+    }
+
+    /// The fields of the schema `name`, in the order of the schema
+    pub fn fields(&self, name: &str) -> Result<&[Field]> {
+        self.schemas.get(name)
+            .map(|fields| fields as &[Field])
+            .ok_or_else(|| result::Error::Misc(format!("schema : unknown schema {}", name)))
+    }
+
+    /// The version of the schema `name`, the fingerprint of its fields
+    pub fn version(&self, name: &str) -> Result<u64> {
+        self.fields(name).map(fingerprint)
+    }
+
+    /// Read a message as `T`, upgrading it first if it's of an older version of the schema of `T`
+    pub fn read<T: CapnpEdge>(&self, msg: &CapnpMsg) -> Result<T> {
+        let expected = match T::schema() {
+            Some(expected) => expected,
+            None => { return T::from_capnp_msg(msg); }
+        };
+        let mut msg = msg.clone();
+        // Each upgrade is applied at most once, a cycle of upgrades cannot loop
+        for _ in 0..self.upgrades.len() {
+            let found = match msg.schema() {
+                Some(found) if found.name == expected.name && found.version != expected.version => found.clone(),
+                _ => break,
+            };
+            match self.upgrades.get(&(found.name.clone(), found.version)) {
+                Some(&(to, upgrade)) => { msg = upgrade(msg)?.with_schema(SchemaTag::new(found.name, to)); }
+                None => break,
+            }
+        }
+        T::from_capnp_msg(&msg)
+    }
+
+    /// The type of the field `field` of the schema `name`
+    pub fn kind(&self, name: &str, field: &str) -> Result<&str> {
+        self.fields(name)?.iter()
+            .find(|f| f.name == field)
+            .map(|f| &f.kind as &str)
+            .ok_or_else(|| result::Error::Misc(format!("schema : {} has no field {}", name, field)))
+    }
+}
+
+/// Declare the function `schema_registry`, with the fields of the schemas of the Cap'n Proto edges.
+///
+/// The call is generated by the build, after the calls of `capnp_edge_json`.
+///
+/// Example :
+///
+/// ```rust,ignore
+/// capnp_edge_schema!(PrimText { text: "Text" }, PrimList { list: "List(Text)" });
+/// ```
+#[macro_export]
+macro_rules! capnp_edge_schema {
+    ($( $name:ident { $( $field:ident : $kind:expr ),* } ),*) => {
+        #[allow(dead_code)]
+        pub fn schema_registry() -> $crate::schema::SchemaRegistry {
+            #[allow(unused_mut)]
+            let mut registry = $crate::schema::SchemaRegistry::new();
+            $( registry.register(stringify!($name), vec![$( $crate::schema::Field::new(stringify!($field), $kind) ),*]); )*
+            registry
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! References to the environment and to secret files in the imsgs and options
+//!
+//! An imsg `'env:DB_PASSWORD'` is replaced by the value of the environment variable `DB_PASSWORD`, and an imsg
+//! `'file:/run/secrets/db'` by the content of the file, without its last new line. They are resolved when the network
+//! is built, so the credentials are never written in a graph or in the code of an agent. Any other imsg is kept as it
+//! is, and `raw:` escapes an imsg which must start with `env:` or `file:` (`'raw:env:x'` is sent as `env:x`).
+//!
+//! The errors name the reference, never the value it resolves to.
+
+use crate::result::{Error, Result};
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// What an imsg refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reference<'a> {
+    /// Sent as it is
+    Literal(&'a str),
+    /// The name of an environment variable
+    Env(&'a str),
+    /// The absolute path of a file
+    File(&'a str),
+}
+
+impl<'a> Reference<'a> {
+    /// Read the reference of an imsg, and check its syntax
+    pub fn parse(msg: &'a str) -> Result<Self> {
+        if msg.starts_with("raw:") {
+            Ok(Reference::Literal(&msg[4..]))
+        } else if msg.starts_with("env:") {
+            let var = &msg[4..];
+            let valid = !var.is_empty() && !var.starts_with(|c: char| c.is_ascii_digit())
+                && var.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !valid {
+                return Err(Error::Misc(format!("bad environment variable \"{}\" in \"{}\"", var, msg)));
+            }
+            Ok(Reference::Env(var))
+        } else if msg.starts_with("file:") {
+            let path = &msg[5..];
+            if !Path::new(path).is_absolute() {
+                return Err(Error::Misc(format!("the secret file \"{}\" must be an absolute path", path)));
+            }
+            Ok(Reference::File(path))
+        } else {
+            Ok(Reference::Literal(msg))
+        }
+    }
+
+    /// The value referred to
+    pub fn resolve(&self) -> Result<String> {
+        match *self {
+            Reference::Literal(msg) => Ok(msg.into()),
+            Reference::Env(var) => env::var(var)
+                .map_err(|e| Error::Misc(format!("cannot read the environment variable {} : {}", var, e))),
+            Reference::File(path) => {
+                let mut content = fs::read_to_string(path)
+                    .map_err(|e| Error::Misc(format!("cannot read the secret file {} : {}", path, e)))?;
+                if content.ends_with('\n') {
+                    content.pop();
+                    if content.ends_with('\r') {
+                        content.pop();
+                    }
+                }
+                Ok(content)
+            },
+        }
+    }
+}
+
+/// The value of an imsg, with its reference resolved
+pub fn resolve(msg: &str) -> Result<String> {
+    Reference::parse(msg)?.resolve()
+}
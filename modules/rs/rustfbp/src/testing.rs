@@ -0,0 +1,557 @@
+//! Test an agent alone, without a scheduler
+//!
+//! A `TestBench` creates one agent, sends messages on its input and option ports, runs it once with `run`, and
+//! gives back what it sent on the captured output ports.
+//!
+//! Example, in the `lib.rs` of an agent :
+//!
+//! ```rust,ignore
+//! let mut bench = TestBench::new(create_agent)?;
+//! bench.capture::<PrimText>("output")?;
+//! bench.send("option", PrimText::new("hello".into()))?;
+//! bench.send("input", PrimText::new("world".into()))?;
+//! bench.run()?;
+//! assert_eq!(bench.received::<PrimText>("output")?, vec![PrimText::new("hello world".into())]);
+//! ```
+//!
+//! The input ports keep 25 messages : more must wait for a run. A run which waits on an empty port with `recv`
+//! blocks, like in a network.
+//!
+//! A `TestNetwork` runs a small graph in a real scheduler. The macro `network!` declares it with the syntax of the
+//! edges of a `.fbp` graph, the sort of a node is given at its first use :
+//!
+//! ```rust,ignore
+//! let mut net = network! {
+//!     open("fs_file_open.so") output -> input lex("core_parser_lexical.so"),
+//!     lex() output -> input sem("core_parser_semantic.so"),
+//! }?;
+//! net.capture::<CoreGraph>("sem", "output")?;
+//! net.capture::<CoreSemanticError>("sem", "error")?;
+//! net.send("open", "input", FsPath("test.fbp".into()))?;
+//! net.run(Duration::from_secs(10))?;
+//! assert_eq!(net.received::<CoreGraph>("sem", "output")?.len(), 1);
+//! assert!(net.received::<CoreSemanticError>("sem", "error")?.is_empty());
+//! net.join();
+//! ```
+//!
+//! A mock stands for an agent the tested ones depend on. `mock_agent!` declares it with the ports of the real agent,
+//! and the graph loads it with the sort `mock:<name>`. It keeps every message it receives, and answers each of them
+//! with the next reply scripted for its port, or the one computed by `respond` :
+//!
+//! ```rust,ignore
+//! mock_agent!(ledger, input(input: PrimText), output(output: PrimU64));
+//!
+//! let ledger = ledger::mock();
+//! ledger.reply("input", "output", PrimU64(42));
+//! let mut net = network! {
+//!     client("app_client.so") request -> input ledger("mock:ledger"),
+//!     ledger() output -> balance client(),
+//! }?;
+//! ...
+//! assert_eq!(ledger.received::<PrimText>("input")?.len(), 1);
+//! ```
+
+extern crate tokio;
+
+use self::tokio::runtime::Builder;
+
+use crate::agent;
+use crate::agent::{Agent, AgentExports};
+use crate::deadletter::{DeadLetter, Reason};
+use crate::ports::{MsgReceiver, MsgSender, PortWatch};
+use crate::result;
+use crate::result::Result;
+use crate::scheduler::{AgentCache, CompMsg, Scheduler, Signal};
+
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::Ordering;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The id of the agent of a `TestBench`
+const ID: usize = 0;
+
+/// The id given to the ports which capture the outputs of a `TestNetwork`, they never run an agent
+const PROBE: usize = ::std::usize::MAX;
+
+/// How often `TestNetwork::run` looks at the ports
+const POLL: Duration = Duration::from_millis(10);
+
+/// How long the ports of a `TestNetwork` must stay unchanged for the network to be idle
+const SETTLE: Duration = Duration::from_millis(100);
+
+/// The mocks declared by the test, by name
+static MOCKS: Mutex<Vec<(String, Arc<MockShared>, AgentExports)>> = Mutex::new(Vec::new());
+
+/// One agent, and the ports around it
+pub struct TestBench {
+    agent: Box<Agent + Send>,
+    // The senders of the input ports, `port` or `port[element]` for an array
+    inputs: HashMap<String, Box<Any + Send>>,
+    // The receivers connected to the output ports, `port` or `port[element]` for an array
+    outputs: HashMap<String, Box<Any + Send>>,
+    sender: Sender<CompMsg>,
+    sched: Receiver<CompMsg>,
+    dead_letters: Vec<(String, Reason)>,
+    // Keeps the dylib of a loaded agent, it must be dropped after the agent
+    _cache: Option<AgentCache>,
+}
+
+impl TestBench {
+    /// Create the agent with `create`, the function `create_agent` or `new` generated by `agent!`
+    pub fn new<F>(create: F) -> Result<Self> where
+        F: FnOnce(usize, Sender<CompMsg>) -> Result<(Box<Agent + Send>, HashMap<String, Box<Any + Send>>)>
+    {
+        let (sender, sched) = channel();
+        let (agent, inputs) = create(ID, sender.clone())?;
+        Ok(TestBench::with_agent(agent, inputs, sender, sched, None))
+    }
+
+    /// Load the agent from its dylib, or its wasm module, like the scheduler does
+    pub fn load(path: &str) -> Result<Self> {
+        let (sender, sched) = channel();
+        let mut cache = AgentCache::new();
+        let (agent, inputs) = cache.create_comp(path, ID, sender.clone())?;
+        Ok(TestBench::with_agent(agent, inputs, sender, sched, Some(cache)))
+    }
+
+    fn with_agent(agent: Box<Agent + Send>, inputs: HashMap<String, Box<Any + Send>>,
+                  sender: Sender<CompMsg>, sched: Receiver<CompMsg>, cache: Option<AgentCache>) -> Self {
+        TestBench {
+            agent: agent,
+            inputs: inputs,
+            outputs: HashMap::new(),
+            sender: sender,
+            sched: sched,
+            dead_letters: vec![],
+            _cache: cache,
+        }
+    }
+
+    /// Send `msg` on the input port `port`, the option port `option`, or a named option
+    pub fn send<T: 'static>(&self, port: &str, msg: T) -> Result<()> {
+        input::<T>(&self.inputs, port)?.send(msg)
+    }
+
+    /// Send `msg` on the element `element` of the array input port `port`, which is added at its first message
+    pub fn send_array<T: Send + 'static>(&mut self, port: &str, element: &str, msg: T) -> Result<()> {
+        let key = format!("{}[{}]", port, element);
+        if !self.inputs.contains_key(&key) {
+            let (recv, sender) = MsgReceiver::<T>::new(ID, self.sender.clone(), true);
+            self.agent.add_inarr_element(port, element.into(), Box::new(recv))?;
+            self.inputs.insert(key.clone(), Box::new(sender));
+        }
+        input::<T>(&self.inputs, &key)?.send(msg)
+    }
+
+    /// Connect the output port `port`, to take its messages with `received`
+    pub fn capture<T: Send + 'static>(&mut self, port: &str) -> Result<()> {
+        let (recv, sender) = MsgReceiver::<T>::unbounded(ID, self.sender.clone());
+        self.agent.connect(port, Box::new(sender))?;
+        self.outputs.insert(port.into(), Box::new(recv));
+        Ok(())
+    }
+
+    /// Connect the element `element` of the array output port `port`, to take its messages with `received_array`
+    pub fn capture_array<T: Send + 'static>(&mut self, port: &str, element: &str) -> Result<()> {
+        let (recv, sender) = MsgReceiver::<T>::unbounded(ID, self.sender.clone());
+        self.agent.connect_array(port, element.into(), Box::new(sender))?;
+        self.outputs.insert(format!("{}[{}]", port, element), Box::new(recv));
+        Ok(())
+    }
+
+    /// The messages sent on the captured output port `port` since the last call
+    pub fn received<T: 'static>(&self, port: &str) -> Result<Vec<T>> {
+        output::<T>(&self.outputs, port)
+    }
+
+    /// The messages sent on the captured element `element` of the array output port `port` since the last call
+    pub fn received_array<T: 'static>(&self, port: &str, element: &str) -> Result<Vec<T>> {
+        output::<T>(&self.outputs, &format!("{}[{}]", port, element))
+    }
+
+    /// Run the agent once, `run_async` for an async agent
+    pub fn run(&mut self) -> Result<Signal> {
+        let res = if self.agent.is_async() {
+            let runtime = Builder::new_current_thread().enable_all().build()?;
+            match self.agent.run_async() {
+                Some(future) => runtime.block_on(future),
+                None => Err(result::Error::Misc("testing : the async agent has no run_async".into())),
+            }
+        } else {
+            self.agent.run()
+        };
+        self.read_sched();
+        res
+    }
+
+    /// The messages sent on output ports which are not captured, or rejected, since the last call
+    pub fn dead_letters(&mut self) -> Vec<(String, Reason)> {
+        self.read_sched();
+        self.dead_letters.drain(..).collect()
+    }
+
+    /// The agent, to call the other methods of `Agent` (`snapshot`, `restore`, ...)
+    pub fn agent(&mut self) -> &mut Box<Agent + Send> {
+        &mut self.agent
+    }
+
+    // Keep the dead letters of the messages sent to the scheduler, the others are only for a scheduler
+    fn read_sched(&mut self) {
+        while let Ok(msg) = self.sched.try_recv() {
+            if let CompMsg::DeadLetter(_, port, reason, _) = msg {
+                self.dead_letters.push((port, reason));
+            }
+        }
+    }
+}
+
+fn input<'a, T: 'static>(inputs: &'a HashMap<String, Box<Any + Send>>, port: &str) -> Result<&'a MsgSender<T>> {
+    let sender = inputs.get(port).ok_or_else(|| result::Error::PortDontExist(port.into()))?;
+    agent::downcast_ref::<MsgSender<T>>(port, sender)
+}
+
+fn output<T: 'static>(outputs: &HashMap<String, Box<Any + Send>>, port: &str) -> Result<Vec<T>> {
+    let recv = outputs.get(port)
+        .ok_or_else(|| result::Error::Misc(format!("testing : the port {} is not captured", port)))?;
+    let recv = agent::downcast_ref::<MsgReceiver<T>>(port, recv)?;
+    let mut msgs = vec![];
+    while let Ok(msg) = recv.try_recv() {
+        msgs.push(msg);
+    }
+    Ok(msgs)
+}
+
+/// A small network in a real scheduler, with its output ports captured
+///
+/// The nodes are loaded from their dylib, like with `Scheduler::add_node`. It's usually declared with `network!`.
+///
+/// The messages are sent and taken with the types of the edges, which are only the same as the ones of the agents
+/// in another agent : a test of Rust edges is an agent itself, like `test_fvm_parser`.
+pub struct TestNetwork {
+    sched: Scheduler,
+    // The receivers connected to the output ports, `agent() port`
+    probes: HashMap<String, Box<Any + Send>>,
+    // The state of the receivers, to know when they stop receiving
+    watches: Vec<Arc<PortWatch>>,
+    started: bool,
+}
+
+impl TestNetwork {
+    pub fn new() -> Self {
+        TestNetwork {
+            sched: Scheduler::new(),
+            probes: HashMap::new(),
+            watches: vec![],
+            started: false,
+        }
+    }
+
+    /// Add the node `name`, of the agent at `sort`
+    pub fn add_node<'a, A, B>(&mut self, name: A, sort: B) -> Result<()> where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>
+    {
+        self.sched.add_node(name, sort)
+    }
+
+    /// Connect the output port `port_out` of `comp_out` to the input port `port_in` of `comp_in`
+    pub fn connect(&self, comp_out: &str, port_out: &str, comp_in: &str, port_in: &str) -> Result<()> {
+        self.sched.connect(comp_out, port_out, comp_in, port_in)
+    }
+
+    /// Send `msg` on the input port `port` of `comp`
+    pub fn send<T: 'static>(&self, comp: &str, port: &str, msg: T) -> Result<()> {
+        self.sched.get_typed_sender::<T, _, _>(comp, port)?.send(msg)
+    }
+
+    /// Connect the output port `port` of `comp`, to take its messages with `received`
+    ///
+    /// It replaces the edge of the port, if it's connected in the graph.
+    pub fn capture<T: Send + 'static>(&mut self, comp: &str, port: &str) -> Result<()> {
+        let (recv, sender) = MsgReceiver::<T>::unbounded(PROBE, self.sched.sender.clone());
+        let watch = sender.watch();
+        self.sched.connect_sender(comp, port, Box::new(sender))?;
+        self.probes.insert(format!("{}() {}", comp, port), Box::new(recv));
+        self.watches.push(watch);
+        Ok(())
+    }
+
+    /// The messages sent on the captured output port `port` of `comp` since the last call
+    pub fn received<T: 'static>(&self, comp: &str, port: &str) -> Result<Vec<T>> {
+        output::<T>(&self.probes, &format!("{}() {}", comp, port))
+    }
+
+    /// Start the network, and wait until it's idle
+    ///
+    /// The network is idle when no message waits in an input port, and no message was sent for a while. It fails if
+    /// the network is still busy after `deadline`.
+    pub fn run(&mut self, deadline: Duration) -> Result<()> {
+        if !self.started {
+            self.sched.start();
+            self.started = true;
+        }
+        let begin = Instant::now();
+        let mut last = None;
+        let mut since = Instant::now();
+        loop {
+            let activity = self.activity();
+            if activity.is_some() && activity == last {
+                if since.elapsed() >= SETTLE {
+                    return Ok(());
+                }
+            } else {
+                last = activity;
+                since = Instant::now();
+            }
+            if begin.elapsed() >= deadline {
+                return Err(result::Error::Misc(format!("testing : the network is still busy after {:?}", deadline)));
+            }
+            thread::sleep(POLL);
+        }
+    }
+
+    /// The messages lost by the network since the last call
+    pub fn dead_letters(&self) -> Vec<DeadLetter> {
+        self.sched.take_dead_letters()
+    }
+
+    /// The scheduler, to change the network while it runs
+    pub fn sched(&mut self) -> &mut Scheduler {
+        &mut self.sched
+    }
+
+    /// Stop the network
+    pub fn join(self) {
+        self.sched.join()
+    }
+
+    // The messages sent since the start, or None while messages wait in an input port
+    fn activity(&self) -> Option<u64> {
+        let network = self.sched.network();
+        let network = network.lock().expect("testing : poisoned network");
+        let mut sent = 0;
+        for watch in network.ports.values() {
+            if watch.queued.load(Ordering::SeqCst) > 0 {
+                return None;
+            }
+            sent += watch.sent.load(Ordering::SeqCst);
+        }
+        // The captured messages wait until `received`, only their count matters
+        for watch in &self.watches {
+            sent += watch.sent.load(Ordering::SeqCst);
+        }
+        Some(sent)
+    }
+}
+
+/// The script and the record of a mock, shared by its handle and its agents
+#[doc(hidden)]
+#[derive(Default)]
+pub struct MockShared {
+    // The messages received, with their port
+    received: Mutex<Vec<(String, Box<Any + Send>)>>,
+    // The replies still to send, by input port
+    script: Mutex<HashMap<String, VecDeque<(String, Box<Any + Send>)>>>,
+    // The functions which compute a reply once the script of the input port is over, with their output port
+    responders: Mutex<HashMap<String, (String, Box<Fn(&(Any + Send)) -> Option<Box<Any + Send>> + Send>)>>,
+}
+
+impl MockShared {
+    /// Keep the message `msg` received on `port`, and take the reply scripted for it
+    pub fn record(&self, port: &str, msg: Box<Any + Send>) -> Option<(String, Box<Any + Send>)> {
+        let reply = self.script.lock().expect("mock : poisoned script").get_mut(port).and_then(|replies| replies.pop_front());
+        let reply = reply.or_else(|| {
+            self.responders.lock().expect("mock : poisoned responders").get(port)
+                .and_then(|&(ref output, ref respond)| respond(&*msg).map(|reply| (output.clone(), reply)))
+        });
+        self.received.lock().expect("mock : poisoned record").push((port.into(), msg));
+        reply
+    }
+}
+
+/// The handle of a mock agent declared by `mock_agent!`, to script its replies and read what it received
+///
+/// All the nodes of the sort `mock:<name>` share the same script and record.
+#[derive(Clone)]
+pub struct Mock {
+    name: String,
+    shared: Arc<MockShared>,
+}
+
+impl Mock {
+    /// Register the mock `name`, with an empty script and record
+    ///
+    /// It's called by the function `mock` of the module declared by `mock_agent!`.
+    pub fn new(name: &str, exports: AgentExports) -> Self {
+        let shared = Arc::new(MockShared::default());
+        let mut mocks = MOCKS.lock().expect("mock : poisoned mocks");
+        mocks.retain(|&(ref n, _, _)| n != name);
+        mocks.push((name.into(), shared.clone(), exports));
+        Mock {
+            name: name.into(),
+            shared: shared,
+        }
+    }
+
+    /// The sort of the nodes of this mock, `mock:<name>`
+    pub fn sort(&self) -> String {
+        format!("mock:{}", self.name)
+    }
+
+    /// Send `msg` on the output port `output`, for the next message received on the input port `input`
+    ///
+    /// The replies of a port are sent in the order of the script, a message without reply left gets none.
+    pub fn reply<T: Send + 'static>(&self, input: &str, output: &str, msg: T) {
+        self.shared.script.lock().expect("mock : poisoned script")
+            .entry(input.into())
+            .or_insert_with(VecDeque::new)
+            .push_back((output.into(), Box::new(msg)));
+    }
+
+    /// Send `respond(msg)` on the output port `output`, for each message `msg` received on the input port `input`
+    /// once its scripted replies are over
+    ///
+    /// ```rust,ignore
+    /// // An echo
+    /// mock.respond("input", "output", |n: &u64| *n);
+    /// ```
+    pub fn respond<I, O, F>(&self, input: &str, output: &str, respond: F) where
+        I: 'static,
+        O: Send + 'static,
+        F: Fn(&I) -> O + Send + 'static
+    {
+        let respond = move |msg: &(Any + Send)| msg.downcast_ref::<I>().map(|msg| Box::new(respond(msg)) as Box<Any + Send>);
+        self.shared.responders.lock().expect("mock : poisoned responders")
+            .insert(input.into(), (output.into(), Box::new(respond)));
+    }
+
+    /// The messages received on the input port `port` since the last call
+    pub fn received<T: 'static>(&self, port: &str) -> Result<Vec<T>> {
+        let mut received = self.shared.received.lock().expect("mock : poisoned record");
+        if received.iter().any(|&(ref p, ref msg)| p == port && !msg.is::<T>()) {
+            return Err(result::Error::Misc(format!("mock : the port {} of {} doesn't take this edge", port, self.name)));
+        }
+        let (msgs, others): (Vec<_>, Vec<_>) = received.drain(..).partition(|&(ref p, _)| p == port);
+        *received = others;
+        Ok(msgs.into_iter().filter_map(|(_, msg)| msg.downcast::<T>().ok()).map(|msg| *msg).collect())
+    }
+}
+
+/// The shared state of the agents of the mock `name`, a new one if the mock is not registered
+#[doc(hidden)]
+pub fn mock_shared(name: &str) -> Arc<MockShared> {
+    MOCKS.lock().expect("mock : poisoned mocks").iter()
+        .find(|&&(ref n, _, _)| n == name)
+        .map(|&(_, ref shared, _)| shared.clone())
+        .unwrap_or_default()
+}
+
+/// The functions of the mock `name`, for the sort `mock:<name>`
+pub fn mock_exports(name: &str) -> Result<AgentExports> {
+    MOCKS.lock().expect("mock : poisoned mocks").iter()
+        .find(|&&(ref n, _, _)| n == name)
+        .map(|&(_, _, exports)| exports)
+        .ok_or_else(|| result::Error::Misc(format!("mock : {} is not declared, call {}::mock() first", name, name)))
+}
+
+/// Declare the mock agent `name`, with the ports of the agent it stands for
+///
+/// It declares the module `name`, whose function `mock` registers the mock and gives its `Mock` handle. The edges
+/// are the ones of the crate, declared by its own `agent!`.
+///
+/// # Example
+/// ```rust,ignore
+/// mock_agent!(ledger, input(input: PrimText, audit: PrimText), output(output: PrimU64));
+/// let ledger = ledger::mock();
+/// ```
+#[macro_export]
+macro_rules! mock_agent {
+    (
+        $name:ident
+        $(, input($( $input_name:ident: $input_contract:ty ),*) )*
+        $(, output($( $output_name:ident: $output_contract:ty ),*) )*
+        $(,)*
+    ) => {
+        pub mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            agent!(@exports[]
+                $( input($( $input_name: $input_contract ),*), )*
+                $( output($( $output_name: $output_contract ),*), )*
+                state(std::sync::Arc<rustfbp::testing::MockShared> => rustfbp::testing::mock_shared(stringify!($name))),
+                fn run(&mut self) -> Result<Signal> {
+                    $($(
+                        while let Ok(msg) = self.input.$input_name.try_recv() {
+                            if let Some((port, reply)) = self.state.record(stringify!($input_name), Box::new(msg)) {
+                                self.mock_reply(port, reply)?;
+                            }
+                        }
+                    )*)*
+                    Ok(End)
+                }
+            );
+            agent!(@exports_fn);
+
+            impl ThisAgent {
+                // Send a scripted reply on its output port, a port not connected gives a dead letter
+                #[allow(dead_code)]
+                fn mock_reply(&mut self, port: String, reply: Box<Any + Send>) -> Result<()> {
+                    match &port as &str {
+                        $($(
+                            stringify!($output_name) => {
+                                let reply = reply.downcast::<$output_contract>()
+                                    .map_err(|_| result::Error::Misc(format!("mock : the reply on {} is not a {}", port, stringify!($output_contract))))?;
+                                let _ = self.output.$output_name.send(*reply);
+                                Ok(())
+                            },
+                        )*)*
+                        _ => Err(result::Error::PortDontExist(port.clone())),
+                    }
+                }
+            }
+
+            /// Register the mock, and get its handle
+            pub fn mock() -> rustfbp::testing::Mock {
+                rustfbp::testing::Mock::new(stringify!($name), exports())
+            }
+        }
+    }
+}
+
+pub use crate::mock_agent;
+
+/// Declare a `TestNetwork`, with the edges of a `.fbp` graph
+///
+/// Each edge is `out(sort) port -> port in(sort)`, the sort of a node is given the first time it appears, and left
+/// empty after. It returns a `Result<TestNetwork>`, the network is not started.
+///
+/// # Example
+/// ```rust,ignore
+/// let net = network! {
+///     open("fs_file_open.so") output -> input lex("core_parser_lexical.so"),
+///     open() error -> file_error errors("core_errors.so"),
+/// }?;
+/// ```
+#[macro_export]
+macro_rules! network {
+    ($( $out:ident ( $( $out_sort:expr )? ) $out_port:ident -> $in_port:ident $in:ident ( $( $in_sort:expr )? ) ),* $(,)?) => {
+        (|| -> $crate::result::Result<$crate::testing::TestNetwork> {
+            #[allow(unused_mut)]
+            let mut net = $crate::testing::TestNetwork::new();
+            $(
+                $( net.add_node(stringify!($out), $out_sort)?; )?
+                $( net.add_node(stringify!($in), $in_sort)?; )?
+                net.connect(stringify!($out), stringify!($out_port), stringify!($in), stringify!($in_port))?;
+            )*
+            Ok(net)
+        })()
+    }
+}
+
+pub use crate::network;
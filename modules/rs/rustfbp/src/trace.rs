@@ -0,0 +1,130 @@
+//! The last messages of the traced edges, for post-mortem debugging
+//!
+//! An output port marked with `Scheduler::trace` keeps a copy of the last messages it sent, serialized with their
+//! `RemoteEdge` (the capnp envelope of a capnp edge), in a ring buffer. The buffer can be read at any time with
+//! `Scheduler::dump_trace`. When the agent at the other end of the edge fails, the buffer is copied with the error, and
+//! read with `Scheduler::trace_failure` : it shows the messages which led to the failure, even after new ones came.
+
+use crate::remote::RemoteEdge;
+use crate::result;
+use crate::result::Result;
+
+use std::collections::{HashMap, VecDeque};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+/// A message sent on a traced edge
+#[derive(Debug, Clone)]
+pub struct TracedMsg {
+    pub time: SystemTime,
+    /// The message, serialized by its `RemoteEdge`
+    pub bytes: Vec<u8>,
+}
+
+/// The trace of an edge when the agent of its input port failed
+#[derive(Debug, Clone)]
+pub struct TraceFailure {
+    /// The agent which failed
+    pub agent: String,
+    pub error: String,
+    pub time: SystemTime,
+    /// The last messages of the edge before the failure, the oldest first
+    pub msgs: Vec<TracedMsg>,
+}
+
+/// The ring buffer of a traced edge
+pub struct Trace {
+    len: usize,
+    msgs: Mutex<VecDeque<TracedMsg>>,
+    failure: Mutex<Option<TraceFailure>>,
+}
+
+impl Trace {
+    /// Keep the last `len` messages
+    pub fn new(len: usize) -> Self {
+        Trace {
+            len: len,
+            msgs: Mutex::new(VecDeque::with_capacity(len)),
+            failure: Mutex::new(None),
+        }
+    }
+
+    /// Keep a message, the oldest one is dropped when the buffer is full
+    pub fn push(&self, bytes: Vec<u8>) {
+        let mut msgs = self.msgs.lock().expect("Trace push : poisoned messages");
+        if msgs.len() >= self.len {
+            msgs.pop_front();
+        }
+        msgs.push_back(TracedMsg {
+            time: SystemTime::now(),
+            bytes: bytes,
+        });
+    }
+
+    /// A copy of the messages, the oldest first
+    pub fn dump(&self) -> Vec<TracedMsg> {
+        self.msgs.lock().expect("Trace dump : poisoned messages").iter().cloned().collect()
+    }
+
+    /// Copy the messages for the failure of `agent`, in place of the previous failure
+    pub fn fail(&self, agent: &str, error: String) {
+        let failure = TraceFailure {
+            agent: agent.into(),
+            error: error,
+            time: SystemTime::now(),
+            msgs: self.dump(),
+        };
+        *self.failure.lock().expect("Trace fail : poisoned failure") = Some(failure);
+    }
+
+    /// The last failure of the agent of the edge
+    pub fn failure(&self) -> Option<TraceFailure> {
+        self.failure.lock().expect("Trace failure : poisoned failure").clone()
+    }
+}
+
+pub type SharedTrace = Arc<Trace>;
+/// The traces of a scheduler, by (agent, output port)
+pub type SharedTraces = Arc<Mutex<HashMap<(String, String), SharedTrace>>>;
+
+/// Serialize a message of a traced port
+pub type TraceEncoder<T> = fn(&T) -> Result<Vec<u8>>;
+
+fn encode<T: RemoteEdge + Clone>(msg: &T) -> Result<Vec<u8>> {
+    msg.clone().to_bytes()
+}
+
+/// Used by the agent macro to trace an output port, which needs a `Clone` and `RemoteEdge` edge
+///
+/// `(&&Tracer::<T>::new()).encoder()` resolves to `TraceEdge` if `T` can be traced, to `NoTraceEdge` otherwise.
+#[doc(hidden)]
+pub struct Tracer<T>(PhantomData<T>);
+
+impl<T> Tracer<T> {
+    pub fn new() -> Self {
+        Tracer(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait TraceEdge<T> {
+    fn encoder(&self) -> Result<TraceEncoder<T>>;
+}
+
+impl<'a, T: RemoteEdge + Clone> TraceEdge<T> for &'a Tracer<T> {
+    fn encoder(&self) -> Result<TraceEncoder<T>> {
+        Ok(encode::<T>)
+    }
+}
+
+#[doc(hidden)]
+pub trait NoTraceEdge<T> {
+    fn encoder(&self) -> Result<TraceEncoder<T>>;
+}
+
+impl<T> NoTraceEdge<T> for Tracer<T> {
+    fn encoder(&self) -> Result<TraceEncoder<T>> {
+        Err(result::Error::Misc("the edge of the port is not Clone and RemoteEdge, it cannot be traced".into()))
+    }
+}
@@ -0,0 +1,544 @@
+//! Agents compiled to WebAssembly
+//!
+//! A sort ending in `.wasm` is loaded by the scheduler as a WASI module instead of a dylib. Each agent runs in its
+//! own instance, so it only reaches the messages of its ports, and the files and environment WASI gives it (only
+//! stderr). Any language compiling to WASI can provide an agent.
+//!
+//! The ports of a wasm agent carry `CapnpMsg`, which cross the boundary in the Cap'n Proto wire format. They can be
+//! connected to the other agents with `CapnpMsg` ports.
+//!
+//! The module exports :
+//!
+//! * `memory`,
+//! * `fbp_alloc(len: i32) -> i32` : allocate `len` bytes, where the scheduler writes the messages for the agent,
+//! * `fbp_describe() -> i64` : the description of the agent (see below),
+//! * `fbp_run() -> i32` : an execution of the agent, returns `0` for `End`, `1` for `Continue`, `2` for `Yield`,
+//!   anything else is an error.
+//!
+//! The state of a wasm agent is not saved by `Scheduler::checkpoint`.
+//!
+//! A buffer is returned as an `i64`, its address in the high 32 bits and its length in the low 32 bits. A negative
+//! value is an error.
+//!
+//! The description is a text, one line for each port or flag :
+//!
+//! ```text
+//! input input PrimText
+//! inarr inputs PrimU64
+//! output output PrimText
+//! outarr outputs PrimText
+//! option PrimText
+//! autostart
+//! blocking
+//! ```
+//!
+//! The module imports the functions of the module `fractalide`, the names and the messages are (address, length)
+//! pairs in its memory :
+//!
+//! * `recv(port, port_len) -> i64` : wait for the next message of an input port (or `option`),
+//! * `try_recv(port, port_len) -> i64` : the next message, negative if none waits,
+//! * `recv_array(port, port_len, element, element_len) -> i64` : the next message of an element of an array input port,
+//! * `elements(port, port_len) -> i64` : the elements of an array input port, one by line,
+//! * `send(port, port_len, msg, msg_len) -> i32` : send a message on an output port, `0` if sent,
+//! * `send_array(port, port_len, element, element_len, msg, msg_len) -> i32` : on an element of an array output port,
+//! * `log(level, msg, msg_len)` : log an entry of the agent, `1` for error to `5` for trace.
+
+extern crate wasmtime;
+extern crate wasmtime_wasi;
+
+use crate::agent::{Agent, AgentFuture, Ports};
+use crate::limits::Throttle;
+use crate::log::{Context, Level};
+use crate::ports::{CapnpMsg, MsgReceiver, MsgSender, OutputPort, OutputSend, PortWatch, Tap};
+use crate::remote::{Remote, RemoteCodec, RemoteReader, RemoteWriter};
+use crate::result;
+use crate::result::Result;
+use crate::scheduler::{balance, merge_ordered, CompMsg, PoolInput, Signal};
+use crate::trace::{SharedTrace, Tracer, TraceEdge};
+
+use self::wasmtime::{Caller, Engine, Instance, Linker, Memory, Module, Store, TypedFunc};
+use self::wasmtime_wasi::WasiCtxBuilder;
+use self::wasmtime_wasi::preview1::{self, WasiP1Ctx};
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::mpsc::Sender;
+
+/// The ports and flags of a wasm agent, from its `fbp_describe`
+#[derive(Debug, Default)]
+pub struct Description {
+    pub inputs: Vec<(String, String)>,
+    pub inarr: Vec<(String, String)>,
+    /// The names live as long as the process, for the dead letters of the output ports
+    pub outputs: Vec<(&'static str, String)>,
+    pub outarr: Vec<(String, String)>,
+    pub option: Option<String>,
+    pub autostart: bool,
+    pub blocking: bool,
+}
+
+impl Description {
+    fn parse(text: &str) -> Result<Self> {
+        let mut desc = Description::default();
+        for line in text.lines() {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                [] => {},
+                ["input", name, schema @ ..] if !schema.is_empty() => { desc.inputs.push((name.to_string(), schema.join(" "))); },
+                ["inarr", name, schema @ ..] if !schema.is_empty() => { desc.inarr.push((name.to_string(), schema.join(" "))); },
+                ["output", name, schema @ ..] if !schema.is_empty() => {
+                    let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+                    desc.outputs.push((name, schema.join(" ")));
+                },
+                ["outarr", name, schema @ ..] if !schema.is_empty() => { desc.outarr.push((name.to_string(), schema.join(" "))); },
+                ["option", schema @ ..] if !schema.is_empty() => { desc.option = Some(schema.join(" ")); },
+                ["autostart"] => { desc.autostart = true; },
+                ["blocking"] => { desc.blocking = true; },
+                _ => { return Err(result::Error::Wasm(format!("bad description line : {}", line))); }
+            }
+        }
+        Ok(desc)
+    }
+
+    fn schema(ports: &[(String, String)], port: &str) -> Result<String> {
+        ports.iter().find(|p| p.0 == port).map(|p| p.1.clone()).ok_or(result::Error::PortDontExist(port.into()))
+    }
+}
+
+/// A wasm module loaded by the scheduler, from which its agents are instantiated
+pub struct WasmSort {
+    engine: Engine,
+    module: Module,
+    linker: Linker<Host>,
+    pub description: Description,
+}
+
+impl WasmSort {
+    /// Compile the module in `path`, and read its description
+    pub fn load(path: &str) -> Result<Self> {
+        let engine = Engine::default();
+        let module = Module::from_file(&engine, path)?;
+        let linker = linker(&engine)?;
+        let mut sort = WasmSort {
+            engine: engine,
+            module: module,
+            linker: linker,
+            description: Description::default(),
+        };
+        let (mut store, instance) = sort.instantiate(Host::new(Context::new(path.into())))?;
+        let describe = instance.get_typed_func::<(), i64>(&mut store, "fbp_describe")?;
+        let packed = describe.call(&mut store, ())?;
+        let text = String::from_utf8(read_packed(&mut store, &instance, packed)?)?;
+        sort.description = Description::parse(&text)?;
+        Ok(sort)
+    }
+
+    fn instantiate(&self, host: Host) -> Result<(Store<Host>, Instance)> {
+        let mut store = Store::new(&self.engine, host);
+        let instance = self.linker.instantiate(&mut store, &self.module)?;
+        Ok((store, instance))
+    }
+
+    /// Instantiate an agent, with the senders of its input ports
+    pub fn create(sort: &Arc<Self>, id: usize, sched: Sender<CompMsg>) -> Result<(Box<Agent + Send>, HashMap<String, Box<Any + Send>>)> {
+        let desc = &sort.description;
+        let mut senders: HashMap<String, Box<Any + Send>> = HashMap::new();
+        let mut host = Host::new(Context::new(format!("agent-{}", id)));
+        for (port, _) in &desc.inputs {
+            let (r, s) = MsgReceiver::<CapnpMsg>::new(id, sched.clone(), true);
+            host.inputs.insert(port.clone(), r);
+            senders.insert(port.clone(), Box::new(s));
+        }
+        if desc.option.is_some() {
+            let (r, s) = MsgReceiver::<CapnpMsg>::new(id, sched.clone(), false);
+            host.inputs.insert("option".into(), r);
+            senders.insert("option".into(), Box::new(s));
+        }
+        for (port, _) in &desc.inarr {
+            host.inarr.insert(port.clone(), HashMap::new());
+        }
+        for (port, _) in &desc.outputs {
+            host.outputs.insert(port.to_string(), OutputPort::new(id, port, sched.clone()));
+        }
+        for (port, _) in &desc.outarr {
+            host.outarr.insert(port.clone(), HashMap::new());
+        }
+
+        let (mut store, instance) = sort.instantiate(host)?;
+        let run = instance.get_typed_func::<(), i32>(&mut store, "fbp_run")?;
+        let agent = WasmAgent {
+            sort: sort.clone(),
+            store: store,
+            run: run,
+        };
+        Ok((Box::new(agent) as Box<Agent + Send>, senders))
+    }
+
+    /// The names of the ports
+    pub fn ports(&self) -> Ports {
+        let names = |ports: &[(String, String)]| ports.iter().map(|p| p.0.clone()).collect();
+        Ports {
+            inputs: names(&self.description.inputs),
+            input_arrays: names(&self.description.inarr),
+            outputs: self.description.outputs.iter().map(|p| p.0.to_string()).collect(),
+            output_arrays: names(&self.description.outarr),
+            options: self.description.option.iter().map(|_| "option".to_string()).collect(),
+        }
+    }
+
+    pub fn get_schema_input(&self, port: &str) -> Result<String> {
+        match self.description.option {
+            Some(ref option) if port == "option" => Ok(option.clone()),
+            _ => Description::schema(&self.description.inputs, port),
+        }
+    }
+
+    pub fn get_schema_input_array(&self, port: &str) -> Result<String> {
+        Description::schema(&self.description.inarr, port)
+    }
+
+    pub fn get_schema_output(&self, port: &str) -> Result<String> {
+        self.description.outputs.iter().find(|p| p.0 == port).map(|p| p.1.clone()).ok_or(result::Error::PortDontExist(port.into()))
+    }
+
+    pub fn get_schema_output_array(&self, port: &str) -> Result<String> {
+        Description::schema(&self.description.outarr, port)
+    }
+}
+
+/// The ports of a wasm agent, reached by the functions it imports
+pub struct Host {
+    wasi: WasiP1Ctx,
+    inputs: HashMap<String, MsgReceiver<CapnpMsg>>,
+    inarr: HashMap<String, HashMap<String, MsgReceiver<CapnpMsg>>>,
+    outputs: HashMap<String, OutputPort<CapnpMsg>>,
+    outarr: HashMap<String, HashMap<String, MsgSender<CapnpMsg>>>,
+    log: Context,
+}
+
+impl Host {
+    fn new(log: Context) -> Self {
+        Host {
+            wasi: WasiCtxBuilder::new().inherit_stderr().build_p1(),
+            inputs: HashMap::new(),
+            inarr: HashMap::new(),
+            outputs: HashMap::new(),
+            outarr: HashMap::new(),
+            log: log,
+        }
+    }
+}
+
+/// An agent running in its wasm instance
+pub struct WasmAgent {
+    sort: Arc<WasmSort>,
+    store: Store<Host>,
+    run: TypedFunc<(), i32>,
+}
+
+impl Agent for WasmAgent {
+    fn is_input_ports(&self) -> bool {
+        !self.sort.description.inputs.is_empty() || !self.sort.description.inarr.is_empty()
+    }
+
+    fn autostart(&self) -> bool {
+        self.sort.description.autostart
+    }
+
+    fn is_blocking(&self) -> bool {
+        self.sort.description.blocking
+    }
+
+    fn is_async(&self) -> bool {
+        false
+    }
+
+    fn batch_flush(&self) -> u64 {
+        100
+    }
+
+    fn connect(&mut self, port: &str, sender: Box<Any + Send>) -> Result<()> {
+        let sender = downcast_sender(port, sender)?;
+        let output = self.store.data_mut().outputs.get_mut(port).ok_or(result::Error::PortDontExist(port.into()))?;
+        **output = Some(sender);
+        Ok(())
+    }
+
+    fn tap(&mut self, port: &str, tap: Option<(Box<Any + Send>, f64)>) -> Result<()> {
+        let tap = match tap {
+            Some((sink, rate)) => Some(Tap::new(downcast_sender(port, sink)?, rate)),
+            None => None,
+        };
+        let output = self.store.data_mut().outputs.get_mut(port).ok_or(result::Error::PortDontExist(port.into()))?;
+        output.set_tap(tap);
+        Ok(())
+    }
+
+    fn trace(&mut self, port: &str, trace: Option<SharedTrace>) -> Result<()> {
+        let trace = match trace {
+            Some(trace) => Some((trace, (&&Tracer::<CapnpMsg>::new()).encoder()?)),
+            None => None,
+        };
+        let output = self.store.data_mut().outputs.get_mut(port).ok_or(result::Error::PortDontExist(port.into()))?;
+        output.set_trace(trace);
+        Ok(())
+    }
+
+    fn connect_array(&mut self, port: &str, element: String, sender: Box<Any + Send>) -> Result<()> {
+        let sender = downcast_sender(port, sender)?;
+        let outarr = self.store.data_mut().outarr.get_mut(port).ok_or(result::Error::PortDontExist(port.into()))?;
+        outarr.insert(element, sender);
+        Ok(())
+    }
+
+    fn disconnect(&mut self, port: &str) -> Result<()> {
+        let output = self.store.data_mut().outputs.get_mut(port).ok_or(result::Error::PortDontExist(port.into()))?;
+        **output = None;
+        Ok(())
+    }
+
+    fn disconnect_array(&mut self, port: &str, element: &str) -> Result<()> {
+        let outarr = self.store.data_mut().outarr.get_mut(port).ok_or(result::Error::PortDontExist(port.into()))?;
+        outarr.remove(element);
+        Ok(())
+    }
+
+    fn add_inarr_element(&mut self, port: &str, element: String, recv: Box<Any + Send>) -> Result<()> {
+        let recv = recv.downcast::<MsgReceiver<CapnpMsg>>()
+            .map_err(|_| result::Error::Wasm(format!("the port {} of a wasm agent only takes CapnpMsg", port)))?;
+        let inarr = self.store.data_mut().inarr.get_mut(port).ok_or(result::Error::PortDontExist(port.into()))?;
+        inarr.insert(element, *recv);
+        Ok(())
+    }
+
+    fn run(&mut self) -> Result<Signal> {
+        self.store.data_mut().log.next_run();
+        match self.run.call(&mut self.store, ())? {
+            0 => Ok(Signal::End),
+            1 => Ok(Signal::Continue),
+            2 => Ok(Signal::Yield),
+            code => Err(result::Error::Wasm(format!("fbp_run returned {}", code))),
+        }
+    }
+
+    fn run_async<'a>(&'a mut self) -> Option<AgentFuture<'a>> {
+        None
+    }
+
+    fn snapshot(&self) -> Result<Vec<u8>> {
+        Ok(vec![])
+    }
+
+    fn restore(&mut self, _bytes: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    fn set_log(&mut self, log: Context) {
+        self.store.data_mut().log = log;
+    }
+}
+
+fn downcast_sender(port: &str, sender: Box<Any + Send>) -> Result<MsgSender<CapnpMsg>> {
+    sender.downcast::<MsgSender<CapnpMsg>>()
+        .map(|s| *s)
+        .map_err(|_| result::Error::Wasm(format!("the port {} of a wasm agent only takes CapnpMsg", port)))
+}
+
+// The functions imported by the agents, and WASI
+fn linker(engine: &Engine) -> Result<Linker<Host>> {
+    let mut linker: Linker<Host> = Linker::new(engine);
+    preview1::add_to_linker_sync(&mut linker, |host: &mut Host| &mut host.wasi)?;
+
+    linker.func_wrap("fractalide", "recv", |mut caller: Caller<'_, Host>, port: i32, port_len: i32| -> i64 {
+        or_negative(|| {
+            let port = read_str(&mut caller, port, port_len)?;
+            let msg = caller.data().inputs.get(&port).ok_or(result::Error::PortDontExist(port.clone()))?.recv()?;
+            write(&mut caller, &msg.to_bytes()?)
+        })
+    })?;
+
+    linker.func_wrap("fractalide", "try_recv", |mut caller: Caller<'_, Host>, port: i32, port_len: i32| -> i64 {
+        or_negative(|| {
+            let port = read_str(&mut caller, port, port_len)?;
+            let msg = caller.data().inputs.get(&port).ok_or(result::Error::PortDontExist(port.clone()))?.try_recv()?;
+            write(&mut caller, &msg.to_bytes()?)
+        })
+    })?;
+
+    linker.func_wrap("fractalide", "recv_array", |mut caller: Caller<'_, Host>, port: i32, port_len: i32, element: i32, element_len: i32| -> i64 {
+        or_negative(|| {
+            let port = read_str(&mut caller, port, port_len)?;
+            let element = read_str(&mut caller, element, element_len)?;
+            let msg = caller.data().inarr.get(&port)
+                .and_then(|inarr| inarr.get(&element))
+                .ok_or(result::Error::ElementNotFound("wasm".into(), port.clone(), element.clone()))?
+                .recv()?;
+            write(&mut caller, &msg.to_bytes()?)
+        })
+    })?;
+
+    linker.func_wrap("fractalide", "elements", |mut caller: Caller<'_, Host>, port: i32, port_len: i32| -> i64 {
+        or_negative(|| {
+            let port = read_str(&mut caller, port, port_len)?;
+            let elements: Vec<String> = caller.data().inarr.get(&port)
+                .ok_or(result::Error::PortDontExist(port.clone()))?
+                .keys().cloned().collect();
+            write(&mut caller, elements.join("\n").as_bytes())
+        })
+    })?;
+
+    linker.func_wrap("fractalide", "send", |mut caller: Caller<'_, Host>, port: i32, port_len: i32, msg: i32, msg_len: i32| -> i32 {
+        or_negative(|| {
+            let port = read_str(&mut caller, port, port_len)?;
+            let msg = CapnpMsg::from_bytes(&read(&mut caller, msg, msg_len)?)?;
+            caller.data().outputs.get(&port).ok_or(result::Error::PortDontExist(port.clone()))?.send(msg)?;
+            Ok(0)
+        }) as i32
+    })?;
+
+    linker.func_wrap("fractalide", "send_array", |mut caller: Caller<'_, Host>, port: i32, port_len: i32, element: i32, element_len: i32, msg: i32, msg_len: i32| -> i32 {
+        or_negative(|| {
+            let port = read_str(&mut caller, port, port_len)?;
+            let element = read_str(&mut caller, element, element_len)?;
+            let msg = CapnpMsg::from_bytes(&read(&mut caller, msg, msg_len)?)?;
+            caller.data().outarr.get(&port)
+                .and_then(|outarr| outarr.get(&element))
+                .ok_or(result::Error::ArrayOutputPortNotConnected("wasm".into(), port.clone(), element.clone()))?
+                .send(msg)?;
+            Ok(0)
+        }) as i32
+    })?;
+
+    linker.func_wrap("fractalide", "log", |mut caller: Caller<'_, Host>, level: i32, msg: i32, msg_len: i32| {
+        if let Ok(msg) = read_str(&mut caller, msg, msg_len) {
+            let level = match level {
+                1 => Level::Error,
+                2 => Level::Warn,
+                3 => Level::Info,
+                4 => Level::Debug,
+                _ => Level::Trace,
+            };
+            caller.data().log.log(level, None, format_args!("{}", msg));
+        }
+    })?;
+
+    Ok(linker)
+}
+
+fn or_negative<F: FnMut() -> Result<i64>>(mut f: F) -> i64 {
+    f().unwrap_or(-1)
+}
+
+fn memory(caller: &mut Caller<'_, Host>) -> Result<Memory> {
+    caller.get_export("memory")
+        .and_then(|export| export.into_memory())
+        .ok_or(result::Error::Wasm("the agent exports no memory".into()))
+}
+
+fn read(caller: &mut Caller<'_, Host>, ptr: i32, len: i32) -> Result<Vec<u8>> {
+    let memory = memory(caller)?;
+    let mut bytes = vec![0u8; len as u32 as usize];
+    memory.read(&*caller, ptr as u32 as usize, &mut bytes).map_err(|e| result::Error::Wasm(e.to_string()))?;
+    Ok(bytes)
+}
+
+fn read_str(caller: &mut Caller<'_, Host>, ptr: i32, len: i32) -> Result<String> {
+    Ok(String::from_utf8(read(caller, ptr, len)?)?)
+}
+
+// Copy `bytes` in a buffer allocated by the agent
+fn write(caller: &mut Caller<'_, Host>, bytes: &[u8]) -> Result<i64> {
+    let alloc = caller.get_export("fbp_alloc")
+        .and_then(|export| export.into_func())
+        .ok_or(result::Error::Wasm("the agent exports no fbp_alloc".into()))?;
+    let ptr = alloc.typed::<i32, i32>(&*caller)?.call(&mut *caller, bytes.len() as i32)?;
+    memory(caller)?.write(&mut *caller, ptr as u32 as usize, bytes).map_err(|e| result::Error::Wasm(e.to_string()))?;
+    Ok(pack(ptr, bytes.len()))
+}
+
+fn pack(ptr: i32, len: usize) -> i64 {
+    ((ptr as u32 as i64) << 32) | len as u32 as i64
+}
+
+// Read a buffer returned by the agent
+fn read_packed(store: &mut Store<Host>, instance: &Instance, packed: i64) -> Result<Vec<u8>> {
+    if packed < 0 {
+        return Err(result::Error::Wasm("the agent returned an error".into()));
+    }
+    let memory = instance.get_memory(&mut *store, "memory").ok_or(result::Error::Wasm("the agent exports no memory".into()))?;
+    let mut bytes = vec![0u8; (packed & 0xffff_ffff) as usize];
+    memory.read(&*store, (packed >> 32) as usize, &mut bytes).map_err(|e| result::Error::Wasm(e.to_string()))?;
+    Ok(bytes)
+}
+
+/* The functions of the dylib agents, the same for all the ports of a wasm agent */
+
+pub extern fn clone_input(_port: &str, sender: &Box<Any + Send>) -> Result<Box<Any + Send>> {
+    let s = sender.downcast_ref::<MsgSender<CapnpMsg>>().ok_or(result::Error::Wasm("not a CapnpMsg sender".into()))?;
+    Ok(Box::new(s.clone()))
+}
+
+pub extern fn watch_input(_port: &str, sender: &Box<Any + Send>) -> Result<Arc<PortWatch>> {
+    let s = sender.downcast_ref::<MsgSender<CapnpMsg>>().ok_or(result::Error::Wasm("not a CapnpMsg sender".into()))?;
+    Ok(s.watch())
+}
+
+pub extern fn close_input(_port: &str, sender: &Box<Any + Send>) -> Result<()> {
+    let s = sender.downcast_ref::<MsgSender<CapnpMsg>>().ok_or(result::Error::Wasm("not a CapnpMsg sender".into()))?;
+    s.close()
+}
+
+pub extern fn reopen_input(_port: &str, sender: &Box<Any + Send>) -> Result<()> {
+    let s = sender.downcast_ref::<MsgSender<CapnpMsg>>().ok_or(result::Error::Wasm("not a CapnpMsg sender".into()))?;
+    s.reopen()
+}
+
+pub extern fn throttle_input(_port: &str, sender: &Box<Any + Send>, throttle: Arc<Throttle>) -> Result<Box<Any + Send>> {
+    let s = sender.downcast_ref::<MsgSender<CapnpMsg>>().ok_or(result::Error::Wasm("not a CapnpMsg sender".into()))?;
+    Ok(Box::new(s.throttled(throttle)))
+}
+
+pub extern fn create_input_array(_port: &str, id: usize, sched: Sender<CompMsg>, must_sched: bool) -> Result<(Box<Any + Send>, Box<Any + Send>)> {
+    let (r, s) = MsgReceiver::<CapnpMsg>::new(id, sched, must_sched);
+    Ok((Box::new(r), Box::new(s)))
+}
+
+pub extern fn remote_output(_port: &str, id: usize, sched: Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)> {
+    let (r, s) = MsgReceiver::<CapnpMsg>::new(id, sched, false);
+    let reader = (&&Remote::<CapnpMsg>::new()).reader(r)?;
+    Ok((Box::new(s), reader))
+}
+
+pub extern fn remote_pipe(port: &str, id: usize, sched: Sender<CompMsg>) -> Result<(Box<Any + Send>, RemoteReader)> {
+    // All the ports of a wasm agent have the same edge
+    remote_output(port, id, sched)
+}
+
+pub extern fn remote_input(_port: &str, sender: &Box<Any + Send>) -> Result<RemoteWriter> {
+    let s = sender.downcast_ref::<MsgSender<CapnpMsg>>().ok_or(result::Error::Wasm("not a CapnpMsg sender".into()))?;
+    (&&Remote::<CapnpMsg>::new()).writer(s.clone())
+}
+
+pub extern fn pool_input(port: &str, senders: Vec<Box<Any + Send>>, mode: PoolInput, sched: Sender<CompMsg>) -> Result<Box<Any + Send>> {
+    let mut targets = vec![];
+    for s in senders {
+        targets.push(downcast_sender(port, s)?);
+    }
+    let (r, s) = MsgReceiver::<CapnpMsg>::new(0, sched, false);
+    balance(r, targets, mode);
+    Ok(Box::new(s) as Box<Any + Send>)
+}
+
+pub extern fn pool_output(_port: &str, target: &Box<Any + Send>, size: usize, sched: Sender<CompMsg>) -> Result<Vec<Box<Any + Send>>> {
+    let target = target.downcast_ref::<MsgSender<CapnpMsg>>().ok_or(result::Error::Wasm("not a CapnpMsg sender".into()))?.clone();
+    let mut recvs = vec![];
+    let mut senders = vec![];
+    for _ in 0..size {
+        let (r, s) = MsgReceiver::<CapnpMsg>::new(0, sched.clone(), false);
+        recvs.push(r);
+        senders.push(Box::new(s) as Box<Any + Send>);
+    }
+    merge_ordered(recvs, target);
+    Ok(senders)
+}
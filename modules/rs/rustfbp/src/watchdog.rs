@@ -0,0 +1,184 @@
+//! Watch a running network for deadlocks and starving agents
+//!
+//! The scheduler keeps the state of every input port (see `PortWatch`) and the edges between them. The watchdog
+//! reads them periodically, builds the graph of the agents waiting for each other, and reports :
+//!
+//! * the cycles of mutually blocked agents, which will never move again,
+//! * the ports where messages wait, but that nobody read for longer than a threshold.
+
+use crate::ports::PortWatch;
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::Ordering;
+use std::sync::mpsc::{Receiver, channel};
+use std::thread;
+use std::time::Duration;
+
+/// An edge of the network, from an output port to an input port
+///
+/// The element of an array port is written `port[element]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Link {
+    pub out_agent: String,
+    pub out_port: String,
+    pub in_agent: String,
+    pub in_port: String,
+}
+
+/// The input ports and the edges of a network, as seen by the watchdog
+#[derive(Default)]
+pub struct Network {
+    pub ports: HashMap<(String, String), Arc<PortWatch>>,
+    pub links: Vec<Link>,
+}
+
+impl Network {
+    /// Forget an agent, its ports and its edges
+    pub fn remove_agent(&mut self, agent: &str) {
+        self.ports.retain(|&(ref a, _), _| a != agent);
+        self.links.retain(|l| l.out_agent != agent && l.in_agent != agent);
+    }
+
+    /// Forget the edges of an output port
+    pub fn disconnect(&mut self, agent: &str, port: &str) {
+        self.links.retain(|l| l.out_agent != agent || l.out_port != port);
+    }
+}
+
+pub type SharedNetwork = Arc<Mutex<Network>>;
+
+/// An agent blocked on one of its edges
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Wait {
+    /// The blocked agent
+    pub agent: String,
+    /// Its port
+    pub port: String,
+    /// The agent at the other end of the edge
+    pub peer: String,
+    /// The port of the other end
+    pub peer_port: String,
+    /// True if the agent waits for room in the port of the peer, false if it waits for a message of the peer
+    pub sending: bool,
+}
+
+/// A problem found by the watchdog
+#[derive(Debug, Clone, PartialEq)]
+pub enum Diagnostic {
+    /// The agents wait for each other, in a cycle
+    Deadlock(Vec<Wait>),
+    /// Messages wait in the port, but the agent did not read any for `idle`
+    Starved {
+        agent: String,
+        port: String,
+        queued: usize,
+        idle: Duration,
+    },
+}
+
+/// Inspect the network every `period`, until the returned receiver is dropped
+///
+/// A deadlock is only reported if it is still there at the next inspection, as agents are often blocked for a
+/// short time. A port is reported as starved once, until it is read again.
+pub fn watch(network: SharedNetwork, period: Duration, starvation: Duration) -> Receiver<Diagnostic> {
+    let (s, r) = channel();
+    thread::spawn(move || {
+        let mut last_cycles: HashSet<Vec<Wait>> = HashSet::new();
+        let mut starved: HashSet<(String, String)> = HashSet::new();
+        loop {
+            thread::sleep(period);
+            let mut diagnostics = vec![];
+            {
+                let network = network.lock().expect("watchdog : poisoned network");
+
+                let cycles: HashSet<Vec<Wait>> = find_cycles(&waits(&network)).into_iter().collect();
+                for cycle in cycles.intersection(&last_cycles) {
+                    diagnostics.push(Diagnostic::Deadlock(cycle.clone()));
+                }
+                last_cycles = cycles;
+
+                for (&(ref agent, ref port), watch) in &network.ports {
+                    let key = (agent.clone(), port.clone());
+                    let queued = watch.queued.load(Ordering::SeqCst);
+                    let idle = watch.idle_for();
+                    if queued > 0 && idle > starvation {
+                        if starved.insert(key) {
+                            diagnostics.push(Diagnostic::Starved {
+                                agent: agent.clone(),
+                                port: port.clone(),
+                                queued: queued,
+                                idle: idle,
+                            });
+                        }
+                    } else {
+                        starved.remove(&key);
+                    }
+                }
+            }
+            for diagnostic in diagnostics {
+                if s.send(diagnostic).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+    r
+}
+
+// The blocked agents, by agent
+fn waits(network: &Network) -> HashMap<String, Vec<Wait>> {
+    let mut waits: HashMap<String, Vec<Wait>> = HashMap::new();
+    for link in &network.links {
+        let watch = match network.ports.get(&(link.in_agent.clone(), link.in_port.clone())) {
+            Some(watch) => watch,
+            None => { continue; }
+        };
+        // The port is full : the senders wait for its agent
+        if watch.blocked_senders.load(Ordering::SeqCst) > 0 {
+            waits.entry(link.out_agent.clone()).or_insert_with(Vec::new).push(Wait {
+                agent: link.out_agent.clone(),
+                port: link.out_port.clone(),
+                peer: link.in_agent.clone(),
+                peer_port: link.in_port.clone(),
+                sending: true,
+            });
+        }
+        // The port is empty : its agent waits for the senders
+        if watch.receiving.load(Ordering::SeqCst) {
+            waits.entry(link.in_agent.clone()).or_insert_with(Vec::new).push(Wait {
+                agent: link.in_agent.clone(),
+                port: link.in_port.clone(),
+                peer: link.out_agent.clone(),
+                peer_port: link.out_port.clone(),
+                sending: false,
+            });
+        }
+    }
+    waits
+}
+
+// The cycles of the wait graph, each one starting from its smallest agent name
+fn find_cycles(waits: &HashMap<String, Vec<Wait>>) -> Vec<Vec<Wait>> {
+    let mut cycles = vec![];
+    for start in waits.keys() {
+        let mut path = vec![];
+        visit(waits, start, start, &mut path, &mut cycles);
+    }
+    cycles
+}
+
+fn visit(waits: &HashMap<String, Vec<Wait>>, start: &str, agent: &str, path: &mut Vec<Wait>, cycles: &mut Vec<Vec<Wait>>) {
+    for wait in waits.get(agent).map(|w| &w[..]).unwrap_or(&[]) {
+        if wait.peer == start {
+            let mut cycle = path.clone();
+            cycle.push(wait.clone());
+            cycles.push(cycle);
+        } else if wait.peer.as_str() > start && !path.iter().any(|w| w.agent == wait.peer) {
+            // Only the agents after the start, so each cycle is found once
+            path.push(wait.clone());
+            visit(waits, start, &wait.peer, path, cycles);
+            path.pop();
+        }
+    }
+}
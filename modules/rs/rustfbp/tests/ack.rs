@@ -0,0 +1,62 @@
+//! The ports in ack mode
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::deadletter::Reason;
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// Sends back a number the second time it sees it, and rejects 0
+pub mod ack_retry {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: u64 [ack]),
+        output(output: u64),
+        state(HashSet<u64> => HashSet::new()),
+        fn run(&mut self) -> Result<Signal> {
+            let msg = self.input.input.recv_ack()?;
+            if *msg == 0 {
+                msg.reject();
+            } else if self.state.insert(*msg) {
+                msg.nack()?;
+            } else {
+                self.output.output.send(*msg)?;
+                msg.ack();
+            }
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+mock_agent!(ack_sink, input(input: u64));
+
+#[test]
+fn a_message_not_acknowledged_is_sent_again_and_a_rejected_one_is_a_dead_letter() {
+    let retry = Mock::new("ack_retry", ack_retry::exports());
+    let sink = ack_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("retry", retry.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.connect("retry", "output", "sink", "input").unwrap();
+    net.send("retry", "input", 1u64).unwrap();
+    net.send("retry", "input", 0u64).unwrap();
+    net.run(DEADLINE).unwrap();
+
+    // 1 went through the port twice
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![1]);
+    let letters = net.dead_letters();
+    assert_eq!(letters.len(), 1);
+    assert_eq!((&letters[0].agent as &str, &letters[0].port as &str), ("retry", "input"));
+    assert_eq!(letters[0].reason, Reason::Rejected);
+    assert_eq!(letters[0].msg.downcast_ref::<u64>(), Some(&0));
+    net.join();
+}
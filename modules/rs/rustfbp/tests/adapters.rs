@@ -0,0 +1,53 @@
+//! The connections through an adapter
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::testing::TestNetwork;
+
+use std::time::Duration;
+
+mock_agent!(adapted_source, input(input: u64), output(output: u64));
+mock_agent!(adapted_to_text, input(input: u64), output(output: String));
+mock_agent!(adapted_double, input(input: u64), output(output: u64));
+mock_agent!(adapted_sink, input(input: String));
+mock_agent!(wrong_source, input(input: u64), output(output: u64));
+mock_agent!(wrong_sink, input(input: String));
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+#[test]
+fn an_adapter_converts_the_messages_between_two_edges() {
+    let source = adapted_source::mock();
+    source.respond("input", "output", |n: &u64| *n);
+    let to_text = adapted_to_text::mock();
+    to_text.respond("input", "output", |n: &u64| format!("#{}", n));
+    let sink = adapted_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("source", source.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    assert!(net.connect("source", "output", "sink", "input").is_err());
+    net.sched().connect_adapted("source", "output", to_text.sort(), "sink", "input").unwrap();
+    assert!(net.sched().agents.contains_key("source.output>sink.input"));
+
+    net.send("source", "input", 1u64).unwrap();
+    net.send("source", "input", 2u64).unwrap();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(sink.received::<String>("input").unwrap(), vec!["#1".to_string(), "#2".to_string()]);
+    net.join();
+}
+
+#[test]
+fn an_adapter_of_the_wrong_edge_is_removed() {
+    let source = wrong_source::mock();
+    let double = adapted_double::mock();
+    let sink = wrong_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("source", source.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    assert!(net.sched().connect_adapted("source", "output", double.sort(), "sink", "input").is_err());
+    assert!(!net.sched().agents.contains_key("source.output>sink.input"));
+    net.join();
+}
@@ -0,0 +1,32 @@
+//! The admin endpoint, built with the feature `admin`
+//!
+//! Run with `cargo test --features admin` in modules/rs/rustfbp.
+
+#![cfg(feature = "admin")]
+
+extern crate rustfbp;
+
+use rustfbp::scheduler::Scheduler;
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[test]
+fn a_silent_client_does_not_hold_the_others() {
+    let sched = Arc::new(Mutex::new(Scheduler::new()));
+    let addr = rustfbp::admin::serve("127.0.0.1:0", sched).expect("serve");
+
+    // Connected, but never sends its request
+    let _silent = TcpStream::connect(addr).expect("connect the silent client");
+
+    let begin = Instant::now();
+    let mut client = TcpStream::connect(addr).expect("connect");
+    client.set_read_timeout(Some(Duration::from_secs(10))).expect("timeout");
+    client.write_all(b"GET /nodes HTTP/1.1\r\nHost: localhost\r\n\r\n").expect("send the request");
+    let mut answer = String::new();
+    client.read_to_string(&mut answer).expect("read the answer");
+    assert!(answer.starts_with("HTTP/1.1 200 OK"), "{}", answer);
+    assert!(begin.elapsed() < Duration::from_secs(2), "answered after {:?}", begin.elapsed());
+}
@@ -0,0 +1,72 @@
+//! The messages built in the arena of a pooled connection
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+extern crate capnp;
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::ports::Arena;
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::time::Duration;
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// Sends a text message built with the builder of its output port for each number
+pub mod pooled_source {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: u64),
+        output(output: CapnpMsg),
+        fn run(&mut self) -> Result<Signal> {
+            let n = self.input.input.recv()?;
+            let mut builder = self.output.output.new_builder();
+            builder.set_root::<capnp::text::Builder, _>(&format!("message {}", n) as &str)?;
+            self.output.output.send(CapnpMsg::new(builder))?;
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+// Drops the messages it receives
+pub mod pooled_sink {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: CapnpMsg),
+        fn run(&mut self) -> Result<Signal> {
+            while self.input.input.try_recv().is_ok() {}
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+#[test]
+fn a_pooled_connection_reuses_the_segments() {
+    let source = Mock::new("pooled_source", pooled_source::exports());
+    let sink = Mock::new("pooled_sink", pooled_sink::exports());
+    let arena = Arena::default();
+    let mut net = TestNetwork::new();
+    net.add_node("source", source.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.sched().connect_pooled("source", "output", "sink", "input", arena.clone()).unwrap();
+    for n in 0..10u64 {
+        net.send("source", "input", n).unwrap();
+        net.run(DEADLINE).unwrap();
+    }
+    // One message at a time : the first one allocates its segment, the next ones reuse it
+    let stats = arena.stats();
+    assert_eq!(stats.allocated, 1);
+    assert_eq!(stats.reused, 9);
+    {
+        let network = net.sched().network();
+        let network = network.lock().unwrap();
+        let watch = &network.ports[&("sink".to_string(), "input".to_string())];
+        assert_eq!(watch.arena_stats(), Some(stats));
+    }
+    net.join();
+}
@@ -0,0 +1,49 @@
+//! The blocking agents, which run on their own thread instead of the workers
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+use std::time::Duration;
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// The threads of the runs of `blocker`
+static THREADS: Mutex<Vec<ThreadId>> = Mutex::new(Vec::new());
+
+pub mod blocker {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: u64),
+        blocking(true),
+        fn run(&mut self) -> Result<Signal> {
+            while self.input.input.try_recv().is_ok() {}
+            THREADS.lock().unwrap().push(thread::current().id());
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+#[test]
+fn a_blocking_agent_keeps_its_thread() {
+    let blocker = Mock::new("blocker", blocker::exports());
+    let mut net = TestNetwork::new();
+    net.add_node("blocker", blocker.sort()).unwrap();
+    net.run(DEADLINE).unwrap();
+    for n in 0..5u64 {
+        net.send("blocker", "input", n).unwrap();
+        net.run(DEADLINE).unwrap();
+    }
+    net.join();
+    let threads = THREADS.lock().unwrap();
+    assert_eq!(threads.len(), 5);
+    assert!(threads.iter().all(|thread| *thread == threads[0]));
+    assert_ne!(threads[0], thread::current().id());
+}
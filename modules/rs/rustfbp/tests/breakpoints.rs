@@ -0,0 +1,43 @@
+//! The breakpoints on the input ports
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::testing::TestNetwork;
+
+use std::thread;
+use std::time::Duration;
+
+mock_agent!(break_sink, input(input: u64));
+
+const DEADLINE: Duration = Duration::from_secs(10);
+// Long enough for a running agent to take its messages
+const WAIT: Duration = Duration::from_millis(300);
+
+#[test]
+fn a_breakpoint_holds_the_messages_until_step() {
+    let sink = break_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.run(DEADLINE).unwrap();
+
+    net.sched().set_breakpoint("sink", "input").unwrap();
+    for n in 1..4u64 {
+        net.send("sink", "input", n).unwrap();
+    }
+    thread::sleep(WAIT);
+    assert!(sink.received::<u64>("input").unwrap().is_empty());
+
+    assert_eq!(net.sched().step("sink", "input", 1).unwrap(), 1);
+    thread::sleep(WAIT);
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![1]);
+
+    net.sched().clear_breakpoint("sink", "input").unwrap();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![2, 3]);
+    // Nothing is held anymore
+    assert_eq!(net.sched().step("sink", "input", 1).unwrap(), 0);
+    net.join();
+}
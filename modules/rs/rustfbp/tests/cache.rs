@@ -0,0 +1,27 @@
+//! The errors of the agent cache, on the ports of a loaded agent
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::result::Error;
+use rustfbp::scheduler::AgentCache;
+
+mock_agent!(adder, input(input: u64), output(output: u64));
+
+#[test]
+fn schema_of_a_missing_port() {
+    let sort = adder::mock().sort();
+    let mut cache = AgentCache::new();
+    cache.load(&sort).expect("load the mock");
+    assert_eq!(cache.get_schema_input(&sort, "input").expect("schema of input"), "u64");
+    match cache.get_schema_input(&sort, "nope") {
+        Err(Error::PortNotFound(comp, port)) => assert_eq!((comp.as_str(), port.as_str()), (sort.as_str(), "nope")),
+        other => panic!("expected PortNotFound, got {:?}", other),
+    }
+    match cache.get_schema_output_array(&sort, "output") {
+        Err(Error::PortNotFound(_, port)) => assert_eq!(port, "output"),
+        other => panic!("expected PortNotFound, got {:?}", other),
+    }
+}
@@ -0,0 +1,40 @@
+//! The dead letters of the scheduler
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::deadletter::Reason;
+use rustfbp::testing::TestNetwork;
+
+use std::time::Duration;
+
+mock_agent!(letter_source, input(input: u64), output(output: u64));
+mock_agent!(letter_alert, input(input: String));
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+#[test]
+fn a_message_sent_on_an_output_not_connected_is_a_dead_letter() {
+    let source = letter_source::mock();
+    source.respond("input", "output", |n: &u64| *n);
+    let alert = letter_alert::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("source", source.sort()).unwrap();
+    net.add_node("alert", alert.sort()).unwrap();
+    net.sched().connect_dead_letters("alert", "input").unwrap();
+    net.send("source", "input", 7u64).unwrap();
+    net.run(DEADLINE).unwrap();
+
+    let letters = net.dead_letters();
+    assert_eq!(letters.len(), 1);
+    assert_eq!((&letters[0].agent as &str, &letters[0].port as &str), ("source", "output"));
+    assert_eq!(letters[0].reason, Reason::NotConnected);
+    assert_eq!(letters[0].msg.downcast_ref::<u64>(), Some(&7));
+    // The alert gets the description of the letter, after its time
+    let alerts = alert.received::<String>("input").unwrap();
+    assert_eq!(alerts.len(), 1);
+    assert!(alerts[0].ends_with(" source() output : output port not connected"), "{}", alerts[0]);
+    net.join();
+}
@@ -0,0 +1,60 @@
+//! The durable edges, which survive a restart
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+mock_agent!(durable_source, input(input: String), output(output: String));
+mock_agent!(durable_sink, input(input: String));
+
+const DEADLINE: Duration = Duration::from_secs(10);
+// Long enough for the durable edge to write its log
+const WAIT: Duration = Duration::from_millis(300);
+
+fn durable_network(storage: &Path, source: &Mock, sink: &Mock) -> TestNetwork {
+    let mut net = TestNetwork::new();
+    net.sched().set_storage(storage);
+    net.add_node("source", source.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.sched().connect_durable("source", "output", "sink", "input").unwrap();
+    net
+}
+
+#[test]
+fn the_messages_not_taken_are_sent_again_after_a_restart() {
+    let storage = env::temp_dir().join(format!("rustfbp-durable-{}", std::process::id()));
+    let source = durable_source::mock();
+    source.respond("input", "output", |s: &String| s.clone());
+    let sink = durable_sink::mock();
+
+    // The sink is paused, so the messages stay in its port when the network stops
+    let mut net = durable_network(&storage, &source, &sink);
+    net.run(DEADLINE).unwrap();
+    net.sched().pause("sink").unwrap();
+    net.send("source", "input", "a".to_string()).unwrap();
+    net.send("source", "input", "b".to_string()).unwrap();
+    thread::sleep(WAIT);
+    net.join();
+    assert!(sink.received::<String>("input").unwrap().is_empty());
+
+    let mut net = durable_network(&storage, &source, &sink);
+    net.run(DEADLINE).unwrap();
+    net.join();
+    assert_eq!(sink.received::<String>("input").unwrap(), vec!["a".to_string(), "b".to_string()]);
+
+    // All the messages are acknowledged, nothing is sent again
+    let mut net = durable_network(&storage, &source, &sink);
+    net.run(DEADLINE).unwrap();
+    net.join();
+    assert!(sink.received::<String>("input").unwrap().is_empty());
+    fs::remove_dir_all(&storage).unwrap();
+}
@@ -0,0 +1,94 @@
+//! The end of stream of the input ports
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::time::Duration;
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// Sends the numbers from 1 to the one it receives, then closes its output
+pub mod eof_count {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: u64),
+        output(output: u64),
+        fn run(&mut self) -> Result<Signal> {
+            let n = self.input.input.recv()?;
+            for i in 1..n + 1 {
+                self.output.output.send(i)?;
+            }
+            self.output.output.close()?;
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+// Sends the sum of the numbers it received at the end of stream
+pub mod eof_sum {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: u64),
+        output(output: u64),
+        state(u64 => 0),
+        fn run(&mut self) -> Result<Signal> {
+            match self.input.input.recv() {
+                Ok(n) => { self.state += n; },
+                Err(rustfbp::result::Error::Closed) => { self.output.output.send(self.state)?; },
+                Err(e) => { return Err(e); },
+            }
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+mock_agent!(eof_close_sink, input(input: u64));
+mock_agent!(eof_removed_source, input(input: u64), output(output: u64));
+mock_agent!(eof_removed_sink, input(input: u64));
+
+fn sum_network(source: &Mock, sink: &Mock) -> TestNetwork {
+    let sum = Mock::new("eof_sum", eof_sum::exports());
+    let mut net = TestNetwork::new();
+    net.add_node("source", source.sort()).unwrap();
+    net.add_node("sum", sum.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.connect("source", "output", "sum", "input").unwrap();
+    net.connect("sum", "output", "sink", "input").unwrap();
+    net
+}
+
+#[test]
+fn a_closed_port_gives_the_end_of_stream_after_its_messages() {
+    let count = Mock::new("eof_count", eof_count::exports());
+    let sink = eof_close_sink::mock();
+    let mut net = sum_network(&count, &sink);
+    net.send("source", "input", 3u64).unwrap();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![6]);
+    net.join();
+}
+
+#[test]
+fn removing_an_agent_gives_the_end_of_stream_downstream() {
+    let source = eof_removed_source::mock();
+    source.respond("input", "output", |n: &u64| *n);
+    let sink = eof_removed_sink::mock();
+    let mut net = sum_network(&source, &sink);
+    net.send("source", "input", 4u64).unwrap();
+    net.send("source", "input", 5u64).unwrap();
+    net.run(DEADLINE).unwrap();
+    assert!(sink.received::<u64>("input").unwrap().is_empty());
+
+    net.sched().remove_agent("source").unwrap();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![9]);
+    net.join();
+}
@@ -0,0 +1,101 @@
+//! The limits of the agents, in a running network
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::limits::{LimitAction, Limits, Rate, Throttle};
+use rustfbp::ports::{SendTimeoutError, TrySendError};
+use rustfbp::testing::TestNetwork;
+
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+mock_agent!(slow, input(input: u64), output(output: u64));
+mock_agent!(held, input(input: u64), output(output: u64));
+mock_agent!(sink, input(input: u64));
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// Room for two u64 in the input port
+fn backpressure() -> Limits {
+    Limits {
+        max_queued_bytes: Some(16),
+        max_run_time: None,
+        action: LimitAction::Backpressure,
+    }
+}
+
+#[test]
+fn a_sender_over_the_limit_waits_for_room() {
+    let slow = slow::mock();
+    slow.respond("input", "output", |n: &u64| {
+        thread::sleep(Duration::from_millis(5));
+        *n
+    });
+    let mut net = TestNetwork::new();
+    net.sched().add_node_with_limits("slow", slow.sort(), backpressure()).unwrap();
+    net.capture::<u64>("slow", "output").unwrap();
+    net.run(DEADLINE).unwrap();
+    for n in 0..20u64 {
+        net.send("slow", "input", n).unwrap();
+    }
+    net.run(DEADLINE).unwrap();
+    assert_eq!(net.received::<u64>("slow", "output").unwrap(), (0..20).collect::<Vec<u64>>());
+    net.join();
+}
+
+#[test]
+fn a_sender_over_the_limit_times_out() {
+    // The agent holds the first message until the test releases it
+    let (release, gate) = mpsc::channel::<()>();
+    let gate = Mutex::new(gate);
+    let held = held::mock();
+    held.respond("input", "output", move |n: &u64| {
+        let _ = gate.lock().unwrap().recv();
+        *n
+    });
+    let mut net = TestNetwork::new();
+    net.sched().add_node_with_limits("held", held.sort(), backpressure()).unwrap();
+    net.capture::<u64>("held", "output").unwrap();
+    net.run(DEADLINE).unwrap();
+    let sender = net.sched().get_typed_sender::<u64, _, _>("held", "input").unwrap();
+    let mut sent = 0;
+    loop {
+        match sender.send_timeout(sent, Duration::from_millis(50)) {
+            Ok(()) => { sent += 1; },
+            Err(SendTimeoutError::Timeout(n)) => {
+                assert_eq!(n, sent);
+                break;
+            },
+            Err(_) => panic!("the port is still there"),
+        }
+        assert!(sent < 10, "the senders must wait over the limit");
+    }
+    drop(release);
+    net.run(DEADLINE).unwrap();
+    assert_eq!(net.received::<u64>("held", "output").unwrap(), (0..sent).collect::<Vec<u64>>());
+    net.join();
+}
+
+#[test]
+fn a_throttled_try_send_does_not_wait() {
+    let sink = sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("sink", sink.sort()).unwrap();
+    let sender = net.sched().get_typed_sender::<u64, _, _>("sink", "input").unwrap()
+        .throttled(Arc::new(Throttle::new(Rate { per_sec: 0.1, burst: 1 })));
+    sender.try_send(0).unwrap();
+    let begin = Instant::now();
+    match sender.try_send(1) {
+        Err(TrySendError::Full(1)) => {},
+        _ => panic!("the bucket is empty"),
+    }
+    assert!(begin.elapsed() < Duration::from_secs(1));
+    net.run(DEADLINE).unwrap();
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![0]);
+    net.join();
+}
@@ -0,0 +1,63 @@
+//! The input ports which drop their oldest messages when they are full
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::limits::Overflow;
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::{Duration, Instant};
+
+mock_agent!(oldest_source, input(input: u64), output(output: u64));
+mock_agent!(oldest_sink, input(input: u64));
+mock_agent!(latest_source, input(input: u64), output(output: u64));
+mock_agent!(latest_sink, input(input: u64));
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// Send 1 to 5 through a paused sink, and give the messages it gets once resumed, with the messages dropped
+fn overflow(source: Mock, sink: Mock, overflow: Overflow) -> (Vec<u64>, u64) {
+    source.respond("input", "output", |n: &u64| *n);
+    let mut net = TestNetwork::new();
+    net.add_node("source", source.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.sched().connect_with_overflow("source", "output", "sink", "input", overflow).unwrap();
+    net.run(DEADLINE).unwrap();
+
+    net.sched().pause("sink").unwrap();
+    for n in 1..6u64 {
+        net.send("source", "input", n).unwrap();
+    }
+    let watch = {
+        let network = net.sched().network();
+        let network = network.lock().unwrap();
+        network.ports[&("sink".to_string(), "input".to_string())].clone()
+    };
+    // The source never waits for the paused sink
+    let begin = Instant::now();
+    let mut sent = 0;
+    while sent < 5 && begin.elapsed() < DEADLINE {
+        sent += source.received::<u64>("input").unwrap().len();
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    net.sched().resume("sink").unwrap();
+    net.run(DEADLINE).unwrap();
+    let received = sink.received::<u64>("input").unwrap();
+    net.join();
+    (received, watch.dropped.load(Ordering::SeqCst))
+}
+
+#[test]
+fn drop_oldest_keeps_the_last_messages() {
+    assert_eq!(overflow(oldest_source::mock(), oldest_sink::mock(), Overflow::DropOldest(2)), (vec![4, 5], 3));
+}
+
+#[test]
+fn latest_only_keeps_the_last_message() {
+    assert_eq!(overflow(latest_source::mock(), latest_sink::mock(), Overflow::LatestOnly), (vec![5], 4));
+}
@@ -0,0 +1,62 @@
+//! Pausing and resuming the agents
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::testing::TestNetwork;
+
+use std::thread;
+use std::time::Duration;
+
+mock_agent!(pause_sink, input(input: u64));
+mock_agent!(pause_relay, input(input: u64), output(output: u64));
+mock_agent!(pause_all_sink, input(input: u64));
+
+const DEADLINE: Duration = Duration::from_secs(10);
+// Long enough for a running agent to take its messages
+const WAIT: Duration = Duration::from_millis(300);
+
+#[test]
+fn a_paused_agent_keeps_its_messages_until_it_is_resumed() {
+    let sink = pause_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.run(DEADLINE).unwrap();
+
+    net.sched().pause("sink").unwrap();
+    net.send("sink", "input", 1u64).unwrap();
+    net.send("sink", "input", 2u64).unwrap();
+    thread::sleep(WAIT);
+    assert!(sink.received::<u64>("input").unwrap().is_empty());
+
+    net.sched().resume("sink").unwrap();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![1, 2]);
+    net.join();
+}
+
+#[test]
+fn pause_all_holds_every_agent() {
+    let relay = pause_relay::mock();
+    relay.respond("input", "output", |n: &u64| n * 10);
+    let sink = pause_all_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("relay", relay.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.connect("relay", "output", "sink", "input").unwrap();
+    net.run(DEADLINE).unwrap();
+
+    net.sched().pause_all();
+    net.send("relay", "input", 1u64).unwrap();
+    thread::sleep(WAIT);
+    assert!(relay.received::<u64>("input").unwrap().is_empty());
+    assert!(sink.received::<u64>("input").unwrap().is_empty());
+
+    net.sched().resume_all();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(relay.received::<u64>("input").unwrap(), vec![1]);
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![10]);
+    net.join();
+}
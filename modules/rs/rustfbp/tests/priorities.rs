@@ -0,0 +1,31 @@
+//! The priorities of the messages
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::ports::Priority;
+use rustfbp::testing::TestNetwork;
+
+use std::time::Duration;
+
+mock_agent!(prio_sink, input(input: u64));
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+#[test]
+fn a_high_message_overtakes_the_waiting_ones() {
+    let sink = prio_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("sink", sink.sort()).unwrap();
+    // Before the start, the messages wait in the port
+    let sender = net.sched().get_typed_sender::<u64, _, _>("sink", "input").unwrap();
+    for n in 1..4u64 {
+        sender.send_with_priority(n, Priority::Normal).unwrap();
+    }
+    sender.send_with_priority(99, Priority::High).unwrap();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![99, 1, 2, 3]);
+    net.join();
+}
@@ -0,0 +1,57 @@
+//! The agents held until another one is ready
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::thread;
+use std::time::Duration;
+
+const DEADLINE: Duration = Duration::from_secs(10);
+// Long enough for a running agent to take its messages
+const WAIT: Duration = Duration::from_millis(300);
+
+// Ready once it receives its connection
+pub mod ready_db {
+    use super::*;
+
+    agent!(@exports[]
+        input(connect: u64),
+        fn run(&mut self) -> Result<Signal> {
+            self.input.connect.recv()?;
+            self.ready()?;
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+mock_agent!(ready_api, input(input: u64));
+
+#[test]
+fn a_held_agent_runs_once_the_other_is_ready() {
+    let db = Mock::new("ready_db", ready_db::exports());
+    let api = ready_api::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("db", db.sort()).unwrap();
+    net.add_node("api", api.sort()).unwrap();
+    net.sched().start_after("api", "db").unwrap();
+    net.run(DEADLINE).unwrap();
+
+    net.send("api", "input", 1u64).unwrap();
+    net.send("api", "input", 2u64).unwrap();
+    thread::sleep(WAIT);
+    assert!(api.received::<u64>("input").unwrap().is_empty());
+    // `resume` doesn't release it
+    net.sched().resume("api").unwrap();
+    thread::sleep(WAIT);
+    assert!(api.received::<u64>("input").unwrap().is_empty());
+
+    net.send("db", "connect", 0u64).unwrap();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(api.received::<u64>("input").unwrap(), vec![1, 2]);
+    net.join();
+}
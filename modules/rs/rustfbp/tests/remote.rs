@@ -0,0 +1,112 @@
+//! The remote edges, against a listener or a sender played by the test
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::deadletter::Reason;
+use rustfbp::testing::TestNetwork;
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+mock_agent!(remote_source, input(input: String), output(output: String));
+mock_agent!(remote_sink, input(input: String));
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+fn write_frame(stream: &mut TcpStream, bytes: &[u8]) {
+    stream.write_all(&(bytes.len() as u32).to_be_bytes()).unwrap();
+    stream.write_all(bytes).unwrap();
+}
+
+fn read_frame(stream: &mut TcpStream) -> Vec<u8> {
+    let mut len = [0u8; 4];
+    stream.read_exact(&mut len).unwrap();
+    let mut bytes = vec![0u8; u32::from_be_bytes(len) as usize];
+    stream.read_exact(&mut bytes).unwrap();
+    bytes
+}
+
+fn read_answer(stream: &mut TcpStream) -> u8 {
+    let mut answer = [0u8; 1];
+    stream.read_exact(&mut answer).unwrap();
+    answer[0]
+}
+
+#[test]
+fn a_message_rejected_by_the_listener_is_a_dead_letter() {
+    // Rejects the first message and accepts the next ones
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    let (frames_s, frames) = mpsc::channel::<Vec<u8>>();
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        read_frame(&mut stream);
+        stream.write_all(&[1]).unwrap();
+        let mut answer = 2;
+        loop {
+            let mut len = [0u8; 4];
+            if stream.read_exact(&mut len).is_err() { return; }
+            let mut bytes = vec![0u8; u32::from_be_bytes(len) as usize];
+            stream.read_exact(&mut bytes).unwrap();
+            frames_s.send(bytes).unwrap();
+            stream.write_all(&[answer]).unwrap();
+            answer = 1;
+        }
+    });
+
+    let source = remote_source::mock();
+    source.respond("input", "output", |s: &String| s.clone());
+    let mut net = TestNetwork::new();
+    net.add_node("source", source.sort()).unwrap();
+    net.sched().connect_remote("source", "output", addr, "far", "input").unwrap();
+    net.send("source", "input", "bad".to_string()).unwrap();
+    net.send("source", "input", "good".to_string()).unwrap();
+    net.run(DEADLINE).unwrap();
+
+    // Each message is sent once
+    assert_eq!(frames.recv_timeout(DEADLINE).unwrap(), b"bad".to_vec());
+    assert_eq!(frames.recv_timeout(DEADLINE).unwrap(), b"good".to_vec());
+    assert!(frames.recv_timeout(Duration::from_millis(300)).is_err());
+
+    let begin = Instant::now();
+    let mut letters = net.dead_letters();
+    while letters.is_empty() && begin.elapsed() < DEADLINE {
+        thread::sleep(Duration::from_millis(10));
+        letters = net.dead_letters();
+    }
+    assert_eq!(letters.len(), 1);
+    assert_eq!((&letters[0].agent as &str, &letters[0].port as &str), ("source", "output"));
+    assert_eq!(letters[0].reason, Reason::RemoteRejected);
+    assert_eq!(letters[0].msg.downcast_ref::<Vec<u8>>(), Some(&b"bad".to_vec()));
+    net.join();
+}
+
+#[test]
+fn the_listener_rejects_a_message_it_cannot_read_and_goes_on() {
+    let sink = remote_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("sink", sink.sort()).unwrap();
+    // A free port for the listener
+    let addr = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().to_string();
+    net.sched().listen_remote(&addr as &str).unwrap();
+    let schema = net.sched().get_schema_input("sink", "input").unwrap();
+
+    let mut stream = TcpStream::connect(&addr as &str).unwrap();
+    write_frame(&mut stream, format!("sink\0input\0{}", schema).as_bytes());
+    assert_eq!(read_answer(&mut stream), 1);
+    // Not UTF-8
+    write_frame(&mut stream, &[0xff, 0xfe]);
+    assert_eq!(read_answer(&mut stream), 2);
+    write_frame(&mut stream, b"hello");
+    assert_eq!(read_answer(&mut stream), 1);
+
+    net.run(DEADLINE).unwrap();
+    assert_eq!(sink.received::<String>("input").unwrap(), vec!["hello".to_string()]);
+    net.join();
+}
@@ -0,0 +1,131 @@
+//! Random sequences of changes to a running network of echo agents
+//!
+//! After each change the network must become idle, every message sent must come out of an echo or be a dead
+//! letter, and the scheduler must stop at the end.
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+extern crate proptest;
+#[macro_use]
+extern crate rustfbp;
+
+use proptest::prelude::*;
+
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::time::Duration;
+
+mock_agent!(echo, input(input: u64), output(output: u64));
+
+// The operations of a sequence, and the time given to the network to become idle after each of them
+const OPS: usize = 40;
+const DEADLINE: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone)]
+enum Op {
+    Add,
+    // The nodes are picked among the ones added, by their index modulo their number
+    Connect(usize, usize),
+    Disconnect(usize),
+    Send(usize, u64),
+    Remove(usize),
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        Just(Op::Add),
+        (any::<usize>(), any::<usize>()).prop_map(|(a, b)| Op::Connect(a, b)),
+        any::<usize>().prop_map(Op::Disconnect),
+        (any::<usize>(), any::<u64>()).prop_map(|(n, msg)| Op::Send(n, msg)),
+        any::<usize>().prop_map(Op::Remove),
+    ]
+}
+
+// The network, and the nodes added to it, None once removed
+struct Model {
+    net: TestNetwork,
+    echo: Mock,
+    nodes: Vec<Option<String>>,
+    sent: usize,
+    // Out of an echo, or a dead letter
+    accounted: usize,
+}
+
+impl Model {
+    fn new() -> Self {
+        let echo = echo::mock();
+        echo.respond("input", "output", |n: &u64| *n);
+        Model {
+            net: TestNetwork::new(),
+            echo: echo,
+            nodes: vec![],
+            sent: 0,
+            accounted: 0,
+        }
+    }
+
+    // The index and the name of a node still in the network
+    fn pick(&self, n: usize) -> Option<(usize, String)> {
+        if self.nodes.is_empty() {
+            return None;
+        }
+        let i = n % self.nodes.len();
+        self.nodes[i].clone().map(|name| (i, name))
+    }
+
+    fn apply(&mut self, op: &Op) -> rustfbp::result::Result<()> {
+        match *op {
+            Op::Add => {
+                let name = format!("echo{}", self.nodes.len());
+                self.net.add_node(&name as &str, self.echo.sort())?;
+                self.net.capture::<u64>(&name, "output")?;
+                self.nodes.push(Some(name));
+            },
+            // Only towards a newer node, a cycle of echos would never be idle
+            Op::Connect(a, b) => {
+                if let (Some((i, a)), Some((j, b))) = (self.pick(a), self.pick(b)) {
+                    if i < j {
+                        self.net.connect(&a, "output", &b, "input")?;
+                    }
+                }
+            },
+            Op::Disconnect(a) => {
+                if let Some((_, a)) = self.pick(a) {
+                    self.net.sched().disconnect(a, "output")?;
+                }
+            },
+            Op::Send(n, msg) => {
+                if let Some((_, n)) = self.pick(n) {
+                    self.net.send(&n, "input", msg)?;
+                    self.sent += 1;
+                }
+            },
+            Op::Remove(n) => {
+                if let Some((i, n)) = self.pick(n) {
+                    self.net.sched().remove_agent(n)?;
+                    self.nodes[i] = None;
+                }
+            },
+        }
+        self.net.run(DEADLINE)?;
+        for i in 0..self.nodes.len() {
+            self.accounted += self.net.received::<u64>(&format!("echo{}", i), "output")?.len();
+        }
+        self.accounted += self.net.dead_letters().len();
+        Ok(())
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn connect_disconnect(ops in prop::collection::vec(op(), 1..OPS)) {
+        let mut model = Model::new();
+        for op in &ops {
+            prop_assert!(model.apply(op).is_ok(), "{:?} failed", op);
+        }
+        prop_assert_eq!(model.sent, model.accounted);
+        model.net.join();
+    }
+}
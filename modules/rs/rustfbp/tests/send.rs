@@ -0,0 +1,75 @@
+//! The sends that don't wait : `try_send` and `send_timeout`
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::ports::{SendTimeoutError, TrySendError};
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// What the sends of `flow_source` gave back
+static FULL: Mutex<Vec<TrySendError<u64>>> = Mutex::new(Vec::new());
+static NOT_CONNECTED: Mutex<Vec<TrySendError<u64>>> = Mutex::new(Vec::new());
+static TIMEOUT: Mutex<Vec<SendTimeoutError<u64>>> = Mutex::new(Vec::new());
+
+// Tries to send the numbers below the one it receives, keeps the ones that don't fit
+pub mod flow_source {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: u64),
+        output(output: u64, spare: u64),
+        fn run(&mut self) -> Result<Signal> {
+            let n = self.input.input.recv()?;
+            for i in 0..n {
+                if let Err(e) = self.output.output.try_send(i) {
+                    FULL.lock().unwrap().push(e);
+                }
+            }
+            if let Err(e) = self.output.spare.try_send(n) {
+                NOT_CONNECTED.lock().unwrap().push(e);
+            }
+            if let Err(e) = self.output.output.send_timeout(n, Duration::from_millis(50)) {
+                TIMEOUT.lock().unwrap().push(e);
+            }
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+mock_agent!(flow_sink, input(input: u64));
+
+#[test]
+fn a_full_port_gives_the_messages_back() {
+    let source = Mock::new("flow_source", flow_source::exports());
+    let sink = flow_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("source", source.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.connect("source", "output", "sink", "input").unwrap();
+    net.run(DEADLINE).unwrap();
+
+    // The paused sink leaves its port full after 25 messages
+    net.sched().pause("sink").unwrap();
+    net.send("source", "input", 30u64).unwrap();
+    let begin = Instant::now();
+    while TIMEOUT.lock().unwrap().is_empty() && begin.elapsed() < DEADLINE {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(*FULL.lock().unwrap(), (25..30).map(TrySendError::Full).collect::<Vec<_>>());
+    assert_eq!(*NOT_CONNECTED.lock().unwrap(), vec![TrySendError::Disconnected(30)]);
+    assert_eq!(*TIMEOUT.lock().unwrap(), vec![SendTimeoutError::Timeout(30)]);
+
+    net.sched().resume("sink").unwrap();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(sink.received::<u64>("input").unwrap(), (0..25).collect::<Vec<_>>());
+    net.join();
+}
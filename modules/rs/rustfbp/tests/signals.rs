@@ -0,0 +1,97 @@
+//! The signals returned by the agents to the scheduler
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::time::Duration;
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// Counts down from the number it receives, one number per execution
+pub mod signal_countdown {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: u64),
+        output(output: u64),
+        state(u64 => 0),
+        fn run(&mut self) -> Result<Signal> {
+            if self.state == 0 {
+                self.state = self.input.input.recv()?;
+            }
+            self.output.output.send(self.state)?;
+            self.state -= 1;
+            Ok(if self.state > 0 { Yield } else { End })
+        }
+    );
+    agent!(@exports_fn);
+}
+
+// Adds each number of `a` to the next number of `b`
+pub mod signal_zip {
+    use super::*;
+
+    agent!(@exports[]
+        input(a: u64, b: u64),
+        output(output: u64),
+        state(Option<u64> => None),
+        fn run(&mut self) -> Result<Signal> {
+            match self.state.take() {
+                None => {
+                    self.state = Some(self.input.a.recv()?);
+                    Ok(WaitFor("b".into()))
+                }
+                Some(a) => {
+                    let b = self.input.b.recv()?;
+                    self.output.output.send(a + b)?;
+                    Ok(End)
+                }
+            }
+        }
+    );
+    agent!(@exports_fn);
+}
+
+mock_agent!(signal_countdown_sink, input(input: u64));
+mock_agent!(signal_zip_sink, input(input: u64));
+
+#[test]
+fn a_yielding_agent_runs_again_without_message() {
+    let countdown = Mock::new("signal_countdown", signal_countdown::exports());
+    let sink = signal_countdown_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("countdown", countdown.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.connect("countdown", "output", "sink", "input").unwrap();
+    net.send("countdown", "input", 3u64).unwrap();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![3, 2, 1]);
+    net.join();
+}
+
+#[test]
+fn an_agent_waiting_for_a_port_holds_its_other_ports() {
+    let zip = Mock::new("signal_zip", signal_zip::exports());
+    let sink = signal_zip_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("zip", zip.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.connect("zip", "output", "sink", "input").unwrap();
+    net.run(DEADLINE).unwrap();
+
+    // The second `a` waits in its port while `zip` waits for `b`
+    net.send("zip", "a", 1u64).unwrap();
+    net.send("zip", "a", 2u64).unwrap();
+    net.sched().wait_idle().unwrap();
+    assert!(sink.received::<u64>("input").unwrap().is_empty());
+
+    net.send("zip", "b", 10u64).unwrap();
+    net.send("zip", "b", 20u64).unwrap();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![11, 22]);
+    net.join();
+}
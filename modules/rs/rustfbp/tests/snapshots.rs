@@ -0,0 +1,67 @@
+//! The snapshots of the state of the agents
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::env;
+use std::fs;
+use std::time::Duration;
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// Sends the number of messages it received so far
+pub mod snapshot_counter {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: u64),
+        output(output: u64),
+        state_serde(u64 => 0),
+        fn run(&mut self) -> Result<Signal> {
+            self.input.input.recv()?;
+            self.state += 1;
+            self.output.output.send(self.state)?;
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+mock_agent!(snapshot_sink, input(input: u64));
+
+fn counter_network(counter: &Mock, sink: &Mock) -> TestNetwork {
+    let mut net = TestNetwork::new();
+    net.add_node("counter", counter.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.connect("counter", "output", "sink", "input").unwrap();
+    net
+}
+
+#[test]
+fn a_restored_network_goes_on_from_its_checkpoint() {
+    let path = env::temp_dir().join(format!("rustfbp-snapshots-{}.checkpoint", std::process::id()));
+    let counter = Mock::new("snapshot_counter", snapshot_counter::exports());
+    let sink = snapshot_sink::mock();
+
+    let mut net = counter_network(&counter, &sink);
+    for n in 0..3u64 {
+        net.send("counter", "input", n).unwrap();
+    }
+    net.run(DEADLINE).unwrap();
+    net.sched().checkpoint(&path).unwrap();
+    net.join();
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![1, 2, 3]);
+
+    // A new network starts from 0, unless it is restored
+    let mut net = counter_network(&counter, &sink);
+    net.sched().restore(&path).unwrap();
+    net.send("counter", "input", 0u64).unwrap();
+    net.run(DEADLINE).unwrap();
+    net.join();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![4]);
+}
@@ -0,0 +1,45 @@
+//! The taps, which copy the messages of an output port
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::ports::MsgReceiver;
+use rustfbp::testing::TestNetwork;
+
+use std::time::Duration;
+
+mock_agent!(tapped_source, input(input: u64), output(output: u64));
+mock_agent!(tapped_sink, input(input: u64));
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+#[test]
+fn a_tap_copies_the_messages_until_untap() {
+    let source = tapped_source::mock();
+    source.respond("input", "output", |n: &u64| *n);
+    let sink = tapped_sink::mock();
+    let mut net = TestNetwork::new();
+    net.add_node("source", source.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.connect("source", "output", "sink", "input").unwrap();
+    let (copies, tap) = MsgReceiver::<u64>::new(0, net.sched().sender.clone(), false);
+    net.sched().tap("source", "output", Box::new(tap), 1.0).unwrap();
+    assert!(net.sched().tap("source", "output", Box::new(0u64), 1.5).is_err());
+
+    net.send("source", "input", 1u64).unwrap();
+    net.send("source", "input", 2u64).unwrap();
+    net.run(DEADLINE).unwrap();
+    assert_eq!(copies.try_recv().unwrap(), 1);
+    assert_eq!(copies.try_recv().unwrap(), 2);
+    assert!(copies.try_recv().is_err());
+
+    net.sched().untap("source", "output").unwrap();
+    net.send("source", "input", 3u64).unwrap();
+    net.run(DEADLINE).unwrap();
+    assert!(copies.try_recv().is_err());
+    // The edge itself is not disturbed
+    assert_eq!(sink.received::<u64>("input").unwrap(), vec![1, 2, 3]);
+    net.join();
+}
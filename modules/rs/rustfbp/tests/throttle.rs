@@ -0,0 +1,70 @@
+//! The throttled connections
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::limits::Rate;
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// When `throttled_sink` received each message
+static RECEIVED: Mutex<Vec<(u64, Instant)>> = Mutex::new(Vec::new());
+
+// Sends the numbers below the one it receives, waiting in `send` over the rate
+pub mod throttled_source {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: u64),
+        output(output: u64),
+        blocking(true),
+        fn run(&mut self) -> Result<Signal> {
+            let n = self.input.input.recv()?;
+            for i in 0..n {
+                self.output.output.send(i)?;
+            }
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+pub mod throttled_sink {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: u64),
+        fn run(&mut self) -> Result<Signal> {
+            while let Ok(n) = self.input.input.try_recv() {
+                RECEIVED.lock().unwrap().push((n, Instant::now()));
+            }
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+#[test]
+fn a_throttled_connection_keeps_to_its_rate() {
+    let source = Mock::new("throttled_source", throttled_source::exports());
+    let sink = Mock::new("throttled_sink", throttled_sink::exports());
+    let mut net = TestNetwork::new();
+    net.add_node("source", source.sort()).unwrap();
+    net.add_node("sink", sink.sort()).unwrap();
+    net.sched().connect_throttled("source", "output", "sink", "input", Rate { per_sec: 20.0, burst: 2 }).unwrap();
+    net.send("source", "input", 6u64).unwrap();
+    net.run(DEADLINE).unwrap();
+    net.join();
+
+    let received = RECEIVED.lock().unwrap();
+    assert_eq!(received.iter().map(|&(n, _)| n).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+    // The burst goes at once, the 4 next messages wait 50 ms each
+    let elapsed = received[5].1 - received[0].1;
+    assert!(elapsed >= Duration::from_millis(150), "{:?}", elapsed);
+}
@@ -0,0 +1,65 @@
+//! The run timeouts of the agents
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::limits::Violation;
+use rustfbp::testing::{Mock, TestNetwork};
+
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// The errors of the receives of `stuck`
+static ERRORS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+// Waits on `wait`, which never gets a message, after each message of `input`
+pub mod stuck {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: u64, wait: u64),
+        blocking(true),
+        fn run(&mut self) -> Result<Signal> {
+            self.input.input.recv()?;
+            match self.input.wait.recv() {
+                Err(rustfbp::result::Error::Cancelled) => {
+                    ERRORS.lock().unwrap().push("cancelled".into());
+                    Err(rustfbp::result::Error::Cancelled)
+                }
+                res => res.map(|_| End),
+            }
+        }
+    );
+    agent!(@exports_fn);
+}
+
+#[test]
+fn a_stuck_agent_is_cancelled_after_its_run_time() {
+    let stuck = Mock::new("stuck", stuck::exports());
+    let mut net = TestNetwork::new();
+    net.sched().add_node_with_timeout("stuck", stuck.sort(), Duration::from_millis(100)).unwrap();
+    let violations = net.sched().supervise();
+    net.send("stuck", "input", 1u64).unwrap();
+    net.run(DEADLINE).unwrap();
+
+    match violations.recv_timeout(DEADLINE).unwrap() {
+        Violation::RunTime(name, max) => {
+            assert_eq!(name, "stuck");
+            assert_eq!(max, Duration::from_millis(100));
+        }
+        violation => panic!("unexpected violation {:?}", violation),
+    }
+    // The blocked receive returns once the violation is reported
+    let begin = Instant::now();
+    while ERRORS.lock().unwrap().is_empty() && begin.elapsed() < DEADLINE {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(*ERRORS.lock().unwrap(), vec!["cancelled".to_string()]);
+    // The agent is not stuck anymore, the network stops
+    net.join();
+}
@@ -0,0 +1,62 @@
+//! The traces of the last messages of an edge
+//!
+//! Run with `cargo test` in modules/rs/rustfbp.
+
+#[macro_use]
+extern crate rustfbp;
+
+use rustfbp::testing::{Mock, TestNetwork};
+use rustfbp::trace::TracedMsg;
+
+use std::time::Duration;
+
+const DEADLINE: Duration = Duration::from_secs(10);
+
+// Fails on the message "boom"
+pub mod trace_fail {
+    use super::*;
+
+    agent!(@exports[]
+        input(input: String),
+        fn run(&mut self) -> Result<Signal> {
+            let msg = self.input.input.recv()?;
+            if msg == "boom" {
+                return Err(rustfbp::result::Error::Misc("boom".into()));
+            }
+            Ok(End)
+        }
+    );
+    agent!(@exports_fn);
+}
+
+mock_agent!(trace_source, input(input: String), output(output: String));
+
+fn texts(msgs: Vec<TracedMsg>) -> Vec<String> {
+    msgs.into_iter().map(|msg| String::from_utf8(msg.bytes).unwrap()).collect()
+}
+
+#[test]
+fn a_trace_keeps_the_last_messages_and_the_ones_before_a_failure() {
+    let source = trace_source::mock();
+    source.respond("input", "output", |s: &String| s.clone());
+    let fail = Mock::new("trace_fail", trace_fail::exports());
+    let mut net = TestNetwork::new();
+    net.add_node("source", source.sort()).unwrap();
+    net.add_node("fail", fail.sort()).unwrap();
+    net.connect("source", "output", "fail", "input").unwrap();
+    net.sched().trace("source", "output", 2).unwrap();
+    for s in &["a", "b", "c"] {
+        net.send("source", "input", s.to_string()).unwrap();
+    }
+    net.run(DEADLINE).unwrap();
+    assert_eq!(texts(net.sched().dump_trace("source", "output").unwrap()), vec!["b", "c"]);
+    assert!(net.sched().trace_failure("source", "output").unwrap().is_none());
+
+    net.send("source", "input", "boom".to_string()).unwrap();
+    net.run(DEADLINE).unwrap();
+    let failure = net.sched().trace_failure("source", "output").unwrap().unwrap();
+    assert_eq!(failure.agent, "fail");
+    assert!(failure.error.contains("boom"), "{}", failure.error);
+    assert_eq!(texts(failure.msgs), vec!["c", "boom"]);
+    net.join();
+}
@@ -0,0 +1,9 @@
+{ crate, crates }:
+
+crate {
+  name = "rustfbp_macros";
+  mods = with crates; [ ];
+  src = ./.;
+  edition = "2018";
+  procMacro = true;
+}
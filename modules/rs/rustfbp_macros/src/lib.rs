@@ -0,0 +1,538 @@
+//! The procedural macros of rustfbp
+//!
+//! `check_ports` is used by the macro `agent!` of rustfbp, an agent doesn't call it directly. `agent_struct`
+//! declares an agent from a structure whose fields are its ports, it's re-exported by rustfbp.
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// The ports declared in an `agent!`, and its `run`
+#[derive(Default)]
+struct Agent {
+    // The name of `self` in `run`
+    arg: Option<String>,
+    run: Vec<TokenTree>,
+    input: Vec<String>,
+    inarr: Vec<String>,
+    output: Vec<String>,
+    outarr: Vec<String>,
+    options: Vec<String>,
+    batch: Vec<Ident>,
+}
+
+impl Agent {
+    fn parse(input: TokenStream) -> Agent {
+        let mut agent = Agent::default();
+        let mut option = false;
+        let mut accumulator = false;
+        let mut tokens = flatten(input).into_iter();
+        while let Some(token) = tokens.next() {
+            let clause = match token {
+                TokenTree::Ident(ident) => ident.to_string(),
+                _ => continue,
+            };
+            let args = match tokens.next() {
+                Some(TokenTree::Group(group)) => flatten(group.stream()),
+                _ => continue,
+            };
+            let names: Vec<String> = args.iter().filter_map(|t| match *t {
+                TokenTree::Ident(ref ident) => Some(ident.to_string()),
+                _ => None,
+            }).collect();
+            match clause.as_str() {
+                "run" => {
+                    agent.arg = names.into_iter().next();
+                    if let Some(body) = tokens.next() {
+                        agent.run = vec![body];
+                    }
+                },
+                "input" => agent.input.extend(names),
+                "inarr" => agent.inarr.extend(names),
+                "output" => agent.output.extend(names),
+                "outarr" => agent.outarr.extend(names),
+                "options" => agent.options.extend(names),
+                "option" => option = !args.is_empty(),
+                "accumulator" => accumulator = !args.is_empty(),
+                "batch" => agent.batch.extend(args.into_iter().filter_map(|t| match t {
+                    TokenTree::Ident(ident) => Some(ident),
+                    _ => None,
+                })),
+                _ => {},
+            }
+        }
+        // The ports added by the scheduler
+        if option {
+            agent.input.push("option".into());
+        }
+        if accumulator {
+            agent.input.push("accumulator".into());
+            agent.output.push("accumulator".into());
+        }
+        agent
+    }
+
+    // The declared ports of the field `kind` of the agent, and how to name them in an error
+    fn ports(&self, kind: &str) -> Option<(&[String], &'static str)> {
+        match kind {
+            "input" => Some((&self.input, "input port")),
+            "inarr" => Some((&self.inarr, "array input port")),
+            "output" => Some((&self.output, "output port")),
+            "outarr" => Some((&self.outarr, "array output port")),
+            "options" => Some((&self.options, "named option")),
+            _ => None,
+        }
+    }
+
+    // The errors of the ports used by `run` as `self.input.x`, `self.output.x`, ... and not declared
+    fn check_run(&self, tokens: &[TokenTree], errors: &mut TokenStream) {
+        let tokens = flatten(tokens.iter().cloned().collect());
+        for (i, token) in tokens.iter().enumerate() {
+            if let TokenTree::Group(ref group) = *token {
+                self.check_run(&group.stream().into_iter().collect::<Vec<_>>(), errors);
+                continue;
+            }
+            let path = match tokens.get(i..i + 5) {
+                Some(path) => path,
+                None => continue,
+            };
+            match (&path[0], &path[1], &path[2], &path[3], &path[4]) {
+                (TokenTree::Ident(arg), TokenTree::Punct(dot1), TokenTree::Ident(kind),
+                 TokenTree::Punct(dot2), TokenTree::Ident(name))
+                    if Some(arg.to_string()) == self.arg && dot1.as_char() == '.' && dot2.as_char() == '.' => {
+                    if let Some((ports, what)) = self.ports(&kind.to_string()) {
+                        let name_s = name.to_string();
+                        if !ports.contains(&name_s) {
+                            errors.extend(error(name.span(), &unknown(what, &name_s, ports)));
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    fn check_batch(&self, errors: &mut TokenStream) {
+        for name in &self.batch {
+            let name_s = name.to_string();
+            if !self.input.contains(&name_s) {
+                let msg = format!("batch : {}", unknown("input port", &name_s, &self.input));
+                errors.extend(error(name.span(), &msg));
+            }
+        }
+    }
+}
+
+fn unknown(what: &str, name: &str, ports: &[String]) -> String {
+    if ports.is_empty() {
+        format!("unknown {} `{}`, the agent declares no {}", what, name, what)
+    } else {
+        format!("unknown {} `{}`, the agent declares : {}", what, name, ports.join(", "))
+    }
+}
+
+// The tokens, with the invisible groups of the captured fragments (`$fun:block`, ...) opened
+fn flatten(tokens: TokenStream) -> Vec<TokenTree> {
+    let mut flat = vec![];
+    for token in tokens {
+        match token {
+            TokenTree::Group(ref group) if group.delimiter() == Delimiter::None => {
+                flat.extend(flatten(group.stream()));
+            },
+            token => flat.push(token),
+        }
+    }
+    flat
+}
+
+// `compile_error!("msg");`, pointing at `span`
+fn error(span: Span, msg: &str) -> TokenStream {
+    let mut msg = Literal::string(msg);
+    msg.set_span(span);
+    let mut tokens = vec![
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenTree::Literal(msg).into())),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+    ];
+    for token in &mut tokens {
+        token.set_span(span);
+    }
+    tokens.into_iter().collect()
+}
+
+/// Check the port names used by an agent, with an error naming the unknown port and listing the declared ones.
+///
+/// Called by `agent!`, with its ports and its `run` :
+///
+/// ```rust,ignore
+/// check_ports! {
+///     input(input) inarr() output(output) outarr() options() option((PrimText)) accumulator() batch()
+///     run(self) { let msg = self.input.input.recv()?; ... }
+/// }
+/// ```
+///
+/// A use of a port in `run` (`self.input.x`, `self.inarr.x`, `self.output.x`, `self.outarr.x`, `self.options.x`)
+/// is checked, and the ports named by `batch` must be input ports. It expands to nothing when all the ports exist.
+#[proc_macro]
+pub fn check_ports(input: TokenStream) -> TokenStream {
+    let agent = Agent::parse(input);
+    let mut errors = TokenStream::new();
+    agent.check_batch(&mut errors);
+    agent.check_run(&agent.run, &mut errors);
+    errors
+}
+
+/// A field of the structure of `agent_struct`, with its attribute
+struct Field {
+    kind: Ident,
+    args: Vec<(Ident, Vec<TokenTree>)>,
+    name: Ident,
+    ty: Vec<TokenTree>,
+}
+
+impl Field {
+    fn flag(&self, flag: &str) -> bool {
+        self.args.iter().any(|(key, value)| key.to_string() == flag && value.is_empty())
+    }
+
+    fn value(&self, key: &str) -> Option<Vec<TokenTree>> {
+        self.args.iter()
+            .find(|(k, value)| k.to_string() == key && !value.is_empty())
+            .map(|(_, value)| value.clone())
+    }
+}
+
+fn ident(name: &str) -> TokenTree {
+    TokenTree::Ident(Ident::new(name, Span::call_site()))
+}
+
+fn punct(c: char) -> TokenTree {
+    TokenTree::Punct(Punct::new(c, Spacing::Alone))
+}
+
+fn group(delimiter: Delimiter, tokens: Vec<TokenTree>) -> TokenTree {
+    TokenTree::Group(Group::new(delimiter, tokens.into_iter().collect()))
+}
+
+// Split the tokens on the commas which are not in a generic type (`HashMap<String, u64>`)
+fn split_commas(tokens: Vec<TokenTree>) -> Vec<Vec<TokenTree>> {
+    let mut parts = vec![];
+    let mut part = vec![];
+    let mut depth = 0;
+    let mut arrow = false;
+    for token in tokens {
+        if let TokenTree::Punct(ref p) = token {
+            match p.as_char() {
+                ',' if depth == 0 => {
+                    parts.push(alone(part));
+                    part = vec![];
+                    arrow = false;
+                    continue;
+                },
+                '<' => depth += 1,
+                // The `>` of `->` doesn't close a generic
+                '>' if !arrow && depth > 0 => depth -= 1,
+                _ => {},
+            }
+            arrow = p.as_char() == '-' && p.spacing() == Spacing::Joint;
+        } else {
+            arrow = false;
+        }
+        part.push(token);
+    }
+    if !part.is_empty() {
+        parts.push(alone(part));
+    }
+    parts
+}
+
+// The last `>` of `Vec<u8>,` is joint with the comma, it must not be joint with what follows it in `agent!`
+fn alone(mut tokens: Vec<TokenTree>) -> Vec<TokenTree> {
+    if let Some(&mut TokenTree::Punct(ref mut p)) = tokens.last_mut() {
+        let mut last = Punct::new(p.as_char(), Spacing::Alone);
+        last.set_span(p.span());
+        *p = last;
+    }
+    tokens
+}
+
+// The arguments of an attribute : `ack`, `batch = 64`, ...
+fn parse_args(tokens: Vec<TokenTree>) -> Result<Vec<(Ident, Vec<TokenTree>)>, TokenStream> {
+    let mut args = vec![];
+    for arg in split_commas(tokens) {
+        let mut arg = arg.into_iter();
+        let key = match arg.next() {
+            Some(TokenTree::Ident(key)) => key,
+            Some(other) => return Err(error(other.span(), "agent_struct : expected `flag` or `key = value`")),
+            None => continue,
+        };
+        let value: Vec<TokenTree> = match arg.next() {
+            Some(TokenTree::Punct(ref p)) if p.as_char() == '=' => arg.collect(),
+            Some(other) => return Err(error(other.span(), "agent_struct : expected `=`")),
+            None => vec![],
+        };
+        args.push((key, value));
+    }
+    Ok(args)
+}
+
+fn parse_field(tokens: Vec<TokenTree>) -> Result<Option<Field>, TokenStream> {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut attribute = None;
+    loop {
+        match tokens.peek() {
+            Some(TokenTree::Punct(p)) if p.as_char() == '#' => {},
+            _ => break,
+        }
+        tokens.next();
+        let attr = match tokens.next() {
+            Some(TokenTree::Group(attr)) => flatten(attr.stream()),
+            _ => continue,
+        };
+        let mut attr = attr.into_iter();
+        if let Some(TokenTree::Ident(kind)) = attr.next() {
+            if kind.to_string() == "doc" {
+                continue;
+            }
+            let args = match attr.next() {
+                Some(TokenTree::Group(args)) => parse_args(args.stream().into_iter().collect())?,
+                _ => vec![],
+            };
+            attribute = Some((kind, args));
+        }
+    }
+    // The visibility is the one of the agent
+    if let Some(TokenTree::Ident(vis)) = tokens.peek() {
+        if vis.to_string() == "pub" {
+            tokens.next();
+            if let Some(&TokenTree::Group(_)) = tokens.peek() {
+                tokens.next();
+            }
+        }
+    }
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(name)) => name,
+        Some(other) => return Err(error(other.span(), "agent_struct : expected the name of a port")),
+        None => return Ok(None),
+    };
+    match tokens.next() {
+        Some(TokenTree::Punct(ref p)) if p.as_char() == ':' => {},
+        _ => return Err(error(name.span(), "agent_struct : expected `name: Edge`")),
+    }
+    let (kind, args) = attribute.ok_or_else(|| error(name.span(),
+        "agent_struct : the field needs one of the attributes #[input], #[inarr], #[output], #[outarr], #[option], #[accumulator] or #[state]"))?;
+    Ok(Some(Field { kind, args, name, ty: tokens.collect() }))
+}
+
+// `name: Type`, or only `Type` for the option, then ` [flag]` if the field has the flag
+fn port(field: &Field, named: bool, flag: Option<&str>) -> Vec<TokenTree> {
+    let mut tokens = vec![];
+    if named {
+        tokens.push(TokenTree::Ident(field.name.clone()));
+        tokens.push(punct(':'));
+    }
+    tokens.extend(field.ty.iter().cloned());
+    if let Some(flag) = flag {
+        if field.flag(flag) {
+            tokens.push(group(Delimiter::Bracket, vec![ident(flag)]));
+        }
+    }
+    tokens
+}
+
+// `name(a, b, ...),`, nothing without ports
+fn clause(name: &str, ports: Vec<Vec<TokenTree>>, out: &mut Vec<TokenTree>) {
+    if ports.is_empty() {
+        return;
+    }
+    let mut args = vec![];
+    for (i, port) in ports.into_iter().enumerate() {
+        if i > 0 {
+            args.push(punct(','));
+        }
+        args.extend(port);
+    }
+    out.push(ident(name));
+    out.push(group(Delimiter::Parenthesis, args));
+    out.push(punct(','));
+}
+
+fn build_agent(args: TokenStream, item: TokenStream) -> Result<TokenStream, TokenStream> {
+    let agent_args = parse_args(args.into_iter().collect())?;
+    let mut item = item.into_iter().peekable();
+    // The attributes of the structure (its doc) are dropped
+    let mut vis = vec![];
+    let name = loop {
+        match item.next() {
+            Some(TokenTree::Punct(ref p)) if p.as_char() == '#' => { item.next(); },
+            Some(TokenTree::Ident(ref i)) if i.to_string() == "pub" => {
+                vis.push(TokenTree::Ident(i.clone()));
+                if let Some(TokenTree::Group(g)) = item.peek() {
+                    vis.push(TokenTree::Group(g.clone()));
+                    item.next();
+                }
+            },
+            Some(TokenTree::Ident(ref i)) if i.to_string() == "struct" => {
+                match item.next() {
+                    Some(TokenTree::Ident(name)) => break name,
+                    _ => return Err(error(i.span(), "agent_struct : expected the name of the structure")),
+                }
+            },
+            Some(other) => return Err(error(other.span(), "agent_struct : expected a structure")),
+            None => return Err(error(Span::call_site(), "agent_struct : expected a structure")),
+        }
+    };
+    let fields = match item.next() {
+        Some(TokenTree::Group(ref g)) if g.delimiter() == Delimiter::Brace => g.stream(),
+        Some(other) => return Err(error(other.span(), "agent_struct : expected a structure with named fields, without generics")),
+        None => return Err(error(name.span(), "agent_struct : expected a structure with named fields")),
+    };
+
+    let (mut input, mut inarr, mut output, mut outarr) = (vec![], vec![], vec![], vec![]);
+    let (mut state, mut state_serde, mut option, mut options, mut accumulator, mut batch) = (vec![], vec![], vec![], vec![], vec![], vec![]);
+    for field in split_commas(fields.into_iter().collect()) {
+        let field = match parse_field(field)? {
+            Some(field) => field,
+            None => continue,
+        };
+        let kind = field.kind.to_string();
+        let named = |expected: &str| -> Result<(), TokenStream> {
+            if field.name.to_string() == expected {
+                Ok(())
+            } else {
+                Err(error(field.name.span(), &format!("agent_struct : the field of #[{}] must be named `{}`", expected, expected)))
+            }
+        };
+        match kind.as_str() {
+            "input" => {
+                if let Some(size) = field.value("batch") {
+                    let mut tokens = vec![TokenTree::Ident(field.name.clone()), punct(':')];
+                    tokens.extend(size);
+                    if let Some(flush) = field.value("flush") {
+                        tokens.push(punct(','));
+                        tokens.extend(flush);
+                    }
+                    batch.push(tokens);
+                }
+                input.push(port(&field, true, Some("ack")));
+            },
+            "inarr" => inarr.push(port(&field, true, None)),
+            "output" => output.push(port(&field, true, None)),
+            "outarr" => outarr.push(port(&field, true, None)),
+            "option" if field.name.to_string() == "option" => option.push(port(&field, false, Some("live"))),
+            "option" => options.push(port(&field, true, Some("live"))),
+            "accumulator" => {
+                named("accumulator")?;
+                let mut tokens = field.ty.clone();
+                match (field.value("init"), field.value("flush")) {
+                    (Some(init), flush) => {
+                        tokens.extend(vec![punct(','), ident("init"), punct('=')]);
+                        tokens.extend(init);
+                        if let Some(flush) = flush {
+                            tokens.extend(vec![punct(','), ident("flush"), punct('=')]);
+                            tokens.extend(flush);
+                        }
+                    },
+                    (None, Some(_)) => return Err(error(field.name.span(), "agent_struct : the flush of the accumulator needs an init")),
+                    (None, None) => {},
+                }
+                accumulator.push(tokens);
+            },
+            "state" => {
+                named("state")?;
+                let init = field.value("init")
+                    .ok_or_else(|| error(field.name.span(), "agent_struct : the state needs its value, #[state(init = ...)]"))?;
+                let mut tokens = field.ty.clone();
+                tokens.extend(vec![TokenTree::Punct(Punct::new('=', Spacing::Joint)), punct('>')]);
+                tokens.extend(init);
+                if field.flag("serde") { state_serde.push(tokens); } else { state.push(tokens); }
+            },
+            _ => return Err(error(field.kind.span(), &format!("agent_struct : unknown port kind #[{}]", kind))),
+        }
+    }
+
+    let mut body = vec![];
+    clause("input", input, &mut body);
+    clause("inarr", inarr, &mut body);
+    clause("output", output, &mut body);
+    clause("outarr", outarr, &mut body);
+    // These clauses take one port each
+    for state in state {
+        clause("state", vec![state], &mut body);
+    }
+    for state in state_serde {
+        clause("state_serde", vec![state], &mut body);
+    }
+    for option in option {
+        clause("option", vec![option], &mut body);
+    }
+    clause("options", options, &mut body);
+    for accumulator in accumulator {
+        clause("accumulator", vec![accumulator], &mut body);
+    }
+    for (key, value) in &agent_args {
+        match key.to_string().as_str() {
+            "autostart" | "blocking" if value.is_empty() => {},
+            _ => return Err(error(key.span(), "agent_struct : expected `autostart` or `blocking`")),
+        }
+    }
+    for flag in &["autostart", "blocking"] {
+        if agent_args.iter().any(|(key, _)| key.to_string() == *flag) {
+            clause(flag, vec![vec![ident("true")]], &mut body);
+        }
+    }
+    for batch in batch {
+        clause("batch", vec![batch], &mut body);
+    }
+    // `run` is the one of the `impl` of the structure
+    let run: TokenStream = "fn run(&mut self) -> Result<Signal> { ThisAgent::run(self) }".parse().unwrap();
+    body.extend(run);
+
+    let mut out = vec![ident("agent"), punct('!'), group(Delimiter::Brace, body)];
+    out.extend("#[allow(dead_code)]".parse::<TokenStream>().unwrap());
+    out.extend(vis);
+    out.extend(vec![ident("type"), TokenTree::Ident(name), punct('='), ident("ThisAgent"), punct(';')]);
+    Ok(out.into_iter().collect())
+}
+
+/// Declare an agent with a structure whose fields are its ports, instead of `agent!`
+///
+/// Each field takes the attribute of its kind of port. `run` is an inherent method of the structure.
+///
+/// ```rust,ignore
+/// #[agent_struct(blocking)]
+/// pub struct Delay {
+///     #[input(batch = 64, flush = 20)]
+///     input: CapnpMsg,
+///     #[output]
+///     output: CapnpMsg,
+///     #[option(live)]
+///     option: FlowDelayOption,
+///     #[state(init = 0)]
+///     state: u64,
+/// }
+///
+/// impl Delay {
+///     fn run(&mut self) -> Result<Signal> {
+///         let msg = self.input.input.recv()?;
+///         self.output.output.send(msg)?;
+///         Ok(End)
+///     }
+/// }
+/// ```
+///
+/// The attributes are `#[input]` (with `ack`, `batch = size` and `flush = ms`), `#[inarr]`, `#[output]`, `#[outarr]`,
+/// `#[option]` (with `live`, a field named other than `option` is a named option), `#[accumulator]` (with
+/// `init = value` and `flush = ms`) and `#[state(init = value)]` (with `serde` for a `state_serde`). The structure
+/// takes `autostart` and `blocking`.
+///
+/// It expands to the same `agent!`, so the agent has the same C ABI (`create_agent`, `get_schema_input`, ...) and the
+/// structure is the `ThisAgent` of the agent.
+#[proc_macro_attribute]
+pub fn agent_struct(args: TokenStream, item: TokenStream) -> TokenStream {
+    match build_agent(args, item) {
+        Ok(tokens) => tokens,
+        Err(error) => error,
+    }
+}
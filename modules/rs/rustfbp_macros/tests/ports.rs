@@ -0,0 +1,12 @@
+//! The port check of `agent!` : a port used by `run` and not declared fails the build, on the name of the port
+//!
+//! Run with `cargo test` in modules/rs/rustfbp_macros. After a change of a message, `TRYBUILD=overwrite cargo test`
+//! writes the new `.stderr` files, to review before committing them.
+
+#[test]
+fn ports() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/declared.rs");
+    t.compile_fail("tests/ui/unknown_*.rs");
+    t.compile_fail("tests/ui/batch_*.rs");
+}
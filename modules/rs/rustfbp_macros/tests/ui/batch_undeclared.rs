@@ -0,0 +1,18 @@
+// The run of an agent! ends its generated match, which warns of unreachable code
+#![allow(unreachable_code)]
+
+#[macro_use]
+extern crate rustfbp;
+
+agent!(@exports[]
+    input(input: u64),
+    output(output: u64),
+    batch(inputs: 16),
+    fn run(&mut self) -> Result<Signal> {
+        let n = self.input.input.recv()?;
+        self.output.output.send(n)?;
+        Ok(End)
+    }
+);
+
+fn main() {}
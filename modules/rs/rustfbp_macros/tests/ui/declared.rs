@@ -0,0 +1,27 @@
+// The run of an agent! ends its generated match, which warns of unreachable code
+#![allow(unreachable_code)]
+
+#[macro_use]
+extern crate rustfbp;
+
+agent!(@exports[]
+    input(input: u64),
+    inarr(inputs: u64),
+    output(output: u64),
+    outarr(outputs: u64),
+    option(u64),
+    fn run(&mut self) -> Result<Signal> {
+        let add = self.input.option.recv()?;
+        let n = self.input.input.recv()?;
+        for input in self.inarr.inputs.values() {
+            let _ = input.try_recv();
+        }
+        for output in self.outarr.outputs.values() {
+            output.send(n + add)?;
+        }
+        self.output.output.send(n + add)?;
+        Ok(End)
+    }
+);
+
+fn main() {}
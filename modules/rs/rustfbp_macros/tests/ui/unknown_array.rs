@@ -0,0 +1,21 @@
+// The run of an agent! ends its generated match, which warns of unreachable code
+#![allow(unreachable_code)]
+
+#[macro_use]
+extern crate rustfbp;
+
+agent!(@exports[]
+    inarr(inputs: u64),
+    outarr(outputs: u64),
+    fn run(&mut self) -> Result<Signal> {
+        for input in self.inarr.input.values() {
+            let n = input.recv()?;
+            for output in self.outarr.output.values() {
+                output.send(n)?;
+            }
+        }
+        Ok(End)
+    }
+);
+
+fn main() {}
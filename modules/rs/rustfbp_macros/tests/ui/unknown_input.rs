@@ -0,0 +1,17 @@
+// The run of an agent! ends its generated match, which warns of unreachable code
+#![allow(unreachable_code)]
+
+#[macro_use]
+extern crate rustfbp;
+
+agent!(@exports[]
+    input(input: u64, control: bool),
+    output(output: u64),
+    fn run(&mut self) -> Result<Signal> {
+        let n = self.input.inptu.recv()?;
+        self.output.output.send(n)?;
+        Ok(End)
+    }
+);
+
+fn main() {}
@@ -27,12 +27,15 @@ self = rec {
   # -   few people are using these nodes so expect breakage
 
   fvm_rs_find_node = callPackage ./fvm/rs/find/node {};
+  fvm_rs_graph_diff = callPackage ./fvm/rs/graph/diff {};
   fvm_rs_errors = callPackage ./fvm/rs/errors {};
   fvm_rs_parser_graph_check = callPackage ./fvm/rs/parser/graph/check {};
   fvm_rs_parser_graph_print = callPackage ./fvm/rs/parser/graph/print {};
   fvm_rs_parser_lexical = callPackage ./fvm/rs/parser/lexical {};
   fvm_rs_parser_semantic = callPackage ./fvm/rs/parser/semantic {};
+  fvm_rs_repl = callPackage ./fvm/rs/repl {};
   fvm_rs_scheduler = callPackage ./fvm/rs/scheduler {};
+  fvm_rs_schema_registry = callPackage ./fvm/rs/schema/registry {};
   fvm_rs_start = callPackage ./fvm/rs/start {};
   fvm_rs_subgraph = callPackage ./fvm/rs/subgraph {};
   fvm_rs_vm = callPackage ./fvm/rs/vm {};
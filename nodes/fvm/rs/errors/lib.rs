@@ -30,7 +30,7 @@ agent! {
 
         // TODO : remove allocation by building a new CoreGraph constructor
         let mut g = CoreGraph::new();
-        g.path = "error".into();
+        g.errors = true;
         let _ = self.output.output.send(g);
         Ok(End)
     }
@@ -9,12 +9,17 @@ use std::process::Command;
 agent! {
     input(input: FsPath),
     output(output: FsPathOption),
+    // The directories searched before nix, separated by ':', from the paths of the manifest of fvm
+    option(String),
     fn run(&mut self) -> Result<Signal> {
         let is_path = self.input.input.recv()?.0;
 
+        let search = self.try_recv_option().unwrap_or_default();
         let mut stdout: String = String::new();
         let new_path = if fs::metadata(format!("{}", is_path)).is_ok() {
             Some(is_path)
+        } else if let Some(path) = search_node_path(&search, &is_path) {
+            Some(path)
         } else {
             stdout = find_node_path(&is_path);
             Some(stdout)
@@ -29,6 +34,14 @@ agent! {
     }
 }
 
+// The node in one of the directories, built like a nix output : `<dir>/<name>/lib/libagent.so` or `lib.subgraph`
+fn search_node_path(search: &str, name: &str) -> Option<String> {
+    search.split(':').filter(|dir| !dir.is_empty())
+        .map(|dir| format!("{}/{}", dir.trim_end_matches('/'), name))
+        .find(|path| fs::metadata(format!("{}/lib/libagent.so", path)).is_ok()
+              || fs::metadata(format!("{}/lib/lib.subgraph", path)).is_ok())
+}
+
 fn find_node_path(name: &str) -> String {
     let nixpkgs = "nixpkgs=https://github.com/NixOS/nixpkgs/archive/125ffff089b6bd360c82cf986d8cc9b17fc2e8ac.tar.gz";
     let output = Command::new("nix-build")
@@ -0,0 +1,8 @@
+{ support, edges, mods }:
+
+support.node.rs.agent {
+  src = ./.;
+  edges = with edges.rs; [ CoreGraph CoreGraphDiff ];
+  capnp_edges = with edges.capnp; [ PrimText ];
+  mods = with mods.rs; [ rustfbp capnp ];
+}
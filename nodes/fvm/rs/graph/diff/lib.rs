@@ -0,0 +1,103 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+// Compare the graph on `old` with the graph on `new`, and send their differences
+//
+// See `CoreGraphDiff::between`. The differences are sent on `output`, and as text on `text` if it's connected, one
+// line by change :
+//
+//   + mul(maths_mul)
+//   - add() output -> a mul()
+//   ~ add(maths_add) => add(maths_sub)
+//   ~ '1' => '2' -> a add()
+//   + mul * 4 ordered
+//   - AFTER=mul:add
+//   + INPORT=add.a:A
+agent! {
+    input(old: CoreGraph, new: CoreGraph),
+    output(output: CoreGraphDiff, text: PrimText),
+    fn run(&mut self) -> Result<Signal> {
+        let old = self.input.old.recv()?;
+        let new = self.input.new.recv()?;
+
+        let diff = CoreGraphDiff::between(&old, &new);
+        if self.output.text.is_some() {
+            self.output.text.send(PrimText::new(text(&diff)))?;
+        }
+        self.output.output.send(diff)?;
+        Ok(End)
+    }
+}
+
+fn text(diff: &CoreGraphDiff) -> String {
+    let mut lines = vec![];
+    for n in &diff.nodes_added {
+        lines.push(format!("+ {}({})", n.name, n.sort));
+    }
+    for n in &diff.nodes_removed {
+        lines.push(format!("- {}({})", n.name, n.sort));
+    }
+    for n in &diff.nodes_changed {
+        lines.push(format!("~ {0}({1}) => {0}({2})", n.name, n.old_sort, n.new_sort));
+    }
+    for e in &diff.edges_added {
+        lines.push(format!("+ {}", edge(e)));
+    }
+    for e in &diff.edges_removed {
+        lines.push(format!("- {}", edge(e)));
+    }
+    for i in &diff.imsgs_added {
+        lines.push(format!("+ '{}' -> {} {}()", i.msg, port(&i.port, &i.elem), i.comp));
+    }
+    for i in &diff.imsgs_removed {
+        lines.push(format!("- '{}' -> {} {}()", i.msg, port(&i.port, &i.elem), i.comp));
+    }
+    for i in &diff.imsgs_changed {
+        lines.push(format!("~ '{}' => '{}' -> {} {}()", i.old_msg, i.new_msg, port(&i.port, &i.elem), i.comp));
+    }
+    for p in &diff.pools_added {
+        lines.push(format!("+ {}", pool(p)));
+    }
+    for p in &diff.pools_removed {
+        lines.push(format!("- {}", pool(p)));
+    }
+    for a in &diff.afters_added {
+        lines.push(format!("+ AFTER={}:{}", a.node, a.after));
+    }
+    for a in &diff.afters_removed {
+        lines.push(format!("- AFTER={}:{}", a.node, a.after));
+    }
+    for e in &diff.ext_in_added {
+        lines.push(format!("+ INPORT={}.{}:{}", e.in_comp, port(&e.in_port, &e.in_elem), e.port));
+    }
+    for e in &diff.ext_in_removed {
+        lines.push(format!("- INPORT={}.{}:{}", e.in_comp, port(&e.in_port, &e.in_elem), e.port));
+    }
+    for e in &diff.ext_out_added {
+        lines.push(format!("+ OUTPORT={}.{}:{}", e.out_comp, port(&e.out_port, &e.out_elem), e.port));
+    }
+    for e in &diff.ext_out_removed {
+        lines.push(format!("- OUTPORT={}.{}:{}", e.out_comp, port(&e.out_port, &e.out_elem), e.port));
+    }
+    lines.join("\n")
+}
+
+fn edge(e: &CoreGraphEdge) -> String {
+    format!("{}() {} -> {} {}()", e.out_comp, port(&e.out_port, &e.out_elem), port(&e.in_port, &e.in_elem), e.in_comp)
+}
+
+fn pool(p: &CoreGraphPool) -> String {
+    if p.ordered {
+        format!("{} * {} ordered", p.name, p.size)
+    } else {
+        format!("{} * {}", p.name, p.size)
+    }
+}
+
+fn port(port: &str, elem: &Option<String>) -> String {
+    match *elem {
+        Some(ref elem) => format!("{}[{}]", port, elem),
+        None => port.into(),
+    }
+}
@@ -0,0 +1,41 @@
+//! `fvm inspect <agent.so>`, the interface of an agent without reading its source
+//!
+//! ```text
+//! $ fvm inspect /nix/store/...-maths_boolean_nand/lib/libagent.so
+//! /nix/store/...-maths_boolean_nand/lib/libagent.so
+//! version 0.1.0
+//! input a : PrimBool
+//! input b : PrimBool
+//! output output : PrimBool
+//! ```
+
+use rustfbp::result::Result;
+use rustfbp::scheduler::AgentCache;
+
+/// Print the version of the agent, and its ports with their edges
+pub fn inspect(sort: &str) -> Result<()> {
+    let mut cache = AgentCache::new();
+    cache.load(sort)?;
+    let ports = cache.get_ports(sort)?;
+    println!("{}", sort);
+    let version = cache.version(sort)?;
+    if !version.is_empty() {
+        println!("version {}", version);
+    }
+    for port in &ports.options {
+        println!("option {} : {}", port, cache.get_schema_input(sort, port)?);
+    }
+    for port in &ports.inputs {
+        println!("input {} : {}", port, cache.get_schema_input(sort, port)?);
+    }
+    for port in &ports.input_arrays {
+        println!("inarr {} : {}", port, cache.get_schema_input_array(sort, port)?);
+    }
+    for port in &ports.outputs {
+        println!("output {} : {}", port, cache.get_schema_output(sort, port)?);
+    }
+    for port in &ports.output_arrays {
+        println!("outarr {} : {}", port, cache.get_schema_output_array(sort, port)?);
+    }
+    Ok(())
+}
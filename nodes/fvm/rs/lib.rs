@@ -0,0 +1,338 @@
+//! Run the graphs of fractalide in another Rust program, like `fvm` does
+//!
+//! A `Network` reads a graph, builds it with the agents of fvm and runs it in the process. The application talks to
+//! the graph through the ports exported by the graph, `INPORT=open.input:IN` and `OUTPORT=check.output:OUT`.
+//!
+//! Example :
+//!
+//! ```rust,ignore
+//! extern crate fractalide;
+//!
+//! let mut net = fractalide::Network::from_fbp_str("
+//!     upper(prim_text_upper) output -> input display(io_print)
+//!     INPORT=upper.input:IN
+//! ")?;
+//! net.send_in("IN", PrimText("hello".into()))?;
+//! net.shutdown();
+//! ```
+//!
+//! As in fvm, the agents are dylibs which free the memory allocated by the program : the program must use the
+//! allocator of the system.
+
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+#[macro_use]
+extern crate serde_derive;
+extern crate toml;
+
+use rustfbp::manager::NetworkManager;
+use rustfbp::scheduler::{AgentCache, Scheduler};
+use rustfbp::ports::{MsgReceiver, MsgSender};
+use rustfbp::log::JsonLines;
+use rustfbp::result;
+use rustfbp::result::{IoContext, Result};
+
+use std::any::{type_name, Any};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::channel;
+
+pub mod manifest;
+use manifest::Manifest;
+
+pub mod edge {
+    include!("edges.rs");
+}
+use edge::*;
+
+// The agents of fvm, which parse the graphs and run them in the scheduler agent
+const NODES: &[(&str, &str)] = &[
+    ("open", "fs_file_open.so"),
+    ("lex", "core_parser_lexical.so"),
+    ("sem", "core_parser_semantic.so"),
+    ("vm", "core_vm.so"),
+    ("errors", "core_errors.so"),
+    ("graph_check", "core_parser_graph_check.so"),
+    ("sched", "core_scheduler.so"),
+    ("core_find_node", "core_find_node.so"),
+    ("start", "core_start.so"),
+];
+
+const EDGES: &[(&str, &str, &str, &str)] = &[
+    // open(fs_file_open) output -> input lex(core_parser_lexical) output -> input sem(core_parser_semantic)
+    // sem() output -> input graph_check(core_parser_graph_check) output -> input vm(core_vm)
+    ("open", "output", "lex", "input"),
+    ("lex", "output", "sem", "input"),
+    ("sem", "output", "graph_check", "input"),
+    ("graph_check", "output", "vm", "input"),
+    // open() error -> file_error errors(core_errors)
+    // sem() error -> semantic_error errors()
+    // graph_check() error -> semantic_error errors()
+    // errors() output -> input vm()
+    ("graph_check", "error", "errors", "semantic_error"),
+    ("open", "error", "errors", "file_error"),
+    ("sem", "error", "errors", "semantic_error"),
+    ("errors", "output", "vm", "input"),
+    // The subgraphs are read by the same parsers : vm() ask_graph -> input open()
+    ("vm", "ask_graph", "open", "input"),
+    // vm() output -> graph sched(core_scheduler)
+    // vm() ask_path -> input core_find_node() output -> new_path vm()
+    ("vm", "output", "sched", "graph"),
+    ("vm", "ask_path", "core_find_node", "input"),
+    ("core_find_node", "output", "vm", "new_path"),
+    // sched() ask_graph -> input vm()
+    ("sched", "ask_graph", "vm", "input"),
+    // start(core_start) output -> action sched()
+    ("start", "output", "sched", "action"),
+];
+
+// Hot-swap the network each time the graph changes, never halt
+const WATCH_NODES: &[(&str, &str)] = &[
+    ("watch", "fs_watch.so"),
+];
+
+const WATCH_EDGES: &[(&str, &str, &str, &str)] = &[
+    ("start", "watch", "watch", "option"),
+    ("start", "watch_add", "watch", "add"),
+    ("watch", "output", "start", "reload"),
+    // The includes and the subgraphs of the graph are watched too
+    ("sched", "loaded", "start", "loaded"),
+];
+
+// The statements typed by the user go through their own parsers, then to the vm
+const REPL_NODES: &[(&str, &str)] = &[
+    ("repl", "core_repl.so"),
+    ("repl_lex", "core_parser_lexical.so"),
+    ("repl_sem", "core_parser_semantic.so"),
+];
+
+const REPL_EDGES: &[(&str, &str, &str, &str)] = &[
+    ("repl", "output", "sched", "action"),
+    ("repl", "lex", "repl_lex", "input"),
+    ("repl_lex", "output", "repl_sem", "input"),
+    ("repl_sem", "output", "vm", "input"),
+    ("repl_sem", "error", "errors", "semantic_error"),
+];
+
+fn add(sched: &mut Scheduler, nodes: &[(&str, &str)], edges: &[(&str, &str, &str, &str)]) -> Result<()> {
+    for &(name, sort) in nodes {
+        sched.add_node(name, sort)?;
+    }
+    for &(out_comp, out_port, in_comp, in_port) in edges {
+        sched.connect(out_comp, out_port, in_comp, in_port).map_err(|e| result::Error::Edge {
+            edge: format!("{}() {} -> {} {}()", out_comp, out_port, in_port, in_comp),
+            source: Box::new(e),
+        })?;
+    }
+    Ok(())
+}
+
+fn sender<T: 'static>(sched: &Scheduler, comp: &str, port: &str) -> Result<MsgSender<T>> {
+    sched.get_typed_sender::<T, _, _>(comp, port)
+}
+
+// The agents of fvm in `sched`, with the logs, the paths and the configuration of the manifest, ready to read a graph
+fn build(sched: &mut Scheduler, manifest: &Manifest, cache: &AgentCache) -> Result<()> {
+    if let Some(level) = manifest.level().map_err(result::Error::Misc)? {
+        sched.log_level(level);
+    }
+    if let Some(ref json) = manifest.log.json {
+        sched.log_to(Box::new(JsonLines::open(json)?));
+    }
+
+    add(sched, NODES, EDGES)?;
+
+    sender::<CoreAction>(sched, "sched", "action")?.send(CoreAction::Cache(cache.clone()))?;
+    if !manifest.paths.is_empty() {
+        sender::<String>(sched, "core_find_node", "option")?.send(manifest.paths.join(":"))?;
+    }
+    if !manifest.features.is_empty() {
+        sender::<String>(sched, "sem", "option")?.send(manifest.features.join(","))?;
+    }
+    if !manifest.nodes.is_empty() {
+        let config = manifest.config().map_err(result::Error::Misc)?;
+        sender::<CoreAction>(sched, "sched", "action")?.send(CoreAction::Configure(config))?;
+    }
+    Ok(())
+}
+
+/// Run the graphs of the manifest like `fvm`, and wait for their end
+///
+/// With `check`, only read the graph and check it against its agents, see `fvm check`.
+pub fn run(manifest: &Manifest, check: bool) {
+    if check {
+        let mut sched = Scheduler::new();
+        let cache = sched.cache.clone();
+        start(&mut sched, manifest, manifest.graph.as_ref(), true, true, &cache);
+        sched.join();
+        return;
+    }
+    // The main graph, with the watch and the repl, then the other graphs of the manifest
+    let mut manager = NetworkManager::new();
+    let cache = manager.cache().clone();
+    let graphs = std::iter::once(manifest.graph.as_ref()).chain(manifest.graphs.iter().map(Some));
+    for (i, graph) in graphs.enumerate() {
+        let mut name = graph.and_then(|g| Path::new(g).file_stem()).map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "repl".into());
+        if manager.names().contains(&name) {
+            name = format!("{}-{}", name, i);
+        }
+        let sched = manager.add(&name).unwrap_or_else(|e| panic!("cannot add the network {} : {}", name, e));
+        start(sched, manifest, graph, i == 0, false, &cache);
+    }
+    // Wait for the end of the executions
+    manager.join_all();
+}
+
+// Run a graph in the network of `sched`, the main one has the watch and the repl of the manifest
+fn start(sched: &mut Scheduler, manifest: &Manifest, graph: Option<&String>, main: bool, check: bool, cache: &AgentCache) {
+    build(sched, manifest, cache).unwrap_or_else(|e| panic!("cannot build fvm : {}", e.chain()));
+
+    let watch = main && manifest.watch;
+    let repl = main && manifest.repl;
+    if let Some(path_fbp) = graph {
+        if check {
+            sender::<String>(sched, "start", "check").and_then(|s| s.send(path_fbp.clone())).expect("cannot send check");
+            return;
+        }
+        sender::<String>(sched, "start", "add").and_then(|s| s.send(path_fbp.clone())).expect("cannot send start");
+
+        if watch {
+            add(sched, WATCH_NODES, WATCH_EDGES).unwrap_or_else(|e| panic!("cannot add the watch : {}", e.chain()));
+            sender::<String>(sched, "start", "watch").and_then(|s| s.send(path_fbp.clone())).expect("cannot send path to watch");
+        }
+    }
+
+    if repl {
+        add(sched, REPL_NODES, REPL_EDGES).unwrap_or_else(|e| panic!("cannot add the repl : {}", e.chain()));
+        if !manifest.features.is_empty() {
+            sender::<String>(sched, "repl_sem", "option").and_then(|s| s.send(manifest.features.join(",")))
+                .expect("cannot send the features to the repl");
+        }
+        sched.start_if_needed("repl").expect("cannot start repl");
+    } else if manifest.batch {
+        // Halt once the graph is idle, even if its agents never end
+        sender::<bool>(sched, "start", "idle").and_then(|s| s.send(true)).expect("cannot send idle");
+    } else if !watch {
+        sender::<bool>(sched, "start", "halt").and_then(|s| s.send(true)).expect("cannot send halt");
+    }
+}
+
+// The graphs of `from_fbp_str`, each in its own file
+static GRAPHS: AtomicUsize = AtomicUsize::new(0);
+
+/// A graph running in the process, see the module
+///
+/// The parsing errors of the graph are printed like in fvm : the ports of a broken graph are then not found.
+pub struct Network {
+    sched: Scheduler,
+    // The senders of the exported input ports, and the receivers of the exported output ports, by their name
+    inputs: HashMap<String, Box<Any + Send>>,
+    outputs: HashMap<String, Box<Any + Send>>,
+    // The file written by `from_fbp_str`, removed with the network
+    file: Option<PathBuf>,
+}
+
+impl Network {
+    /// Run the graph of the manifest, with its paths, logs and nodes. `watch`, `repl` and `batch` are for fvm only.
+    pub fn from_manifest(manifest: &Manifest) -> Result<Self> {
+        let graph = manifest.graph.clone().ok_or_else(|| result::Error::Misc("the manifest has no graph".into()))?;
+        let mut sched = Scheduler::new();
+        let cache = sched.cache.clone();
+        build(&mut sched, manifest, &cache)?;
+        // Not through the start agent : the actions of the network must come after the graph
+        sender::<CoreAction>(&sched, "sched", "action")?.send(CoreAction::Add(CoreActionAdd {
+            name: "main".into(),
+            comp: graph,
+        }))?;
+        Ok(Network {
+            sched: sched,
+            inputs: HashMap::new(),
+            outputs: HashMap::new(),
+            file: None,
+        })
+    }
+
+    /// Run the graph of the file `path`
+    pub fn from_fbp_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let manifest = Manifest {
+            graph: Some(path.as_ref().to_string_lossy().into_owned()),
+            ..Manifest::default()
+        };
+        Network::from_manifest(&manifest)
+    }
+
+    /// Run the graph of the text `fbp`
+    pub fn from_fbp_str(fbp: &str) -> Result<Self> {
+        let file = env::temp_dir().join(format!("fractalide_{}_{}.fbp", process::id(), GRAPHS.fetch_add(1, Ordering::SeqCst)));
+        fs::write(&file, fbp).path(&file)?;
+        let mut network = Network::from_fbp_file(&file)?;
+        network.file = Some(file);
+        Ok(network)
+    }
+
+    /// Send `msg` to the exported input port `port` of the graph, `INPORT=node.port:PORT`
+    ///
+    /// Fails with `Error::TypeMismatch` if the port takes another edge than `T`.
+    pub fn send_in<T: Send + 'static>(&mut self, port: &str, msg: T) -> Result<()> {
+        if !self.inputs.contains_key(port) {
+            let (s, r) = channel();
+            sender::<CoreAction>(&self.sched, "sched", "action")?.send(CoreAction::GetSender(port.into(), s))?;
+            let input = r.recv()??;
+            self.inputs.insert(port.into(), input);
+        }
+        match self.inputs[port].downcast_ref::<MsgSender<T>>() {
+            Some(sender) => sender.send(msg),
+            None => Err(mismatch::<T>(port)),
+        }
+    }
+
+    /// Wait for the next message of the exported output port `port` of the graph, `OUTPORT=node.port:PORT`
+    ///
+    /// The port is connected at the first call : the messages sent before are not received. Fails with
+    /// `Error::TypeMismatch` if the port was first received with another edge than `T`.
+    pub fn recv_out<T: Send + 'static>(&mut self, port: &str) -> Result<T> {
+        if !self.outputs.contains_key(port) {
+            // The receiver belongs to no agent : its messages never start one
+            let (recv, s) = MsgReceiver::<T>::new(::std::usize::MAX, self.sched.sender.clone(), false);
+            sender::<CoreAction>(&self.sched, "sched", "action")?.send(CoreAction::ConnectSender(CoreActionConnectSender {
+                name: "main".into(),
+                port: port.into(),
+                elem: String::new(),
+                output: Box::new(s),
+            }))?;
+            self.outputs.insert(port.into(), Box::new(recv));
+        }
+        match self.outputs[port].downcast_ref::<MsgReceiver<T>>() {
+            Some(recv) => recv.recv(),
+            None => Err(mismatch::<T>(port)),
+        }
+    }
+
+    /// Stop the graph once it is idle, and wait for the end of fvm
+    pub fn shutdown(self) {
+        // The ports of the application are closed first, so the agents which read them can end
+        drop(self.inputs);
+        drop(self.outputs);
+        if let Ok(action) = sender::<CoreAction>(&self.sched, "sched", "action") {
+            let _ = action.send(CoreAction::HaltIdle);
+        }
+        self.sched.join();
+        if let Some(file) = self.file {
+            let _ = fs::remove_file(file);
+        }
+    }
+}
+
+fn mismatch<T>(port: &str) -> result::Error {
+    result::Error::TypeMismatch {
+        port: port.into(),
+        expected: type_name::<T>().into(),
+        found: "another edge".into(),
+    }
+}
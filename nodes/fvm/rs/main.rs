@@ -1,98 +1,109 @@
-#![feature(alloc_system)]
-
-extern crate alloc_system;
-
-#[macro_use]
+extern crate fractalide;
 extern crate rustfbp;
-extern crate capnp;
 
-use self::rustfbp::scheduler::{Scheduler};
-use self::rustfbp::ports::{MsgSender};
+use fractalide::manifest::{Manifest, MANIFEST};
+use fractalide::run;
 
-use std::collections::HashMap;
+use std::alloc::System;
 use std::env;
-use std::thread;
-use std::any::Any;
-
-fn main() {
-    run(&env::args().nth(1).unwrap());
-}
-
-mod edge {
-    include!("edges.rs");
-}
-use edge::*;
-
-#[allow(unused_must_use)]
-fn run(path_fbp: &str) {
-
-    let mut sched = Scheduler::new();
-    sched.add_node("open", "fs_file_open.so").expect("cannot add node");
-    sched.add_node("lex", "core_parser_lexical.so").expect("cannot add node");
-    sched.add_node("sem", "core_parser_semantic.so").expect("cannot add node");
-    sched.add_node("vm", "core_vm.so").expect("cannot add node");
-    sched.add_node("errors", "core_errors.so").expect("cannot add node");
-    sched.add_node("graph_print", "core_parser_graph_print.so").expect("cannot add node");
-    sched.add_node("graph_check", "core_parser_graph_check.so").expect("cannot add node");
-    sched.add_node("sched", "core_scheduler.so").expect("cannot add node");
-    sched.add_node("core_find_node", "core_find_node.so").expect("cannot add node");
-    sched.add_node("start", "core_start.so").expect("cannot add start");
-
-    // open(fs_file_open) output -> input lex(core_parser_lexical)
-    // lex() output -> input sem(core_parser_semantic)
-    // sem() output -> input graph_check(core_parser_graph_check)
-    // graph_check() output -> input vm(core_vm)
-    // graph_check() error -> semantic_error errors(core_errors)
-
-    sched.connect("open", "output", "lex", "input").expect("cannot connect");
-    // Test sched.soft_add_input_array_element("lex", "numbers", "taga").expect("cannot add input array");
-    // Test sched.connect_array_to_array("open", "numbers", "1", "lex", "numbers", "taga").expect("cannot connect array");
+use std::path::Path;
+use std::process;
 
-    sched.connect("lex", "output", "sem", "input").expect("cannot connect");
-    sched.connect("sem", "output", "graph_check", "input").expect("cannot connect");
-    sched.connect("graph_check", "output", "vm", "input").expect("cannot connect");
-    sched.connect("graph_check", "error", "errors", "semantic_error").expect("cannot connect");
+mod inspect;
+mod new_agent;
 
-    // open() error -> file_error errors()
-    // sem() error -> semantic_error errors()
-    // errors() output -> input vm()
+// The agents are dylibs which free the memory allocated by fvm, and the other way around : all of them use the
+// allocator of the system
+#[global_allocator]
+static ALLOCATOR: System = System;
 
-    sched.connect("open", "error", "errors", "file_error").expect("cannot connect");
-    sched.connect("sem", "error", "errors", "semantic_error").expect("cannot connect");
-    sched.connect("errors", "output", "vm", "input").expect("cannot connect");
-
-    // reccursive part
-    // vm() ask_graph -> input open()
-
-   sched.connect("vm", "ask_graph", "open", "input").expect("cannot connect");
-
-    // With Graph print
-    // sched.connect("vm", "output", "graph_print", "input").expect("cannot connect");
-    // sched.connect("graph_print", "output", "sched", "graph").expect("cannot connect");
-
-    // Without Graph print
-    // vm() output -> graph sched(core_scheduler)
-    // vm() ask_path -> input core_find_node()
-    // core_find_node() output -> new_path vm()
-
-    sched.connect("vm", "output", "sched", "graph").expect("cannot connect");
-    sched.connect("vm", "ask_path", "core_find_node", "input").expect("cannot connect");
-    sched.connect("core_find_node", "output", "vm", "new_path").expect("cannot connect");
-
-    // imsg() ask_graph -> input vm()
-
-    sched.connect("sched", "ask_graph", "vm", "input").expect("cannot connect ask_graph");
-
-    // Manage the iip
-    sched.connect("start", "output", "sched", "action").expect("cannot connect start to sched");
-
-    let add: Box<Any + Send> = sched.get_sender("start", "add").expect("action of sched not found");
-    let add = add.downcast::<MsgSender<String>>().expect("cannot downcast add");
-    add.send(path_fbp.into()).expect("cannot send start");
+fn main() {
+    // A node isolated in its own process runs here
+    rustfbp::isolate::child();
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.get(0).map(|a| a as &str) {
+        Some("inspect") => {
+            let sort = args.get(1).unwrap_or_else(|| panic!("usage: fvm inspect <agent.so>"));
+            if let Err(e) = inspect::inspect(sort) {
+                eprintln!("{} : {}", sort, e);
+                process::exit(1);
+            }
+            return;
+        },
+        Some("new-agent") => {
+            let usage = "usage: fvm new-agent <path> [--inputs port:edge,...] [--outputs port:edge,...]";
+            let path = args.get(1).unwrap_or_else(|| panic!("{}", usage));
+            let (mut inputs, mut outputs) = (vec![], vec![]);
+            let mut flags = args[2..].iter();
+            while let Some(flag) = flags.next() {
+                let ports = match flag as &str {
+                    "--inputs" => &mut inputs,
+                    "--outputs" => &mut outputs,
+                    _ => panic!("{}", usage),
+                };
+                for port in flags.next().unwrap_or_else(|| panic!("{}", usage)).split(',') {
+                    let mut port = port.splitn(2, ':');
+                    match (port.next(), port.next()) {
+                        (Some(name), Some(edge)) => ports.push((name.to_string(), edge.to_string())),
+                        _ => panic!("{}", usage),
+                    }
+                }
+            }
+            if let Err(e) = new_agent::new_agent(path, &inputs, &outputs) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+            return;
+        },
+        Some("check") => {
+            // Exits with the result of the check, see the scheduler agent
+            let graph = args.get(1).unwrap_or_else(|| panic!("usage: fvm check <graph>"));
+            let manifest = Manifest { graph: Some(graph.clone()), ..Manifest::default() };
+            run(&manifest, true);
+            return;
+        },
+        _ => {},
+    }
+    let mut watch = false;
+    let mut repl = false;
+    let mut batch = false;
+    let mut features = vec![];
+    let mut paths = vec![];
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match &arg as &str {
+            "--watch" => { watch = true; },
+            "--repl" => { repl = true; },
+            "--batch" => { batch = true; },
+            // The `@if feature(name)` sections kept, `--features debug,tap`
+            "--features" => {
+                let list = args.next().unwrap_or_else(|| panic!("usage: fvm --features <feature,...>"));
+                features.extend(list.split(',').filter(|f| !f.is_empty()).map(String::from));
+            },
+            _ => { paths.push(arg); },
+        }
+    }
+    // Graphs, each in its own network, or a manifest which declares the graphs and how to run them
+    let mut manifest = if paths.len() == 1 && paths[0].ends_with(".toml") {
+        load(&paths[0])
+    } else if !paths.is_empty() {
+        let graph = paths.remove(0);
+        Manifest { graph: Some(graph), graphs: paths, ..Manifest::default() }
+    } else if Path::new(MANIFEST).exists() {
+        load(MANIFEST)
+    } else {
+        Manifest::default()
+    };
+    manifest.watch |= watch;
+    manifest.repl |= repl;
+    manifest.batch |= batch;
+    manifest.features.extend(features);
+    if manifest.graph.is_none() && (manifest.watch || !manifest.repl) {
+        panic!("usage: fvm [--watch] [--repl] [--batch] [--features <feature,...>] <graph... | fvm.toml>, fvm check <graph>, fvm inspect <agent.so> or fvm new-agent <path>");
+    }
+    run(&manifest, false);
+}
 
-    let halt = sched.get_sender("start", "halt").expect("actio of sched not found");
-    let halt = halt.downcast::<MsgSender<bool>>().expect("cannot downcast halt");
-    halt.send(true).expect("cannot send halt");
-    // Wait for the end of the execution
-    sched.join();
+fn load(path: &str) -> Manifest {
+    Manifest::load(path).unwrap_or_else(|e| panic!("{}", e))
 }
@@ -0,0 +1,160 @@
+//! The manifest of fvm, `fvm.toml`, which declares how to run a graph
+//!
+//! ```toml
+//! # The graph, and the directories where its nodes are searched before nix
+//! graph = "app.fbp"
+//! # The other graphs, each in its own network of the same process, see `rustfbp::manager`
+//! graphs = ["billing.fbp"]
+//! paths = ["agents", "/opt/fractalide"]
+//! # The `@if feature(name)` sections of the graphs kept
+//! features = ["debug"]
+//! watch = false
+//! repl = false
+//! batch = false
+//!
+//! [log]
+//! level = "debug"
+//! json = "/var/log/app.json"
+//!
+//! # A node of the running graph, by its name in `list`
+//! [nodes.db]
+//! option = "env:DATABASE_URL"
+//! isolated = true
+//!
+//! # Its own thread, pinned to the cores 2 and 3
+//! [nodes.parse]
+//! cpus = [2, 3]
+//!
+//! [nodes.db.limits]
+//! max_queued_bytes = 1048576
+//! max_run_time_ms = 5000
+//! action = "restart"
+//! ```
+//!
+//! The relative paths are relative to the directory of the manifest. The option of a node is sent to its port
+//! `option` after the imsgs of the graph, with its `env:` or `file:` reference resolved (see `rustfbp::secret`).
+//! The `action` of the limits is `backpressure` (by default), `drop_oldest`, `restart` or `cancel`, see
+//! `rustfbp::limits`. An `isolated` node runs in its own process, see `rustfbp::isolate`. A node with `cpus` runs on its
+//! own thread, any core if the list is empty, see `rustfbp::affinity`.
+
+use rustfbp::affinity::Placement;
+use rustfbp::limits::{LimitAction, Limits};
+use rustfbp::log::LevelFilter;
+use rustfbp::secret::Reference;
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use edge::{CoreConfig, CoreNodeConfig};
+use toml;
+
+/// The manifest read by `fvm` without argument
+pub const MANIFEST: &str = "fvm.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    pub graph: Option<String>,
+    #[serde(default)]
+    pub graphs: Vec<String>,
+    #[serde(default)]
+    pub paths: Vec<String>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    #[serde(default)]
+    pub watch: bool,
+    #[serde(default)]
+    pub repl: bool,
+    #[serde(default)]
+    pub batch: bool,
+    #[serde(default)]
+    pub log: Log,
+    #[serde(default)]
+    pub nodes: HashMap<String, Node>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Log {
+    pub level: Option<String>,
+    pub json: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Node {
+    pub option: Option<String>,
+    pub limits: Option<NodeLimits>,
+    #[serde(default)]
+    pub isolated: bool,
+    pub cpus: Option<Vec<usize>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NodeLimits {
+    pub max_queued_bytes: Option<usize>,
+    pub max_run_time_ms: Option<u64>,
+    pub action: Option<String>,
+}
+
+impl Manifest {
+    /// Read a manifest, and check its log level and its limits
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Manifest, String> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| format!("cannot read {} : {}", path.display(), e))?;
+        let mut manifest: Manifest = toml::from_str(&text).map_err(|e| format!("{} : {}", path.display(), e))?;
+
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let resolve = |p: &String| dir.join(p).to_string_lossy().into_owned();
+        manifest.graph = manifest.graph.as_ref().map(&resolve);
+        manifest.graphs = manifest.graphs.iter().map(&resolve).collect();
+        manifest.paths = manifest.paths.iter().map(&resolve).collect();
+        manifest.log.json = manifest.log.json.as_ref().map(&resolve);
+
+        manifest.level().map_err(|e| format!("{} : {}", path.display(), e))?;
+        manifest.config().map_err(|e| format!("{} : {}", path.display(), e))?;
+        Ok(manifest)
+    }
+
+    /// The log level, if set
+    pub fn level(&self) -> Result<Option<LevelFilter>, String> {
+        match self.log.level {
+            Some(ref level) => level.parse().map(Some).map_err(|_| format!("unknown log level {}", level)),
+            None => Ok(None),
+        }
+    }
+
+    /// The options and the limits of the nodes, for the scheduler agent
+    pub fn config(&self) -> Result<CoreConfig, String> {
+        let mut config = CoreConfig::default();
+        for (name, node) in &self.nodes {
+            let limits = match node.limits {
+                Some(ref limits) => Some(Limits {
+                    max_queued_bytes: limits.max_queued_bytes,
+                    max_run_time: limits.max_run_time_ms.map(Duration::from_millis),
+                    action: match limits.action.as_ref().map(|a| a as &str) {
+                        None | Some("backpressure") => LimitAction::Backpressure,
+                        Some("drop_oldest") => LimitAction::DropOldest,
+                        Some("restart") => LimitAction::Restart,
+                        Some("cancel") => LimitAction::Cancel,
+                        Some(action) => { return Err(format!("unknown action {} for the node {}", action, name)); }
+                    },
+                }),
+                None => None,
+            };
+            if let Some(ref option) = node.option {
+                Reference::parse(option).map_err(|e| format!("option of the node {} : {}", name, e))?;
+            }
+            config.nodes.insert(name.clone(), CoreNodeConfig {
+                option: node.option.clone(),
+                limits: limits,
+                isolated: node.isolated,
+                placement: node.cpus.clone().map(|cpus| Placement { cpus: cpus }),
+            });
+        }
+        Ok(config)
+    }
+}
@@ -0,0 +1,230 @@
+//! `fvm new-agent <path>`, the skeleton of a new agent in `nodes/rs/<path>`
+//!
+//! ```text
+//! $ fvm new-agent fs/file/hash --inputs input:fs_path --outputs output:prim_text,error:fs_file_error
+//! ```
+//!
+//! Run it at the root of fractalide. An edge is given in snake case, `prim_text` for `PrimText`, and is searched in
+//! `edges/rs` then in `edges/capnp`. The agent gets :
+//!
+//! * `default.nix`, to build it with the other nodes : it must still be added to `nodes/rs/default.nix`
+//! * `Cargo.toml` and `build.rs`, to build it and run its tests with cargo. `build.rs` generates `edges.rs` and
+//!   `edge_capnp.rs` from the edges of the tree, like the nix build does
+//! * `lib.rs`, the `agent!` with its ports, and a test of one run with a `TestBench`
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// An edge of the agent, with its rust type
+struct Edge {
+    name: String,
+    dir: String,
+    capnp: bool,
+}
+
+/// Create the agent `path`, `fs/file/hash`, with its ports `name:edge`
+pub fn new_agent(path: &str, inputs: &[(String, String)], outputs: &[(String, String)]) -> Result<(), String> {
+    if !Path::new("edges").is_dir() || !Path::new("nodes/rs").is_dir() {
+        return Err("run fvm new-agent at the root of fractalide".into());
+    }
+    let path = path.trim_matches('/');
+    if path.is_empty() || path.split('/').any(|p| p.is_empty() || p == "." || p == "..") {
+        return Err(format!("bad agent path {}, expected like fs/file/hash", path));
+    }
+    let dir = Path::new("nodes/rs").join(path);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let inputs = ports(inputs)?;
+    let outputs = ports(outputs)?;
+    let mut edges: Vec<&Edge> = vec![];
+    for &(_, ref edge) in inputs.iter().chain(&outputs) {
+        if !edges.iter().any(|e| e.name == edge.name) {
+            edges.push(edge);
+        }
+    }
+
+    let crate_name = path.replace('/', "_");
+    // From nodes/rs/<path> to the root
+    let root = vec![".."; path.split('/').count() + 2].join("/");
+    fs::create_dir_all(&dir).map_err(|e| format!("cannot create {} : {}", dir.display(), e))?;
+    write(dir.join("default.nix"), &default_nix(&edges))?;
+    write(dir.join("Cargo.toml"), &cargo_toml(&crate_name, &root))?;
+    write(dir.join("build.rs"), &build_rs(&edges, &root))?;
+    write(dir.join("lib.rs"), &lib_rs(&inputs, &outputs))?;
+    println!("created {}", dir.display());
+    println!("add it to nodes/rs/default.nix : {} = callPackage ./{} {{}};", crate_name, path);
+    Ok(())
+}
+
+// The ports with their edges, found in the tree
+fn ports(ports: &[(String, String)]) -> Result<Vec<(String, Edge)>, String> {
+    ports.iter().map(|&(ref port, ref edge)| {
+        if port.is_empty() || !port.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(format!("bad port name {}", port));
+        }
+        Ok((port.clone(), find_edge(edge)?))
+    }).collect()
+}
+
+fn find_edge(edge: &str) -> Result<Edge, String> {
+    let dir = edge.replace('_', "/");
+    let name = edge.split('_').map(|part| {
+        let mut chars = part.chars();
+        chars.next().map(|c| c.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+    }).collect::<String>();
+    if Path::new("edges/rs").join(&dir).join("edge.rs").is_file() {
+        Ok(Edge { name: name, dir: dir, capnp: false })
+    } else if Path::new("edges/capnp").join(&dir).join("default.nix").is_file() {
+        Ok(Edge { name: name, dir: dir, capnp: true })
+    } else {
+        Err(format!("unknown edge {}, neither in edges/rs/{} nor in edges/capnp/{}", edge, dir, dir))
+    }
+}
+
+fn write(path: PathBuf, content: &str) -> Result<(), String> {
+    fs::write(&path, content).map_err(|e| format!("cannot write {} : {}", path.display(), e))
+}
+
+fn names(edges: &[&Edge], capnp: bool) -> Vec<String> {
+    edges.iter().filter(|e| e.capnp == capnp).map(|e| e.name.clone()).collect()
+}
+
+fn dirs(edges: &[&Edge], capnp: bool) -> String {
+    edges.iter().filter(|e| e.capnp == capnp).map(|e| format!("\"{}\", ", e.dir)).collect::<String>()
+        .trim_end_matches(", ").to_string()
+}
+
+fn default_nix(edges: &[&Edge]) -> String {
+    let mut nix = String::from("{ agent, edges, mods, pkgs }:\n\nagent {\n  src = ./.;\n");
+    let capnp = names(edges, true);
+    if !capnp.is_empty() {
+        nix.push_str(&format!("  capnp_edges = with edges.capnp; [ {} ];\n", capnp.join(" ")));
+    }
+    let rs = names(edges, false);
+    if !rs.is_empty() {
+        nix.push_str(&format!("  edges = with edges.rs; [ {} ];\n", rs.join(" ")));
+    }
+    nix.push_str("  mods = with mods.rs; [ rustfbp capnp ];\n  osdeps = with pkgs; [];\n}\n");
+    nix
+}
+
+fn cargo_toml(crate_name: &str, root: &str) -> String {
+    format!(r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2018"
+build = "build.rs"
+
+[lib]
+name = "agent"
+path = "lib.rs"
+crate-type = ["dylib"]
+
+[dependencies]
+capnp = "^0.8.10"
+rustfbp = {{ path = "{root}/modules/rs/rustfbp" }}
+
+[build-dependencies]
+capnpc = "^0.8"
+"#, name = crate_name, root = root)
+}
+
+fn build_rs(edges: &[&Edge], root: &str) -> String {
+    format!(r#"//! Generate `edges.rs` and `edge_capnp.rs` from the edges of the tree, like the nix build does
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+const ROOT: &str = "{root}";
+const EDGES: &[&str] = &[{rs}];
+const CAPNP_EDGES: &[&str] = &[{capnp}];
+
+fn main() {{
+    let root = Path::new(ROOT);
+    let mut edges = String::from("// This file is generated don't edit it.\n\n");
+    for edge in EDGES {{
+        let path = root.join("edges/rs").join(edge).join("edge.rs");
+        println!("cargo:rerun-if-changed={{}}", path.display());
+        edges.push_str(&fs::read_to_string(&path).expect("cannot read an edge"));
+        edges.push('\n');
+    }}
+    fs::write("edges.rs", edges).expect("cannot write edges.rs");
+
+    // The schema of a capnp edge is the `schema` of its nix expression, the edges it uses must be listed too
+    let mut schema = String::new();
+    for edge in CAPNP_EDGES {{
+        let path = root.join("edges/capnp").join(edge).join("default.nix");
+        println!("cargo:rerun-if-changed={{}}", path.display());
+        let nix = fs::read_to_string(&path).expect("cannot read an edge");
+        let start = nix.find("schema").and_then(|i| nix[i..].find("''").map(|j| i + j + 2)).expect("no schema");
+        let end = start + nix[start..].find("'';").expect("no end of schema");
+        schema.push_str(&nix[start..end]);
+    }}
+    if schema.is_empty() {{
+        fs::write("edge_capnp.rs", "").expect("cannot write edge_capnp.rs");
+        return;
+    }}
+    let id = String::from_utf8(Command::new("capnp").arg("id").output().expect("cannot run capnp id").stdout)
+        .expect("bad capnp id");
+    fs::write("edge.capnp", format!("{{}};\n\n{{}}", id.trim(), schema)).expect("cannot write edge.capnp");
+    capnpc::CompilerCommand::new().file("edge.capnp").output_path(".").run().expect("cannot compile edge.capnp");
+    // The typed Rust structures of the flat schemas, see rustfbp::capnp_edge
+    let typed = Command::new("awk").arg("-f").arg(root.join("support/edge/capnp/capnpTyped.awk")).arg("edge.capnp")
+        .output().expect("cannot run awk").stdout;
+    let mut rs = fs::read_to_string("edge_capnp.rs").expect("cannot read edge_capnp.rs");
+    rs.push_str(&String::from_utf8(typed).expect("bad typed structures"));
+    fs::write("edge_capnp.rs", rs).expect("cannot write edge_capnp.rs");
+}}
+"#, root = root, rs = dirs(edges, false), capnp = dirs(edges, true))
+}
+
+fn lib_rs(inputs: &[(String, Edge)], outputs: &[(String, Edge)]) -> String {
+    let decl = |ports: &[(String, Edge)]| {
+        ports.iter().map(|&(ref port, ref edge)| format!("{}: {}", port, edge.name)).collect::<Vec<_>>().join(", ")
+    };
+    let mut lib = String::from("#[macro_use]\nextern crate rustfbp;\nextern crate capnp;\n\nagent! {\n");
+    if !inputs.is_empty() {
+        lib.push_str(&format!("    input({}),\n", decl(inputs)));
+    }
+    if !outputs.is_empty() {
+        lib.push_str(&format!("    output({}),\n", decl(outputs)));
+    }
+    lib.push_str("    fn run(&mut self) -> Result<Signal> {\n");
+    for &(ref port, _) in inputs {
+        lib.push_str(&format!("        let {} = self.input.{}.recv()?;\n", port, port));
+    }
+    lib.push_str("        // TODO : compute the outputs\n");
+    for &(ref port, _) in inputs {
+        lib.push_str(&format!("        let _ = {};\n", port));
+    }
+    lib.push_str("        Ok(End)\n    }\n}\n\n");
+
+    lib.push_str("#[cfg(test)]\nmod tests {\n    use super::*;\n    use rustfbp::testing::TestBench;\n\n");
+    // The edges have no default message, the test runs once the messages are written
+    if !inputs.is_empty() {
+        lib.push_str("    // TODO : send a message on each input, then remove #[ignore]\n");
+    }
+    lib.push_str("    #[test]\n");
+    if !inputs.is_empty() {
+        lib.push_str("    #[ignore]\n");
+    }
+    lib.push_str("    fn run_once() {\n");
+    lib.push_str("        let mut bench = TestBench::new(create_agent).expect(\"cannot create the agent\");\n");
+    for &(ref port, ref edge) in outputs {
+        lib.push_str(&format!("        bench.capture::<{}>(\"{}\").expect(\"cannot capture {}\");\n",
+                              edge.name, port, port));
+    }
+    for &(ref port, ref edge) in inputs {
+        lib.push_str(&format!("        // bench.send::<{}>(\"{}\", {} {{ .. }}).expect(\"cannot send on {}\");\n",
+                              edge.name, port, edge.name, port));
+    }
+    lib.push_str("        bench.run().expect(\"cannot run the agent\");\n");
+    for &(ref port, ref edge) in outputs {
+        lib.push_str(&format!("        let _ = bench.received::<{}>(\"{}\").expect(\"cannot get {}\");\n",
+                              edge.name, port, port));
+    }
+    lib.push_str("    }\n}\n");
+    lib
+}
@@ -32,6 +32,14 @@ agent! {
                     nodes.insert(name, sort);
                 }
             }
+            // An include is a subgraph node
+            for i in &graph.includes {
+                if nodes.contains_key(&i.name) {
+                    errors.push(format!("The node \"{}()\" has been declared more than once : {} and include \"{}\"", i.name, nodes[&i.name], i.path));
+                } else {
+                    nodes.insert(&i.name, &i.path);
+                }
+            }
 
 
             let mut edges = HashMap::new();
@@ -0,0 +1,29 @@
+//! Feed arbitrary bytes to the lexer of core_parser_lexical, which must never panic nor loop
+//!
+//! `cargo fuzz run lex_line` in `nodes/fvm/rs/parser/lexical`.
+
+#![no_main]
+
+#[macro_use]
+extern crate libfuzzer_sys;
+#[macro_use]
+extern crate nom;
+
+mod edges {
+    include!("../../../../../../../edges/rs/core/lexical/edge.rs");
+}
+
+#[path = "../../lexer.rs"]
+mod lexer;
+
+use edges::CoreLexicalToken;
+
+fuzz_target!(|data: &[u8]| {
+    // fs_file_open gives the lines to the lexer
+    for line in data.split(|b| *b == b'\n') {
+        match lexer::lex_line(line).last() {
+            Some(&(CoreLexicalToken::Break, _)) => {},
+            other => panic!("the line doesn't end with a Break : {:?}", other),
+        }
+    }
+});
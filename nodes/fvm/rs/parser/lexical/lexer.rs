@@ -0,0 +1,265 @@
+//! The lexer of the fbp language, without the agent, so it's also built by the fuzz target
+
+use nom::{ErrorKind, IResult};
+use nom::multispace;
+
+use std::str::FromStr;
+
+use super::edges::CoreLexicalToken;
+
+// `// comment` or `# comment`, until the end of the line
+named!(comment<&[u8], CoreLexicalToken>, do_parse!(
+    many0!(multispace) >>
+    alt!(tag!(b"//") | tag!(b"#")) >>
+    opt!(complete!(is_not!("\n"))) >>
+    ( CoreLexicalToken::Comment )
+));
+
+named!(bind<&[u8], CoreLexicalToken>, do_parse!(
+    ws!(tag!(b"->")) >>
+    ( CoreLexicalToken::Bind )
+));
+
+named!(external<&[u8], CoreLexicalToken>, do_parse!(
+    ws!(tag!(b"=>")) >>
+    ( CoreLexicalToken::External )
+));
+
+fn imsg(input: &[u8]) -> IResult<&[u8], CoreLexicalToken> {
+    quoted(input).map(CoreLexicalToken::IMsg)
+}
+
+/// A string between quotes, `'...'` or `"..."`, with the spaces around it
+///
+/// `\'`, `\"`, `\\`, `\n`, `\t` and `\r` are escaped, any other backslash is kept as is.
+pub fn quoted(input: &[u8]) -> IResult<&[u8], String> {
+    let start = input.iter().take_while(|b| b.is_ascii_whitespace()).count();
+    let quote = match input.get(start) {
+        Some(&q) if q == b'\'' || q == b'"' => q,
+        _ => { return IResult::Error(error_position!(ErrorKind::Custom(0), input)); },
+    };
+    let mut bytes = vec![];
+    let mut i = start + 1;
+    while i < input.len() {
+        match input[i] {
+            b'\\' if i + 1 < input.len() => {
+                match input[i + 1] {
+                    b'n' => { bytes.push(b'\n'); },
+                    b't' => { bytes.push(b'\t'); },
+                    b'r' => { bytes.push(b'\r'); },
+                    b'\\' | b'\'' | b'"' => { bytes.push(input[i + 1]); },
+                    other => { bytes.push(b'\\'); bytes.push(other); },
+                }
+                i += 2;
+            },
+            q if q == quote => {
+                let end = i + 1 + input[i + 1..].iter().take_while(|b| b.is_ascii_whitespace()).count();
+                return match String::from_utf8(bytes) {
+                    Ok(text) => IResult::Done(&input[end..], text),
+                    Err(_) => IResult::Error(error_position!(ErrorKind::Custom(1), input)),
+                };
+            },
+            b => {
+                bytes.push(b);
+                i += 1;
+            },
+        }
+    }
+    // Not closed
+    IResult::Error(error_position!(ErrorKind::Custom(2), input))
+}
+
+named!(name<&str>,
+    map_res!(
+        is_not!(" \t\r\n[("),
+        std::str::from_utf8
+    )
+);
+
+named!(selection<String>, do_parse!(
+    many0!(multispace) >>
+    tag!(b"[") >>
+    selection: map_res!(
+        take_until!("]"),
+        std::str::from_utf8
+    ) >>
+    tag!(b"]") >>
+    many0!(multispace) >>
+    ( selection.to_string() )
+));
+
+named!(comp<CoreLexicalToken>, do_parse!(
+    many0!(multispace) >>
+    name: name >>
+    tag!(b"(") >>
+        many0!(multispace) >>
+        sort: opt!(complete!(map_res!(
+            map_res!(
+                take_until1!(")"),
+                std::str::from_utf8
+            ),
+        std::str::FromStr::from_str
+    ))) >>
+    tag!(b")") >>
+    ( CoreLexicalToken::Comp(name.into(), sort) )
+));
+
+// A node without a name, named by the semantic parser : `'x' -> input (core_print)`
+named!(anon<CoreLexicalToken>, do_parse!(
+    many0!(multispace) >>
+    tag!(b"(") >>
+    many0!(multispace) >>
+    sort: map_res!(
+        take_until1!(")"),
+        std::str::from_utf8
+    ) >>
+    tag!(b")") >>
+    ( CoreLexicalToken::Comp(String::new(), Some(sort.trim().into())) )
+));
+
+named!(port<CoreLexicalToken>, do_parse!(
+    many0!(multispace) >>
+    name: name >>
+    selection: opt!(complete!(selection)) >>
+    many0!(multispace) >>
+    ( CoreLexicalToken::Port(name.into(), selection) )
+));
+
+named!(pool<&[u8], CoreLexicalToken>, do_parse!(
+    ws!(tag!(b"*")) >>
+    size: map_res!(
+        map_res!(
+            nom::digit,
+            std::str::from_utf8
+        ),
+        usize::from_str
+    ) >>
+    ordered: opt!(complete!(ws!(tag!(b"ordered")))) >>
+    ( CoreLexicalToken::Pool(size, ordered.is_some()) )
+));
+
+// A port of the network, with the node and the port behind it : `INPORT=open.input:IN`
+named!(export<&[u8], CoreLexicalToken>, do_parse!(
+    many0!(multispace) >>
+    input: alt!(value!(true, tag!(b"INPORT")) | value!(false, tag!(b"OUTPORT"))) >>
+    tag!(b"=") >>
+    comp: map_res!(
+        is_not!(". "),
+        std::str::from_utf8
+    ) >>
+    tag!(b".") >>
+    port: map_res!(
+        is_not!("[: "),
+        std::str::from_utf8
+    ) >>
+    selection: opt!(complete!(selection)) >>
+    tag!(b":") >>
+    name: map_res!(
+        is_not!(" \t\r\n"),
+        std::str::from_utf8
+    ) >>
+    many0!(multispace) >>
+    ( if input {
+        CoreLexicalToken::InPort(comp.into(), port.into(), selection, name.into())
+    } else {
+        CoreLexicalToken::OutPort(comp.into(), port.into(), selection, name.into())
+    } )
+));
+
+// The elements of an array port, for its `[*]` connections : `ELEMENTS=workers.input:0..4`
+named!(elements<&[u8], CoreLexicalToken>, do_parse!(
+    many0!(multispace) >>
+    tag!(b"ELEMENTS=") >>
+    comp: map_res!(
+        is_not!(". "),
+        std::str::from_utf8
+    ) >>
+    tag!(b".") >>
+    port: map_res!(
+        is_not!(": "),
+        std::str::from_utf8
+    ) >>
+    tag!(b":") >>
+    list: map_res!(
+        is_not!(" \t\r\n"),
+        std::str::from_utf8
+    ) >>
+    many0!(multispace) >>
+    ( CoreLexicalToken::Elements(comp.into(), port.into(), list.into()) )
+));
+
+// A node which doesn't run until other nodes are ready : `AFTER=api:db,cache`
+named!(after<&[u8], CoreLexicalToken>, do_parse!(
+    many0!(multispace) >>
+    tag!(b"AFTER=") >>
+    node: map_res!(
+        is_not!(": "),
+        std::str::from_utf8
+    ) >>
+    tag!(b":") >>
+    list: map_res!(
+        is_not!(" \t\r\n"),
+        std::str::from_utf8
+    ) >>
+    many0!(multispace) >>
+    ( CoreLexicalToken::After(node.into(), list.into()) )
+));
+
+// A graph file, used as a subgraph named `log` : `include "common/logging.fbp" as log`
+named!(include<&[u8], CoreLexicalToken>, do_parse!(
+    many0!(multispace) >>
+    tag!(b"include") >>
+    path: quoted >>
+    tag!(b"as") >>
+    many0!(multispace) >>
+    name: name >>
+    many0!(multispace) >>
+    ( CoreLexicalToken::Include(path, name.into()) )
+));
+
+// A section of the graph kept only with a feature given to fvm : `@if feature(debug)`, `@else` and `@end`
+named!(directive<&[u8], CoreLexicalToken>, do_parse!(
+    many0!(multispace) >>
+    tag!(b"@") >>
+    token: alt!(
+        complete!(do_parse!(
+            tag!(b"if") >>
+            many0!(multispace) >>
+            tag!(b"feature(") >>
+            feature: map_res!(
+                take_until!(")"),
+                std::str::from_utf8
+            ) >>
+            tag!(b")") >>
+            ( CoreLexicalToken::If(feature.trim().into()) )
+        )) |
+        value!(CoreLexicalToken::Else, tag!(b"else")) |
+        value!(CoreLexicalToken::EndIf, tag!(b"end"))
+    ) >>
+    many0!(multispace) >>
+    ( token )
+));
+
+named!(comp_or_port<&[u8], CoreLexicalToken>, alt!(complete!(comp) | complete!(anon) | port));
+
+named!(literal<&[u8], CoreLexicalToken>, alt!(comment | complete!(directive) | complete!(export) | complete!(elements) | complete!(after) | complete!(include) | imsg | bind | external | complete!(pool) | comp_or_port));
+
+/// The tokens of one statement, followed by a `Break`, with the offset where each of them starts in `text`
+pub fn lex_line(mut text: &[u8]) -> Vec<(CoreLexicalToken, usize)> {
+    let len = text.len();
+    let mut tokens = vec![];
+    loop {
+        match literal(text) {
+            // A literal which takes nothing would loop forever
+            IResult::Done(rest, _) if rest.len() == text.len() => { break; },
+            IResult::Done(rest, lit) => {
+                let spaces = text.iter().take_while(|b| b.is_ascii_whitespace()).count();
+                tokens.push((lit, len - text.len() + spaces));
+                text = rest;
+            },
+            _ => { break;}
+        }
+    }
+    tokens.push((CoreLexicalToken::Break, len));
+    tokens
+}
@@ -5,90 +5,12 @@ extern crate capnp;
 #[macro_use]
 extern crate nom;
 
-use nom::{IResult, AsBytes};
-use nom::multispace;
-
-use std::str::FromStr;
-
-named!(comment<&[u8], CoreLexicalToken>, do_parse!(
-    many0!(multispace) >>
-    tag!(b"//") >>
-    is_not!("\n") >>
-    ( CoreLexicalToken::Comment )
-));
-
-named!(bind<&[u8], CoreLexicalToken>, do_parse!(
-    ws!(tag!(b"->")) >>
-    ( CoreLexicalToken::Bind )
-));
-
-named!(external<&[u8], CoreLexicalToken>, do_parse!(
-    ws!(tag!(b"=>")) >>
-    ( CoreLexicalToken::External )
-));
-
-named!(imsg<&[u8], CoreLexicalToken>, do_parse!(
-    many0!(multispace) >>
-    tag!(b"'") >>
-    imsg: map_res!(
-        take_until!("'"),
-        std::str::from_utf8
-    ) >>
-    tag!(b"'") >>
-    many0!(multispace) >>
-    ( CoreLexicalToken::IMsg(imsg.into()) )
-));
-
-named!(name<&str>,
-    map_res!(
-        is_not!(" [("),
-        std::str::from_utf8
-    )
-);
-
-named!(selection<String>, do_parse!(
-    many0!(multispace) >>
-    tag!(b"[") >>
-    selection: map_res!(
-        take_until!("]"),
-        std::str::from_utf8
-    ) >>
-    tag!(b"]") >>
-    many0!(multispace) >>
-    ( selection.to_string() )
-));
-
-named!(comp<CoreLexicalToken>, do_parse!(
-    many0!(multispace) >>
-    name: name >>
-    tag!(b"(") >>
-        many0!(multispace) >>
-        sort: opt!(complete!(map_res!(
-            map_res!(
-                take_until1!(")"),
-                std::str::from_utf8
-            ),
-        std::str::FromStr::from_str
-    ))) >>
-    tag!(b")") >>
-    ( CoreLexicalToken::Comp(name.into(), sort) )
-));
-
-named!(port<CoreLexicalToken>, do_parse!(
-    many0!(multispace) >>
-    name: name >>
-    selection: opt!(complete!(selection)) >>
-    many0!(multispace) >>
-    ( CoreLexicalToken::Port(name.into(), selection) )
-));
-
-named!(comp_or_port<&[u8], CoreLexicalToken>, alt!(complete!(comp) | port));
-
-named!(literal<&[u8], CoreLexicalToken>, alt!(comment | imsg | bind | external | comp_or_port));
+mod lexer;
 
 agent! {
     input(input: FsFileDesc),
     output(output: CoreLexical),
+    blocking(true),
     fn run(&mut self) -> Result<Signal>{
         let file = self.input.input.recv()?;
 
@@ -105,25 +27,32 @@ agent! {
 }
 
 fn handle_stream(comp: &ThisAgent) -> Result<()> {
+    // The end of the last chunk, waiting for the rest of its line
+    let mut pending: Vec<u8> = vec![];
+    let mut statement = Statement::new();
     loop {
         let file = comp.input.input.recv()?;
 
         // print it
         match file {
             FsFileDesc::Text(text) => {
-                let mut text = text.as_bytes();
-                loop {
-                    match literal(text) {
-                        IResult::Done(rest, lit) => {
-                            let _ = comp.output.output.send(CoreLexical::Token(lit));
-                            text = rest;
-                        },
-                        _ => { break;}
-                    }
+                for line in text.as_bytes().split(|b| *b == b'\n') {
+                    statement.push(comp, line);
+                }
+            },
+            FsFileDesc::Chunk(chunk) => {
+                pending.extend(chunk);
+                while let Some(pos) = pending.iter().position(|b| *b == b'\n') {
+                    let rest = pending.split_off(pos + 1);
+                    statement.push(comp, &pending[..pos]);
+                    pending = rest;
                 }
-                let _ = comp.output.output.send(CoreLexical::Token(CoreLexicalToken::Break));
             },
             FsFileDesc::End(path) => {
+                if !pending.is_empty() {
+                    statement.push(comp, &pending);
+                }
+                statement.flush(comp);
                 let _ = comp.output.output.send(CoreLexical::End(path));
                 break;
             },
@@ -132,3 +61,79 @@ fn handle_stream(comp: &ThisAgent) -> Result<()> {
     }
     Ok(())
 }
+
+// The lines of the statement being read
+//
+// A line ending with `\`, or indented deeper than the first line of the statement, continues it :
+//
+// a() output -> input b() \
+//     output -> input c()
+//
+// A directive, `@if feature(debug)`, is a statement of its own line : the lines of its section can be indented.
+struct Statement {
+    text: Vec<u8>,
+    // Where each line starts in `text`, with its number in the graph
+    lines: Vec<(usize, usize)>,
+    // The indentation of the first line
+    indent: usize,
+    // The last line ends with `\`
+    open: bool,
+    // The lines read so far
+    count: usize,
+}
+
+impl Statement {
+    fn new() -> Self {
+        Statement {
+            text: vec![],
+            lines: vec![],
+            indent: 0,
+            open: false,
+            count: 0,
+        }
+    }
+
+    fn push(&mut self, comp: &ThisAgent, line: &[u8]) {
+        self.count += 1;
+        let line = if line.ends_with(b"\r") { &line[..line.len() - 1] } else { line };
+        let indent = line.iter().take_while(|b| **b == b' ' || **b == b'\t').count();
+        let blank = indent == line.len();
+        let directive = line[indent..].starts_with(b"@");
+        let continued = !self.lines.is_empty() && !directive && (self.open || (!blank && indent > self.indent));
+        if !continued {
+            self.flush(comp);
+            self.indent = indent;
+        } else {
+            self.text.push(b'\n');
+        }
+        self.lines.push((self.text.len(), self.count));
+        let end = line.iter().rposition(|b| *b != b' ' && *b != b'\t').map(|end| end + 1).unwrap_or(0);
+        self.open = line[..end].ends_with(b"\\");
+        if self.open {
+            self.text.extend_from_slice(&line[..end - 1]);
+        } else {
+            self.text.extend_from_slice(line);
+        }
+        if directive {
+            self.flush(comp);
+        }
+    }
+
+    // Send the tokens of the statement
+    fn flush(&mut self, comp: &ThisAgent) {
+        if self.lines.is_empty() {
+            return;
+        }
+        for (token, offset) in lexer::lex_line(&self.text) {
+            let &(start, line) = self.lines.iter().rev().find(|&&(start, _)| start <= offset).unwrap_or(&self.lines[0]);
+            let pos = CoreLexicalPos {
+                line: line,
+                column: offset - start + 1,
+            };
+            let _ = comp.output.output.send(CoreLexical::Token(token, pos));
+        }
+        self.text.clear();
+        self.lines.clear();
+        self.open = false;
+    }
+}
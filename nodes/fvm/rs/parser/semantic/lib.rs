@@ -9,19 +9,44 @@ enum State {
     CompPortExternal, CompPortExternalPort,
     PortExternal, PortExternalPort,
     IMSGBind, IMSGBindPort,
+    Export, Declare,
     ErrorS
 }
 use State::*;
 
+use std::collections::HashMap;
+
+// A `@if feature(name)` section, until its `@end`
+struct Section {
+    feature: String,
+    line: usize,
+    // The feature is given
+    enabled: bool,
+    // After its `@else`
+    otherwise: bool,
+}
+
+impl Section {
+    // Its statements are in the graph
+    fn kept(&self) -> bool {
+        self.enabled != self.otherwise
+    }
+}
+
 agent! {
     input(input: CoreLexical),
     output(output: CoreGraph, error: CoreSemanticError),
+    // The features given to fvm, separated by ',', for the `@if feature(name)` sections
+    option(String),
+    blocking(true),
     fn run(&mut self) -> Result<Signal> {
         let literal = self.input.input.recv()?;
+        let features = self.try_recv_option().unwrap_or_default();
+        let features: Vec<&str> = features.split(',').map(|f| f.trim()).filter(|f| !f.is_empty()).collect();
 
         match literal {
             CoreLexical::Start(path) => {
-                match handle_stream(&self)? {
+                match handle_stream(&self, &features)? {
                     Ok(mut graph) => {
                         graph.path = path;
                         self.output.output.send(graph);
@@ -41,12 +66,20 @@ agent! {
     }
 }
 
-fn handle_stream(comp: &ThisAgent) -> Result<std::result::Result<CoreGraph, Vec<String>>> {
+fn handle_stream(comp: &ThisAgent, features: &[&str]) -> Result<std::result::Result<CoreGraph, Vec<String>>> {
     let mut state = Break;
     let mut stack: Vec<CoreLexicalToken> = vec![];
     let mut graph = CoreGraph::new();
     let mut errors: Vec<String> = vec![];
     let mut line: usize = 1;
+    // The connections to several elements of array ports, by line, expanded once all the elements are declared
+    let mut spread_edges: Vec<(usize, CoreGraphEdge)> = vec![];
+    let mut spread_imsgs: Vec<(usize, CoreGraphIMsg)> = vec![];
+    let mut elements: HashMap<(String, String), Vec<String>> = HashMap::new();
+    // The anonymous nodes named so far
+    let mut anonymous: usize = 0;
+    // The `@if` sections the statements are in, the innermost last
+    let mut sections: Vec<Section> = vec![];
 
     loop {
         let literal = comp.input.input.recv()?;
@@ -54,7 +87,42 @@ fn handle_stream(comp: &ThisAgent) -> Result<std::result::Result<CoreGraph, Vec<
             CoreLexical::End(_) => {
                 break;
             },
-            CoreLexical::Token(token) => {
+            CoreLexical::Token(token, pos) => {
+                line = pos.line;
+                // A directive is a statement of its own, the statements of the sections not kept are skipped
+                match token {
+                    CoreLexicalToken::If(ref feature) => {
+                        if state != Break && state != ErrorS {
+                            errors.push(format!("line {} : Found \"@if feature({})\", when \"{}\" was expected.", line, feature, get_expected(&state)));
+                            state = ErrorS;
+                        }
+                        sections.push(Section {
+                            feature: feature.clone(),
+                            line: line,
+                            enabled: features.contains(&(feature as &str)),
+                            otherwise: false,
+                        });
+                        continue;
+                    },
+                    CoreLexicalToken::Else => {
+                        match sections.last_mut() {
+                            Some(ref mut section) if !section.otherwise => { section.otherwise = true; },
+                            Some(section) => {
+                                errors.push(format!("line {} : \"@if feature({})\" of line {} has already an \"@else\".", line, section.feature, section.line));
+                            },
+                            None => { errors.push(format!("line {} : Found \"@else\" without \"@if\".", line)); },
+                        }
+                        continue;
+                    },
+                    CoreLexicalToken::EndIf => {
+                        if sections.pop().is_none() {
+                            errors.push(format!("line {} : Found \"@end\" without \"@if\".", line));
+                        }
+                        continue;
+                    },
+                    _ if sections.iter().any(|s| !s.kept()) => { continue; },
+                    _ => {},
+                }
                 match token {
                     CoreLexicalToken::Bind => {
                         state = match state {
@@ -108,6 +176,13 @@ fn handle_stream(comp: &ThisAgent) -> Result<std::result::Result<CoreGraph, Vec<
                         };
                     },
                     CoreLexicalToken::Comp(name, sort) => {
+                        let name = match sort {
+                            Some(ref s) if name.is_empty() => {
+                                anonymous += 1;
+                                anonymous_name(s, anonymous)
+                            },
+                            _ => name,
+                        };
                         if let Some(ref s) = sort {
                             if s != "" {
                                 graph.nodes.push(CoreGraphNode {
@@ -126,14 +201,19 @@ fn handle_stream(comp: &ThisAgent) -> Result<std::result::Result<CoreGraph, Vec<
                                     let (in_p_n, in_p_s) = if let CoreLexicalToken::Port(n, s) = in_p { (n, s) } else { unreachable!() };
                                     let (out_p_n, out_p_s) = if let CoreLexicalToken::Port(n, s) = out_p { (n, s) } else { unreachable!() };
                                     let (out_c_n, _) = if let CoreLexicalToken::Comp(n, s) = out_c { (n, s) } else { unreachable!() };
-                                    graph.edges.push(CoreGraphEdge {
+                                    let edge = CoreGraphEdge {
                                         out_comp: out_c_n,
                                         out_port: out_p_n,
                                         out_elem: out_p_s,
                                         in_port: in_p_n,
                                         in_elem: in_p_s,
                                         in_comp: in_c_n,
-                                    });
+                                    };
+                                    if is_spread(&edge.out_elem) || is_spread(&edge.in_elem) {
+                                        spread_edges.push((line, edge));
+                                    } else {
+                                        graph.edges.push(edge);
+                                    }
                                 }
                                 stack.push(CoreLexicalToken::Comp(name, sort));
                                 Compo
@@ -162,12 +242,21 @@ fn handle_stream(comp: &ThisAgent) -> Result<std::result::Result<CoreGraph, Vec<
                                     let in_c_n = name.clone();
                                     let (in_p_n, in_p_s) = if let CoreLexicalToken::Port(n, s) = in_p { (n, s) } else { unreachable!() };
                                     let imsg = if let CoreLexicalToken::IMsg(imsg) = imsg { imsg } else { unreachable!() };
-                                    graph.imsgs.push(CoreGraphIMsg {
+                                    // 'env:VAR' and 'file:/path' are resolved by the scheduler, only check them here
+                                    if let Err(result::Error::Misc(e)) = rustfbp::secret::Reference::parse(&imsg) {
+                                        errors.push(format!("line {} : {}", line, e));
+                                    }
+                                    let imsg = CoreGraphIMsg {
                                         msg: imsg,
                                         port: in_p_n,
                                         elem: in_p_s,
                                         comp: in_c_n,
-                                    });
+                                    };
+                                    if is_spread(&imsg.elem) {
+                                        spread_imsgs.push((line, imsg));
+                                    } else {
+                                        graph.imsgs.push(imsg);
+                                    }
                                 }
                                 stack.push(CoreLexicalToken::Comp(name, sort));
                                 Compo
@@ -194,12 +283,13 @@ fn handle_stream(comp: &ThisAgent) -> Result<std::result::Result<CoreGraph, Vec<
                         };
                     },
                     CoreLexicalToken::Break => {
-                        line += 1;
                         state = match state {
                             CompPortBind => { state },
                             IMSGBind => { state },
                             Compo => { stack.clear(); Break },
                             CompPortExternalPort => { stack.clear(); Break },
+                            Export => { Break },
+                            Declare => { Break },
                             Break => { Break },
                             ErrorS => { ErrorS },
                             _ => {
@@ -208,13 +298,180 @@ fn handle_stream(comp: &ThisAgent) -> Result<std::result::Result<CoreGraph, Vec<
                             },
                         };
                     },
+                    CoreLexicalToken::Pool(size, ordered) => {
+                        state = match state {
+                            Compo => {
+                                if let Some(&CoreLexicalToken::Comp(ref name, _)) = stack.last() {
+                                    graph.pools.push(CoreGraphPool {
+                                        name: name.clone(),
+                                        size: size,
+                                        ordered: ordered,
+                                    });
+                                }
+                                Compo
+                            },
+                            ErrorS => { ErrorS },
+                            _ => {
+                                errors.push(format!("line {} : Found a \"* {}\", when \"{}\" was expected.", line, size, get_expected(&state)));
+                                ErrorS
+                            },
+                        };
+                    },
+                    CoreLexicalToken::InPort(comp, port, elem, name) => {
+                        state = match state {
+                            Break => {
+                                graph.ext_in.push(CoreGraphExtIn {
+                                    port: name,
+                                    in_port: port,
+                                    in_elem: elem,
+                                    in_comp: comp,
+                                });
+                                Export
+                            },
+                            ErrorS => { ErrorS },
+                            _ => {
+                                errors.push(format!("line {} : Found \"INPORT={}.{}:{}\", when \"{}\" was expected.", line, comp, port, name, get_expected(&state)));
+                                ErrorS
+                            },
+                        };
+                    },
+                    CoreLexicalToken::OutPort(comp, port, elem, name) => {
+                        state = match state {
+                            Break => {
+                                graph.ext_out.push(CoreGraphExtOut {
+                                    port: name,
+                                    out_port: port,
+                                    out_elem: elem,
+                                    out_comp: comp,
+                                });
+                                Export
+                            },
+                            ErrorS => { ErrorS },
+                            _ => {
+                                errors.push(format!("line {} : Found \"OUTPORT={}.{}:{}\", when \"{}\" was expected.", line, comp, port, name, get_expected(&state)));
+                                ErrorS
+                            },
+                        };
+                    },
+                    CoreLexicalToken::Elements(comp, port, list) => {
+                        state = match state {
+                            Break => {
+                                match element_list(&list) {
+                                    Some(Ok(list)) => {
+                                        if elements.insert((comp.clone(), port.clone()), list).is_some() {
+                                            errors.push(format!("line {} : The elements of {}.{} are already declared.", line, comp, port));
+                                        }
+                                    },
+                                    Some(Err(e)) => { errors.push(format!("line {} : {}", line, e)); },
+                                    None => { elements.insert((comp, port), vec![list]); },
+                                }
+                                Declare
+                            },
+                            ErrorS => { ErrorS },
+                            _ => {
+                                errors.push(format!("line {} : Found \"ELEMENTS={}.{}:{}\", when \"{}\" was expected.", line, comp, port, list, get_expected(&state)));
+                                ErrorS
+                            },
+                        };
+                    },
+                    CoreLexicalToken::After(node, list) => {
+                        state = match state {
+                            Break => {
+                                for after in list.split(',').map(|a| a.trim()).filter(|a| !a.is_empty()) {
+                                    if after == node {
+                                        errors.push(format!("line {} : \"{}\" cannot start after itself.", line, node));
+                                    } else if !graph.afters.iter().any(|a| a.node == node && a.after == after) {
+                                        graph.afters.push(CoreGraphAfter {
+                                            node: node.clone(),
+                                            after: after.into(),
+                                        });
+                                    }
+                                }
+                                Declare
+                            },
+                            ErrorS => { ErrorS },
+                            _ => {
+                                errors.push(format!("line {} : Found \"AFTER={}:{}\", when \"{}\" was expected.", line, node, list, get_expected(&state)));
+                                ErrorS
+                            },
+                        };
+                    },
+                    CoreLexicalToken::Include(path, name) => {
+                        state = match state {
+                            Break => {
+                                if graph.includes.iter().any(|i| i.name == name) {
+                                    errors.push(format!("line {} : \"{}\" is already the name of an include.", line, name));
+                                }
+                                graph.includes.push(CoreGraphInclude {
+                                    name: name,
+                                    path: path,
+                                });
+                                Declare
+                            },
+                            ErrorS => { ErrorS },
+                            _ => {
+                                errors.push(format!("line {} : Found \"include \"{}\" as {}\", when \"{}\" was expected.", line, path, name, get_expected(&state)));
+                                ErrorS
+                            },
+                        };
+                    },
                     CoreLexicalToken::Comment => {
                     }
+                    CoreLexicalToken::If(_) | CoreLexicalToken::Else | CoreLexicalToken::EndIf => { unreachable!() },
                 }
             },
             _ => { panic!("bad stream"); },
         }
     }
+    for section in sections {
+        errors.push(format!("line {} : \"@if feature({})\" is not closed by \"@end\".", section.line, section.feature));
+    }
+    for (line, edge) in spread_edges {
+        let outs = spread(&edge.out_elem, &edge.out_comp, &edge.out_port, &elements);
+        let ins = spread(&edge.in_elem, &edge.in_comp, &edge.in_port, &elements);
+        let pairs = match (outs, ins) {
+            (Err(e), _) | (_, Err(e)) => Err(e),
+            (Ok(Spread::Many(outs)), Ok(Spread::Many(ins))) => {
+                if outs.len() == ins.len() {
+                    Ok(outs.into_iter().map(Some).zip(ins.into_iter().map(Some)).collect())
+                } else {
+                    Err(format!("{} elements in {}[{}], but {} in {}[{}].", outs.len(), edge.out_port, edge.out_elem.clone().unwrap_or_default(),
+                                ins.len(), edge.in_port, edge.in_elem.clone().unwrap_or_default()))
+                }
+            },
+            (Ok(Spread::Many(outs)), Ok(Spread::Any)) => Ok(outs.into_iter().map(|e| (Some(e.clone()), Some(e))).collect()),
+            (Ok(Spread::Any), Ok(Spread::Many(ins))) => Ok(ins.into_iter().map(|e| (Some(e.clone()), Some(e))).collect()),
+            (Ok(Spread::Many(outs)), Ok(Spread::One(elem))) => Ok(outs.into_iter().map(|e| (Some(e), elem.clone())).collect()),
+            (Ok(Spread::One(elem)), Ok(Spread::Many(ins))) => Ok(ins.into_iter().map(|e| (elem.clone(), Some(e))).collect()),
+            _ => Err(format!("The elements of {}.{} or {}.{} must be declared with \"ELEMENTS=\".", edge.out_comp, edge.out_port, edge.in_comp, edge.in_port)),
+        };
+        match pairs {
+            Ok(pairs) => {
+                for (out_elem, in_elem) in pairs {
+                    graph.edges.push(CoreGraphEdge {
+                        out_elem: out_elem,
+                        in_elem: in_elem,
+                        .. edge.clone()
+                    });
+                }
+            },
+            Err(e) => { errors.push(format!("line {} : {}", line, e)); },
+        }
+    }
+    for (line, imsg) in spread_imsgs {
+        match spread(&imsg.elem, &imsg.comp, &imsg.port, &elements) {
+            Ok(Spread::Many(elems)) => {
+                for elem in elems {
+                    graph.imsgs.push(CoreGraphIMsg {
+                        elem: Some(elem),
+                        .. imsg.clone()
+                    });
+                }
+            },
+            Ok(_) => { errors.push(format!("line {} : The elements of {}.{} must be declared with \"ELEMENTS=\".", line, imsg.comp, imsg.port)); },
+            Err(e) => { errors.push(format!("line {} : {}", line, e)); },
+        }
+    }
     if errors.len() > 0 {
         Ok(Err(errors))
     } else {
@@ -222,6 +479,66 @@ fn handle_stream(comp: &ThisAgent) -> Result<std::result::Result<CoreGraph, Vec<
     }
 }
 
+// The name of the `n`th anonymous node of a graph, `_core_print_1` for `(core_print)`
+fn anonymous_name(sort: &str, n: usize) -> String {
+    let sort: String = sort.chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect();
+    format!("_{}_{}", sort.trim_matches('_'), n)
+}
+
+// The elements of one side of a connection
+enum Spread {
+    // A simple port, or one element
+    One(Option<String>),
+    Many(Vec<String>),
+    // `[*]` on a port without declared elements, which takes the ones of the other side
+    Any,
+}
+
+// True if the selection names several elements : `[*]`, `[0..4]` or `[a,b,c]`
+fn is_spread(elem: &Option<String>) -> bool {
+    match *elem {
+        Some(ref elem) => elem.trim() == "*" || element_list(elem).is_some(),
+        None => false,
+    }
+}
+
+// The elements of a range `0..4` (4 excluded) or of a list `a,b,c`, None for a single element
+fn element_list(text: &str) -> Option<std::result::Result<Vec<String>, String>> {
+    let text = text.trim();
+    if let Some(dots) = text.find("..") {
+        let range = (text[..dots].trim().parse::<usize>(), text[dots + 2..].trim().parse::<usize>());
+        return Some(match range {
+            (Ok(start), Ok(end)) if start < end => Ok((start..end).map(|i| i.to_string()).collect()),
+            _ => Err(format!("Bad range of elements \"{}\", expected \"start..end\" with start < end.", text)),
+        });
+    }
+    if text.contains(',') {
+        let list: Vec<String> = text.split(',').map(|e| e.trim().to_string()).collect();
+        if list.iter().any(|e| e.is_empty()) {
+            return Some(Err(format!("Empty element in \"{}\".", text)));
+        }
+        return Some(Ok(list));
+    }
+    None
+}
+
+fn spread(elem: &Option<String>, comp: &str, port: &str, elements: &HashMap<(String, String), Vec<String>>) -> std::result::Result<Spread, String> {
+    let elem = match *elem {
+        Some(ref elem) => elem,
+        None => { return Ok(Spread::One(None)); },
+    };
+    if elem.trim() == "*" {
+        return Ok(match elements.get(&(comp.to_string(), port.to_string())) {
+            Some(list) => Spread::Many(list.clone()),
+            None => Spread::Any,
+        });
+    }
+    match element_list(elem) {
+        Some(list) => list.map(Spread::Many),
+        None => Ok(Spread::One(Some(elem.clone()))),
+    }
+}
+
 fn get_expected(state: &State) -> String {
     match *state {
         Break => { "[Component, Port, IMSG, NewLine]".into() },
@@ -237,6 +554,8 @@ fn get_expected(state: &State) -> String {
         PortExternalPort => { "[Component]".into() },
         IMSGBind => { "[Port]".into() },
         IMSGBindPort => { "[Component]".into() },
+        Export => { "[NewLine]".into() },
+        Declare => { "[NewLine]".into() },
         ErrorS => { unreachable!() }
     }
 }
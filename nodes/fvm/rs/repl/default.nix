@@ -0,0 +1,7 @@
+{ support, edges, mods }:
+
+support.node.rs.agent {
+  src = ./.;
+  edges = with edges.rs; [ CoreAction FsFileDesc ];
+  mods = with mods.rs; [ rustfbp capnp ];
+}
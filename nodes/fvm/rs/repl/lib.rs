@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use std::io;
+
+static HELP: &'static str = "FBP statements are applied to the running network :
+  a(maths_boolean_nand)        add a node
+  a() output -> a b()          connect two nodes
+  'true' -> a a()              send an imsg
+Commands :
+  start                        start the nodes without input port
+  list                         display the nodes and the edges
+  stop                         halt the network and quit
+  help                         display this help";
+
+agent! {
+    output(output: CoreAction, lex: FsFileDesc),
+    blocking(true),
+    fn run(&mut self) -> Result<Signal> {
+        let stdin = io::stdin();
+        loop {
+            print!("fvm> ");
+            io::stdout().flush()?;
+
+            let mut line = String::new();
+            // EOF is handled like stop
+            if stdin.read_line(&mut line)? == 0 {
+                self.output.output.send(CoreAction::Halt)?;
+                return Ok(End);
+            }
+
+            match line.trim() {
+                "" => {},
+                "help" => { println!("{}", HELP); },
+                "start" => { self.output.output.send(CoreAction::Start("repl".into()))?; },
+                "list" => { self.output.output.send(CoreAction::List("repl".into()))?; },
+                "stop" => {
+                    self.output.output.send(CoreAction::Halt)?;
+                    return Ok(End);
+                },
+                statement => {
+                    // The scheduler waits for the parsed graph
+                    self.output.output.send(CoreAction::Apply("repl".into()))?;
+                    self.output.lex.send(FsFileDesc::Start("repl".into()))?;
+                    self.output.lex.send(FsFileDesc::Text(statement.into()))?;
+                    self.output.lex.send(FsFileDesc::End("repl".into()))?;
+                },
+            }
+        }
+    }
+}
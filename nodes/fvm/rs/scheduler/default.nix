@@ -2,6 +2,6 @@
 
 support.node.rs.agent {
   src = ./.;
-  edges = with edges.rs; [ CoreGraph FsPath CoreAction CoreScheduler ];
+  edges = with edges.rs; [ CoreGraph CoreGraphDiff FsPath CoreAction CoreScheduler ];
   mods = with mods.rs; [ rustfbp capnp ];
 }
@@ -1,7 +1,10 @@
 #[macro_use]
 extern crate rustfbp;
-use rustfbp::scheduler::{Scheduler};
+use rustfbp::scheduler::{compatible_schema, AgentCache, Scheduler};
+use rustfbp::limits::Limits;
+use rustfbp::secret;
 use std::mem;
+use std::process;
 use std::str;
 use std::fs::File;
 extern crate capnp;
@@ -11,10 +14,12 @@ type BAny = Box<Any + Send>;
 agent! {
     input(action: CoreAction,
            graph: CoreGraph),
+    // The files of each graph added or reloaded go out on `loaded`, for `fvm --watch`
     output(// error: error,
-            ask_graph: CoreGraph),
+            ask_graph: CoreGraph, loaded: CoreGraph),
     outarr(outputs: BAny),
     accumulator(CoreScheduler),
+    blocking(true),
     fn run(&mut self) -> Result<Signal> {
         let mut acc = if let Ok(acc) = self.input.accumulator.try_recv() {
             acc
@@ -34,11 +39,82 @@ agent! {
                 self.output.ask_graph.send(g);
                 add_graph(self, &add.name, &mut acc)?;
             }
+            CoreAction::Reload(reload) => {
+                let mut g = CoreGraph::new();
+                g.path = reload.comp.clone();
+                g.nodes.push(CoreGraphNode {
+                    name: reload.name.clone(),
+                    sort: reload.comp,
+                });
+                self.output.ask_graph.send(g);
+                reload_graph(self, &reload.name, &mut acc)?;
+            }
+            CoreAction::Check(check) => {
+                let mut g = CoreGraph::new();
+                g.path = check.comp.clone();
+                g.nodes.push(CoreGraphNode {
+                    name: check.name,
+                    sort: check.comp.clone(),
+                });
+                self.output.ask_graph.send(g)?;
+                let i_graph = self.input.graph.recv()?;
+                // The parsing errors are already displayed by the errors agent
+                if i_graph.errors {
+                    process::exit(1);
+                }
+                let errors = check_graph(&mut acc.sched.cache, &i_graph);
+                if errors.is_empty() {
+                    process::exit(0);
+                }
+                println!("Graph at : {}", check.comp);
+                for error in errors {
+                    println!("  {}", error);
+                }
+                process::exit(1);
+            }
+            CoreAction::Apply(name) => {
+                apply_graph(self, &name, &mut acc)?;
+            }
+            CoreAction::Start(name) => {
+                if let Some(subnet) = acc.subnets.get(&name) {
+                    for n in &subnet.nodes {
+                        acc.sched.start_if_needed(n as &str)?;
+                    }
+                }
+            }
+            CoreAction::List(name) => {
+                if let Some(subnet) = acc.subnets.get(&name) {
+                    for n in &subnet.graph.nodes {
+                        println!("{}({})", n.name, n.sort);
+                    }
+                    for e in &subnet.graph.edges {
+                        println!("{}", edge_text(e));
+                    }
+                }
+            }
+            CoreAction::Configure(config) => {
+                acc.config = config;
+            }
+            CoreAction::Cache(cache) => {
+                acc.sched.cache = cache;
+            }
+            CoreAction::ConnectSender(connect) => {
+                acc.sched.connect_exported_output(&connect.port, connect.output)?;
+            }
+            CoreAction::GetSender(port, reply) => {
+                let _ = reply.send(acc.sched.get_exported_sender(&port));
+            }
             CoreAction::Halt => {
                 let sched = mem::replace(&mut acc.sched, Scheduler::new());
                 sched.join();
                 return Ok(End);
             }
+            CoreAction::HaltIdle => {
+                acc.sched.wait_idle()?;
+                let sched = mem::replace(&mut acc.sched, Scheduler::new());
+                sched.stop();
+                return Ok(End);
+            }
             _ => { unimplemented!() }
         }
 
@@ -144,52 +220,37 @@ agent! {
 
 fn add_graph(mut agent: &mut ThisAgent, name: &str, acc: &mut CoreScheduler) -> Result<()> {
     let i_graph = agent.input.graph.recv()?;
+    send_loaded(agent, &i_graph)?;
+    // The errors are already displayed by the errors agent
+    if i_graph.errors { return Ok(()); }
 
     let mut subnet = CoreSchedulerSubnet::new();
-    for n in i_graph.nodes {
+    for n in &i_graph.nodes {
         subnet.nodes.push(n.name.clone());
-        acc.sched.add_node(n.name, n.sort);
+        add_node(&mut acc.sched, &acc.config, &i_graph, n)?;
+    }
+    // Before the imsgs, which would run the nodes
+    for a in &i_graph.afters {
+        acc.sched.start_after(&a.node as &str, &a.after as &str)?;
     }
 
-    for e in i_graph.edges {
-        match (e.out_elem, e.in_elem) {
-            (None, None) => {
-                acc.sched.connect(e.out_comp, e.out_port,
-                                  e.in_comp, e.in_port)?;
-            }
-            (Some(out_elem), None) => {
-                acc.sched.connect_array(e.out_comp, e.out_port, out_elem,
-                                        e.in_comp, e.in_port)?;
-            }
-            (None, Some(in_elem)) => {
-                acc.sched.connect_to_array(e.out_comp, e.out_port,
-                                           e.in_comp, e.in_port, in_elem)?;
-            }
-            (Some(out_elem), Some(in_elem)) => {
-                acc.sched.connect_array_to_array(e.out_comp, e.out_port, out_elem,
-                                                 e.in_comp, e.in_port, in_elem)?;
-            }
-        }
+    for e in &i_graph.edges {
+        connect_edge(&mut acc.sched, e)?;
     }
 
-    for ext in i_graph.ext_in {
-        subnet.ext_in.insert(ext.port, (ext.in_comp, ext.in_port));
+    for ext in &i_graph.ext_in {
+        subnet.ext_in.insert(ext.port.clone(), (ext.in_comp.clone(), ext.in_port.clone()));
     }
-    for ext in i_graph.ext_out {
-        subnet.ext_out.insert(ext.port, (ext.out_comp, ext.out_port));
+    for ext in &i_graph.ext_out {
+        subnet.ext_out.insert(ext.port.clone(), (ext.out_comp.clone(), ext.out_port.clone()));
     }
+    export_ports(&mut acc.sched, &i_graph)?;
 
-    for imsg in i_graph.imsgs {
-        // TODO: manage action
-        let sender = if let Some(elem) = imsg.elem {
-            acc.sched.get_array_sender(imsg.comp, imsg.port, elem)?
-        } else {
-            acc.sched.get_sender(imsg.comp, imsg.port)?
-        };
-
-        let sender = sender.downcast::<MsgSender<String>>().expect("cannot downcast the sender");
-
-        sender.send(imsg.msg)?;
+    for imsg in &i_graph.imsgs {
+        send_imsg(&acc.sched, imsg)?;
+    }
+    for n in &i_graph.nodes {
+        send_option(&acc.sched, &acc.config, &n.name)?;
     }
 
     // Start all agents without input port
@@ -198,10 +259,346 @@ fn add_graph(mut agent: &mut ThisAgent, name: &str, acc: &mut CoreScheduler) ->
     }
 
     // Remember the subnet
+    subnet.graph = i_graph;
+    acc.subnets.insert(name.into(), subnet);
+
+    Ok(())
+}
+
+// Apply only the difference between the running subnet and the new graph,
+// the unchanged agents and their connections keep their in-flight messages
+fn reload_graph(mut agent: &mut ThisAgent, name: &str, acc: &mut CoreScheduler) -> Result<()> {
+    let i_graph = agent.input.graph.recv()?;
+    send_loaded(agent, &i_graph)?;
+    // Keep the running subnet if the new graph is broken
+    if i_graph.errors { return Ok(()); }
+
+    let old = match acc.subnets.remove(name) {
+        Some(old) => old.graph,
+        None => CoreGraph::new(),
+    };
+
+    let diff = CoreGraphDiff::between(&old, &i_graph);
+    // A node is also replaced when its sort or its pool changes
+    let replaced = |comp: &str| {
+        diff.nodes_changed.iter().any(|n| n.name == comp)
+            || diff.pools_added.iter().chain(&diff.pools_removed).any(|p| p.name == comp)
+    };
+    let removed: Vec<&CoreGraphNode> = old.nodes.iter()
+        .filter(|n| replaced(&n.name) || diff.nodes_removed.iter().any(|r| r.name == n.name))
+        .collect();
+    let added: Vec<&CoreGraphNode> = i_graph.nodes.iter()
+        .filter(|n| replaced(&n.name) || diff.nodes_added.iter().any(|a| a.name == n.name))
+        .collect();
+    let is_removed = |comp: &str| removed.iter().any(|n| n.name == comp);
+    let is_added = |comp: &str| added.iter().any(|n| n.name == comp);
+
+    for ext in &diff.ext_in_removed {
+        let _ = acc.sched.unexport_input(&ext.port);
+    }
+    for ext in &diff.ext_out_removed {
+        let _ = acc.sched.unexport_output(&ext.port);
+    }
+
+    // Disconnect the edges that are no longer in the graph
+    for e in &old.edges {
+        if is_removed(&e.out_comp) { continue; }
+        if diff.edges_removed.contains(e) || is_removed(&e.in_comp) {
+            match e.out_elem {
+                Some(ref out_elem) => acc.sched.disconnect_array(&e.out_comp as &str, &e.out_port as &str, out_elem as &str)?,
+                None => acc.sched.disconnect(&e.out_comp as &str, &e.out_port as &str)?,
+            }
+        }
+    }
+
+    for n in &removed {
+        if acc.sched.is_isolated(&n.name) {
+            acc.sched.remove_isolated_node(&n.name as &str)?;
+        } else {
+            acc.sched.remove_agent(&n.name as &str)?;
+        }
+    }
+    for n in &added {
+        add_node(&mut acc.sched, &acc.config, &i_graph, n)?;
+    }
+    // A removed `AFTER=` is not undone : the node is already running, or runs once `after` is ready or removed
+    for a in &i_graph.afters {
+        if diff.afters_added.contains(a) || is_added(&a.node) {
+            acc.sched.start_after(&a.node as &str, &a.after as &str)?;
+        }
+    }
+
+    // Connect the new edges, and the edges of the replaced agents
+    for e in &i_graph.edges {
+        if diff.edges_added.contains(e) || is_added(&e.out_comp) || is_added(&e.in_comp) {
+            connect_edge(&mut acc.sched, e)?;
+        }
+    }
+
+    // An exported port is looked up by name, a replaced node keeps it
+    for ext in &diff.ext_in_added {
+        acc.sched.export_input(&ext.port as &str, &ext.in_comp as &str, &ext.in_port as &str, ext.in_elem.as_ref().map(|e| e as &str))?;
+    }
+    for ext in &diff.ext_out_added {
+        acc.sched.export_output(&ext.port as &str, &ext.out_comp as &str, &ext.out_port as &str, ext.out_elem.as_ref().map(|e| e as &str))?;
+    }
+
+    let changed = |imsg: &CoreGraphIMsg| {
+        diff.imsgs_changed.iter().any(|c| c.comp == imsg.comp && c.port == imsg.port && c.elem == imsg.elem)
+    };
+    for imsg in &i_graph.imsgs {
+        if diff.imsgs_added.contains(imsg) || changed(imsg) || is_added(&imsg.comp) {
+            send_imsg(&acc.sched, imsg)?;
+        }
+    }
+    for n in &added {
+        send_option(&acc.sched, &acc.config, &n.name)?;
+    }
+
+    for n in &added {
+        acc.sched.start_if_needed(&n.name as &str)?;
+    }
+
+    let mut subnet = CoreSchedulerSubnet::new();
+    for n in &i_graph.nodes {
+        subnet.nodes.push(n.name.clone());
+    }
+    for ext in &i_graph.ext_in {
+        subnet.ext_in.insert(ext.port.clone(), (ext.in_comp.clone(), ext.in_port.clone()));
+    }
+    for ext in &i_graph.ext_out {
+        subnet.ext_out.insert(ext.port.clone(), (ext.out_comp.clone(), ext.out_port.clone()));
+    }
+    subnet.graph = i_graph;
     acc.subnets.insert(name.into(), subnet);
 
     Ok(())
 }
+
+// Add a graph to an existing subnet. The errors are displayed and don't stop
+// the scheduler, so an interactive user can fix the faulty statement
+fn apply_graph(mut agent: &mut ThisAgent, name: &str, acc: &mut CoreScheduler) -> Result<()> {
+    let i_graph = agent.input.graph.recv()?;
+    if i_graph.errors { return Ok(()); }
+
+    let mut subnet = acc.subnets.remove(name).unwrap_or(CoreSchedulerSubnet::new());
+    for n in &i_graph.nodes {
+        match add_node(&mut acc.sched, &acc.config, &i_graph, n) {
+            Ok(()) => {
+                subnet.nodes.push(n.name.clone());
+                subnet.graph.nodes.push(n.clone());
+                if let Some(pool) = get_pool(&i_graph, &n.name) {
+                    subnet.graph.pools.push(pool.clone());
+                }
+            }
+            Err(e) => { log_error!(agent, "{}", e); }
+        }
+    }
+    for a in &i_graph.afters {
+        match acc.sched.start_after(&a.node as &str, &a.after as &str) {
+            Ok(()) => { subnet.graph.afters.push(a.clone()); }
+            Err(e) => { log_error!(agent, "{}", e); }
+        }
+    }
+    for e in &i_graph.edges {
+        match connect_edge(&mut acc.sched, e) {
+            Ok(()) => { subnet.graph.edges.push(e.clone()); }
+            Err(e) => { log_error!(agent, "{}", e); }
+        }
+    }
+    for imsg in &i_graph.imsgs {
+        if let Err(e) = send_imsg(&acc.sched, imsg) {
+            log_error!(agent, "{}", e);
+        }
+    }
+    for n in &i_graph.nodes {
+        if let Err(e) = send_option(&acc.sched, &acc.config, &n.name) {
+            log_error!(agent, "{}", e);
+        }
+    }
+    acc.subnets.insert(name.into(), subnet);
+
+    Ok(())
+}
+
+// The files of the graph, even a broken one : a fix of one of its files reloads it
+fn send_loaded(agent: &mut ThisAgent, graph: &CoreGraph) -> Result<()> {
+    if agent.output.loaded.is_none() {
+        return Ok(());
+    }
+    let mut loaded = CoreGraph::new();
+    loaded.path = graph.path.clone();
+    loaded.errors = graph.errors;
+    loaded.files = graph.files.clone();
+    agent.output.loaded.send(loaded)
+}
+
+// The ports of the graph become the ports of the network, see `Scheduler::export_input`
+fn export_ports(sched: &mut Scheduler, graph: &CoreGraph) -> Result<()> {
+    for ext in &graph.ext_in {
+        sched.export_input(&ext.port as &str, &ext.in_comp as &str, &ext.in_port as &str, ext.in_elem.as_ref().map(|e| e as &str))?;
+    }
+    for ext in &graph.ext_out {
+        sched.export_output(&ext.port as &str, &ext.out_comp as &str, &ext.out_port as &str, ext.out_elem.as_ref().map(|e| e as &str))?;
+    }
+    Ok(())
+}
+
+fn get_pool<'a>(graph: &'a CoreGraph, name: &str) -> Option<&'a CoreGraphPool> {
+    graph.pools.iter().find(|p| p.name == name)
+}
+
+// The errors the scheduler would only find when adding the graph : the agents which cannot be loaded, the ports
+// which don't exist, and the edges between ports of different edges
+fn check_graph(cache: &mut AgentCache, graph: &CoreGraph) -> Vec<String> {
+    let mut errors = vec![];
+    let mut loaded = HashMap::new();
+    for n in &graph.nodes {
+        match cache.load(&n.sort).and_then(|_| cache.get_ports(&n.sort)) {
+            Ok(ports) => { loaded.insert(n.name.clone(), (n.sort.clone(), ports)); }
+            Err(e) => { errors.push(format!("agent {}({}) : {}", n.name, n.sort, e)); }
+        }
+    }
+    // The edge of a port, None if it doesn't exist or if its agent could not be loaded
+    let schema = |comp: &str, port: &str, array: bool, output: bool, errors: &mut Vec<String>| -> Option<String> {
+        let (sort, ports) = match loaded.get(comp) {
+            Some(&(ref sort, ref ports)) => (sort, ports),
+            None => {
+                if !graph.nodes.iter().any(|n| n.name == comp) {
+                    errors.push(format!("agent {}() is not declared", comp));
+                }
+                return None;
+            }
+        };
+        let (names, kind) = match (array, output) {
+            (false, false) => (ports.inputs.iter().chain(&ports.options).collect::<Vec<_>>(), "input"),
+            (true, false) => (ports.input_arrays.iter().collect(), "array input"),
+            (false, true) => (ports.outputs.iter().collect(), "output"),
+            (true, true) => (ports.output_arrays.iter().collect(), "array output"),
+        };
+        if !names.iter().any(|name| *name == port) {
+            errors.push(format!("agent {}({}) has no {} port {}", comp, sort, kind, port));
+            return None;
+        }
+        match (array, output) {
+            (false, false) => cache.get_schema_input(sort, port),
+            (true, false) => cache.get_schema_input_array(sort, port),
+            (false, true) => cache.get_schema_output(sort, port),
+            (true, true) => cache.get_schema_output_array(sort, port),
+        }.map_err(|e| errors.push(format!("agent {}({}) port {} : {}", comp, sort, port, e))).ok()
+    };
+    for e in &graph.edges {
+        let out = schema(&e.out_comp, &e.out_port, e.out_elem.is_some(), true, &mut errors);
+        let in_ = schema(&e.in_comp, &e.in_port, e.in_elem.is_some(), false, &mut errors);
+        if let (Some(out), Some(in_)) = (out, in_) {
+            if !compatible_schema(&out, &in_) {
+                errors.push(format!("{}() {} -> {} {}() : the edge {} cannot go to {}",
+                                    e.out_comp, e.out_port, e.in_port, e.in_comp, out, in_));
+            }
+        }
+    }
+    for imsg in &graph.imsgs {
+        schema(&imsg.comp, &imsg.port, imsg.elem.is_some(), false, &mut errors);
+    }
+    for ext in &graph.ext_in {
+        schema(&ext.in_comp, &ext.in_port, ext.in_elem.is_some(), false, &mut errors);
+    }
+    for ext in &graph.ext_out {
+        schema(&ext.out_comp, &ext.out_port, ext.out_elem.is_some(), true, &mut errors);
+    }
+    for a in &graph.afters {
+        for node in &[&a.node, &a.after] {
+            if !graph.nodes.iter().any(|n| n.name == **node) {
+                errors.push(format!("agent {}() in AFTER= is not declared", node));
+            }
+        }
+    }
+    errors
+}
+
+// Add the node, or its instances if it's a pool, with its limits and its thread from the manifest
+fn add_node(sched: &mut Scheduler, config: &CoreConfig, graph: &CoreGraph, n: &CoreGraphNode) -> Result<()> {
+    let limits = config.nodes.get(&n.name).and_then(|c| c.limits);
+    let placement = config.nodes.get(&n.name).and_then(|c| c.placement.clone());
+    if config.nodes.get(&n.name).map(|c| c.isolated).unwrap_or(false) {
+        if get_pool(graph, &n.name).is_some() || limits.is_some() || placement.is_some() {
+            return Err(result::Error::Misc(format!("the isolated node {} cannot be a pool, have limits or cpus", n.name)));
+        }
+        return sched.add_isolated_node(n.name.clone(), n.sort.clone());
+    }
+    add_local_node(sched, graph, n, limits)?;
+    match placement {
+        Some(placement) => sched.set_placement(&n.name as &str, placement),
+        None => Ok(()),
+    }
+}
+
+fn add_local_node(sched: &mut Scheduler, graph: &CoreGraph, n: &CoreGraphNode, limits: Option<Limits>) -> Result<()> {
+    match (get_pool(graph, &n.name), limits) {
+        (Some(_), Some(_)) => {
+            Err(result::Error::Misc(format!("the pool {} cannot have limits", n.name)))
+        }
+        (Some(pool), None) => sched.add_pool(n.name.clone(), n.sort.clone(), pool.size, pool.ordered),
+        (None, Some(limits)) => sched.add_node_with_limits(n.name.clone(), n.sort.clone(), limits),
+        (None, None) => sched.add_node(n.name.clone(), n.sort.clone()),
+    }
+}
+
+// Send the option of the node from the manifest, after the imsgs of the graph
+fn send_option(sched: &Scheduler, config: &CoreConfig, name: &str) -> Result<()> {
+    match config.nodes.get(name).and_then(|c| c.option.clone()) {
+        Some(option) => send_imsg(sched, &CoreGraphIMsg {
+            msg: option,
+            port: "option".into(),
+            elem: None,
+            comp: name.into(),
+        }),
+        None => Ok(()),
+    }
+}
+
+// The edge as written in a graph, `a() out[1] -> in b()`
+fn edge_text(e: &CoreGraphEdge) -> String {
+    format!("{}() {}{} -> {}{} {}()",
+            e.out_comp, e.out_port, e.out_elem.as_ref().map(|e| format!("[{}]", e)).unwrap_or("".into()),
+            e.in_port, e.in_elem.as_ref().map(|e| format!("[{}]", e)).unwrap_or("".into()), e.in_comp)
+}
+
+fn connect_edge(sched: &mut Scheduler, e: &CoreGraphEdge) -> Result<()> {
+    let res = match (&e.out_elem, &e.in_elem) {
+        (&None, &None) => {
+            sched.connect(&e.out_comp as &str, &e.out_port as &str,
+                          &e.in_comp as &str, &e.in_port as &str)
+        }
+        (&Some(ref out_elem), &None) => {
+            sched.connect_array(&e.out_comp as &str, &e.out_port as &str, out_elem as &str,
+                                &e.in_comp as &str, &e.in_port as &str)
+        }
+        (&None, &Some(ref in_elem)) => {
+            sched.connect_to_array(&e.out_comp as &str, &e.out_port as &str,
+                                   &e.in_comp as &str, &e.in_port as &str, in_elem as &str)
+        }
+        (&Some(ref out_elem), &Some(ref in_elem)) => {
+            sched.connect_array_to_array(&e.out_comp as &str, &e.out_port as &str, out_elem as &str,
+                                         &e.in_comp as &str, &e.in_port as &str, in_elem as &str)
+        }
+    };
+    res.map_err(|err| result::Error::Edge { edge: edge_text(e), source: Box::new(err) })
+}
+
+fn send_imsg(sched: &Scheduler, imsg: &CoreGraphIMsg) -> Result<()> {
+    // TODO: manage action
+    let sender = if let Some(ref elem) = imsg.elem {
+        sched.get_typed_array_sender::<String, _, _, _>(&imsg.comp as &str, &imsg.port as &str, elem as &str)?
+    } else {
+        sched.get_typed_sender::<String, _, _>(&imsg.comp as &str, &imsg.port as &str)?
+    };
+
+    // 'env:VAR' and 'file:/path' are only read now, when the network is built
+    let msg = secret::resolve(&imsg.msg)
+        .map_err(|e| result::Error::Misc(format!("imsg of {}() {} : {}", imsg.comp, imsg.port, e)))?;
+    sender.send(msg)
+}
 /*
 fn split_input(s: &str) -> Result<(String, Option<String>)> {
     let pos2 = s.find("~");
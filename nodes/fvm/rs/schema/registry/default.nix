@@ -0,0 +1,9 @@
+{ lib, support, edges, mods }:
+
+support.node.rs.agent {
+  src = ./.;
+  edges = with edges.rs; [ CoreSchema ];
+  # the registry starts with the schemas of these edges
+  capnp_edges = (lib.collect lib.isDerivation edges.capnp);
+  mods = with mods.rs; [ rustfbp capnp ];
+}
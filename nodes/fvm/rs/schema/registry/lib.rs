@@ -0,0 +1,31 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use rustfbp::schema::{Field, SchemaRegistry};
+
+// Answer the fields of a schema, for the generic agents which work on any edge
+//
+// The registry knows the schemas of all the Cap'n Proto edges of the tree, and the ones registered on `register`. A
+// schema name received on `query` is answered on `reply`, with no field if the schema is unknown.
+agent! {
+    input(register: CoreSchema, query: String),
+    output(reply: CoreSchema),
+    state(SchemaRegistry => schema_registry()),
+    fn run(&mut self) -> Result<Signal> {
+        while let Ok(schema) = self.input.register.try_recv() {
+            let fields = schema.fields.into_iter().map(|(name, kind)| Field::new(name, kind)).collect();
+            self.state.register(&schema.name, fields);
+        }
+        while let Ok(name) = self.input.query.try_recv() {
+            let fields = self.state.fields(&name)
+                .map(|fields| fields.iter().map(|f| (f.name.clone(), f.kind.clone())).collect())
+                .unwrap_or_default();
+            self.output.reply.send(CoreSchema {
+                name: name,
+                fields: fields,
+            })?;
+        }
+        Ok(End)
+    }
+}
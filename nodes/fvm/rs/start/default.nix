@@ -2,6 +2,6 @@
 
 support.node.rs.agent {
   src = ./.;
-  edges = with edges.rs; [ CoreAction ];
+  edges = with edges.rs; [ CoreAction CoreGraph FsPath FsWatchEvent ];
   mods = with mods.rs; [ rustfbp capnp ];
 }
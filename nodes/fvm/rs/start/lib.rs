@@ -2,9 +2,15 @@
 extern crate rustfbp;
 extern crate capnp;
 
+use std::fs;
+use std::path::Path;
+
 agent! {
-    input(add: String, halt: bool),
-    output(output: CoreAction),
+    // `loaded` gives the files of the watched graph, its includes and its subgraphs, each time it's loaded
+    input(add: String, check: String, watch: String, reload: FsWatchEvent, loaded: CoreGraph, halt: bool, idle: bool),
+    // The first directory to watch goes to the option of the watcher, the next ones to its `add` port
+    output(output: CoreAction, watch: FsPath, watch_add: FsPath),
+    state(Option<Watched> => None),
     fn run(&mut self) -> Result<Signal>{
         if let Ok(path) = self.input.add.try_recv() {
             self.output.output.send(CoreAction::Add(CoreActionAdd{
@@ -12,9 +18,76 @@ agent! {
                 comp: path,
             }))?;
         }
+        if let Ok(path) = self.input.check.try_recv() {
+            self.output.output.send(CoreAction::Check(CoreActionAdd{
+                name: "main".into(),
+                comp: path,
+            }))?;
+        }
+        if let Ok(path) = self.input.watch.try_recv() {
+            let file = canonical(&path);
+            self.output.watch.send(FsPath(dir(&file)))?;
+            self.state = Some(Watched {
+                dirs: vec![dir(&file)],
+                files: vec![file],
+                path: path,
+            });
+        }
+        if let Some(ref mut watched) = self.state {
+            while let Ok(graph) = self.input.loaded.try_recv() {
+                for file in graph.files {
+                    // The directories are watched, an editor often saves a graph by replacing its file
+                    let dir = dir(&file);
+                    if !watched.dirs.contains(&dir) {
+                        self.output.watch_add.send(FsPath(dir.clone()))?;
+                        watched.dirs.push(dir);
+                    }
+                    if !watched.files.contains(&file) {
+                        watched.files.push(file);
+                    }
+                }
+            }
+        }
+        let mut changed = false;
+        while let Ok(event) = self.input.reload.try_recv() {
+            if let Some(ref watched) = self.state {
+                changed |= event.kind != FsWatchKind::Deleted && watched.files.contains(&canonical(&event.path));
+            }
+        }
+        // The events of one save are one reload
+        if changed {
+            if let Some(ref watched) = self.state {
+                self.output.output.send(CoreAction::Reload(CoreActionAdd{
+                    name: "main".into(),
+                    comp: watched.path.clone(),
+                }))?;
+            }
+        }
         if let Ok(_) = self.input.halt.try_recv() {
             self.output.output.send(CoreAction::Halt)?;
         }
+        if let Ok(_) = self.input.idle.try_recv() {
+            self.output.output.send(CoreAction::HaltIdle)?;
+        }
         Ok(End)
     }
 }
+
+// The graph reloaded when one of its files changes
+pub struct Watched {
+    // As given to `watch`
+    path: String,
+    // The canonical paths of the graph, of its includes and of its subgraphs
+    files: Vec<String>,
+    dirs: Vec<String>,
+}
+
+fn canonical(path: &str) -> String {
+    fs::canonicalize(path).map(|p| p.to_string_lossy().into_owned()).unwrap_or(path.into())
+}
+
+fn dir(file: &str) -> String {
+    Path::new(file).parent().map(|dir| dir.to_string_lossy().into_owned())
+        .filter(|dir| !dir.is_empty())
+        .unwrap_or(".".into())
+}
@@ -3,12 +3,14 @@ extern crate rustfbp;
 extern crate capnp;
 
 use std::fs;
+use std::path::Path;
 
 type BAny = Box<Any + Send>;
 
 agent! {
     input(input: CoreGraph, new_path: FsPathOption, error: BAny),
     output(output: CoreGraph, ask_graph: FsPath, ask_path: FsPath),
+    blocking(true),
     fn run(&mut self) -> Result<Signal>{
         let mut errors = false;
         let mut graph = CoreGraph::new();
@@ -16,17 +18,26 @@ agent! {
         // retrieve the asked graph
         let mut i_graph = self.input.input.recv()?;
 
-        add_graph(self, (&mut errors, &mut graph), i_graph, "")?;
+        add_graph(self, (&mut errors, &mut graph), i_graph, "", false, &mut vec![])?;
 
-        if !errors {
-            self.output.output.send(graph)?;
-        }
+        // always answer, so the scheduler doesn't wait forever on a broken graph
+        graph.errors = errors;
+        self.output.output.send(graph)?;
         Ok(End)
     }
 }
-fn add_graph(agent: &ThisAgent, (mut errors, mut graph): (&mut bool, &mut CoreGraph), new_graph: CoreGraph, name: &str) -> Result<()> {
-
-    if new_graph.path == "error" { *errors = true; }
+// The ports of the graph are kept when it's the graph asked by the scheduler, they become the ports of the network
+//
+// `includes` are the paths of the graphs being added, from the top one, to refuse an include cycle.
+fn add_graph(agent: &ThisAgent, (mut errors, mut graph): (&mut bool, &mut CoreGraph), new_graph: CoreGraph, name: &str, main: bool,
+             includes: &mut Vec<String>) -> Result<()> {
+
+    if new_graph.errors { *errors = true; }
+    let path = canonical(&new_graph.path);
+    if !path.is_empty() && !graph.files.contains(&path) {
+        graph.files.push(path.clone());
+    }
+    includes.push(path);
 
     for e in new_graph.edges {
         graph.edges.push(CoreGraphEdge {
@@ -38,6 +49,19 @@ fn add_graph(agent: &ThisAgent, (mut errors, mut graph): (&mut bool, &mut CoreGr
             in_comp: format!("{}-{}", name, e.in_comp),
         });
     }
+    for p in new_graph.pools {
+        graph.pools.push(CoreGraphPool {
+            name: format!("{}-{}", name, p.name),
+            size: p.size,
+            ordered: p.ordered,
+        });
+    }
+    for a in new_graph.afters {
+        graph.afters.push(CoreGraphAfter {
+            node: format!("{}-{}", name, a.node),
+            after: format!("{}-{}", name, a.after),
+        });
+    }
     for n in new_graph.imsgs {
         graph.imsgs.push(CoreGraphIMsg {
             msg: n.msg,
@@ -64,8 +88,7 @@ fn add_graph(agent: &ThisAgent, (mut errors, mut graph): (&mut bool, &mut CoreGr
             }
         }
 
-        // add only if it's the main subnet
-        if graph.nodes.len() < 1 {
+        if main {
             graph.ext_in.push(CoreGraphExtIn {
                 port: n.port,
                 in_port: n.in_port,
@@ -84,8 +107,7 @@ fn add_graph(agent: &ThisAgent, (mut errors, mut graph): (&mut bool, &mut CoreGr
             }
         }
 
-        // add only if it's the main subnet
-        if graph.nodes.len() < 1 {
+        if main {
             graph.ext_out.push(CoreGraphExtOut {
                 port: n.port,
                 out_port: n.out_port,
@@ -96,7 +118,12 @@ fn add_graph(agent: &ThisAgent, (mut errors, mut graph): (&mut bool, &mut CoreGr
     }
 
     for n in new_graph.nodes {
-        agent.output.ask_path.send(FsPath(n.sort.clone()));
+        // A generic agent, `flow_delay<PrimText>`, is found by its name and keeps its edge
+        let (sort, edge) = match n.sort.find('<') {
+            Some(i) => (n.sort[..i].to_string(), n.sort[i..].to_string()),
+            None => (n.sort.clone(), String::new()),
+        };
+        agent.output.ask_path.send(FsPath(sort));
 
         let FsPathOption(new_path) = agent.input.new_path.recv()?;
 
@@ -113,25 +140,63 @@ fn add_graph(agent: &ThisAgent, (mut errors, mut graph): (&mut bool, &mut CoreGr
             },
             None => {
                 // println!("Error in : {}", new_graph.path);
-                println!("agent {}({}) doesn't exist", n.name, n.sort);
-                *errors = false;
+                log_error!(agent, "agent {}({}) doesn't exist", n.name, n.sort);
+                *errors = true;
                 continue;
             }
         };
 
+        if is_subgraph && !edge.is_empty() {
+            log_error!(agent, "subgraph {}({}) cannot be generic, only an agent can", n.name, n.sort);
+            *errors = true;
+            continue;
+        }
+
         if is_subgraph {
+            let pool = format!("{}-{}", name, n.name);
+            if graph.pools.iter().any(|p| p.name == pool) {
+                log_error!(agent, "subgraph {}({}) cannot be a pool, only an agent can", n.name, n.sort);
+                *errors = true;
+                continue;
+            }
             agent.output.ask_graph.send(FsPath(path))?;
 
             // retrieve the asked graph
             let mut i_graph = agent.input.input.recv()?;
 
-            add_graph(agent, (&mut errors, &mut graph), i_graph, &format!("{}-{}", name, n.name));
+            add_graph(agent, (&mut errors, &mut graph), i_graph, &format!("{}-{}", name, n.name), name.is_empty(), includes);
         } else {
             graph.nodes.push(CoreGraphNode {
                 name: format!("{}-{}", name, n.name),
-                sort: path,
+                sort: format!("{}{}", path, edge),
             });
         }
     }
+
+    // An included graph is a subgraph, found by its path
+    for i in new_graph.includes {
+        let path = include_path(&new_graph.path, &i.path);
+        if let Some(start) = includes.iter().position(|p| *p == path) {
+            let mut cycle = includes[start..].to_vec();
+            cycle.push(path);
+            log_error!(agent, "include cycle : {}", cycle.join(" -> "));
+            *errors = true;
+            continue;
+        }
+        agent.output.ask_graph.send(FsPath(path))?;
+        let i_graph = agent.input.input.recv()?;
+        add_graph(agent, (&mut errors, &mut graph), i_graph, &format!("{}-{}", name, i.name), name.is_empty(), includes)?;
+    }
+    includes.pop();
     Ok(())
 }
+
+// The path of a graph included by the graph at `from`, relative to its directory
+fn include_path(from: &str, path: &str) -> String {
+    let dir = Path::new(from).parent().unwrap_or(Path::new(""));
+    canonical(&dir.join(path).to_string_lossy())
+}
+
+fn canonical(path: &str) -> String {
+    fs::canonicalize(path).map(|p| p.to_string_lossy().into_owned()).unwrap_or(path.into())
+}
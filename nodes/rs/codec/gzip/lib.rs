@@ -0,0 +1,109 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate flate2;
+
+use flate2::Compression;
+use flate2::write::{GzDecoder, GzEncoder};
+use std::io;
+
+// Compress or decompress a substream of `FsFileDesc` with gzip
+//
+// The `Start` and `End` of the substream are forwarded, and its content is sent as `Chunk`s as the codec produces
+// it. A `Text` is taken as a line. A stream which cannot be decompressed is sent on `error`, and its remaining content
+// is dropped until its `End`.
+agent! {
+    input(input: FsFileDesc),
+    output(output: FsFileDesc, error: CodecError),
+    // The name and the codec of the current substream, no codec after an error
+    state(Option<(String, Option<Codec>)> => None),
+    option(CodecOption),
+    fn run(&mut self) -> Result<Signal> {
+        let opt = self.recv_option();
+
+        match self.input.input.recv()? {
+            FsFileDesc::Start(name) => {
+                self.state = Some((name.clone(), Some(Codec::new(&opt))));
+                self.output.output.send(FsFileDesc::Start(name))?;
+            },
+            FsFileDesc::Text(text) => {
+                let mut bytes = text.into_bytes();
+                bytes.push(b'\n');
+                write(&mut self.state, &bytes, &self.output.output, &self.output.error)?;
+            },
+            FsFileDesc::Chunk(chunk) => {
+                write(&mut self.state, &chunk, &self.output.output, &self.output.error)?;
+            },
+            FsFileDesc::End(name) => {
+                if let Some((stream, Some(codec))) = self.state.take() {
+                    match codec.finish() {
+                        Ok(ref out) if out.is_empty() => {},
+                        Ok(out) => { self.output.output.send(FsFileDesc::Chunk(out))?; },
+                        Err(e) => { let _ = self.output.error.send(CodecError(stream, e.to_string())); },
+                    }
+                }
+                self.output.output.send(FsFileDesc::End(name))?;
+            },
+        }
+        Ok(End)
+    }
+}
+
+// Feed the codec of the current substream, if any
+fn write(state: &mut Option<(String, Option<Codec>)>, bytes: &[u8], output: &OutputPort<FsFileDesc>, error: &OutputPort<CodecError>) -> Result<()> {
+    if let Some((ref name, ref mut codec)) = *state {
+        let out = match *codec {
+            Some(ref mut codec) => codec.write(bytes),
+            None => { return Ok(()); },
+        };
+        match out {
+            Ok(ref out) if out.is_empty() => {},
+            Ok(out) => { output.send(FsFileDesc::Chunk(out))?; },
+            Err(e) => {
+                let _ = error.send(CodecError(name.clone(), e.to_string()));
+                *codec = None;
+            },
+        }
+    }
+    Ok(())
+}
+
+pub enum Codec {
+    Compress(GzEncoder<Vec<u8>>),
+    Decompress(GzDecoder<Vec<u8>>),
+}
+
+impl Codec {
+    fn new(opt: &CodecOption) -> Self {
+        match opt.mode {
+            CodecMode::Compress => {
+                let level = opt.level.map(Compression::new).unwrap_or_default();
+                Codec::Compress(GzEncoder::new(vec![], level))
+            },
+            CodecMode::Decompress => Codec::Decompress(GzDecoder::new(vec![])),
+        }
+    }
+
+    // The bytes produced so far
+    fn write(&mut self, bytes: &[u8]) -> io::Result<Vec<u8>> {
+        match *self {
+            Codec::Compress(ref mut encoder) => {
+                encoder.write_all(bytes)?;
+                Ok(encoder.get_mut().split_off(0))
+            },
+            Codec::Decompress(ref mut decoder) => {
+                decoder.write_all(bytes)?;
+                decoder.flush()?;
+                Ok(decoder.get_mut().split_off(0))
+            },
+        }
+    }
+
+    // The last bytes
+    fn finish(self) -> io::Result<Vec<u8>> {
+        match self {
+            Codec::Compress(encoder) => encoder.finish(),
+            Codec::Decompress(decoder) => decoder.finish(),
+        }
+    }
+}
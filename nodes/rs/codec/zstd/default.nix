@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ FsFileDesc CodecOption CodecError ];
+  mods = with mods.rs; [ rustfbp capnp zstd ];
+  osdeps = with pkgs; [];
+}
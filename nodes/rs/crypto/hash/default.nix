@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ FsFileDesc CryptoDigest CryptoHashOption CryptoVerify ];
+  mods = with mods.rs; [ rustfbp capnp blake2 sha2 ];
+  osdeps = with pkgs; [];
+}
@@ -0,0 +1,119 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate blake2;
+extern crate hmac;
+extern crate sha2;
+
+use blake2::{Blake2b512, Blake2s256};
+use hmac::{Hmac, Mac, SimpleHmac};
+use sha2::Sha256;
+
+// Sign the content of each substream of `FsFileDesc` with HMAC and the key of the option, a `Text` is taken as a line
+//
+// The digest of a stream is sent on `output` at its `End`. With `verify`, it's instead checked against the digest of
+// the same name received on `expected`, in any order, and the result is sent on `verify`.
+agent! {
+    input(input: FsFileDesc, expected: CryptoDigest),
+    output(output: CryptoDigest, verify: CryptoVerify),
+    state(Streams => Streams::default()),
+    option(CryptoHmacOption),
+    fn run(&mut self) -> Result<Signal> {
+        let opt = self.recv_option();
+
+        if let Ok(expected) = self.input.expected.try_recv() {
+            self.state.expected.insert(expected.name, (expected.algorithm, expected.digest));
+        }
+
+        if let Ok(desc) = self.input.input.try_recv() {
+            match desc {
+                FsFileDesc::Start(name) => {
+                    self.state.current = Some((name, Hasher::new(opt.algorithm, &opt.key)));
+                },
+                FsFileDesc::Text(text) => {
+                    if let Some((_, ref mut hasher)) = self.state.current {
+                        hasher.update(text.as_bytes());
+                        hasher.update(b"\n");
+                    }
+                },
+                FsFileDesc::Chunk(chunk) => {
+                    if let Some((_, ref mut hasher)) = self.state.current {
+                        hasher.update(&chunk);
+                    }
+                },
+                FsFileDesc::End(_) => {
+                    if let Some((name, hasher)) = self.state.current.take() {
+                        let digest = hasher.finalize();
+                        if opt.verify {
+                            self.state.computed.insert(name, digest);
+                        } else {
+                            self.output.output.send(CryptoDigest { name: name, algorithm: opt.algorithm, digest: digest })?;
+                        }
+                    }
+                },
+            }
+        }
+
+        // The streams with both their digest and the expected one
+        let ready: Vec<String> = self.state.computed.keys()
+            .filter(|name| self.state.expected.contains_key(*name))
+            .cloned()
+            .collect();
+        for name in ready {
+            let digest = self.state.computed.remove(&name).unwrap_or_default();
+            let valid = match self.state.expected.remove(&name) {
+                Some((algorithm, expected)) => algorithm == opt.algorithm && same(&digest, &expected),
+                None => false,
+            };
+            self.output.verify.send(CryptoVerify { name: name, valid: valid })?;
+        }
+        Ok(End)
+    }
+}
+
+#[derive(Default)]
+pub struct Streams {
+    // The stream being signed
+    current: Option<(String, Hasher)>,
+    // With `verify`, the digests of the ended streams and the expected digests, until they meet
+    computed: HashMap<String, Vec<u8>>,
+    expected: HashMap<String, (CryptoAlgorithm, Vec<u8>)>,
+}
+
+pub enum Hasher {
+    Sha256(Hmac<Sha256>),
+    Blake2b(SimpleHmac<Blake2b512>),
+    Blake2s(SimpleHmac<Blake2s256>),
+}
+
+impl Hasher {
+    // HMAC takes keys of any length
+    fn new(algorithm: CryptoAlgorithm, key: &[u8]) -> Self {
+        match algorithm {
+            CryptoAlgorithm::Sha256 => Hasher::Sha256(Mac::new_from_slice(key).expect("crypto_hmac : any key length")),
+            CryptoAlgorithm::Blake2b => Hasher::Blake2b(Mac::new_from_slice(key).expect("crypto_hmac : any key length")),
+            CryptoAlgorithm::Blake2s => Hasher::Blake2s(Mac::new_from_slice(key).expect("crypto_hmac : any key length")),
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        match *self {
+            Hasher::Sha256(ref mut mac) => mac.update(bytes),
+            Hasher::Blake2b(ref mut mac) => mac.update(bytes),
+            Hasher::Blake2s(ref mut mac) => mac.update(bytes),
+        }
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        match self {
+            Hasher::Sha256(mac) => mac.finalize().into_bytes().to_vec(),
+            Hasher::Blake2b(mac) => mac.finalize().into_bytes().to_vec(),
+            Hasher::Blake2s(mac) => mac.finalize().into_bytes().to_vec(),
+        }
+    }
+}
+
+// Compare in a time which doesn't depend on where the digests differ
+fn same(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ DbPostgresOption DbPostgresQuery DbPostgresRow DbPostgresError ];
+  mods = with mods.rs; [ rustfbp capnp postgres r2d2 r2d2_postgres serde_json ];
+  osdeps = with pkgs; [];
+}
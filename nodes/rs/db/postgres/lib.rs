@@ -0,0 +1,94 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate postgres;
+extern crate r2d2;
+extern crate r2d2_postgres;
+extern crate serde_json;
+
+use postgres::{NoTls, Row};
+use postgres::fallible_iterator::FallibleIterator;
+use postgres::types::{ToSql, Type};
+use r2d2_postgres::PostgresConnectionManager;
+
+type Pool = r2d2::Pool<PostgresConnectionManager<NoTls>>;
+
+agent! {
+    input(input: DbPostgresQuery),
+    output(output: DbPostgresRow, error: DbPostgresError),
+    // The pool reconnects the broken connections
+    state(Option<Pool> => None),
+    option(DbPostgresOption),
+    fn run(&mut self) -> Result<Signal> {
+        let query = self.input.input.recv()?;
+
+        // Connect at the first query
+        if self.state.is_none() {
+            let opt = self.recv_option();
+            match connect(&opt) {
+                Ok(pool) => { self.state = Some(pool); },
+                Err(e) => {
+                    let _ = self.output.error.send(DbPostgresError(e));
+                    return Ok(End);
+                }
+            }
+        }
+
+        if let Some(ref pool) = self.state {
+            let mut started = false;
+            let mut rows = 0;
+            let res = (|| -> std::result::Result<(), String> {
+                let mut client = pool.get().map_err(|e| e.to_string())?;
+                let params: Vec<&(ToSql + Sync)> = query.params.iter().map(|p| p as &(ToSql + Sync)).collect();
+                let mut stream = client.query_raw(query.sql.as_str(), params.iter().map(|p| *p as &ToSql)).map_err(|e| e.to_string())?;
+                self.output.output.send(DbPostgresRow::Start(query.sql.clone())).map_err(|e| e.to_string())?;
+                started = true;
+                // Streamed, a big result is not kept in memory
+                while let Some(row) = stream.next().map_err(|e| e.to_string())? {
+                    self.output.output.send(DbPostgresRow::Row(columns(&row))).map_err(|e| e.to_string())?;
+                    rows += 1;
+                }
+                Ok(())
+            })();
+            // A started substream is always ended, even cut by an error
+            if started {
+                self.output.output.send(DbPostgresRow::End(rows))?;
+            }
+            if let Err(e) = res {
+                let _ = self.output.error.send(DbPostgresError(e));
+            }
+        }
+        Ok(End)
+    }
+}
+
+fn connect(opt: &DbPostgresOption) -> std::result::Result<Pool, String> {
+    let config = opt.url.parse().map_err(|e: postgres::Error| e.to_string())?;
+    let manager = PostgresConnectionManager::new(config, NoTls);
+    r2d2::Pool::builder()
+        .max_size(opt.pool_size.max(1))
+        .build(manager)
+        .map_err(|e| e.to_string())
+}
+
+// The columns of a row, by name, as text
+fn columns(row: &Row) -> Vec<(String, Option<String>)> {
+    row.columns().iter().enumerate()
+        .map(|(i, column)| (column.name().to_string(), text(row, i, column.type_())))
+        .collect()
+}
+
+fn text(row: &Row, i: usize, kind: &Type) -> Option<String> {
+    let value = match *kind {
+        Type::BOOL => row.try_get::<_, Option<bool>>(i).map(|v| v.map(|v| v.to_string())),
+        Type::INT2 => row.try_get::<_, Option<i16>>(i).map(|v| v.map(|v| v.to_string())),
+        Type::INT4 => row.try_get::<_, Option<i32>>(i).map(|v| v.map(|v| v.to_string())),
+        Type::INT8 => row.try_get::<_, Option<i64>>(i).map(|v| v.map(|v| v.to_string())),
+        Type::FLOAT4 => row.try_get::<_, Option<f32>>(i).map(|v| v.map(|v| v.to_string())),
+        Type::FLOAT8 => row.try_get::<_, Option<f64>>(i).map(|v| v.map(|v| v.to_string())),
+        Type::JSON | Type::JSONB => row.try_get::<_, Option<serde_json::Value>>(i).map(|v| v.map(|v| v.to_string())),
+        // The text types, the other types must be cast to text in the query
+        _ => row.try_get::<_, Option<String>>(i),
+    };
+    value.unwrap_or(None)
+}
@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ FsPath DbSqliteKvRequest DbSqliteKvReply ];
+  mods = with mods.rs; [ rustfbp capnp rusqlite ];
+  osdeps = with pkgs; [ sqlite ];
+}
@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate rusqlite;
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+agent! {
+    input(input: DbSqliteKvRequest),
+    output(output: DbSqliteKvReply),
+    state(Option<Connection> => None),
+    // The path of the database file, created if needed
+    option(FsPath),
+    fn run(&mut self) -> Result<Signal> {
+        let request = self.input.input.recv()?;
+
+        // Open the database at the first request
+        if self.state.is_none() {
+            let opt = self.recv_option();
+            match open(&opt.0) {
+                Ok(conn) => { self.state = Some(conn); },
+                Err(e) => {
+                    self.output.output.send(DbSqliteKvReply::Error(e.to_string()))?;
+                    return Ok(End);
+                }
+            }
+        }
+
+        if let Some(ref conn) = self.state {
+            let reply = match execute(conn, request) {
+                Ok(reply) => reply,
+                Err(e) => DbSqliteKvReply::Error(e.to_string()),
+            };
+            self.output.output.send(reply)?;
+        }
+        Ok(End)
+    }
+}
+
+fn open(path: &str) -> rusqlite::Result<Connection> {
+    let conn = Connection::open(path)?;
+    conn.execute("create table if not exists kv (key text primary key, value text not null)", [])?;
+    Ok(conn)
+}
+
+fn execute(conn: &Connection, request: DbSqliteKvRequest) -> rusqlite::Result<DbSqliteKvReply> {
+    Ok(match request {
+        DbSqliteKvRequest::Get(key) => {
+            let value = conn.query_row("select value from kv where key = ?1", params![key], |row| row.get(0)).optional()?;
+            DbSqliteKvReply::Value(key, value)
+        },
+        DbSqliteKvRequest::Set(key, value) => {
+            conn.execute("insert or replace into kv (key, value) values (?1, ?2)", params![key, value])?;
+            DbSqliteKvReply::Done(key)
+        },
+        DbSqliteKvRequest::Delete(key) => {
+            conn.execute("delete from kv where key = ?1", params![key])?;
+            DbSqliteKvReply::Done(key)
+        },
+        DbSqliteKvRequest::List(prefix) => {
+            let mut stmt = conn.prepare("select key from kv where substr(key, 1, length(?1)) = ?1 order by key")?;
+            let keys = stmt.query_map(params![prefix], |row| row.get(0))?.collect::<rusqlite::Result<Vec<String>>>()?;
+            DbSqliteKvReply::Keys(keys)
+        },
+    })
+}
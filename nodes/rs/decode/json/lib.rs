@@ -0,0 +1,37 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate serde_json;
+
+use rustfbp::json::{JsonRegistry, Value};
+
+// Build the Cap'n Proto messages of the schema named by the option from JSON text
+//
+// Invalid JSON, or JSON which doesn't fit the schema, is sent on `error` as `MaybeJson::Error`. An error received on
+// `input` is forwarded on `error`.
+agent! {
+    input(input: MaybeJson),
+    output(output: CapnpMsg, error: MaybeJson),
+    state(JsonRegistry => json_registry()),
+    option(JsonOption),
+    fn run(&mut self) -> Result<Signal> {
+        let json = self.input.input.recv()?;
+        let opt = self.recv_option();
+
+        let text = match json {
+            MaybeJson::Json(text) => text,
+            MaybeJson::Error(e) => {
+                let _ = self.output.error.send(MaybeJson::Error(e));
+                return Ok(End);
+            }
+        };
+        let msg = serde_json::from_str::<Value>(&text)
+            .map_err(result::Error::from)
+            .and_then(|value| self.state.decode(&opt.schema, &value));
+        match msg {
+            Ok(msg) => { self.output.output.send(msg)?; },
+            Err(e) => { let _ = self.output.error.send(MaybeJson::Error(format!("{} : {}", opt.schema, e))); },
+        }
+        Ok(End)
+    }
+}
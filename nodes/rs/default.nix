@@ -38,9 +38,9 @@ in
   test_nand = callPackage ./test/nand {};
   test_not = callPackage ./test/not {};
   test_edges = callPackage ./test/edges {};
+  test_fvm_parser = callPackage ./test/fvm/parser {};
   ui_js_nodes = buffet.fractals.ui_js.nodes.rs;
   app_growtest = buffet.fractals.ui_js.nodes.rs.app_growtest;
-  web_server = callPackage ./web/server {};
   workbench = buffet.fractals.workbench.nodes.rs.workbench;
   workbench_test = buffet.fractals.workbench.nodes.rs.test;
 
@@ -48,15 +48,57 @@ in
   # -   draft nodes change a lot in tandom with other nodes in their subgraph
   # -   there will be change in these nodes and few people are using these nodes so expect breakage
 
+  codec_gzip = callPackage ./codec/gzip {};
+  codec_zstd = callPackage ./codec/zstd {};
+  crypto_hash = callPackage ./crypto/hash {};
+  crypto_hmac = callPackage ./crypto/hmac {};
+  db_postgres = callPackage ./db/postgres {};
+  db_sqlite_kv = callPackage ./db/sqlite/kv {};
+  decode_json = callPackage ./decode/json {};
+  encode_json = callPackage ./encode/json {};
+  flow_circuit_breaker = callPackage ./flow/circuit/breaker {};
+  flow_clone = callPackage ./flow/clone {};
+  flow_count = callPackage ./flow/count {};
+  flow_dedup = callPackage ./flow/dedup {};
+  flow_delay = callPackage ./flow/delay {};
+  flow_drop = callPackage ./flow/drop {};
+  flow_gate = callPackage ./flow/gate {};
+  flow_join = callPackage ./flow/join {};
+  flow_loadbalance = callPackage ./flow/loadbalance {};
+  flow_retry = callPackage ./flow/retry {};
+  flow_route = callPackage ./flow/route {};
+  flow_switch = callPackage ./flow/switch {};
+  fmt_csv_read = callPackage ./fmt/csv/read {};
+  fmt_csv_write = callPackage ./fmt/csv/write {};
+  fs_dir_list = callPackage ./fs/dir/list {};
   fs_list_dir = callPackage ./fs/list/dir {};
   fs_file_open = callPackage ./fs/file/open {};
+  fs_file_write = callPackage ./fs/file/write {};
+  fs_path_from_text = callPackage ./fs/path/from_text {};
+  fs_watch = callPackage ./fs/watch {};
   halter = callPackage ./halter {};
   io_print = callPackage ./io/print {};
+  lang_python = callPackage ./lang/python {};
   msg_action = callPackage ./msg/action {};
   msg_clone = callPackage ./msg/clone {};
   msg_delay = callPackage ./msg/delay {};
   msg_dispatcher = callPackage ./msg/dispatcher {};
+  msg_mqtt_pub = callPackage ./msg/mqtt/pub {};
+  msg_mqtt_sub = callPackage ./msg/mqtt/sub {};
   msg_replace = callPackage ./msg/replace {};
+  net_zmq_pub = callPackage ./net/zmq/pub {};
+  net_zmq_pull = callPackage ./net/zmq/pull {};
+  net_zmq_push = callPackage ./net/zmq/push {};
+  net_zmq_sub = callPackage ./net/zmq/sub {};
+  proc_exec = callPackage ./proc/exec {};
+  stream_window = callPackage ./stream/window {};
+  text_regex_extract = callPackage ./text/regex/extract {};
+  text_regex_match = callPackage ./text/regex/match {};
+  text_template = callPackage ./text/template {};
+  time_ticker = callPackage ./time/ticker {};
+  web_client = callPackage ./web/client {};
+  web_server = callPackage ./web/server {};
+  web_ws = callPackage ./web/ws {};
 
   # STABLE NODES
   # -   stable nodes do not change names of ports, agents nor subgraphs,
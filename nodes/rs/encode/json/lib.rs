@@ -0,0 +1,35 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate serde_json;
+
+use rustfbp::json::JsonRegistry;
+
+// Convert the Cap'n Proto messages of the schema named by the option into JSON text
+//
+// A message which cannot be read with the schema is sent as `MaybeJson::Error`, the next messages are still converted.
+agent! {
+    input(input: CapnpMsg),
+    output(output: MaybeJson),
+    state(JsonRegistry => json_registry()),
+    option(JsonOption),
+    fn run(&mut self) -> Result<Signal> {
+        let msg = self.input.input.recv()?;
+        let opt = self.recv_option();
+
+        let json = self.state.encode(&opt.schema, &msg).and_then(|value| {
+            let text = if opt.pretty {
+                serde_json::to_string_pretty(&value)?
+            } else {
+                value.to_string()
+            };
+            Ok(text)
+        });
+        let json = match json {
+            Ok(text) => MaybeJson::Json(text),
+            Err(e) => MaybeJson::Error(format!("{} : {}", opt.schema, e)),
+        };
+        self.output.output.send(json)?;
+        Ok(End)
+    }
+}
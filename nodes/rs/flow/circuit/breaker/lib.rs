@@ -0,0 +1,104 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+// Send the messages on `output` while the service behind it works, else on `fallback`
+//
+// The outcomes of the messages come on `feedback`. The circuit is closed at first, and opens when the failures of the
+// last outcomes are over the rate of the option. Open, every message goes to `fallback`. After the cooldown, the
+// circuit is half open : a single trial message goes to `output`, and its outcome closes the circuit or opens it
+// again. The cooldown is checked when a message comes.
+agent! {
+    input(input: CapnpMsg, feedback: FlowCircuitBreakerFeedback),
+    output(output: CapnpMsg, fallback: CapnpMsg),
+    state(Circuit => Circuit::Closed(VecDeque::new())),
+    option(FlowCircuitBreakerOption),
+    fn run(&mut self) -> Result<Signal> {
+        let opt = self.try_recv_option().unwrap_or(FlowCircuitBreakerOption {
+            window: 20,
+            min_calls: 10,
+            failure_rate: 0.5,
+            cooldown: 30_000,
+        });
+
+        while let Ok(outcome) = self.input.feedback.try_recv() {
+            let before = self.state.name();
+            self.state.report(outcome, &opt);
+            if self.state.name() != before {
+                log_info!(self, "circuit {} -> {}", before, self.state.name());
+            }
+        }
+
+        while let Ok(msg) = self.input.input.try_recv() {
+            let before = self.state.name();
+            let pass = self.state.pass(&opt);
+            if self.state.name() != before {
+                log_info!(self, "circuit {} -> {}", before, self.state.name());
+            }
+            if pass {
+                self.output.output.send(msg)?;
+            } else {
+                self.output.fallback.send(msg)?;
+            }
+        }
+        Ok(End)
+    }
+}
+
+pub enum Circuit {
+    // The last outcomes, true for a failure
+    Closed(VecDeque<bool>),
+    Open(Instant),
+    // Whether the trial message is sent
+    HalfOpen(bool),
+}
+
+impl Circuit {
+    fn name(&self) -> &'static str {
+        match *self {
+            Circuit::Closed(_) => "closed",
+            Circuit::Open(_) => "open",
+            Circuit::HalfOpen(_) => "half open",
+        }
+    }
+
+    fn report(&mut self, outcome: FlowCircuitBreakerFeedback, opt: &FlowCircuitBreakerOption) {
+        let failure = outcome == FlowCircuitBreakerFeedback::Failure;
+        let open = match *self {
+            Circuit::Closed(ref mut outcomes) => {
+                outcomes.push_back(failure);
+                while outcomes.len() > opt.window {
+                    outcomes.pop_front();
+                }
+                let failures = outcomes.iter().filter(|f| **f).count();
+                outcomes.len() >= opt.min_calls && !outcomes.is_empty()
+                    && failures as f64 > opt.failure_rate * outcomes.len() as f64
+            },
+            // The outcome of a message sent before the circuit opened
+            Circuit::Open(_) | Circuit::HalfOpen(false) => { return; },
+            Circuit::HalfOpen(true) => failure,
+        };
+        if open {
+            *self = Circuit::Open(Instant::now());
+        } else if let Circuit::HalfOpen(_) = *self {
+            *self = Circuit::Closed(VecDeque::new());
+        }
+    }
+
+    // Whether a message goes to `output`
+    fn pass(&mut self, opt: &FlowCircuitBreakerOption) -> bool {
+        if let Circuit::Open(since) = *self {
+            if since.elapsed() >= Duration::from_millis(opt.cooldown) {
+                *self = Circuit::HalfOpen(false);
+            }
+        }
+        match *self {
+            Circuit::Closed(_) => true,
+            Circuit::Open(_) => false,
+            Circuit::HalfOpen(ref mut sent) => !std::mem::replace(sent, true),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+agent! {
+    input(input: CapnpMsg),
+    outarr(clone: CapnpMsg),
+    fn run(&mut self) -> Result<Signal> {
+        let msg = self.input.input.recv()?;
+        // The clones share the message until one of them changes it
+        for sender in self.outarr.clone.values() {
+            sender.send(msg.clone())?;
+        }
+        Ok(End)
+    }
+}
@@ -0,0 +1,17 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+agent! {
+    input(input: CapnpMsg),
+    output(output: CapnpMsg, count: u64),
+    state(u64 => 0),
+    fn run(&mut self) -> Result<Signal> {
+        let msg = self.input.input.recv()?;
+        self.state += 1;
+        // The messages go through, the count is sent if someone listens
+        let _ = self.output.output.send(msg);
+        let _ = self.output.count.send(self.state);
+        Ok(End)
+    }
+}
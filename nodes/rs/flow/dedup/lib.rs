@@ -0,0 +1,108 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate serde_json;
+
+use rustfbp::json::JsonRegistry;
+
+use std::cmp;
+use std::collections::BTreeMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+// Forward the messages on `output`, except the ones whose key was already seen
+//
+// The key of a message is a field, or the hash of its content without its headers. The agent remembers at most
+// `capacity` keys, each for `ttl` from the first time it was seen : a key seen again is kept longer than the others
+// when the capacity is reached, but not past its ttl. The duplicates are sent on `duplicate` if it's connected, else
+// dropped.
+agent! {
+    input(input: CapnpMsg),
+    output(output: CapnpMsg, duplicate: CapnpMsg),
+    state(Seen => Seen::new()),
+    option(FlowDedupOption),
+    fn run(&mut self) -> Result<Signal> {
+        let msg = self.input.input.recv()?;
+        let opt = self.recv_option();
+
+        let key = if opt.field.is_empty() {
+            let mut hasher = DefaultHasher::new();
+            msg.to_bytes()?.hash(&mut hasher);
+            format!("{:016x}", hasher.finish())
+        } else {
+            let fields = self.state.json.encode_msg(&opt.schema, &msg)?;
+            match fields.get(&opt.field) {
+                Some(value) => value.to_string(),
+                None => {
+                    return Err(result::Error::Misc(format!("flow_dedup : the message has no field {}", opt.field)));
+                },
+            }
+        };
+
+        let ttl = if opt.ttl > 0 { Some(Duration::from_millis(opt.ttl)) } else { None };
+        if self.state.seen(key, ttl, cmp::max(opt.capacity, 1)) {
+            if self.output.duplicate.is_some() {
+                self.output.duplicate.send(msg)?;
+            }
+        } else {
+            self.output.output.send(msg)?;
+        }
+        Ok(End)
+    }
+}
+
+pub struct Seen {
+    json: JsonRegistry,
+    // By key : when it was first seen, and its last use
+    keys: HashMap<String, (Instant, u64)>,
+    // The keys by last use, the least recent first
+    uses: BTreeMap<u64, String>,
+    next: u64,
+}
+
+impl Seen {
+    fn new() -> Self {
+        Seen {
+            json: json_registry(),
+            keys: HashMap::new(),
+            uses: BTreeMap::new(),
+            next: 0,
+        }
+    }
+
+    // Whether `key` was seen, and remember it
+    fn seen(&mut self, key: String, ttl: Option<Duration>, capacity: usize) -> bool {
+        let used = self.next;
+        self.next += 1;
+        let seen = match self.keys.get_mut(&key) {
+            Some(&mut (first, ref mut last)) if ttl.map_or(true, |ttl| first.elapsed() < ttl) => {
+                self.uses.remove(last);
+                *last = used;
+                true
+            },
+            Some(&mut (ref mut first, ref mut last)) => {
+                // Expired, seen as new
+                self.uses.remove(last);
+                *first = Instant::now();
+                *last = used;
+                false
+            },
+            None => {
+                self.keys.insert(key.clone(), (Instant::now(), used));
+                false
+            },
+        };
+        self.uses.insert(used, key);
+        while self.keys.len() > capacity {
+            let oldest = match self.uses.keys().next() {
+                Some(&oldest) => oldest,
+                None => break,
+            };
+            if let Some(key) = self.uses.remove(&oldest) {
+                self.keys.remove(&key);
+            }
+        }
+        seen
+    }
+}
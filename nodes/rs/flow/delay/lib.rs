@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use std::thread;
+use std::time::Duration;
+
+#[agent_struct(blocking)]
+pub struct FlowDelay {
+    #[input]
+    input: CapnpMsg,
+    #[output]
+    output: CapnpMsg,
+    #[option]
+    option: FlowDelayOption,
+}
+
+impl FlowDelay {
+    fn run(&mut self) -> Result<Signal> {
+        let msg = self.input.input.recv()?;
+        let delay = self.try_recv_option().map(|opt| opt.0).unwrap_or(1000);
+        thread::sleep(Duration::from_millis(delay));
+        self.output.output.send(msg)?;
+        Ok(End)
+    }
+}
@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+agent! {
+    input(input: CapnpMsg),
+    fn run(&mut self) -> Result<Signal> {
+        let _ = self.input.input.recv()?;
+        Ok(End)
+    }
+}
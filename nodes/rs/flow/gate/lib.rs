@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use std::collections::VecDeque;
+
+// The messages held while the gate is closed, the oldest ones are dropped beyond it
+const MAX_HELD: usize = 1024;
+
+agent! {
+    input(input: CapnpMsg, control: bool),
+    output(output: CapnpMsg),
+    // Is the gate open, and the messages waiting for it to open
+    state((bool, VecDeque<CapnpMsg>) => (true, VecDeque::new())),
+    fn run(&mut self) -> Result<Signal> {
+        // true opens the gate, false closes it
+        while let Ok(open) = self.input.control.try_recv() {
+            self.state.0 = open;
+        }
+        let mut dropped = 0;
+        while let Ok(msg) = self.input.input.try_recv() {
+            if self.state.1.len() == MAX_HELD {
+                self.state.1.pop_front();
+                dropped += 1;
+            }
+            self.state.1.push_back(msg);
+        }
+        if dropped > 0 {
+            log_warn!(self, port = "input", "the gate is closed and holds {} messages, {} dropped", MAX_HELD, dropped);
+        }
+
+        if self.state.0 {
+            while let Some(msg) = self.state.1.pop_front() {
+                self.output.output.send(msg)?;
+            }
+        }
+        Ok(End)
+    }
+}
@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ FlowJoinOption FlowJoined ];
+  mods = with mods.rs; [ rustfbp capnp ];
+  osdeps = with pkgs; [];
+}
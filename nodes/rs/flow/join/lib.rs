@@ -0,0 +1,145 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+// The incomplete groups kept without an option
+const MAX_PENDING: usize = 1024;
+
+// Pair the messages of the elements of `input` by their key, and send them together once every element has one
+//
+// The key of a message is chosen by the option : its rank on its element (the default, a zip), its action or one of
+// its headers. A message without key is dropped, and a second message of an element for a key replaces the first.
+// A group still incomplete after the timeout, or the oldest one when there are more than `max_pending`, is sent on
+// `expired` with the messages it has. The timeouts are checked when a message comes. Without an option, the messages
+// are paired by rank, and at most `MAX_PENDING` groups are kept.
+agent! {
+    inarr(input: CapnpMsg),
+    output(output: FlowJoined, expired: FlowJoined),
+    state(Joiner => Joiner::default()),
+    option(FlowJoinOption),
+    fn run(&mut self) -> Result<Signal> {
+        let opt = self.try_recv_option().unwrap_or(FlowJoinOption {
+            key: FlowJoinKey::Order,
+            timeout: 0,
+            max_pending: MAX_PENDING,
+        });
+        let elements = self.inarr.input.len();
+
+        for (element, recv) in self.inarr.input.iter() {
+            while let Ok((action, msg)) = recv.try_recv_with_action() {
+                let key = match opt.key {
+                    FlowJoinKey::Order => Some(self.state.rank(element)),
+                    FlowJoinKey::Action => action,
+                    FlowJoinKey::Header(ref name) => msg.header(name).map(|v| String::from_utf8_lossy(v).into_owned()),
+                };
+                let key = match key {
+                    Some(key) => key,
+                    None => {
+                        log_warn!(self, port = "input", "message without key on {}, dropped", element);
+                        continue;
+                    },
+                };
+                if let Some(messages) = self.state.add(key.clone(), element, msg, elements) {
+                    self.output.output.send(joined(key, messages)?)?;
+                }
+            }
+        }
+
+        let timeout = if opt.timeout > 0 { Some(Duration::from_millis(opt.timeout)) } else { None };
+        for (key, messages) in self.state.expire(timeout, opt.max_pending) {
+            let _ = self.output.expired.send(joined(key, messages)?);
+        }
+        Ok(End)
+    }
+}
+
+#[derive(Default)]
+pub struct Joiner {
+    // The incomplete groups, by key
+    groups: HashMap<String, Group>,
+    // The keys of the groups with their number, the oldest first. A group already sent leaves its key, skipped when
+    // its number is not the one of the group of the key
+    order: VecDeque<(u64, String)>,
+    next: u64,
+    // The number of messages received by each element, for the key `Order`
+    ranks: HashMap<String, u64>,
+}
+
+struct Group {
+    number: u64,
+    since: Instant,
+    messages: HashMap<String, CapnpMsg>,
+}
+
+impl Joiner {
+    fn rank(&mut self, element: &str) -> String {
+        let rank = self.ranks.entry(element.to_string()).or_insert(0);
+        *rank += 1;
+        (*rank - 1).to_string()
+    }
+
+    // Add a message to the group of `key`, which is taken once it has a message for each of the `elements`
+    fn add(&mut self, key: String, element: &str, msg: CapnpMsg, elements: usize) -> Option<HashMap<String, CapnpMsg>> {
+        let complete = {
+            let (next, order) = (&mut self.next, &mut self.order);
+            let group = self.groups.entry(key.clone()).or_insert_with(|| {
+                *next += 1;
+                order.push_back((*next, key.clone()));
+                Group {
+                    number: *next,
+                    since: Instant::now(),
+                    messages: HashMap::new(),
+                }
+            });
+            group.messages.insert(element.to_string(), msg);
+            group.messages.len() >= elements
+        };
+        if !complete {
+            return None;
+        }
+        let group = self.groups.remove(&key);
+        // The keys of the groups already sent, when they are most of the queue
+        if self.order.len() > 2 * self.groups.len() + 64 {
+            let groups = &self.groups;
+            self.order.retain(|&(number, ref key)| groups.get(key).map_or(false, |g| g.number == number));
+        }
+        group.map(|group| group.messages)
+    }
+
+    // Take the groups older than `timeout`, and the oldest ones above `max_pending`
+    fn expire(&mut self, timeout: Option<Duration>, max_pending: usize) -> Vec<(String, HashMap<String, CapnpMsg>)> {
+        let mut expired = vec![];
+        while let Some((number, key)) = self.order.pop_front() {
+            let (late, over) = match self.groups.get(&key) {
+                Some(group) if group.number == number => (
+                    timeout.map_or(false, |timeout| group.since.elapsed() >= timeout),
+                    max_pending > 0 && self.groups.len() > max_pending,
+                ),
+                // Already sent
+                _ => { continue; },
+            };
+            if !late && !over {
+                self.order.push_front((number, key));
+                break;
+            }
+            if let Some(group) = self.groups.remove(&key) {
+                expired.push((key, group.messages));
+            }
+        }
+        expired
+    }
+}
+
+fn joined(key: String, messages: HashMap<String, CapnpMsg>) -> Result<FlowJoined> {
+    let mut messages = messages.into_iter()
+        .map(|(element, msg)| Ok((element, msg.to_envelope()?)))
+        .collect::<Result<Vec<_>>>()?;
+    messages.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(FlowJoined {
+        key: key,
+        messages: messages,
+    })
+}
@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+agent! {
+    input(input: CapnpMsg),
+    outarr(output: CapnpMsg),
+    // The next element for the round robin
+    state(usize => 0),
+    option(FlowLoadbalanceOption),
+    fn run(&mut self) -> Result<Signal> {
+        let (action, msg) = self.input.input.recv_with_action()?;
+        let opt = self.try_recv_option().unwrap_or(FlowLoadbalanceOption::RoundRobin);
+
+        // Sorted, so an action is always sent to the same element
+        let mut elements: Vec<&String> = self.outarr.output.keys().collect();
+        if elements.is_empty() {
+            return Err(result::Error::OutputNotConnected);
+        }
+        elements.sort();
+
+        let element = match (opt, action.as_ref()) {
+            (FlowLoadbalanceOption::LeastLoaded, _) => {
+                let outputs = &self.outarr.output;
+                elements.iter()
+                    .min_by_key(|e| outputs[**e].queue_len())
+                    .cloned()
+                    .expect("elements is not empty")
+            },
+            (FlowLoadbalanceOption::HashAction, Some(action)) => {
+                let mut hasher = DefaultHasher::new();
+                action.hash(&mut hasher);
+                elements[hasher.finish() as usize % elements.len()]
+            },
+            // Round robin, also for the messages without action
+            _ => {
+                self.state = (self.state + 1) % elements.len();
+                elements[self.state]
+            },
+        };
+
+        let sender = &self.outarr.output[element];
+        match action {
+            Some(action) => { sender.send_with_action(msg, action)?; },
+            None => { sender.send(msg)?; },
+        }
+        Ok(End)
+    }
+}
@@ -0,0 +1,77 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use std::cmp;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// The header which names a message for its reply
+const RETRY_ID: &str = "retry-id";
+// The header of the last error of a message sent on `error`
+const RETRY_ERROR: &str = "retry-error";
+
+// Send a message on `output` until its reply on `reply` is an `Ack`, waiting longer after each failure
+//
+// Each message gets a header `retry-id`, the agent behind `output` must keep it (see `CapnpMsg::forward`) and answer
+// with its id. An `Err`, or no reply before the timeout, is a failure : the message is sent again after the backoff,
+// which doubles up to `max_backoff`. After `attempts` failures, the message is sent on `error`, with the last error in
+// its header `retry-error`. The messages are retried one at a time, in order.
+agent! {
+    input(input: CapnpMsg, reply: FlowRetryReply),
+    output(output: CapnpMsg, error: CapnpMsg),
+    // The id of the next message
+    state(u64 => 0),
+    option(FlowRetryOption),
+    blocking(true),
+    fn run(&mut self) -> Result<Signal> {
+        let mut msg = self.input.input.recv()?;
+        let opt = self.try_recv_option().unwrap_or(FlowRetryOption {
+            attempts: 5,
+            backoff: 100,
+            max_backoff: 10_000,
+            timeout: 5_000,
+        });
+
+        let id = self.state.to_string();
+        self.state += 1;
+        msg.set_header(RETRY_ID, id.clone());
+
+        let mut backoff = opt.backoff;
+        let mut error = String::new();
+        for attempt in 0..cmp::max(opt.attempts, 1) {
+            if attempt > 0 {
+                thread::sleep(Duration::from_millis(backoff));
+                backoff = cmp::min(backoff.saturating_mul(2), opt.max_backoff);
+            }
+            self.output.output.send(msg.clone())?;
+            match wait_reply(&self.input.reply, &id, Duration::from_millis(opt.timeout))? {
+                Ok(()) => { return Ok(End); },
+                Err(e) => {
+                    log_warn!(self, "attempt {} of {} failed : {}", attempt + 1, id, e);
+                    error = e;
+                },
+            }
+        }
+        msg.set_header(RETRY_ERROR, error);
+        self.output.error.send(msg)?;
+        Ok(End)
+    }
+}
+
+// The reply for `id`, the replies of the other messages are late and ignored
+fn wait_reply(reply: &MsgReceiver<FlowRetryReply>, id: &str, timeout: Duration) -> Result<std::result::Result<(), String>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let left = deadline.saturating_duration_since(Instant::now());
+        match reply.recv_timeout(left) {
+            Ok(FlowRetryReply::Ack(ref r)) if r == id => { return Ok(Ok(())); },
+            Ok(FlowRetryReply::Err(ref r, ref e)) if r == id => { return Ok(Err(e.clone())); },
+            Ok(_) => {},
+            Err(result::Error::RecvTimeout) => {
+                return Ok(Err(format!("no reply after {} ms", timeout.as_millis())));
+            },
+            Err(e) => { return Err(e); },
+        }
+    }
+}
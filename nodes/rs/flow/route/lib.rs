@@ -0,0 +1,372 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate serde_json;
+
+use rustfbp::json::{JsonRegistry, Value};
+use rustfbp::schema::SchemaRegistry;
+
+use std::cmp::Ordering;
+
+// Route the messages by their content, to the element of the first rule whose predicate is true
+//
+// The predicates compare the fields of the message, read with the schema of the option (see `FlowRouteOption`) :
+//
+//   size > 1024 && kind == "image" => big
+//   !urgent || (retries >= 3 && owner != "ops") => slow
+//   default => small
+//
+// A field alone is true if it's true, a number which isn't 0, or a text, a data or a list which isn't empty, and a
+// comparison of a field with a literal of another type is false, except for `!=`. A message matched by no rule, or
+// routed to an element which isn't connected, is sent on `default`.
+agent! {
+    input(input: CapnpMsg),
+    output(default: CapnpMsg),
+    outarr(output: CapnpMsg),
+    state(Router => Router::new()),
+    option(FlowRouteOption),
+    fn run(&mut self) -> Result<Signal> {
+        let (action, msg) = self.input.input.recv_with_action()?;
+        let opt = self.recv_option();
+        if opt.rules != self.state.rules || opt.schema != self.state.schema {
+            self.state.compile(&opt)?;
+        }
+
+        let fields = self.state.json.encode_msg(&opt.schema, &msg)?;
+        let sender = self.state.compiled.iter()
+            .find(|rule| rule.predicate.as_ref().map_or(true, |p| p.eval(&fields)))
+            .and_then(|rule| self.outarr.output.get(&rule.element));
+        match (sender, action) {
+            (Some(sender), Some(action)) => { sender.send_with_action(msg, action)?; },
+            (Some(sender), None) => { sender.send(msg)?; },
+            _ => { self.output.default.send(msg)?; },
+        }
+        Ok(End)
+    }
+}
+
+pub struct Router {
+    json: JsonRegistry,
+    schemas: SchemaRegistry,
+    // The option the rules were compiled from
+    schema: String,
+    rules: String,
+    compiled: Vec<Rule>,
+}
+
+impl Router {
+    fn new() -> Self {
+        Router {
+            json: json_registry(),
+            schemas: schema_registry(),
+            schema: String::new(),
+            rules: String::new(),
+            compiled: vec![],
+        }
+    }
+
+    fn compile(&mut self, opt: &FlowRouteOption) -> Result<()> {
+        let compiled = parse_rules(&opt.rules)
+            .map_err(|e| result::Error::Misc(format!("flow_route : {}", e)))?;
+        // The fields are checked now if the schema is known, else on each message
+        if !opt.schema.is_empty() {
+            for rule in &compiled {
+                if let Some(ref predicate) = rule.predicate {
+                    let mut fields = vec![];
+                    predicate.fields(&mut fields);
+                    for field in fields {
+                        let name = field.split('.').next().unwrap_or(field);
+                        self.schemas.kind(&opt.schema, name)?;
+                    }
+                }
+            }
+        }
+        self.schema = opt.schema.clone();
+        self.rules = opt.rules.clone();
+        self.compiled = compiled;
+        Ok(())
+    }
+}
+
+struct Rule {
+    // None for `default`
+    predicate: Option<Expr>,
+    element: String,
+}
+
+enum Expr {
+    Or(Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Cmp(String, Op, Value),
+    Field(String),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Expr {
+    fn eval(&self, msg: &Value) -> bool {
+        match *self {
+            Expr::Or(ref a, ref b) => a.eval(msg) || b.eval(msg),
+            Expr::And(ref a, ref b) => a.eval(msg) && b.eval(msg),
+            Expr::Not(ref a) => !a.eval(msg),
+            Expr::Cmp(ref field, op, ref literal) => {
+                match lookup(msg, field) {
+                    Some(value) => compare(value, op, literal),
+                    None => false,
+                }
+            },
+            Expr::Field(ref field) => lookup(msg, field).map_or(false, truthy),
+        }
+    }
+
+    fn fields<'a>(&'a self, fields: &mut Vec<&'a str>) {
+        match *self {
+            Expr::Or(ref a, ref b) | Expr::And(ref a, ref b) => {
+                a.fields(fields);
+                b.fields(fields);
+            },
+            Expr::Not(ref a) => a.fields(fields),
+            Expr::Cmp(ref field, _, _) | Expr::Field(ref field) => fields.push(field),
+        }
+    }
+}
+
+// The member `a.b` of a JSON object, for the fields of the nested structures
+fn lookup<'a>(msg: &'a Value, field: &str) -> Option<&'a Value> {
+    field.split('.').try_fold(msg, |value, name| value.get(name))
+}
+
+fn truthy(value: &Value) -> bool {
+    match *value {
+        Value::Null => false,
+        Value::Bool(b) => b,
+        Value::Number(ref n) => n.as_f64().map_or(false, |n| n != 0.0),
+        Value::String(ref s) => !s.is_empty(),
+        Value::Array(ref a) => !a.is_empty(),
+        Value::Object(ref o) => !o.is_empty(),
+    }
+}
+
+fn compare(value: &Value, op: Op, literal: &Value) -> bool {
+    let ord = match (value, literal) {
+        (&Value::Number(ref a), &Value::Number(ref b)) => {
+            a.as_f64().and_then(|a| b.as_f64().and_then(|b| a.partial_cmp(&b)))
+        },
+        (&Value::String(ref a), &Value::String(ref b)) => Some(a.cmp(b)),
+        (&Value::Bool(a), &Value::Bool(b)) => Some(a.cmp(&b)),
+        _ => None,
+    };
+    match ord {
+        None => op == Op::Ne,
+        Some(ord) => match op {
+            Op::Eq => ord == Ordering::Equal,
+            Op::Ne => ord != Ordering::Equal,
+            Op::Lt => ord == Ordering::Less,
+            Op::Le => ord != Ordering::Greater,
+            Op::Gt => ord == Ordering::Greater,
+            Op::Ge => ord != Ordering::Less,
+        },
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Name(String),
+    Literal(Value),
+    Op(Op),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+    Arrow,
+    // `;` or a new line
+    End,
+}
+
+impl std::fmt::Debug for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let op = match *self {
+            Op::Eq => "==",
+            Op::Ne => "!=",
+            Op::Lt => "<",
+            Op::Le => "<=",
+            Op::Gt => ">",
+            Op::Ge => ">=",
+        };
+        write!(f, "{}", op)
+    }
+}
+
+fn tokenize(text: &str) -> std::result::Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+    let mut chars = text.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        chars.next();
+        let next = chars.peek().cloned();
+        let token = match (c, next) {
+            (' ', _) | ('\t', _) | ('\r', _) => continue,
+            (';', _) | ('\n', _) => Token::End,
+            ('(', _) => Token::Open,
+            (')', _) => Token::Close,
+            ('=', Some('>')) => { chars.next(); Token::Arrow },
+            ('=', Some('=')) => { chars.next(); Token::Op(Op::Eq) },
+            ('!', Some('=')) => { chars.next(); Token::Op(Op::Ne) },
+            ('<', Some('=')) => { chars.next(); Token::Op(Op::Le) },
+            ('>', Some('=')) => { chars.next(); Token::Op(Op::Ge) },
+            ('&', Some('&')) => { chars.next(); Token::And },
+            ('|', Some('|')) => { chars.next(); Token::Or },
+            ('<', _) => Token::Op(Op::Lt),
+            ('>', _) => Token::Op(Op::Gt),
+            ('!', _) => Token::Not,
+            ('"', _) => {
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('n') => s.push('\n'),
+                            Some('t') => s.push('\t'),
+                            Some(c) => s.push(c),
+                            None => return Err("unterminated string".into()),
+                        },
+                        Some(c) => s.push(c),
+                        None => return Err("unterminated string".into()),
+                    }
+                }
+                Token::Literal(Value::String(s))
+            },
+            (c, _) if c.is_ascii_digit() || c == '-' => {
+                let mut s = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || c == '.') { break; }
+                    s.push(c);
+                    chars.next();
+                }
+                let value: Value = serde_json::from_str(&s).map_err(|_| format!("bad number {}", s))?;
+                if !value.is_number() { return Err(format!("bad number {}", s)); }
+                Token::Literal(value)
+            },
+            (c, _) if c.is_alphabetic() || c == '_' => {
+                let mut s = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_' || c == '.') { break; }
+                    s.push(c);
+                    chars.next();
+                }
+                match &s[..] {
+                    "true" => Token::Literal(Value::Bool(true)),
+                    "false" => Token::Literal(Value::Bool(false)),
+                    _ => Token::Name(s),
+                }
+            },
+            (c, _) => return Err(format!("unexpected {}", c)),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn parse_rules(text: &str) -> std::result::Result<Vec<Rule>, String> {
+    let tokens = tokenize(text)?;
+    let mut rules = vec![];
+    for rule in tokens.split(|t| *t == Token::End).filter(|rule| !rule.is_empty()) {
+        let arrow = rule.iter().position(|t| *t == Token::Arrow)
+            .ok_or_else(|| format!("no => in the rule {:?}", rule))?;
+        let element = match &rule[arrow + 1..] {
+            [Token::Name(element)] => element.clone(),
+            _ => return Err(format!("expected an element after => in the rule {:?}", rule)),
+        };
+        let predicate = match &rule[..arrow] {
+            [Token::Name(default)] if default == "default" => None,
+            predicate => {
+                let mut parser = Parser { tokens: predicate, pos: 0 };
+                let expr = parser.or()?;
+                if parser.pos != predicate.len() {
+                    return Err(format!("unexpected {:?} in the rule {:?}", predicate[parser.pos], rule));
+                }
+                Some(expr)
+            },
+        };
+        rules.push(Rule {
+            predicate: predicate,
+            element: element,
+        });
+    }
+    Ok(rules)
+}
+
+// or := and ('||' and)*, and := not ('&&' not)*, not := '!' not | '(' or ')' | name (op literal)?
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, token: Token) -> bool {
+        if self.tokens.get(self.pos) == Some(&token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn or(&mut self) -> std::result::Result<Expr, String> {
+        let mut expr = self.and()?;
+        while self.eat(Token::Or) {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> std::result::Result<Expr, String> {
+        let mut expr = self.not()?;
+        while self.eat(Token::And) {
+            expr = Expr::And(Box::new(expr), Box::new(self.not()?));
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> std::result::Result<Expr, String> {
+        match self.next() {
+            Some(Token::Not) => Ok(Expr::Not(Box::new(self.not()?))),
+            Some(Token::Open) => {
+                let expr = self.or()?;
+                if !self.eat(Token::Close) {
+                    return Err("expected )".into());
+                }
+                Ok(expr)
+            },
+            Some(Token::Name(field)) => {
+                match self.tokens.get(self.pos) {
+                    Some(&Token::Op(op)) => {
+                        self.pos += 1;
+                        match self.next() {
+                            Some(Token::Literal(literal)) => Ok(Expr::Cmp(field.clone(), op, literal.clone())),
+                            _ => Err(format!("expected a literal after {} {:?}", field, op)),
+                        }
+                    },
+                    _ => Ok(Expr::Field(field.clone())),
+                }
+            },
+            Some(token) => Err(format!("unexpected {:?}", token)),
+            None => Err("expected a predicate".into()),
+        }
+    }
+}
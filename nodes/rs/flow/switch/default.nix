@@ -0,0 +1,10 @@
+{ lib, agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ FlowSwitchOption ];
+  # the registry holds the flat schemas of these edges
+  capnp_edges = (lib.collect lib.isDerivation edges.capnp);
+  mods = with mods.rs; [ rustfbp capnp ];
+  osdeps = with pkgs; [];
+}
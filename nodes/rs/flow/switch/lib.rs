@@ -0,0 +1,36 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use rustfbp::json::{JsonRegistry, Value};
+
+// Route the messages by the value of one of their fields, read with the schema of the option (see
+// `FlowSwitchOption`). A message without the field, whose value has no route, or routed to an element which isn't
+// connected, is sent on `default`.
+agent! {
+    input(input: CapnpMsg),
+    output(default: CapnpMsg),
+    outarr(output: CapnpMsg),
+    state(JsonRegistry => json_registry()),
+    option(FlowSwitchOption),
+    fn run(&mut self) -> Result<Signal> {
+        let (action, msg) = self.input.input.recv_with_action()?;
+        let opt = self.recv_option();
+
+        let fields = self.state.encode_msg(&opt.schema, &msg)?;
+        let value = opt.field.split('.').try_fold(&fields, |value, name| value.get(name)).map(|value| match *value {
+            Value::String(ref s) => s.clone(),
+            ref value => value.to_string(),
+        });
+        let element = value.and_then(|value| {
+            opt.routes.iter().find(|&&(ref v, _)| *v == value).map(|&(_, ref element)| element.clone())
+        });
+        let sender = element.and_then(|element| self.outarr.output.get(&element));
+        match (sender, action) {
+            (Some(sender), Some(action)) => { sender.send_with_action(msg, action)?; },
+            (Some(sender), None) => { sender.send(msg)?; },
+            _ => { self.output.default.send(msg)?; },
+        }
+        Ok(End)
+    }
+}
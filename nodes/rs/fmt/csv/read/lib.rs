@@ -0,0 +1,138 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate csv;
+
+use std::fs::File;
+
+// Read CSV text into records
+//
+// A path on `path` reads the file, streamed. A substream of `FsFileDesc` on `input` (from `fs_file_open`, in lines or
+// in chunks) is read at its end. Each CSV text is sent as a substream of `FmtCsvRecord`, named like the file.
+//
+// A row which doesn't fit the columns of the option is sent on `error` and skipped.
+agent! {
+    input(path: FsPath, input: FsFileDesc),
+    output(output: FmtCsvRecord, error: FmtCsvError),
+    // The name and the text of the substream of `input` being read
+    state(Option<(String, Vec<u8>)> => None),
+    option(FmtCsvOption),
+    fn run(&mut self) -> Result<Signal> {
+        let opt = self.try_recv_option().unwrap_or(FmtCsvOption { delimiter: b',', header: true, columns: vec![] });
+
+        if let Ok(FsPath(path)) = self.input.path.try_recv() {
+            match File::open(&path) {
+                Ok(file) => { read(&path, file, &opt, &self.output.output, &self.output.error)?; },
+                Err(e) => { let _ = self.output.error.send(FmtCsvError(format!("{} : {}", path, e))); },
+            }
+        }
+
+        if let Ok(desc) = self.input.input.try_recv() {
+            match desc {
+                FsFileDesc::Start(name) => { self.state = Some((name, vec![])); },
+                FsFileDesc::Text(line) => {
+                    if let Some((_, ref mut text)) = self.state {
+                        text.extend_from_slice(line.as_bytes());
+                        text.push(b'\n');
+                    }
+                },
+                FsFileDesc::Chunk(chunk) => {
+                    if let Some((_, ref mut text)) = self.state {
+                        text.extend_from_slice(&chunk);
+                    }
+                },
+                FsFileDesc::End(_) => {
+                    if let Some((name, text)) = self.state.take() {
+                        read(&name, &text[..], &opt, &self.output.output, &self.output.error)?;
+                    }
+                },
+            }
+        }
+        Ok(End)
+    }
+}
+
+// Send the substream of the records of `text`
+fn read<R: Read>(name: &str, text: R, opt: &FmtCsvOption, output: &OutputPort<FmtCsvRecord>, error: &OutputPort<FmtCsvError>) -> Result<()> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(opt.delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(text);
+    let mut records = reader.records();
+
+    output.send(FmtCsvRecord::Start(name.to_string()))?;
+    let res = (|| -> std::result::Result<(), String> {
+        let header = if opt.header {
+            match records.next() {
+                Some(row) => Some(row.map_err(|e| e.to_string())?),
+                None => None,
+            }
+        } else {
+            None
+        };
+        let columns = columns(opt, header.as_ref())?;
+
+        for (line, row) in records.enumerate() {
+            let row = row.map_err(|e| e.to_string())?;
+            match fields(&columns, &row) {
+                Ok(fields) => { output.send(FmtCsvRecord::Row(fields)).map_err(|e| e.to_string())?; },
+                Err(e) => {
+                    let line = row.position().map(|p| p.line()).unwrap_or(line as u64 + 1);
+                    let _ = error.send(FmtCsvError(format!("{} line {} : {}", name, line, e)));
+                },
+            }
+        }
+        Ok(())
+    })();
+    // A started substream is always ended, even cut by an error
+    output.send(FmtCsvRecord::End(name.to_string()))?;
+    if let Err(e) = res {
+        let _ = error.send(FmtCsvError(format!("{} : {}", name, e)));
+    }
+    Ok(())
+}
+
+// The name, the kind and the position in the row of each column of the records
+fn columns(opt: &FmtCsvOption, header: Option<&csv::StringRecord>) -> std::result::Result<Vec<(String, FmtCsvKind, usize)>, String> {
+    match header {
+        Some(header) if opt.columns.is_empty() => {
+            Ok(header.iter().enumerate().map(|(i, name)| (name.to_string(), FmtCsvKind::Text, i)).collect())
+        },
+        Some(header) => {
+            opt.columns.iter().map(|column| {
+                header.iter().position(|name| name == column.name)
+                    .map(|i| (column.name.clone(), column.kind, i))
+                    .ok_or_else(|| format!("no column {} in the header", column.name))
+            }).collect()
+        },
+        // Without header, the columns are the ones of the option, or all the columns named by their position
+        None => {
+            Ok(opt.columns.iter().enumerate().map(|(i, column)| (column.name.clone(), column.kind, i)).collect())
+        },
+    }
+}
+
+fn fields(columns: &[(String, FmtCsvKind, usize)], row: &csv::StringRecord) -> std::result::Result<Vec<(String, FmtCsvValue)>, String> {
+    if columns.is_empty() {
+        return Ok(row.iter().enumerate().map(|(i, field)| (i.to_string(), value(FmtCsvKind::Text, field).unwrap_or(FmtCsvValue::Null))).collect());
+    }
+    columns.iter().map(|&(ref name, kind, i)| {
+        let field = row.get(i).unwrap_or("");
+        value(kind, field)
+            .map(|value| (name.clone(), value))
+            .map_err(|e| format!("column {} : {}", name, e))
+    }).collect()
+}
+
+fn value(kind: FmtCsvKind, field: &str) -> std::result::Result<FmtCsvValue, String> {
+    if field.is_empty() {
+        return Ok(FmtCsvValue::Null);
+    }
+    match kind {
+        FmtCsvKind::Text => Ok(FmtCsvValue::Text(field.to_string())),
+        FmtCsvKind::Int => field.trim().parse().map(FmtCsvValue::Int).map_err(|e| format!("{} : {}", field, e)),
+        FmtCsvKind::Float => field.trim().parse().map(FmtCsvValue::Float).map_err(|e| format!("{} : {}", field, e)),
+        FmtCsvKind::Bool => field.trim().parse().map(FmtCsvValue::Bool).map_err(|e| format!("{} : {}", field, e)),
+    }
+}
@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ FsFileDesc FmtCsvOption FmtCsvRecord FmtCsvError ];
+  mods = with mods.rs; [ rustfbp capnp csv ];
+  osdeps = with pkgs; [];
+}
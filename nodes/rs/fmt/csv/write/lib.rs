@@ -0,0 +1,74 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate csv;
+
+// Write records as CSV text
+//
+// Each substream of `FmtCsvRecord` is sent as a substream of `FsFileDesc`, one `Text` per row, ready for
+// `fs_file_write`. With `header`, the names of the columns of the first row are written first. The columns of the
+// option, if any, pick and order the fields of each row; a missing field is empty.
+agent! {
+    input(input: FmtCsvRecord),
+    output(output: FsFileDesc, error: FmtCsvError),
+    // True while the header of the current substream is still to write
+    state(bool => false),
+    option(FmtCsvOption),
+    fn run(&mut self) -> Result<Signal> {
+        let opt = self.try_recv_option().unwrap_or(FmtCsvOption { delimiter: b',', header: true, columns: vec![] });
+
+        match self.input.input.recv()? {
+            FmtCsvRecord::Start(name) => {
+                self.state = opt.header;
+                self.output.output.send(FsFileDesc::Start(name))?;
+            },
+            FmtCsvRecord::Row(fields) => {
+                let names: Vec<String> = if opt.columns.is_empty() {
+                    fields.iter().map(|&(ref name, _)| name.clone()).collect()
+                } else {
+                    opt.columns.iter().map(|column| column.name.clone()).collect()
+                };
+                if self.state {
+                    self.state = false;
+                    self.output.output.send(FsFileDesc::Text(line(opt.delimiter, names.iter().map(|name| name.as_str()))?))?;
+                }
+                let texts: Vec<String> = names.iter().map(|name| {
+                    fields.iter()
+                        .find(|&&(ref field, _)| field == name)
+                        .map(|&(_, ref value)| text(value))
+                        .unwrap_or_default()
+                }).collect();
+                match line(opt.delimiter, texts.iter().map(|text| text.as_str())) {
+                    Ok(line) => { self.output.output.send(FsFileDesc::Text(line))?; },
+                    Err(e) => { let _ = self.output.error.send(FmtCsvError(e.to_string())); },
+                }
+            },
+            FmtCsvRecord::End(name) => {
+                self.output.output.send(FsFileDesc::End(name))?;
+            },
+        }
+        Ok(End)
+    }
+}
+
+// One row of CSV, quoted if needed, without the line terminator
+fn line<'a, I: Iterator<Item = &'a str>>(delimiter: u8, fields: I) -> Result<String> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .terminator(csv::Terminator::Any(b'\n'))
+        .from_writer(vec![]);
+    writer.write_record(fields).map_err(|e| result::Error::Misc(format!("csv : {}", e)))?;
+    let mut bytes = writer.into_inner().map_err(|e| result::Error::Misc(format!("csv : {}", e)))?;
+    bytes.pop();
+    Ok(String::from_utf8(bytes)?)
+}
+
+fn text(value: &FmtCsvValue) -> String {
+    match *value {
+        FmtCsvValue::Null => String::new(),
+        FmtCsvValue::Text(ref text) => text.clone(),
+        FmtCsvValue::Int(i) => i.to_string(),
+        FmtCsvValue::Float(f) => f.to_string(),
+        FmtCsvValue::Bool(b) => b.to_string(),
+    }
+}
@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ FsPath FsFileError FsDirListOption ];
+  mods = with mods.rs; [ rustfbp capnp glob ];
+  osdeps = with pkgs; [];
+}
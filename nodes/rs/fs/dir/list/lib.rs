@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate glob;
+
+use std::fs;
+use std::path::Path;
+
+use glob::Pattern;
+
+agent! {
+    input(input: FsPath),
+    output(output: FsPath, error: FsFileError),
+    option(FsDirListOption),
+    fn run(&mut self) -> Result<Signal> {
+        let path = self.input.input.recv()?.0;
+        let opt = self.try_recv_option().unwrap_or(FsDirListOption { recursive: false, glob: None });
+        let pattern = match opt.glob {
+            Some(ref glob) => { Some(Pattern::new(glob).map_err(|e| result::Error::Misc(format!("fs_dir_list : bad glob {} : {}", glob, e)))?) },
+            None => None,
+        };
+
+        if list(Path::new(&path), opt.recursive, &pattern, &self.output.output).is_err() {
+            let _ = self.output.error.send(FsFileError(path));
+        }
+        Ok(End)
+    }
+}
+
+fn list(dir: &Path, recursive: bool, pattern: &Option<Pattern>, output: &Option<MsgSender<FsPath>>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let matches = match *pattern {
+            Some(ref pattern) => path.file_name().map(|name| pattern.matches(&name.to_string_lossy())).unwrap_or(false),
+            None => true,
+        };
+        if matches {
+            output.send(FsPath(path.to_string_lossy().into_owned()))?;
+        }
+        // The type of the entry itself : a link to a directory is listed, not followed, it could loop
+        if recursive && entry.file_type()?.is_dir() {
+            list(&path, recursive, pattern, output)?;
+        }
+    }
+    Ok(())
+}
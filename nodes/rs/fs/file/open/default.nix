@@ -2,7 +2,7 @@
 
 agent {
   src = ./.;
-  edges = with edges.rs; [ FsPath FsFileDesc FsFileError ];
+  edges = with edges.rs; [ FsPath FsFileDesc FsFileError FsFileOpenOption ];
   mods = with mods.rs; [ rustfbp capnp ];
   osdeps = with pkgs; [];
 }
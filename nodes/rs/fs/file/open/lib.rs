@@ -9,6 +9,7 @@ use std::io::BufRead;
 agent! {
     input(input: FsPath),
     output(output: FsFileDesc, error: FsFileError),
+    option(FsFileOpenOption),
     fn run(&mut self) -> Result<Signal> {
         // Get the path
         let mut path = self.input.input.recv()?.0;
@@ -25,10 +26,25 @@ agent! {
         // Send start
         self.output.output.send(FsFileDesc::Start(path.clone()))?;
 
-        // Send lines
-        let file = BufReader::new(&file);
-        for line in file.lines() {
-            self.output.output.send(FsFileDesc::Text(line?))?;
+        match self.try_recv_option() {
+            // Send chunks
+            Some(FsFileOpenOption(size)) => {
+                let mut file = file;
+                loop {
+                    let mut chunk = vec![0; size];
+                    let read = file.read(&mut chunk)?;
+                    if read == 0 { break; }
+                    chunk.truncate(read);
+                    self.output.output.send(FsFileDesc::Chunk(chunk))?;
+                }
+            },
+            // Send lines
+            None => {
+                let file = BufReader::new(&file);
+                for line in file.lines() {
+                    self.output.output.send(FsFileDesc::Text(line?))?;
+                }
+            }
         }
 
         // Send stop
@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::io::BufWriter;
+
+agent! {
+    input(input: FsFileDesc),
+    output(output: FsPath, error: FsFileError),
+    // The file of the current stream, None if it cannot be opened
+    state(Option<(String, Option<BufWriter<File>>)> => None),
+    option(FsFileWriteOption),
+    fn run(&mut self) -> Result<Signal> {
+        let opt = self.try_recv_option().unwrap_or(FsFileWriteOption { path: None, append: false, fsync: false });
+
+        match self.input.input.recv()? {
+            FsFileDesc::Start(path) => {
+                let path = opt.path.unwrap_or(path);
+                let file = OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .append(opt.append)
+                    .truncate(!opt.append)
+                    .open(&path);
+                let file = match file {
+                    Ok(file) => { Some(BufWriter::new(file)) },
+                    Err(_) => {
+                        let _ = self.output.error.send(FsFileError(path.clone()));
+                        None
+                    }
+                };
+                self.state = Some((path, file));
+            },
+            FsFileDesc::Text(text) => {
+                if let Some((ref path, Some(ref mut file))) = self.state {
+                    if writeln!(file, "{}", text).is_err() {
+                        let _ = self.output.error.send(FsFileError(path.clone()));
+                    }
+                }
+            },
+            FsFileDesc::Chunk(chunk) => {
+                if let Some((ref path, Some(ref mut file))) = self.state {
+                    if file.write_all(&chunk).is_err() {
+                        let _ = self.output.error.send(FsFileError(path.clone()));
+                    }
+                }
+            },
+            FsFileDesc::End(_) => {
+                if let Some((path, file)) = self.state.take() {
+                    if let Some(file) = file {
+                        let done = file.into_inner()
+                            .map_err(io::Error::from)
+                            .and_then(|file| if opt.fsync { file.sync_all() } else { Ok(()) });
+                        match done {
+                            Ok(()) => { self.output.output.send(FsPath(path))?; },
+                            Err(_) => { let _ = self.output.error.send(FsFileError(path)); },
+                        }
+                    }
+                }
+            },
+        }
+        Ok(End)
+    }
+}
@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+// An adapter for `Scheduler::connect_adapted`, from a text to a path
+agent! {
+    input(input: String),
+    output(output: FsPath),
+    fn run(&mut self) -> Result<Signal> {
+        let text = self.input.input.recv()?;
+        self.output.output.send(FsPath(text))?;
+        Ok(End)
+    }
+}
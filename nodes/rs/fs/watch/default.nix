@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ FsPath FsWatchEvent FsFileError ];
+  mods = with mods.rs; [ rustfbp capnp notify ];
+  osdeps = with pkgs; [];
+}
@@ -0,0 +1,71 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate notify;
+
+use std::path::Path;
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use notify::{EventKind, RecursiveMode, Watcher};
+
+// How often the watcher looks for more paths to watch
+const TICK: Duration = Duration::from_millis(500);
+
+agent! {
+    // More paths to watch, after the one of the option
+    input(add: FsPath),
+    output(output: FsWatchEvent, error: FsFileError),
+    option(FsPath),
+    blocking(true),
+    fn run(&mut self) -> Result<Signal> {
+        let path = self.recv_option().0;
+
+        // inotify, kqueue, ... depending on the platform
+        let (s, r) = channel();
+        let watcher = notify::recommended_watcher(s)
+            .and_then(|mut watcher| {
+                watcher.watch(Path::new(&path), RecursiveMode::Recursive)?;
+                Ok(watcher)
+            });
+        let mut watcher = match watcher {
+            Ok(watcher) => { watcher },
+            Err(_) => {
+                let _ = self.output.error.send(FsFileError(path));
+                return Ok(End);
+            }
+        };
+
+        loop {
+            while let Ok(FsPath(path)) = self.input.add.try_recv() {
+                if watcher.watch(Path::new(&path), RecursiveMode::Recursive).is_err() {
+                    let _ = self.output.error.send(FsFileError(path));
+                }
+            }
+            let event = match r.recv_timeout(TICK) {
+                Ok(Ok(event)) => { event },
+                Ok(Err(_)) | Err(RecvTimeoutError::Timeout) => { continue; },
+                Err(RecvTimeoutError::Disconnected) => { break; },
+            };
+            let kind = match event.kind {
+                EventKind::Create(_) => FsWatchKind::Created,
+                EventKind::Modify(_) => FsWatchKind::Modified,
+                EventKind::Remove(_) => FsWatchKind::Deleted,
+                _ => { continue; },
+            };
+            for changed in event.paths {
+                self.output.output.send(FsWatchEvent {
+                    path: changed.to_string_lossy().into_owned(),
+                    kind: kind.clone(),
+                    timestamp: now(),
+                })?;
+            }
+        }
+        Ok(End)
+    }
+}
+
+fn now() -> u64 {
+    let since = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or(Duration::from_millis(0));
+    since.as_secs() * 1000 + (since.subsec_nanos() / 1_000_000) as u64
+}
@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ FsPath ];
+  mods = with mods.rs; [ rustfbp capnp pyo3 serde_json ];
+  osdeps = with pkgs; [ python3 ];
+}
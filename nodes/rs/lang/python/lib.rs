@@ -0,0 +1,98 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate pyo3;
+extern crate serde_json;
+
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
+use serde_json::Value;
+use std::fs;
+use std::mem;
+
+// The messages sent by the script during its callbacks, as JSON
+#[pyclass]
+#[derive(Default)]
+struct Outbox {
+    msgs: Vec<(String, String)>,
+}
+
+#[pymethods]
+impl Outbox {
+    // `fractalide.send("result", {...})` in the script sends the dict on the element `result` of `outputs`
+    fn send(&mut self, py: Python, port: String, msg: PyObject) -> PyResult<()> {
+        let json: String = py.import("json")?.call_method1("dumps", (msg,))?.extract()?;
+        self.msgs.push((port, json));
+        Ok(())
+    }
+}
+
+struct Script {
+    module: Py<PyModule>,
+    outbox: Py<Outbox>,
+}
+
+// Run a Python script, given by its path in the option
+//
+// A message on the element `x` of `inputs` calls the function `x` of the script with the message as a dict.
+// The script sends its messages with `fractalide.send(element, dict)`, `fractalide` is set once the script is loaded.
+agent! {
+    inarr(inputs: SerdeEdge<Value>),
+    outarr(outputs: SerdeEdge<Value>),
+    state(Option<Script> => None),
+    option(FsPath),
+    fn run(&mut self) -> Result<Signal> {
+        if self.state.is_none() {
+            let opt = self.recv_option();
+            self.state = Some(load(&opt.0)?);
+        }
+
+        let mut msgs = vec![];
+        for (element, recv) in self.inarr.inputs.iter() {
+            while let Ok(msg) = recv.try_recv() {
+                msgs.push((element.clone(), msg.into_inner()));
+            }
+        }
+
+        let sent = match self.state {
+            Some(ref script) => call(script, msgs)?,
+            None => vec![],
+        };
+        for (port, json) in sent {
+            let msg: Value = serde_json::from_str(&json)?;
+            match self.outarr.outputs.get(&port) {
+                Some(sender) => { sender.send(SerdeEdge(msg))?; },
+                None => { log_warn!(self, port = "outputs", "the element {} is not connected", port); },
+            }
+        }
+        Ok(End)
+    }
+}
+
+fn load(path: &str) -> Result<Script> {
+    let code = fs::read_to_string(path)?;
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| -> PyResult<Script> {
+        let outbox = Py::new(py, Outbox::default())?;
+        let module = PyModule::from_code(py, &code, path, "script")?;
+        module.add("fractalide", outbox.clone_ref(py))?;
+        Ok(Script { module: module.into(), outbox: outbox })
+    }).map_err(python_error)
+}
+
+// Call the function of each message, and take what the script sent
+fn call(script: &Script, msgs: Vec<(String, Value)>) -> Result<Vec<(String, String)>> {
+    Python::with_gil(|py| -> PyResult<Vec<(String, String)>> {
+        let module = script.module.as_ref(py);
+        let json = py.import("json")?;
+        for (element, msg) in msgs {
+            let msg = json.call_method1("loads", (msg.to_string(),))?;
+            module.getattr(element.as_str())?.call1((msg,))?;
+        }
+        Ok(mem::replace(&mut script.outbox.borrow_mut(py).msgs, vec![]))
+    }).map_err(python_error)
+}
+
+fn python_error(e: PyErr) -> result::Error {
+    result::Error::Misc(format!("python : {}", e))
+}
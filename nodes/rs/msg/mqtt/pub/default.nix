@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ MsgMqttOption MsgMqttMessage ];
+  mods = with mods.rs; [ rustfbp capnp rumqttc ];
+  osdeps = with pkgs; [];
+}
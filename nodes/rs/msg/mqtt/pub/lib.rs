@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate rumqttc;
+
+use rumqttc::{Client, Event, MqttOptions, QoS, Transport};
+use std::cmp;
+use std::thread;
+use std::time::Duration;
+
+agent! {
+    input(input: MsgMqttMessage),
+    // The connection is driven by another thread
+    state(Option<Client> => None),
+    option(MsgMqttOption),
+    fn run(&mut self) -> Result<Signal> {
+        let msg = self.input.input.recv()?;
+        let opt = self.recv_option();
+
+        // Connect at the first message
+        if self.state.is_none() {
+            let (client, mut connection) = Client::new(options(&opt), 64);
+            let log = self.log.clone();
+            let broker = format!("{}:{}", opt.host, opt.port);
+            thread::spawn(move || {
+                // Reconnects by itself, until the client is dropped with the agent
+                let mut backoff = 100;
+                for event in connection.iter() {
+                    match event {
+                        Ok(Event::Incoming(_)) => { backoff = 100; },
+                        Ok(_) => {},
+                        Err(rumqttc::ConnectionError::RequestsDone) => { return; },
+                        Err(e) => {
+                            log.warn(format_args!("connection to {} lost : {}", broker, e));
+                            thread::sleep(Duration::from_millis(backoff));
+                            backoff = cmp::min(backoff * 2, 30_000);
+                        },
+                    }
+                }
+            });
+            self.state = Some(client);
+        }
+
+        if let Some(ref client) = self.state {
+            client.publish(msg.topic, qos(opt.qos), false, msg.payload).map_err(mqtt_error)?;
+        }
+        Ok(End)
+    }
+}
+
+fn options(opt: &MsgMqttOption) -> MqttOptions {
+    let mut options = MqttOptions::new(opt.client_id.clone(), opt.host.clone(), opt.port);
+    options.set_keep_alive(Duration::from_secs(30));
+    if opt.tls {
+        options.set_transport(Transport::tls_with_default_config());
+    }
+    options
+}
+
+fn qos(qos: u8) -> QoS {
+    if qos == 0 { QoS::AtMostOnce } else { QoS::AtLeastOnce }
+}
+
+fn mqtt_error(e: rumqttc::ClientError) -> result::Error {
+    result::Error::Misc(format!("mqtt : {}", e))
+}
@@ -0,0 +1,83 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate rumqttc;
+
+use rumqttc::{Client, Event, MqttOptions, Packet, QoS, Transport};
+use std::cmp;
+use std::thread;
+use std::time::Duration;
+
+agent! {
+    outarr(outputs: MsgMqttMessage),
+    option(MsgMqttOption),
+    blocking(true),
+    fn run(&mut self) -> Result<Signal> {
+        let opt = self.recv_option();
+        let (client, mut connection) = Client::new(options(&opt), 64);
+
+        // The connection reconnects by itself, the subscriptions are made again at each connection
+        let mut backoff = 100;
+        for event in connection.iter() {
+            match event {
+                Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                    backoff = 100;
+                    for (filter, _) in &opt.subscriptions {
+                        client.subscribe(filter.as_str(), qos(opt.qos)).map_err(mqtt_error)?;
+                    }
+                },
+                Ok(Event::Incoming(Packet::Publish(publish))) => {
+                    // The first subscription matching the topic
+                    let element = opt.subscriptions.iter()
+                        .find(|(filter, _)| matches(filter, &publish.topic))
+                        .map(|(_, element)| element);
+                    let output = element.and_then(|element| self.outarr.outputs.get(element));
+                    match output {
+                        Some(output) => {
+                            output.send(MsgMqttMessage { topic: publish.topic, payload: publish.payload.to_vec() })?;
+                        },
+                        None => { log_warn!(self, port = "outputs", "no element for the topic {}", publish.topic); },
+                    }
+                },
+                Ok(_) => {},
+                Err(e) => {
+                    log_warn!(self, "connection to {}:{} lost : {}", opt.host, opt.port, e);
+                    thread::sleep(Duration::from_millis(backoff));
+                    backoff = cmp::min(backoff * 2, 30_000);
+                },
+            }
+        }
+        Ok(End)
+    }
+}
+
+fn options(opt: &MsgMqttOption) -> MqttOptions {
+    let mut options = MqttOptions::new(opt.client_id.clone(), opt.host.clone(), opt.port);
+    options.set_keep_alive(Duration::from_secs(30));
+    if opt.tls {
+        options.set_transport(Transport::tls_with_default_config());
+    }
+    options
+}
+
+fn qos(qos: u8) -> QoS {
+    if qos == 0 { QoS::AtMostOnce } else { QoS::AtLeastOnce }
+}
+
+// A topic filter, with the `+` (one level) and `#` (all the remaining levels) wildcards
+fn matches(filter: &str, topic: &str) -> bool {
+    let mut topic = topic.split('/');
+    for level in filter.split('/') {
+        match (level, topic.next()) {
+            ("#", _) => { return true; },
+            ("+", Some(_)) => {},
+            (level, Some(t)) if level == t => {},
+            _ => { return false; },
+        }
+    }
+    topic.next().is_none()
+}
+
+fn mqtt_error(e: rumqttc::ClientError) -> result::Error {
+    result::Error::Misc(format!("mqtt : {}", e))
+}
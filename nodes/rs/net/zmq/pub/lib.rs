@@ -0,0 +1,31 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate zmq;
+
+mod socket;
+
+use socket::{open, zmq_error};
+
+agent! {
+    input(input: CapnpMsg),
+    state(Option<zmq::Socket> => None),
+    option(NetZmqSocket),
+    fn run(&mut self) -> Result<Signal> {
+        let msg = self.input.input.recv()?;
+
+        // Open the socket with the option at the first message
+        let opt = self.recv_option();
+        if self.state.is_none() {
+            self.state = Some(open(zmq::PUB, &opt.endpoint, opt.bind)?);
+        }
+
+        // Each message is published in two frames : the topic and the capnp message
+        let bytes = msg.to_bytes()?;
+        if let Some(ref socket) = self.state {
+            socket.send(opt.topic.as_bytes(), zmq::SNDMORE).map_err(zmq_error)?;
+            socket.send(&bytes, 0).map_err(zmq_error)?;
+        }
+        Ok(End)
+    }
+}
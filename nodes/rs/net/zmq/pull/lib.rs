@@ -0,0 +1,33 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate zmq;
+
+mod socket;
+
+use socket::{open, zmq_error};
+
+agent! {
+    output(output: CapnpMsg, error: NetZmqError),
+    option(NetZmqSocket),
+    blocking(true),
+    fn run(&mut self) -> Result<Signal> {
+        let opt = self.recv_option();
+        let socket = open(zmq::PULL, &opt.endpoint, opt.bind)?;
+
+        // Forward each received capnp message, a frame which isn't one goes to `error`
+        loop {
+            let bytes = socket.recv_bytes(0).map_err(zmq_error)?;
+            match CapnpMsg::from_bytes(&bytes) {
+                Ok(msg) => { self.output.output.send(msg)?; },
+                Err(e) => {
+                    let _ = self.output.error.send(NetZmqError {
+                        endpoint: opt.endpoint.clone(),
+                        error: e.to_string(),
+                        frame: bytes,
+                    });
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate zmq;
+
+mod socket;
+
+use socket::{open, zmq_error};
+
+agent! {
+    input(input: CapnpMsg),
+    state(Option<zmq::Socket> => None),
+    option(NetZmqSocket),
+    fn run(&mut self) -> Result<Signal> {
+        let msg = self.input.input.recv()?;
+
+        // Open the socket with the option at the first message
+        if self.state.is_none() {
+            let opt = self.recv_option();
+            self.state = Some(open(zmq::PUSH, &opt.endpoint, opt.bind)?);
+        }
+
+        let bytes = msg.to_bytes()?;
+        if let Some(ref socket) = self.state {
+            socket.send(&bytes, 0).map_err(zmq_error)?;
+        }
+        Ok(End)
+    }
+}
@@ -0,0 +1,21 @@
+// The socket of the zmq agents, copied next to their lib.rs by their configurePhase
+
+use rustfbp::result;
+use rustfbp::result::Result;
+
+use zmq;
+
+/// Bind the socket `kind` on `endpoint`, or connect it
+pub fn open(kind: zmq::SocketType, endpoint: &str, bind: bool) -> Result<zmq::Socket> {
+    let socket = zmq::Context::new().socket(kind).map_err(zmq_error)?;
+    if bind {
+        socket.bind(endpoint).map_err(zmq_error)?;
+    } else {
+        socket.connect(endpoint).map_err(zmq_error)?;
+    }
+    Ok(socket)
+}
+
+pub fn zmq_error(e: zmq::Error) -> result::Error {
+    result::Error::Misc(format!("zmq : {}", e))
+}
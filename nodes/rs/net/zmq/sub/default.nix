@@ -0,0 +1,12 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ NetZmqSocket NetZmqError ];
+  mods = with mods.rs; [ rustfbp capnp zmq ];
+  osdeps = with pkgs; [ zeromq ];
+  # the socket shared by the zmq agents
+  configurePhase = ''
+    cp ${../socket.rs} socket.rs
+  '';
+}
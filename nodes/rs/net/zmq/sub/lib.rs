@@ -0,0 +1,36 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate zmq;
+
+mod socket;
+
+use socket::{open, zmq_error};
+
+agent! {
+    output(output: CapnpMsg, error: NetZmqError),
+    option(NetZmqSocket),
+    blocking(true),
+    fn run(&mut self) -> Result<Signal> {
+        let opt = self.recv_option();
+        let socket = open(zmq::SUB, &opt.endpoint, opt.bind)?;
+        socket.set_subscribe(opt.topic.as_bytes()).map_err(zmq_error)?;
+
+        // The capnp message is the last frame, after the topic, a frame which isn't one goes to `error`
+        loop {
+            let mut frames = socket.recv_multipart(0).map_err(zmq_error)?;
+            if let Some(bytes) = frames.pop() {
+                match CapnpMsg::from_bytes(&bytes) {
+                    Ok(msg) => { self.output.output.send(msg)?; },
+                    Err(e) => {
+                        let _ = self.output.error.send(NetZmqError {
+                            endpoint: opt.endpoint.clone(),
+                            error: e.to_string(),
+                            frame: bytes,
+                        });
+                    },
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use std::cmp;
+use std::io;
+use std::io::{BufReader, BufWriter};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+// Run a program, which talks with the network on its stdin and stdout
+//
+// A message on the element `x` of `inputs` is written on the stdin of the program, and a frame of the program on
+// its stdout is sent on the element of `outputs` it names. Each frame is :
+//
+//   the length of the name of the element, as a big endian u32, then the name,
+//   the length of the message, as a big endian u32, then the Cap'n Proto message in the wire format.
+//
+// The program is killed when the agent is dropped, and started again when it exits if the option says `restart`.
+// Its stderr is the one of the scheduler.
+agent! {
+    inarr(inputs: CapnpMsg),
+    outarr(outputs: CapnpMsg),
+    // The frames to write, the program is kept by another thread
+    state(Option<Sender<(String, Vec<u8>)>> => None),
+    option(ProcExecOption),
+    autostart(true),
+    fn run(&mut self) -> Result<Signal> {
+        // The first run starts the program
+        if self.state.is_none() {
+            let opt = self.recv_option();
+            let (s, r) = channel();
+            let outputs = self.outarr.outputs.clone();
+            let log = self.log.clone();
+            thread::spawn(move || {
+                supervise(opt, r, outputs, log);
+            });
+            self.state = Some(s);
+        }
+
+        for (element, recv) in self.inarr.inputs.iter() {
+            while let Ok(msg) = recv.try_recv() {
+                if let Some(ref stdin) = self.state {
+                    stdin.send((element.clone(), msg.to_bytes()?)).map_err(|_| result::Error::MpscSend)?;
+                }
+            }
+        }
+        Ok(End)
+    }
+}
+
+// Start the program, and again each time it exits if `restart`, until the agent is dropped
+fn supervise(opt: ProcExecOption, inbound: Receiver<(String, Vec<u8>)>, outputs: HashMap<String, MsgSender<CapnpMsg>>, log: rustfbp::log::Context) {
+    let mut backoff = 100;
+    loop {
+        let mut child = match spawn(&opt) {
+            Ok(child) => child,
+            Err(e) => {
+                log.error(format_args!("cannot start {} : {}", opt.program, e));
+                return;
+            }
+        };
+        if let Some(stdout) = child.stdout.take() {
+            let outputs = outputs.clone();
+            let log = log.clone();
+            thread::spawn(move || {
+                if let Err(e) = read_frames(stdout, &outputs, &log) {
+                    log.warn(format_args!("bad frame on stdout : {}", e));
+                }
+            });
+        }
+        let stdin = child.stdin.take().expect("proc_exec : stdin is piped");
+        let dropped = forward(&mut child, stdin, &inbound);
+        if dropped {
+            let _ = child.kill();
+            let _ = child.wait();
+            return;
+        }
+
+        match child.wait() {
+            Ok(status) => { log.warn(format_args!("{} exited : {}", opt.program, status)); },
+            Err(e) => { log.warn(format_args!("{} exited : {}", opt.program, e)); },
+        }
+        if !opt.restart {
+            return;
+        }
+        thread::sleep(Duration::from_millis(backoff));
+        backoff = cmp::min(backoff * 2, 30_000);
+    }
+}
+
+fn spawn(opt: &ProcExecOption) -> io::Result<Child> {
+    Command::new(&opt.program)
+        .args(&opt.args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+}
+
+// Write the inbound frames on stdin. Returns true when the agent is dropped, false when the program exits.
+fn forward(child: &mut Child, stdin: ChildStdin, inbound: &Receiver<(String, Vec<u8>)>) -> bool {
+    let mut stdin = BufWriter::new(stdin);
+    loop {
+        match inbound.recv_timeout(Duration::from_millis(100)) {
+            Ok((element, bytes)) => {
+                let written = write_frame(&mut stdin, element.as_bytes())
+                    .and_then(|_| write_frame(&mut stdin, &bytes))
+                    .and_then(|_| stdin.flush());
+                if written.is_err() {
+                    return false;
+                }
+            },
+            Err(RecvTimeoutError::Timeout) => {
+                if let Ok(Some(_)) = child.try_wait() {
+                    return false;
+                }
+            },
+            Err(RecvTimeoutError::Disconnected) => { return true; },
+        }
+    }
+}
+
+// Send the frames of stdout on the outputs, until the program closes it
+fn read_frames(stdout: ChildStdout, outputs: &HashMap<String, MsgSender<CapnpMsg>>, log: &rustfbp::log::Context) -> Result<()> {
+    let mut stdout = BufReader::new(stdout);
+    loop {
+        let element = match read_frame(&mut stdout)? {
+            Some(element) => String::from_utf8(element)?,
+            None => { return Ok(()); },
+        };
+        let bytes = read_frame(&mut stdout)?.ok_or(result::Error::Misc("frame cut after the element".into()))?;
+        match outputs.get(&element) {
+            Some(output) => { output.send(CapnpMsg::from_bytes(&bytes)?)?; },
+            None => { log.warn(format_args!("the element {} of outputs is not connected", element)); },
+        }
+    }
+}
+
+fn write_frame<W: Write>(w: &mut W, bytes: &[u8]) -> io::Result<()> {
+    let len = bytes.len() as u32;
+    w.write_all(&[(len >> 24) as u8, (len >> 16) as u8, (len >> 8) as u8, len as u8])?;
+    w.write_all(bytes)
+}
+
+// None at the end of the stream
+fn read_frame<R: Read>(r: &mut R) -> Result<Option<Vec<u8>>> {
+    let mut len = [0u8; 4];
+    match r.read_exact(&mut len) {
+        Ok(()) => {},
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => { return Ok(None); },
+        Err(e) => { return Err(e.into()); },
+    }
+    let len = ((len[0] as usize) << 24) | ((len[1] as usize) << 16) | ((len[2] as usize) << 8) | len[3] as usize;
+    let mut bytes = vec![0u8; len];
+    r.read_exact(&mut bytes)?;
+    Ok(Some(bytes))
+}
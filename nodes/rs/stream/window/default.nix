@@ -0,0 +1,10 @@
+{ lib, agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ StreamWindow StreamWindowAggregate StreamWindowOption ];
+  # the registry holds the flat schemas of these edges
+  capnp_edges = (lib.collect lib.isDerivation edges.capnp);
+  mods = with mods.rs; [ rustfbp capnp serde_json ];
+  osdeps = with pkgs; [];
+}
@@ -0,0 +1,114 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate serde_json;
+
+use rustfbp::json::JsonRegistry;
+
+use std::cmp;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+// Group the messages in windows of a number of messages or of a time, and send each window
+//
+// A window is sent as a substream on `window`, and as its count and the sum of a field on `aggregate`, for the ports
+// which are connected. A window of time starts with its first message, and is sent at its end. With a slide shorter
+// than the size, the windows overlap : a message is in each window which covers it.
+agent! {
+    input(input: CapnpMsg),
+    output(window: StreamWindow, aggregate: StreamWindowAggregate),
+    state(Windows => Windows::new()),
+    option(StreamWindowOption),
+    blocking(true),
+    fn run(&mut self) -> Result<Signal> {
+        let opt = self.recv_option();
+
+        match opt.size {
+            StreamWindowSize::Count(size) => {
+                let size = cmp::max(size, 1) as usize;
+                let slide = if opt.slide == 0 { size } else { cmp::min(opt.slide as usize, size) };
+                while let Ok(msg) = self.input.input.try_recv() {
+                    self.state.msgs.push_back((Instant::now(), msg));
+                    if self.state.msgs.len() >= size {
+                        let n = self.state.next;
+                        self.state.next += 1;
+                        send(n, &self.state, &opt, &self.output.window, &self.output.aggregate)?;
+                        self.state.msgs.drain(..slide);
+                    }
+                }
+            },
+            StreamWindowSize::Time(size) => {
+                let size = Duration::from_millis(cmp::max(size, 1));
+                let slide = if opt.slide == 0 { size } else { cmp::min(Duration::from_millis(opt.slide), size) };
+                let msg = self.input.input.recv()?;
+                let mut start = Instant::now();
+                self.state.msgs.push_back((start, msg));
+                // The windows which cover the messages, until none is left
+                while !self.state.msgs.is_empty() {
+                    let end = start + size;
+                    loop {
+                        match self.input.input.recv_timeout(end.saturating_duration_since(Instant::now())) {
+                            Ok(msg) => { self.state.msgs.push_back((Instant::now(), msg)); },
+                            Err(result::Error::RecvTimeout) => { break; },
+                            Err(e) => { return Err(e); },
+                        }
+                    }
+                    let n = self.state.next;
+                    self.state.next += 1;
+                    send(n, &self.state, &opt, &self.output.window, &self.output.aggregate)?;
+                    start += slide;
+                    while self.state.msgs.front().map_or(false, |m| m.0 < start) {
+                        self.state.msgs.pop_front();
+                    }
+                }
+            },
+        }
+        Ok(End)
+    }
+}
+
+pub struct Windows {
+    json: JsonRegistry,
+    // The messages of the current window, with when they came
+    msgs: VecDeque<(Instant, CapnpMsg)>,
+    // The number of the next window
+    next: u64,
+}
+
+impl Windows {
+    fn new() -> Self {
+        Windows {
+            json: json_registry(),
+            msgs: VecDeque::new(),
+            next: 0,
+        }
+    }
+}
+
+fn send(n: u64, windows: &Windows, opt: &StreamWindowOption,
+        window: &OutputPort<StreamWindow>, aggregate: &OutputPort<StreamWindowAggregate>) -> Result<()> {
+    if window.is_some() {
+        window.send(StreamWindow::Start(n.to_string()))?;
+        for &(_, ref msg) in &windows.msgs {
+            window.send(StreamWindow::Msg(msg.to_envelope()?))?;
+        }
+        window.send(StreamWindow::End(n.to_string()))?;
+    }
+    if aggregate.is_some() {
+        let mut sum = 0.0;
+        if !opt.field.is_empty() {
+            for &(_, ref msg) in &windows.msgs {
+                // The messages without the field, or without a number in it, count for 0, a message which can't be
+                // read fails the window
+                let fields = windows.json.encode_msg(&opt.schema, msg)?;
+                sum += fields.get(&opt.field).and_then(|v| v.as_f64()).unwrap_or(0.0);
+            }
+        }
+        aggregate.send(StreamWindowAggregate {
+            window: n,
+            count: windows.msgs.len() as u64,
+            sum: sum,
+        })?;
+    }
+    Ok(())
+}
@@ -0,0 +1,14 @@
+{ agent, edges, mods, nodes }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ FsPath FsFileError CoreLexical CoreGraph CoreSemanticError ];
+  mods = with mods.rs; [ rustfbp capnp ];
+  configurePhase = ''
+    substituteInPlace lib.rs --replace "fs_file_open.so" "${nodes.rs.fs_file_open}/lib/libagent.so"
+    substituteInPlace lib.rs --replace "core_parser_lexical.so" "${nodes.fvm_rs_parser_lexical}/lib/libagent.so"
+    substituteInPlace lib.rs --replace "core_parser_semantic.so" "${nodes.fvm_rs_parser_semantic}/lib/libagent.so"
+    substituteInPlace lib.rs --replace "core_parser_graph_check.so" "${nodes.fvm_rs_parser_graph_check}/lib/libagent.so"
+    substituteInPlace lib.rs --replace "golden_corpus" "${./golden}"
+  '';
+}
@@ -0,0 +1,241 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+
+use rustfbp::testing::TestNetwork;
+
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::process;
+use std::time::Duration;
+
+// The corpus of the golden tests : for each `name.fbp`, the tokens of the lexer are in `name.tokens`, and the graph
+// of the semantic parser with the feature `debug`, or its errors, in `name.graph`. FBP_GOLDEN_DIR reads another
+// corpus, and FBP_GOLDEN_UPDATE writes the dumps instead of comparing them.
+const GOLDEN: &str = "golden_corpus";
+
+// The parsers of fvm, from the path of a graph to the checked graph
+fn pipeline() -> Result<TestNetwork> {
+    let mut net = network! {
+        open("fs_file_open.so") output -> input lex("core_parser_lexical.so"),
+        lex() output -> input sem("core_parser_semantic.so"),
+        sem() output -> input graph_check("core_parser_graph_check.so"),
+    }?;
+    net.capture::<FsFileError>("open", "error")?;
+    net.capture::<CoreSemanticError>("sem", "error")?;
+    net.capture::<CoreGraph>("graph_check", "output")?;
+    net.capture::<CoreSemanticError>("graph_check", "error")?;
+    Ok(net)
+}
+
+// What came out of the pipeline for one graph
+struct Outcome {
+    graphs: Vec<CoreGraph>,
+    file_errors: Vec<FsFileError>,
+    semantic_errors: Vec<CoreSemanticError>,
+    check_errors: Vec<CoreSemanticError>,
+}
+
+fn parse(path: &str) -> Result<Outcome> {
+    let mut net = pipeline()?;
+    net.send("open", "input", FsPath(path.into()))?;
+    net.run(Duration::from_secs(10))?;
+    let outcome = Outcome {
+        graphs: net.received("graph_check", "output")?,
+        file_errors: net.received("open", "error")?,
+        semantic_errors: net.received("sem", "error")?,
+        check_errors: net.received("graph_check", "error")?,
+    };
+    net.join();
+    Ok(outcome)
+}
+
+fn parse_text(name: &str, fbp: &str) -> Result<Outcome> {
+    let path = env::temp_dir().join(format!("test_fvm_parser_{}_{}.fbp", process::id(), name));
+    File::create(&path)?.write_all(fbp.as_bytes())?;
+    let path = path.to_string_lossy().into_owned();
+    parse(&path)
+}
+
+fn valid_graph() -> Result<Option<String>> {
+    let outcome = parse_text("valid", "a(sort_a) output -> input b(sort_b)\n")?;
+    if !outcome.semantic_errors.is_empty() || !outcome.check_errors.is_empty() {
+        return Ok(Some(format!("unexpected errors : {:?} {:?}", outcome.semantic_errors, outcome.check_errors)));
+    }
+    if outcome.graphs.len() != 1 {
+        return Ok(Some(format!("expected one graph, got {}", outcome.graphs.len())));
+    }
+    let graph = &outcome.graphs[0];
+    let nodes: Vec<(&str, &str)> = graph.nodes.iter().map(|n| (&n.name as &str, &n.sort as &str)).collect();
+    if !nodes.contains(&("a", "sort_a")) || !nodes.contains(&("b", "sort_b")) {
+        return Ok(Some(format!("unexpected nodes : {:?}", graph.nodes)));
+    }
+    let edge = CoreGraphEdge {
+        out_comp: "a".into(), out_port: "output".into(), out_elem: None,
+        in_port: "input".into(), in_elem: None, in_comp: "b".into(),
+    };
+    if graph.edges != vec![edge] {
+        return Ok(Some(format!("unexpected edges : {:?}", graph.edges)));
+    }
+    Ok(None)
+}
+
+fn syntax_error() -> Result<Option<String>> {
+    let outcome = parse_text("syntax", "a(sort_a) output -> -> input b(sort_b)\n")?;
+    if !outcome.graphs.is_empty() {
+        return Ok(Some(format!("expected no graph, got {:?}", outcome.graphs)));
+    }
+    match outcome.semantic_errors.first() {
+        Some(error) if !error.parsing.is_empty() => Ok(None),
+        _ => Ok(Some(format!("expected a semantic error, got {:?}", outcome.semantic_errors))),
+    }
+}
+
+fn one_to_many() -> Result<Option<String>> {
+    let outcome = parse_text("one_to_many", "a(sort_a) output -> input b(sort_b)\na() output -> input c(sort_c)\n")?;
+    if !outcome.graphs.is_empty() || !outcome.semantic_errors.is_empty() {
+        return Ok(Some(format!("expected only a check error, got {:?} {:?}", outcome.graphs, outcome.semantic_errors)));
+    }
+    match outcome.check_errors.first() {
+        Some(error) if error.parsing.iter().any(|e| e.contains("one-2-many")) => Ok(None),
+        _ => Ok(Some(format!("expected a one-2-many error, got {:?}", outcome.check_errors))),
+    }
+}
+
+fn missing_file() -> Result<Option<String>> {
+    let path = env::temp_dir().join(format!("test_fvm_parser_{}_missing.fbp", process::id()));
+    let outcome = parse(&path.to_string_lossy())?;
+    if !outcome.graphs.is_empty() {
+        return Ok(Some(format!("expected no graph, got {:?}", outcome.graphs)));
+    }
+    if outcome.file_errors.len() != 1 {
+        return Ok(Some(format!("expected a file error, got {:?}", outcome.file_errors)));
+    }
+    Ok(None)
+}
+
+// The tokens of the graph at `path`, one per line
+fn dump_tokens(path: &str) -> Result<String> {
+    let mut net = network! {
+        open("fs_file_open.so") output -> input lex("core_parser_lexical.so"),
+    }?;
+    net.capture::<CoreLexical>("lex", "output")?;
+    net.send("open", "input", FsPath(path.into()))?;
+    net.run(Duration::from_secs(10))?;
+    let mut dump = String::new();
+    for lexical in net.received::<CoreLexical>("lex", "output")? {
+        if let CoreLexical::Token(token, _) = lexical {
+            dump.push_str(&format!("{:?}\n", token));
+        }
+    }
+    net.join();
+    Ok(dump)
+}
+
+// The graph of the semantic parser for the graph at `path`, or its errors
+fn dump_graph(path: &str) -> Result<String> {
+    let mut net = network! {
+        open("fs_file_open.so") output -> input lex("core_parser_lexical.so"),
+        lex() output -> input sem("core_parser_semantic.so"),
+    }?;
+    net.capture::<CoreGraph>("sem", "output")?;
+    net.capture::<CoreSemanticError>("sem", "error")?;
+    net.send("sem", "option", String::from("debug"))?;
+    net.send("open", "input", FsPath(path.into()))?;
+    net.run(Duration::from_secs(10))?;
+    let mut dump = String::new();
+    for graph in net.received::<CoreGraph>("sem", "output")? {
+        for n in &graph.nodes {
+            dump.push_str(&format!("node {}({})\n", n.name, n.sort));
+        }
+        for e in &graph.edges {
+            dump.push_str(&format!("edge {}() {} -> {} {}()\n", e.out_comp, port(&e.out_port, &e.out_elem), port(&e.in_port, &e.in_elem), e.in_comp));
+        }
+        for i in &graph.imsgs {
+            dump.push_str(&format!("imsg '{}' -> {} {}()\n", i.msg, port(&i.port, &i.elem), i.comp));
+        }
+        for e in &graph.ext_in {
+            dump.push_str(&format!("ext_in {} => {} {}()\n", e.port, port(&e.in_port, &e.in_elem), e.in_comp));
+        }
+        for e in &graph.ext_out {
+            dump.push_str(&format!("ext_out {}() {} => {}\n", e.out_comp, port(&e.out_port, &e.out_elem), e.port));
+        }
+        for p in &graph.pools {
+            dump.push_str(&format!("pool {} * {}{}\n", p.name, p.size, if p.ordered { " ordered" } else { "" }));
+        }
+        for i in &graph.includes {
+            dump.push_str(&format!("include \"{}\" as {}\n", i.path, i.name));
+        }
+        for a in &graph.afters {
+            dump.push_str(&format!("after {}() {}()\n", a.node, a.after));
+        }
+    }
+    for error in net.received::<CoreSemanticError>("sem", "error")? {
+        for e in &error.parsing {
+            dump.push_str(&format!("error {}\n", e));
+        }
+    }
+    net.join();
+    Ok(dump)
+}
+
+fn port(name: &str, elem: &Option<String>) -> String {
+    match *elem {
+        Some(ref elem) => format!("{}[{}]", name, elem),
+        None => name.into(),
+    }
+}
+
+fn golden() -> Result<Option<String>> {
+    let dir = env::var("FBP_GOLDEN_DIR").unwrap_or_else(|_| GOLDEN.into());
+    let update = env::var("FBP_GOLDEN_UPDATE").is_ok();
+    let mut graphs: Vec<_> = fs::read_dir(&dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().map(|ext| ext == "fbp").unwrap_or(false))
+        .collect();
+    graphs.sort();
+    let mut failures = vec![];
+    for graph in graphs {
+        let path = graph.to_string_lossy().into_owned();
+        let dumps = vec![("tokens", dump_tokens(&path)?), ("graph", dump_graph(&path)?)];
+        for (ext, dump) in dumps {
+            let expected = graph.with_extension(ext);
+            if update {
+                File::create(&expected)?.write_all(dump.as_bytes())?;
+            } else if fs::read_to_string(&expected).ok().as_ref() != Some(&dump) {
+                failures.push(format!("{} differs, got :\n{}", expected.to_string_lossy(), dump));
+            }
+        }
+    }
+    if failures.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(failures.join("\n")))
+    }
+}
+
+agent! {
+    fn run(&mut self) -> Result<Signal> {
+        let tests: Vec<(&str, fn() -> Result<Option<String>>)> = vec![
+            ("valid graph", valid_graph),
+            ("syntax error", syntax_error),
+            ("one-2-many connection", one_to_many),
+            ("missing file", missing_file),
+            ("golden corpus", golden),
+        ];
+        let mut failed = 0;
+        for (name, test) in tests {
+            match test() {
+                Ok(None) => println!("test {} ... ok", name),
+                Ok(Some(reason)) => { println!("test {} ... FAILED : {}", name, reason); failed += 1; },
+                Err(err) => { println!("test {} ... FAILED : {}", name, err); failed += 1; },
+            }
+        }
+        if failed > 0 {
+            println!("{} tests failed", failed);
+            process::exit(1);
+        }
+        Ok(End)
+    }
+}
@@ -0,0 +1,55 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate regex;
+
+use regex::{Captures, Regex};
+
+// Send the groups of the matches of the regular expression of the option in each text on `output`
+//
+// The first match, or each match if the option says `all`, is sent as a `TextRegexCaptures`, with the groups named
+// by their name if they have one, else by their number. A text without match is sent on `unmatched`. The expression
+// is compiled once per option.
+agent! {
+    input(input: PrimText),
+    output(output: TextRegexCaptures, unmatched: PrimText),
+    // The pattern and its compiled expression
+    state(Option<(String, Regex)> => None),
+    option(TextRegexOption),
+    fn run(&mut self) -> Result<Signal> {
+        let text = self.input.input.recv()?;
+        let opt = self.recv_option();
+        if self.state.as_ref().map_or(true, |&(ref pattern, _)| *pattern != opt.pattern) {
+            let regex = Regex::new(&opt.pattern)
+                .map_err(|e| result::Error::Misc(format!("text_regex_extract : bad pattern {} : {}", opt.pattern, e)))?;
+            self.state = Some((opt.pattern.clone(), regex));
+        }
+        let regex = match self.state {
+            Some((_, ref regex)) => regex,
+            None => unreachable!(),
+        };
+
+        let found: Vec<TextRegexCaptures> = if opt.all {
+            regex.captures_iter(&text.text).map(|caps| groups(regex, &caps)).collect()
+        } else {
+            regex.captures(&text.text).map(|caps| groups(regex, &caps)).into_iter().collect()
+        };
+        if found.is_empty() {
+            self.output.unmatched.send(text)?;
+        }
+        for groups in found {
+            self.output.output.send(groups)?;
+        }
+        Ok(End)
+    }
+}
+
+fn groups(regex: &Regex, caps: &Captures) -> TextRegexCaptures {
+    let groups = regex.capture_names().enumerate().map(|(i, name)| {
+        let name = name.map(|name| name.to_string()).unwrap_or_else(|| i.to_string());
+        (name, caps.get(i).map(|m| m.as_str().to_string()))
+    }).collect();
+    TextRegexCaptures {
+        groups: groups,
+    }
+}
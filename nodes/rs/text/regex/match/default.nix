@@ -0,0 +1,9 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ TextRegexOption ];
+  capnp_edges = with edges.capnp; [ PrimText ];
+  mods = with mods.rs; [ rustfbp capnp regex ];
+  osdeps = with pkgs; [];
+}
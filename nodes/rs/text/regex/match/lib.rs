@@ -0,0 +1,35 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate regex;
+
+use regex::Regex;
+
+// Send the texts which match the regular expression of the option on `matched`, the others on `unmatched`
+//
+// The expression is compiled once per option. A text matches if the expression matches a part of it, anchor it with
+// `^` and `$` to match the whole text.
+agent! {
+    input(input: PrimText),
+    output(matched: PrimText, unmatched: PrimText),
+    // The pattern and its compiled expression
+    state(Option<(String, Regex)> => None),
+    option(TextRegexOption),
+    fn run(&mut self) -> Result<Signal> {
+        let text = self.input.input.recv()?;
+        let opt = self.recv_option();
+        if self.state.as_ref().map_or(true, |&(ref pattern, _)| *pattern != opt.pattern) {
+            let regex = Regex::new(&opt.pattern)
+                .map_err(|e| result::Error::Misc(format!("text_regex_match : bad pattern {} : {}", opt.pattern, e)))?;
+            self.state = Some((opt.pattern, regex));
+        }
+
+        let matched = self.state.as_ref().map_or(false, |&(_, ref regex)| regex.is_match(&text.text));
+        if matched {
+            self.output.matched.send(text)?;
+        } else {
+            self.output.unmatched.send(text)?;
+        }
+        Ok(End)
+    }
+}
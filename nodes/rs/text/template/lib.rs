@@ -0,0 +1,112 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate serde_json;
+
+use rustfbp::json::{JsonRegistry, Value};
+
+// Render the template of the option with the fields of each message, as a `PrimText`
+//
+//   {name} has {count} items
+//
+// A text field is written as is, a number or a boolean as in JSON, and a list as its JSON. `{a.b}` is the field `b`
+// of the structure `a`. A field the message doesn't have is an error.
+agent! {
+    input(input: CapnpMsg),
+    output(output: PrimText),
+    state(Template => Template::new()),
+    option(TextTemplateOption),
+    fn run(&mut self) -> Result<Signal> {
+        let msg = self.input.input.recv()?;
+        let opt = self.recv_option();
+        if opt.template != self.state.source {
+            self.state.compile(&opt.template)?;
+        }
+
+        let fields = self.state.json.encode_msg(&opt.schema, &msg)?;
+        let text = self.state.render(&fields)
+            .map_err(|field| result::Error::Misc(format!("text_template : the message has no field {}", field)))?;
+        self.output.output.send(PrimText::new(text))?;
+        Ok(End)
+    }
+}
+
+pub struct Template {
+    json: JsonRegistry,
+    // The template the parts were compiled from
+    source: String,
+    parts: Vec<Part>,
+}
+
+enum Part {
+    Text(String),
+    Field(String),
+}
+
+impl Template {
+    fn new() -> Self {
+        Template {
+            json: json_registry(),
+            source: String::new(),
+            parts: vec![],
+        }
+    }
+
+    fn compile(&mut self, template: &str) -> Result<()> {
+        let mut parts = vec![];
+        let mut text = String::new();
+        let mut chars = template.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => { chars.next(); text.push('{'); },
+                '}' if chars.peek() == Some(&'}') => { chars.next(); text.push('}'); },
+                '{' => {
+                    let mut field = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => field.push(c),
+                            None => {
+                                return Err(result::Error::Misc(format!("text_template : unclosed {{ in {}", template)));
+                            },
+                        }
+                    }
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::replace(&mut text, String::new())));
+                    }
+                    parts.push(Part::Field(field.trim().to_string()));
+                },
+                '}' => {
+                    return Err(result::Error::Misc(format!("text_template : unopened }} in {}", template)));
+                },
+                c => text.push(c),
+            }
+        }
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        self.source = template.to_string();
+        self.parts = parts;
+        Ok(())
+    }
+
+    // The text, or the field which is missing
+    fn render(&self, fields: &Value) -> std::result::Result<String, String> {
+        let mut text = String::new();
+        for part in &self.parts {
+            match *part {
+                Part::Text(ref t) => text.push_str(t),
+                Part::Field(ref field) => {
+                    let value = field.split('.').try_fold(fields, |value, name| value.get(name))
+                        .ok_or_else(|| field.clone())?;
+                    match *value {
+                        Value::String(ref s) => text.push_str(s),
+                        Value::Null => {},
+                        ref value => text.push_str(&value.to_string()),
+                    }
+                },
+            }
+        }
+        Ok(text)
+    }
+}
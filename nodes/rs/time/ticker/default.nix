@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ TimeTimestamp TimeTickerOption TimeTickerControl ];
+  mods = with mods.rs; [ rustfbp capnp chrono cron ];
+  osdeps = with pkgs; [];
+}
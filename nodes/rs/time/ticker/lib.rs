@@ -0,0 +1,103 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate chrono;
+extern crate cron;
+
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::Utc;
+use cron::Schedule;
+
+agent! {
+    input(control: TimeTickerControl),
+    output(output: TimeTimestamp),
+    state(Option<Ticker> => None),
+    option(TimeTickerOption),
+    autostart(true),
+    fn run(&mut self) -> Result<Signal> {
+        // The first run starts the ticks
+        if self.state.is_none() {
+            let opt = self.recv_option();
+            let schedule = match opt {
+                TimeTickerOption::Cron(ref expr) => {
+                    Some(Schedule::from_str(expr).map_err(|e| result::Error::Misc(format!("time_ticker : bad cron expression {} : {}", expr, e)))?)
+                },
+                TimeTickerOption::Interval(0) => {
+                    return Err(result::Error::Misc("time_ticker : the interval must be at least 1 ms".into()));
+                },
+                TimeTickerOption::Interval(_) => None,
+            };
+            let ticker = Ticker {
+                paused: Arc::new(AtomicBool::new(false)),
+                stopped: Arc::new(AtomicBool::new(false)),
+            };
+            let output = self.output.output.clone();
+            let paused = ticker.paused.clone();
+            let stopped = ticker.stopped.clone();
+            thread::spawn(move || {
+                tick(opt, schedule, paused, stopped, output);
+            });
+            self.state = Some(ticker);
+        }
+
+        while let Ok(control) = self.input.control.try_recv() {
+            if let Some(ref ticker) = self.state {
+                ticker.paused.store(match control {
+                    TimeTickerControl::Pause => true,
+                    TimeTickerControl::Resume => false,
+                }, Ordering::SeqCst);
+            }
+        }
+        Ok(End)
+    }
+}
+
+// Shared with the thread that ticks
+pub struct Ticker {
+    // True when paused
+    paused: Arc<AtomicBool>,
+    // Set when the agent is dropped, the thread ends at its next tick
+    stopped: Arc<AtomicBool>,
+}
+
+impl Drop for Ticker {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+fn tick(opt: TimeTickerOption, schedule: Option<Schedule>, paused: Arc<AtomicBool>, stopped: Arc<AtomicBool>,
+        output: Option<MsgSender<TimeTimestamp>>) {
+    loop {
+        // Wait for the next tick
+        let wait = match (opt.clone(), schedule.as_ref()) {
+            (TimeTickerOption::Interval(ms), _) => Duration::from_millis(ms),
+            (_, Some(schedule)) => {
+                match schedule.upcoming(Utc).next() {
+                    Some(next) => (next - Utc::now()).to_std().unwrap_or(Duration::from_millis(0)),
+                    // The cron expression has no more times
+                    None => { return; },
+                }
+            },
+            (_, None) => { return; },
+        };
+        thread::sleep(wait);
+
+        if stopped.load(Ordering::SeqCst) { return; }
+        if paused.load(Ordering::SeqCst) { continue; }
+        if let Some(ref output) = output {
+            // The receiver is gone
+            if output.send(TimeTimestamp(now())).is_err() { return; }
+        }
+    }
+}
+
+fn now() -> u64 {
+    let since = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or(Duration::from_millis(0));
+    since.as_secs() * 1000 + (since.subsec_nanos() / 1_000_000) as u64
+}
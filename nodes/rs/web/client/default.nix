@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ WebRequest WebResponse WebClientError ];
+  mods = with mods.rs; [ rustfbp capnp ureq ];
+  osdeps = with pkgs; [];
+}
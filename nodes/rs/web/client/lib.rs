@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate ureq;
+
+agent! {
+    input(input: WebRequest),
+    output(output: WebResponse, error: WebClientError),
+    // The ureq agent keeps the connections alive between the requests
+    state(ureq::Agent => ureq::Agent::new()),
+    blocking(true),
+    fn run(&mut self) -> Result<Signal> {
+        let req = self.input.input.recv()?;
+
+        let mut http = self.state.request(&req.method, &req.url);
+        for &(ref field, ref value) in &req.headers {
+            http = http.set(field, value);
+        }
+
+        // A 4xx or 5xx status is still a response
+        let response = match http.send_bytes(&req.body) {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => {
+                let _ = self.output.error.send(WebClientError { id: req.id, error: e.to_string() });
+                return Ok(End);
+            }
+        };
+
+        let status = response.status();
+        let headers = response.headers_names().into_iter()
+            .filter_map(|field| {
+                let value = response.header(&field).map(|v| v.to_string());
+                value.map(|v| (field, v))
+            })
+            .collect();
+        let mut body = vec![];
+        if let Err(e) = response.into_reader().read_to_end(&mut body) {
+            let _ = self.output.error.send(WebClientError { id: req.id, error: e.to_string() });
+            return Ok(End);
+        }
+
+        self.output.output.send(WebResponse {
+            id: req.id,
+            status: status,
+            headers: headers,
+            body: body,
+        })?;
+        Ok(End)
+    }
+}
@@ -2,7 +2,7 @@
 
 agent {
   src = ./.;
-  capnp_edges = with edges.capnp; [ FsPath NetProtocolDomainPort NetUrl ];
-  mods = with mods.rs; [ rustfbp capnp iron mount staticfile ];
-  osdeps = with pkgs; [ openssl ];
+  edges = with edges.rs; [ WebRequest WebResponse WebServerOption ];
+  mods = with mods.rs; [ rustfbp capnp tiny_http ];
+  osdeps = with pkgs; [];
 }
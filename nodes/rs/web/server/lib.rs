@@ -1,41 +1,160 @@
 #[macro_use]
 extern crate rustfbp;
-extern crate iron;
-extern crate mount;
-extern crate staticfile;
 extern crate capnp;
+extern crate tiny_http;
 
-use iron::status;
-use iron::Iron;
-use mount::Mount;
-use staticfile::Static;
-use std::path::Path;
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 
+use tiny_http::{Header, Request, Response, Server};
+
+// The requests waiting for their response, by id, with their deadline
+type Pending = Arc<Mutex<HashMap<usize, (Request, Instant)>>>;
+
+// How often the listener looks for the requests past their deadline
+const TICK: Duration = Duration::from_millis(500);
 
 agent! {
-    web_server, edges(path, domain_port, url)
-    inputs(www_dir: path, domain_port: domain_port, url: url),
-    inputs_array(),
-    outputs(),
-    outputs_array(),
-    option(),
-    acc(),
-    fn run(&mut self) -> Result<()> {
-        let mut ip = try!(self.ports.recv("www_dir"));
-        let www_dir: path::Reader = try!(ip.read_schema());
-        let www_dir = try!(www_dir.get_path());
-
-        let mut url_ip = try!(self.ports.recv("url"));
-        let url: url::Reader = try!(url_ip.read_schema());
-        let url = try!(url.get_url());
-
-        let mut domain_ip = try!(self.ports.recv("domain_port"));
-        let domain_port: domain_port::Reader = try!(domain_ip.read_schema());
-        let domain_port = try!(domain_port.get_domain_port());
-
-        let mut mount = Mount::new();
-        mount.mount(url, Static::new(Path::new(www_dir)));
-        Iron::new(mount).http(domain_port).unwrap();
-        Ok(())
+    // The requests read by the listener come in on `incoming`, to be routed with the current `request` array
+    input(response: WebResponse, incoming: WebRequest),
+    outarr(request: WebRequest),
+    state(Option<Pending> => None),
+    option(WebServerOption),
+    autostart(true),
+    fn run(&mut self) -> Result<Signal> {
+        // The first run starts the HTTP server
+        if self.state.is_none() {
+            let opt = self.recv_option();
+            let server = Server::http(&opt.bind as &str)
+                .map_err(|e| result::Error::Misc(format!("web_server cannot bind {} : {}", opt.bind, e)))?;
+            let pending: Pending = Arc::new(Mutex::new(HashMap::new()));
+            let timeout = Duration::from_millis(if opt.timeout > 0 { opt.timeout } else { 30_000 });
+            let max_body = if opt.max_body > 0 { opt.max_body as usize } else { 1 << 20 };
+            let incoming = self.input.incoming.get_sender();
+            let requests = pending.clone();
+            thread::spawn(move || {
+                listen(server, timeout, max_body, incoming, requests);
+            });
+            self.state = Some(pending);
+        }
+        let pending = match self.state {
+            Some(ref pending) => pending.clone(),
+            None => { return Ok(End); },
+        };
+
+        // Route the new requests, the elements connected since the start included
+        let opt = self.recv_option();
+        while let Ok(request) = self.input.incoming.try_recv() {
+            // The longest route prefix wins
+            let sender = opt.routes.iter()
+                .filter(|&&(ref prefix, _)| request.url.starts_with(prefix as &str))
+                .max_by_key(|&&(ref prefix, _)| prefix.len())
+                .and_then(|&(_, ref element)| self.outarr.request.get(element));
+            let id = request.id;
+            let status = match sender {
+                Some(sender) => if sender.send(request).is_ok() { continue; } else { 503 },
+                None => 404,
+            };
+            if let Some((request, _)) = pending.lock().expect("web_server : poisoned requests").remove(&id) {
+                let _ = request.respond(Response::empty(status));
+            }
+        }
+
+        // Complete the exchanges
+        while let Ok(response) = self.input.response.try_recv() {
+            let request = pending.lock().expect("web_server : poisoned requests").remove(&response.id);
+            if let Some((request, _)) = request {
+                let _ = request.respond(to_http(response));
+            }
+        }
+        Ok(End)
+    }
+}
+
+fn listen(server: Server, timeout: Duration, max_body: usize, incoming: MsgSender<WebRequest>, pending: Pending) {
+    // Set once the agent is gone
+    let gone = Arc::new(AtomicBool::new(false));
+    let mut id = 0;
+    while !gone.load(Ordering::SeqCst) {
+        let request = match server.recv_timeout(TICK) {
+            Ok(request) => request,
+            Err(_) => { return; },
+        };
+        expire(&pending);
+        let request = match request {
+            Some(request) => request,
+            None => { continue; },
+        };
+        id += 1;
+        let incoming = incoming.clone();
+        let pending = pending.clone();
+        let gone = gone.clone();
+        // A slow client only holds the thread which reads its body
+        thread::spawn(move || {
+            read(id, request, timeout, max_body, incoming, pending, gone);
+        });
+    }
+}
+
+// Read the body of the request `id`, and send the request to the agent
+fn read(id: usize, mut request: Request, timeout: Duration, max_body: usize, incoming: MsgSender<WebRequest>,
+        pending: Pending, gone: Arc<AtomicBool>) {
+    if request.body_length().map(|len| len > max_body).unwrap_or(false) {
+        let _ = request.respond(Response::empty(413));
+        return;
+    }
+    // A chunked body has no length, one byte over the limit is enough to refuse it
+    let mut body = vec![];
+    if request.as_reader().take(max_body as u64 + 1).read_to_end(&mut body).is_err() {
+        let _ = request.respond(Response::empty(400));
+        return;
+    }
+    if body.len() > max_body {
+        let _ = request.respond(Response::empty(413));
+        return;
+    }
+    let msg = WebRequest {
+        id: id,
+        method: request.method().to_string(),
+        url: request.url().to_string(),
+        headers: request.headers().iter()
+            .map(|h| (h.field.to_string(), h.value.to_string()))
+            .collect(),
+        body: body,
+    };
+    pending.lock().expect("web_server : poisoned requests").insert(id, (request, Instant::now() + timeout));
+    if incoming.send(msg).is_err() {
+        if let Some((request, _)) = pending.lock().expect("web_server : poisoned requests").remove(&id) {
+            let _ = request.respond(Response::empty(503));
+        }
+        gone.store(true, Ordering::SeqCst);
+    }
+}
+
+// Answer 504 to the requests past their deadline, their late responses are ignored
+fn expire(pending: &Pending) {
+    let now = Instant::now();
+    let mut pending = pending.lock().expect("web_server : poisoned requests");
+    let expired: Vec<usize> = pending.iter()
+        .filter(|&(_, &(_, deadline))| deadline <= now)
+        .map(|(&id, _)| id)
+        .collect();
+    for id in expired {
+        if let Some((request, _)) = pending.remove(&id) {
+            let _ = request.respond(Response::empty(504));
+        }
+    }
+}
+
+fn to_http(response: WebResponse) -> Response<Cursor<Vec<u8>>> {
+    let mut http = Response::from_data(response.body).with_status_code(response.status);
+    for (field, value) in response.headers {
+        if let Ok(header) = Header::from_bytes(field.as_bytes(), value.as_bytes()) {
+            http.add_header(header);
+        }
     }
+    http
 }
@@ -0,0 +1,8 @@
+{ agent, edges, mods, pkgs }:
+
+agent {
+  src = ./.;
+  edges = with edges.rs; [ WebWsFrame WebWsOption ];
+  mods = with mods.rs; [ rustfbp capnp tungstenite ];
+  osdeps = with pkgs; [];
+}
@@ -0,0 +1,110 @@
+#[macro_use]
+extern crate rustfbp;
+extern crate capnp;
+extern crate tungstenite;
+
+use std::cmp;
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::thread;
+use std::time::Duration;
+
+use tungstenite::{Message, WebSocket};
+
+agent! {
+    input(input: WebWsFrame),
+    output(output: WebWsFrame),
+    // The frames to send, the connection is kept by another thread
+    state(Option<Sender<WebWsFrame>> => None),
+    option(WebWsOption),
+    autostart(true),
+    fn run(&mut self) -> Result<Signal> {
+        // The first run starts the connection
+        if self.state.is_none() {
+            let opt = self.recv_option();
+            let listener = if opt.server { Some(TcpListener::bind(&opt.address as &str)?) } else { None };
+            let (s, r) = channel();
+            let output = self.output.output.clone();
+            let log = self.log.clone();
+            thread::spawn(move || {
+                bridge(opt, listener, r, output, log);
+            });
+            self.state = Some(s);
+        }
+
+        while let Ok(frame) = self.input.input.try_recv() {
+            if let Some(ref outbound) = self.state {
+                outbound.send(frame).map_err(|_| result::Error::MpscSend)?;
+            }
+        }
+        Ok(End)
+    }
+}
+
+// Reconnect with an exponential backoff, until the agent is dropped
+fn bridge(opt: WebWsOption, listener: Option<TcpListener>, outbound: Receiver<WebWsFrame>, output: Option<MsgSender<WebWsFrame>>, log: rustfbp::log::Context) {
+    let mut backoff = 100;
+    loop {
+        match open(&opt, &listener) {
+            Ok(mut ws) => {
+                backoff = 100;
+                match serve(&mut ws, &outbound, &output) {
+                    Ok(()) => { return; },
+                    Err(e) => { log.warn(format_args!("connection lost : {}", e)); },
+                }
+            }
+            Err(e) => {
+                log.warn(format_args!("cannot connect to {} : {}", opt.address, e));
+                thread::sleep(Duration::from_millis(backoff));
+                backoff = cmp::min(backoff * 2, 30_000);
+            }
+        }
+    }
+}
+
+fn open(opt: &WebWsOption, listener: &Option<TcpListener>) -> io::Result<WebSocket<TcpStream>> {
+    let ws = match *listener {
+        Some(ref listener) => {
+            let (stream, _) = listener.accept()?;
+            tungstenite::accept(stream).map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?
+        }
+        None => {
+            // ws://host:port/path
+            let host = opt.address.trim_left_matches("ws://").split('/').next().unwrap_or("");
+            let stream = TcpStream::connect(host)?;
+            let (ws, _) = tungstenite::client(&opt.address as &str, stream).map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+            ws
+        }
+    };
+    // Wake up regularly to send the outbound frames
+    ws.get_ref().set_read_timeout(Some(Duration::from_millis(50)))?;
+    Ok(ws)
+}
+
+// Returns Ok when the agent is dropped, Err when the connection is lost
+fn serve(ws: &mut WebSocket<TcpStream>, outbound: &Receiver<WebWsFrame>, output: &Option<MsgSender<WebWsFrame>>) -> tungstenite::Result<()> {
+    loop {
+        loop {
+            let msg = match outbound.try_recv() {
+                Ok(WebWsFrame::Text(text)) => Message::Text(text),
+                Ok(WebWsFrame::Binary(bytes)) => Message::Binary(bytes),
+                Err(TryRecvError::Empty) => { break; },
+                Err(TryRecvError::Disconnected) => { return Ok(()); },
+            };
+            ws.write_message(msg)?;
+        }
+
+        let frame = match ws.read_message() {
+            Ok(Message::Text(text)) => WebWsFrame::Text(text),
+            Ok(Message::Binary(bytes)) => WebWsFrame::Binary(bytes),
+            Ok(Message::Close(_)) => { return Err(tungstenite::Error::ConnectionClosed); },
+            Ok(_) => { continue; },
+            Err(tungstenite::Error::Io(ref e)) if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut => { continue; },
+            Err(e) => { return Err(e); },
+        };
+        if let Some(ref output) = *output {
+            let _ = output.send(frame);
+        }
+    }
+}
@@ -11,8 +11,8 @@ in (pkgs {
     (import (builtins.toPath "${rustOverlay}/rust-overlay.nix"))
     (self: super: rec {
       rust = with super.rustChannels; {
-        rustc = nightly.rust;
-        inherit (nightly) cargo;
+        rustc = stable.rust;
+        inherit (stable) cargo;
       };
       rustPlatform = super.recurseIntoAttrs (super.makeRustPlatform rust);
     })
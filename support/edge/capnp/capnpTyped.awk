@@ -0,0 +1,68 @@
+# Generate a `capnp_edge!` call for each flat struct of a Cap'n Proto schema.
+# A struct is flat when all its fields are primitive, Text or Data; other structs are skipped.
+# Then a `capnp_edge_json!` call declares the JSON registry of all the flat structs, and a `capnp_edge_schema!` call
+# the fields of all the structs, flat or not.
+
+function snake(s,    out, i, c) {
+  out = ""
+  for (i = 1; i <= length(s); i++) {
+    c = substr(s, i, 1)
+    if (c != tolower(c)) {
+      if (i > 1) { out = out "_" }
+      c = tolower(c)
+    }
+    out = out c
+  }
+  return out
+}
+
+BEGIN {
+  split("Text Data Bool Int8 Int16 Int32 Int64 UInt8 UInt16 UInt32 UInt64 Float32 Float64", list, " ")
+  for (k in list) { kinds[list[k]] = 1 }
+}
+
+/^[ \t]*struct[ \t]+[A-Za-z0-9_]+/ && depth == 0 {
+  name = $2
+  sub(/\{.*/, "", name)
+  fields = ""
+  schema = ""
+  flat = 1
+}
+
+/@[0-9]+[ \t]*:/ && depth == 1 && name != "" {
+  field = $1
+  kind = $0
+  sub(/^[^:]*:[ \t]*/, "", kind)
+  sub(/[ \t]*[;=].*$/, "", kind)
+  schema = schema (schema == "" ? "" : ", ") snake(field) ": \"" kind "\""
+  if (kind in kinds) {
+    if (fields != "") { fields = fields "," }
+    fields = fields "\n    " snake(field) ": " kind " = (get_" snake(field) ", set_" snake(field) ")"
+  } else if (kind != "Void") {
+    flat = 0
+  }
+}
+
+/union|group/ && depth >= 1 { flat = 0 }
+
+{
+  depth += gsub(/\{/, "{")
+  depth -= gsub(/\}/, "}")
+  if (depth == 0 && name != "" && /\}/) {
+    if (flat && fields != "") {
+      print ""
+      print "capnp_edge!(" name ", " snake(name) " {" fields
+      print "});"
+      names = names (names == "" ? "" : ", ") name
+    }
+    schemas = schemas (schemas == "" ? "" : ",") "\n  " name " { " schema " }"
+    name = ""
+  }
+}
+
+END {
+  print ""
+  print "capnp_edge_json!(" names ");"
+  print ""
+  print "capnp_edge_schema!(" schemas (schemas == "" ? "" : "\n") ");"
+}
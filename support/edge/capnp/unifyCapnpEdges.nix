@@ -32,6 +32,8 @@ stdenv.mkDerivation (args // rec {
           ''
           else if target == "rs" then ''
             ${capnproto}/bin/capnp compile -o${capnpcPlugins.rs}/bin/capnpc_rust edge.capnp
+            # typed Rust structures of the flat schemas, see rustfbp::capnp_edge
+            awk -f ${./capnpTyped.awk} edge.capnp >> edge_capnp.rs
           ''
           else ''
             echo "Unknown capnproto compiler plugin called."
@@ -15,6 +15,8 @@
   , capnp_edges ? []
   , edges ? []
   , configurePhase ? ""
+  , edition ? ""
+  , version ? ""
   , ... } @ args:
 let
   compName = if name == null then genName src else name;
@@ -34,13 +36,14 @@ in
     unifiedRustEdges = unifiedRustEdges;
     buildInputs = osdeps;
     crateName = compName;
-    version = "";
+    version = version;
     libPath = "lib.rs";
     dependencies = mods;
     fractalType = fractalType;
     src = src;
     features = [];
     configurePhase = configurePhase;
+    edition = edition;
     release = buffet.release;
     verbose = buffet.verbose;
   }
@@ -2,6 +2,7 @@
 */
 {
   test_nand  = (import ../. { node = "test_nand"; }).pkg;
+  test_fvm_parser = (import ../. { node = "test_fvm_parser"; }).pkg;
   bench      = (import ../. { node = "bench"; }).pkg;
   bench_load = (import ../. { node = "bench_load"; }).pkg;
 }